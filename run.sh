@@ -53,4 +53,4 @@ cargo build ${CARGO_LOG_LEVEL} ${RELEASE_FLAG}
 #source './zandbox/.env'
 #cargo run ${CARGO_LOG_LEVEL} ${RELEASE_FLAG} --bin 'zandbox' -- ${LOG_LEVEL} \
 #  --network "${NETWORK}" \
-#  --postgresql "${DATABASE_URL}"
+#  --database "${DATABASE_URL}"
@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS zandbox.snapshots (
+    account_id         BIGINT NOT NULL,
+    version            BIGINT NOT NULL,
+
+    fields             JSON NOT NULL,
+    balances           JSON NOT NULL,
+    transactions       JSON NOT NULL,
+    is_pending         BOOLEAN NOT NULL DEFAULT FALSE,
+
+    created_at         TIMESTAMP NOT NULL,
+
+    PRIMARY KEY        (account_id, version),
+
+    CONSTRAINT fk_account_id
+        FOREIGN KEY (account_id)
+            REFERENCES zandbox.contracts(account_id)
+);
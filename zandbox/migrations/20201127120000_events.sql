@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS zandbox.events (
+    id                 BIGSERIAL NOT NULL,
+    account_id         BIGINT NOT NULL,
+    version            BIGINT NOT NULL,
+
+    name               TEXT NOT NULL,
+    value              JSON NOT NULL,
+
+    created_at         TIMESTAMP NOT NULL,
+
+    PRIMARY KEY        (id),
+
+    CONSTRAINT fk_account_id
+        FOREIGN KEY (account_id)
+            REFERENCES zandbox.contracts(account_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_events_account_id ON zandbox.events (account_id, id);
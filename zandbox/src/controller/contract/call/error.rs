@@ -20,6 +20,8 @@ pub enum Error {
     ContractNotFound(String),
     /// The contract with the specified address is locked.
     ContractLocked(String),
+    /// The contract storage has been concurrently changed too many times during the call.
+    ContractStateConflict(String),
     /// The specified method does not exist in the contract.
     MethodNotFound(String),
     /// The immutable method must be called via the `query` endpoint.
@@ -33,7 +35,7 @@ pub enum Error {
 
     /// The virtual machine contract method runtime error.
     RuntimeError(RuntimeError),
-    /// The PostgreSQL database error.
+    /// The database error.
     Database(sqlx::Error),
     /// The ZkSync server client error.
     ZkSyncClient(zksync::error::ClientError),
@@ -41,6 +43,8 @@ pub enum Error {
     ZkSyncSigner(zksync_eth_signer::error::SignerError),
     /// The ZkSync transfer errors.
     TransferFailure(String),
+    /// The ZkSync transfers have not been committed in time, but can still be committed.
+    TransferTimeout(Vec<String>),
 }
 
 impl From<TransactionError> for Error {
@@ -72,6 +76,7 @@ impl ResponseError for Error {
         match self {
             Self::ContractNotFound(..) => StatusCode::NOT_FOUND,
             Self::ContractLocked(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::ContractStateConflict(..) => StatusCode::CONFLICT,
             Self::MethodNotFound(..) => StatusCode::BAD_REQUEST,
             Self::MethodIsImmutable(..) => StatusCode::BAD_REQUEST,
             Self::InvalidInput(..) => StatusCode::BAD_REQUEST,
@@ -83,6 +88,7 @@ impl ResponseError for Error {
             Self::ZkSyncClient(..) => StatusCode::SERVICE_UNAVAILABLE,
             Self::ZkSyncSigner(..) => StatusCode::INTERNAL_SERVER_ERROR,
             Self::TransferFailure { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::TransferTimeout(..) => StatusCode::GATEWAY_TIMEOUT,
         }
     }
 }
@@ -103,6 +109,10 @@ impl fmt::Display for Error {
                 format!("Contract with address {} not found", address)
             }
             Self::ContractLocked(address) => format!("Contract with address {} is locked", address),
+            Self::ContractStateConflict(address) => format!(
+                "Contract with address {} storage is being changed concurrently, try again later",
+                address
+            ),
             Self::MethodNotFound(name) => format!("Method `{}` not found", name),
             Self::MethodIsImmutable(name) => {
                 format!("Method `{}` is immutable: use 'query' instead", name)
@@ -118,6 +128,10 @@ impl fmt::Display for Error {
             Self::ZkSyncClient(inner) => format!("ZkSync: {:?}", inner),
             Self::ZkSyncSigner(inner) => format!("ZkSync: {:?}", inner),
             Self::TransferFailure(inner) => format!("Transfer failure: {}", inner),
+            Self::TransferTimeout(tx_hashes) => format!(
+                "Transfers {} have not been committed in time. The contract storage is locked until they are committed or the lock expires",
+                tx_hashes.join(", ")
+            ),
         };
 
         log::warn!("{}", error);
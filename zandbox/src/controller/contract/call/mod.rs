@@ -19,13 +19,18 @@ use serde_json::Value as JsonValue;
 use zksync::operations::SyncTransactionHandle;
 use zksync_eth_signer::PrivateKeySigner;
 use zksync_types::tx::ZkSyncTx;
+use zksync_types::AccountId;
 
 use zinc_build::Value as BuildValue;
 use zinc_vm::Bn256;
 use zinc_vm::ContractInput;
 use zinc_zksync::Transaction;
 
+use crate::database::client::Client as DatabaseClient;
+use crate::database::model::event::insert::Input as EventInsertInput;
 use crate::database::model::field::select::Input as FieldSelectInput;
+use crate::database::model::snapshot::reserve::Input as SnapshotReserveInput;
+use crate::database::model::snapshot::select::Input as SnapshotSelectInput;
 use crate::response::Response;
 use crate::shared_data::SharedData;
 use crate::storage::Storage;
@@ -41,14 +46,24 @@ use self::request::Query as RequestQuery;
 /// 1. Get the contract from the in-memory cache.
 /// 2. Extract the called method from its metadata and check if it is mutable.
 /// 3. Parse the method input arguments.
-/// 4. Get the contract storage from data sources and convert it to the Zinc VM representation.
-/// 5. Run the method on the Zinc VM.
-/// 6. Extract the storage with the updated state from the Zinc VM.
-/// 7. Create a transactions array from the client and contract transfers.
-/// 8. Send the transactions to zkSync and store its handles.
-/// 9. Wait for all transactions to be committed.
-/// 10. Update the contract storage state in the database.
-/// 11. Send the contract method execution result back to the client.
+/// 4. Wait for the preceding calls to the same contract in the per-contract call queue.
+/// 5. Get the next contract storage version and the contract storage from data sources, and
+///    convert the storage to the Zinc VM representation.
+/// 6. Run the method on the Zinc VM.
+/// 7. Check if the contract storage version has not been changed by another Zandbox instance,
+///    and repeat the steps 5-6 otherwise.
+/// 8. Extract the storage with the updated state from the Zinc VM.
+/// 9. Create a transactions array from the client and contract transfers.
+/// 10. Reserve the contract storage snapshot version. The version uniqueness rejects a concurrent
+///     writer with the same pre-state before any transaction is sent.
+/// 11. Send the transactions to zkSync and wait for all of them to be committed. The snapshot
+///     version is released if the call fails at this or any later step, except for the commit
+///     timeout, when the transactions can still be committed, so the version is kept reserved
+///     until the reservation lifetime expires.
+/// 12. Write the contract storage snapshot with the transaction hashes and the updated contract
+///     storage state to the database in a single database transaction.
+/// 13. Append the emitted events to the database and send them to the WebSocket subscribers.
+/// 14. Send the contract method execution result back to the client.
 ///
 pub async fn handle(
     app_data: web::Data<Arc<RwLock<SharedData>>>,
@@ -58,10 +73,10 @@ pub async fn handle(
     let query = query.into_inner();
     let body = body.into_inner();
 
-    let postgresql = app_data
+    let database = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
-        .postgresql
+        .database
         .clone();
 
     log::debug!(
@@ -108,37 +123,67 @@ pub async fn handle(
     let input_value = BuildValue::try_from_typed_json(body.arguments, method.input)
         .map_err(Error::InvalidInput)?;
 
-    log::debug!("Loading the pre-transaction contract storage");
-    let database_fields = postgresql
-        .select_fields(FieldSelectInput::new(account_id))
-        .await?;
-    let storage = Storage::new_with_data(
-        database_fields,
-        contract.build.storage.as_slice(),
-        contract.eth_address,
-        &wallet,
-    )
-    .await?;
+    log::debug!("Waiting for the preceding calls to the contract to be finished");
+    let call_lock = app_data
+        .write()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .call_queue
+        .get(query.address);
+    let _call_guard = call_lock.lock().await;
 
-    log::debug!("Running the contract method on the virtual machine");
-    let method = query.method;
-    let contract_build = contract.build;
     let transaction = (&body.transaction).try_to_msg(&wallet)?;
-    let vm_time = std::time::Instant::now();
-    let output = async_std::task::spawn_blocking(move || {
-        zinc_vm::ContractFacade::new(contract_build).run::<Bn256>(ContractInput::new(
-            input_value,
-            storage.into_build(),
-            method,
-            transaction,
-        ))
-    })
-    .await
-    .map_err(Error::RuntimeError)?;
-    log::debug!("VM executed in {} ms", vm_time.elapsed().as_millis());
+    let mut attempt = 1;
+    let (version, output) = loop {
+        log::debug!("Loading the pre-transaction contract storage");
+        let version = next_version(&database, account_id).await?;
+        let database_fields = database
+            .select_fields(FieldSelectInput::new(account_id))
+            .await?;
+        let storage = Storage::new_with_data(
+            database_fields,
+            contract.build.storage.as_slice(),
+            contract.eth_address,
+            &wallet,
+        )
+        .await?;
+
+        log::debug!("Running the contract method on the virtual machine");
+        let contract_build = contract.build.clone();
+        let input_value = input_value.clone();
+        let method = query.method.clone();
+        let transaction = transaction.clone();
+        let vm_time = std::time::Instant::now();
+        let output = async_std::task::spawn_blocking(move || {
+            zinc_vm::ContractFacade::new(contract_build).run::<Bn256>(ContractInput::new(
+                input_value,
+                storage.into_build(),
+                method,
+                transaction,
+            ))
+        })
+        .await
+        .map_err(Error::RuntimeError)?;
+        log::debug!("VM executed in {} ms", vm_time.elapsed().as_millis());
+
+        let latest_version = next_version(&database, account_id).await?;
+        if latest_version == version {
+            break (version, output);
+        }
+        if attempt >= zinc_const::limit::CALL_ATTEMPTS {
+            return Err(Error::ContractStateConflict(
+                serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
+            ));
+        }
+        log::warn!(
+            "The contract storage has been changed from version {} to {} during the call, retrying",
+            version - 1,
+            latest_version - 1,
+        );
+        attempt += 1;
+    };
 
     log::debug!("Loading the post-transaction contract storage");
-    let storage = Storage::from_build(output.storage).into_database_update(account_id);
+    let storage = Storage::from_build(output.storage);
 
     log::debug!("Building the transaction list");
     let mut transactions = Vec::with_capacity(1 + output.transfers.len());
@@ -205,11 +250,132 @@ pub async fn handle(
         nonce += 1;
     }
 
+    log::debug!("Reserving the contract storage snapshot {}", version);
+    let reservation = SnapshotReserveInput::new(
+        account_id,
+        version,
+        Duration::from_secs(zinc_const::zandbox::SNAPSHOT_RESERVATION_LIFETIME),
+    );
+    if !database.reserve_snapshot(reservation).await? {
+        return Err(Error::ContractStateConflict(
+            serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
+        ));
+    }
+
     log::debug!(
         "Sending the transactions to zkSync on network `{}`",
         query.network
     );
-    let handles: Vec<SyncTransactionHandle> = wallet
+    let tx_hashes = match send_transactions(&wallet, transactions).await {
+        Ok(tx_hashes) => tx_hashes,
+        Err(error @ Error::TransferTimeout(..)) => {
+            log::warn!(
+                "Keeping the contract storage snapshot {} reserved, since the transactions can still be committed",
+                version
+            );
+            return Err(error);
+        }
+        Err(error) => {
+            release_snapshot(&database, reservation).await;
+            return Err(error);
+        }
+    };
+
+    log::debug!("Loading the post-transaction contract balances");
+    let balances = match Storage::load_balances(&wallet).await {
+        Ok(balances) => balances,
+        Err(error) => {
+            release_snapshot(&database, reservation).await;
+            return Err(error.into());
+        }
+    };
+
+    log::debug!(
+        "Committing the contract storage snapshot {} to the database",
+        version
+    );
+    if let Err(error) = database
+        .commit_snapshot(
+            storage
+                .clone()
+                .into_database_snapshot(account_id, version, balances, tx_hashes),
+            storage.into_database_update(account_id),
+        )
+        .await
+    {
+        release_snapshot(&database, reservation).await;
+        return Err(error.into());
+    }
+
+    log::debug!("Appending {} contract events", output.events.len());
+    let events: Vec<JsonValue> = database
+        .insert_events(
+            output
+                .events
+                .into_iter()
+                .map(|event| {
+                    EventInsertInput::new(account_id, version, event.name, event.value.into_json())
+                })
+                .collect(),
+        )
+        .await?
+        .into_iter()
+        .map(|event| serde_json::to_value(event).expect(zinc_const::panic::DATA_CONVERSION))
+        .collect();
+    app_data
+        .write()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .publish_events(query.address, events.as_slice());
+
+    let response = json!({
+        "output": output.result.into_json(),
+    });
+
+    log::debug!("The call has been successfully executed");
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
+
+///
+/// Returns the version of the next contract storage snapshot.
+///
+async fn next_version(database: &DatabaseClient, account_id: AccountId) -> Result<u32, Error> {
+    Ok(database
+        .select_snapshot(SnapshotSelectInput::new(account_id, None))
+        .await?
+        .map(|snapshot| snapshot.version as u32 + 1)
+        .unwrap_or(1))
+}
+
+///
+/// Releases the contract storage snapshot `reservation` after the call has failed.
+///
+/// A release failure is only logged, since the original call error must be returned, and the
+/// abandoned reservation is reclaimed by another call after its lifetime expires.
+///
+async fn release_snapshot(database: &DatabaseClient, reservation: SnapshotReserveInput) {
+    log::debug!(
+        "Releasing the contract storage snapshot {}",
+        reservation.version
+    );
+    if let Err(error) = database.release_snapshot(reservation).await {
+        log::error!(
+            "Contract storage snapshot {} releasing: {:?}",
+            reservation.version,
+            error
+        );
+    }
+}
+
+///
+/// Sends the `transactions` to zkSync in a batch and waits for them to be committed.
+///
+/// Returns the transaction hashes.
+///
+async fn send_transactions(
+    wallet: &zksync::Wallet<PrivateKeySigner>,
+    transactions: Vec<Transaction>,
+) -> Result<Vec<String>, Error> {
+    let tx_hashes = wallet
         .provider
         .send_txs_batch(
             transactions
@@ -222,10 +388,11 @@ pub async fn handle(
                 })
                 .collect(),
         )
-        .await?
-        .into_iter()
+        .await?;
+    let handles: Vec<SyncTransactionHandle> = tx_hashes
+        .iter()
         .map(|tx_hash| {
-            let mut handle = SyncTransactionHandle::new(tx_hash, wallet.provider.clone())
+            let mut handle = SyncTransactionHandle::new(*tx_hash, wallet.provider.clone())
                 .commit_timeout(Duration::from_secs(10));
             handle
                 .polling_interval(Duration::from_millis(200))
@@ -237,7 +404,18 @@ pub async fn handle(
     if let Some(handle) = handles.last() {
         log::debug!("Waiting for the batch transaction to be committed");
 
-        let tx_info = handle.wait_for_commit().await?;
+        let tx_info = handle
+            .wait_for_commit()
+            .await
+            .map_err(|error| match error {
+                zksync::error::ClientError::OperationTimeout => Error::TransferTimeout(
+                    tx_hashes
+                        .iter()
+                        .map(|tx_hash| tx_hash.to_string())
+                        .collect(),
+                ),
+                error => Error::ZkSyncClient(error),
+            })?;
         if !tx_info.success.unwrap_or_default() {
             return Err(Error::TransferFailure(
                 tx_info
@@ -247,13 +425,8 @@ pub async fn handle(
         }
     }
 
-    log::debug!("Committing the contract storage state to the database");
-    postgresql.update_fields(storage).await?;
-
-    let response = json!({
-        "output": output.result.into_json(),
-    });
-
-    log::debug!("The call has been successfully executed");
-    Ok(Response::new_with_data(StatusCode::OK, response))
+    Ok(tx_hashes
+        .into_iter()
+        .map(|tx_hash| tx_hash.to_string())
+        .collect())
 }
@@ -12,7 +12,7 @@ use actix_web::ResponseError;
 ///
 #[derive(Debug)]
 pub enum Error {
-    /// The PostgreSQL database error.
+    /// The database error.
     Database(sqlx::Error),
 }
 
@@ -28,13 +28,13 @@ use self::response::Instance as ResponseInstance;
 pub async fn handle(
     app_data: web::Data<Arc<RwLock<SharedData>>>,
 ) -> crate::Result<ResponseBody, Error> {
-    let postgresql = app_data
+    let database = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
-        .postgresql
+        .database
         .clone();
 
-    let response: ResponseBody = postgresql
+    let response: ResponseBody = database
         .select_contracts_curve()
         .await?
         .into_iter()
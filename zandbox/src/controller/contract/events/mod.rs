@@ -0,0 +1,84 @@
+//!
+//! The contract resource GET method `events` module.
+//!
+
+pub mod error;
+pub mod request;
+pub mod response;
+pub mod subscribe;
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::database::model::event::select::Input as EventSelectInput;
+use crate::response::Response;
+use crate::shared_data::SharedData;
+
+use self::error::Error;
+use self::request::Query as RequestQuery;
+use self::response::Body as ResponseBody;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the contract from the in-memory cache.
+/// 2. Get the page of events emitted after the specified one from the database.
+/// 3. Send the events back to the client.
+///
+/// The client gets the next page by passing the ID of the last received event as `after`.
+///
+pub async fn handle(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    query: web::Query<RequestQuery>,
+) -> crate::Result<ResponseBody, Error> {
+    let query = query.into_inner();
+
+    let database = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .database
+        .clone();
+
+    log::debug!(
+        "Getting the events of the contract {} after {}",
+        serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
+        query
+            .after
+            .map(|after| after.to_string())
+            .unwrap_or_else(|| "the beginning".to_owned()),
+    );
+
+    let account_id = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .contracts
+        .get(&query.address)
+        .ok_or_else(|| {
+            Error::ContractNotFound(
+                serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
+            )
+        })?
+        .account_id
+        .ok_or_else(|| {
+            Error::ContractLocked(
+                serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
+            )
+        })?;
+
+    let limit = query
+        .limit
+        .unwrap_or(zinc_const::limit::EVENTS_PAGE_SIZE)
+        .min(zinc_const::limit::EVENTS_PAGE_SIZE_MAX);
+    let events = database
+        .select_events(EventSelectInput::new(account_id, query.after, limit))
+        .await?;
+
+    Ok(Response::new_with_data(
+        StatusCode::OK,
+        ResponseBody::new(events),
+    ))
+}
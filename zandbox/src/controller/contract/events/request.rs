@@ -0,0 +1,21 @@
+//!
+//! The contract resource GET `events` request.
+//!
+
+use serde::Deserialize;
+
+use zksync::web3::types::Address;
+
+///
+/// The contract resource GET `events` request query.
+///
+#[derive(Debug, Deserialize)]
+pub struct Query {
+    /// The contract ETH address.
+    pub address: Address,
+    /// The ID of the last event received by the client. If not specified, the events are
+    /// returned from the beginning.
+    pub after: Option<i64>,
+    /// The page size. If not specified, the default page size is used.
+    pub limit: Option<u32>,
+}
@@ -0,0 +1,25 @@
+//!
+//! The contract resource GET `events` response.
+//!
+
+use serde::Serialize;
+
+use crate::database::model::event::select::Output as EventSelectOutput;
+
+///
+/// The contract resource GET `events` response body.
+///
+#[derive(Debug, Serialize)]
+pub struct Body {
+    /// The events page in the order of emitting.
+    pub events: Vec<EventSelectOutput>,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(events: Vec<EventSelectOutput>) -> Self {
+        Self { events }
+    }
+}
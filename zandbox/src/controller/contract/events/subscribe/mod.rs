@@ -0,0 +1,56 @@
+//!
+//! The contract resource GET method `events/subscribe` module.
+//!
+
+pub mod request;
+pub mod session;
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::web;
+use actix_web::HttpRequest;
+use actix_web::HttpResponse;
+use actix_web_actors::ws;
+
+use crate::shared_data::SharedData;
+
+use super::error::Error;
+
+use self::request::Query as RequestQuery;
+use self::session::Session;
+
+///
+/// The WebSocket upgrade request handler.
+///
+/// Sequence:
+/// 1. Check if the contract exists in the in-memory cache.
+/// 2. Subscribe to the contract events emitted by the subsequent calls.
+/// 3. Start the WebSocket session, which sends the events to the client.
+///
+/// The events emitted before the subscription must be requested via the GET `events` method.
+///
+pub async fn handle(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    query: web::Query<RequestQuery>,
+    request: HttpRequest,
+    payload: web::Payload,
+) -> Result<HttpResponse, actix_web::Error> {
+    let query = query.into_inner();
+
+    log::debug!(
+        "Subscribing to the events of the contract {}",
+        serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
+    );
+
+    let mut shared_data = app_data.write().expect(zinc_const::panic::SYNCHRONIZATION);
+    if !shared_data.contracts.contains_key(&query.address) {
+        return Err(Error::ContractNotFound(
+            serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
+        )
+        .into());
+    }
+    let receiver = shared_data.subscribe_events(query.address);
+
+    ws::start(Session::new(receiver), &request, payload)
+}
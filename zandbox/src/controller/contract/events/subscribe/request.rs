@@ -0,0 +1,16 @@
+//!
+//! The contract resource GET `events/subscribe` request.
+//!
+
+use serde::Deserialize;
+
+use zksync::web3::types::Address;
+
+///
+/// The contract resource GET `events/subscribe` request query.
+///
+#[derive(Debug, Deserialize)]
+pub struct Query {
+    /// The contract ETH address.
+    pub address: Address,
+}
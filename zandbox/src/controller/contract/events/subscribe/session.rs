@@ -0,0 +1,70 @@
+//!
+//! The contract events WebSocket session.
+//!
+
+use actix::Actor;
+use actix::ActorContext;
+use actix::AsyncContext;
+use actix::StreamHandler;
+use actix_web_actors::ws;
+use futures::channel::mpsc;
+use serde_json::Value as JsonValue;
+
+///
+/// The contract events WebSocket session.
+///
+/// Every event is sent as a separate text message with the same JSON representation as in the
+/// GET `events` response. Incoming messages, except the control ones, are ignored.
+///
+pub struct Session {
+    /// The contract events receiver, which is moved to the actor context on start.
+    receiver: Option<mpsc::UnboundedReceiver<JsonValue>>,
+}
+
+impl Session {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(receiver: mpsc::UnboundedReceiver<JsonValue>) -> Self {
+        Self {
+            receiver: Some(receiver),
+        }
+    }
+}
+
+impl Actor for Session {
+    type Context = ws::WebsocketContext<Self>;
+
+    fn started(&mut self, context: &mut Self::Context) {
+        if let Some(receiver) = self.receiver.take() {
+            context.add_stream(receiver);
+        }
+    }
+}
+
+impl StreamHandler<JsonValue> for Session {
+    fn handle(&mut self, event: JsonValue, context: &mut Self::Context) {
+        context.text(event.to_string());
+    }
+}
+
+impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for Session {
+    fn handle(
+        &mut self,
+        message: Result<ws::Message, ws::ProtocolError>,
+        context: &mut Self::Context,
+    ) {
+        match message {
+            Ok(ws::Message::Ping(message)) => context.pong(&message),
+            Ok(ws::Message::Close(reason)) => {
+                context.close(reason);
+                context.stop();
+            }
+            Ok(_) => {}
+            Err(error) => {
+                log::warn!("Event subscription WebSocket: {}", error);
+                context.stop();
+            }
+        }
+    }
+}
@@ -35,7 +35,7 @@ pub enum Error {
 
     /// The virtual machine contract method runtime error.
     RuntimeError(RuntimeError),
-    /// The PostgreSQL database error.
+    /// The database error.
     Database(sqlx::Error),
     /// The ZkSync server client error.
     ZkSyncClient(zksync::error::ClientError),
@@ -53,10 +53,10 @@ pub async fn handle(
     let query = query.into_inner();
     let body = body.into_inner();
 
-    let postgresql = app_data
+    let database = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
-        .postgresql
+        .database
         .clone();
 
     log::debug!(
@@ -104,7 +104,7 @@ pub async fn handle(
         .map_err(Error::InvalidInput)?;
 
     log::debug!("Loading the pre-transaction contract storage");
-    let database_fields = postgresql
+    let database_fields = database
         .select_fields(FieldSelectInput::new(account_id))
         .await?;
     let storage = Storage::new_with_data(
@@ -25,7 +25,7 @@ pub enum Error {
     /// Failed to execute the change-pubkey transaction.
     ChangePubkey(String),
 
-    /// The PostgreSQL database error.
+    /// The database error.
     Database(sqlx::Error),
     /// The ZkSync server client error.
     ZkSyncClient(zksync::error::ClientError),
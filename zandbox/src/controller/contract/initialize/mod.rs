@@ -20,6 +20,7 @@ use zksync_types::tx::ZkSyncTx;
 use crate::database::model::contract::insert_new::Input as ContractInsertNewInput;
 use crate::response::Response;
 use crate::shared_data::SharedData;
+use crate::storage::Storage;
 
 use self::error::Error;
 use self::request::Body as RequestBody;
@@ -35,6 +36,7 @@ use self::response::Body as ResponseBody;
 /// 3. Send the change-pubkey transaction for the contract.
 /// 4. Set the received contract account ID.
 /// 5. Write the contract and its storage to the persistent database.
+/// 6. Write the initial contract storage snapshot with the initialization transaction hashes.
 ///
 pub async fn handle(
     app_data: web::Data<Arc<RwLock<SharedData>>>,
@@ -44,10 +46,10 @@ pub async fn handle(
     let query = query.into_inner();
     let body = body.into_inner();
 
-    let postgresql = app_data
+    let database = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
-        .postgresql
+        .database
         .clone();
 
     log::debug!(
@@ -98,6 +100,7 @@ pub async fn handle(
         _ => panic!(zinc_const::panic::VALUE_ALWAYS_EXISTS),
     };
 
+    let mut transactions = Vec::with_capacity(2);
     let tx_info = wallet
         .provider
         .send_tx(
@@ -106,6 +109,8 @@ pub async fn handle(
         )
         .await
         .map(|tx_hash| {
+            transactions.push(tx_hash.to_string());
+
             let mut handle = SyncTransactionHandle::new(tx_hash, wallet.provider.clone())
                 .commit_timeout(Duration::from_secs(10));
             handle
@@ -141,6 +146,7 @@ pub async fn handle(
     handle
         .polling_interval(Duration::from_millis(200))
         .expect("Validated inside the method");
+    transactions.push(handle.hash().to_string());
     let tx_info = handle.wait_for_commit().await?;
     if !tx_info.success.unwrap_or_default() {
         return Err(Error::ChangePubkey(
@@ -163,8 +169,8 @@ pub async fn handle(
         })?
         .set_account_id(account_id);
 
-    log::debug!("Writing the contract to the persistent PostgreSQL database");
-    postgresql
+    log::debug!("Writing the contract to the persistent database");
+    database
         .insert_contract(ContractInsertNewInput::new(
             account_id,
             contract.name,
@@ -179,9 +185,20 @@ pub async fn handle(
         ))
         .await?;
 
-    log::debug!("Writing the contract storage to the persistent PostgreSQL database");
-    postgresql
-        .insert_fields(contract.storage.into_database_insert(account_id))
+    log::debug!("Writing the contract storage to the persistent database");
+    database
+        .insert_fields(contract.storage.clone().into_database_insert(account_id))
+        .await?;
+
+    log::debug!("Writing the initial contract storage snapshot to the persistent database");
+    let balances = Storage::load_balances(&wallet).await?;
+    database
+        .insert_snapshot(contract.storage.into_database_snapshot(
+            account_id,
+            0,
+            balances,
+            transactions,
+        ))
         .await?;
 
     let response = ResponseBody::new(account_id);
@@ -4,7 +4,9 @@
 
 pub mod call;
 pub mod curve;
+pub mod events;
 pub mod fee;
 pub mod initialize;
 pub mod post;
 pub mod query;
+pub mod storage;
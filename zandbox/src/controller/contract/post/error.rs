@@ -26,7 +26,7 @@ pub enum Error {
 
     /// The virtual machine constructor runtime error.
     RuntimeError(RuntimeError),
-    /// The PostgreSQL database error.
+    /// The database error.
     Database(sqlx::Error),
 }
 
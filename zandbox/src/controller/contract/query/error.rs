@@ -27,10 +27,12 @@ pub enum Error {
     MethodArgumentsNotFound(String),
     /// Invalid contract method arguments.
     InvalidInput(BuildValueError),
+    /// The contract storage snapshot with the specified version does not exist.
+    SnapshotNotFound(u32),
 
     /// The virtual machine contract method runtime error.
     RuntimeError(RuntimeError),
-    /// The PostgreSQL database error.
+    /// The database error.
     Database(sqlx::Error),
     /// The ZkSync server client error.
     ZkSyncClient(zksync::error::ClientError),
@@ -57,6 +59,7 @@ impl ResponseError for Error {
             Self::MethodIsMutable(..) => StatusCode::BAD_REQUEST,
             Self::MethodArgumentsNotFound(..) => StatusCode::BAD_REQUEST,
             Self::InvalidInput(..) => StatusCode::BAD_REQUEST,
+            Self::SnapshotNotFound(..) => StatusCode::NOT_FOUND,
 
             Self::RuntimeError(..) => StatusCode::UNPROCESSABLE_ENTITY,
             Self::Database(..) => StatusCode::SERVICE_UNAVAILABLE,
@@ -89,6 +92,9 @@ impl fmt::Display for Error {
                 format!("Method `{}` input arguments missing in the request", name)
             }
             Self::InvalidInput(inner) => format!("Input: {}", inner),
+            Self::SnapshotNotFound(version) => {
+                format!("Contract storage version {} not found", version)
+            }
 
             Self::RuntimeError(inner) => format!("Runtime: {:?}", inner),
             Self::Database(inner) => format!("Database: {:?}", inner),
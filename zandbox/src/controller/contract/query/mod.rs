@@ -21,6 +21,7 @@ use zinc_vm::ContractInput;
 use zinc_zksync::TransactionMsg;
 
 use crate::database::model::field::select::Input as FieldSelectInput;
+use crate::database::model::snapshot::select::Input as SnapshotSelectInput;
 use crate::response::Response;
 use crate::shared_data::SharedData;
 use crate::storage::Storage;
@@ -34,7 +35,9 @@ use self::request::Query as RequestQuery;
 ///
 /// Sequence:
 /// 1. Get the contract from the in-memory cache.
-/// 2. Get the contract storage from data sources and convert it to the Zinc VM representation.
+/// 2. Get the latest or the specified version contract storage from data sources and convert
+/// it to the Zinc VM representation. The specified version storage is taken from its snapshot
+/// along with the balances, so the historical queries do not mix the storage states.
 /// 3. If the method was not specified, return the contract storage to the client.
 /// 4. Extract the called method from the contract metadata and check if it is immutable.
 /// 5. Parse the method input arguments.
@@ -49,10 +52,10 @@ pub async fn handle(
     let query = query.into_inner();
     let body = body.into_inner();
 
-    let postgresql = app_data
+    let database = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
-        .postgresql
+        .database
         .clone();
 
     let contract = app_data
@@ -72,27 +75,44 @@ pub async fn handle(
         )
     })?;
 
-    log::debug!("Initializing the contract wallet");
-    let provider = zksync::Provider::new(query.network);
-    let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
-        query.address,
-        PrivateKeySigner::new(contract.eth_private_key),
-        query.network,
-    )
-    .await?;
-    let wallet = zksync::Wallet::new(provider, wallet_credentials).await?;
-
     log::debug!("Loading the contract storage");
-    let database_fields = postgresql
-        .select_fields(FieldSelectInput::new(account_id))
-        .await?;
-    let storage = Storage::new_with_data(
-        database_fields,
-        contract.build.storage.as_slice(),
-        contract.eth_address,
-        &wallet,
-    )
-    .await?;
+    let storage = match query.version {
+        Some(version) => {
+            log::debug!("Using the contract storage version {}", version);
+            let snapshot = database
+                .select_snapshot(SnapshotSelectInput::new(account_id, Some(version)))
+                .await?
+                .ok_or(Error::SnapshotNotFound(version))?;
+            Storage::new_with_balances(
+                snapshot.fields,
+                snapshot.balances,
+                contract.build.storage.as_slice(),
+                contract.eth_address,
+            )
+        }
+        None => {
+            log::debug!("Initializing the contract wallet");
+            let provider = zksync::Provider::new(query.network);
+            let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
+                query.address,
+                PrivateKeySigner::new(contract.eth_private_key),
+                query.network,
+            )
+            .await?;
+            let wallet = zksync::Wallet::new(provider, wallet_credentials).await?;
+
+            let database_fields = database
+                .select_fields(FieldSelectInput::new(account_id))
+                .await?;
+            Storage::new_with_data(
+                database_fields,
+                contract.build.storage.as_slice(),
+                contract.eth_address,
+                &wallet,
+            )
+            .await?
+        }
+    };
 
     let method_name = match query.method {
         Some(method_name) => {
@@ -0,0 +1,69 @@
+//!
+//! The contract resource GET method `storage/diff` module.
+//!
+
+pub mod request;
+pub mod response;
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+use serde_json::Value as JsonValue;
+
+use crate::response::Response;
+use crate::shared_data::SharedData;
+
+use super::error::Error;
+
+use self::request::Query as RequestQuery;
+use self::response::Body as ResponseBody;
+use self::response::Change as ResponseChange;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the contract from the in-memory cache.
+/// 2. Get the older and the newer storage snapshots from the database.
+/// 3. Send the public fields, which values differ between the snapshots, back to the client.
+///
+pub async fn handle(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    query: web::Query<RequestQuery>,
+) -> crate::Result<ResponseBody, Error> {
+    let query = query.into_inner();
+
+    log::debug!(
+        "Comparing the storage versions {} and {} of the contract {}",
+        query.from,
+        query
+            .to
+            .map(|version| version.to_string())
+            .unwrap_or_else(|| "latest".to_owned()),
+        serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
+    );
+
+    let (from, before) = super::select(app_data.clone(), query.address, Some(query.from)).await?;
+    let (to, after) = super::select(app_data, query.address, query.to).await?;
+
+    let changes = after
+        .into_iter()
+        .filter_map(|(name, after)| {
+            let before = before
+                .get(name.as_str())
+                .cloned()
+                .unwrap_or(JsonValue::Null);
+            if before == after {
+                None
+            } else {
+                Some(ResponseChange::new(name, before, after))
+            }
+        })
+        .collect();
+
+    let response = ResponseBody::new(from.version, to.version, changes);
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
@@ -0,0 +1,20 @@
+//!
+//! The contract resource GET `storage/diff` request.
+//!
+
+use serde::Deserialize;
+
+use zksync::web3::types::Address;
+
+///
+/// The contract resource GET `storage/diff` request query.
+///
+#[derive(Debug, Deserialize)]
+pub struct Query {
+    /// The contract ETH address.
+    pub address: Address,
+    /// The older storage snapshot version.
+    pub from: u32,
+    /// The newer storage snapshot version. If not specified, the latest snapshot is used.
+    pub to: Option<u32>,
+}
@@ -0,0 +1,54 @@
+//!
+//! The contract resource GET `storage/diff` response.
+//!
+
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+///
+/// The contract resource GET `storage/diff` response body.
+///
+#[derive(Debug, Serialize)]
+pub struct Body {
+    /// The older storage snapshot version.
+    pub from: i64,
+    /// The newer storage snapshot version.
+    pub to: i64,
+    /// The public contract storage fields, which values differ between the snapshots.
+    pub changes: Vec<Change>,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(from: i64, to: i64, changes: Vec<Change>) -> Self {
+        Self { from, to, changes }
+    }
+}
+
+///
+/// The contract storage field change.
+///
+#[derive(Debug, Serialize)]
+pub struct Change {
+    /// The field name.
+    pub name: String,
+    /// The field value in the older snapshot.
+    pub before: JsonValue,
+    /// The field value in the newer snapshot.
+    pub after: JsonValue,
+}
+
+impl Change {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, before: JsonValue, after: JsonValue) -> Self {
+        Self {
+            name,
+            before,
+            after,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//!
+//! The contract resource GET `storage` error.
+//!
+
+use std::fmt;
+
+use actix_web::http::StatusCode;
+use actix_web::ResponseError;
+
+///
+/// The contract resource GET `storage` error.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The contract with the specified address is not found in the server cache.
+    ContractNotFound(String),
+    /// The contract with the specified address is locked.
+    ContractLocked(String),
+    /// The contract storage snapshot with the specified version does not exist.
+    SnapshotNotFound(u32),
+    /// The contract with the specified address has no storage snapshots at all.
+    NoSnapshots(String),
+
+    /// The database error.
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for Error {
+    fn from(inner: sqlx::Error) -> Self {
+        Self::Database(inner)
+    }
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::ContractNotFound(..) => StatusCode::NOT_FOUND,
+            Self::ContractLocked(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::SnapshotNotFound(..) => StatusCode::NOT_FOUND,
+            Self::NoSnapshots(..) => StatusCode::NOT_FOUND,
+
+            Self::Database(..) => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_str())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error = match self {
+            Self::ContractNotFound(address) => {
+                format!("Contract with address {} not found", address)
+            }
+            Self::ContractLocked(address) => format!("Contract with address {} is locked", address),
+            Self::SnapshotNotFound(version) => {
+                format!("Contract storage version {} not found", version)
+            }
+            Self::NoSnapshots(address) => {
+                format!("Contract with address {} has no storage snapshots", address)
+            }
+
+            Self::Database(inner) => format!("Database: {:?}", inner),
+        };
+
+        log::warn!("{}", error);
+        write!(f, "{}", error)
+    }
+}
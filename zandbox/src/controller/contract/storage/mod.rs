@@ -0,0 +1,115 @@
+//!
+//! The contract resource GET method `storage` module.
+//!
+
+pub mod diff;
+pub mod error;
+pub mod request;
+pub mod response;
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+use serde_json::Map as JsonMap;
+use serde_json::Value as JsonValue;
+
+use zksync::web3::types::Address;
+
+use crate::database::model::snapshot::select::Input as SnapshotSelectInput;
+use crate::database::model::snapshot::select::Output as SnapshotSelectOutput;
+use crate::response::Response;
+use crate::shared_data::SharedData;
+
+use self::error::Error;
+use self::request::Query as RequestQuery;
+use self::response::Body as ResponseBody;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the contract from the in-memory cache.
+/// 2. Get the storage snapshot of the specified or the latest version from the database.
+/// 3. Send the public snapshot fields and the transaction hashes back to the client.
+///
+pub async fn handle(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    query: web::Query<RequestQuery>,
+) -> crate::Result<ResponseBody, Error> {
+    let query = query.into_inner();
+
+    log::debug!(
+        "Getting the storage version {} of the contract {}",
+        query
+            .version
+            .map(|version| version.to_string())
+            .unwrap_or_else(|| "latest".to_owned()),
+        serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
+    );
+
+    let (snapshot, storage) = select(app_data, query.address, query.version).await?;
+
+    let response = ResponseBody::new(
+        snapshot.version,
+        snapshot.transactions,
+        JsonValue::Object(storage),
+    );
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
+
+///
+/// Selects the contract storage snapshot along with its public fields.
+///
+/// The implicit fields are not included into the snapshots, since they are not stored by
+/// Zandbox, and the private fields are filtered out like in the `query` method.
+///
+async fn select(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    address: Address,
+    version: Option<u32>,
+) -> Result<(SnapshotSelectOutput, JsonMap<String, JsonValue>), Error> {
+    let database = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .database
+        .clone();
+
+    let contract = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .contracts
+        .get(&address)
+        .cloned()
+        .ok_or_else(|| {
+            Error::ContractNotFound(
+                serde_json::to_string(&address).expect(zinc_const::panic::DATA_CONVERSION),
+            )
+        })?;
+    let account_id = contract.account_id.ok_or_else(|| {
+        Error::ContractLocked(
+            serde_json::to_string(&address).expect(zinc_const::panic::DATA_CONVERSION),
+        )
+    })?;
+
+    let snapshot = database
+        .select_snapshot(SnapshotSelectInput::new(account_id, version))
+        .await?
+        .ok_or_else(|| match version {
+            Some(version) => Error::SnapshotNotFound(version),
+            None => Error::NoSnapshots(
+                serde_json::to_string(&address).expect(zinc_const::panic::DATA_CONVERSION),
+            ),
+        })?;
+
+    let mut storage = JsonMap::with_capacity(snapshot.fields.len());
+    for (index, field) in snapshot.fields.iter().enumerate() {
+        if contract.build.storage[index + zinc_const::contract::IMPLICIT_FIELDS_COUNT].is_public {
+            storage.insert(field.name.to_owned(), field.value.to_owned());
+        }
+    }
+
+    Ok((snapshot, storage))
+}
@@ -0,0 +1,18 @@
+//!
+//! The contract resource GET `storage` request.
+//!
+
+use serde::Deserialize;
+
+use zksync::web3::types::Address;
+
+///
+/// The contract resource GET `storage` request query.
+///
+#[derive(Debug, Deserialize)]
+pub struct Query {
+    /// The contract ETH address.
+    pub address: Address,
+    /// The storage snapshot version. If not specified, the latest snapshot is returned.
+    pub version: Option<u32>,
+}
@@ -0,0 +1,32 @@
+//!
+//! The contract resource GET `storage` response.
+//!
+
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+///
+/// The contract resource GET `storage` response body.
+///
+#[derive(Debug, Serialize)]
+pub struct Body {
+    /// The storage snapshot version.
+    pub version: i64,
+    /// The hashes of the zkSync transactions, which have produced the snapshot.
+    pub transactions: Vec<String>,
+    /// The public contract storage fields, except the implicit ones.
+    pub storage: JsonValue,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(version: i64, transactions: Vec<String>, storage: JsonValue) -> Self {
+        Self {
+            version,
+            transactions,
+            storage,
+        }
+    }
+}
@@ -40,6 +40,25 @@ pub fn configure(config: &mut web::ServiceConfig) {
                             .route(web::head().to(head::handle))
                             .route(web::post().to(contract::call::handle)),
                     )
+                    .service(
+                        web::resource("/storage")
+                            .route(web::head().to(head::handle))
+                            .route(web::get().to(contract::storage::handle)),
+                    )
+                    .service(
+                        web::resource("/storage/diff")
+                            .route(web::head().to(head::handle))
+                            .route(web::get().to(contract::storage::diff::handle)),
+                    )
+                    .service(
+                        web::resource("/events")
+                            .route(web::head().to(head::handle))
+                            .route(web::get().to(contract::events::handle)),
+                    )
+                    .service(
+                        web::resource("/events/subscribe")
+                            .route(web::get().to(contract::events::subscribe::handle)),
+                    )
                     .service(
                         web::resource("/curve")
                             .route(web::head().to(head::handle))
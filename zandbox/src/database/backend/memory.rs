@@ -0,0 +1,447 @@
+//!
+//! The in-memory database storage backend.
+//!
+
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+use std::time::Instant;
+
+use async_trait::async_trait;
+
+use crate::database::model::contract::insert_new::Input as ContractInsertNewInput;
+use crate::database::model::contract::select_all::Output as ContractSelectAllOutput;
+use crate::database::model::contract::select_curve::Output as ContractSelectCurveOutput;
+use crate::database::model::event::insert::Input as EventInsertInput;
+use crate::database::model::event::select::Input as EventSelectInput;
+use crate::database::model::event::select::Output as EventSelectOutput;
+use crate::database::model::field::insert::Input as FieldInsertInput;
+use crate::database::model::field::select::Input as FieldSelectInput;
+use crate::database::model::field::select::Output as FieldSelectOutput;
+use crate::database::model::field::update::Input as FieldUpdateInput;
+use crate::database::model::snapshot::insert::Input as SnapshotInsertInput;
+use crate::database::model::snapshot::reserve::Input as SnapshotReserveInput;
+use crate::database::model::snapshot::select::Input as SnapshotSelectInput;
+use crate::database::model::snapshot::select::Output as SnapshotSelectOutput;
+
+use super::Backend;
+
+///
+/// The in-memory database client.
+///
+/// The data is lost when the client is dropped, so it is only useful for the hermetic tests and
+/// local experiments. The uniqueness constraints of the persistent backends are checked as well.
+///
+#[derive(Default)]
+pub struct Client {
+    /// The contracts in the order of insertion.
+    contracts: RwLock<Vec<ContractSelectAllOutput>>,
+    /// The contract storage fields by the contract account ID and the field index.
+    fields: RwLock<BTreeMap<(i64, i16), FieldSelectOutput>>,
+    /// The contract storage snapshots by the contract account ID and the snapshot version.
+    snapshots: RwLock<BTreeMap<(i64, i64), SnapshotSelectOutput>>,
+    /// The reservation times by the contract account ID and the reserved snapshot version.
+    pending_snapshots: RwLock<BTreeMap<(i64, i64), Instant>>,
+    /// The contract events by their IDs, along with the contract account IDs.
+    events: RwLock<BTreeMap<i64, (i64, EventSelectOutput)>>,
+}
+
+impl Client {
+    ///
+    /// Initializes an empty client instance.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl Backend for Client {
+    async fn select_contracts(&self) -> Result<Vec<ContractSelectAllOutput>, sqlx::Error> {
+        Ok(self
+            .contracts
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .clone())
+    }
+
+    async fn select_contracts_curve(&self) -> Result<Vec<ContractSelectCurveOutput>, sqlx::Error> {
+        Ok(self
+            .contracts
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .iter()
+            .filter(|contract| contract.name == "curve")
+            .map(|contract| {
+                ContractSelectCurveOutput::new(
+                    contract.eth_address.clone(),
+                    contract.name.clone(),
+                    contract.version.clone(),
+                    contract.instance.clone(),
+                )
+            })
+            .collect())
+    }
+
+    async fn insert_contract(&self, input: ContractInsertNewInput) -> Result<(), sqlx::Error> {
+        let mut contracts = self
+            .contracts
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+
+        let account_id = input.account_id as i64;
+        let eth_address = <[u8; zinc_const::size::ETH_ADDRESS]>::from(input.eth_address).to_vec();
+        if contracts.iter().any(|contract| {
+            contract.account_id == account_id
+                || contract.eth_address == eth_address
+                || (contract.name == input.name
+                    && contract.version == input.version
+                    && contract.instance == input.instance)
+        }) {
+            return Err(sqlx::Error::Protocol(format!(
+                "contract `{} v{}` instance `{}` with account ID {} already exists",
+                input.name, input.version, input.instance, account_id,
+            )));
+        }
+
+        contracts.push(ContractSelectAllOutput {
+            account_id,
+
+            name: input.name,
+            version: input.version,
+            instance: input.instance,
+
+            source_code: input.source_code,
+            bytecode: input.bytecode,
+            verifying_key: input.verifying_key,
+
+            eth_address,
+            eth_private_key: <[u8; zinc_const::size::ETH_PRIVATE_KEY]>::from(input.eth_private_key)
+                .to_vec(),
+        });
+
+        Ok(())
+    }
+
+    async fn delete_contracts(&self) -> Result<(), sqlx::Error> {
+        self.contracts
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .clear();
+
+        Ok(())
+    }
+
+    async fn select_fields(
+        &self,
+        input: FieldSelectInput,
+    ) -> Result<Vec<FieldSelectOutput>, sqlx::Error> {
+        let account_id = input.account_id as i64;
+
+        Ok(self
+            .fields
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .range((account_id, i16::MIN)..=(account_id, i16::MAX))
+            .map(|(_key, field)| field.to_owned())
+            .collect())
+    }
+
+    async fn insert_fields(&self, input: Vec<FieldInsertInput>) -> Result<(), sqlx::Error> {
+        let contracts = self
+            .contracts
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let mut fields = self
+            .fields
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+
+        for field in input.into_iter() {
+            let key = (field.account_id as i64, field.index);
+
+            if !contracts
+                .iter()
+                .any(|contract| contract.account_id == key.0)
+            {
+                return Err(sqlx::Error::Protocol(format!(
+                    "contract with account ID {} does not exist",
+                    key.0
+                )));
+            }
+            if fields.contains_key(&key) {
+                return Err(sqlx::Error::Protocol(format!(
+                    "field {} of the contract with account ID {} already exists",
+                    key.1, key.0
+                )));
+            }
+
+            fields.insert(
+                key,
+                FieldSelectOutput {
+                    name: field.name,
+                    value: field.value,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn update_fields(&self, input: Vec<FieldUpdateInput>) -> Result<(), sqlx::Error> {
+        let mut fields = self
+            .fields
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+
+        for field in input.into_iter() {
+            if let Some(stored) = fields.get_mut(&(field.account_id as i64, field.index)) {
+                stored.value = field.value;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn delete_fields(&self) -> Result<(), sqlx::Error> {
+        self.fields
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .clear();
+
+        Ok(())
+    }
+
+    async fn select_snapshot(
+        &self,
+        input: SnapshotSelectInput,
+    ) -> Result<Option<SnapshotSelectOutput>, sqlx::Error> {
+        let account_id = input.account_id as i64;
+        let snapshots = self
+            .snapshots
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+
+        Ok(match input.version {
+            Some(version) => snapshots.get(&(account_id, version as i64)).cloned(),
+            None => snapshots
+                .range((account_id, i64::MIN)..=(account_id, i64::MAX))
+                .next_back()
+                .map(|(_key, snapshot)| snapshot.to_owned()),
+        })
+    }
+
+    async fn insert_snapshot(&self, input: SnapshotInsertInput) -> Result<(), sqlx::Error> {
+        let contracts = self
+            .contracts
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let mut snapshots = self
+            .snapshots
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let pending_snapshots = self
+            .pending_snapshots
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+
+        let key = (input.account_id as i64, input.version as i64);
+        if !contracts
+            .iter()
+            .any(|contract| contract.account_id == key.0)
+        {
+            return Err(sqlx::Error::Protocol(format!(
+                "contract with account ID {} does not exist",
+                key.0
+            )));
+        }
+        if snapshots.contains_key(&key) || pending_snapshots.contains_key(&key) {
+            return Err(sqlx::Error::Protocol(format!(
+                "snapshot {} of the contract with account ID {} already exists",
+                key.1, key.0
+            )));
+        }
+
+        snapshots.insert(
+            key,
+            SnapshotSelectOutput {
+                version: key.1,
+                fields: input.fields,
+                balances: input.balances,
+                transactions: input.transactions,
+            },
+        );
+
+        Ok(())
+    }
+
+    async fn reserve_snapshot(&self, input: SnapshotReserveInput) -> Result<bool, sqlx::Error> {
+        let contracts = self
+            .contracts
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let snapshots = self
+            .snapshots
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let mut pending_snapshots = self
+            .pending_snapshots
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+
+        let key = (input.account_id as i64, input.version as i64);
+        if !contracts
+            .iter()
+            .any(|contract| contract.account_id == key.0)
+        {
+            return Err(sqlx::Error::Protocol(format!(
+                "contract with account ID {} does not exist",
+                key.0
+            )));
+        }
+
+        let abandoned: Vec<(i64, i64)> = pending_snapshots
+            .range((key.0, i64::MIN)..=(key.0, i64::MAX))
+            .filter(|(_key, reserved_at)| reserved_at.elapsed() >= input.lifetime)
+            .map(|(abandoned, _reserved_at)| *abandoned)
+            .collect();
+        for abandoned in abandoned.iter() {
+            pending_snapshots.remove(abandoned);
+        }
+        if snapshots.contains_key(&key) || pending_snapshots.contains_key(&key) {
+            return Ok(false);
+        }
+
+        pending_snapshots.insert(key, Instant::now());
+        Ok(true)
+    }
+
+    async fn commit_snapshot(
+        &self,
+        snapshot: SnapshotInsertInput,
+        fields: Vec<FieldUpdateInput>,
+    ) -> Result<(), sqlx::Error> {
+        let mut snapshots = self
+            .snapshots
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let mut pending_snapshots = self
+            .pending_snapshots
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let mut stored_fields = self
+            .fields
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+
+        let key = (snapshot.account_id as i64, snapshot.version as i64);
+        if pending_snapshots.remove(&key).is_none() {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        snapshots.insert(
+            key,
+            SnapshotSelectOutput {
+                version: key.1,
+                fields: snapshot.fields,
+                balances: snapshot.balances,
+                transactions: snapshot.transactions,
+            },
+        );
+        for field in fields.into_iter() {
+            if let Some(stored) = stored_fields.get_mut(&(field.account_id as i64, field.index)) {
+                stored.value = field.value;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn release_snapshot(&self, input: SnapshotReserveInput) -> Result<(), sqlx::Error> {
+        self.pending_snapshots
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .remove(&(input.account_id as i64, input.version as i64));
+
+        Ok(())
+    }
+
+    async fn delete_snapshots(&self) -> Result<(), sqlx::Error> {
+        self.snapshots
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .clear();
+        self.pending_snapshots
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .clear();
+
+        Ok(())
+    }
+
+    async fn select_events(
+        &self,
+        input: EventSelectInput,
+    ) -> Result<Vec<EventSelectOutput>, sqlx::Error> {
+        let account_id = input.account_id as i64;
+        let after = input.after.unwrap_or_default();
+
+        Ok(self
+            .events
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .range(after.saturating_add(1)..)
+            .filter(|(_id, (event_account_id, _event))| *event_account_id == account_id)
+            .take(input.limit as usize)
+            .map(|(_id, (_account_id, event))| event.to_owned())
+            .collect())
+    }
+
+    async fn insert_events(
+        &self,
+        input: Vec<EventInsertInput>,
+    ) -> Result<Vec<EventSelectOutput>, sqlx::Error> {
+        let contracts = self
+            .contracts
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let mut events = self
+            .events
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+
+        let mut output = Vec::with_capacity(input.len());
+        for event in input.into_iter() {
+            let account_id = event.account_id as i64;
+            if !contracts
+                .iter()
+                .any(|contract| contract.account_id == account_id)
+            {
+                return Err(sqlx::Error::Protocol(format!(
+                    "contract with account ID {} does not exist",
+                    account_id
+                )));
+            }
+
+            let id = events.keys().next_back().copied().unwrap_or_default() + 1;
+            let event = EventSelectOutput {
+                id,
+                version: event.version as i64,
+                name: event.name,
+                value: event.value,
+            };
+            events.insert(id, (account_id, event.clone()));
+            output.push(event);
+        }
+
+        Ok(output)
+    }
+
+    async fn delete_events(&self) -> Result<(), sqlx::Error> {
+        self.events
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .clear();
+
+        Ok(())
+    }
+}
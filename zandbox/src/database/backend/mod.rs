@@ -0,0 +1,147 @@
+//!
+//! The database storage backend.
+//!
+
+pub mod memory;
+pub mod postgresql;
+pub mod sqlite;
+
+use async_trait::async_trait;
+
+use crate::database::model::contract::insert_new::Input as ContractInsertNewInput;
+use crate::database::model::contract::select_all::Output as ContractSelectAllOutput;
+use crate::database::model::contract::select_curve::Output as ContractSelectCurveOutput;
+use crate::database::model::event::insert::Input as EventInsertInput;
+use crate::database::model::event::select::Input as EventSelectInput;
+use crate::database::model::event::select::Output as EventSelectOutput;
+use crate::database::model::field::insert::Input as FieldInsertInput;
+use crate::database::model::field::select::Input as FieldSelectInput;
+use crate::database::model::field::select::Output as FieldSelectOutput;
+use crate::database::model::field::update::Input as FieldUpdateInput;
+use crate::database::model::snapshot::insert::Input as SnapshotInsertInput;
+use crate::database::model::snapshot::reserve::Input as SnapshotReserveInput;
+use crate::database::model::snapshot::select::Input as SnapshotSelectInput;
+use crate::database::model::snapshot::select::Output as SnapshotSelectOutput;
+
+///
+/// The database storage backend, which persists the published contracts and their storage.
+///
+/// The contracts are returned in the order of their publishing, and the storage fields are
+/// returned in the order of their indexes.
+///
+/// The storage snapshots are append-only, so the contract storage history is never overwritten,
+/// unlike the latest storage fields state. A snapshot version is reserved by a pending snapshot
+/// before the call transactions are sent, and the pending snapshots are never selected.
+///
+/// The events are append-only as well, and are returned in the order of their emitting.
+///
+#[async_trait]
+pub trait Backend: Send + Sync {
+    ///
+    /// Selects all the contracts.
+    ///
+    async fn select_contracts(&self) -> Result<Vec<ContractSelectAllOutput>, sqlx::Error>;
+
+    ///
+    /// Selects the Curve contracts.
+    ///
+    async fn select_contracts_curve(&self) -> Result<Vec<ContractSelectCurveOutput>, sqlx::Error>;
+
+    ///
+    /// Inserts a contract instance.
+    ///
+    async fn insert_contract(&self, input: ContractInsertNewInput) -> Result<(), sqlx::Error>;
+
+    ///
+    /// Deletes all the contracts.
+    ///
+    async fn delete_contracts(&self) -> Result<(), sqlx::Error>;
+
+    ///
+    /// Selects the contract storage fields.
+    ///
+    async fn select_fields(
+        &self,
+        input: FieldSelectInput,
+    ) -> Result<Vec<FieldSelectOutput>, sqlx::Error>;
+
+    ///
+    /// Inserts the contract storage fields.
+    ///
+    async fn insert_fields(&self, input: Vec<FieldInsertInput>) -> Result<(), sqlx::Error>;
+
+    ///
+    /// Updates the contract storage fields.
+    ///
+    async fn update_fields(&self, input: Vec<FieldUpdateInput>) -> Result<(), sqlx::Error>;
+
+    ///
+    /// Deletes all the contract storage fields.
+    ///
+    async fn delete_fields(&self) -> Result<(), sqlx::Error>;
+
+    ///
+    /// Selects the contract storage snapshot of the specified or the latest version.
+    ///
+    async fn select_snapshot(
+        &self,
+        input: SnapshotSelectInput,
+    ) -> Result<Option<SnapshotSelectOutput>, sqlx::Error>;
+
+    ///
+    /// Inserts a contract storage snapshot.
+    ///
+    async fn insert_snapshot(&self, input: SnapshotInsertInput) -> Result<(), sqlx::Error>;
+
+    ///
+    /// Reserves the contract storage snapshot version with a pending snapshot.
+    ///
+    /// The pending snapshots of the contract, which are older than the reservation lifetime, are
+    /// reclaimed beforehand, so a version is not blocked forever by a call which has never
+    /// committed or released it.
+    ///
+    /// Returns `false` if the version has been already taken by another call.
+    ///
+    async fn reserve_snapshot(&self, input: SnapshotReserveInput) -> Result<bool, sqlx::Error>;
+
+    ///
+    /// Writes the reserved contract storage snapshot along with the updated storage fields
+    /// in a single transaction.
+    ///
+    async fn commit_snapshot(
+        &self,
+        snapshot: SnapshotInsertInput,
+        fields: Vec<FieldUpdateInput>,
+    ) -> Result<(), sqlx::Error>;
+
+    ///
+    /// Releases the reserved contract storage snapshot version.
+    ///
+    async fn release_snapshot(&self, input: SnapshotReserveInput) -> Result<(), sqlx::Error>;
+
+    ///
+    /// Deletes all the contract storage snapshots.
+    ///
+    async fn delete_snapshots(&self) -> Result<(), sqlx::Error>;
+
+    ///
+    /// Selects a page of the contract events.
+    ///
+    async fn select_events(
+        &self,
+        input: EventSelectInput,
+    ) -> Result<Vec<EventSelectOutput>, sqlx::Error>;
+
+    ///
+    /// Inserts the contract events, returning them along with the assigned IDs.
+    ///
+    async fn insert_events(
+        &self,
+        input: Vec<EventInsertInput>,
+    ) -> Result<Vec<EventSelectOutput>, sqlx::Error>;
+
+    ///
+    /// Deletes all the contract events.
+    ///
+    async fn delete_events(&self) -> Result<(), sqlx::Error>;
+}
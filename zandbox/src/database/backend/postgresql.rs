@@ -0,0 +1,590 @@
+//!
+//! The PostgreSQL database storage backend.
+//!
+
+use async_trait::async_trait;
+use serde_json::Value as JsonValue;
+use sqlx::pool::Pool;
+use sqlx::postgres::PgPoolOptions;
+use sqlx::Postgres;
+use sqlx::Row;
+
+use crate::database::model::contract::insert_new::Input as ContractInsertNewInput;
+use crate::database::model::contract::select_all::Output as ContractSelectAllOutput;
+use crate::database::model::contract::select_curve::Output as ContractSelectCurveOutput;
+use crate::database::model::event::insert::Input as EventInsertInput;
+use crate::database::model::event::select::Input as EventSelectInput;
+use crate::database::model::event::select::Output as EventSelectOutput;
+use crate::database::model::field::insert::Input as FieldInsertInput;
+use crate::database::model::field::select::Input as FieldSelectInput;
+use crate::database::model::field::select::Output as FieldSelectOutput;
+use crate::database::model::field::update::Input as FieldUpdateInput;
+use crate::database::model::snapshot::insert::Input as SnapshotInsertInput;
+use crate::database::model::snapshot::reserve::Input as SnapshotReserveInput;
+use crate::database::model::snapshot::select::Input as SnapshotSelectInput;
+use crate::database::model::snapshot::select::Output as SnapshotSelectOutput;
+
+use super::Backend;
+
+///
+/// The PostgreSQL database asynchronous client.
+///
+pub struct Client {
+    /// The database connection pool.
+    pool: Pool<Postgres>,
+}
+
+impl Client {
+    ///
+    /// Initializes a client instance.
+    ///
+    pub async fn new(connection_uri: &str) -> Result<Self, sqlx::Error> {
+        let pool = PgPoolOptions::new()
+            .max_connections(8)
+            .connect(connection_uri)
+            .await?;
+
+        Ok(Self { pool })
+    }
+}
+
+#[async_trait]
+impl Backend for Client {
+    ///
+    /// Select the contracts from the `contracts` table.
+    ///
+    async fn select_contracts(&self) -> Result<Vec<ContractSelectAllOutput>, sqlx::Error> {
+        const STATEMENT: &str = r#"
+        SELECT
+            account_id,
+            
+            name,
+            version,
+            instance,
+
+            source_code,
+            bytecode,
+            verifying_key,
+
+            eth_address,
+            eth_private_key
+        FROM zandbox.contracts
+        ORDER BY created_at;
+        "#;
+
+        Ok(sqlx::query_as(STATEMENT).fetch_all(&self.pool).await?)
+    }
+
+    ///
+    /// Select the Curve contracts from the `contracts` table.
+    ///
+    async fn select_contracts_curve(&self) -> Result<Vec<ContractSelectCurveOutput>, sqlx::Error> {
+        const STATEMENT: &str = r#"
+        SELECT
+            eth_address,
+            
+            name,
+            version,
+            instance
+        FROM zandbox.contracts
+        WHERE
+            name = 'curve'
+        ORDER BY created_at;
+        "#;
+
+        Ok(sqlx::query_as(STATEMENT).fetch_all(&self.pool).await?)
+    }
+
+    ///
+    /// Inserts a contract instance into the `contracts` table.
+    ///
+    async fn insert_contract(&self, input: ContractInsertNewInput) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        INSERT INTO zandbox.contracts (
+            account_id,
+
+            name,
+            version,
+            instance,
+
+            zinc_version,
+            source_code,
+            bytecode,
+            verifying_key,
+
+            eth_address,
+            eth_private_key,
+
+            created_at
+        ) VALUES (
+            $1,
+            $2,
+            $3,
+            $4,
+            $5,
+            $6,
+            $7,
+            $8,
+            $9,
+            $10,
+            NOW()
+        );
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.name)
+            .bind(input.version)
+            .bind(input.instance)
+            .bind(input.zinc_version)
+            .bind(input.source_code)
+            .bind(input.bytecode)
+            .bind(input.verifying_key)
+            .bind(<[u8; zinc_const::size::ETH_ADDRESS]>::from(input.eth_address).to_vec())
+            .bind(<[u8; zinc_const::size::ETH_PRIVATE_KEY]>::from(input.eth_private_key).to_vec())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Deletes the `contracts` table contents.
+    ///
+    async fn delete_contracts(&self) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        DELETE FROM zandbox.contracts;
+        "#;
+
+        sqlx::query(STATEMENT).execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Selects contract storage fields from the `fields` table.
+    ///
+    async fn select_fields(
+        &self,
+        input: FieldSelectInput,
+    ) -> Result<Vec<FieldSelectOutput>, sqlx::Error> {
+        const STATEMENT: &str = r#"
+        SELECT
+            name,
+            value
+        FROM zandbox.fields
+        WHERE
+            account_id = $1
+        ORDER BY index;
+        "#;
+
+        Ok(sqlx::query_as(STATEMENT)
+            .bind(input.account_id)
+            .fetch_all(&self.pool)
+            .await?)
+    }
+
+    ///
+    /// Inserts contract storage fields into the `fields` table.
+    ///
+    async fn insert_fields(&self, input: Vec<FieldInsertInput>) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        INSERT INTO zandbox.fields (
+            account_id,
+            index,
+
+            name,
+            value
+        ) VALUES (
+            $1,
+            $2,
+            $3,
+            $4
+        );
+        "#;
+
+        for field in input.into_iter() {
+            sqlx::query(STATEMENT)
+                .bind(field.account_id)
+                .bind(field.index)
+                .bind(field.name)
+                .bind(field.value)
+                .execute(&self.pool)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Updates contract storage fields in the `fields` table.
+    ///
+    async fn update_fields(&self, input: Vec<FieldUpdateInput>) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        UPDATE zandbox.fields
+        SET
+            value = $3
+        WHERE
+            index = $2
+        AND account_id = $1;
+        "#;
+
+        for field in input.into_iter() {
+            sqlx::query(STATEMENT)
+                .bind(field.account_id)
+                .bind(field.index)
+                .bind(field.value)
+                .execute(&self.pool)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Deletes the `field` table contents.
+    ///
+    async fn delete_fields(&self) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        DELETE FROM zandbox.fields;
+        "#;
+
+        sqlx::query(STATEMENT).execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Selects a contract storage snapshot from the `snapshots` table.
+    ///
+    async fn select_snapshot(
+        &self,
+        input: SnapshotSelectInput,
+    ) -> Result<Option<SnapshotSelectOutput>, sqlx::Error> {
+        const STATEMENT: &str = r#"
+        SELECT
+            version,
+            fields,
+            balances,
+            transactions
+        FROM zandbox.snapshots
+        WHERE
+            account_id = $1
+        AND ($2::BIGINT IS NULL OR version = $2)
+        AND NOT is_pending
+        ORDER BY version DESC
+        LIMIT 1;
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.version.map(|version| version as i64))
+            .fetch_optional(&self.pool)
+            .await?
+            .map(|row| {
+                let fields: JsonValue = row.try_get("fields")?;
+                let transactions: JsonValue = row.try_get("transactions")?;
+
+                Ok(SnapshotSelectOutput {
+                    version: row.try_get("version")?,
+                    fields: serde_json::from_value(fields)
+                        .map_err(|error| sqlx::Error::Decode(Box::new(error)))?,
+                    balances: row.try_get("balances")?,
+                    transactions: serde_json::from_value(transactions)
+                        .map_err(|error| sqlx::Error::Decode(Box::new(error)))?,
+                })
+            })
+            .transpose()
+    }
+
+    ///
+    /// Inserts a contract storage snapshot into the `snapshots` table.
+    ///
+    async fn insert_snapshot(&self, input: SnapshotInsertInput) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        INSERT INTO zandbox.snapshots (
+            account_id,
+            version,
+
+            fields,
+            balances,
+            transactions,
+
+            created_at
+        ) VALUES (
+            $1,
+            $2,
+            $3,
+            $4,
+            $5,
+            NOW()
+        );
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.version as i64)
+            .bind(serde_json::to_value(input.fields).expect(zinc_const::panic::DATA_CONVERSION))
+            .bind(input.balances)
+            .bind(
+                serde_json::to_value(input.transactions).expect(zinc_const::panic::DATA_CONVERSION),
+            )
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Reserves a contract storage snapshot version with a pending row in the `snapshots` table,
+    /// deleting the abandoned pending rows of the contract in the same transaction.
+    ///
+    async fn reserve_snapshot(&self, input: SnapshotReserveInput) -> Result<bool, sqlx::Error> {
+        const STATEMENT_RECLAIM: &str = r#"
+        DELETE FROM zandbox.snapshots
+        WHERE
+            account_id = $1
+        AND is_pending
+        AND created_at <= NOW() - MAKE_INTERVAL(secs => $2);
+        "#;
+
+        const STATEMENT_RESERVE: &str = r#"
+        INSERT INTO zandbox.snapshots (
+            account_id,
+            version,
+
+            fields,
+            balances,
+            transactions,
+            is_pending,
+
+            created_at
+        ) VALUES (
+            $1,
+            $2,
+            '[]',
+            '[]',
+            '[]',
+            TRUE,
+            NOW()
+        )
+        ON CONFLICT (account_id, version) DO NOTHING;
+        "#;
+
+        let mut transaction = self.pool.begin().await?;
+
+        sqlx::query(STATEMENT_RECLAIM)
+            .bind(input.account_id as i64)
+            .bind(input.lifetime.as_secs_f64())
+            .execute(&mut transaction)
+            .await?;
+
+        let done = sqlx::query(STATEMENT_RESERVE)
+            .bind(input.account_id as i64)
+            .bind(input.version as i64)
+            .execute(&mut transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        Ok(done.rows_affected() == 1)
+    }
+
+    ///
+    /// Writes the reserved contract storage snapshot into the `snapshots` table and updates the
+    /// contract storage fields in the `fields` table in a single transaction.
+    ///
+    async fn commit_snapshot(
+        &self,
+        snapshot: SnapshotInsertInput,
+        fields: Vec<FieldUpdateInput>,
+    ) -> Result<(), sqlx::Error> {
+        const STATEMENT_SNAPSHOT: &str = r#"
+        UPDATE zandbox.snapshots
+        SET
+            fields = $3,
+            balances = $4,
+            transactions = $5,
+            is_pending = FALSE,
+            created_at = NOW()
+        WHERE
+            account_id = $1
+        AND version = $2
+        AND is_pending;
+        "#;
+
+        const STATEMENT_FIELD: &str = r#"
+        UPDATE zandbox.fields
+        SET
+            value = $3
+        WHERE
+            index = $2
+        AND account_id = $1;
+        "#;
+
+        let mut transaction = self.pool.begin().await?;
+
+        let done = sqlx::query(STATEMENT_SNAPSHOT)
+            .bind(snapshot.account_id as i64)
+            .bind(snapshot.version as i64)
+            .bind(serde_json::to_value(snapshot.fields).expect(zinc_const::panic::DATA_CONVERSION))
+            .bind(snapshot.balances)
+            .bind(
+                serde_json::to_value(snapshot.transactions)
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+            )
+            .execute(&mut transaction)
+            .await?;
+        if done.rows_affected() != 1 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        for field in fields.into_iter() {
+            sqlx::query(STATEMENT_FIELD)
+                .bind(field.account_id)
+                .bind(field.index)
+                .bind(field.value)
+                .execute(&mut transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Deletes a pending contract storage snapshot from the `snapshots` table.
+    ///
+    async fn release_snapshot(&self, input: SnapshotReserveInput) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        DELETE FROM zandbox.snapshots
+        WHERE
+            account_id = $1
+        AND version = $2
+        AND is_pending;
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.version as i64)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Deletes the `snapshots` table contents.
+    ///
+    async fn delete_snapshots(&self) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        DELETE FROM zandbox.snapshots;
+        "#;
+
+        sqlx::query(STATEMENT).execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Selects a page of the contract events from the `events` table.
+    ///
+    async fn select_events(
+        &self,
+        input: EventSelectInput,
+    ) -> Result<Vec<EventSelectOutput>, sqlx::Error> {
+        const STATEMENT: &str = r#"
+        SELECT
+            id,
+            version,
+            name,
+            value
+        FROM zandbox.events
+        WHERE
+            account_id = $1
+        AND ($2::BIGINT IS NULL OR id > $2)
+        ORDER BY id
+        LIMIT $3;
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.after)
+            .bind(input.limit as i64)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(|row| {
+                Ok(EventSelectOutput {
+                    id: row.try_get("id")?,
+                    version: row.try_get("version")?,
+                    name: row.try_get("name")?,
+                    value: row.try_get("value")?,
+                })
+            })
+            .collect()
+    }
+
+    ///
+    /// Inserts the contract events into the `events` table, returning them along with the
+    /// assigned IDs.
+    ///
+    async fn insert_events(
+        &self,
+        input: Vec<EventInsertInput>,
+    ) -> Result<Vec<EventSelectOutput>, sqlx::Error> {
+        const STATEMENT: &str = r#"
+        INSERT INTO zandbox.events (
+            account_id,
+            version,
+
+            name,
+            value,
+
+            created_at
+        ) VALUES (
+            $1,
+            $2,
+            $3,
+            $4,
+            NOW()
+        )
+        RETURNING id;
+        "#;
+
+        let mut output = Vec::with_capacity(input.len());
+        for event in input.into_iter() {
+            let id = sqlx::query(STATEMENT)
+                .bind(event.account_id as i64)
+                .bind(event.version as i64)
+                .bind(event.name.as_str())
+                .bind(&event.value)
+                .fetch_one(&self.pool)
+                .await?
+                .try_get("id")?;
+
+            output.push(EventSelectOutput {
+                id,
+                version: event.version as i64,
+                name: event.name,
+                value: event.value,
+            });
+        }
+
+        Ok(output)
+    }
+
+    ///
+    /// Deletes the `events` table contents.
+    ///
+    async fn delete_events(&self) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        DELETE FROM zandbox.events;
+        "#;
+
+        sqlx::query(STATEMENT).execute(&self.pool).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,721 @@
+//!
+//! The SQLite database storage backend.
+//!
+
+use async_trait::async_trait;
+use sqlx::pool::Pool;
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::sqlite::SqliteRow;
+use sqlx::Row;
+use sqlx::Sqlite;
+
+use crate::database::model::contract::insert_new::Input as ContractInsertNewInput;
+use crate::database::model::contract::select_all::Output as ContractSelectAllOutput;
+use crate::database::model::contract::select_curve::Output as ContractSelectCurveOutput;
+use crate::database::model::event::insert::Input as EventInsertInput;
+use crate::database::model::event::select::Input as EventSelectInput;
+use crate::database::model::event::select::Output as EventSelectOutput;
+use crate::database::model::field::insert::Input as FieldInsertInput;
+use crate::database::model::field::select::Input as FieldSelectInput;
+use crate::database::model::field::select::Output as FieldSelectOutput;
+use crate::database::model::field::update::Input as FieldUpdateInput;
+use crate::database::model::snapshot::insert::Input as SnapshotInsertInput;
+use crate::database::model::snapshot::reserve::Input as SnapshotReserveInput;
+use crate::database::model::snapshot::select::Input as SnapshotSelectInput;
+use crate::database::model::snapshot::select::Output as SnapshotSelectOutput;
+
+use super::Backend;
+
+///
+/// The SQLite database asynchronous client.
+///
+/// The tables are created on initialization, so a fresh database file or the `sqlite::memory:`
+/// database can be used without migrations. SQLite does not support JSON natively, so the JSON
+/// values are stored as text.
+///
+pub struct Client {
+    /// The database connection pool.
+    pool: Pool<Sqlite>,
+}
+
+impl Client {
+    ///
+    /// Initializes a client instance and creates the tables.
+    ///
+    /// The pool is limited to a single connection, since every `sqlite::memory:` connection
+    /// opens a separate database.
+    ///
+    pub async fn new(connection_uri: &str) -> Result<Self, sqlx::Error> {
+        const STATEMENT_CONTRACTS: &str = r#"
+        CREATE TABLE IF NOT EXISTS contracts (
+            account_id         INTEGER NOT NULL,
+
+            name               TEXT NOT NULL,
+            version            TEXT NOT NULL,
+            instance           TEXT NOT NULL,
+
+            zinc_version       TEXT NOT NULL,
+            source_code        TEXT NOT NULL,
+            bytecode           BLOB NOT NULL,
+            verifying_key      BLOB NOT NULL,
+
+            eth_address        BLOB NOT NULL,
+            eth_private_key    BLOB NOT NULL,
+
+            created_at         TEXT NOT NULL,
+
+            PRIMARY KEY        (account_id),
+
+            CONSTRAINT unq_eth_address
+                UNIQUE (eth_address),
+            CONSTRAINT unq_name_version_instance
+                UNIQUE (name, version, instance)
+        );
+        "#;
+
+        const STATEMENT_FIELDS: &str = r#"
+        CREATE TABLE IF NOT EXISTS fields (
+            account_id         INTEGER NOT NULL,
+            "index"            INTEGER NOT NULL,
+
+            name               TEXT NOT NULL,
+            value              TEXT NOT NULL,
+
+            PRIMARY KEY        (account_id, "index"),
+
+            CONSTRAINT fk_account_id
+                FOREIGN KEY (account_id)
+                    REFERENCES contracts(account_id)
+        );
+        "#;
+
+        const STATEMENT_SNAPSHOTS: &str = r#"
+        CREATE TABLE IF NOT EXISTS snapshots (
+            account_id         INTEGER NOT NULL,
+            version            INTEGER NOT NULL,
+
+            fields             TEXT NOT NULL,
+            balances           TEXT NOT NULL,
+            transactions       TEXT NOT NULL,
+            is_pending         BOOLEAN NOT NULL DEFAULT FALSE,
+
+            created_at         TEXT NOT NULL,
+
+            PRIMARY KEY        (account_id, version),
+
+            CONSTRAINT fk_account_id
+                FOREIGN KEY (account_id)
+                    REFERENCES contracts(account_id)
+        );
+        "#;
+
+        const STATEMENT_EVENTS: &str = r#"
+        CREATE TABLE IF NOT EXISTS events (
+            id                 INTEGER PRIMARY KEY AUTOINCREMENT,
+            account_id         INTEGER NOT NULL,
+            version            INTEGER NOT NULL,
+
+            name               TEXT NOT NULL,
+            value              TEXT NOT NULL,
+
+            created_at         TEXT NOT NULL,
+
+            CONSTRAINT fk_account_id
+                FOREIGN KEY (account_id)
+                    REFERENCES contracts(account_id)
+        );
+        "#;
+
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect(connection_uri)
+            .await?;
+
+        sqlx::query(STATEMENT_CONTRACTS).execute(&pool).await?;
+        sqlx::query(STATEMENT_FIELDS).execute(&pool).await?;
+        sqlx::query(STATEMENT_SNAPSHOTS).execute(&pool).await?;
+        sqlx::query(STATEMENT_EVENTS).execute(&pool).await?;
+
+        Ok(Self { pool })
+    }
+
+    ///
+    /// Decodes a JSON value stored as text.
+    ///
+    fn get_json<T>(row: &SqliteRow, column: &str) -> Result<T, sqlx::Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let value: String = row.try_get(column)?;
+        serde_json::from_str(value.as_str()).map_err(|error| sqlx::Error::Decode(Box::new(error)))
+    }
+}
+
+#[async_trait]
+impl Backend for Client {
+    ///
+    /// Select the contracts from the `contracts` table.
+    ///
+    async fn select_contracts(&self) -> Result<Vec<ContractSelectAllOutput>, sqlx::Error> {
+        const STATEMENT: &str = r#"
+        SELECT
+            account_id,
+
+            name,
+            version,
+            instance,
+
+            source_code,
+            bytecode,
+            verifying_key,
+
+            eth_address,
+            eth_private_key
+        FROM contracts
+        ORDER BY rowid;
+        "#;
+
+        sqlx::query(STATEMENT)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(|row| {
+                Ok(ContractSelectAllOutput {
+                    account_id: row.try_get("account_id")?,
+
+                    name: row.try_get("name")?,
+                    version: row.try_get("version")?,
+                    instance: row.try_get("instance")?,
+
+                    source_code: Self::get_json(&row, "source_code")?,
+                    bytecode: row.try_get("bytecode")?,
+                    verifying_key: row.try_get("verifying_key")?,
+
+                    eth_address: row.try_get("eth_address")?,
+                    eth_private_key: row.try_get("eth_private_key")?,
+                })
+            })
+            .collect()
+    }
+
+    ///
+    /// Select the Curve contracts from the `contracts` table.
+    ///
+    async fn select_contracts_curve(&self) -> Result<Vec<ContractSelectCurveOutput>, sqlx::Error> {
+        const STATEMENT: &str = r#"
+        SELECT
+            eth_address,
+
+            name,
+            version,
+            instance
+        FROM contracts
+        WHERE
+            name = 'curve'
+        ORDER BY rowid;
+        "#;
+
+        Ok(sqlx::query_as(STATEMENT).fetch_all(&self.pool).await?)
+    }
+
+    ///
+    /// Inserts a contract instance into the `contracts` table.
+    ///
+    async fn insert_contract(&self, input: ContractInsertNewInput) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        INSERT INTO contracts (
+            account_id,
+
+            name,
+            version,
+            instance,
+
+            zinc_version,
+            source_code,
+            bytecode,
+            verifying_key,
+
+            eth_address,
+            eth_private_key,
+
+            created_at
+        ) VALUES (
+            $1,
+            $2,
+            $3,
+            $4,
+            $5,
+            $6,
+            $7,
+            $8,
+            $9,
+            $10,
+            DATETIME('now')
+        );
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.name)
+            .bind(input.version)
+            .bind(input.instance)
+            .bind(input.zinc_version)
+            .bind(input.source_code.to_string())
+            .bind(input.bytecode)
+            .bind(input.verifying_key)
+            .bind(<[u8; zinc_const::size::ETH_ADDRESS]>::from(input.eth_address).to_vec())
+            .bind(<[u8; zinc_const::size::ETH_PRIVATE_KEY]>::from(input.eth_private_key).to_vec())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Deletes the `contracts` table contents.
+    ///
+    async fn delete_contracts(&self) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        DELETE FROM contracts;
+        "#;
+
+        sqlx::query(STATEMENT).execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Selects contract storage fields from the `fields` table.
+    ///
+    async fn select_fields(
+        &self,
+        input: FieldSelectInput,
+    ) -> Result<Vec<FieldSelectOutput>, sqlx::Error> {
+        const STATEMENT: &str = r#"
+        SELECT
+            name,
+            value
+        FROM fields
+        WHERE
+            account_id = $1
+        ORDER BY "index";
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(|row| {
+                Ok(FieldSelectOutput {
+                    name: row.try_get("name")?,
+                    value: Self::get_json(&row, "value")?,
+                })
+            })
+            .collect()
+    }
+
+    ///
+    /// Inserts contract storage fields into the `fields` table.
+    ///
+    async fn insert_fields(&self, input: Vec<FieldInsertInput>) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        INSERT INTO fields (
+            account_id,
+            "index",
+
+            name,
+            value
+        ) VALUES (
+            $1,
+            $2,
+            $3,
+            $4
+        );
+        "#;
+
+        for field in input.into_iter() {
+            sqlx::query(STATEMENT)
+                .bind(field.account_id as i64)
+                .bind(field.index as i64)
+                .bind(field.name)
+                .bind(field.value.to_string())
+                .execute(&self.pool)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Updates contract storage fields in the `fields` table.
+    ///
+    async fn update_fields(&self, input: Vec<FieldUpdateInput>) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        UPDATE fields
+        SET
+            value = $3
+        WHERE
+            "index" = $2
+        AND account_id = $1;
+        "#;
+
+        for field in input.into_iter() {
+            sqlx::query(STATEMENT)
+                .bind(field.account_id as i64)
+                .bind(field.index as i64)
+                .bind(field.value.to_string())
+                .execute(&self.pool)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Deletes the `field` table contents.
+    ///
+    async fn delete_fields(&self) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        DELETE FROM fields;
+        "#;
+
+        sqlx::query(STATEMENT).execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Selects a contract storage snapshot from the `snapshots` table.
+    ///
+    async fn select_snapshot(
+        &self,
+        input: SnapshotSelectInput,
+    ) -> Result<Option<SnapshotSelectOutput>, sqlx::Error> {
+        const STATEMENT: &str = r#"
+        SELECT
+            version,
+            fields,
+            balances,
+            transactions
+        FROM snapshots
+        WHERE
+            account_id = $1
+        AND ($2 IS NULL OR version = $2)
+        AND NOT is_pending
+        ORDER BY version DESC
+        LIMIT 1;
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.version.map(|version| version as i64))
+            .fetch_optional(&self.pool)
+            .await?
+            .map(|row| {
+                Ok(SnapshotSelectOutput {
+                    version: row.try_get("version")?,
+                    fields: Self::get_json(&row, "fields")?,
+                    balances: Self::get_json(&row, "balances")?,
+                    transactions: Self::get_json(&row, "transactions")?,
+                })
+            })
+            .transpose()
+    }
+
+    ///
+    /// Inserts a contract storage snapshot into the `snapshots` table.
+    ///
+    async fn insert_snapshot(&self, input: SnapshotInsertInput) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        INSERT INTO snapshots (
+            account_id,
+            version,
+
+            fields,
+            balances,
+            transactions,
+
+            created_at
+        ) VALUES (
+            $1,
+            $2,
+            $3,
+            $4,
+            $5,
+            DATETIME('now')
+        );
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.version as i64)
+            .bind(serde_json::to_string(&input.fields).expect(zinc_const::panic::DATA_CONVERSION))
+            .bind(serde_json::to_string(&input.balances).expect(zinc_const::panic::DATA_CONVERSION))
+            .bind(
+                serde_json::to_string(&input.transactions)
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+            )
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Reserves a contract storage snapshot version with a pending row in the `snapshots` table,
+    /// deleting the abandoned pending rows of the contract in the same transaction.
+    ///
+    async fn reserve_snapshot(&self, input: SnapshotReserveInput) -> Result<bool, sqlx::Error> {
+        const STATEMENT_RECLAIM: &str = r#"
+        DELETE FROM snapshots
+        WHERE
+            account_id = $1
+        AND is_pending
+        AND created_at <= DATETIME('now', '-' || $2 || ' seconds');
+        "#;
+
+        const STATEMENT_RESERVE: &str = r#"
+        INSERT INTO snapshots (
+            account_id,
+            version,
+
+            fields,
+            balances,
+            transactions,
+            is_pending,
+
+            created_at
+        ) VALUES (
+            $1,
+            $2,
+            '[]',
+            '[]',
+            '[]',
+            TRUE,
+            DATETIME('now')
+        )
+        ON CONFLICT (account_id, version) DO NOTHING;
+        "#;
+
+        let mut transaction = self.pool.begin().await?;
+
+        sqlx::query(STATEMENT_RECLAIM)
+            .bind(input.account_id as i64)
+            .bind(input.lifetime.as_secs() as i64)
+            .execute(&mut transaction)
+            .await?;
+
+        let done = sqlx::query(STATEMENT_RESERVE)
+            .bind(input.account_id as i64)
+            .bind(input.version as i64)
+            .execute(&mut transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        Ok(done.rows_affected() == 1)
+    }
+
+    ///
+    /// Writes the reserved contract storage snapshot into the `snapshots` table and updates the
+    /// contract storage fields in the `fields` table in a single transaction.
+    ///
+    async fn commit_snapshot(
+        &self,
+        snapshot: SnapshotInsertInput,
+        fields: Vec<FieldUpdateInput>,
+    ) -> Result<(), sqlx::Error> {
+        const STATEMENT_SNAPSHOT: &str = r#"
+        UPDATE snapshots
+        SET
+            fields = $3,
+            balances = $4,
+            transactions = $5,
+            is_pending = FALSE,
+            created_at = DATETIME('now')
+        WHERE
+            account_id = $1
+        AND version = $2
+        AND is_pending;
+        "#;
+
+        const STATEMENT_FIELD: &str = r#"
+        UPDATE fields
+        SET
+            value = $3
+        WHERE
+            "index" = $2
+        AND account_id = $1;
+        "#;
+
+        let mut transaction = self.pool.begin().await?;
+
+        let done = sqlx::query(STATEMENT_SNAPSHOT)
+            .bind(snapshot.account_id as i64)
+            .bind(snapshot.version as i64)
+            .bind(
+                serde_json::to_string(&snapshot.fields).expect(zinc_const::panic::DATA_CONVERSION),
+            )
+            .bind(
+                serde_json::to_string(&snapshot.balances)
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+            )
+            .bind(
+                serde_json::to_string(&snapshot.transactions)
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+            )
+            .execute(&mut transaction)
+            .await?;
+        if done.rows_affected() != 1 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        for field in fields.into_iter() {
+            sqlx::query(STATEMENT_FIELD)
+                .bind(field.account_id as i64)
+                .bind(field.index as i64)
+                .bind(field.value.to_string())
+                .execute(&mut transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Deletes a pending contract storage snapshot from the `snapshots` table.
+    ///
+    async fn release_snapshot(&self, input: SnapshotReserveInput) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        DELETE FROM snapshots
+        WHERE
+            account_id = $1
+        AND version = $2
+        AND is_pending;
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.version as i64)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Deletes the `snapshots` table contents.
+    ///
+    async fn delete_snapshots(&self) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        DELETE FROM snapshots;
+        "#;
+
+        sqlx::query(STATEMENT).execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Selects a page of the contract events from the `events` table.
+    ///
+    async fn select_events(
+        &self,
+        input: EventSelectInput,
+    ) -> Result<Vec<EventSelectOutput>, sqlx::Error> {
+        const STATEMENT: &str = r#"
+        SELECT
+            id,
+            version,
+            name,
+            value
+        FROM events
+        WHERE
+            account_id = $1
+        AND ($2 IS NULL OR id > $2)
+        ORDER BY id
+        LIMIT $3;
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.after)
+            .bind(input.limit as i64)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(|row| {
+                Ok(EventSelectOutput {
+                    id: row.try_get("id")?,
+                    version: row.try_get("version")?,
+                    name: row.try_get("name")?,
+                    value: Self::get_json(&row, "value")?,
+                })
+            })
+            .collect()
+    }
+
+    ///
+    /// Inserts the contract events into the `events` table, returning them along with the
+    /// assigned IDs.
+    ///
+    async fn insert_events(
+        &self,
+        input: Vec<EventInsertInput>,
+    ) -> Result<Vec<EventSelectOutput>, sqlx::Error> {
+        const STATEMENT: &str = r#"
+        INSERT INTO events (
+            account_id,
+            version,
+
+            name,
+            value,
+
+            created_at
+        ) VALUES (
+            $1,
+            $2,
+            $3,
+            $4,
+            DATETIME('now')
+        );
+        "#;
+
+        let mut output = Vec::with_capacity(input.len());
+        for event in input.into_iter() {
+            let id = sqlx::query(STATEMENT)
+                .bind(event.account_id as i64)
+                .bind(event.version as i64)
+                .bind(event.name.as_str())
+                .bind(event.value.to_string())
+                .execute(&self.pool)
+                .await?
+                .last_insert_rowid();
+
+            output.push(EventSelectOutput {
+                id,
+                version: event.version as i64,
+                name: event.name,
+                value: event.value,
+            });
+        }
+
+        Ok(output)
+    }
+
+    ///
+    /// Deletes the `events` table contents.
+    ///
+    async fn delete_events(&self) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        DELETE FROM events;
+        "#;
+
+        sqlx::query(STATEMENT).execute(&self.pool).await?;
+
+        Ok(())
+    }
+}
@@ -2,63 +2,82 @@
 //! The Zinc database asynchronous client.
 //!
 
-use sqlx::pool::Pool;
-use sqlx::postgres::PgPoolOptions;
-use sqlx::Postgres;
+use std::sync::Arc;
 
+use crate::database::backend::memory::Client as MemoryClient;
+use crate::database::backend::postgresql::Client as PostgresqlClient;
+use crate::database::backend::sqlite::Client as SqliteClient;
+use crate::database::backend::Backend;
 use crate::database::model::contract::insert_new::Input as ContractInsertNewInput;
 use crate::database::model::contract::select_all::Output as ContractSelectAllOutput;
 use crate::database::model::contract::select_curve::Output as ContractSelectCurveOutput;
+use crate::database::model::event::insert::Input as EventInsertInput;
+use crate::database::model::event::select::Input as EventSelectInput;
+use crate::database::model::event::select::Output as EventSelectOutput;
 use crate::database::model::field::insert::Input as FieldInsertInput;
 use crate::database::model::field::select::Input as FieldSelectInput;
 use crate::database::model::field::select::Output as FieldSelectOutput;
 use crate::database::model::field::update::Input as FieldUpdateInput;
+use crate::database::model::snapshot::insert::Input as SnapshotInsertInput;
+use crate::database::model::snapshot::reserve::Input as SnapshotReserveInput;
+use crate::database::model::snapshot::select::Input as SnapshotSelectInput;
+use crate::database::model::snapshot::select::Output as SnapshotSelectOutput;
 
 ///
 /// The database asynchronous client adapter.
 ///
+/// The storage backend is chosen by the connection URI scheme:
+/// - `postgres://` or `postgresql://` for PostgreSQL;
+/// - `sqlite:`, e.g. `sqlite://zandbox.db?mode=rwc` or `sqlite::memory:`, for SQLite;
+/// - `memory://` for the non-persistent in-memory storage.
+///
 #[derive(Clone)]
 pub struct Client {
-    /// The database connection pool.
-    pool: Pool<Postgres>,
+    /// The storage backend shared between the client clones.
+    backend: Arc<dyn Backend>,
 }
 
 impl Client {
+    /// The PostgreSQL connection URI schemes.
+    const SCHEMES_POSTGRESQL: [&'static str; 2] = ["postgres://", "postgresql://"];
+
+    /// The SQLite connection URI scheme.
+    const SCHEME_SQLITE: &'static str = "sqlite:";
+
+    /// The in-memory storage connection URI scheme.
+    const SCHEME_MEMORY: &'static str = "memory://";
+
     ///
-    /// Initializes a client instance.
+    /// Initializes a client instance with the backend chosen by the `connection_uri` scheme.
     ///
     pub async fn new(connection_uri: &str) -> Result<Self, sqlx::Error> {
-        let pool = PgPoolOptions::new()
-            .max_connections(8)
-            .connect(connection_uri)
-            .await?;
-
-        Ok(Self { pool })
+        let backend: Arc<dyn Backend> = if Self::SCHEMES_POSTGRESQL
+            .iter()
+            .any(|scheme| connection_uri.starts_with(scheme))
+        {
+            Arc::new(PostgresqlClient::new(connection_uri).await?)
+        } else if connection_uri.starts_with(Self::SCHEME_SQLITE) {
+            Arc::new(SqliteClient::new(connection_uri).await?)
+        } else if connection_uri.starts_with(Self::SCHEME_MEMORY) {
+            Arc::new(MemoryClient::new())
+        } else {
+            return Err(sqlx::Error::Configuration(
+                format!(
+                    "unsupported database URI `{}`, expected one of `postgres://`, `postgresql://`, `sqlite:`, `memory://`",
+                    connection_uri
+                )
+                .into(),
+            ));
+        };
+
+        Ok(Self { backend })
     }
 
     ///
     /// Select the contracts from the `contracts` table.
     ///
     pub async fn select_contracts(&self) -> Result<Vec<ContractSelectAllOutput>, sqlx::Error> {
-        const STATEMENT: &str = r#"
-        SELECT
-            account_id,
-            
-            name,
-            version,
-            instance,
-
-            source_code,
-            bytecode,
-            verifying_key,
-
-            eth_address,
-            eth_private_key
-        FROM zandbox.contracts
-        ORDER BY created_at;
-        "#;
-
-        Ok(sqlx::query_as(STATEMENT).fetch_all(&self.pool).await?)
+        self.backend.select_contracts().await
     }
 
     ///
@@ -67,86 +86,21 @@ impl Client {
     pub async fn select_contracts_curve(
         &self,
     ) -> Result<Vec<ContractSelectCurveOutput>, sqlx::Error> {
-        const STATEMENT: &str = r#"
-        SELECT
-            eth_address,
-            
-            name,
-            version,
-            instance
-        FROM zandbox.contracts
-        WHERE
-            name = 'curve'
-        ORDER BY created_at;
-        "#;
-
-        Ok(sqlx::query_as(STATEMENT).fetch_all(&self.pool).await?)
+        self.backend.select_contracts_curve().await
     }
 
     ///
     /// Inserts a contract instance into the `contracts` table.
     ///
     pub async fn insert_contract(&self, input: ContractInsertNewInput) -> Result<(), sqlx::Error> {
-        const STATEMENT: &str = r#"
-        INSERT INTO zandbox.contracts (
-            account_id,
-
-            name,
-            version,
-            instance,
-
-            zinc_version,
-            source_code,
-            bytecode,
-            verifying_key,
-
-            eth_address,
-            eth_private_key,
-
-            created_at
-        ) VALUES (
-            $1,
-            $2,
-            $3,
-            $4,
-            $5,
-            $6,
-            $7,
-            $8,
-            $9,
-            $10,
-            NOW()
-        );
-        "#;
-
-        sqlx::query(STATEMENT)
-            .bind(input.account_id as i64)
-            .bind(input.name)
-            .bind(input.version)
-            .bind(input.instance)
-            .bind(input.zinc_version)
-            .bind(input.source_code)
-            .bind(input.bytecode)
-            .bind(input.verifying_key)
-            .bind(<[u8; zinc_const::size::ETH_ADDRESS]>::from(input.eth_address).to_vec())
-            .bind(<[u8; zinc_const::size::ETH_PRIVATE_KEY]>::from(input.eth_private_key).to_vec())
-            .execute(&self.pool)
-            .await?;
-
-        Ok(())
+        self.backend.insert_contract(input).await
     }
 
     ///
     /// Deletes the `contracts` table contents.
     ///
     pub async fn delete_contracts(&self) -> Result<(), sqlx::Error> {
-        const STATEMENT: &str = r#"
-        DELETE FROM zandbox.contracts;
-        "#;
-
-        sqlx::query(STATEMENT).execute(&self.pool).await?;
-
-        Ok(())
+        self.backend.delete_contracts().await
     }
 
     ///
@@ -156,89 +110,109 @@ impl Client {
         &self,
         input: FieldSelectInput,
     ) -> Result<Vec<FieldSelectOutput>, sqlx::Error> {
-        const STATEMENT: &str = r#"
-        SELECT
-            name,
-            value
-        FROM zandbox.fields
-        WHERE
-            account_id = $1
-        ORDER BY index;
-        "#;
-
-        Ok(sqlx::query_as(STATEMENT)
-            .bind(input.account_id)
-            .fetch_all(&self.pool)
-            .await?)
+        self.backend.select_fields(input).await
     }
 
     ///
     /// Inserts contract storage fields into the `fields` table.
     ///
     pub async fn insert_fields(&self, input: Vec<FieldInsertInput>) -> Result<(), sqlx::Error> {
-        const STATEMENT: &str = r#"
-        INSERT INTO zandbox.fields (
-            account_id,
-            index,
-
-            name,
-            value
-        ) VALUES (
-            $1,
-            $2,
-            $3,
-            $4
-        );
-        "#;
-
-        for field in input.into_iter() {
-            sqlx::query(STATEMENT)
-                .bind(field.account_id)
-                .bind(field.index)
-                .bind(field.name)
-                .bind(field.value)
-                .execute(&self.pool)
-                .await?;
-        }
-
-        Ok(())
+        self.backend.insert_fields(input).await
     }
 
     ///
     /// Updates contract storage fields in the `fields` table.
     ///
     pub async fn update_fields(&self, input: Vec<FieldUpdateInput>) -> Result<(), sqlx::Error> {
-        const STATEMENT: &str = r#"
-        UPDATE zandbox.fields
-        SET
-            value = $3
-        WHERE
-            index = $2
-        AND account_id = $1;
-        "#;
-
-        for field in input.into_iter() {
-            sqlx::query(STATEMENT)
-                .bind(field.account_id)
-                .bind(field.index)
-                .bind(field.value)
-                .execute(&self.pool)
-                .await?;
-        }
-
-        Ok(())
+        self.backend.update_fields(input).await
     }
 
     ///
     /// Deletes the `field` table contents.
     ///
     pub async fn delete_fields(&self) -> Result<(), sqlx::Error> {
-        const STATEMENT: &str = r#"
-        DELETE FROM zandbox.fields;
-        "#;
+        self.backend.delete_fields().await
+    }
+
+    ///
+    /// Selects a contract storage snapshot from the `snapshots` table.
+    ///
+    pub async fn select_snapshot(
+        &self,
+        input: SnapshotSelectInput,
+    ) -> Result<Option<SnapshotSelectOutput>, sqlx::Error> {
+        self.backend.select_snapshot(input).await
+    }
+
+    ///
+    /// Inserts a contract storage snapshot into the `snapshots` table.
+    ///
+    pub async fn insert_snapshot(&self, input: SnapshotInsertInput) -> Result<(), sqlx::Error> {
+        self.backend.insert_snapshot(input).await
+    }
+
+    ///
+    /// Reserves a contract storage snapshot version in the `snapshots` table.
+    ///
+    /// The abandoned reservations of the contract are reclaimed beforehand.
+    ///
+    /// Returns `false` if the version has been already taken by another call.
+    ///
+    pub async fn reserve_snapshot(&self, input: SnapshotReserveInput) -> Result<bool, sqlx::Error> {
+        self.backend.reserve_snapshot(input).await
+    }
+
+    ///
+    /// Writes the reserved contract storage snapshot into the `snapshots` table and updates the
+    /// contract storage fields in the `fields` table atomically.
+    ///
+    pub async fn commit_snapshot(
+        &self,
+        snapshot: SnapshotInsertInput,
+        fields: Vec<FieldUpdateInput>,
+    ) -> Result<(), sqlx::Error> {
+        self.backend.commit_snapshot(snapshot, fields).await
+    }
 
-        sqlx::query(STATEMENT).execute(&self.pool).await?;
+    ///
+    /// Releases a reserved contract storage snapshot version in the `snapshots` table.
+    ///
+    pub async fn release_snapshot(&self, input: SnapshotReserveInput) -> Result<(), sqlx::Error> {
+        self.backend.release_snapshot(input).await
+    }
 
-        Ok(())
+    ///
+    /// Deletes the `snapshots` table contents.
+    ///
+    pub async fn delete_snapshots(&self) -> Result<(), sqlx::Error> {
+        self.backend.delete_snapshots().await
+    }
+
+    ///
+    /// Selects a page of the contract events from the `events` table.
+    ///
+    pub async fn select_events(
+        &self,
+        input: EventSelectInput,
+    ) -> Result<Vec<EventSelectOutput>, sqlx::Error> {
+        self.backend.select_events(input).await
+    }
+
+    ///
+    /// Inserts the contract events into the `events` table, returning them along with the
+    /// assigned IDs.
+    ///
+    pub async fn insert_events(
+        &self,
+        input: Vec<EventInsertInput>,
+    ) -> Result<Vec<EventSelectOutput>, sqlx::Error> {
+        self.backend.insert_events(input).await
+    }
+
+    ///
+    /// Deletes the `events` table contents.
+    ///
+    pub async fn delete_events(&self) -> Result<(), sqlx::Error> {
+        self.backend.delete_events().await
     }
 }
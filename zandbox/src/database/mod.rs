@@ -2,5 +2,6 @@
 //! The Zinc database library.
 //!
 
+pub mod backend;
 pub mod client;
 pub mod model;
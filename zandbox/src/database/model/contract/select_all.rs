@@ -7,7 +7,7 @@ use serde_json::Value as JsonValue;
 ///
 /// The database contract SELECT all output model.
 ///
-#[derive(Debug, sqlx::FromRow)]
+#[derive(Debug, Clone, sqlx::FromRow)]
 pub struct Output {
     /// The contract account ID.
     pub account_id: i64,
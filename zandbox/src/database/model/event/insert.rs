@@ -0,0 +1,36 @@
+//!
+//! The database contract event INSERT model.
+//!
+
+use serde_json::Value as JsonValue;
+
+use zksync_types::AccountId;
+
+///
+/// The database contract event INSERT input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID referencing `contracts.account_id`.
+    pub account_id: AccountId,
+    /// The contract storage snapshot version, which has been produced by the emitting call.
+    pub version: u32,
+    /// The event name.
+    pub name: String,
+    /// The event data in JSON representation.
+    pub value: JsonValue,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: AccountId, version: u32, name: String, value: JsonValue) -> Self {
+        Self {
+            account_id,
+            version,
+            name,
+            value,
+        }
+    }
+}
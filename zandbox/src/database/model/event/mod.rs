@@ -0,0 +1,6 @@
+//!
+//! The database contract event model.
+//!
+
+pub mod insert;
+pub mod select;
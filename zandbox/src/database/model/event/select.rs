@@ -0,0 +1,50 @@
+//!
+//! The database contract event SELECT model.
+//!
+
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use zksync_types::AccountId;
+
+///
+/// The database contract event SELECT input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID referencing `contracts.account_id`.
+    pub account_id: AccountId,
+    /// The ID of the last event received by the client. If not specified, the events are
+    /// selected from the beginning.
+    pub after: Option<i64>,
+    /// The maximal number of the selected events.
+    pub limit: u32,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: AccountId, after: Option<i64>, limit: u32) -> Self {
+        Self {
+            account_id,
+            after,
+            limit,
+        }
+    }
+}
+
+///
+/// The database contract event SELECT output model.
+///
+#[derive(Debug, Clone, Serialize)]
+pub struct Output {
+    /// The event unique ID, which grows in the order of emitting.
+    pub id: i64,
+    /// The contract storage snapshot version, which has been produced by the emitting call.
+    pub version: i64,
+    /// The event name.
+    pub name: String,
+    /// The event data in JSON representation.
+    pub value: JsonValue,
+}
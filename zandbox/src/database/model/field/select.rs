@@ -2,6 +2,8 @@
 //! The database contract storage field SELECT model.
 //!
 
+use serde::Deserialize;
+use serde::Serialize;
 use serde_json::Value as JsonValue;
 
 use zksync_types::AccountId;
@@ -27,7 +29,7 @@ impl Input {
 ///
 /// The database contract storage field SELECT output model.
 ///
-#[derive(Debug, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Output {
     /// The field name.
     pub name: String,
@@ -3,4 +3,6 @@
 //!
 
 pub mod contract;
+pub mod event;
 pub mod field;
+pub mod snapshot;
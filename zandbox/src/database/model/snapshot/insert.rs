@@ -0,0 +1,47 @@
+//!
+//! The database contract storage snapshot INSERT model.
+//!
+
+use serde_json::Value as JsonValue;
+
+use zksync_types::AccountId;
+
+use crate::database::model::field::select::Output as FieldSelectOutput;
+
+///
+/// The database contract storage snapshot INSERT input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID referencing `contracts.account_id`.
+    pub account_id: AccountId,
+    /// The snapshot version, which is `0` after initialization and incremented by every call.
+    pub version: u32,
+    /// The contract storage fields except the implicit ones, in the order of their indexes.
+    pub fields: Vec<FieldSelectOutput>,
+    /// The contract zkSync account balances at the moment of taking the snapshot.
+    pub balances: JsonValue,
+    /// The hashes of the zkSync transactions, which have produced the snapshot.
+    pub transactions: Vec<String>,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        account_id: AccountId,
+        version: u32,
+        fields: Vec<FieldSelectOutput>,
+        balances: JsonValue,
+        transactions: Vec<String>,
+    ) -> Self {
+        Self {
+            account_id,
+            version,
+            fields,
+            balances,
+            transactions,
+        }
+    }
+}
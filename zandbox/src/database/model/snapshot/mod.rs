@@ -0,0 +1,7 @@
+//!
+//! The database contract storage snapshot model.
+//!
+
+pub mod insert;
+pub mod reserve;
+pub mod select;
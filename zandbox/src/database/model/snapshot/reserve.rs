@@ -0,0 +1,36 @@
+//!
+//! The database contract storage snapshot reservation model.
+//!
+
+use std::time::Duration;
+
+use zksync_types::AccountId;
+
+///
+/// The database contract storage snapshot reservation input model.
+///
+/// The model is used to reserve, and release if the call has failed, the snapshot version.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Input {
+    /// The contract account ID referencing `contracts.account_id`.
+    pub account_id: AccountId,
+    /// The reserved snapshot version.
+    pub version: u32,
+    /// The period, after which the pending snapshots of the contract are considered abandoned,
+    /// e.g. by a crashed instance, and are reclaimed by the next reservation.
+    pub lifetime: Duration,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: AccountId, version: u32, lifetime: Duration) -> Self {
+        Self {
+            account_id,
+            version,
+            lifetime,
+        }
+    }
+}
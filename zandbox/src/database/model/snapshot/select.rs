@@ -0,0 +1,47 @@
+//!
+//! The database contract storage snapshot SELECT model.
+//!
+
+use serde_json::Value as JsonValue;
+
+use zksync_types::AccountId;
+
+use crate::database::model::field::select::Output as FieldSelectOutput;
+
+///
+/// The database contract storage snapshot SELECT input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID referencing `contracts.account_id`.
+    pub account_id: AccountId,
+    /// The snapshot version. If not specified, the latest snapshot is selected.
+    pub version: Option<u32>,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: AccountId, version: Option<u32>) -> Self {
+        Self {
+            account_id,
+            version,
+        }
+    }
+}
+
+///
+/// The database contract storage snapshot SELECT output model.
+///
+#[derive(Debug, Clone)]
+pub struct Output {
+    /// The snapshot version.
+    pub version: i64,
+    /// The contract storage fields except the implicit ones, in the order of their indexes.
+    pub fields: Vec<FieldSelectOutput>,
+    /// The contract zkSync account balances at the moment of taking the snapshot.
+    pub balances: JsonValue,
+    /// The hashes of the zkSync transactions, which have produced the snapshot.
+    pub transactions: Vec<String>,
+}
@@ -7,6 +7,7 @@ mod tests;
 
 pub(crate) mod controller;
 pub(crate) mod database;
+pub(crate) mod mock;
 pub(crate) mod response;
 pub(crate) mod shared_data;
 pub(crate) mod storage;
@@ -16,6 +17,7 @@ pub use self::database::client::Client as DatabaseClient;
 pub use self::database::model::contract::select_all::Output as ContractSelectAllOutput;
 pub use self::database::model::field::select::Input as FieldSelectInput;
 pub use self::database::model::field::select::Output as FieldSelectOutput;
+pub use self::mock::configure as configure_zksync_mock;
 pub use self::shared_data::contract::Contract as SharedDataContract;
 pub use self::shared_data::SharedData;
 pub use self::storage::Storage as ContractStorage;
@@ -0,0 +1,36 @@
+//!
+//! The Zandbox local zkSync mock server.
+//!
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use actix_web::web;
+use serde_json::Value as JsonValue;
+
+use zinc_zksync::MockState;
+
+///
+/// The local zkSync mock routing initializer.
+///
+/// The mock is served at the zkSync `localhost` network JSON-RPC address, so the zkSync SDK
+/// providers of Zandbox and Zargo talk to it without any changes.
+///
+pub fn configure(config: &mut web::ServiceConfig) {
+    config.service(web::resource("/").route(web::post().to(handle)));
+}
+
+///
+/// The JSON-RPC request handler.
+///
+pub async fn handle(
+    state: web::Data<Arc<Mutex<MockState>>>,
+    request: web::Json<JsonValue>,
+) -> web::Json<JsonValue> {
+    let response = state
+        .lock()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .handle(request.into_inner());
+
+    web::Json(response)
+}
@@ -0,0 +1,61 @@
+//!
+//! The per-contract call queue.
+//!
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Weak;
+
+use futures::lock::Mutex;
+
+use zksync::web3::types::Address;
+
+///
+/// The per-contract call queue.
+///
+/// Every contract has its own asynchronous mutex, so the mutable calls to the same contract are
+/// executed one by one in the order of their arrival, whereas the calls to different contracts
+/// are not blocked by each other.
+///
+/// The queue only keeps weak references to the locks, so a lock is dropped as soon as the last
+/// call to its contract is finished, and the queue does not grow with the number of contracts.
+///
+#[derive(Debug, Default)]
+pub struct CallQueue {
+    /// The contract call locks by the contract address.
+    locks: HashMap<Address, Weak<Mutex<()>>>,
+}
+
+impl CallQueue {
+    ///
+    /// Initializes an empty queue.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Returns the call lock of the contract with the specified `address`.
+    ///
+    /// The lock must be held from loading the contract storage until the updated storage is
+    /// written to the database. The entries of the dropped locks are removed.
+    ///
+    pub fn get(&mut self, address: Address) -> Arc<Mutex<()>> {
+        self.locks.retain(|_address, lock| lock.strong_count() > 0);
+
+        if let Some(lock) = self.locks.get(&address).and_then(Weak::upgrade) {
+            return lock;
+        }
+
+        let lock = Arc::new(Mutex::new(()));
+        self.locks.insert(address, Arc::downgrade(&lock));
+        lock
+    }
+
+    ///
+    /// Checks if there are no calls in progress.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.locks.values().all(|lock| lock.strong_count() == 0)
+    }
+}
@@ -2,36 +2,74 @@
 //! The Zandbox server daemon shared application data.
 //!
 
+pub mod call_queue;
 pub mod contract;
 
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::RwLock;
 
+use futures::channel::mpsc;
+use serde_json::Value as JsonValue;
+
 use zksync::web3::types::Address;
 
 use crate::database::client::Client as DatabaseClient;
 
+use self::call_queue::CallQueue;
 use self::contract::Contract;
 
 ///
 /// The Zandbox server daemon shared application data.
 ///
 pub struct SharedData {
-    /// The PostgreSQL asynchronous client.
-    pub postgresql: DatabaseClient,
+    /// The database asynchronous client.
+    pub database: DatabaseClient,
     /// The precompiled contracts written at application startup.
     pub contracts: HashMap<Address, Contract>,
+    /// The contract event WebSocket subscribers.
+    pub event_subscribers: HashMap<Address, Vec<mpsc::UnboundedSender<JsonValue>>>,
+    /// The per-contract queue, which serializes the mutable calls to the same contract.
+    pub call_queue: CallQueue,
 }
 
 impl SharedData {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(postgresql: DatabaseClient, contracts: HashMap<Address, Contract>) -> Self {
+    pub fn new(database: DatabaseClient, contracts: HashMap<Address, Contract>) -> Self {
         Self {
-            postgresql,
+            database,
             contracts,
+            event_subscribers: HashMap::new(),
+            call_queue: CallQueue::new(),
+        }
+    }
+
+    ///
+    /// Subscribes to the events of the contract with the specified `address`.
+    ///
+    pub fn subscribe_events(&mut self, address: Address) -> mpsc::UnboundedReceiver<JsonValue> {
+        let (sender, receiver) = mpsc::unbounded();
+        self.event_subscribers
+            .entry(address)
+            .or_insert_with(Vec::new)
+            .push(sender);
+        receiver
+    }
+
+    ///
+    /// Sends the `events` to the subscribers of the contract with the specified `address`.
+    ///
+    /// The subscribers, whose connections have been closed, are removed.
+    ///
+    pub fn publish_events(&mut self, address: Address, events: &[JsonValue]) {
+        if let Some(subscribers) = self.event_subscribers.get_mut(&address) {
+            subscribers.retain(|subscriber| {
+                events
+                    .iter()
+                    .all(|event| subscriber.unbounded_send(event.to_owned()).is_ok())
+            });
         }
     }
 
@@ -16,6 +16,7 @@ use zinc_build::Value as BuildValue;
 use crate::database::model::field::insert::Input as FieldInsertInput;
 use crate::database::model::field::select::Output as FieldSelectOutput;
 use crate::database::model::field::update::Input as FieldUpdateInput;
+use crate::database::model::snapshot::insert::Input as SnapshotInsertInput;
 
 ///
 /// The Zandbox contract storage wrapper.
@@ -57,6 +58,27 @@ impl Storage {
         address: zksync_types::Address,
         wallet: &zksync::Wallet<PrivateKeySigner>,
     ) -> Result<Self, zksync::error::ClientError> {
+        let balances = Self::load_balances(wallet).await?;
+
+        Ok(Self::new_with_balances(
+            database_fields,
+            balances,
+            types,
+            address,
+        ))
+    }
+
+    ///
+    /// Populates the storage with the database data and the previously loaded `balances`.
+    ///
+    /// The `balances` are either loaded with `load_balances` or taken from a storage snapshot.
+    ///
+    pub fn new_with_balances(
+        database_fields: Vec<FieldSelectOutput>,
+        balances: JsonValue,
+        types: &[ContractFieldType],
+        address: zksync_types::Address,
+    ) -> Self {
         let mut fields = Vec::with_capacity(database_fields.len());
 
         fields.push(BuildContractFieldValue::new(
@@ -72,22 +94,10 @@ impl Storage {
             true,
         ));
 
-        let account_info = wallet.account_info().await?;
-        let mut balances = Vec::with_capacity(account_info.committed.balances.len());
-        for (symbol, balance) in account_info.committed.balances.into_iter() {
-            let token = wallet
-                .tokens
-                .resolve(TokenLike::Symbol(symbol))
-                .ok_or(zksync::error::ClientError::UnknownToken)?;
-            balances.push(json!({
-                "key": token.address,
-                "value": balance.0.to_string(),
-            }));
-        }
         fields.push(BuildContractFieldValue::new(
             zinc_const::contract::FIELD_NAME_BALANCES.to_owned(),
             BuildValue::try_from_typed_json(
-                JsonValue::Array(balances),
+                balances,
                 types[zinc_const::contract::FIELD_INDEX_BALANCES]
                     .r#type
                     .to_owned(),
@@ -113,7 +123,31 @@ impl Storage {
             ));
         }
 
-        Ok(Self { fields })
+        Self { fields }
+    }
+
+    ///
+    /// Loads the committed contract balances from the zkSync account info.
+    ///
+    /// The balances are represented as the JSON map of the `balances` implicit field.
+    ///
+    pub async fn load_balances(
+        wallet: &zksync::Wallet<PrivateKeySigner>,
+    ) -> Result<JsonValue, zksync::error::ClientError> {
+        let account_info = wallet.account_info().await?;
+        let mut balances = Vec::with_capacity(account_info.committed.balances.len());
+        for (symbol, balance) in account_info.committed.balances.into_iter() {
+            let token = wallet
+                .tokens
+                .resolve(TokenLike::Symbol(symbol))
+                .ok_or(zksync::error::ClientError::UnknownToken)?;
+            balances.push(json!({
+                "key": token.address,
+                "value": balance.0.to_string(),
+            }));
+        }
+
+        Ok(JsonValue::Array(balances))
     }
 
     ///
@@ -171,6 +205,36 @@ impl Storage {
             .collect()
     }
 
+    ///
+    /// Converts the storage into the snapshot INSERT query database representation.
+    ///
+    /// The `balances` must be loaded after the snapshot transactions are committed, since the
+    /// storage `balances` field is not updated by the VM transfers.
+    ///
+    pub fn into_database_snapshot(
+        self,
+        account_id: zksync_types::AccountId,
+        version: u32,
+        balances: JsonValue,
+        transactions: Vec<String>,
+    ) -> SnapshotInsertInput {
+        let fields = self
+            .fields
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, field)| match index {
+                zinc_const::contract::FIELD_INDEX_ADDRESS => None,
+                zinc_const::contract::FIELD_INDEX_BALANCES => None,
+                _index => Some(FieldSelectOutput {
+                    name: field.name,
+                    value: field.value.into_json(),
+                }),
+            })
+            .collect();
+
+        SnapshotInsertInput::new(account_id, version, fields, balances, transactions)
+    }
+
     ///
     /// Wraps the fields with the VM value type.
     ///
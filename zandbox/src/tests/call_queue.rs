@@ -0,0 +1,183 @@
+//!
+//! The Zandbox contract call queue tests.
+//!
+
+use actix_web::web;
+use num_old::BigUint;
+use serde_json::json;
+
+use zksync::web3::types::Address;
+use zksync_eth_signer::PrivateKeySigner;
+use zksync_types::AccountId;
+
+use zinc_zksync::MockState;
+
+use crate::controller::contract::call::error::Error as CallError;
+use crate::database::model::snapshot::select::Input as SnapshotSelectInput;
+use crate::shared_data::SharedData;
+use crate::tests::sandbox;
+
+/// The initial contract deposit, which must cover the contract change-pubkey fee.
+const DEPOSIT: u64 = 1_000_000_000_000_000;
+
+/// The amount sent to the contract with every call.
+const CALL_AMOUNT: u64 = 100_000_000_000_000;
+
+/// The number of the concurrent calls.
+const CALLS_COUNT: usize = 8;
+
+///
+/// Publishes the counter contract and creates `CALLS_COUNT` callers.
+///
+/// Returns the contract owner, address, zkSync account ID, and the callers.
+///
+async fn initialize(
+    app_data: &sandbox::AppData,
+) -> (
+    zksync::Wallet<PrivateKeySigner>,
+    Address,
+    AccountId,
+    Vec<zksync::Wallet<PrivateKeySigner>>,
+) {
+    let owner = sandbox::account().await;
+    let address = sandbox::publish(app_data, &owner, "counter", json!({}), DEPOSIT).await;
+    let account_id = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .contracts
+        .get(&address)
+        .and_then(|contract| contract.account_id)
+        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+    let mut callers = Vec::with_capacity(CALLS_COUNT);
+    for _ in 0..CALLS_COUNT {
+        callers.push(sandbox::account().await);
+    }
+
+    (owner, address, account_id, callers)
+}
+
+///
+/// Returns the `calls` counter value from the contract storage snapshot `version`.
+///
+async fn snapshot_calls(
+    app_data: &sandbox::AppData,
+    account_id: AccountId,
+    version: u32,
+) -> String {
+    let database = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .database
+        .clone();
+
+    database
+        .select_snapshot(SnapshotSelectInput::new(account_id, Some(version)))
+        .await
+        .expect("Database contract storage snapshot selecting")
+        .expect("Every successful call must append a snapshot")
+        .fields
+        .into_iter()
+        .find(|field| field.name == "calls")
+        .and_then(|field| field.value.as_str().map(|value| value.to_owned()))
+        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+}
+
+#[tokio::test]
+async fn ok_serialized() {
+    let app_data = sandbox::app_data().await;
+    let (owner, address, account_id, callers) = initialize(&app_data).await;
+
+    let results = futures::future::join_all(callers.iter().map(|caller| {
+        sandbox::call(
+            &app_data,
+            caller,
+            address,
+            "increment",
+            json!({}),
+            "ETH",
+            CALL_AMOUNT,
+        )
+    }))
+    .await;
+    for result in results.into_iter() {
+        result.expect("The calls to the same contract must be serialized");
+    }
+
+    let storage = sandbox::query(&app_data, address, None, None, None).await;
+    assert_eq!(storage["calls"], json!(CALLS_COUNT.to_string()));
+    for version in 1..=CALLS_COUNT as u32 {
+        assert_eq!(
+            snapshot_calls(&app_data, account_id, version).await,
+            version.to_string(),
+            "Every call must be applied to the snapshot of the previous one"
+        );
+    }
+
+    assert_eq!(
+        sandbox::balance(&owner, address, "ETH").await,
+        BigUint::from(DEPOSIT - MockState::FEE_CHANGE_PUBKEY + CALLS_COUNT as u64 * CALL_AMOUNT)
+    );
+    assert!(
+        app_data
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .call_queue
+            .is_empty(),
+        "The call locks must be dropped after the calls are finished"
+    );
+}
+
+#[tokio::test]
+async fn error_conflict_between_instances() {
+    let app_data = sandbox::app_data().await;
+    let (owner, address, account_id, callers) = initialize(&app_data).await;
+
+    let (database, contracts) = {
+        let shared_data = app_data.read().expect(zinc_const::panic::SYNCHRONIZATION);
+        (shared_data.database.clone(), shared_data.contracts.clone())
+    };
+    let instances = [
+        app_data.clone(),
+        web::Data::new(SharedData::new(database, contracts).wrap()),
+    ];
+
+    let results = futures::future::join_all(callers.iter().enumerate().map(|(index, caller)| {
+        sandbox::call(
+            &instances[index % instances.len()],
+            caller,
+            address,
+            "increment",
+            json!({}),
+            "ETH",
+            CALL_AMOUNT,
+        )
+    }))
+    .await;
+
+    let mut successes = 0;
+    for result in results.into_iter() {
+        match result {
+            Ok(_) => successes += 1,
+            Err(CallError::ContractStateConflict(_)) => {}
+            Err(error) => panic!("Unexpected call error: {:?}", error),
+        }
+    }
+    assert!(successes > 0, "At least one of the calls must be committed");
+
+    let storage = sandbox::query(&app_data, address, None, None, None).await;
+    assert_eq!(storage["calls"], json!(successes.to_string()));
+    for version in 1..=successes as u32 {
+        assert_eq!(
+            snapshot_calls(&app_data, account_id, version).await,
+            version.to_string(),
+            "Every committed call must be applied to the snapshot of the previous one"
+        );
+    }
+
+    assert_eq!(
+        sandbox::balance(&owner, address, "ETH").await,
+        BigUint::from(DEPOSIT - MockState::FEE_CHANGE_PUBKEY + successes as u64 * CALL_AMOUNT),
+        "The transfers of the rejected calls must not be sent"
+    );
+}
@@ -0,0 +1,412 @@
+//!
+//! The Zandbox database backend tests.
+//!
+
+use std::time::Duration;
+
+use serde_json::json;
+
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+use zksync_types::AccountId;
+
+use crate::database::client::Client as DatabaseClient;
+use crate::database::model::contract::insert_new::Input as ContractInsertNewInput;
+use crate::database::model::event::insert::Input as EventInsertInput;
+use crate::database::model::event::select::Input as EventSelectInput;
+use crate::database::model::field::insert::Input as FieldInsertInput;
+use crate::database::model::field::select::Input as FieldSelectInput;
+use crate::database::model::field::update::Input as FieldUpdateInput;
+use crate::database::model::snapshot::insert::Input as SnapshotInsertInput;
+use crate::database::model::snapshot::reserve::Input as SnapshotReserveInput;
+use crate::database::model::snapshot::select::Input as SnapshotSelectInput;
+
+static POSTGRESQL_URL: &str = "postgres://postgres@localhost/zinc";
+
+static SQLITE_URL: &str = "sqlite::memory:";
+
+static MEMORY_URL: &str = "memory://";
+
+const LIFETIME: Duration = Duration::from_secs(zinc_const::zandbox::SNAPSHOT_RESERVATION_LIFETIME);
+
+fn contract(account_id: AccountId, name: &str) -> ContractInsertNewInput {
+    ContractInsertNewInput::new(
+        account_id,
+        name.to_owned(),
+        "0.1.0".to_owned(),
+        "test".to_owned(),
+        env!("CARGO_PKG_VERSION").to_owned(),
+        json!({ "main.zn": "contract Test {}" }),
+        vec![1, 2, 3],
+        vec![4, 5, 6],
+        Address::from_low_u64_be(account_id as u64),
+        H256::from_low_u64_be(account_id as u64),
+    )
+}
+
+async fn check(connection_uri: &str) {
+    let client = DatabaseClient::new(connection_uri)
+        .await
+        .expect("Database client initialization");
+    client
+        .delete_events()
+        .await
+        .expect("Database contract events deleting");
+    client
+        .delete_snapshots()
+        .await
+        .expect("Database contract storage snapshots deleting");
+    client
+        .delete_fields()
+        .await
+        .expect("Database contract storage deleting");
+    client
+        .delete_contracts()
+        .await
+        .expect("Database contracts deleting");
+
+    client
+        .insert_contract(contract(1, "curve"))
+        .await
+        .expect("Database contract inserting");
+    client
+        .insert_contract(contract(2, "uniswap"))
+        .await
+        .expect("Database contract inserting");
+    assert!(
+        client.insert_contract(contract(1, "curve")).await.is_err(),
+        "Duplicate contracts must be rejected"
+    );
+
+    let contracts = client
+        .select_contracts()
+        .await
+        .expect("Database contracts selecting");
+    assert_eq!(
+        contracts
+            .iter()
+            .map(|contract| contract.account_id)
+            .collect::<Vec<i64>>(),
+        vec![1, 2]
+    );
+    assert_eq!(
+        contracts[0].source_code,
+        json!({ "main.zn": "contract Test {}" })
+    );
+    assert_eq!(contracts[0].bytecode, vec![1, 2, 3]);
+    assert_eq!(
+        contracts[1].eth_address,
+        Address::from_low_u64_be(2).as_bytes().to_vec()
+    );
+
+    let curves = client
+        .select_contracts_curve()
+        .await
+        .expect("Database contracts selecting");
+    assert_eq!(curves.len(), 1);
+    assert_eq!(curves[0].name, "curve");
+
+    client
+        .insert_fields(vec![
+            FieldInsertInput::new(1, 1, "balance".to_owned(), json!("42")),
+            FieldInsertInput::new(1, 0, "address".to_owned(), json!("0x01")),
+            FieldInsertInput::new(2, 0, "address".to_owned(), json!("0x02")),
+        ])
+        .await
+        .expect("Database contract storage inserting");
+    client
+        .update_fields(vec![FieldUpdateInput::new(1, 1, json!("25"))])
+        .await
+        .expect("Database contract storage updating");
+
+    let fields = client
+        .select_fields(FieldSelectInput::new(1))
+        .await
+        .expect("Database contract storage selecting");
+    assert_eq!(
+        fields
+            .into_iter()
+            .map(|field| (field.name, field.value))
+            .collect::<Vec<_>>(),
+        vec![
+            ("address".to_owned(), json!("0x01")),
+            ("balance".to_owned(), json!("25")),
+        ]
+    );
+
+    client
+        .insert_snapshot(SnapshotInsertInput::new(
+            1,
+            0,
+            client
+                .select_fields(FieldSelectInput::new(1))
+                .await
+                .expect("Database contract storage selecting"),
+            json!([]),
+            vec!["sync-tx:01".to_owned()],
+        ))
+        .await
+        .expect("Database contract storage snapshot inserting");
+    client
+        .update_fields(vec![FieldUpdateInput::new(1, 1, json!("50"))])
+        .await
+        .expect("Database contract storage updating");
+    client
+        .insert_snapshot(SnapshotInsertInput::new(
+            1,
+            1,
+            client
+                .select_fields(FieldSelectInput::new(1))
+                .await
+                .expect("Database contract storage selecting"),
+            json!([{ "key": "0x00", "value": "100" }]),
+            vec!["sync-tx:02".to_owned(), "sync-tx:03".to_owned()],
+        ))
+        .await
+        .expect("Database contract storage snapshot inserting");
+    assert!(
+        client
+            .insert_snapshot(SnapshotInsertInput::new(1, 1, vec![], json!([]), vec![]))
+            .await
+            .is_err(),
+        "Duplicate snapshots must be rejected"
+    );
+
+    let latest = client
+        .select_snapshot(SnapshotSelectInput::new(1, None))
+        .await
+        .expect("Database contract storage snapshot selecting")
+        .expect("The latest snapshot must exist");
+    assert_eq!(latest.version, 1);
+    assert_eq!(latest.fields[1].value, json!("50"));
+    assert_eq!(latest.balances, json!([{ "key": "0x00", "value": "100" }]));
+    assert_eq!(latest.transactions, vec!["sync-tx:02", "sync-tx:03"]);
+
+    let initial = client
+        .select_snapshot(SnapshotSelectInput::new(1, Some(0)))
+        .await
+        .expect("Database contract storage snapshot selecting")
+        .expect("The initial snapshot must exist");
+    assert_eq!(initial.version, 0);
+    assert_eq!(initial.fields[0].name, "address");
+    assert_eq!(initial.fields[1].value, json!("25"));
+    assert_eq!(initial.balances, json!([]));
+    assert_eq!(initial.transactions, vec!["sync-tx:01"]);
+
+    assert!(client
+        .select_snapshot(SnapshotSelectInput::new(1, Some(2)))
+        .await
+        .expect("Database contract storage snapshot selecting")
+        .is_none());
+    assert!(client
+        .select_snapshot(SnapshotSelectInput::new(2, None))
+        .await
+        .expect("Database contract storage snapshot selecting")
+        .is_none());
+
+    assert!(
+        client
+            .reserve_snapshot(SnapshotReserveInput::new(1, 2, LIFETIME))
+            .await
+            .expect("Database contract storage snapshot reserving"),
+        "A free snapshot version must be reserved"
+    );
+    assert!(
+        !client
+            .reserve_snapshot(SnapshotReserveInput::new(1, 2, LIFETIME))
+            .await
+            .expect("Database contract storage snapshot reserving"),
+        "A reserved snapshot version must be rejected"
+    );
+    assert!(
+        !client
+            .reserve_snapshot(SnapshotReserveInput::new(1, 1, LIFETIME))
+            .await
+            .expect("Database contract storage snapshot reserving"),
+        "A committed snapshot version must be rejected"
+    );
+    assert_eq!(
+        client
+            .select_snapshot(SnapshotSelectInput::new(1, None))
+            .await
+            .expect("Database contract storage snapshot selecting")
+            .expect("The latest snapshot must exist")
+            .version,
+        1,
+        "Pending snapshots must not be selected"
+    );
+
+    client
+        .release_snapshot(SnapshotReserveInput::new(1, 2, LIFETIME))
+        .await
+        .expect("Database contract storage snapshot releasing");
+    assert!(
+        client
+            .reserve_snapshot(SnapshotReserveInput::new(1, 2, LIFETIME))
+            .await
+            .expect("Database contract storage snapshot reserving"),
+        "A released snapshot version must be reserved again"
+    );
+
+    let mut fields = client
+        .select_fields(FieldSelectInput::new(1))
+        .await
+        .expect("Database contract storage selecting");
+    fields[1].value = json!("75");
+    client
+        .commit_snapshot(
+            SnapshotInsertInput::new(1, 2, fields, json!([]), vec!["sync-tx:04".to_owned()]),
+            vec![FieldUpdateInput::new(1, 1, json!("75"))],
+        )
+        .await
+        .expect("Database contract storage snapshot committing");
+    assert!(
+        client
+            .commit_snapshot(
+                SnapshotInsertInput::new(1, 3, vec![], json!([]), vec![]),
+                vec![FieldUpdateInput::new(1, 1, json!("100"))],
+            )
+            .await
+            .is_err(),
+        "Snapshots without a reserved version must be rejected"
+    );
+
+    assert!(
+        client
+            .reserve_snapshot(SnapshotReserveInput::new(1, 3, LIFETIME))
+            .await
+            .expect("Database contract storage snapshot reserving"),
+        "A free snapshot version must be reserved"
+    );
+    assert!(
+        client
+            .reserve_snapshot(SnapshotReserveInput::new(1, 3, Duration::from_secs(0)))
+            .await
+            .expect("Database contract storage snapshot reserving"),
+        "An abandoned snapshot version must be reclaimed"
+    );
+    assert!(
+        !client
+            .reserve_snapshot(SnapshotReserveInput::new(1, 3, LIFETIME))
+            .await
+            .expect("Database contract storage snapshot reserving"),
+        "A reclaimed snapshot version must be reserved by the reclaiming call"
+    );
+    client
+        .release_snapshot(SnapshotReserveInput::new(1, 3, LIFETIME))
+        .await
+        .expect("Database contract storage snapshot releasing");
+
+    let latest = client
+        .select_snapshot(SnapshotSelectInput::new(1, None))
+        .await
+        .expect("Database contract storage snapshot selecting")
+        .expect("The latest snapshot must exist");
+    assert_eq!(latest.version, 2);
+    assert_eq!(latest.fields[1].value, json!("75"));
+    assert_eq!(latest.transactions, vec!["sync-tx:04"]);
+    assert_eq!(
+        client
+            .select_fields(FieldSelectInput::new(1))
+            .await
+            .expect("Database contract storage selecting")[1]
+            .value,
+        json!("75"),
+        "The storage fields must be updated along with the snapshot"
+    );
+
+    let inserted = client
+        .insert_events(vec![
+            EventInsertInput::new(1, 1, "Deposit".to_owned(), json!({ "amount": "25" })),
+            EventInsertInput::new(2, 1, "Swap".to_owned(), json!({ "amount": "5" })),
+            EventInsertInput::new(1, 1, "Withdrawal".to_owned(), json!({ "amount": "10" })),
+            EventInsertInput::new(1, 2, "Deposit".to_owned(), json!({ "amount": "50" })),
+        ])
+        .await
+        .expect("Database contract events inserting");
+    assert_eq!(inserted.len(), 4);
+    assert_eq!(inserted[1].name, "Swap");
+    assert!(
+        client
+            .insert_events(vec![EventInsertInput::new(
+                3,
+                1,
+                "Deposit".to_owned(),
+                json!({})
+            )])
+            .await
+            .is_err(),
+        "Events of unknown contracts must be rejected"
+    );
+
+    let page = client
+        .select_events(EventSelectInput::new(1, None, 2))
+        .await
+        .expect("Database contract events selecting");
+    assert_eq!(
+        page.iter()
+            .map(|event| (event.version, event.name.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(1, "Deposit"), (1, "Withdrawal")]
+    );
+    assert_eq!(page[1].value, json!({ "amount": "10" }));
+    assert_eq!(page[0].id, inserted[0].id);
+    assert_eq!(page[1].id, inserted[2].id);
+
+    let page = client
+        .select_events(EventSelectInput::new(1, Some(page[1].id), 2))
+        .await
+        .expect("Database contract events selecting");
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].version, 2);
+    assert_eq!(page[0].value, json!({ "amount": "50" }));
+
+    assert!(client
+        .select_events(EventSelectInput::new(1, Some(page[0].id), 2))
+        .await
+        .expect("Database contract events selecting")
+        .is_empty());
+
+    client
+        .delete_events()
+        .await
+        .expect("Database contract events deleting");
+    client
+        .delete_snapshots()
+        .await
+        .expect("Database contract storage snapshots deleting");
+    client
+        .delete_fields()
+        .await
+        .expect("Database contract storage deleting");
+    client
+        .delete_contracts()
+        .await
+        .expect("Database contracts deleting");
+    assert!(client
+        .select_contracts()
+        .await
+        .expect("Database contracts selecting")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn ok_memory() {
+    check(MEMORY_URL).await;
+}
+
+#[tokio::test]
+async fn ok_sqlite() {
+    check(SQLITE_URL).await;
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_postgresql() {
+    check(POSTGRESQL_URL).await;
+}
+
+#[tokio::test]
+async fn error_unknown_scheme() {
+    assert!(DatabaseClient::new("mysql://localhost/zinc").await.is_err());
+}
@@ -0,0 +1,70 @@
+//!
+//! The Zandbox contract flow tests.
+//!
+
+use num_old::BigUint;
+use serde_json::json;
+
+use zinc_zksync::MockState;
+
+use crate::tests::sandbox;
+
+/// The initial contract deposit, which must cover the contract change-pubkey fee.
+const DEPOSIT: u64 = 1_000_000_000_000_000;
+
+/// The amount sent to the contract with every call.
+const CALL_AMOUNT: u64 = 100_000_000_000_000;
+
+#[tokio::test]
+async fn ok_publish_initialize_call_query() {
+    let app_data = sandbox::app_data().await;
+    let owner = sandbox::account().await;
+
+    let address = sandbox::publish(&app_data, &owner, "counter", json!({}), DEPOSIT).await;
+    let initial_balance = DEPOSIT - MockState::FEE_CHANGE_PUBKEY;
+    assert_eq!(
+        sandbox::balance(&owner, address, "ETH").await,
+        BigUint::from(initial_balance)
+    );
+
+    let output = sandbox::call(
+        &app_data,
+        &owner,
+        address,
+        "increment",
+        json!({}),
+        "ETH",
+        CALL_AMOUNT,
+    )
+    .await
+    .expect("Contract calling");
+    assert_eq!(output["output"], json!(null));
+
+    let storage = sandbox::query(&app_data, address, None, None, None).await;
+    assert_eq!(storage["calls"], json!("1"));
+    assert_eq!(storage["deposited"], json!(CALL_AMOUNT.to_string()));
+    assert_eq!(
+        storage["balances"][0]["value"],
+        json!((initial_balance + CALL_AMOUNT).to_string())
+    );
+
+    let output = sandbox::query(&app_data, address, Some("get_calls"), Some(json!({})), None).await;
+    assert_eq!(output["output"], json!("1"));
+
+    let storage = sandbox::query(&app_data, address, None, None, Some(0)).await;
+    assert_eq!(storage["calls"], json!("0"));
+    assert_eq!(
+        storage["balances"][0]["value"],
+        json!(initial_balance.to_string())
+    );
+
+    let faucet_amount: BigUint = zinc_const::zandbox::ZKSYNC_MOCK_FAUCET_AMOUNT
+        .parse()
+        .expect(zinc_const::panic::DATA_CONVERSION);
+    let spent = DEPOSIT + CALL_AMOUNT + 2 * MockState::FEE_TRANSFER;
+    assert_eq!(
+        sandbox::balance(&owner, owner.signer.address, "ETH").await,
+        faucet_amount - BigUint::from(spent),
+        "The owner account must be funded only once"
+    );
+}
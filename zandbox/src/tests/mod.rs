@@ -1,12 +1,16 @@
 //!
 //! The Zandbox tests.
 //!
-//! The following applications must be run in separate terminals for the tests to work:
-//! `zksync server`
-//! `zandbox`
+//! The `database` tests use the in-memory and SQLite backends, which require no running services,
+//! except for the PostgreSQL one, which is run with the `integration-tests` feature.
 //!
-//! The following applications must be present in `$PATH`:
-//! `zargo`
+//! The `flow`, `curve`, and `call_queue` tests drive the request handlers with the `zinc-examples`
+//! contracts in the `sandbox`, which serves the local zkSync mock and requires no running services
+//! either.
 //!
 
+mod call_queue;
 mod curve;
+mod database;
+mod flow;
+mod sandbox;
@@ -0,0 +1,365 @@
+//!
+//! The Zandbox offline test sandbox.
+//!
+//! The contracts from the `zinc-examples` directory are compiled in-process and driven through
+//! the request handlers, whereas zkSync is replaced with the local mock served in the background
+//! at the zkSync `localhost` network address.
+//!
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+use std::thread;
+
+use actix_web::web;
+use actix_web::App;
+use actix_web::HttpServer;
+use lazy_static::lazy_static;
+use num_old::BigUint;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+use zksync_eth_signer::PrivateKeySigner;
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::tx::ZkSyncTx;
+use zksync_types::TokenLike;
+use zksync_types::TxFeeTypes;
+
+use zinc_compiler::Source as CompilerSource;
+use zinc_compiler::State as CompilerState;
+use zinc_manifest::Manifest;
+use zinc_zksync::CallRequestBody;
+use zinc_zksync::CallRequestQuery;
+use zinc_zksync::InitializeRequestBody;
+use zinc_zksync::InitializeRequestQuery;
+use zinc_zksync::MockState;
+use zinc_zksync::PublishRequestBody;
+use zinc_zksync::PublishRequestQuery;
+use zinc_zksync::QueryRequestBody;
+use zinc_zksync::QueryRequestQuery;
+use zinc_zksync::Source;
+use zinc_zksync::Transaction;
+
+use crate::controller::contract::call;
+use crate::controller::contract::initialize;
+use crate::controller::contract::post;
+use crate::controller::contract::query;
+use crate::database::client::Client as DatabaseClient;
+use crate::response::Response;
+use crate::shared_data::SharedData;
+
+/// The sandbox network, which is served by the local zkSync mock.
+pub const NETWORK: zksync::Network = zksync::Network::Localhost;
+
+/// The tokens known to the local zkSync mock.
+static TOKENS: [&str; 2] = ["ETH", "DAI"];
+
+/// The token of the initial contract deposit.
+static DEPOSIT_TOKEN: &str = "ETH";
+
+/// The sandbox database, which is kept in memory.
+static DATABASE_URL: &str = "memory://";
+
+/// The example projects directory relative to the Zandbox crate.
+static EXAMPLES_PATH: &str = "../zinc-examples/";
+
+/// The published contract instance name.
+static INSTANCE: &str = "test";
+
+lazy_static! {
+    /// The local zkSync mock state, which is served in the background on the first access.
+    static ref ZKSYNC_MOCK: Arc<Mutex<MockState>> = serve_zksync_mock();
+}
+
+///
+/// The Actix application data of the sandbox Zandbox instance.
+///
+pub type AppData = web::Data<Arc<RwLock<SharedData>>>;
+
+///
+/// Initializes a Zandbox instance with an empty in-memory database.
+///
+pub async fn app_data() -> AppData {
+    let database = DatabaseClient::new(DATABASE_URL)
+        .await
+        .expect("Database client initialization");
+
+    web::Data::new(SharedData::new(database, HashMap::new()).wrap())
+}
+
+///
+/// Creates a zkSync account with a random private key.
+///
+/// The account is funded with the faucet amount of every token once, like the `zargo faucet`
+/// command does, so the subsequent transfers spend the balances like they would on a real network.
+///
+pub async fn account() -> zksync::Wallet<PrivateKeySigner> {
+    let mut private_key = H256::default();
+    private_key.randomize();
+    let address = PackedEthSignature::address_from_private_key(&private_key)
+        .expect(zinc_const::panic::DATA_CONVERSION);
+
+    let mut zksync_mock = ZKSYNC_MOCK
+        .lock()
+        .expect(zinc_const::panic::SYNCHRONIZATION);
+    for token in TOKENS.iter() {
+        zksync_mock
+            .deposit(
+                serde_json::to_string(&address)
+                    .expect(zinc_const::panic::DATA_CONVERSION)
+                    .replace("\"", "")
+                    .as_str(),
+                &json!(token),
+                num::BigUint::from_str(zinc_const::zandbox::ZKSYNC_MOCK_FAUCET_AMOUNT)
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+            )
+            .expect("Mock account funding");
+    }
+    drop(zksync_mock);
+
+    let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
+        address,
+        PrivateKeySigner::new(private_key),
+        NETWORK,
+    )
+    .await
+    .expect("Wallet credentials initialization");
+    zksync::Wallet::new(zksync::Provider::new(NETWORK), wallet_credentials)
+        .await
+        .expect("Wallet initialization")
+}
+
+///
+/// Returns the committed `token` balance of the `address` account.
+///
+pub async fn balance(
+    wallet: &zksync::Wallet<PrivateKeySigner>,
+    address: Address,
+    token: &str,
+) -> BigUint {
+    wallet
+        .provider
+        .account_info(address)
+        .await
+        .expect("Account info retrieving")
+        .committed
+        .balances
+        .get(token)
+        .map(|balance| balance.0.to_owned())
+        .unwrap_or_default()
+}
+
+///
+/// Signs a transfer of `amount` of `token` from the `wallet` account to `recipient`.
+///
+/// The transfer fee is paid by the sender in the same token, like it is done by Zargo.
+///
+pub async fn transfer(
+    wallet: &zksync::Wallet<PrivateKeySigner>,
+    recipient: Address,
+    token: &str,
+    amount: u64,
+) -> Transaction {
+    let token_like = TokenLike::Symbol(token.to_owned());
+    let token = wallet
+        .tokens
+        .resolve(token_like.clone())
+        .expect("Token resolving");
+
+    let amount = zksync::utils::closest_packable_token_amount(&BigUint::from(amount));
+    let fee = wallet
+        .provider
+        .get_tx_fee(TxFeeTypes::Transfer, recipient, token_like)
+        .await
+        .expect("Fee getting")
+        .total_fee;
+    let nonce = wallet
+        .provider
+        .account_info(wallet.signer.address)
+        .await
+        .expect("Account info retrieving")
+        .committed
+        .nonce;
+
+    let (transfer, signature) = wallet
+        .signer
+        .sign_transfer(token, amount, fee, recipient, nonce)
+        .await
+        .expect("Transaction signing");
+
+    Transaction::new(
+        ZkSyncTx::Transfer(Box::new(transfer)),
+        signature.expect(zinc_const::panic::DATA_CONVERSION),
+    )
+}
+
+///
+/// Publishes the `zinc-examples` `project` and initializes it with the ETH `deposit` from `owner`.
+///
+/// Returns the contract address.
+///
+pub async fn publish(
+    app_data: &AppData,
+    owner: &zksync::Wallet<PrivateKeySigner>,
+    project: &str,
+    arguments: JsonValue,
+    deposit: u64,
+) -> Address {
+    let mut project_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    project_path.push(EXAMPLES_PATH);
+    project_path.push(project);
+    let mut source_path = project_path.clone();
+    source_path.push(zinc_const::directory::SOURCE);
+
+    let manifest = Manifest::try_from(&project_path).expect("Manifest reading");
+    let source = Source::try_from_path(&source_path, true).expect("Source reading");
+    let publish_query = PublishRequestQuery::new(
+        manifest.project.name.clone(),
+        manifest.project.version.clone(),
+        INSTANCE.to_owned(),
+        NETWORK,
+    );
+    let bytecode = compile(source_path, manifest);
+
+    let response = post::handle(
+        app_data.clone(),
+        web::Query(publish_query),
+        web::Json(PublishRequestBody::new(source, bytecode, arguments, vec![])),
+    )
+    .await
+    .expect("Contract publishing");
+    let address: Address = serde_json::from_value(data(response)["address"].to_owned())
+        .expect(zinc_const::panic::DATA_CONVERSION);
+
+    let transaction = transfer(owner, address, DEPOSIT_TOKEN, deposit).await;
+    initialize::handle(
+        app_data.clone(),
+        web::Query(InitializeRequestQuery::new(address, NETWORK)),
+        web::Json(InitializeRequestBody::new(transaction)),
+    )
+    .await
+    .expect("Contract initialization");
+
+    address
+}
+
+///
+/// Calls the mutable contract `method`, sending `amount` of `token` from `caller` to the contract.
+///
+pub async fn call(
+    app_data: &AppData,
+    caller: &zksync::Wallet<PrivateKeySigner>,
+    address: Address,
+    method: &str,
+    arguments: JsonValue,
+    token: &str,
+    amount: u64,
+) -> Result<JsonValue, call::error::Error> {
+    let transaction = transfer(caller, address, token, amount).await;
+
+    call::handle(
+        app_data.clone(),
+        web::Query(CallRequestQuery::new(address, method.to_owned(), NETWORK)),
+        web::Json(CallRequestBody::new(arguments, transaction)),
+    )
+    .await
+    .map(data)
+}
+
+///
+/// Queries the contract storage or calls the immutable `method` if it is specified.
+///
+/// The `version` storage snapshot is used instead of the latest storage if it is specified.
+///
+pub async fn query(
+    app_data: &AppData,
+    address: Address,
+    method: Option<&str>,
+    arguments: Option<JsonValue>,
+    version: Option<u32>,
+) -> JsonValue {
+    let response = query::handle(
+        app_data.clone(),
+        web::Query(QueryRequestQuery::new(
+            address,
+            method.map(|method| method.to_owned()),
+            NETWORK,
+            version,
+        )),
+        web::Json(QueryRequestBody::new(arguments)),
+    )
+    .await
+    .expect("Contract querying");
+
+    data(response)
+}
+
+///
+/// Compiles the contract project from `source_path` with the Zinc compiler library.
+///
+fn compile(source_path: PathBuf, manifest: Manifest) -> Vec<u8> {
+    thread::Builder::new()
+        .stack_size(zinc_const::limit::COMPILER_STACK_SIZE)
+        .spawn(move || {
+            let source = CompilerSource::try_from_entry(&source_path).expect("Source reading");
+            let state = source.compile(manifest).expect("Contract compiling");
+            CompilerState::unwrap_rc(state)
+                .into_application(true)
+                .into_build()
+                .bytecode
+        })
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .join()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+}
+
+///
+/// Extracts the data payload the handler `response` would send to the client.
+///
+fn data<T, E>(response: Response<T, E>) -> JsonValue
+where
+    T: Serialize,
+    E: Serialize + actix_web::ResponseError,
+{
+    serde_json::to_value(response).expect(zinc_const::panic::DATA_CONVERSION)["data"].to_owned()
+}
+
+///
+/// Serves the local zkSync mock on a separate Actix system thread.
+///
+/// Returns the mock state after the server socket has been bound.
+///
+fn serve_zksync_mock() -> Arc<Mutex<MockState>> {
+    let state = Arc::new(Mutex::new(MockState::new()));
+
+    let server_state = state.clone();
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let system = actix_rt::System::new(zinc_const::app_name::ZANDBOX);
+        HttpServer::new(move || {
+            App::new()
+                .data(server_state.clone())
+                .configure(crate::mock::configure)
+        })
+        .bind(format!(
+            "{}:{}",
+            zinc_const::zandbox::ZKSYNC_MOCK_HOST,
+            zinc_const::zandbox::ZKSYNC_MOCK_PORT
+        ))
+        .expect("Local zkSync mock binding")
+        .run();
+        sender.send(()).expect(zinc_const::panic::SYNCHRONIZATION);
+        system.run()
+    });
+    receiver.recv().expect(zinc_const::panic::SYNCHRONIZATION);
+
+    state
+}
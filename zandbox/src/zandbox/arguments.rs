@@ -21,11 +21,11 @@ pub struct Arguments {
     #[structopt(short = "p", long = "http-port")]
     pub http_port: Option<u16>,
 
-    /// The PostgreSQL connection string.
-    #[structopt(short = "d", long = "postgresql")]
-    pub postgresql_uri: String,
+    /// The database connection string, e.g. `postgres://...`, `sqlite://...`, or `memory://`.
+    #[structopt(short = "d", long = "database", alias = "postgresql")]
+    pub database_uri: String,
 
-    /// The zkSync network identifier.
+    /// The zkSync network identifier, or `localhost-mock` to serve a local zkSync mock.
     #[structopt(short = "n", long = "network")]
     pub network: String,
 }
@@ -7,6 +7,8 @@ mod error;
 
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::Mutex;
 
 use actix_web::middleware;
 use actix_web::web;
@@ -20,6 +22,7 @@ use zksync_eth_signer::PrivateKeySigner;
 use zksync_types::AccountId;
 
 use zinc_build::Application as BuildApplication;
+use zinc_zksync::MockState;
 
 use zandbox::ContractSelectAllOutput;
 use zandbox::ContractStorage;
@@ -42,18 +45,37 @@ async fn main() -> Result<(), Error> {
 
     log::info!("Zandbox server started");
 
-    let network =
-        zksync::Network::from_str(args.network.as_str()).map_err(Error::InvalidNetwork)?;
-
-    log::info!("Initializing the PostgreSQL client");
-    let postgresql = DatabaseClient::new(args.postgresql_uri.as_str()).await?;
+    let (network, mock_server) = if args.network == zinc_const::zandbox::NETWORK_LOCALHOST_MOCK {
+        log::info!("Starting the local zkSync mock");
+        let state = Arc::new(Mutex::new(MockState::new()));
+        let server = HttpServer::new(move || {
+            App::new()
+                .wrap(middleware::Logger::default())
+                .data(state.clone())
+                .configure(zandbox::configure_zksync_mock)
+        })
+        .bind(format!(
+            "{}:{}",
+            zinc_const::zandbox::ZKSYNC_MOCK_HOST,
+            zinc_const::zandbox::ZKSYNC_MOCK_PORT
+        ))
+        .map_err(Error::ServerBinding)?
+        .run();
+
+        (zksync::Network::Localhost, Some(server))
+    } else {
+        let network =
+            zksync::Network::from_str(args.network.as_str()).map_err(Error::InvalidNetwork)?;
+
+        (network, None)
+    };
+
+    log::info!("Initializing the database client");
+    let database = DatabaseClient::new(args.database_uri.as_str()).await?;
 
     log::info!("Loading the compiled contracts from the database");
-    let database_data: Vec<ContractSelectAllOutput> = postgresql
-        .select_contracts()
-        .await?
-        .into_par_iter()
-        .collect();
+    let database_data: Vec<ContractSelectAllOutput> =
+        database.select_contracts().await?.into_par_iter().collect();
 
     let mut contracts = HashMap::with_capacity(database_data.len());
     for contract in database_data.into_iter() {
@@ -88,7 +110,7 @@ async fn main() -> Result<(), Error> {
         .await?;
         let wallet = zksync::Wallet::new(provider, wallet_credentials).await?;
 
-        let database_fields = postgresql
+        let database_fields = database
             .select_fields(FieldSelectInput::new(contract.account_id as AccountId))
             .await?;
 
@@ -118,7 +140,7 @@ async fn main() -> Result<(), Error> {
         );
     }
 
-    let data = SharedData::new(postgresql, contracts).wrap();
+    let data = SharedData::new(database, contracts).wrap();
 
     HttpServer::new(move || {
         App::new()
@@ -139,6 +161,11 @@ async fn main() -> Result<(), Error> {
     .await
     .map_err(Error::ServerRuntime)?;
 
+    if let Some(mock_server) = mock_server {
+        log::info!("Stopping the local zkSync mock");
+        mock_server.stop(true).await;
+    }
+
     log::info!("Zandbox server finished");
     Ok(())
 }
@@ -41,6 +41,10 @@ pub struct Command {
     /// Builds the release version.
     #[structopt(long = "release")]
     pub is_release: bool,
+
+    /// The compiler errors and warnings format, either `human` or `json`.
+    #[structopt(long = "message-format", default_value = "human")]
+    pub message_format: String,
 }
 
 impl Command {
@@ -87,6 +91,7 @@ impl Command {
                 &source_directory_path,
                 &binary_path,
                 false,
+                Some(self.message_format.as_str()),
             )
             .map_err(Error::Compiler)?;
         } else {
@@ -99,6 +104,7 @@ impl Command {
                 &source_directory_path,
                 &binary_path,
                 false,
+                Some(self.message_format.as_str()),
             )
             .map_err(Error::Compiler)?;
         }
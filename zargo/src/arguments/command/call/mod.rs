@@ -80,9 +80,7 @@ impl Command {
             .parse()
             .map_err(Error::InvalidContractAddress)?;
 
-        let network = zksync::Network::from_str(self.network.as_str())
-            .map(Network::from)
-            .map_err(Error::NetworkInvalid)?;
+        let network = Network::from_str(self.network.as_str()).map_err(Error::NetworkInvalid)?;
 
         let url = network
             .try_into_url()
@@ -144,6 +142,17 @@ impl Command {
         let signer_address = PackedEthSignature::address_from_private_key(&signer_private_key)
             .map_err(Error::SenderAddressDeriving)?;
 
+        let msg = input
+            .inner
+            .as_object()
+            .ok_or(Error::InvalidInputData)?
+            .get("msg")
+            .cloned()
+            .ok_or(Error::InvalidInputData)?;
+        let msg = TransactionMsg::try_from(&msg)
+            .map_err(TransactionError::Parsing)
+            .map_err(Error::Transaction)?;
+
         let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
             signer_address,
             PrivateKeySigner::new(signer_private_key),
@@ -155,16 +164,6 @@ impl Command {
             .await
             .map_err(Error::WalletInitialization)?;
 
-        let msg = input
-            .inner
-            .as_object()
-            .ok_or(Error::InvalidInputData)?
-            .get("msg")
-            .cloned()
-            .ok_or(Error::InvalidInputData)?;
-        let msg = TransactionMsg::try_from(&msg)
-            .map_err(TransactionError::Parsing)
-            .map_err(Error::Transaction)?;
         let transaction = crate::transaction::try_into_zksync(msg.clone(), &wallet, None)
             .await
             .map_err(Error::Transaction)?;
@@ -7,8 +7,12 @@ use failure::Fail;
 use crate::arguments::command::build::error::Error as BuildCommandError;
 use crate::arguments::command::call::error::Error as CallCommandError;
 use crate::arguments::command::clean::error::Error as CleanCommandError;
+use crate::arguments::command::export_verifier::error::Error as ExportVerifierCommandError;
+use crate::arguments::command::faucet::error::Error as FaucetCommandError;
+use crate::arguments::command::fmt::error::Error as FmtCommandError;
 use crate::arguments::command::init::error::Error as InitCommandError;
 use crate::arguments::command::new::error::Error as NewCommandError;
+use crate::arguments::command::profile::error::Error as ProfileCommandError;
 use crate::arguments::command::proof_check::error::Error as ProofCheckCommandError;
 use crate::arguments::command::prove::error::Error as ProveCommandError;
 use crate::arguments::command::publish::error::Error as PublishCommandError;
@@ -35,12 +39,18 @@ pub enum Error {
     /// The `clean` command error.
     #[fail(display = "{}", _0)]
     Clean(CleanCommandError),
+    /// The `fmt` command error.
+    #[fail(display = "{}", _0)]
+    Fmt(FmtCommandError),
     /// The `run` command error.
     #[fail(display = "{}", _0)]
     Run(RunCommandError),
     /// The `test` command error.
     #[fail(display = "{}", _0)]
     Test(TestCommandError),
+    /// The `profile` command error.
+    #[fail(display = "{}", _0)]
+    Profile(ProfileCommandError),
     /// The `setup` command error.
     #[fail(display = "{}", _0)]
     Setup(SetupCommandError),
@@ -53,6 +63,12 @@ pub enum Error {
     /// The `proof-check` command error.
     #[fail(display = "{}", _0)]
     ProofCheck(ProofCheckCommandError),
+    /// The `export-verifier` command error.
+    #[fail(display = "{}", _0)]
+    ExportVerifier(ExportVerifierCommandError),
+    /// The `faucet` command error.
+    #[fail(display = "{}", _0)]
+    Faucet(FaucetCommandError),
     /// The `publish` command error.
     #[fail(display = "{}", _0)]
     Publish(PublishCommandError),
@@ -88,6 +104,12 @@ impl From<CleanCommandError> for Error {
     }
 }
 
+impl From<FmtCommandError> for Error {
+    fn from(inner: FmtCommandError) -> Self {
+        Self::Fmt(inner)
+    }
+}
+
 impl From<RunCommandError> for Error {
     fn from(inner: RunCommandError) -> Self {
         Self::Run(inner)
@@ -100,6 +122,12 @@ impl From<TestCommandError> for Error {
     }
 }
 
+impl From<ProfileCommandError> for Error {
+    fn from(inner: ProfileCommandError) -> Self {
+        Self::Profile(inner)
+    }
+}
+
 impl From<SetupCommandError> for Error {
     fn from(inner: SetupCommandError) -> Self {
         Self::Setup(inner)
@@ -124,6 +152,18 @@ impl From<ProofCheckCommandError> for Error {
     }
 }
 
+impl From<ExportVerifierCommandError> for Error {
+    fn from(inner: ExportVerifierCommandError) -> Self {
+        Self::ExportVerifier(inner)
+    }
+}
+
+impl From<FaucetCommandError> for Error {
+    fn from(inner: FaucetCommandError) -> Self {
+        Self::Faucet(inner)
+    }
+}
+
 impl From<PublishCommandError> for Error {
     fn from(inner: PublishCommandError) -> Self {
         Self::Publish(inner)
@@ -0,0 +1,68 @@
+//!
+//! The Zargo package manager `export-verifier` subcommand.
+//!
+
+use std::io;
+
+use failure::Fail;
+use num::BigInt;
+
+use crate::error::file::Error as FileError;
+
+///
+/// The Zargo package manager `export-verifier` subcommand error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The manifest file error.
+    #[fail(display = "manifest {}", _0)]
+    Manifest(zinc_manifest::Error),
+    /// The contract method to call is missing.
+    #[fail(display = "contract method to call must be specified")]
+    MethodMissing,
+    /// The contract method does not exist.
+    #[fail(display = "contract method `{}` does not exist", _0)]
+    MethodNotFound(String),
+    /// The keys have been generated with a backend, which has no Solidity verifier.
+    #[fail(
+        display = "the Solidity verifier is only available for the `groth16` backend, but found `{}`",
+        _0
+    )]
+    BackendUnsupported(zinc_vm::Backend),
+    /// The backend record file error.
+    #[fail(display = "backend record file {}", _0)]
+    BackendFile(FileError),
+    /// The verifying key file error.
+    #[fail(display = "verifying key file {}", _0)]
+    VerifyingKeyFile(FileError),
+    /// The verifying key decoding error.
+    #[fail(display = "verifying key decoding: {}", _0)]
+    VerifyingKeyDecoding(io::Error),
+    /// The verifier contract file error.
+    #[fail(display = "verifier file {}", _0)]
+    VerifierFile(FileError),
+    /// The bytecode binary file error.
+    #[fail(display = "bytecode binary file {}", _0)]
+    BytecodeFile(FileError),
+    /// The bytecode binary decoding error.
+    #[fail(display = "bytecode binary decoding: {}", _0)]
+    BytecodeDecoding(String),
+    /// The output file error.
+    #[fail(display = "output file {}", _0)]
+    OutputFile(FileError<serde_json::Error>),
+    /// The output file data does not match the application output type.
+    #[fail(display = "output data is invalid: {}", _0)]
+    OutputInvalid(zinc_build::ValueError),
+    /// The public input value does not fit into the scalar field.
+    #[fail(display = "public input value `{}` overflows the scalar field", _0)]
+    OutputOverflow(BigInt),
+    /// The proof reading error.
+    #[fail(display = "proof reading: {}", _0)]
+    ProofReading(io::Error),
+    /// The proof hexadecimal decoding error.
+    #[fail(display = "proof decoding: {}", _0)]
+    ProofHexDecoding(rustc_hex::FromHexError),
+    /// The proof decoding error.
+    #[fail(display = "proof decoding: {}", _0)]
+    ProofDecoding(io::Error),
+}
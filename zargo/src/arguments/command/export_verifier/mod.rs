@@ -0,0 +1,183 @@
+//!
+//! The Zargo package manager `export-verifier` subcommand.
+//!
+
+pub mod error;
+
+use std::convert::TryFrom;
+use std::fs;
+use std::io::Read;
+use std::path::PathBuf;
+
+use colored::Colorize;
+use rustc_hex::FromHex;
+use structopt::StructOpt;
+
+use franklin_crypto::bellman::groth16::Proof as Groth16Proof;
+use franklin_crypto::bellman::groth16::VerifyingKey as Groth16VerifyingKey;
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_build::Application as BuildApplication;
+use zinc_build::Value as BuildValue;
+use zinc_manifest::Manifest;
+use zinc_manifest::ProjectType;
+use zinc_vm::Backend;
+
+use crate::error::file::Error as FileError;
+use crate::ethereum::proof::Proof;
+use crate::ethereum::verifier::Verifier;
+use crate::ethereum::verifying_key::VerifyingKey;
+use crate::project::build::bytecode::Bytecode as BytecodeFile;
+use crate::project::build::Directory as BuildDirectory;
+use crate::project::data::verifying_key::VerifyingKey as VerifyingKeyFile;
+use crate::project::data::Directory as DataDirectory;
+
+use self::error::Error;
+
+///
+/// The Zargo package manager `export-verifier` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Exports the Solidity verifier contract")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Prints the `verifyProof` calldata for the proof passed via stdin instead.
+    #[structopt(long = "calldata")]
+    pub is_calldata: bool,
+
+    /// The contract method the proof is generated for. Only for contracts.
+    #[structopt(long = "method")]
+    pub method: Option<String>,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub fn execute(self) -> Result<(), Error> {
+        let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::Manifest)?;
+
+        match manifest.project.r#type {
+            ProjectType::Contract if self.is_calldata && self.method.is_none() => {
+                return Err(Error::MethodMissing)
+            }
+            _ => {}
+        }
+
+        let mut manifest_path = self.manifest_path.clone();
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let data_directory_path = DataDirectory::path(&manifest_path);
+        let mut verifying_key_path = data_directory_path.clone();
+        verifying_key_path.push(zinc_const::file_name::VERIFYING_KEY.to_owned());
+
+        let backend = Backend::read_record(&verifying_key_path).map_err(|error| {
+            Error::BackendFile(FileError::Reading(
+                Backend::record_path(&verifying_key_path)
+                    .to_string_lossy()
+                    .to_string(),
+                error,
+            ))
+        })?;
+        match backend {
+            Backend::Groth16 => {}
+            backend => return Err(Error::BackendUnsupported(backend)),
+        }
+
+        if self.is_calldata {
+            return self.calldata(&manifest_path, &data_directory_path);
+        }
+
+        let verifying_key =
+            VerifyingKeyFile::try_from(&verifying_key_path).map_err(Error::VerifyingKeyFile)?;
+        let verifying_key = Groth16VerifyingKey::<Bn256>::read(verifying_key.inner.as_slice())
+            .map_err(Error::VerifyingKeyDecoding)?;
+
+        eprintln!(
+            "   {} the verifier contract to `{}`",
+            "Exporting".bright_green(),
+            data_directory_path.to_string_lossy(),
+        );
+
+        Verifier::new(VerifyingKey::from(&verifying_key))
+            .write_to(&data_directory_path)
+            .map_err(Error::VerifierFile)?;
+
+        Ok(())
+    }
+
+    ///
+    /// Encodes the proof passed via stdin and the public output data as the verifier calldata.
+    ///
+    fn calldata(self, manifest_path: &PathBuf, data_directory_path: &PathBuf) -> Result<(), Error> {
+        let mut proof = String::new();
+        std::io::stdin()
+            .read_to_string(&mut proof)
+            .map_err(Error::ProofReading)?;
+        let proof: Vec<u8> = proof.trim().from_hex().map_err(Error::ProofHexDecoding)?;
+        let proof = Groth16Proof::<Bn256>::read(proof.as_slice()).map_err(Error::ProofDecoding)?;
+
+        let build_directory_path = BuildDirectory::path(manifest_path);
+        let mut binary_path = build_directory_path;
+        binary_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY,
+            zinc_const::extension::BINARY
+        ));
+        let bytecode = BytecodeFile::try_from(&binary_path).map_err(Error::BytecodeFile)?;
+        let application = BuildApplication::try_from_slice(bytecode.inner.as_slice())
+            .map_err(Error::BytecodeDecoding)?;
+
+        let output_type = match application {
+            BuildApplication::Circuit(circuit) => circuit.output,
+            BuildApplication::Contract(contract) => {
+                let method_name = self.method.ok_or(Error::MethodMissing)?;
+                let method = contract
+                    .methods
+                    .get(method_name.as_str())
+                    .cloned()
+                    .ok_or(Error::MethodNotFound(method_name))?;
+                if method.is_mutable {
+                    method.output.into_mutable_method_output()
+                } else {
+                    method.output
+                }
+            }
+        };
+
+        let output_file_name = format!(
+            "{}.{}",
+            zinc_const::file_name::OUTPUT,
+            zinc_const::extension::JSON,
+        );
+        let mut output_path = data_directory_path.to_owned();
+        output_path.push(output_file_name.as_str());
+        let output = fs::read_to_string(&output_path).map_err(|error| {
+            Error::OutputFile(FileError::Reading(output_file_name.clone(), error))
+        })?;
+        let output = serde_json::from_str(output.as_str())
+            .map_err(|error| Error::OutputFile(FileError::Parsing(output_file_name, error)))?;
+        let output =
+            BuildValue::try_from_typed_json(output, output_type).map_err(Error::OutputInvalid)?;
+
+        let public_input =
+            Proof::encode_input(output.into_flat_values()).map_err(Error::OutputOverflow)?;
+
+        println!("{}", Proof::from(&proof).calldata(public_input.as_slice()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+//!
+//! The Zargo package manager `faucet` subcommand.
+//!
+
+use failure::Fail;
+
+use crate::error::directory::Error as DirectoryError;
+use crate::error::file::Error as FileError;
+
+///
+/// The Zargo package manager `faucet` subcommand error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The invalid network error.
+    #[fail(display = "invalid network name: {}", _0)]
+    NetworkInvalid(String),
+    /// The network is not the local zkSync mock.
+    #[fail(display = "the faucet is only available in the `{}` network", _0)]
+    NetworkNotMock(&'static str),
+    /// The manifest file error.
+    #[fail(display = "manifest {}", _0)]
+    Manifest(zinc_manifest::Error),
+    /// The project template, keys, and other auxiliary data directory error.
+    #[fail(display = "data directory {}", _0)]
+    DataDirectory(DirectoryError),
+    /// The private key file error.
+    #[fail(display = "private key file {}", _0)]
+    PrivateKeyFile(FileError),
+    /// The sender private key is invalid.
+    #[fail(display = "sender private key is invalid: {}", _0)]
+    SenderPrivateKeyInvalid(rustc_hex::FromHexError),
+    /// The sender address cannot be derived from the private key.
+    #[fail(
+        display = "could not derive the ETH address from the private key: {}",
+        _0
+    )]
+    SenderAddressDeriving(anyhow::Error),
+    /// The local zkSync mock deposit error.
+    #[fail(display = "mock deposit: {}", _0)]
+    MockDeposit(String),
+}
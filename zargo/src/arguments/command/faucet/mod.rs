@@ -0,0 +1,115 @@
+//!
+//! The Zargo package manager `faucet` subcommand.
+//!
+
+pub mod error;
+
+use std::convert::TryFrom;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use colored::Colorize;
+use serde_json::json;
+use structopt::StructOpt;
+
+use zksync::web3::types::H256;
+use zksync_types::tx::PackedEthSignature;
+
+use zinc_manifest::Manifest;
+
+use crate::network::Network;
+use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
+use crate::project::data::Directory as DataDirectory;
+
+use self::error::Error;
+
+///
+/// The Zargo package manager `faucet` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Funds the project account in the local zkSync mock")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Sets the network name, which must be the local zkSync mock.
+    #[structopt(long = "network", default_value = "localhost-mock")]
+    pub network: String,
+
+    /// Sets the funded token.
+    #[structopt(long = "token", default_value = "ETH")]
+    pub token: String,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    /// The project account is funded once, so the `publish` and `call` commands spend the
+    /// faucet amount like they would spend a real balance.
+    ///
+    pub async fn execute(self) -> Result<(), Error> {
+        let network = Network::from_str(self.network.as_str()).map_err(Error::NetworkInvalid)?;
+        if !network.is_mock() {
+            return Err(Error::NetworkNotMock(
+                zinc_const::zandbox::NETWORK_LOCALHOST_MOCK,
+            ));
+        }
+
+        let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::Manifest)?;
+
+        let mut manifest_path = self.manifest_path;
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        DataDirectory::create(&manifest_path).map_err(Error::DataDirectory)?;
+        let data_directory_path = DataDirectory::path(&manifest_path);
+        let mut private_key_path = data_directory_path.clone();
+        private_key_path.push(zinc_const::file_name::PRIVATE_KEY.to_owned());
+
+        if !PrivateKeyFile::exists_at(&data_directory_path) {
+            PrivateKeyFile::default()
+                .write_to(&data_directory_path)
+                .map_err(Error::PrivateKeyFile)?;
+        }
+
+        let private_key =
+            PrivateKeyFile::try_from(&private_key_path).map_err(Error::PrivateKeyFile)?;
+        let signer_private_key: H256 = private_key
+            .inner
+            .parse()
+            .map_err(Error::SenderPrivateKeyInvalid)?;
+        let signer_address = PackedEthSignature::address_from_private_key(&signer_private_key)
+            .map_err(Error::SenderAddressDeriving)?;
+
+        eprintln!(
+            "     {} the account {} of `{} v{}` with {} {} on network `{}`",
+            "Funding".bright_green(),
+            serde_json::to_string(&signer_address)
+                .expect(zinc_const::panic::DATA_CONVERSION)
+                .replace("\"", ""),
+            manifest.project.name,
+            manifest.project.version,
+            zinc_const::zandbox::ZKSYNC_MOCK_FAUCET_AMOUNT,
+            self.token,
+            network,
+        );
+
+        network
+            .mock_deposit(signer_address, json!(self.token))
+            .await
+            .map_err(Error::MockDeposit)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+//!
+//! The Zargo package manager `fmt` subcommand.
+//!
+
+use failure::Fail;
+
+use crate::error::file::Error as FileError;
+
+///
+/// The Zargo package manager `fmt` subcommand error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The manifest file error.
+    #[fail(display = "manifest {}", _0)]
+    Manifest(zinc_manifest::Error),
+    /// The project source code reading error.
+    #[fail(display = "source code {}", _0)]
+    Source(zinc_source::Error),
+    /// The source code file formatting error.
+    #[fail(display = "`{}` formatting: {}", _0, _1)]
+    Formatting(String, zinc_formatter::Error),
+    /// The formatted source code file writing error.
+    #[fail(display = "source code file {}", _0)]
+    SourceFile(FileError),
+    /// Some source code files are not formatted. Only in the check mode.
+    #[fail(display = "{} file(s) must be formatted", _0)]
+    Unformatted(usize),
+}
@@ -0,0 +1,114 @@
+//!
+//! The Zargo package manager `fmt` subcommand.
+//!
+
+pub mod error;
+
+use std::convert::TryFrom;
+use std::fs;
+use std::path::PathBuf;
+
+use colored::Colorize;
+use structopt::StructOpt;
+
+use zinc_formatter::Formatter;
+use zinc_manifest::Manifest;
+use zinc_source::File as SourceFile;
+use zinc_source::Source;
+
+use crate::error::file::Error as FileError;
+use crate::project::source::Directory as SourceDirectory;
+
+use self::error::Error;
+
+///
+/// The Zargo package manager `fmt` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Formats the project source code")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Checks the formatting without writing the files, and fails if any file must be formatted.
+    #[structopt(long = "check")]
+    pub is_check: bool,
+
+    /// The maximal line width, which is 100 by default.
+    #[structopt(long = "width")]
+    pub width: Option<usize>,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub fn execute(self) -> Result<(), Error> {
+        let _manifest = Manifest::try_from(&self.manifest_path).map_err(Error::Manifest)?;
+
+        let mut manifest_path = self.manifest_path;
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let source_directory_path = SourceDirectory::path(&manifest_path);
+        let source = Source::try_from_path(&source_directory_path, true).map_err(Error::Source)?;
+
+        let mut files = Vec::new();
+        Self::collect(source, &mut files);
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let formatter = self.width.map(Formatter::new).unwrap_or_default();
+        let mut unformatted = 0;
+        for file in files.into_iter() {
+            let path = PathBuf::from(file.path.as_str());
+            let formatted = formatter
+                .format(&path, file.code.as_str())
+                .map_err(|error| Error::Formatting(file.path.clone(), error))?;
+            if formatted == file.code {
+                continue;
+            }
+
+            if self.is_check {
+                eprintln!("{} `{}`", "Unformatted".bright_red(), file.path);
+                unformatted += 1;
+            } else {
+                if self.verbosity > 0 {
+                    eprintln!("  {} `{}`", "Formatting".bright_green(), file.path);
+                }
+                fs::write(&path, formatted)
+                    .map_err(|error| FileError::Writing(file.path, error))
+                    .map_err(Error::SourceFile)?;
+            }
+        }
+
+        if unformatted > 0 {
+            return Err(Error::Unformatted(unformatted));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Collects the source code files from the directory tree.
+    ///
+    fn collect(source: Source, files: &mut Vec<SourceFile>) {
+        match source {
+            Source::File(file) => files.push(file),
+            Source::Directory(directory) => {
+                for (_name, module) in directory.modules.into_iter() {
+                    Self::collect(module, files);
+                }
+            }
+        }
+    }
+}
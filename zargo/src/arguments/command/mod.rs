@@ -6,8 +6,12 @@ pub mod build;
 pub mod call;
 pub mod clean;
 pub mod error;
+pub mod export_verifier;
+pub mod faucet;
+pub mod fmt;
 pub mod init;
 pub mod new;
+pub mod profile;
 pub mod proof_check;
 pub mod prove;
 pub mod publish;
@@ -23,8 +27,12 @@ use self::build::Command as BuildCommand;
 use self::call::Command as CallCommand;
 use self::clean::Command as CleanCommand;
 use self::error::Error;
+use self::export_verifier::Command as ExportVerifierCommand;
+use self::faucet::Command as FaucetCommand;
+use self::fmt::Command as FmtCommand;
 use self::init::Command as InitCommand;
 use self::new::Command as NewCommand;
+use self::profile::Command as ProfileCommand;
 use self::proof_check::Command as ProofCheckCommand;
 use self::prove::Command as ProveCommand;
 use self::publish::Command as PublishCommand;
@@ -48,10 +56,14 @@ pub enum Command {
     Build(BuildCommand),
     /// Removes the project build artifacts.
     Clean(CleanCommand),
+    /// Formats the project source code.
+    Fmt(FmtCommand),
     /// Runs the project and prints its output.
     Run(RunCommand),
     /// Runs the project unit tests.
     Test(TestCommand),
+    /// Runs the project and prints its constraint profile.
+    Profile(ProfileCommand),
     /// Generates a pair of proving and verifying keys.
     Setup(SetupCommand),
     /// Generates the zero-knowledge proof for given input data.
@@ -60,6 +72,10 @@ pub enum Command {
     Verify(VerifyCommand),
     /// Runs the full project building, running, trusted setup, proving & verifying sequence.
     ProofCheck(ProofCheckCommand),
+    /// Exports the Solidity verifier contract.
+    ExportVerifier(ExportVerifierCommand),
+    /// Funds the project account in the local zkSync mock.
+    Faucet(FaucetCommand),
     /// Uploads the smart contract to the specified network.
     Publish(PublishCommand),
     /// Queries a contract storage or calls an immutable method.
@@ -78,12 +94,16 @@ impl Command {
             Self::Init(inner) => inner.execute()?,
             Self::Build(inner) => inner.execute()?,
             Self::Clean(inner) => inner.execute()?,
+            Self::Fmt(inner) => inner.execute()?,
             Self::Run(inner) => inner.execute()?,
             Self::Test(inner) => inner.execute()?,
+            Self::Profile(inner) => inner.execute()?,
             Self::Setup(inner) => inner.execute()?,
             Self::Prove(inner) => inner.execute()?,
             Self::Verify(inner) => inner.execute()?,
             Self::ProofCheck(inner) => inner.execute()?,
+            Self::ExportVerifier(inner) => inner.execute()?,
+            Self::Faucet(inner) => inner.execute().await?,
             Self::Publish(inner) => inner.execute().await?,
             Self::Query(inner) => inner.execute().await?,
             Self::Call(inner) => inner.execute().await?,
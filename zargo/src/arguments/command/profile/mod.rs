@@ -0,0 +1,146 @@
+//!
+//! The Zargo package manager `profile` subcommand.
+//!
+
+pub mod error;
+
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use zinc_manifest::Manifest;
+use zinc_manifest::ProjectType;
+
+use crate::executable::compiler::Compiler;
+use crate::executable::virtual_machine::VirtualMachine;
+use crate::project::build::Directory as BuildDirectory;
+use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
+use crate::project::data::Directory as DataDirectory;
+use crate::project::source::Directory as SourceDirectory;
+
+use self::error::Error;
+
+///
+/// The Zargo package manager `profile` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Runs the project and prints its constraint profile")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// The contract method to call. Only for contracts.
+    #[structopt(long = "method")]
+    pub method: Option<String>,
+
+    /// Profiles the release build.
+    #[structopt(long = "release")]
+    pub is_release: bool,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub fn execute(self) -> Result<(), Error> {
+        let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::Manifest)?;
+
+        match manifest.project.r#type {
+            ProjectType::Contract if self.method.is_none() => return Err(Error::MethodMissing),
+            _ => {}
+        }
+
+        let mut manifest_path = self.manifest_path.clone();
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let source_directory_path = SourceDirectory::path(&manifest_path);
+
+        DataDirectory::create(&manifest_path).map_err(Error::DataDirectory)?;
+        let data_directory_path = DataDirectory::path(&manifest_path);
+        let mut input_path = data_directory_path.clone();
+        input_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::INPUT,
+            zinc_const::extension::JSON,
+        ));
+        if self.method.is_some() && !PrivateKeyFile::exists_at(&data_directory_path) {
+            PrivateKeyFile::default()
+                .write_to(&data_directory_path)
+                .map_err(Error::PrivateKeyFile)?;
+        }
+
+        BuildDirectory::create(&manifest_path).map_err(Error::BuildDirectory)?;
+        let build_directory_path = BuildDirectory::path(&manifest_path);
+        let mut binary_path = build_directory_path.clone();
+        binary_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY,
+            zinc_const::extension::BINARY
+        ));
+        let mut folded_path = build_directory_path;
+        folded_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::PROFILE,
+            zinc_const::extension::FOLDED
+        ));
+
+        if self.is_release {
+            Compiler::build_release(
+                self.verbosity,
+                manifest.project.name.as_str(),
+                manifest.project.version.as_str(),
+                &manifest_path,
+                &data_directory_path,
+                &source_directory_path,
+                &binary_path,
+                false,
+                None,
+            )
+            .map_err(Error::Compiler)?;
+        } else {
+            Compiler::build_debug(
+                self.verbosity,
+                manifest.project.name.as_str(),
+                manifest.project.version.as_str(),
+                &manifest_path,
+                &data_directory_path,
+                &source_directory_path,
+                &binary_path,
+                false,
+                None,
+            )
+            .map_err(Error::Compiler)?;
+        }
+
+        match self.method {
+            Some(method) => VirtualMachine::profile_contract(
+                self.verbosity,
+                &binary_path,
+                &input_path,
+                &folded_path,
+                method.as_str(),
+            ),
+            None => VirtualMachine::profile_circuit(
+                self.verbosity,
+                &binary_path,
+                &input_path,
+                &folded_path,
+            ),
+        }
+        .map_err(Error::VirtualMachine)?;
+
+        Ok(())
+    }
+}
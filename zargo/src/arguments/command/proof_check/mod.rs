@@ -48,6 +48,15 @@ pub struct Command {
     /// Executes the release build.
     #[structopt(long = "release")]
     pub is_release: bool,
+
+    /// The proving backend, either `groth16` or `plonk`.
+    #[structopt(long = "backend", default_value = "groth16")]
+    pub backend: String,
+
+    /// The path to the universal reference string file in the monomial form.
+    /// Required by the `plonk` backend.
+    #[structopt(long = "srs", parse(from_os_str))]
+    pub srs_path: Option<PathBuf>,
 }
 
 impl Command {
@@ -112,6 +121,7 @@ impl Command {
                 &source_directory_path,
                 &binary_path,
                 false,
+                None,
             )
             .map_err(Error::Compiler)?;
         } else {
@@ -124,6 +134,7 @@ impl Command {
                 &source_directory_path,
                 &binary_path,
                 false,
+                None,
             )
             .map_err(Error::Compiler)?;
         }
@@ -145,6 +156,8 @@ impl Command {
                     method.as_str(),
                     &proving_key_path,
                     &verifying_key_path,
+                    self.backend.as_str(),
+                    self.srs_path.as_ref(),
                 )
                 .map_err(Error::VirtualMachineSetup)?;
 
@@ -156,6 +169,7 @@ impl Command {
                     method.as_str(),
                     &proving_key_path,
                     &verifying_key_path,
+                    self.backend.as_str(),
                 )
                 .map_err(Error::VirtualMachineProveAndVerify)?;
             }
@@ -173,6 +187,8 @@ impl Command {
                     &binary_path,
                     &proving_key_path,
                     &verifying_key_path,
+                    self.backend.as_str(),
+                    self.srs_path.as_ref(),
                 )
                 .map_err(Error::VirtualMachineSetup)?;
 
@@ -183,6 +199,7 @@ impl Command {
                     &output_path,
                     &proving_key_path,
                     &verifying_key_path,
+                    self.backend.as_str(),
                 )
                 .map_err(Error::VirtualMachineProveAndVerify)?;
             }
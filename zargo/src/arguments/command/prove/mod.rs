@@ -40,6 +40,10 @@ pub struct Command {
     /// The contract method to prove. Only for contracts.
     #[structopt(long = "method")]
     pub method: Option<String>,
+
+    /// The proving backend, either `groth16` or `plonk`.
+    #[structopt(long = "backend", default_value = "groth16")]
+    pub backend: String,
 }
 
 impl Command {
@@ -96,6 +100,7 @@ impl Command {
                 &input_path,
                 &output_path,
                 method.as_str(),
+                self.backend.as_str(),
             ),
             None => VirtualMachine::prove_circuit(
                 self.verbosity,
@@ -103,6 +108,7 @@ impl Command {
                 &proving_key_path,
                 &input_path,
                 &output_path,
+                self.backend.as_str(),
             ),
         }
         .map_err(Error::VirtualMachine)?;
@@ -82,9 +82,7 @@ impl Command {
     /// Executes the command.
     ///
     pub async fn execute(self) -> Result<(), Error> {
-        let network = zksync::Network::from_str(self.network.as_str())
-            .map(Network::from)
-            .map_err(Error::NetworkInvalid)?;
+        let network = Network::from_str(self.network.as_str()).map_err(Error::NetworkInvalid)?;
 
         let url = network
             .try_into_url()
@@ -146,6 +144,7 @@ impl Command {
             &source_directory_path,
             &binary_path,
             false,
+            None,
         )
         .map_err(Error::Compiler)?;
 
@@ -172,6 +171,8 @@ impl Command {
                 zinc_const::contract::CONSTRUCTOR_NAME,
                 &proving_key_path,
                 &verifying_key_path,
+                "groth16",
+                None,
             )
             .map_err(Error::VirtualMachine)?;
         }
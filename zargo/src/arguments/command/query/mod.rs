@@ -55,6 +55,10 @@ pub struct Command {
     /// Sets the contract method to call. If not specified, the contract storage is queried.
     #[structopt(long = "method")]
     pub method: Option<String>,
+
+    /// Sets the contract storage version to query. If not specified, the latest storage is used.
+    #[structopt(long = "storage-version")]
+    pub storage_version: Option<u32>,
 }
 
 impl Command {
@@ -66,9 +70,7 @@ impl Command {
             .parse()
             .map_err(Error::InvalidContractAddress)?;
 
-        let network = zksync::Network::from_str(self.network.as_str())
-            .map(Network::from)
-            .map_err(Error::NetworkInvalid)?;
+        let network = Network::from_str(self.network.as_str()).map_err(Error::NetworkInvalid)?;
 
         let url = network
             .try_into_url()
@@ -144,7 +146,12 @@ impl Command {
                         Method::PUT,
                         Url::parse_with_params(
                             format!("{}{}", url, zinc_const::zandbox::CONTRACT_QUERY_URL).as_str(),
-                            QueryRequestQuery::new(address, self.method, network.into()),
+                            QueryRequestQuery::new(
+                                address,
+                                self.method,
+                                network.into(),
+                                self.storage_version,
+                            ),
                         )
                         .expect(zinc_const::panic::DATA_CONVERSION),
                     )
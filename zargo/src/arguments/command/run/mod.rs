@@ -106,6 +106,7 @@ impl Command {
                 &source_directory_path,
                 &binary_path,
                 false,
+                None,
             )
             .map_err(Error::Compiler)?;
         } else {
@@ -118,6 +119,7 @@ impl Command {
                 &source_directory_path,
                 &binary_path,
                 false,
+                None,
             )
             .map_err(Error::Compiler)?;
         }
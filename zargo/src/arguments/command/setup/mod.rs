@@ -39,6 +39,15 @@ pub struct Command {
     /// The contract method to do the setup for. Only for contracts.
     #[structopt(long = "method")]
     pub method: Option<String>,
+
+    /// The proving backend, either `groth16` or `plonk`.
+    #[structopt(long = "backend", default_value = "groth16")]
+    pub backend: String,
+
+    /// The path to the universal reference string file in the monomial form.
+    /// Required by the `plonk` backend.
+    #[structopt(long = "srs", parse(from_os_str))]
+    pub srs_path: Option<PathBuf>,
 }
 
 impl Command {
@@ -79,12 +88,16 @@ impl Command {
                 method.as_str(),
                 &proving_key_path,
                 &verifying_key_path,
+                self.backend.as_str(),
+                self.srs_path.as_ref(),
             ),
             None => VirtualMachine::setup_circuit(
                 self.verbosity,
                 &binary_path,
                 &proving_key_path,
                 &verifying_key_path,
+                self.backend.as_str(),
+                self.srs_path.as_ref(),
             ),
         }
         .map_err(Error::VirtualMachine)?;
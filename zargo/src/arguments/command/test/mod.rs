@@ -83,6 +83,7 @@ impl Command {
             &source_directory_path,
             &binary_path,
             true,
+            None,
         )
         .map_err(Error::Compiler)?;
 
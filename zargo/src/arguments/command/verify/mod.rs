@@ -39,6 +39,10 @@ pub struct Command {
     /// The contract method to verify. Only for contracts.
     #[structopt(long = "method")]
     pub method: Option<String>,
+
+    /// The proving backend. If not specified, it is read from the record written by `setup`.
+    #[structopt(long = "backend")]
+    pub backend: Option<String>,
 }
 
 impl Command {
@@ -83,12 +87,14 @@ impl Command {
                 &verifying_key_path,
                 &output_path,
                 method.as_str(),
+                self.backend.as_deref(),
             ),
             _ => VirtualMachine::verify_circuit(
                 self.verbosity,
                 &binary_path,
                 &verifying_key_path,
                 &output_path,
+                self.backend.as_deref(),
             ),
         }
         .map_err(Error::VirtualMachine)?;
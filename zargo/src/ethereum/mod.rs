@@ -0,0 +1,11 @@
+//!
+//! The Ethereum verifier contract and its calldata.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod point;
+pub mod proof;
+pub mod verifier;
+pub mod verifying_key;
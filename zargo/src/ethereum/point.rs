@@ -0,0 +1,109 @@
+//!
+//! The BN254 curve points in the Ethereum precompile encoding.
+//!
+
+use franklin_crypto::bellman::pairing::bn256::G1Affine;
+use franklin_crypto::bellman::pairing::bn256::G2Affine;
+use franklin_crypto::bellman::pairing::ff::PrimeField;
+use franklin_crypto::bellman::pairing::CurveAffine;
+
+///
+/// The G1 point with coordinates as `0x`-prefixed 32-byte hexadecimal words.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct G1Point {
+    /// The `x` coordinate.
+    pub x: String,
+    /// The `y` coordinate.
+    pub y: String,
+}
+
+impl G1Point {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(x: &str, y: &str) -> Self {
+        Self {
+            x: x.to_owned(),
+            y: y.to_owned(),
+        }
+    }
+
+    ///
+    /// The point coordinates in the order expected by the Ethereum precompiles.
+    ///
+    pub fn words(&self) -> Vec<&str> {
+        vec![self.x.as_str(), self.y.as_str()]
+    }
+}
+
+impl From<G1Affine> for G1Point {
+    fn from(point: G1Affine) -> Self {
+        if point.is_zero() {
+            return Self::new(ZERO, ZERO);
+        }
+
+        let (x, y) = point.into_xy_unchecked();
+        Self {
+            x: x.into_repr().to_string(),
+            y: y.into_repr().to_string(),
+        }
+    }
+}
+
+///
+/// The G2 point with coordinates as `0x`-prefixed 32-byte hexadecimal words.
+///
+/// The `Fq2` coordinates are stored as `[c1, c0]`, that is, the imaginary part goes first,
+/// as it is expected by the Ethereum pairing precompile.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct G2Point {
+    /// The `x` coordinate.
+    pub x: [String; 2],
+    /// The `y` coordinate.
+    pub y: [String; 2],
+}
+
+impl G2Point {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(x: [&str; 2], y: [&str; 2]) -> Self {
+        Self {
+            x: [x[0].to_owned(), x[1].to_owned()],
+            y: [y[0].to_owned(), y[1].to_owned()],
+        }
+    }
+
+    ///
+    /// The point coordinates in the order expected by the Ethereum precompiles.
+    ///
+    pub fn words(&self) -> Vec<&str> {
+        vec![
+            self.x[0].as_str(),
+            self.x[1].as_str(),
+            self.y[0].as_str(),
+            self.y[1].as_str(),
+        ]
+    }
+}
+
+impl From<G2Affine> for G2Point {
+    fn from(point: G2Affine) -> Self {
+        if point.is_zero() {
+            return Self::new([ZERO, ZERO], [ZERO, ZERO]);
+        }
+
+        let (x, y) = point.into_xy_unchecked();
+        Self {
+            x: [x.c1.into_repr().to_string(), x.c0.into_repr().to_string()],
+            y: [y.c1.into_repr().to_string(), y.c0.into_repr().to_string()],
+        }
+    }
+}
+
+///
+/// The zero word, which is used to encode the point at infinity.
+///
+static ZERO: &str = "0x0000000000000000000000000000000000000000000000000000000000000000";
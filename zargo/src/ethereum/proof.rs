@@ -0,0 +1,93 @@
+//!
+//! The Groth16 proof in the Ethereum precompile encoding.
+//!
+
+use num::BigInt;
+use num::Signed;
+
+use franklin_crypto::bellman::groth16::Proof as Groth16Proof;
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+use franklin_crypto::bellman::pairing::bn256::Fr;
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::pairing::ff::PrimeField;
+
+use crate::ethereum::point::G1Point;
+use crate::ethereum::point::G2Point;
+
+///
+/// The Groth16 proof, which is passed to the verifier contract.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Proof {
+    /// The `A` point.
+    pub a: G1Point,
+    /// The `B` point.
+    pub b: G2Point,
+    /// The `C` point.
+    pub c: G1Point,
+}
+
+impl Proof {
+    /// The `verifyProof(uint256[2],uint256[2][2],uint256[2],uint256[])` function selector.
+    pub const VERIFY_SELECTOR: &'static str = "c32e370e";
+
+    ///
+    /// Converts the flattened public input values into scalar field words.
+    ///
+    /// The negative values are mapped to the field the same way the virtual machine does it.
+    /// Returns the first value which does not fit into the field as the error.
+    ///
+    pub fn encode_input(values: Vec<BigInt>) -> Result<Vec<String>, BigInt> {
+        values
+            .into_iter()
+            .map(|value| {
+                let mut scalar = match Fr::from_str(value.abs().to_str_radix(10).as_str()) {
+                    Some(scalar) => scalar,
+                    None => return Err(value),
+                };
+                if value.is_negative() {
+                    scalar.negate();
+                }
+                Ok(scalar.into_repr().to_string())
+            })
+            .collect()
+    }
+
+    ///
+    /// Encodes the `verifyProof` call with the proof and `public_input` words as ABI calldata.
+    ///
+    pub fn calldata(&self, public_input: &[String]) -> String {
+        let head_size = self.a.words().len() + self.b.words().len() + self.c.words().len() + 1;
+        let input_offset = format!("{:x}", head_size * zinc_const::size::ETH_WORD);
+        let input_length = format!("{:x}", public_input.len());
+
+        let mut calldata = format!("0x{}", Self::VERIFY_SELECTOR);
+        for word in self
+            .a
+            .words()
+            .into_iter()
+            .chain(self.b.words().into_iter())
+            .chain(self.c.words().into_iter())
+            .chain(vec![input_offset.as_str(), input_length.as_str()].into_iter())
+            .chain(public_input.iter().map(String::as_str))
+        {
+            let word = word.trim_start_matches("0x");
+            calldata.push_str(
+                "0".repeat(zinc_const::size::ETH_WORD * 2 - word.len())
+                    .as_str(),
+            );
+            calldata.push_str(word);
+        }
+        calldata
+    }
+}
+
+impl From<&Groth16Proof<Bn256>> for Proof {
+    fn from(proof: &Groth16Proof<Bn256>) -> Self {
+        Self {
+            a: G1Point::from(proof.a),
+            b: G2Point::from(proof.b),
+            c: G1Point::from(proof.c),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//!
+//! The Ethereum verifier tests.
+//!
+
+use num::BigInt;
+
+use crate::ethereum::point::G1Point;
+use crate::ethereum::point::G2Point;
+use crate::ethereum::proof::Proof;
+use crate::ethereum::verifier::Verifier;
+use crate::ethereum::verifying_key::VerifyingKey;
+
+static G1_X: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+static G1_Y: &str = "0x0000000000000000000000000000000000000000000000000000000000000002";
+
+static G2_X: [&str; 2] = [
+    "0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+    "0x1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+];
+static G2_Y: [&str; 2] = [
+    "0x090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+    "0x12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+];
+
+#[test]
+fn ok_verifier_snapshot() {
+    let verifying_key = VerifyingKey {
+        alpha: G1Point::new(G1_X, G1_Y),
+        beta: G2Point::new(G2_X, G2_Y),
+        gamma: G2Point::new(G2_X, G2_Y),
+        delta: G2Point::new(G2_X, G2_Y),
+        ic: vec![G1Point::new(G1_X, G1_Y), G1Point::new(G1_X, G1_Y)],
+    };
+
+    let expected = include_str!("tests/verifier.sol");
+
+    let result = Verifier::new(verifying_key).template();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_calldata_snapshot() {
+    let proof = Proof {
+        a: G1Point::new(G1_X, G1_Y),
+        b: G2Point::new(G2_X, G2_Y),
+        c: G1Point::new(G1_X, G1_Y),
+    };
+    let public_input = Proof::encode_input(vec![BigInt::from(42), BigInt::from(-1)])
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let expected = include_str!("tests/calldata.txt").trim();
+
+    let result = proof.calldata(public_input.as_slice());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_input_overflow() {
+    let value = BigInt::from(1) << 254;
+
+    let expected = Err(value.clone());
+
+    let result = Proof::encode_input(vec![value]);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,212 @@
+//!
+//! The Solidity verifier contract `verifier.sol` file.
+//!
+
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::error::file::Error as FileError;
+use crate::ethereum::point::G1Point;
+use crate::ethereum::point::G2Point;
+use crate::ethereum::verifying_key::VerifyingKey;
+
+///
+/// The Solidity Groth16 verifier contract file representation.
+///
+pub struct Verifier {
+    /// The verifying key, which is embedded into the contract.
+    pub verifying_key: VerifyingKey,
+}
+
+impl Verifier {
+    ///
+    /// Creates a new file representation instance.
+    ///
+    pub fn new(verifying_key: VerifyingKey) -> Self {
+        Self { verifying_key }
+    }
+
+    ///
+    /// Creates the file in the project at the given `path`.
+    ///
+    pub fn write_to(self, path: &PathBuf) -> Result<(), FileError> {
+        let path = Self::append_default(path);
+        let mut file =
+            File::create(&path).map_err(|error| FileError::Creating(Self::file_name(), error))?;
+        file.write_all(self.template().as_bytes())
+            .map_err(|error| FileError::Writing(Self::file_name(), error))
+    }
+
+    ///
+    /// If the path is a directory, appends the missing elements by default.
+    ///
+    fn append_default(path: &PathBuf) -> PathBuf {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            if !path.ends_with(zinc_const::directory::DATA) {
+                path.push(PathBuf::from(zinc_const::directory::DATA));
+            }
+            path.push(PathBuf::from(Self::file_name()));
+        }
+        path
+    }
+
+    ///
+    /// The verifier contract template function.
+    ///
+    pub fn template(&self) -> String {
+        let key = &self.verifying_key;
+
+        let mut ic = format!("        vk.ic = new Pairing.G1Point[]({});\n", key.ic.len());
+        for (index, point) in key.ic.iter().enumerate() {
+            ic.push_str(format!("        vk.ic[{}] = {};\n", index, Self::g1(point)).as_str());
+        }
+
+        format!(
+            r#"// SPDX-License-Identifier: MIT
+//
+// The Groth16 verifier contract generated by Zargo. Do not edit it manually.
+//
+
+pragma solidity ^0.6.11;
+
+library Pairing {{
+    uint256 constant PRIME_Q = 21888242871839275222246405745257275088696311157297823662689037894645226208583;
+
+    struct G1Point {{
+        uint256 X;
+        uint256 Y;
+    }}
+
+    // The coordinates are encoded as `X[0] * i + X[1]`.
+    struct G2Point {{
+        uint256[2] X;
+        uint256[2] Y;
+    }}
+
+    function negate(G1Point memory p) internal pure returns (G1Point memory) {{
+        if (p.X == 0 && p.Y == 0) {{
+            return G1Point(0, 0);
+        }}
+        return G1Point(p.X, PRIME_Q - (p.Y % PRIME_Q));
+    }}
+
+    function plus(G1Point memory p1, G1Point memory p2) internal view returns (G1Point memory r) {{
+        uint256[4] memory input = [p1.X, p1.Y, p2.X, p2.Y];
+        bool success;
+        assembly {{
+            success := staticcall(sub(gas(), 2000), 6, input, 0x80, r, 0x40)
+        }}
+        require(success, "pairing-add-failed");
+    }}
+
+    function scalarMul(G1Point memory p, uint256 s) internal view returns (G1Point memory r) {{
+        uint256[3] memory input = [p.X, p.Y, s];
+        bool success;
+        assembly {{
+            success := staticcall(sub(gas(), 2000), 7, input, 0x60, r, 0x40)
+        }}
+        require(success, "pairing-mul-failed");
+    }}
+
+    function pairing(G1Point[4] memory p1, G2Point[4] memory p2) internal view returns (bool) {{
+        uint256[24] memory input;
+        for (uint256 i = 0; i < 4; i++) {{
+            uint256 j = i * 6;
+            input[j + 0] = p1[i].X;
+            input[j + 1] = p1[i].Y;
+            input[j + 2] = p2[i].X[0];
+            input[j + 3] = p2[i].X[1];
+            input[j + 4] = p2[i].Y[0];
+            input[j + 5] = p2[i].Y[1];
+        }}
+        uint256[1] memory out;
+        bool success;
+        assembly {{
+            success := staticcall(sub(gas(), 2000), 8, input, 0x300, out, 0x20)
+        }}
+        require(success, "pairing-opcode-failed");
+        return out[0] != 0;
+    }}
+}}
+
+contract Verifier {{
+    uint256 constant SNARK_SCALAR_FIELD = 21888242871839275222246405745257275088548364400416034343698204186575808495617;
+
+    struct VerifyingKey {{
+        Pairing.G1Point alpha;
+        Pairing.G2Point beta;
+        Pairing.G2Point gamma;
+        Pairing.G2Point delta;
+        Pairing.G1Point[] ic;
+    }}
+
+    function verifyingKey() internal pure returns (VerifyingKey memory vk) {{
+        vk.alpha = {alpha};
+        vk.beta = {beta};
+        vk.gamma = {gamma};
+        vk.delta = {delta};
+{ic}    }}
+
+    function verifyProof(
+        uint256[2] memory a,
+        uint256[2][2] memory b,
+        uint256[2] memory c,
+        uint256[] memory input
+    ) public view returns (bool) {{
+        VerifyingKey memory vk = verifyingKey();
+        require(input.length + 1 == vk.ic.length, "verifier-bad-input");
+
+        Pairing.G1Point memory x = vk.ic[0];
+        for (uint256 i = 0; i < input.length; i++) {{
+            require(input[i] < SNARK_SCALAR_FIELD, "verifier-gte-snark-scalar-field");
+            x = Pairing.plus(x, Pairing.scalarMul(vk.ic[i + 1], input[i]));
+        }}
+
+        return Pairing.pairing(
+            [Pairing.negate(Pairing.G1Point(a[0], a[1])), vk.alpha, x, Pairing.G1Point(c[0], c[1])],
+            [Pairing.G2Point(b[0], b[1]), vk.beta, vk.gamma, vk.delta]
+        );
+    }}
+}}
+"#,
+            alpha = Self::g1(&key.alpha),
+            beta = Self::g2(&key.beta),
+            gamma = Self::g2(&key.gamma),
+            delta = Self::g2(&key.delta),
+            ic = ic,
+        )
+    }
+
+    ///
+    /// Renders the G1 point constructor.
+    ///
+    fn g1(point: &G1Point) -> String {
+        format!(
+            "Pairing.G1Point(uint256({}), uint256({}))",
+            point.x, point.y
+        )
+    }
+
+    ///
+    /// Renders the G2 point constructor.
+    ///
+    fn g2(point: &G2Point) -> String {
+        format!(
+            "Pairing.G2Point([uint256({}), uint256({})], [uint256({}), uint256({})])",
+            point.x[0], point.x[1], point.y[0], point.y[1]
+        )
+    }
+
+    ///
+    /// Creates a string with the default file name.
+    ///
+    fn file_name() -> String {
+        format!(
+            "{}.{}",
+            zinc_const::file_name::VERIFIER,
+            zinc_const::extension::SOLIDITY,
+        )
+    }
+}
@@ -0,0 +1,38 @@
+//!
+//! The Groth16 verifying key in the Ethereum precompile encoding.
+//!
+
+use franklin_crypto::bellman::groth16::VerifyingKey as Groth16VerifyingKey;
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use crate::ethereum::point::G1Point;
+use crate::ethereum::point::G2Point;
+
+///
+/// The Groth16 verifying key, which is embedded into the verifier contract.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifyingKey {
+    /// The `alpha` point.
+    pub alpha: G1Point,
+    /// The `beta` point.
+    pub beta: G2Point,
+    /// The `gamma` point.
+    pub gamma: G2Point,
+    /// The `delta` point.
+    pub delta: G2Point,
+    /// The public input commitment points. The first one is the constant term.
+    pub ic: Vec<G1Point>,
+}
+
+impl From<&Groth16VerifyingKey<Bn256>> for VerifyingKey {
+    fn from(key: &Groth16VerifyingKey<Bn256>) -> Self {
+        Self {
+            alpha: G1Point::from(key.alpha_g1),
+            beta: G2Point::from(key.beta_g2),
+            gamma: G2Point::from(key.gamma_g2),
+            delta: G2Point::from(key.delta_g2),
+            ic: key.ic.iter().cloned().map(G1Point::from).collect(),
+        }
+    }
+}
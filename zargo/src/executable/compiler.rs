@@ -37,6 +37,8 @@ impl Compiler {
     ///
     /// If `is_test_only` is set, passes the flag to only build the project unit tests.
     ///
+    /// If `message_format` is set, passes it to select the errors and warnings format.
+    ///
     #[allow(clippy::too_many_arguments)]
     pub fn build_debug(
         verbosity: usize,
@@ -47,6 +49,7 @@ impl Compiler {
         source_path: &PathBuf,
         binary_path: &PathBuf,
         is_test_only: bool,
+        message_format: Option<&str>,
     ) -> Result<(), Error> {
         eprintln!("   {} {} v{}", "Compiling".bright_green(), name, version);
 
@@ -63,6 +66,10 @@ impl Compiler {
             } else {
                 vec![]
             })
+            .args(match message_format {
+                Some(message_format) => vec!["--message-format", message_format],
+                None => vec![],
+            })
             .arg(source_path)
             .spawn()
             .map_err(Error::Spawning)?;
@@ -83,6 +90,8 @@ impl Compiler {
     ///
     /// If `is_test_only` is set, passes the flag to only build the project unit tests.
     ///
+    /// If `message_format` is set, passes it to select the errors and warnings format.
+    ///
     #[allow(clippy::too_many_arguments)]
     pub fn build_release(
         verbosity: usize,
@@ -93,6 +102,7 @@ impl Compiler {
         source_path: &PathBuf,
         binary_path: &PathBuf,
         is_test_only: bool,
+        message_format: Option<&str>,
     ) -> Result<(), Error> {
         eprintln!("   {} {} v{}", "Compiling".bright_green(), name, version);
 
@@ -109,6 +119,10 @@ impl Compiler {
             } else {
                 vec![]
             })
+            .args(match message_format {
+                Some(message_format) => vec!["--message-format", message_format],
+                None => vec![],
+            })
             .arg("--opt-dfe")
             .arg(source_path)
             .spawn()
@@ -2,6 +2,7 @@
 //! The compiler executable.
 //!
 
+use std::ffi::OsStr;
 use std::io;
 use std::io::Write;
 use std::path::PathBuf;
@@ -125,6 +126,81 @@ impl VirtualMachine {
         Ok(())
     }
 
+    ///
+    /// Executes the virtual machine `profile` subcommand for circuit.
+    ///
+    pub fn profile_circuit(
+        verbosity: usize,
+        binary_path: &PathBuf,
+        input_path: &PathBuf,
+        folded_path: &PathBuf,
+    ) -> Result<(), Error> {
+        eprintln!(
+            "   {} `{}`",
+            "Profiling".bright_green(),
+            binary_path.to_string_lossy(),
+        );
+
+        let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .arg("profile")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--input")
+            .arg(input_path)
+            .arg("--folded")
+            .arg(folded_path)
+            .spawn()
+            .map_err(Error::Spawning)?;
+
+        let status = process.wait().map_err(Error::Waiting)?;
+
+        if !status.success() {
+            return Err(Error::Failure(status));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Executes the virtual machine `profile` subcommand for contract.
+    ///
+    pub fn profile_contract(
+        verbosity: usize,
+        binary_path: &PathBuf,
+        input_path: &PathBuf,
+        folded_path: &PathBuf,
+        method: &str,
+    ) -> Result<(), Error> {
+        eprintln!(
+            "   {} `{}`",
+            "Profiling".bright_green(),
+            binary_path.to_string_lossy(),
+        );
+
+        let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .arg("profile")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--input")
+            .arg(input_path)
+            .arg("--folded")
+            .arg(folded_path)
+            .arg("--method")
+            .arg(method)
+            .spawn()
+            .map_err(Error::Spawning)?;
+
+        let status = process.wait().map_err(Error::Waiting)?;
+
+        if !status.success() {
+            return Err(Error::Failure(status));
+        }
+
+        Ok(())
+    }
+
     ///
     /// Executes the virtual machine `test` subcommand.
     ///
@@ -150,6 +226,8 @@ impl VirtualMachine {
         binary_path: &PathBuf,
         proving_key_path: &PathBuf,
         verifying_key_path: &PathBuf,
+        backend: &str,
+        srs_path: Option<&PathBuf>,
     ) -> Result<(), Error> {
         eprintln!(
             "  {} key pair `{}` and `{}`",
@@ -161,12 +239,19 @@ impl VirtualMachine {
         let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
             .args(vec!["-v"; verbosity])
             .arg("setup")
+            .arg("--backend")
+            .arg(backend)
             .arg("--binary")
             .arg(binary_path)
             .arg("--proving-key")
             .arg(proving_key_path)
             .arg("--verifying-key")
             .arg(verifying_key_path)
+            .args(
+                srs_path
+                    .map(|srs_path| vec![OsStr::new("--srs"), srs_path.as_os_str()])
+                    .unwrap_or_default(),
+            )
             .spawn()
             .map_err(Error::Spawning)?;
 
@@ -188,6 +273,8 @@ impl VirtualMachine {
         method: &str,
         proving_key_path: &PathBuf,
         verifying_key_path: &PathBuf,
+        backend: &str,
+        srs_path: Option<&PathBuf>,
     ) -> Result<(), Error> {
         eprintln!(
             "  {} key pair `{}` and `{}`",
@@ -199,6 +286,8 @@ impl VirtualMachine {
         let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
             .args(vec!["-v"; verbosity])
             .arg("setup")
+            .arg("--backend")
+            .arg(backend)
             .arg("--binary")
             .arg(binary_path)
             .arg("--method")
@@ -207,6 +296,11 @@ impl VirtualMachine {
             .arg(proving_key_path)
             .arg("--verifying-key")
             .arg(verifying_key_path)
+            .args(
+                srs_path
+                    .map(|srs_path| vec![OsStr::new("--srs"), srs_path.as_os_str()])
+                    .unwrap_or_default(),
+            )
             .spawn()
             .map_err(Error::Spawning)?;
 
@@ -228,6 +322,7 @@ impl VirtualMachine {
         proving_key_path: &PathBuf,
         input_path: &PathBuf,
         output_path: &PathBuf,
+        backend: &str,
     ) -> Result<(), Error> {
         eprintln!(
             "     {} `{}` with `{}`",
@@ -239,6 +334,8 @@ impl VirtualMachine {
         let mut child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
             .args(vec!["-v"; verbosity])
             .arg("prove")
+            .arg("--backend")
+            .arg(backend)
             .arg("--binary")
             .arg(binary_path)
             .arg("--proving-key")
@@ -269,6 +366,7 @@ impl VirtualMachine {
         input_path: &PathBuf,
         output_path: &PathBuf,
         method: &str,
+        backend: &str,
     ) -> Result<(), Error> {
         eprintln!(
             "     {} `{}` with `{}`",
@@ -280,6 +378,8 @@ impl VirtualMachine {
         let mut child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
             .args(vec!["-v"; verbosity])
             .arg("prove")
+            .arg("--backend")
+            .arg(backend)
             .arg("--binary")
             .arg(binary_path)
             .arg("--proving-key")
@@ -310,6 +410,7 @@ impl VirtualMachine {
         binary_path: &PathBuf,
         verifying_key_path: &PathBuf,
         output_path: &PathBuf,
+        backend: Option<&str>,
     ) -> Result<(), Error> {
         eprintln!(
             "   {} `{}` with `{}`",
@@ -321,6 +422,11 @@ impl VirtualMachine {
         let mut child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
             .args(vec!["-v"; verbosity])
             .arg("verify")
+            .args(
+                backend
+                    .map(|backend| vec!["--backend", backend])
+                    .unwrap_or_default(),
+            )
             .arg("--binary")
             .arg(binary_path)
             .arg("--verifying-key")
@@ -348,6 +454,7 @@ impl VirtualMachine {
         verifying_key_path: &PathBuf,
         output_path: &PathBuf,
         method: &str,
+        backend: Option<&str>,
     ) -> Result<(), Error> {
         eprintln!(
             "   {} `{}` with `{}`",
@@ -359,6 +466,11 @@ impl VirtualMachine {
         let mut child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
             .args(vec!["-v"; verbosity])
             .arg("verify")
+            .args(
+                backend
+                    .map(|backend| vec!["--backend", backend])
+                    .unwrap_or_default(),
+            )
             .arg("--binary")
             .arg(binary_path)
             .arg("--verifying-key")
@@ -391,6 +503,7 @@ impl VirtualMachine {
         output_path: &PathBuf,
         proving_key_path: &PathBuf,
         verifying_key_path: &PathBuf,
+        backend: &str,
     ) -> Result<(), Error> {
         eprintln!(
             "     {} `{}` with `{}`",
@@ -402,6 +515,8 @@ impl VirtualMachine {
         let prover_output = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
             .args(vec!["-v"; verbosity])
             .arg("prove")
+            .arg("--backend")
+            .arg(backend)
             .arg("--binary")
             .arg(binary_path)
             .arg("--proving-key")
@@ -423,6 +538,8 @@ impl VirtualMachine {
         let mut verifier_child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
             .args(vec!["-v"; verbosity])
             .arg("verify")
+            .arg("--backend")
+            .arg(backend)
             .arg("--binary")
             .arg(binary_path)
             .arg("--verifying-key")
@@ -460,6 +577,7 @@ impl VirtualMachine {
         method: &str,
         proving_key_path: &PathBuf,
         verifying_key_path: &PathBuf,
+        backend: &str,
     ) -> Result<(), Error> {
         eprintln!(
             "     {} `{}` with `{}`",
@@ -471,6 +589,8 @@ impl VirtualMachine {
         let prover_output = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
             .args(vec!["-v"; verbosity])
             .arg("prove")
+            .arg("--backend")
+            .arg(backend)
             .arg("--binary")
             .arg(binary_path)
             .arg("--proving-key")
@@ -494,6 +614,8 @@ impl VirtualMachine {
         let mut verifier_child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
             .args(vec!["-v"; verbosity])
             .arg("verify")
+            .arg("--backend")
+            .arg(backend)
             .arg("--binary")
             .arg(binary_path)
             .arg("--verifying-key")
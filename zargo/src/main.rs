@@ -4,6 +4,7 @@
 
 pub(crate) mod arguments;
 pub(crate) mod error;
+pub(crate) mod ethereum;
 pub(crate) mod executable;
 pub(crate) mod network;
 pub(crate) mod project;
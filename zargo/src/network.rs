@@ -3,6 +3,12 @@
 //!
 
 use std::fmt;
+use std::str::FromStr;
+
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use zksync::web3::types::Address;
 
 ///
 /// The zkSync SDK network wrapper.
@@ -11,6 +17,8 @@ use std::fmt;
 pub struct Network {
     /// The zkSync type.
     inner: zksync::Network,
+    /// Whether the network is the local zkSync mock hosted by Zandbox.
+    is_mock: bool,
 }
 
 impl Network {
@@ -24,11 +32,70 @@ impl Network {
             another => Err(another),
         }
     }
+
+    ///
+    /// Whether the network is the local zkSync mock.
+    ///
+    pub fn is_mock(self) -> bool {
+        self.is_mock
+    }
+
+    ///
+    /// Credits the faucet amount of `token` to the `address` account in the local zkSync mock.
+    ///
+    /// There is no Ethereum network behind the mock, so the sender accounts are funded directly.
+    ///
+    pub async fn mock_deposit(self, address: Address, token: JsonValue) -> Result<(), String> {
+        let response = reqwest::Client::new()
+            .post(
+                format!(
+                    "http://{}:{}",
+                    zinc_const::zandbox::ZKSYNC_MOCK_HOST,
+                    zinc_const::zandbox::ZKSYNC_MOCK_PORT
+                )
+                .as_str(),
+            )
+            .json(&json!({
+                "jsonrpc": "2.0",
+                "method": "mock_deposit",
+                "params": [address, token, zinc_const::zandbox::ZKSYNC_MOCK_FAUCET_AMOUNT],
+                "id": 1,
+            }))
+            .send()
+            .await
+            .map_err(|error| error.to_string())?
+            .json::<JsonValue>()
+            .await
+            .map_err(|error| error.to_string())?;
+
+        match response.get("error") {
+            Some(error) => Err(error["message"].to_string()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for Network {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value == zinc_const::zandbox::NETWORK_LOCALHOST_MOCK {
+            return Ok(Self {
+                inner: zksync::Network::Localhost,
+                is_mock: true,
+            });
+        }
+
+        zksync::Network::from_str(value).map(Self::from)
+    }
 }
 
 impl From<zksync::Network> for Network {
     fn from(inner: zksync::Network) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            is_mock: false,
+        }
     }
 }
 
@@ -40,6 +107,10 @@ impl Into<zksync::Network> for Network {
 
 impl fmt::Display for Network {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.inner)
+        if self.is_mock {
+            write!(f, "{}", zinc_const::zandbox::NETWORK_LOCALHOST_MOCK)
+        } else {
+            write!(f, "{}", self.inner)
+        }
     }
 }
@@ -0,0 +1,28 @@
+//!
+//! The Zinc VM bytecode contract application event.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::data::r#type::Type as BuildType;
+
+///
+/// The contract event, emitted with the `zksync::emit` function.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Event {
+    /// The event name, which is the emitted structure identifier.
+    pub name: String,
+    /// The event data type.
+    pub r#type: BuildType,
+}
+
+impl Event {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, r#type: BuildType) -> Self {
+        Self { name, r#type }
+    }
+}
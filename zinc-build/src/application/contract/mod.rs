@@ -2,6 +2,7 @@
 //! The Zinc VM bytecode contract application.
 //!
 
+pub mod event;
 pub mod method;
 
 use std::collections::HashMap;
@@ -13,6 +14,7 @@ use crate::application::unit_test::UnitTest;
 use crate::data::r#type::contract_field::ContractField as ContractFieldType;
 use crate::instructions::Instruction;
 
+use self::event::Event;
 use self::method::Method;
 
 ///
@@ -26,6 +28,8 @@ pub struct Contract {
     pub storage: Vec<ContractFieldType>,
     /// The contract methods.
     pub methods: HashMap<String, Method>,
+    /// The contract events, whose indexes are passed to the `zksync::emit` function calls.
+    pub events: Vec<Event>,
     /// The contract unit tests.
     pub unit_tests: HashMap<String, UnitTest>,
     /// The contract bytecode instructions.
@@ -40,6 +44,7 @@ impl Contract {
         name: String,
         storage: Vec<ContractFieldType>,
         methods: HashMap<String, Method>,
+        events: Vec<Event>,
         unit_tests: HashMap<String, UnitTest>,
         instructions: Vec<Instruction>,
     ) -> Self {
@@ -47,6 +52,7 @@ impl Contract {
             name,
             storage,
             methods,
+            events,
             unit_tests,
             instructions,
         }
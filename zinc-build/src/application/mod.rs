@@ -22,6 +22,7 @@ use crate::data::value::Value;
 use crate::instructions::Instruction;
 
 use self::circuit::Circuit;
+use self::contract::event::Event as ContractEvent;
 use self::contract::method::Method as ContractMethod;
 use self::contract::Contract;
 
@@ -65,6 +66,7 @@ impl Application {
         name: String,
         storage: Vec<ContractFieldType>,
         methods: HashMap<String, ContractMethod>,
+        events: Vec<ContractEvent>,
         unit_tests: HashMap<String, UnitTest>,
         instructions: Vec<Instruction>,
     ) -> Self {
@@ -72,6 +74,7 @@ impl Application {
             name,
             storage,
             methods,
+            events,
             unit_tests,
             instructions,
         ))
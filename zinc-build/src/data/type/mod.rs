@@ -34,6 +34,14 @@ pub enum Type {
         /// The variant list.
         variants: Vec<(String, BigInt)>,
     },
+    /// The `enum` type, whose variants carry data. It is flattened to the payload of the largest
+    /// variant size, where each scalar is a field element, followed by the variant discriminant.
+    DataEnumeration {
+        /// The enumeration discriminant bitlength.
+        bitlength: usize,
+        /// The variant list, where each variant payload is a unit, tuple, or structure type.
+        variants: Vec<(String, Type)>,
+    },
 
     /// The array type.
     Array(Box<Type>, usize),
@@ -43,6 +51,8 @@ pub enum Type {
     Structure(Vec<(String, Type)>),
     /// The contract type.
     Contract(Vec<ContractField>),
+    /// The built-in `Option` type, which is flattened to its payload followed by a boolean tag.
+    Option(Box<Type>),
 
     /// The `std::collections::MTreeMap` type.
     Map {
@@ -79,6 +89,15 @@ impl Type {
             Self::Enumeration { bitlength, .. } => {
                 vec![ScalarType::Integer(IntegerType::new(false, bitlength))]
             }
+            Self::DataEnumeration {
+                bitlength,
+                variants,
+            } => {
+                let payload_size = Self::data_enumeration_payload_size(variants.as_slice());
+                let mut types = vec![ScalarType::Field; payload_size];
+                types.push(ScalarType::Integer(IntegerType::new(false, bitlength)));
+                types
+            }
 
             Self::Array(r#type, size) => vec![Self::into_flat_scalar_types(*r#type); size]
                 .into_iter()
@@ -99,6 +118,11 @@ impl Type {
                 .map(|field| Self::into_flat_scalar_types(field.r#type))
                 .flatten()
                 .collect(),
+            Self::Option(r#type) => {
+                let mut types = Self::into_flat_scalar_types(*r#type);
+                types.push(ScalarType::Boolean);
+                types
+            }
 
             Self::Map { .. } => vec![],
         }
@@ -125,16 +149,32 @@ impl Type {
             Self::Unit => 0,
             Self::Scalar(_) => 1,
             Self::Enumeration { .. } => 1,
+            Self::DataEnumeration { variants, .. } => {
+                Self::data_enumeration_payload_size(variants.as_slice()) + 1
+            }
 
             Self::Array(r#type, size) => r#type.size() * *size,
             Self::Tuple(fields) => fields.iter().map(Self::size).sum(),
             Self::Structure(fields) => fields.iter().map(|(_, r#type)| r#type.size()).sum(),
             Self::Contract(fields) => fields.iter().map(|field| field.r#type.size()).sum(),
+            Self::Option(r#type) => r#type.size() + 1,
 
             Self::Map { .. } => 0,
         }
     }
 
+    ///
+    /// Returns the payload size of the data-carrying enumeration, which is the size of its
+    /// largest variant.
+    ///
+    pub fn data_enumeration_payload_size(variants: &[(String, Self)]) -> usize {
+        variants
+            .iter()
+            .map(|(_name, r#type)| r#type.size())
+            .max()
+            .unwrap_or_default()
+    }
+
     ///
     /// Removes the first structure field, if the field is a contract instance.
     ///
@@ -165,6 +205,19 @@ impl fmt::Display for Type {
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
+            Self::DataEnumeration { variants, .. } => write!(
+                f,
+                "enum {}",
+                variants
+                    .iter()
+                    .map(|(name, r#type)| match r#type {
+                        Self::Unit => name.to_owned(),
+                        Self::Tuple(_) => format!("{}{}", name, r#type),
+                        r#type => format!("{} {{ {} }}", name, r#type),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
 
             Self::Array(inner, size) => write!(f, "[{}; {}]", inner, size),
             Self::Tuple(types) => write!(
@@ -194,6 +247,7 @@ impl fmt::Display for Type {
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
+            Self::Option(inner) => write!(f, "Option<{}>", inner),
 
             Self::Map {
                 key_type,
@@ -9,7 +9,9 @@ pub mod scalar;
 use std::collections::HashSet;
 
 use num::BigInt;
+use num::One;
 use num::Signed;
+use num::ToPrimitive;
 use num::Zero;
 use serde::Deserialize;
 use serde::Serialize;
@@ -49,6 +51,18 @@ pub enum Value {
         /// The enumeration variant value.
         value: ScalarValue,
     },
+    /// A data-carrying enumeration value, represented with its variant name string for unit
+    /// variants, or with a single-key JSON object, where the key is the variant name.
+    DataEnumeration {
+        /// The enumeration variant name.
+        name: String,
+        /// The enumeration variant discriminant.
+        discriminant: ScalarValue,
+        /// The enumeration variant payload.
+        value: Box<Value>,
+        /// The enumeration payload size, which the variant payload is padded to.
+        payload_size: usize,
+    },
 
     /// Represented with JSON array.
     Array(Vec<Value>),
@@ -56,6 +70,13 @@ pub enum Value {
     Structure(Vec<(String, Value)>),
     /// Represented with JSON object.
     Contract(Vec<ContractField>),
+    /// Represented with `null` or the inner value.
+    Option {
+        /// The inner value, which is zeroed if the option is empty.
+        value: Box<Value>,
+        /// Whether the option contains a value.
+        is_some: bool,
+    },
 
     /// The `std::collections::MTreeMap` value.
     Map(Vec<(Value, Value)>),
@@ -92,6 +113,23 @@ impl Value {
                     },
                 }
             }
+            Type::DataEnumeration {
+                bitlength,
+                mut variants,
+            } => {
+                let payload_size = Type::data_enumeration_payload_size(variants.as_slice());
+                let (name, r#type) = variants.remove(0);
+
+                Self::DataEnumeration {
+                    name,
+                    discriminant: ScalarValue::Integer(
+                        BigInt::zero(),
+                        IntegerType::new(false, bitlength),
+                    ),
+                    value: Box::new(Self::new(r#type)),
+                    payload_size,
+                }
+            }
 
             Type::Array(r#type, size) => Self::Array(vec![Self::new(*r#type); size]),
             Type::Tuple(fields) => Self::Array(fields.into_iter().map(Self::new).collect()),
@@ -107,6 +145,10 @@ impl Value {
                     .map(ContractField::new_from_type)
                     .collect(),
             ),
+            Type::Option(r#type) => Self::Option {
+                value: Box::new(Self::new(*r#type)),
+                is_some: false,
+            },
 
             Type::Map { .. } => Self::Map(vec![]),
         }
@@ -123,11 +165,16 @@ impl Value {
                 bitlength,
                 variants,
             } => Self::enumeration_from_json(value, bitlength, variants),
+            Type::DataEnumeration {
+                bitlength,
+                variants,
+            } => Self::data_enumeration_from_json(value, bitlength, variants),
 
             Type::Array(inner, size) => Self::array_from_json(value, *inner, size),
             Type::Tuple(inner) => Self::tuple_from_json(value, inner),
             Type::Structure(fields) => Self::structure_from_json(value, fields),
             Type::Contract(fields) => Self::contract_from_json(value, fields),
+            Type::Option(inner) => Self::option_from_json(value, *inner),
 
             Type::Map {
                 key_type,
@@ -170,6 +217,31 @@ impl Value {
                 })
                 .map(Self::Scalar)
                 .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+            Type::DataEnumeration {
+                bitlength,
+                mut variants,
+            } => {
+                let payload_size = Type::data_enumeration_payload_size(variants.as_slice());
+                let discriminant = flat_values
+                    .get(payload_size)
+                    .cloned()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                let index = discriminant
+                    .to_usize()
+                    .filter(|index| *index < variants.len())
+                    .unwrap_or_default();
+                let (name, r#type) = variants.remove(index);
+
+                Self::DataEnumeration {
+                    name,
+                    discriminant: ScalarValue::Integer(
+                        discriminant,
+                        IntegerType::new(false, bitlength),
+                    ),
+                    value: Box::new(Self::from_flat_values(r#type, flat_values)),
+                    payload_size,
+                }
+            }
             Type::Array(r#type, size) => {
                 let mut offset = 0;
                 let mut result = Vec::with_capacity(size);
@@ -216,6 +288,16 @@ impl Value {
                 }
                 Self::Contract(result)
             }
+            Type::Option(r#type) => {
+                let is_some = flat_values
+                    .get(r#type.size())
+                    .map(|tag| !tag.is_zero())
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                Self::Option {
+                    value: Box::new(Self::from_flat_values(*r#type, flat_values)),
+                    is_some,
+                }
+            }
 
             Type::Map { .. } => Self::Map(vec![]),
         }
@@ -231,6 +313,17 @@ impl Value {
             Self::Unit => vec![],
             Self::Scalar(value) => vec![value.to_bigint()],
             Self::Enumeration { name: _, value } => vec![value.to_bigint()],
+            Self::DataEnumeration {
+                name: _,
+                discriminant,
+                value,
+                payload_size,
+            } => {
+                let mut values = Self::into_flat_values(*value);
+                values.resize(payload_size, BigInt::zero());
+                values.push(discriminant.to_bigint());
+                values
+            }
 
             Self::Array(values) => values
                 .into_iter()
@@ -247,6 +340,15 @@ impl Value {
                 .map(|field| Self::into_flat_values(field.value))
                 .flatten()
                 .collect(),
+            Self::Option { value, is_some } => {
+                let mut values = Self::into_flat_values(*value);
+                values.push(if is_some {
+                    BigInt::one()
+                } else {
+                    BigInt::zero()
+                });
+                values
+            }
 
             Self::Map(_entries) => vec![],
         }
@@ -275,6 +377,14 @@ impl Value {
                 ScalarValue::Boolean(value) => JsonValue::Bool(value),
             },
             Self::Enumeration { name, value: _ } => JsonValue::String(name),
+            Self::DataEnumeration { name, value, .. } => match *value {
+                Self::Unit => JsonValue::String(name),
+                value => {
+                    let mut object = JsonMap::<String, JsonValue>::with_capacity(1);
+                    object.insert(name, Self::into_json(value));
+                    JsonValue::Object(object)
+                }
+            },
 
             Self::Array(values) => {
                 JsonValue::Array(values.into_iter().map(Self::into_json).collect())
@@ -293,6 +403,13 @@ impl Value {
                 }
                 JsonValue::Object(object)
             }
+            Self::Option { value, is_some } => {
+                if is_some {
+                    value.into_json()
+                } else {
+                    JsonValue::Null
+                }
+            }
 
             Self::Map(entries) => {
                 let mut array = Vec::with_capacity(entries.len());
@@ -411,6 +528,60 @@ impl Value {
         }
     }
 
+    ///
+    /// Creates a data-carrying enumeration value from the JSON `value`.
+    ///
+    /// Unit variants are represented with their name strings, e.g. `"Empty"`, and the others
+    /// are represented with single-key objects, e.g. `{ "Transfer": ["1", "2"] }`.
+    ///
+    fn data_enumeration_from_json(
+        value: JsonValue,
+        bitlength: usize,
+        variants: Vec<(String, Type)>,
+    ) -> Result<Self, Error> {
+        let payload_size = Type::data_enumeration_payload_size(variants.as_slice());
+
+        let (name, payload) = match value {
+            JsonValue::String(name) => (name, JsonValue::Null),
+            JsonValue::Object(object) if object.len() == 1 => object
+                .into_iter()
+                .next()
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+            value => {
+                return Err(ErrorType::TypeError {
+                    expected: variants
+                        .iter()
+                        .map(|(name, _type)| name.to_owned())
+                        .collect::<Vec<String>>()
+                        .join(" | "),
+                    found: value.to_string(),
+                }
+                .into())
+            }
+        };
+
+        let (index, r#type) = match variants
+            .into_iter()
+            .enumerate()
+            .find(|(_index, (variant, _type))| variant == &name)
+        {
+            Some((index, (_name, r#type))) => (index, r#type),
+            None => return Err(Error::from(ErrorType::UnexpectedVariant(name))),
+        };
+
+        let value = Self::try_from_typed_json(payload, r#type).push_structure(name.as_str())?;
+
+        Ok(Self::DataEnumeration {
+            name,
+            discriminant: ScalarValue::Integer(
+                BigInt::from(index),
+                IntegerType::new(false, bitlength),
+            ),
+            value: Box::new(value),
+            payload_size,
+        })
+    }
+
     ///
     /// Creates a field value from the JSON `value`.
     ///
@@ -580,6 +751,23 @@ impl Value {
         Ok(Self::Contract(field_values))
     }
 
+    ///
+    /// Creates an `Option` value from the JSON `value`, where `null` stands for an empty option.
+    ///
+    fn option_from_json(value: JsonValue, r#type: Type) -> Result<Self, Error> {
+        if value.is_null() {
+            return Ok(Self::Option {
+                value: Box::new(Self::new(r#type)),
+                is_some: false,
+            });
+        }
+
+        Ok(Self::Option {
+            value: Box::new(Self::try_from_typed_json(value, r#type)?),
+            is_some: true,
+        })
+    }
+
     ///
     /// Creates an `std::collections::MTreeMap` value from the JSON `value`.
     ///
@@ -14,6 +14,10 @@ pub enum LibraryFunctionIdentifier {
     CryptoSha256,
     /// The `std::crypto::pedersen` function identifier.
     CryptoPedersen,
+    /// The `std::crypto::poseidon` function identifier.
+    CryptoPoseidon,
+    /// The `std::crypto::rescue` function identifier.
+    CryptoRescue,
     /// The `std::crypto::schnorr::Signature::verify` function identifier.
     CryptoSchnorrSignatureVerify,
 
@@ -32,12 +36,28 @@ pub enum LibraryFunctionIdentifier {
     ArrayTruncate,
     /// The `std::array::pad` function identifier.
     ArrayPad,
+    /// The `std::array::map` function identifier.
+    ArrayMap,
+    /// The `std::array::fold` function identifier.
+    ArrayFold,
+    /// The `std::array::zip` function identifier.
+    ArrayZip,
+    /// The `std::array::contains` function identifier.
+    ArrayContains,
+    /// The `std::array::index_of` function identifier.
+    ArrayIndexOf,
+    /// The `std::array::sum` function identifier.
+    ArraySum,
+    /// The `std::array::sort` function identifier.
+    ArraySort,
 
     /// The `std::ff::invert` function identifier.
     FfInvert,
 
     /// The `zksync::transfer` function identifier.
     ZksyncTransfer,
+    /// The `zksync::emit` function identifier.
+    ZksyncEmit,
 
     /// The `std::collections::MTreeMap::get` function identifier.
     CollectionsMTreeMapGet,
@@ -8,6 +8,7 @@ pub(crate) mod data;
 pub(crate) mod instructions;
 
 pub use self::application::circuit::Circuit;
+pub use self::application::contract::event::Event as ContractEvent;
 pub use self::application::contract::method::Method as ContractMethod;
 pub use self::application::contract::Contract;
 pub use self::application::unit_test::UnitTest;
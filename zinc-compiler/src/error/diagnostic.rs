@@ -0,0 +1,394 @@
+//!
+//! The Zinc compiler error diagnostic.
+//!
+
+use std::fmt;
+
+use colored::ColoredString;
+use colored::Colorize;
+
+use zinc_lexical::Location;
+use zinc_lexical::FILE_INDEX;
+
+///
+/// The diagnostic severity.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    /// The error, which prevents the application from being compiled.
+    Error,
+    /// The lint warning, which is only reported to the user.
+    Warning,
+}
+
+impl Severity {
+    ///
+    /// Paints the `text` with the severity color.
+    ///
+    fn paint(self, text: &str) -> ColoredString {
+        match self {
+            Self::Error => text.bright_red(),
+            Self::Warning => text.bright_yellow(),
+        }
+    }
+
+    ///
+    /// Returns the severity title with the optional diagnostic `code`, which starts the
+    /// diagnostic message, e.g. `error[Z0407]`.
+    ///
+    fn title(self, code: Option<&str>) -> ColoredString {
+        match code {
+            Some(code) => self.paint(format!("{}[{}]", self, code).as_str()),
+            None => self.paint(self.to_string().as_str()),
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+///
+/// The compiler error data, which is extracted from the error for rendering or sending to
+/// the editor integrations.
+///
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    /// The diagnostic severity, which is `Error` unless the diagnostic is a lint warning.
+    pub severity: Severity,
+    /// The diagnostic code, which is the lint name for warnings.
+    pub code: Option<&'static str>,
+    /// The error message.
+    pub message: String,
+    /// The primary error location. `None` for locationless errors.
+    pub location: Option<Location>,
+    /// The end of the erroneous source code range, which starts at `location`.
+    pub end: Option<Location>,
+    /// The secondary location, which helps the user to fix the error more easily.
+    pub reference: Option<Location>,
+    /// The optional help message.
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    ///
+    /// Creates a locationless diagnostic, that is, not related to any specific place in the source code.
+    ///
+    pub fn new(message: &str, help: Option<&str>) -> Self {
+        Self {
+            severity: Severity::Error,
+            code: None,
+            message: message.to_owned(),
+            location: None,
+            end: None,
+            reference: None,
+            help: help.map(str::to_owned),
+        }
+    }
+
+    ///
+    /// Creates a diagnostic pointing to a single `location`.
+    ///
+    pub fn with_location(message: &str, location: Location, help: Option<&str>) -> Self {
+        Self {
+            severity: Severity::Error,
+            code: None,
+            message: message.to_owned(),
+            location: Some(location),
+            end: None,
+            reference: None,
+            help: help.map(str::to_owned),
+        }
+    }
+
+    ///
+    /// Creates a diagnostic pointing to a `location` with an optional second `reference` location.
+    ///
+    pub fn with_reference(
+        message: &str,
+        location: Location,
+        reference: Option<Location>,
+        help: Option<&str>,
+    ) -> Self {
+        Self {
+            severity: Severity::Error,
+            code: None,
+            message: message.to_owned(),
+            location: Some(location),
+            end: None,
+            reference,
+            help: help.map(str::to_owned),
+        }
+    }
+
+    ///
+    /// Creates a diagnostic with two location bounds, which enclose the erroneous part of the source code.
+    ///
+    pub fn with_range(message: &str, start: Location, end: Location, help: Option<&str>) -> Self {
+        Self {
+            severity: Severity::Error,
+            code: None,
+            message: message.to_owned(),
+            location: Some(start),
+            end: Some(end),
+            reference: None,
+            help: help.map(str::to_owned),
+        }
+    }
+
+    ///
+    /// Turns the diagnostic into a lint warning.
+    ///
+    pub fn into_warning(mut self) -> Self {
+        self.severity = Severity::Warning;
+        self
+    }
+
+    ///
+    /// Sets the diagnostic `code`.
+    ///
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    ///
+    /// Converts the diagnostic into the machine-readable JSON object.
+    ///
+    /// The primary span is the first one, and the reference span, if any, is the secondary one.
+    ///
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut spans = Vec::with_capacity(2);
+        if let Some(location) = self.location {
+            spans.push(Self::span_to_json(location, self.end, true));
+        }
+        if let Some(reference) = self.reference {
+            spans.push(Self::span_to_json(reference, None, false));
+        }
+
+        serde_json::json!({
+            "severity": self.severity.to_string(),
+            "code": self.code,
+            "message": self.message,
+            "spans": spans,
+            "help": self.help,
+        })
+    }
+
+    ///
+    /// Formats the diagnostic into the user-friendly readable output.
+    ///
+    pub fn format(&self) -> String {
+        let help = self.help.as_deref();
+        let message = self.message.as_str();
+
+        match (self.location, self.end) {
+            (Some(start), Some(end)) => {
+                Self::format_range(self.severity, self.code, message, start, end, help)
+            }
+            (Some(location), None) => Self::format_line(
+                self.severity,
+                self.code,
+                message,
+                location,
+                self.reference,
+                help,
+            ),
+            (None, _) => Self::format_message(self.severity, self.code, message, help),
+        }
+    }
+
+    ///
+    /// Converts the source code span starting at `location` into the JSON object.
+    ///
+    fn span_to_json(
+        location: Location,
+        end: Option<Location>,
+        is_primary: bool,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "file": FILE_INDEX.get_path(location.file).to_string_lossy(),
+            "line": location.line,
+            "column": location.column,
+            "end_line": end.map(|end| end.line),
+            "end_column": end.map(|end| end.column),
+            "is_primary": is_primary,
+        })
+    }
+
+    ///
+    /// Formats a `message` with an optional `help` message.
+    ///
+    /// The error is locationless, that is, not related to any specific place in the source code.
+    ///
+    fn format_message(
+        severity: Severity,
+        code: Option<&str>,
+        message: &str,
+        help: Option<&str>,
+    ) -> String {
+        let mut strings = Vec::with_capacity(8);
+        strings.push(String::new());
+        strings.push(format!(
+            "{}: {}",
+            severity.title(code),
+            message.bright_white()
+        ));
+        if let Some(help) = help {
+            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
+        }
+        strings.push(String::new());
+        strings.join("\n")
+    }
+
+    ///
+    /// Formats a `message` with an optional `help` message.
+    ///
+    /// The error may have a second location reference, which helps the user to fix the error more easily.
+    ///
+    fn format_line(
+        severity: Severity,
+        code: Option<&str>,
+        message: &str,
+        location: Location,
+        reference: Option<Location>,
+        help: Option<&str>,
+    ) -> String {
+        let index = FILE_INDEX
+            .inner
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let context = index
+            .get(&location.file)
+            .expect(zinc_const::panic::VALIDATED_DURING_SOURCE_CODE_MAPPING)
+            .code
+            .lines()
+            .collect::<Vec<&str>>();
+        let line_number_length = location.line.to_string().len();
+        let mut strings = Vec::with_capacity(11);
+        strings.push(String::new());
+        strings.push(format!(
+            "{}: {}",
+            severity.title(code),
+            message.bright_white()
+        ));
+        if let Some(reference) = reference {
+            let context = index
+                .get(&reference.file)
+                .expect(zinc_const::panic::VALIDATED_DURING_SOURCE_CODE_MAPPING)
+                .code
+                .lines()
+                .collect::<Vec<&str>>();
+            let line_number_length = reference.line.to_string().len();
+            strings.push(format!(
+                "{}{}",
+                " ".repeat(line_number_length + 1),
+                "|".bright_cyan()
+            ));
+            if let Some(line) = context.get(reference.line - 1) {
+                strings.push(format!(
+                    "{}{}",
+                    (reference.line.to_string() + " | ").bright_cyan(),
+                    line
+                ));
+            }
+            strings.push(format!(
+                "{}{} {}{}",
+                " ".repeat(line_number_length + 1),
+                "|".bright_cyan(),
+                severity.paint("_".repeat(reference.column - 1).as_str()),
+                severity.paint("^")
+            ));
+        }
+        strings.push(format!(" {} {}", "-->".bright_cyan(), location));
+        strings.push(format!(
+            "{}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan()
+        ));
+        if let Some(line) = context.get(location.line - 1) {
+            strings.push(format!(
+                "{}{}",
+                (location.line.to_string() + " | ").bright_cyan(),
+                line
+            ));
+        }
+        strings.push(format!(
+            "{}{} {}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan(),
+            severity.paint("_".repeat(location.column - 1).as_str()),
+            severity.paint("^")
+        ));
+        if let Some(help) = help {
+            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
+        }
+        strings.push(String::new());
+        strings.join("\n")
+    }
+
+    ///
+    /// Formats a `message` with an optional `help` message.
+    ///
+    /// The error has two location bounds, which enclose the erroneous part of the source code.
+    ///
+    fn format_range(
+        severity: Severity,
+        code: Option<&str>,
+        message: &str,
+        start: Location,
+        end: Location,
+        help: Option<&str>,
+    ) -> String {
+        let index = FILE_INDEX
+            .inner
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let context = index
+            .get(&start.file)
+            .expect(zinc_const::panic::VALIDATED_DURING_SOURCE_CODE_MAPPING)
+            .code
+            .lines()
+            .collect::<Vec<&str>>();
+        let line_number_length = end.line.to_string().len();
+        let mut strings = Vec::with_capacity(8 + end.line - start.line);
+        strings.push(String::new());
+        strings.push(format!(
+            "{}: {}",
+            severity.title(code),
+            message.bright_white()
+        ));
+        strings.push(format!(" {} {}", "-->".bright_cyan(), start));
+        strings.push(format!(
+            "{}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan()
+        ));
+        for line_number in start.line..=end.line {
+            if let Some(line) = context.get(line_number - 1) {
+                strings.push(format!(
+                    "{}{}",
+                    (line_number.to_string() + " | ").bright_cyan(),
+                    line
+                ));
+            }
+        }
+        strings.push(format!(
+            "{}{} {}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan(),
+            severity.paint("_".repeat(end.column - 1).as_str()),
+            severity.paint("^")
+        ));
+        if let Some(help) = help {
+            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
+        }
+        strings.push(String::new());
+        strings.join("\n")
+    }
+}
@@ -0,0 +1,4850 @@
+//!
+//! The Zinc compiler error.
+//!
+
+pub mod diagnostic;
+pub mod registry;
+
+use zinc_lexical::Error as LexicalError;
+use zinc_math::InferenceError;
+use zinc_syntax::Error as SyntaxError;
+use zinc_syntax::ParsingError;
+
+use crate::semantic::analyzer::attribute::error::Error as AttributeError;
+use crate::semantic::analyzer::expression::conditional::error::Error as ConditionalExpressionError;
+use crate::semantic::analyzer::expression::error::Error as ExpressionError;
+use crate::semantic::analyzer::expression::r#match::error::Error as MatchExpressionError;
+use crate::semantic::analyzer::statement::error::Error as StatementError;
+use crate::semantic::analyzer::statement::r#for::error::Error as ForStatementError;
+use crate::semantic::analyzer::statement::r#impl::error::Error as ImplStatementError;
+use crate::semantic::analyzer::statement::r#loop::error::Error as LoopStatementError;
+use crate::semantic::analyzer::statement::r#return::error::Error as ReturnStatementError;
+use crate::semantic::analyzer::statement::r#use::error::Error as UseStatementError;
+use crate::semantic::binding::error::Error as BindingError;
+use crate::semantic::casting::error::Error as CastingError;
+use crate::semantic::element::constant::array::error::Error as ArrayConstantError;
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
+use crate::semantic::element::constant::structure::error::Error as StructureConstantError;
+use crate::semantic::element::constant::tuple::error::Error as TupleConstantError;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::place::error::Error as PlaceError;
+use crate::semantic::element::r#type::contract::error::Error as ContractTypeError;
+use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::function::intrinsic::debug::error::Error as DebugFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::error::Error as IntrinsicFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::error::Error as StandardLibraryFunctionError;
+use crate::semantic::element::r#type::function::test::error::Error as TestFunctionError;
+use crate::semantic::element::r#type::generic::error::Error as GenericError;
+use crate::semantic::element::r#type::option::error::Error as OptionTypeError;
+use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
+use crate::semantic::element::value::array::error::Error as ArrayValueError;
+use crate::semantic::element::value::contract::error::Error as ContractValueError;
+use crate::semantic::element::value::error::Error as ValueError;
+use crate::semantic::element::value::integer::error::Error as IntegerValueError;
+use crate::semantic::element::value::structure::error::Error as StructureValueError;
+use crate::semantic::element::value::tuple::error::Error as TupleValueError;
+use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::error::Error as ScopeError;
+
+use self::diagnostic::Diagnostic;
+
+///
+/// The Zinc compiler error.
+///
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The lexical analysis error.
+    Lexical(LexicalError),
+    /// The syntax analysis error.
+    Syntax(SyntaxError),
+    /// The semantic analysis error.
+    Semantic(SemanticError),
+}
+
+impl Error {
+    ///
+    /// The codes, which are returned by `code`. Every one of them must have a registry entry.
+    ///
+    pub const CODES: &[&str] = &[
+        "Z0001", "Z0002", "Z0003", "Z0004", "Z0005", "Z0101", "Z0102", "Z0103", "Z0104", "Z0105",
+        "Z0106", "Z0107", "Z0108", "Z0109", "Z0110", "Z0111", "Z0112", "Z0201", "Z0202", "Z0203",
+        "Z0204", "Z0205", "Z0206", "Z0207", "Z0208", "Z0209", "Z0210", "Z0211", "Z0212", "Z0213",
+        "Z0214", "Z0215", "Z0216", "Z0217", "Z0218", "Z0219", "Z0301", "Z0302", "Z0303", "Z0304",
+        "Z0305", "Z0306", "Z0307", "Z0308", "Z0309", "Z0310", "Z0401", "Z0402", "Z0403", "Z0404",
+        "Z0405", "Z0406", "Z0407", "Z0408", "Z0409", "Z0410", "Z0411", "Z0412", "Z0501", "Z0502",
+        "Z0503", "Z0504", "Z0505", "Z0506", "Z0507", "Z0508", "Z0509", "Z0510", "Z0511", "Z0512",
+        "Z0513", "Z0514", "Z0601", "Z0602", "Z0603", "Z0604", "Z0605", "Z0606", "Z0607", "Z0608",
+        "Z0609", "Z0610", "Z0611", "Z0612", "Z0613", "Z0614", "Z0701", "Z0702", "Z0703", "Z0704",
+        "Z0705", "Z0706", "Z0707", "Z0708", "Z0709", "Z0710", "Z0711", "Z0712", "Z0713", "Z0714",
+        "Z0715", "Z0716", "Z0717", "Z0718", "Z0719", "Z0801", "Z0802", "Z0803", "Z0804", "Z0805",
+    ];
+
+    ///
+    /// Formats the compiler error into the user-friendly readable output.
+    ///
+    pub fn format(self) -> String {
+        self.diagnostic().format()
+    }
+
+    ///
+    /// Returns the stable error code, which is explained by `znc --explain <CODE>`.
+    ///
+    /// The match has no wildcard arm, so every new error variant must be assigned a code.
+    ///
+    pub fn code(&self) -> &'static str {
+        let code = match self {
+            Self::Lexical(LexicalError::UnterminatedBlockComment { .. }) => "Z0001",
+            Self::Lexical(LexicalError::UnterminatedDoubleQuoteString { .. }) => "Z0002",
+            Self::Lexical(LexicalError::ExpectedOneOfBinary { .. }) => "Z0003",
+            Self::Lexical(LexicalError::ExpectedOneOfOctal { .. }) => "Z0003",
+            Self::Lexical(LexicalError::ExpectedOneOfDecimal { .. }) => "Z0003",
+            Self::Lexical(LexicalError::ExpectedOneOfHexadecimal { .. }) => "Z0003",
+            Self::Lexical(LexicalError::InvalidCharacter { .. }) => "Z0004",
+            Self::Lexical(LexicalError::UnexpectedEnd { .. }) => "Z0005",
+            Self::Syntax(SyntaxError::ExpectedOneOf { .. }) => "Z0101",
+            Self::Syntax(SyntaxError::ExpectedOneOfOrOperator { .. }) => "Z0102",
+            Self::Syntax(SyntaxError::ExpectedIdentifier { .. }) => "Z0103",
+            Self::Syntax(SyntaxError::ExpectedMutOrIdentifier { .. }) => "Z0104",
+            Self::Syntax(SyntaxError::ExpectedFieldIdentifier { .. }) => "Z0105",
+            Self::Syntax(SyntaxError::ExpectedType { .. }) => "Z0106",
+            Self::Syntax(SyntaxError::ExpectedTypeOrValue { .. }) => "Z0107",
+            Self::Syntax(SyntaxError::ExpectedValue { .. }) => "Z0108",
+            Self::Syntax(SyntaxError::ExpectedExpressionOrOperand { .. }) => "Z0109",
+            Self::Syntax(SyntaxError::ExpectedIntegerLiteral { .. }) => "Z0110",
+            Self::Syntax(SyntaxError::ExpectedBindingPattern { .. }) => "Z0111",
+            Self::Syntax(SyntaxError::ExpectedMatchPattern { .. }) => "Z0112",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentFirstOperandExpectedPlace { .. },
+            )) => "Z0201",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentSecondOperandExpectedEvaluable { .. },
+            )) => "Z0202",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseOrFirstOperandExpectedPlace { .. },
+            )) => "Z0201",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseOrSecondOperandExpectedEvaluable { .. },
+            )) => "Z0202",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseXorFirstOperandExpectedPlace { .. },
+            )) => "Z0201",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseXorSecondOperandExpectedEvaluable { .. },
+            )) => "Z0202",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseAndFirstOperandExpectedPlace { .. },
+            )) => "Z0201",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseAndSecondOperandExpectedEvaluable { .. },
+            )) => "Z0202",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseShiftLeftFirstOperandExpectedPlace {
+                    ..
+                },
+            )) => "Z0201",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseShiftLeftSecondOperandExpectedEvaluable {
+                    ..
+                },
+            )) => "Z0202",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseShiftRightFirstOperandExpectedPlace {
+                    ..
+                },
+            )) => "Z0201",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseShiftRightSecondOperandExpectedEvaluable {
+                    ..
+                },
+            )) => "Z0202",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentAdditionFirstOperandExpectedPlace { .. },
+            )) => "Z0201",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentAdditionSecondOperandExpectedEvaluable { .. },
+            )) => "Z0202",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentSubtractionFirstOperandExpectedPlace { .. },
+            )) => "Z0201",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentSubtractionSecondOperandExpectedEvaluable {
+                    ..
+                },
+            )) => "Z0202",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentMultiplicationFirstOperandExpectedPlace { .. },
+            )) => "Z0201",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentMultiplicationSecondOperandExpectedEvaluable {
+                    ..
+                },
+            )) => "Z0202",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentDivisionFirstOperandExpectedPlace { .. },
+            )) => "Z0201",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentDivisionSecondOperandExpectedEvaluable { .. },
+            )) => "Z0202",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentRemainderFirstOperandExpectedPlace { .. },
+            )) => "Z0201",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentRemainderSecondOperandExpectedEvaluable { .. },
+            )) => "Z0202",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorRangeInclusiveFirstOperandExpectedConstant { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorRangeInclusiveFirstOperandExpectedInteger { .. },
+            ))) => "Z0203",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorRangeInclusiveSecondOperandExpectedConstant { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorRangeInclusiveSecondOperandExpectedInteger { .. },
+            ))) => "Z0203",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorRangeFirstOperandExpectedConstant { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorRangeFirstOperandExpectedInteger { .. },
+            ))) => "Z0203",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorRangeSecondOperandExpectedConstant { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorRangeSecondOperandExpectedInteger { .. },
+            ))) => "Z0203",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorOrFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorOrFirstOperandExpectedBoolean { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorOrFirstOperandExpectedBoolean { .. },
+            ))) => "Z0204",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorOrSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorOrSecondOperandExpectedBoolean { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorOrSecondOperandExpectedBoolean { .. },
+            ))) => "Z0204",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorXorFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorXorFirstOperandExpectedBoolean { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorXorFirstOperandExpectedBoolean { .. },
+            ))) => "Z0204",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorXorSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorXorSecondOperandExpectedBoolean { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorXorSecondOperandExpectedBoolean { .. },
+            ))) => "Z0204",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAndFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorAndFirstOperandExpectedBoolean { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorAndFirstOperandExpectedBoolean { .. },
+            ))) => "Z0204",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAndSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorAndSecondOperandExpectedBoolean { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorAndSecondOperandExpectedBoolean { .. },
+            ))) => "Z0204",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorEqualsFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorEqualsFirstOperandExpectedPrimitiveType { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorEqualsFirstOperandExpectedPrimitiveType { .. },
+            ))) => "Z0205",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorEqualsSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorEqualsSecondOperandExpectedUnit { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorEqualsSecondOperandExpectedBoolean { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorEqualsSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorEqualsSecondOperandExpectedUnit { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorEqualsSecondOperandExpectedBoolean { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorEqualsSecondOperandExpectedInteger { .. },
+            ))) => "Z0205",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorNotEqualsFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorNotEqualsFirstOperandExpectedPrimitiveType { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorNotEqualsFirstOperandExpectedPrimitiveType { .. },
+            ))) => "Z0205",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorNotEqualsSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorNotEqualsSecondOperandExpectedUnit { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorNotEqualsSecondOperandExpectedBoolean { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorNotEqualsSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorNotEqualsSecondOperandExpectedUnit { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorNotEqualsSecondOperandExpectedBoolean { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorNotEqualsSecondOperandExpectedInteger { .. },
+            ))) => "Z0205",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorGreaterEqualsFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorGreaterEqualsFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorGreaterEqualsFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorGreaterEqualsSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorGreaterEqualsSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorGreaterEqualsSecondOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorLesserEqualsFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorLesserEqualsFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorLesserEqualsFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorLesserEqualsSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorLesserEqualsSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorLesserEqualsSecondOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorGreaterFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorGreaterFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorGreaterFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorGreaterSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorGreaterSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorGreaterSecondOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorLesserFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorLesserFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorLesserFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorLesserSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorLesserSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorLesserSecondOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseOrFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseOrFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseOrFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseOrSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseOrSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseOrSecondOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseXorFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseXorFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseXorFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseXorSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseXorSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseXorSecondOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseAndFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseAndFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseAndFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseAndSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseAndSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseAndSecondOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseShiftLeftFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedConstant { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned {
+                    ..
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(
+                    IntegerConstantError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned {
+                        ..
+                    },
+                ),
+            ))) => "Z0207",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseShiftRightFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseShiftRightFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseShiftRightFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseShiftRightSecondOperandExpectedConstant { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseShiftRightSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned {
+                    ..
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseShiftRightSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(
+                    IntegerConstantError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned {
+                        ..
+                    },
+                ),
+            ))) => "Z0207",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAdditionFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorAdditionFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorAdditionFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAdditionSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorAdditionSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorAdditionSecondOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorSubtractionFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorSubtractionFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorSubtractionFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorSubtractionSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorSubtractionSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorSubtractionSecondOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorMultiplicationFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorMultiplicationFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorMultiplicationFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorMultiplicationSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorMultiplicationSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorMultiplicationSecondOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorDivisionFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorDivisionFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorDivisionFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorDivisionSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorDivisionSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorDivisionSecondOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorRemainderFirstOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorRemainderFirstOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorRemainderFirstOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorRemainderSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorRemainderSecondOperandExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorRemainderSecondOperandExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorCastingFirstOperandExpectedEvaluable { .. },
+            )) => "Z0208",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorCastingSecondOperandExpectedType { .. },
+            )) => "Z0208",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Casting {
+                ..
+            })))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Casting { .. },
+            ))) => "Z0209",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorNotExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorNotExpectedBoolean { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorNotExpectedBoolean { .. },
+            ))) => "Z0204",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseNotExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseNotExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseNotExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorNegationExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorNegationExpectedInteger { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorNegationExpectedInteger { .. },
+            ))) => "Z0206",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorIndexFirstOperandExpectedPlaceOrEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::OperatorIndexFirstOperandExpectedArray { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorIndexFirstOperandExpectedArray { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorIndexFirstOperandExpectedArray { .. },
+            ))) => "Z0210",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorIndexSecondOperandExpectedEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::OperatorIndexSecondOperandExpectedIntegerOrRange { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorIndexSecondOperandExpectedIntegerOrRange { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorIndexSecondOperandExpectedIntegerOrRange { .. },
+            ))) => "Z0210",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorDotFirstOperandExpectedPlaceOrEvaluable { .. },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::OperatorDotFirstOperandExpectedTuple { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::OperatorDotFirstOperandExpectedInstance { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorDotFirstOperandExpectedTuple { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorDotFirstOperandExpectedInstance { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorDotFirstOperandExpectedTuple { .. },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorDotFirstOperandExpectedInstance { .. },
+            ))) => "Z0211",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorDotSecondOperandExpectedIdentifier { .. },
+            )) => "Z0211",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorPathFirstOperandExpectedPath { .. },
+            )) => "Z0212",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorPathSecondOperandExpectedIdentifier { .. },
+            )) => "Z0212",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorStructureFirstOperandExpectedType { .. },
+            )) => "Z0213",
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorStructureSecondOperandExpectedLiteral { .. },
+            )) => "Z0213",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Array(
+                ArrayValueError::PushingInvalidType { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Array(ArrayConstantError::PushingInvalidType { .. }),
+            ))) => "Z0301",
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Array(ArrayConstantError::IndexOutOfRange { .. }),
+            ))) => "Z0302",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Array(
+                ArrayValueError::SliceStartOutOfRange { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Array(ArrayConstantError::SliceStartOutOfRange { .. }),
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::ArraySliceStartOutOfRange { .. },
+            ))) => "Z0303",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Array(
+                ArrayValueError::SliceEndOutOfRange { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Array(ArrayConstantError::SliceEndOutOfRange { .. }),
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::ArraySliceEndOutOfRange { .. },
+            ))) => "Z0303",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Array(
+                ArrayValueError::SliceEndLesserThanStart { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Array(ArrayConstantError::SliceEndLesserThanStart { .. }),
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::ArraySliceEndLesserThanStart { .. },
+            ))) => "Z0303",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::NotInitialized { .. },
+            )))) => "Z0304",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Tuple(
+                TupleValueError::FieldOutOrRange { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Tuple(TupleConstantError::FieldOutOrRange { .. }),
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::TupleFieldOutOfRange { .. },
+            ))) => "Z0305",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::FieldDoesNotExist { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Structure(StructureConstantError::FieldDoesNotExist { .. }),
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::StructureFieldDoesNotExist { .. },
+            ))) => "Z0306",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Contract(
+                ContractValueError::FieldDoesNotExist { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::ContractFieldDoesNotExist { .. },
+            ))) => "Z0306",
+            Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::MutatingWithDifferentType { .. },
+            ))) => "Z0307",
+            Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::MutatingImmutableMemory { .. },
+            ))) => "Z0308",
+            Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::MutatingImmutableContractField { .. },
+            ))) => "Z0309",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::FieldExpected { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Contract(
+                ContractValueError::FieldExpected { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Structure(StructureConstantError::FieldExpected { .. }),
+            ))) => "Z0310",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::FieldInvalidType { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Contract(
+                ContractValueError::FieldInvalidType { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Structure(StructureConstantError::FieldInvalidType { .. }),
+            ))) => "Z0310",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::FieldOutOfRange { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Contract(
+                ContractValueError::FieldOutOfRange { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Structure(StructureConstantError::FieldOutOfRange { .. }),
+            ))) => "Z0310",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::FieldMissing { .. },
+            )))) => "Z0310",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchEquals { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchEquals { .. }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchNotEquals { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchNotEquals { .. }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchGreaterEquals { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchGreaterEquals { .. }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchLesserEquals { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchLesserEquals { .. }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchGreater { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchGreater { .. }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchLesser { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchLesser { .. }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchBitwiseOr { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseOr { .. }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchBitwiseXor { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseXor { .. }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchBitwiseAnd { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseAnd { .. }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchAddition { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchAddition { .. }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchSubtraction { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchSubtraction { .. }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchMultiplication { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchMultiplication {
+                    ..
+                }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchDivision { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchDivision { .. }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchRemainder { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchRemainder { .. }),
+            ))) => "Z0214",
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowAddition { .. }),
+            ))) => "Z0215",
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowSubtraction { .. }),
+            ))) => "Z0215",
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowMultiplication { .. }),
+            ))) => "Z0215",
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowDivision { .. }),
+            ))) => "Z0215",
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowRemainder { .. }),
+            ))) => "Z0215",
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowCasting { .. }),
+            ))) => "Z0215",
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowNegation { .. }),
+            ))) => "Z0215",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::ForbiddenFieldDivision { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ForbiddenFieldDivision { .. }),
+            ))) => "Z0216",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::ForbiddenFieldRemainder { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ForbiddenFieldRemainder { .. }),
+            ))) => "Z0216",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::ForbiddenSignedBitwise { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ForbiddenSignedBitwise { .. }),
+            ))) => "Z0216",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::ForbiddenFieldBitwise { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ForbiddenFieldBitwise { .. }),
+            ))) => "Z0216",
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::ForbiddenFieldNegation { .. },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ForbiddenFieldNegation { .. }),
+            ))) => "Z0216",
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ZeroDivision { .. }),
+            ))) => "Z0217",
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ZeroRemainder { .. }),
+            ))) => "Z0217",
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::IntegerTooLarge { .. }),
+            ))) => "Z0218",
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::Parsing { .. }),
+            ))) => "Z0219",
+            Self::Semantic(SemanticError::Element(ElementError::Type(
+                TypeError::TypeRequired { .. },
+            ))) => "Z0401",
+            Self::Semantic(SemanticError::Element(ElementError::Type(
+                TypeError::AliasDoesNotPointToType { .. },
+            ))) => "Z0402",
+            Self::Semantic(SemanticError::Element(ElementError::Type(
+                TypeError::UnexpectedGenerics { .. },
+            ))) => "Z0403",
+            Self::Semantic(SemanticError::Element(ElementError::Type(
+                TypeError::InstantiationForbidden { .. },
+            ))) => "Z0404",
+            Self::Semantic(SemanticError::Element(ElementError::Type(
+                TypeError::FunctionTypeOutsideArgument { .. },
+            ))) => "Z0405",
+            Self::Semantic(SemanticError::Scope(ScopeError::ItemRedeclared { .. })) => "Z0406",
+            Self::Semantic(SemanticError::Scope(ScopeError::ItemUndeclared { .. })) => "Z0407",
+            Self::Semantic(SemanticError::Scope(ScopeError::ItemIsNotANamespace { .. })) => "Z0408",
+            Self::Semantic(SemanticError::Scope(ScopeError::AssociatedItemWithoutOwner {
+                ..
+            })) => "Z0409",
+            Self::Semantic(SemanticError::Scope(ScopeError::ContractRedeclared { .. })) => "Z0410",
+            Self::Semantic(SemanticError::Scope(ScopeError::ReferenceLoop { .. })) => "Z0411",
+            Self::Semantic(SemanticError::Scope(ScopeError::ClosureCapture { .. })) => "Z0412",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::ArgumentCount { .. },
+            )))) => "Z0501",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::ArgumentType { .. },
+            )))) => "Z0502",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::ArgumentConstantness { .. },
+            )))) => "Z0503",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::ArgumentNotEvaluable { .. },
+            )))) => "Z0504",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::ReturnType { .. },
+            )))) => "Z0505",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::NonCallable { .. },
+            )))) => "Z0506",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::CallingMutableFromImmutable { .. },
+            )))) => "Z0507",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::Unknown { .. }),
+            )))) => "Z0508",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::ExclamationMarkMissing { .. }),
+            )))) => "Z0509",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::Debug(
+                    DebugFunctionError::ArgumentCount { .. },
+                )),
+            )))) => "Z0501",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::StandardLibrary(
+                    StandardLibraryFunctionError::ArrayTruncatingToBiggerSize { .. },
+                )),
+            )))) => "Z0510",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::StandardLibrary(
+                    StandardLibraryFunctionError::ArrayPaddingToLesserSize { .. },
+                )),
+            )))) => "Z0510",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::StandardLibrary(
+                    StandardLibraryFunctionError::ArrayNewLengthInvalid { .. },
+                )),
+            )))) => "Z0510",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::StandardLibrary(
+                    StandardLibraryFunctionError::ArraySumEmpty { .. },
+                )),
+            )))) => "Z0510",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::StandardLibrary(
+                    StandardLibraryFunctionError::ArrayZipSizeMismatch { .. },
+                )),
+            )))) => "Z0510",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Test(TestFunctionError::CallForbidden { .. }),
+            )))) => "Z0511",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Test(TestFunctionError::BeyondModuleScope { .. }),
+            )))) => "Z0511",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Test(TestFunctionError::PublicForbidden { .. }),
+            )))) => "Z0511",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Test(TestFunctionError::ConstantForbidden { .. }),
+            )))) => "Z0511",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Test(TestFunctionError::CannotHaveArguments { .. }),
+            )))) => "Z0511",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Test(TestFunctionError::CannotReturnValue { .. }),
+            )))) => "Z0511",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Structure(
+                StructureTypeError::DuplicateField { .. },
+            )))) => "Z0601",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Structure(
+                StructureTypeError::ExpectedGenerics { .. },
+            )))) => "Z0602",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Structure(
+                StructureTypeError::UnexpectedGenerics { .. },
+            )))) => "Z0602",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Structure(
+                StructureTypeError::InvalidGenericsNumber { .. },
+            )))) => "Z0602",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Option(
+                OptionTypeError::ExpectedGenerics { .. },
+            )))) => "Z0603",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Option(
+                OptionTypeError::InvalidGenericsNumber { .. },
+            )))) => "Z0603",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Option(
+                OptionTypeError::PayloadTypeNotInferred { .. },
+            )))) => "Z0603",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::DuplicateVariantValue { .. },
+            )))) => "Z0604",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::VariantValueWithData { .. },
+            )))) => "Z0605",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::VariantValueMissing { .. },
+            )))) => "Z0605",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::DuplicateVariantField { .. },
+            )))) => "Z0606",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Contract(
+                ContractTypeError::DuplicateField { .. },
+            )))) => "Z0601",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::DuplicateParameter { .. },
+            )))) => "Z0607",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::ConstantParameterForbidden { .. },
+            )))) => "Z0608",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::ConstantParameterExpectedInteger { .. },
+            )))) => "Z0608",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::ConstantArgumentOverflow { .. },
+            )))) => "Z0609",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::CannotInfer { .. },
+            )))) => "Z0610",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::InferenceConflict { .. },
+            )))) => "Z0610",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::BoundExpectedTrait { .. },
+            )))) => "Z0611",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::BoundNotSatisfied { .. },
+            )))) => "Z0611",
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::EntryPoint { .. },
+            )))) => "Z0612",
+            Self::Semantic(SemanticError::Expression(ExpressionError::NonConstantElement {
+                ..
+            })) => "Z0613",
+            Self::Semantic(SemanticError::Expression(ExpressionError::TraitAsOperand {
+                ..
+            })) => "Z0614",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::ScrutineeInvalidType { .. },
+            ))) => "Z0701",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::NotExhausted { .. },
+            ))) => "Z0702",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::LessThanTwoBranches { .. },
+            ))) => "Z0703",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchUnreachable { .. },
+            ))) => "Z0704",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternPathExpectedConstant { .. },
+            ))) => "Z0705",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternExpectedVariant { .. },
+            ))) => "Z0705",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternNestedExpectedBinding { .. },
+            ))) => "Z0705",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternVariantFieldCount { .. },
+            ))) => "Z0705",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternVariantFieldUnknown { .. },
+            ))) => "Z0705",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternVariantFieldMissing { .. },
+            ))) => "Z0705",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternVariantKindMismatch { .. },
+            ))) => "Z0705",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternInvalidType { .. },
+            ))) => "Z0705",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchExpressionInvalidType { .. },
+            ))) => "Z0706",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchDuplicate { .. },
+            ))) => "Z0707",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Conditional(
+                ConditionalExpressionError::ExpectedBooleanCondition { .. },
+            ))) => "Z0708",
+            Self::Semantic(SemanticError::Expression(ExpressionError::Conditional(
+                ConditionalExpressionError::BranchTypesMismatch { .. },
+            ))) => "Z0709",
+            Self::Semantic(SemanticError::Statement(StatementError::For(
+                ForStatementError::WhileExpectedBooleanCondition { .. },
+            ))) => "Z0708",
+            Self::Semantic(SemanticError::Statement(StatementError::For(
+                ForStatementError::BoundsExpectedConstantRangeExpression { .. },
+            ))) => "Z0710",
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::BoundMissing { .. },
+            ))) => "Z0711",
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::BoundDuplicate { .. },
+            ))) => "Z0711",
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::BoundExpectedSingleArgument { .. },
+            ))) => "Z0711",
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::BoundExpectedConstantInteger { .. },
+            ))) => "Z0711",
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::WhileExpectedBooleanCondition { .. },
+            ))) => "Z0708",
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::ExpectedBreak { .. },
+            ))) => "Z0712",
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::BreakOutsideLoop { .. },
+            ))) => "Z0713",
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::ContinueOutsideLoop { .. },
+            ))) => "Z0713",
+            Self::Semantic(SemanticError::Statement(StatementError::Return(
+                ReturnStatementError::OutsideFunction { .. },
+            ))) => "Z0714",
+            Self::Semantic(SemanticError::Statement(StatementError::Return(
+                ReturnStatementError::ConstantFunction { .. },
+            ))) => "Z0714",
+            Self::Semantic(SemanticError::Statement(StatementError::Use(
+                UseStatementError::ExpectedPath { .. },
+            ))) => "Z0715",
+            Self::Semantic(SemanticError::Statement(StatementError::Impl(
+                ImplStatementError::ExpectedStructureOrEnumeration { .. },
+            ))) => "Z0716",
+            Self::Semantic(SemanticError::Statement(StatementError::Impl(
+                ImplStatementError::ExpectedTrait { .. },
+            ))) => "Z0716",
+            Self::Semantic(SemanticError::Statement(StatementError::Impl(
+                ImplStatementError::MissingTraitMethod { .. },
+            ))) => "Z0717",
+            Self::Semantic(SemanticError::Statement(StatementError::Impl(
+                ImplStatementError::UnexpectedTraitMethod { .. },
+            ))) => "Z0717",
+            Self::Semantic(SemanticError::Statement(StatementError::Impl(
+                ImplStatementError::TraitMethodArgumentCount { .. },
+            ))) => "Z0717",
+            Self::Semantic(SemanticError::Statement(StatementError::Impl(
+                ImplStatementError::DuplicateTraitImplementation { .. },
+            ))) => "Z0717",
+            Self::Semantic(SemanticError::Attribute(AttributeError::Unknown { .. })) => "Z0718",
+            Self::Semantic(SemanticError::Attribute(AttributeError::AllowExpectedLints {
+                ..
+            })) => "Z0719",
+            Self::Semantic(SemanticError::Attribute(AttributeError::AllowExpectedLintName {
+                ..
+            })) => "Z0719",
+            Self::Semantic(SemanticError::Attribute(AttributeError::LintUnknown { .. })) => "Z0719",
+            Self::Semantic(SemanticError::Binding(BindingError::ExpectedTuple { .. })) => "Z0512",
+            Self::Semantic(SemanticError::Binding(BindingError::FunctionMethodSelfNotFirst {
+                ..
+            })) => "Z0513",
+            Self::Semantic(SemanticError::Binding(
+                BindingError::FunctionArgumentDestructuringUnavailable { .. },
+            )) => "Z0514",
+            Self::Semantic(SemanticError::EntryPointMissing) => "Z0801",
+            Self::Semantic(SemanticError::EntryPointAmbiguous { .. }) => "Z0802",
+            Self::Semantic(SemanticError::EntryPointConstant { .. }) => "Z0803",
+            Self::Semantic(SemanticError::FunctionMainBeyondEntry { .. }) => "Z0804",
+            Self::Semantic(SemanticError::ContractBeyondEntry { .. }) => "Z0804",
+            Self::Semantic(SemanticError::ModuleFileNotFound { .. }) => "Z0805",
+        };
+
+        debug_assert!(
+            Self::CODES.contains(&code),
+            "{} is missing in the code list",
+            code
+        );
+        code
+    }
+
+    ///
+    /// Extracts the message, locations, and help from the compiler error.
+    ///
+    pub fn diagnostic(self) -> Diagnostic {
+        let code = self.code();
+
+        let diagnostic = match self {
+            Self::Lexical(LexicalError::UnterminatedBlockComment { start, end }) => {
+                Diagnostic::with_range("unterminated block comment", start, end, None)
+            }
+            Self::Lexical(LexicalError::UnterminatedDoubleQuoteString { start, end }) => {
+                Diagnostic::with_range("unterminated double quote string", start, end, None)
+            }
+            Self::Lexical(LexicalError::ExpectedOneOfBinary {
+                location,
+                expected,
+                found,
+            }) => Diagnostic::with_location(
+                format!(
+                    "expected one of binary symbols {} or '_', found `{}`",
+                    expected, found
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Lexical(LexicalError::ExpectedOneOfOctal {
+                location,
+                expected,
+                found,
+            }) => Diagnostic::with_location(
+                format!(
+                    "expected one of octal symbols {} or '_', found `{}`",
+                    expected, found
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Lexical(LexicalError::ExpectedOneOfDecimal {
+                location,
+                expected,
+                found,
+            }) => Diagnostic::with_location(
+                format!(
+                    "expected one of decimal symbols {} or '_', found `{}`",
+                    expected, found
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Lexical(LexicalError::ExpectedOneOfHexadecimal {
+                location,
+                expected,
+                found,
+            }) => Diagnostic::with_location(
+                format!(
+                    "expected one of hexadecimal symbols {} or '_', found `{}`",
+                    expected, found
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Lexical(LexicalError::InvalidCharacter { location, found }) => {
+                Diagnostic::with_location(
+                    format!("invalid character `{}`", found).as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Lexical(LexicalError::UnexpectedEnd { location }) => {
+                Diagnostic::with_location("unexpected end of input", location, None)
+            }
+            Self::Syntax(SyntaxError::ExpectedOneOf {
+                location,
+                expected,
+                found,
+                help,
+            }) => Diagnostic::with_location(
+                format!("expected one of {}, found `{}`", expected, found).as_str(),
+                location,
+                help,
+            ),
+            Self::Syntax(SyntaxError::ExpectedOneOfOrOperator {
+                location,
+                expected,
+                found,
+                help,
+            }) => Diagnostic::with_location(
+                format!(
+                    "expected one of {} or an operator, found `{}`",
+                    expected, found
+                )
+                .as_str(),
+                location,
+                help,
+            ),
+            Self::Syntax(SyntaxError::ExpectedIdentifier {
+                location,
+                found,
+                help,
+            }) => Diagnostic::with_location(
+                format!("expected identifier, found `{}`", found).as_str(),
+                location,
+                help,
+            ),
+            Self::Syntax(SyntaxError::ExpectedMutOrIdentifier {
+                location,
+                found,
+                help,
+            }) => Diagnostic::with_location(
+                format!("expected keyword `mut` or identifier, found `{}`", found).as_str(),
+                location,
+                help,
+            ),
+            Self::Syntax(SyntaxError::ExpectedFieldIdentifier {
+                location,
+                found,
+                help,
+            }) => Diagnostic::with_location(
+                format!("expected field identifier, found `{}`", found).as_str(),
+                location,
+                help,
+            ),
+            Self::Syntax(SyntaxError::ExpectedType {
+                location,
+                found,
+                help,
+            }) => Diagnostic::with_location(
+                format!("expected type, found `{}`", found).as_str(),
+                location,
+                help,
+            ),
+            Self::Syntax(SyntaxError::ExpectedTypeOrValue {
+                location,
+                found,
+                help,
+            }) => Diagnostic::with_location(
+                format!(
+                    "expected `:` with type or `=` with value, found `{}`",
+                    found
+                )
+                .as_str(),
+                location,
+                help,
+            ),
+            Self::Syntax(SyntaxError::ExpectedValue {
+                location,
+                found,
+                help,
+            }) => Diagnostic::with_location(
+                format!("expected `=` with value, found `{}`", found).as_str(),
+                location,
+                help,
+            ),
+            Self::Syntax(SyntaxError::ExpectedExpressionOrOperand { location, found }) => {
+                Diagnostic::with_location(
+                    format!("expected expression or operand, found `{}`", found).as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Syntax(SyntaxError::ExpectedIntegerLiteral { location, found }) => {
+                Diagnostic::with_location(
+                    format!("expected integer literal, found `{}`", found).as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Syntax(SyntaxError::ExpectedBindingPattern { location, found }) => {
+                Diagnostic::with_location(
+                    format!("expected identifier or `_`, found `{}`", found).as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Syntax(SyntaxError::ExpectedMatchPattern { location, found }) => {
+                Diagnostic::with_location(
+                    format!(
+                        "expected identifier, boolean or integer literal, path, or `_`, found `{}`",
+                        found
+                    )
+                    .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentFirstOperandExpectedPlace { location, found },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment operator `=` expected a memory place as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentSecondOperandExpectedEvaluable { location, found },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment operator `=` expected a value as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseOrFirstOperandExpectedPlace {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment bitwise OR operator `|=` expected a memory place as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseOrSecondOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment bitwise OR operator `|=` expected a value as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseXorFirstOperandExpectedPlace {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment bitwise XOR operator `^=` expected a memory place as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseXorSecondOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment bitwise XOR operator `^=` expected a value as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseAndFirstOperandExpectedPlace {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment bitwise AND operator `&=` expected a memory place as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseAndSecondOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment bitwise AND operator `&=` expected a value as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseShiftLeftFirstOperandExpectedPlace {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment bitwise shift left operator `<<=` expected a memory place as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseShiftLeftSecondOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment bitwise shift left operator `<<=` expected a value as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseShiftRightFirstOperandExpectedPlace {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment bitwise shift right operator `>>=` expected a memory place as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentBitwiseShiftRightSecondOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment bitwise shift right operator `>>=` expected a value as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentAdditionFirstOperandExpectedPlace {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment operator `+=` expected a memory place as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentAdditionSecondOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment operator `+=` expected a value as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentSubtractionFirstOperandExpectedPlace {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment operator `-=` expected a memory place as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentSubtractionSecondOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment operator `-=` expected a value as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentMultiplicationFirstOperandExpectedPlace {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment operator `*=` expected a memory place as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentMultiplicationSecondOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment operator `*=` expected a value as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentDivisionFirstOperandExpectedPlace {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment operator `/=` expected a memory place as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentDivisionSecondOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment operator `/=` expected a value as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentRemainderFirstOperandExpectedPlace {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment operator `%=` expected a memory place as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAssignmentRemainderSecondOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the assignment operator `%=` expected a value as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorRangeInclusiveFirstOperandExpectedConstant {
+                    location,
+                    found,
+                },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorRangeInclusiveFirstOperandExpectedInteger {
+                    location,
+                    found,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the inclusive range operator `..=` expected an integer constant as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorRangeInclusiveSecondOperandExpectedConstant {
+                    location,
+                    found,
+                },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorRangeInclusiveSecondOperandExpectedInteger {
+                    location,
+                    found,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the inclusive range operator `..=` expected an integer constant as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorRangeFirstOperandExpectedConstant { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorRangeFirstOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the range operator `..` expected an integer constant as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorRangeSecondOperandExpectedConstant { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorRangeSecondOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the range operator `..` expected an integer constant as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorOrFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorOrFirstOperandExpectedBoolean { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorOrFirstOperandExpectedBoolean { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the OR operator `||` expected a boolean as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorOrSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorOrSecondOperandExpectedBoolean { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorOrSecondOperandExpectedBoolean { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the OR operator `||` expected a boolean as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorXorFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorXorFirstOperandExpectedBoolean { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorXorFirstOperandExpectedBoolean { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the XOR operator `^^` expected a boolean as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorXorSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorXorSecondOperandExpectedBoolean { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorXorSecondOperandExpectedBoolean { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the XOR operator `^^` expected a boolean as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAndFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorAndFirstOperandExpectedBoolean { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorAndFirstOperandExpectedBoolean { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the AND operator `&&` expected a boolean as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAndSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorAndSecondOperandExpectedBoolean { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorAndSecondOperandExpectedBoolean { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the AND operator `&&` expected a boolean as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorEqualsFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorEqualsFirstOperandExpectedPrimitiveType { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorEqualsFirstOperandExpectedPrimitiveType { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the equals operator `==` expected a unit, boolean or integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorEqualsSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorEqualsSecondOperandExpectedUnit { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorEqualsSecondOperandExpectedBoolean { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorEqualsSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorEqualsSecondOperandExpectedUnit { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorEqualsSecondOperandExpectedBoolean { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorEqualsSecondOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the equals operator `==` expected a unit, boolean or integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorNotEqualsFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorNotEqualsFirstOperandExpectedPrimitiveType { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
+                    location,
+                    found,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the not equals operator `!=` expected a boolean or integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorNotEqualsSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorNotEqualsSecondOperandExpectedUnit { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorNotEqualsSecondOperandExpectedBoolean { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorNotEqualsSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorNotEqualsSecondOperandExpectedUnit { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorNotEqualsSecondOperandExpectedBoolean { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorNotEqualsSecondOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the not equals operator `!=` expected a boolean or integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorGreaterEqualsFirstOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorGreaterEqualsFirstOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorGreaterEqualsFirstOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the greater equals operator `>=` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorGreaterEqualsSecondOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorGreaterEqualsSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorGreaterEqualsSecondOperandExpectedInteger {
+                    location,
+                    found,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the greater equals operator `>=` expected an integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorLesserEqualsFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorLesserEqualsFirstOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorLesserEqualsFirstOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the lesser equals operator `<=` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorLesserEqualsSecondOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorLesserEqualsSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorLesserEqualsSecondOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the lesser equals operator `<=` expected an integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorGreaterFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorGreaterFirstOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorGreaterFirstOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the greater operator `>` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorGreaterSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorGreaterSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorGreaterSecondOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the greater operator `>` expected an integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorLesserFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorLesserFirstOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorLesserFirstOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the lesser operator `<` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorLesserSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorLesserSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorLesserSecondOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the lesser operator `<` expected an integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseOrFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseOrFirstOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseOrFirstOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise OR operator `|` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseOrSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseOrSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseOrSecondOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise OR operator `|` expected an integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseXorFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseXorFirstOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseXorFirstOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise XOR operator `^` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseXorSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseXorSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseXorSecondOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise XOR operator `^` expected an integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseAndFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseAndFirstOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseAndFirstOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise AND operator `&` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseAndSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseAndSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseAndSecondOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise AND operator `&` expected an integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseShiftLeftFirstOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger {
+                    location,
+                    found,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise shift left operator `<<` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedConstant {
+                    location,
+                    found,
+                },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger {
+                    location,
+                    found,
+                },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned {
+                    location,
+                    found,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger {
+                    location,
+                    found,
+                },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(
+                    IntegerConstantError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned {
+                        location,
+                        found,
+                    },
+                ),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise shift left operator `<<` expected an unsigned integer constant as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseShiftRightFirstOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseShiftRightFirstOperandExpectedInteger {
+                    location,
+                    found,
+                },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseShiftRightFirstOperandExpectedInteger {
+                    location,
+                    found,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise shift right operator `>>` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseShiftRightSecondOperandExpectedConstant {
+                    location,
+                    found,
+                },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseShiftRightSecondOperandExpectedInteger {
+                    location,
+                    found,
+                },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned {
+                    location,
+                    found,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseShiftRightSecondOperandExpectedInteger {
+                    location,
+                    found,
+                },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(
+                    IntegerConstantError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned {
+                        location,
+                        found,
+                    },
+                ),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise shift right operator `>>` expected an unsigned integer constant as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAdditionFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorAdditionFirstOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorAdditionFirstOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the addition operator `+` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorAdditionSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorAdditionSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorAdditionSecondOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the addition operator `+` expected an integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorSubtractionFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorSubtractionFirstOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorSubtractionFirstOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the subtraction operator `-` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorSubtractionSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorSubtractionSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorSubtractionSecondOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the subtraction operator `-` expected an integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorMultiplicationFirstOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorMultiplicationFirstOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorMultiplicationFirstOperandExpectedInteger {
+                    location,
+                    found,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the multiplication operator `*` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorMultiplicationSecondOperandExpectedEvaluable {
+                    location,
+                    found,
+                },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorMultiplicationSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorMultiplicationSecondOperandExpectedInteger {
+                    location,
+                    found,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the multiplication operator `*` expected an integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorDivisionFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorDivisionFirstOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorDivisionFirstOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the division operator `/` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorDivisionSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorDivisionSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorDivisionSecondOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the division operator `/` expected an integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorRemainderFirstOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorRemainderFirstOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorRemainderFirstOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the remainder operator `%` expected an integer as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorRemainderSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorRemainderSecondOperandExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorRemainderSecondOperandExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the remainder operator `%` expected an integer as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorCastingFirstOperandExpectedEvaluable { location, found },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the casting operator `as` expected a value as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorCastingSecondOperandExpectedType { location, found },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the casting operator `as` expected a type as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Casting {
+                location,
+                inner: CastingError::CastingFromInvalidType { from, to },
+                reference,
+            })))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Casting {
+                location,
+                inner: CastingError::CastingToInvalidType { from, to },
+                reference,
+            })))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Casting {
+                    location,
+                    inner: CastingError::CastingFromInvalidType { from, to },
+                    reference,
+                },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Casting {
+                    location,
+                    inner: CastingError::CastingToInvalidType { from, to },
+                    reference,
+                },
+            ))) => Diagnostic::with_reference(
+                format!("cannot cast from `{}` to `{}`", from, to,).as_str(),
+                location,
+                Some(reference),
+                Some("only integer values can be casted to an integer with different bitlength or field element"),
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorNotExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorNotExpectedBoolean { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorNotExpectedBoolean { location, found },
+            ))) => Diagnostic::with_location(
+                format!("the NOT operator `!` expected a boolean, found `{}`", found,).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorBitwiseNotExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorBitwiseNotExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorBitwiseNotExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise NOT operator `~` expected an integer, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorNegationExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorNegationExpectedInteger { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorNegationExpectedInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the negation operator `-` expected an integer, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorIndexFirstOperandExpectedPlaceOrEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::OperatorIndexFirstOperandExpectedArray { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorIndexFirstOperandExpectedArray { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorIndexFirstOperandExpectedArray { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the index operator `[]` expected an array as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorIndexSecondOperandExpectedEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::OperatorIndexSecondOperandExpectedIntegerOrRange { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorIndexSecondOperandExpectedIntegerOrRange { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorIndexSecondOperandExpectedIntegerOrRange { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the index operator `[]` expected an integer or range as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorDotFirstOperandExpectedPlaceOrEvaluable { location, found },
+            ))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::OperatorDotFirstOperandExpectedTuple { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::OperatorDotFirstOperandExpectedInstance { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorDotFirstOperandExpectedTuple { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(
+                ValueError::OperatorDotFirstOperandExpectedInstance { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorDotFirstOperandExpectedTuple { location, found },
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::OperatorDotFirstOperandExpectedInstance { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the field access operator `.` expected a tuple or object instance as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorDotSecondOperandExpectedIdentifier { location, found },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the field access operator `.` expected a tuple or object instance field identifier as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorPathFirstOperandExpectedPath { location, found },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the path resolution operator `::` expected an item identifier as the first operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorPathSecondOperandExpectedIdentifier { location, found },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the path resolution operator `::` expected an item identifier as the second operand, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorStructureFirstOperandExpectedType { location, found },
+            )) => Diagnostic::with_location(
+                format!("the path must point to a structure type, found `{}`", found,).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                ElementError::OperatorStructureSecondOperandExpectedLiteral { location, found },
+            )) => Diagnostic::with_location(
+                format!(
+                    "the structure type expected a structure literal, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Array(
+                ArrayValueError::PushingInvalidType {
+                    location,
+                    expected,
+                    found,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Array(ArrayConstantError::PushingInvalidType {
+                    location,
+                    expected,
+                    found,
+                }),
+            ))) => Diagnostic::with_location(
+                format!("expected `{}`, found `{}`", expected, found,).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Array(ArrayConstantError::IndexOutOfRange {
+                    location,
+                    index,
+                    size,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "index `{}` is out of range of the array of size {}",
+                    index, size,
+                )
+                .as_str(),
+                location,
+                Some("array index must be within the array size"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Array(
+                ArrayValueError::SliceStartOutOfRange { location, start },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Array(ArrayConstantError::SliceStartOutOfRange { location, start }),
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::ArraySliceStartOutOfRange { location, start },
+            ))) => Diagnostic::with_location(
+                format!("left slice bound `{}` is negative", start,).as_str(),
+                location,
+                Some("slice range bounds must be within the array size"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Array(
+                ArrayValueError::SliceEndOutOfRange {
+                    location,
+                    end,
+                    size,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Array(ArrayConstantError::SliceEndOutOfRange {
+                    location,
+                    end,
+                    size,
+                }),
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::ArraySliceEndOutOfRange {
+                    location,
+                    end,
+                    size,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "right slice bound `{}` is out of range of the array of size {}",
+                    end, size,
+                )
+                .as_str(),
+                location,
+                Some("slice range bounds must be within the array size"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Array(
+                ArrayValueError::SliceEndLesserThanStart {
+                    location,
+                    start,
+                    end,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Array(ArrayConstantError::SliceEndLesserThanStart {
+                    location,
+                    start,
+                    end,
+                }),
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::ArraySliceEndLesserThanStart {
+                    location,
+                    start,
+                    end,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "left slice bound `{}` is greater than right slice bound `{}`",
+                    start, end,
+                )
+                .as_str(),
+                location,
+                Some("left slice range bound must be lesser or equal to the right one"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::NotInitialized {
+                    location,
+                    type_identifier,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "`{}` must be initialized with a structure literal",
+                    type_identifier,
+                )
+                .as_str(),
+                location,
+                Some(
+                    format!(
+                        "consider initializing the value, e.g. `{} {{ a: 42, b: 25, ... }}`",
+                        type_identifier
+                    )
+                    .as_str(),
+                ),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Tuple(
+                TupleValueError::FieldOutOrRange {
+                    location,
+                    type_identifier,
+                    field_index,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Tuple(TupleConstantError::FieldOutOrRange {
+                    location,
+                    type_identifier,
+                    field_index,
+                }),
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::TupleFieldOutOfRange {
+                    location,
+                    type_identifier,
+                    field_index,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "`{}` has no field with index `{}`",
+                    type_identifier, field_index,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::FieldDoesNotExist {
+                    location,
+                    type_identifier,
+                    field_name,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Structure(StructureConstantError::FieldDoesNotExist {
+                    location,
+                    type_identifier,
+                    field_name,
+                }),
+            )))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::StructureFieldDoesNotExist {
+                    location,
+                    type_identifier,
+                    field_name,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "field or method `{}` does not exist in `{}`",
+                    field_name, type_identifier,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Contract(
+                ContractValueError::FieldDoesNotExist {
+                    location,
+                    type_identifier,
+                    field_name,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::ContractFieldDoesNotExist {
+                    location,
+                    type_identifier,
+                    field_name,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "field or method `{}` does not exist in `{}`",
+                    field_name, type_identifier,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::MutatingWithDifferentType {
+                    location,
+                    expected,
+                    found,
+                },
+            ))) => Diagnostic::with_location(
+                format!("expected `{}`, found `{}`", expected, found).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::MutatingImmutableMemory {
+                    location,
+                    name,
+                    reference,
+                },
+            ))) => Diagnostic::with_reference(
+                format!("cannot assign twice to immutable variable `{}`", name).as_str(),
+                location,
+                reference,
+                Some(format!("make this variable mutable: `mut {}`", name).as_str()),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Place(
+                PlaceError::MutatingImmutableContractField { location, name },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "cannot mutate the immutable contract storage field `{}`",
+                    name
+                )
+                .as_str(),
+                location,
+                Some("such fields cannot be changed by the contract logic"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::FieldExpected {
+                    location,
+                    type_identifier,
+                    position,
+                    expected,
+                    found,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Contract(
+                ContractValueError::FieldExpected {
+                    location,
+                    type_identifier,
+                    position,
+                    expected,
+                    found,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Structure(StructureConstantError::FieldExpected {
+                    location,
+                    type_identifier,
+                    position,
+                    expected,
+                    found,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "`{}` expected field `{}` at position {}, found `{}`",
+                    type_identifier, expected, position, found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::FieldInvalidType {
+                    location,
+                    type_identifier,
+                    field_name,
+                    expected,
+                    found,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Contract(
+                ContractValueError::FieldInvalidType {
+                    location,
+                    type_identifier,
+                    field_name,
+                    expected,
+                    found,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Structure(StructureConstantError::FieldInvalidType {
+                    location,
+                    type_identifier,
+                    field_name,
+                    expected,
+                    found,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "field `{}` of `{}` expected type `{}`, found `{}`",
+                    field_name, type_identifier, expected, found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::FieldOutOfRange {
+                    location,
+                    type_identifier,
+                    expected,
+                    found,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Contract(
+                ContractValueError::FieldOutOfRange {
+                    location,
+                    type_identifier,
+                    expected,
+                    found,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Structure(StructureConstantError::FieldOutOfRange {
+                    location,
+                    type_identifier,
+                    expected,
+                    found,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "`{}` expected {} fields, found {}",
+                    type_identifier, expected, found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::FieldMissing {
+                    location,
+                    type_identifier,
+                    field_name,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "`{}` field `{}` is not initialized",
+                    type_identifier, field_name,
+                )
+                .as_str(),
+                location,
+                Some("all the fields must be initialized in the declaration order"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchEquals {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchEquals {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the equals operator `==` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchNotEquals {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchNotEquals {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the not equals operator `!=` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchGreaterEquals {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchGreaterEquals {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the greater equals operator `>=` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchLesserEquals {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchLesserEquals {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the lesser equals operator `<=` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchGreater {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchGreater {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the greater operator `>` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchLesser {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchLesser {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the lesser operator `<` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchBitwiseOr {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseOr {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise OR operator `|` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchBitwiseXor {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseXor {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise XOR operator `^` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchBitwiseAnd {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseAnd {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the bitwise AND operator `&` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchAddition {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchAddition {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the addition operator `+` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchSubtraction {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchSubtraction {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the subtraction operator `-` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchMultiplication {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchMultiplication {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the multiplication operator `*` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchDivision {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchDivision {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the division operator `/` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::TypesMismatchRemainder {
+                    location,
+                    first,
+                    second,
+                },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::TypesMismatchRemainder {
+                    location,
+                    first,
+                    second,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the remainder operator `%` expected two integers of the same type, found `{}` and `{}`",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowAddition {
+                    location,
+                    value,
+                    r#type,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the addition operator `+` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                    value, r#type,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowSubtraction {
+                    location,
+                    value,
+                    r#type,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the subtraction operator `-` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                    value, r#type,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowMultiplication {
+                    location,
+                    value,
+                    r#type,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the multiplication operator `*` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                    value, r#type,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowDivision {
+                    location,
+                    value,
+                    r#type,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the division operator `/` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                    value, r#type,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowRemainder {
+                    location,
+                    value,
+                    r#type,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the remainder operator `%` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                    value, r#type,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowCasting {
+                    location,
+                    value,
+                    r#type,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the casting operator `as` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                    value, r#type,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::OverflowNegation {
+                    location,
+                    value,
+                    r#type,
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the negation operator `-` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                    value, r#type,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::ForbiddenFieldDivision { location },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ForbiddenFieldDivision { location }),
+            ))) => Diagnostic::with_location(
+                "the division operator `/` is forbidden for the `field` type",
+                location,
+                Some("for inversion consider using `std::ff::invert`"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::ForbiddenFieldRemainder { location },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ForbiddenFieldRemainder { location }),
+            ))) => Diagnostic::with_location(
+                "the remainder operator `%` is forbidden for the `field` type",
+                location,
+                Some("`field` type values cannot be used to get a remainder"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::ForbiddenSignedBitwise { location },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ForbiddenSignedBitwise { location }),
+            ))) => Diagnostic::with_location(
+                "the bitwise operators are forbidden for the signed integer types",
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::ForbiddenFieldBitwise { location },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ForbiddenFieldBitwise { location }),
+            ))) => Diagnostic::with_location(
+                "the bitwise operators are forbidden for the `field` type",
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(
+                IntegerValueError::ForbiddenFieldNegation { location },
+            ))))
+            | Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ForbiddenFieldNegation { location }),
+            ))) => Diagnostic::with_location(
+                "the negation operator `-` is forbidden for the `field` type",
+                location,
+                Some("`field` type values cannot be negative"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ZeroDivision { location }),
+            ))) => Diagnostic::with_location("division by zero", location, None),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::ZeroRemainder { location }),
+            ))) => Diagnostic::with_location("remainder of division by zero", location, None),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::IntegerTooLarge {
+                    location,
+                    inner:
+                        InferenceError::Overflow {
+                            value,
+                            is_signed,
+                            bitlength,
+                        },
+                }),
+            ))) => Diagnostic::with_location(
+                format!(
+                    "`{}` is larger than `{}` bits with sign `{}`",
+                    value, bitlength, is_signed
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::Parsing {
+                    location,
+                    inner: zinc_math::BigIntError::NumberParsing(inner),
+                }),
+            ))) => Diagnostic::with_location(
+                format!("The number parsing error: {}", inner).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::Parsing {
+                    location,
+                    inner: zinc_math::BigIntError::ExponentParsing(inner),
+                }),
+            ))) => Diagnostic::with_location(
+                format!("The exponent value parsing error: {}", inner).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Constant(
+                ConstantError::Integer(IntegerConstantError::Parsing {
+                    location,
+                    inner: zinc_math::BigIntError::ExponentTooSmall(exponent),
+                }),
+            ))) => Diagnostic::with_location(
+                format!("The exponent value `{}` is too small", exponent).as_str(),
+                location,
+                Some("the exponent value must be equal or greater than the number of fractional digits"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(
+                TypeError::TypeRequired {
+                    location,
+                    identifier,
+                },
+            ))) => Diagnostic::with_location(
+                format!("type is required for binding `{}`", identifier).as_str(),
+                location,
+                Some(
+                    format!(
+                        "consider giving the binding a type, e.g. `{}: u8`",
+                        identifier
+                    )
+                    .as_str(),
+                ),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(
+                TypeError::AliasDoesNotPointToType { location, found },
+            ))) => Diagnostic::with_location(
+                format!("expected type, found `{}`", found).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(
+                TypeError::UnexpectedGenerics { location, r#type },
+            ))) => Diagnostic::with_location(
+                format!("type `{}` got unexpected generics", r#type).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(
+                TypeError::InstantiationForbidden { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "type `{}` cannot be instantiated beyond the contract storage",
+                    found,
+                )
+                .as_str(),
+                location,
+                Some("consider removing strings, ranges, functions, and maps from the type declaration"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(
+                TypeError::FunctionTypeOutsideArgument { location },
+            ))) => Diagnostic::with_location(
+                "function types can only be used as function argument types",
+                location,
+                Some("consider passing a closure or a function to a function argument"),
+            ),
+            Self::Semantic(SemanticError::Scope(ScopeError::ItemRedeclared {
+                location,
+                name,
+                reference,
+            })) => Diagnostic::with_reference(
+                format!("item `{}` already declared here", name).as_str(),
+                location,
+                reference,
+                Some("consider giving the latter item another name"),
+            ),
+            Self::Semantic(SemanticError::Scope(ScopeError::ItemUndeclared { location, name })) => {
+                Diagnostic::with_location(
+                    format!("cannot find item `{}` in this scope", name).as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Scope(ScopeError::ItemIsNotANamespace {
+                location,
+                name,
+            })) => Diagnostic::with_location(
+                format!("item `{}` is not a namespace", name).as_str(),
+                location,
+                Some("only modules, structures, enumerations, and contracts can contain items within their namespaces"),
+            ),
+            Self::Semantic(SemanticError::Scope(ScopeError::AssociatedItemWithoutOwner {
+                location,
+                name,
+            })) => Diagnostic::with_location(
+                format!(
+                    "associated item `{}` is accessed without specifying its namespace or entity",
+                    name,
+                )
+                .as_str(),
+                location,
+                Some("consider adding the namespace or entity prefix to the item"),
+            ),
+            Self::Semantic(SemanticError::Scope(ScopeError::ContractRedeclared {
+                location,
+                reference,
+            })) => Diagnostic::with_reference(
+                "another contract is already declared here",
+                location,
+                Some(reference),
+                Some("only one contract may be declared in the project"),
+            ),
+            Self::Semantic(SemanticError::Scope(ScopeError::ReferenceLoop { location })) => {
+                Diagnostic::with_location(
+                    "reference loop detected",
+                    location,
+                    Some("consider removing circular references between the items"),
+                )
+            }
+            Self::Semantic(SemanticError::Scope(ScopeError::ClosureCapture { location, name })) => {
+                Diagnostic::with_location(
+                    format!(
+                        "closure captures the local variable `{}` and cannot be passed to another function",
+                        name
+                    )
+                    .as_str(),
+                    location,
+                    Some("consider passing the variable to the function as an ordinar argument"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::ArgumentCount {
+                    location,
+                    function,
+                    expected,
+                    found,
+                    reference,
+                },
+            )))) => Diagnostic::with_reference(
+                format!(
+                    "function `{}` expected {} arguments, found {}",
+                    function, expected, found
+                )
+                .as_str(),
+                location,
+                reference,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::ArgumentType {
+                    location,
+                    function,
+                    name,
+                    position,
+                    expected,
+                    found,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "function `{}` expected type `{}` as the argument `{}` (#{}), found `{}`",
+                    function, expected, name, position, found
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::ArgumentConstantness {
+                    location,
+                    function,
+                    name,
+                    position,
+                    found,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "function `{}` expected a constant as the argument `{}` (#{}), found a non-constant of type `{}`",
+                    function, name, position, found
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::ArgumentNotEvaluable {
+                    location,
+                    function,
+                    position,
+                    found,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "function `{}` expected a value as the argument #{}, found `{}`",
+                    function, position, found
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::ReturnType {
+                    location,
+                    function,
+                    expected,
+                    found,
+                    reference,
+                },
+            )))) => Diagnostic::with_reference(
+                format!(
+                    "function `{}` must return a value of type `{}`, found `{}`",
+                    function, expected, found
+                )
+                .as_str(),
+                location,
+                Some(reference),
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::NonCallable { location, name },
+            )))) => Diagnostic::with_location(
+                format!("attempt to call a non-callable item `{}`", name).as_str(),
+                location,
+                Some("only functions may be called"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::CallingMutableFromImmutable { location, function },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "the mutable method `{}` was called with an immutable instance",
+                    function,
+                )
+                .as_str(),
+                location,
+                Some("consider making the instance mutable"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::Unknown { location, function }),
+            )))) => Diagnostic::with_location(
+                format!(
+                    "attempt to call a non-intrinsic function `{}` with `!` specifier",
+                    function
+                )
+                .as_str(),
+                location,
+                Some("only intrinsic functions require the `!` symbol after the function name"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::ExclamationMarkMissing {
+                    location,
+                    function,
+                }),
+            )))) => Diagnostic::with_location(
+                format!(
+                    "attempt to call an intrinsic function `{}` without `!` specifier",
+                    function
+                )
+                .as_str(),
+                location,
+                Some("intrinsic functions require the `!` symbol after the function name"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::Debug(
+                    DebugFunctionError::ArgumentCount {
+                        location,
+                        expected,
+                        found,
+                    },
+                )),
+            )))) => Diagnostic::with_location(
+                format!(
+                    "the `dbg!` function expected {} arguments, but got {}",
+                    expected, found,
+                )
+                .as_str(),
+                location,
+                Some("the number of `dbg!` arguments after the format string must be equal to the number of placeholders, e.g. `dbg!(\"{}, {}\", a, b)`"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::StandardLibrary(
+                    StandardLibraryFunctionError::ArrayTruncatingToBiggerSize {
+                        location,
+                        from,
+                        to,
+                    },
+                )),
+            )))) => Diagnostic::with_location(
+                format!(
+                    "attempt to truncate an array from size `{}` to bigger size `{}`",
+                    from, to,
+                )
+                .as_str(),
+                location,
+                Some("consider truncating the array to a smaller size"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::StandardLibrary(
+                    StandardLibraryFunctionError::ArrayPaddingToLesserSize { location, from, to },
+                )),
+            )))) => Diagnostic::with_location(
+                format!(
+                    "attempt to pad an array from size `{}` to lesser size `{}`",
+                    from, to,
+                )
+                .as_str(),
+                location,
+                Some("consider padding the array to a bigger size"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::StandardLibrary(
+                    StandardLibraryFunctionError::ArrayNewLengthInvalid { location, value },
+                )),
+            )))) => Diagnostic::with_location(
+                format!("new array length `{}` cannot act as an index", value,).as_str(),
+                location,
+                Some("array indexes cannot be greater than maximum of `u64`"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::StandardLibrary(
+                    StandardLibraryFunctionError::ArraySumEmpty { location },
+                )),
+            )))) => Diagnostic::with_location(
+                "an empty array cannot be summed",
+                location,
+                Some("consider using `array::fold` with an initial value"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Intrinsic(IntrinsicFunctionError::StandardLibrary(
+                    StandardLibraryFunctionError::ArrayZipSizeMismatch {
+                        location,
+                        first,
+                        second,
+                    },
+                )),
+            )))) => Diagnostic::with_location(
+                format!(
+                    "arrays of sizes `{}` and `{}` cannot be zipped",
+                    first, second,
+                )
+                .as_str(),
+                location,
+                Some("the zipped arrays must have the same size"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Test(TestFunctionError::CallForbidden { location, function }),
+            )))) => Diagnostic::with_location(
+                format!("unit test function `{}` cannot be called", function,).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Test(TestFunctionError::BeyondModuleScope { location, function }),
+            )))) => Diagnostic::with_location(
+                format!(
+                    "unit test function `{}` must be declared at the module root scope",
+                    function,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Test(TestFunctionError::PublicForbidden { location, function }),
+            )))) => Diagnostic::with_location(
+                format!(
+                    "unit test function `{}` cannot be declared as public",
+                    function,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Test(TestFunctionError::ConstantForbidden { location, function }),
+            )))) => Diagnostic::with_location(
+                format!(
+                    "unit test function `{}` cannot be declared as constant",
+                    function,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Test(TestFunctionError::CannotHaveArguments { location, function }),
+            )))) => Diagnostic::with_location(
+                format!("unit test function `{}` cannot accept arguments", function,).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Test(TestFunctionError::CannotReturnValue { location, function }),
+            )))) => Diagnostic::with_location(
+                format!("unit test function `{}` cannot return a value", function,).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Structure(
+                StructureTypeError::DuplicateField {
+                    location,
+                    type_identifier,
+                    field_name,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "`{}` has a duplicate field `{}`",
+                    type_identifier, field_name,
+                )
+                .as_str(),
+                location,
+                Some("consider giving the field a unique name"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Structure(
+                StructureTypeError::ExpectedGenerics {
+                    location,
+                    type_identifier,
+                    expected,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "structure `{}` expected {} generic arguments",
+                    type_identifier, expected,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Structure(
+                StructureTypeError::UnexpectedGenerics {
+                    location,
+                    type_identifier,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "structure `{}` got unexpected generic arguments",
+                    type_identifier,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Structure(
+                StructureTypeError::InvalidGenericsNumber {
+                    location,
+                    type_identifier,
+                    expected,
+                    found,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "structure `{}` expected {} generic arguments, found {}",
+                    type_identifier, expected, found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Option(
+                OptionTypeError::ExpectedGenerics { location },
+            )))) => Diagnostic::with_location(
+                "type `Option` expected 1 generic argument, found none",
+                location,
+                Some("specify the payload type, e.g. `Option<u8>`"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Option(
+                OptionTypeError::InvalidGenericsNumber { location, found },
+            )))) => Diagnostic::with_location(
+                format!("type `Option` expected 1 generic argument, found {}", found).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Option(
+                OptionTypeError::PayloadTypeNotInferred { location },
+            )))) => Diagnostic::with_location(
+                "type annotations needed for `Option<_>`",
+                location,
+                Some("specify the payload type, e.g. `let value: Option<u8> = None;`"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::DuplicateVariantValue {
+                    location,
+                    type_identifier,
+                    variant_name,
+                    variant_value,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "`{}` has a duplicate variant `{}` with value `{}`",
+                    type_identifier, variant_name, variant_value,
+                )
+                .as_str(),
+                location,
+                Some("variants with the same value are temporarily prohibited"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::VariantValueWithData {
+                    location,
+                    type_identifier,
+                    variant_name,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "`{}` variant `{}` cannot have an explicit value",
+                    type_identifier, variant_name,
+                )
+                .as_str(),
+                location,
+                Some("variants of enumerations carrying data are numbered automatically"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::VariantValueMissing {
+                    location,
+                    type_identifier,
+                    variant_name,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "`{}` variant `{}` must have a value",
+                    type_identifier, variant_name,
+                )
+                .as_str(),
+                location,
+                Some("variants of enumerations without data must be initialized, e.g. `enum Data { A = 1 }`"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::DuplicateVariantField {
+                    location,
+                    type_identifier,
+                    variant_name,
+                    field_name,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "`{}::{}` has a duplicate field `{}`",
+                    type_identifier, variant_name, field_name,
+                )
+                .as_str(),
+                location,
+                Some("consider giving the field a unique name"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Contract(
+                ContractTypeError::DuplicateField {
+                    location,
+                    type_identifier,
+                    field_name,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "`{}` has a duplicate field `{}`",
+                    type_identifier, field_name,
+                )
+                .as_str(),
+                location,
+                Some("consider giving the field a unique name"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::DuplicateParameter {
+                    location,
+                    item,
+                    name,
+                },
+            )))) => Diagnostic::with_location(
+                format!("`{}` has a duplicate generic parameter `{}`", item, name,).as_str(),
+                location,
+                Some("consider giving the parameter a unique name"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::ConstantParameterForbidden {
+                    location,
+                    item,
+                    name,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "`{}` cannot have a constant generic parameter `{}`",
+                    item, name,
+                )
+                .as_str(),
+                location,
+                Some("constant generic parameters are only allowed in functions"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::ConstantParameterExpectedInteger {
+                    location,
+                    name,
+                    found,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "constant generic parameter `{}` expected an integer type, found `{}`",
+                    name, found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::ConstantArgumentOverflow {
+                    location,
+                    name,
+                    value,
+                    r#type,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "constant generic argument `{}` = `{}` does not fit into `{}`",
+                    name, value, r#type,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::CannotInfer {
+                    location,
+                    item,
+                    name,
+                },
+            )))) => Diagnostic::with_location(
+                format!("cannot infer the generic argument `{}` of `{}`", name, item,).as_str(),
+                location,
+                Some("generic parameters must be used in the argument or field types"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::InferenceConflict {
+                    location,
+                    item,
+                    name,
+                    expected,
+                    found,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "generic argument `{}` of `{}` is inferred as `{}`, found `{}`",
+                    name, item, expected, found,
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::BoundExpectedTrait { location, found },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "expected a trait as the generic parameter bound, found `{}`",
+                    found,
+                )
+                .as_str(),
+                location,
+                Some("generic parameters may be bounded only by traits, e.g. `T: Hashable`"),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::BoundNotSatisfied {
+                    location,
+                    item,
+                    name,
+                    r#type,
+                    r#trait,
+                },
+            )))) => Diagnostic::with_location(
+                format!(
+                    "the trait `{}` is not implemented for `{}`, required by the parameter `{}` of `{}`",
+                    r#trait, r#type, name, item,
+                )
+                .as_str(),
+                location,
+                Some(
+                    format!(
+                        "consider implementing the trait: `impl {} for {} {{ ... }}`",
+                        r#trait, r#type
+                    )
+                    .as_str(),
+                ),
+            ),
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Generic(
+                GenericError::EntryPoint { location, function },
+            )))) => Diagnostic::with_location(
+                format!("entry point `{}` cannot be generic", function,).as_str(),
+                location,
+                Some("entry points and unit tests are never instantiated, so their types must be concrete"),
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::NonConstantElement {
+                location,
+                found,
+            })) => Diagnostic::with_location(
+                format!(
+                    "attempt to use a non-constant value `{}` in a constant expression",
+                    found
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::TraitAsOperand {
+                location,
+                name,
+            })) => Diagnostic::with_location(
+                format!("expected a value or type, found the trait `{}`", name).as_str(),
+                location,
+                Some("traits can only be implemented for types and used as generic parameter bounds"),
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::ScrutineeInvalidType { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "match scrutinee expected a boolean, integer, or `Option` expression, found `{}`",
+                    found
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::NotExhausted { location },
+            ))) => Diagnostic::with_location(
+                "match expression must be exhaustive",
+                location,
+                Some("ensure that all possible cases are being handled, possibly by adding wildcards or more match arms"),
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::LessThanTwoBranches { location },
+            ))) => Diagnostic::with_location(
+                "match expression must have at least two branches",
+                location,
+                Some("consider adding some branches to make the expression useful"),
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchUnreachable { location },
+            ))) => Diagnostic::with_location(
+                "match expression branch is unreachable",
+                location,
+                Some("consider removing the branch or moving it above the branch with a wildcard or irrefutable binding"),
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternPathExpectedConstant { location, found },
+            ))) => Diagnostic::with_location(
+                format!("expected path to a constant, found `{}`", found).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternExpectedVariant { location, found },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "expected `Some` or an enumeration variant, found `{}`",
+                    found
+                )
+                .as_str(),
+                location,
+                Some("only `Some` and data-carrying enumeration variants can be matched with nested patterns"),
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternNestedExpectedBinding { location },
+            ))) => Diagnostic::with_location(
+                "nested patterns must be bindings or wildcards",
+                location,
+                Some("bind the value like `Some(value)`, or ignore it like `Some(_)`"),
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternVariantFieldCount {
+                    location,
+                    variant,
+                    expected,
+                    found,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the `{}` pattern expects {} nested patterns, found {}",
+                    variant, expected, found
+                )
+                .as_str(),
+                location,
+                Some("each variant field must be bound or ignored with the wildcard `_`"),
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternVariantFieldUnknown {
+                    location,
+                    variant,
+                    field,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the `{}` variant does not have a field named `{}`",
+                    variant, field
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternVariantFieldMissing {
+                    location,
+                    variant,
+                    field,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "the `{}` pattern does not mention the field `{}`",
+                    variant, field
+                )
+                .as_str(),
+                location,
+                Some("bind the field, or ignore the rest of the fields with `..`"),
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternVariantKindMismatch { location, variant },
+            ))) => Diagnostic::with_location(
+                format!("the `{}` pattern does not match the variant kind", variant).as_str(),
+                location,
+                Some("match tuple-like variants like `Variant(a, b)`, and structure-like ones like `Variant { a, b }`"),
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternInvalidType {
+                    location,
+                    expected,
+                    found,
+                    reference,
+                },
+            ))) => Diagnostic::with_reference(
+                format!("expected `{}`, found `{}`", expected, found).as_str(),
+                location,
+                Some(reference),
+                Some("all branch patterns must be compatible with the type of the expression being matched"),
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchExpressionInvalidType {
+                    location,
+                    expected,
+                    found,
+                    reference,
+                },
+            ))) => Diagnostic::with_reference(
+                format!("expected `{}`, found `{}`", expected, found).as_str(),
+                location,
+                Some(reference),
+                Some("all branches must return the type returned by the first branch"),
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchDuplicate {
+                    location,
+                    reference,
+                },
+            ))) => Diagnostic::with_reference(
+                "match expression contains a duplicate branch pattern",
+                location,
+                Some(reference),
+                Some("each pattern may occur only once"),
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Conditional(
+                ConditionalExpressionError::ExpectedBooleanCondition { location, found },
+            ))) => Diagnostic::with_location(
+                format!("expected `bool`, found `{}`", found).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Expression(ExpressionError::Conditional(
+                ConditionalExpressionError::BranchTypesMismatch {
+                    location,
+                    expected,
+                    found,
+                    reference,
+                },
+            ))) => Diagnostic::with_reference(
+                format!(
+                    "if and else branches return incompatible types `{}` and `{}`",
+                    expected, found
+                )
+                .as_str(),
+                location,
+                Some(reference),
+                None,
+            ),
+
+            Self::Semantic(SemanticError::Statement(StatementError::For(
+                ForStatementError::WhileExpectedBooleanCondition { location, found },
+            ))) => Diagnostic::with_location(
+                format!("expected `bool`, found `{}`", found).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::For(
+                ForStatementError::BoundsExpectedConstantRangeExpression { location, found },
+            ))) => Diagnostic::with_location(
+                format!("expected a constant range expression, found `{}`", found).as_str(),
+                location,
+                Some("only constant ranges allowed, e.g. `for i in 0..42 { ... }`"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::BoundMissing { location },
+            ))) => Diagnostic::with_location(
+                "loop iterations count must be bounded",
+                location,
+                Some("specify the maximal number of iterations, e.g. `#[bound(16)] while x < y { ... }`"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::BoundDuplicate {
+                    location,
+                    reference,
+                },
+            ))) => Diagnostic::with_reference(
+                "loop bound is specified more than once",
+                location,
+                Some(reference),
+                None,
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::BoundExpectedSingleArgument { location, found },
+            ))) => Diagnostic::with_location(
+                format!("loop bound expected 1 argument, found {}", found).as_str(),
+                location,
+                Some("specify the maximal number of iterations, e.g. `#[bound(16)]`"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::BoundExpectedConstantInteger { location, found },
+            ))) => Diagnostic::with_location(
+                format!("expected a constant integer expression, found `{}`", found).as_str(),
+                location,
+                Some("only constant bounds allowed, e.g. `#[bound(N * 2)]`"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::WhileExpectedBooleanCondition { location, found },
+            ))) => Diagnostic::with_location(
+                format!("expected `bool`, found `{}`", found).as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::ExpectedBreak { location },
+            ))) => Diagnostic::with_location(
+                "`loop` without `break` never terminates",
+                location,
+                Some("add a `break` statement or use `while` with a condition"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::BreakOutsideLoop { location },
+            ))) => Diagnostic::with_location(
+                "`break` outside of a loop",
+                location,
+                Some("`break` may only be used inside `for`, `while`, and `loop` bodies"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Loop(
+                LoopStatementError::ContinueOutsideLoop { location },
+            ))) => Diagnostic::with_location(
+                "`continue` outside of a loop",
+                location,
+                Some("`continue` may only be used inside `for`, `while`, and `loop` bodies"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Return(
+                ReturnStatementError::OutsideFunction { location },
+            ))) => Diagnostic::with_location(
+                "`return` outside of a function",
+                location,
+                Some("`return` may only be used inside runtime function bodies"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Return(
+                ReturnStatementError::ConstantFunction { location, function },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "`return` is forbidden in the constant function `{}`",
+                    function
+                )
+                .as_str(),
+                location,
+                Some("constant functions must produce their value as the trailing block expression"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Use(
+                UseStatementError::ExpectedPath { location, found },
+            ))) => Diagnostic::with_location(
+                format!("`use` expected an item path, but got `{}`", found).as_str(),
+                location,
+                Some("consider specifying a valid path to an item to import"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Impl(
+                ImplStatementError::ExpectedStructureOrEnumeration { location, found },
+            ))) => Diagnostic::with_location(
+                format!("`impl` expected a type with namespace, found `{}`", found).as_str(),
+                location,
+                Some("only structures and enumerations can have an implementation"),
+            ),
+
+            Self::Semantic(SemanticError::Statement(StatementError::Impl(
+                ImplStatementError::ExpectedTrait { location, found },
+            ))) => Diagnostic::with_location(
+                format!("`impl ... for` expected a trait, found `{}`", found).as_str(),
+                location,
+                Some("only traits can be implemented for a type, e.g. `impl Hashable for Data { ... }`"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Impl(
+                ImplStatementError::MissingTraitMethod {
+                    location,
+                    r#trait,
+                    method,
+                    reference,
+                },
+            ))) => Diagnostic::with_reference(
+                format!(
+                    "not all trait items implemented, missing `{}` of the trait `{}`",
+                    method, r#trait,
+                )
+                .as_str(),
+                location,
+                Some(reference),
+                Some("implement the method or provide its default body in the trait"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Impl(
+                ImplStatementError::UnexpectedTraitMethod {
+                    location,
+                    r#trait,
+                    method,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "method `{}` is not a member of the trait `{}`",
+                    method, r#trait,
+                )
+                .as_str(),
+                location,
+                Some("move the method to a separate `impl` block without a trait"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Impl(
+                ImplStatementError::TraitMethodArgumentCount {
+                    location,
+                    r#trait,
+                    method,
+                    expected,
+                    found,
+                    reference,
+                },
+            ))) => Diagnostic::with_reference(
+                format!(
+                    "method `{}` has {} arguments, but the declaration in the trait `{}` has {}",
+                    method, found, r#trait, expected,
+                )
+                .as_str(),
+                location,
+                Some(reference),
+                Some("the implemented method must have the same arguments as the trait one"),
+            ),
+            Self::Semantic(SemanticError::Statement(StatementError::Impl(
+                ImplStatementError::DuplicateTraitImplementation {
+                    location,
+                    r#trait,
+                    r#type,
+                },
+            ))) => Diagnostic::with_location(
+                format!(
+                    "conflicting implementations of the trait `{}` for the type `{}`",
+                    r#trait, r#type,
+                )
+                .as_str(),
+                location,
+                Some("a trait can be implemented for a type only once"),
+            ),
+
+            Self::Semantic(SemanticError::Attribute(AttributeError::Unknown {
+                location,
+                found,
+            })) => Diagnostic::with_location(
+                format!("unknown attribute `{}`", found).as_str(),
+                location,
+                Some("see the reference to get the list of allowed attributes"),
+            ),
+            Self::Semantic(SemanticError::Attribute(AttributeError::AllowExpectedLints {
+                location,
+            })) => Diagnostic::with_location(
+                "the `allow` attribute expects a list of lint names",
+                location,
+                Some("specify the lints to suppress, e.g. `#[allow(unused_variables)]`"),
+            ),
+            Self::Semantic(SemanticError::Attribute(AttributeError::AllowExpectedLintName {
+                location,
+            })) => Diagnostic::with_location(
+                "expected a lint name",
+                location,
+                Some("the `allow` attribute arguments must be lint names, e.g. `#[allow(unused_variables)]`"),
+            ),
+            Self::Semantic(SemanticError::Attribute(AttributeError::LintUnknown {
+                location,
+                found,
+            })) => Diagnostic::with_location(
+                format!("unknown lint `{}`", found).as_str(),
+                location,
+                Some("see the reference to get the list of lints"),
+            ),
+
+            Self::Semantic(SemanticError::Binding(BindingError::ExpectedTuple {
+                location,
+                expected,
+                found,
+            })) => Diagnostic::with_location(
+                format!(
+                    "expected a tuple with {} elements, found `{}`",
+                    expected, found
+                )
+                .as_str(),
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Binding(BindingError::FunctionMethodSelfNotFirst {
+                location,
+                name,
+                position,
+            })) => Diagnostic::with_location(
+                format!(
+                    "expected the `{}` binding to be at the first position, but found at the position #`{}`",
+                    name, position,
+                )
+                .as_str(),
+                location,
+                Some(format!("consider moving the `{}` binding to the first place", name).as_str()),
+            ),
+            Self::Semantic(SemanticError::Binding(
+                BindingError::FunctionArgumentDestructuringUnavailable { location },
+            )) => Diagnostic::with_location(
+                "tuple function argument destructuring is not implemented yet",
+                location,
+                Some("consider passing the arguments separately for now"),
+            ),
+
+            Self::Semantic(SemanticError::EntryPointMissing) => Diagnostic::new(
+                "the project entry point is missing",
+                Some("create the `main` function or a contract definition in the entry point file"),
+            ),
+            Self::Semantic(SemanticError::EntryPointAmbiguous { main, contract }) => {
+                Diagnostic::with_reference(
+                    "the entry file contains both the `main` function and contract definition",
+                    main,
+                    Some(contract),
+                    Some("consider choosing between the circuit and contract project type"),
+                )
+            }
+            Self::Semantic(SemanticError::EntryPointConstant { location }) => {
+                Diagnostic::with_location(
+                    "the entry point cannot be constant",
+                    location,
+                    Some("consider removing the `const` modifier"),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionMainBeyondEntry { location }) => {
+                Diagnostic::with_location(
+                    "the `main` function is declared beyond the `main.zn` entry file",
+                    location,
+                    Some("the `main` function may be declared only in the entry file"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractBeyondEntry { location }) => {
+                Diagnostic::with_location(
+                    "contract is declared beyond the entry file",
+                    location,
+                    Some("contracts may be declared only once in the entry file"),
+                )
+            }
+            Self::Semantic(SemanticError::ModuleFileNotFound { location, name }) => {
+                Diagnostic::with_location(
+                    format!("file not found for module `{}`", name).as_str(),
+                    location,
+                    Some(
+                        format!(
+                            "create a file called `{}.zn` inside the module directory",
+                            name
+                        )
+                        .as_str(),
+                    ),
+                )
+            }
+        };
+
+        diagnostic.with_code(code)
+    }
+}
+
+impl From<LexicalError> for Error {
+    fn from(error: LexicalError) -> Self {
+        Self::Lexical(error)
+    }
+}
+
+impl From<SyntaxError> for Error {
+    fn from(error: SyntaxError) -> Self {
+        Self::Syntax(error)
+    }
+}
+
+impl From<ParsingError> for Error {
+    fn from(error: ParsingError) -> Self {
+        match error {
+            ParsingError::Lexical(error) => Self::Lexical(error),
+            ParsingError::Syntax(error) => Self::Syntax(error),
+        }
+    }
+}
+
+impl From<SemanticError> for Error {
+    fn from(error: SemanticError) -> Self {
+        Self::Semantic(error)
+    }
+}
@@ -0,0 +1,329 @@
+//!
+//! The control flow expression and statement error codes.
+//!
+
+use super::Entry;
+
+///
+/// The control flow expression and statement error codes, which are in the `Z07xx` range.
+///
+pub static ENTRIES: &[Entry] = &[
+    Entry {
+        code: "Z0701",
+        title: "invalid match scrutinee",
+        explanation: r#"
+Only booleans, integers, enumerations, and `Option` values can be matched.
+"#,
+        example: r#"
+fn main() {
+    let result = match () {
+        0 => false,
+        1 => true,
+    };
+}
+"#,
+    },
+    Entry {
+        code: "Z0702",
+        title: "non-exhaustive match",
+        explanation: r#"
+The `match` expression branches must cover all the possible scrutinee values.
+
+Add the missing branches, or the wildcard branch `_` at the end.
+"#,
+        example: r#"
+fn main() {
+    let scrutinee = 42;
+    let result = match scrutinee {
+        1 => 10,
+        2 => 20,
+    };
+}
+"#,
+    },
+    Entry {
+        code: "Z0703",
+        title: "match with a single branch",
+        explanation: r#"
+The `match` expression must have at least two branches.
+
+Use the branch expression directly instead of the `match` with a single
+branch.
+"#,
+        example: r#"
+fn main() {
+    let scrutinee = 42;
+    let result = match scrutinee {
+        _ => 10,
+    };
+}
+"#,
+    },
+    Entry {
+        code: "Z0704",
+        title: "unreachable match branch",
+        explanation: r#"
+The branch follows an irrefutable pattern, like the wildcard `_` or a binding,
+which matches all the remaining values, so the branch is never taken.
+
+Move the irrefutable branch to the end of the `match` expression.
+"#,
+        example: r#"
+fn main() {
+    let scrutinee = 42;
+    let result = match scrutinee {
+        1 => 10,
+        _ => 101,
+        2 => 20,
+    };
+}
+"#,
+    },
+    Entry {
+        code: "Z0705",
+        title: "invalid match pattern",
+        explanation: r#"
+The `match` branch pattern does not match the scrutinee:
+
+- the pattern type must be the scrutinee type;
+- a path pattern must point to a constant or an enumeration variant;
+- a destructuring pattern must point to `Some` or an enumeration variant, and
+  have the variant kind, that is, `Variant(a, b)` for the tuple-like variants
+  and `Variant { a, b }` for the structure-like ones;
+- the destructuring pattern must mention every variant field, or end with `..`;
+- the nested patterns must be bindings or wildcards.
+"#,
+        example: r#"
+fn main(value: Option<u8>) -> u8 {
+    match value {
+        Some(inner, other) => inner,
+        None => 0,
+    }
+}
+"#,
+    },
+    Entry {
+        code: "Z0706",
+        title: "match branches of different types",
+        explanation: r#"
+All the `match` branch expressions must have the type of the first one.
+"#,
+        example: r#"
+fn main() {
+    let scrutinee = 42;
+    let result = match scrutinee {
+        0 => false,
+        1 => 0,
+    };
+}
+"#,
+    },
+    Entry {
+        code: "Z0707",
+        title: "duplicate match branch",
+        explanation: r#"
+Two `match` branches have the same pattern, so the second one is never taken.
+"#,
+        example: r#"
+fn main() {
+    let scrutinee = 42;
+    let result = match scrutinee {
+        42 => 10,
+        42 => 20,
+        _ => 30,
+    };
+}
+"#,
+    },
+    Entry {
+        code: "Z0708",
+        title: "non-boolean condition",
+        explanation: r#"
+The conditions of `if` expressions and `while` loops must be `bool` values.
+There are no implicit conversions from integers to booleans.
+
+Use a comparison like `value != 0` instead.
+"#,
+        example: r#"
+fn main() {
+    if 42 { 1 } else { 2 }
+}
+"#,
+    },
+    Entry {
+        code: "Z0709",
+        title: "conditional branches of different types",
+        explanation: r#"
+The `if` and `else` branches of a conditional expression must have the same
+type. A conditional without the `else` branch has the unit type `()`.
+"#,
+        example: r#"
+fn main() {
+    if true { 42 } else { false }
+}
+"#,
+    },
+    Entry {
+        code: "Z0710",
+        title: "non-constant `for` loop bounds",
+        explanation: r#"
+The `for` loop bounds must be a constant integer range like `0..10`, since the
+number of iterations must be known at compile time in a circuit.
+
+Use the `while` condition to exit a loop early.
+"#,
+        example: r#"
+fn main() {
+    let mut sum = 0;
+    for i in true {
+        sum = sum + i;
+    }
+}
+"#,
+    },
+    Entry {
+        code: "Z0711",
+        title: "invalid loop bound",
+        explanation: r#"
+The `while` and `loop` loops must be annotated with exactly one
+`#[bound(N)]` attribute, where `N` is an integer constant, which limits the
+number of iterations, since it must be known at compile time in a circuit.
+"#,
+        example: r#"
+fn main() {
+    let mut value = 1;
+    while value < 100 {
+        value *= 2;
+    }
+}
+"#,
+    },
+    Entry {
+        code: "Z0712",
+        title: "`loop` without `break`",
+        explanation: r#"
+The `loop` body must contain a `break` statement, otherwise the loop always
+runs up to its bound, which is most likely a mistake.
+
+Use a `for` loop to run a fixed number of iterations.
+"#,
+        example: r#"
+fn main() {
+    let mut value = 1;
+    #[bound(8)]
+    loop {
+        value *= 2;
+    }
+}
+"#,
+    },
+    Entry {
+        code: "Z0713",
+        title: "`break` or `continue` outside of a loop",
+        explanation: r#"
+The `break` and `continue` statements can only be used inside a loop body.
+"#,
+        example: r#"
+fn main() {
+    break;
+}
+"#,
+    },
+    Entry {
+        code: "Z0714",
+        title: "invalid `return`",
+        explanation: r#"
+The `return` statement can only be used inside a function body, and is
+forbidden in the constant functions, which must produce their result with the
+trailing expression.
+"#,
+        example: r#"
+const VALUE: u8 = {
+    return 42;
+};
+
+fn main() -> u8 {
+    VALUE
+}
+"#,
+    },
+    Entry {
+        code: "Z0715",
+        title: "invalid `use` path",
+        explanation: r#"
+The `use` statement expects a path to an item, e.g. `use std::crypto::sha256;`.
+"#,
+        example: r#"
+use 5;
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0716",
+        title: "invalid `impl` target",
+        explanation: r#"
+The `impl` statement can only be applied to structures, enumerations, and
+contracts, and the `impl ... for` statement expects a trait before `for`.
+"#,
+        example: r#"
+type X = field;
+
+impl X {
+    fn impossible() {}
+}
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0717",
+        title: "trait implementation does not match the trait",
+        explanation: r#"
+A trait implementation must implement all the trait methods with the same
+number of arguments, cannot add methods, which the trait does not declare,
+and may only be implemented once for every type.
+"#,
+        example: r#"
+trait Hashable {
+    fn hash(self) -> field;
+}
+
+struct Data {}
+
+impl Hashable for Data {}
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0718",
+        title: "unknown attribute",
+        explanation: r#"
+The known attributes are `#[test]`, `#[should_panic]`, and `#[ignore]` for the
+unit test functions, `#[bound(N)]` for the loops, and `#[allow(...)]` for the
+lints. The contract storage fields may only have the `#[allow(...)]` attribute.
+"#,
+        example: r#"
+#[inline]
+fn inlined() {}
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0719",
+        title: "invalid `allow` attribute",
+        explanation: r#"
+The `#[allow(...)]` attribute expects a list of the known lint names, e.g.
+`#[allow(unused_variables, unused_mut)]`. See the Zinc book for the list of
+lints.
+"#,
+        example: r#"
+#[allow(dead_code)]
+fn unused() {}
+
+fn main() {}
+"#,
+    },
+];
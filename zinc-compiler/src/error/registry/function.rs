@@ -0,0 +1,247 @@
+//!
+//! The function and binding error codes.
+//!
+
+use super::Entry;
+
+///
+/// The function and binding error codes, which are in the `Z05xx` range.
+///
+pub static ENTRIES: &[Entry] = &[
+    Entry {
+        code: "Z0501",
+        title: "wrong number of arguments",
+        explanation: r#"
+The function is called with more or fewer arguments than it declares.
+"#,
+        example: r#"
+fn double(value: u8) -> u8 {
+    value * 2
+}
+
+fn main() -> u8 {
+    double()
+}
+"#,
+    },
+    Entry {
+        code: "Z0502",
+        title: "argument of a wrong type",
+        explanation: r#"
+The function argument value has a type, which differs from the declared
+argument type.
+
+Cast the value to the argument type, if such a cast is allowed.
+"#,
+        example: r#"
+fn double(value: u8) -> u8 {
+    value * 2
+}
+
+fn main() -> u8 {
+    double(true)
+}
+"#,
+    },
+    Entry {
+        code: "Z0503",
+        title: "non-constant argument",
+        explanation: r#"
+Some intrinsic and standard library function arguments, like the new array
+length of `std::array::truncate`, must be known at compile time, since they
+affect the circuit structure.
+
+Pass a literal or constant instead of a variable.
+"#,
+        example: r#"
+fn main() {
+    let new_length = 4;
+    std::array::truncate([true; 8], new_length);
+}
+"#,
+    },
+    Entry {
+        code: "Z0504",
+        title: "argument is not a value",
+        explanation: r#"
+A function argument must be a value, but found an item like a type or module.
+"#,
+        example: r#"
+fn another(x: u8) -> u8 {
+    42
+}
+
+type X = u8;
+
+fn main() {
+    let value = another(X);
+}
+"#,
+    },
+    Entry {
+        code: "Z0505",
+        title: "wrong return type",
+        explanation: r#"
+The function body or `return` statement produces a value, which type differs
+from the declared function return type.
+
+Functions without the `->` return type annotation return the unit type `()`.
+"#,
+        example: r#"
+fn main(x: u8) -> u8 {
+    if x == 0 {
+        return;
+    }
+    x
+}
+"#,
+    },
+    Entry {
+        code: "Z0506",
+        title: "non-callable item called",
+        explanation: r#"
+Only functions, methods, and closures can be called with the `()` operator.
+"#,
+        example: r#"
+type another = (u8, u8);
+
+fn main() {
+    let value = another();
+}
+"#,
+    },
+    Entry {
+        code: "Z0507",
+        title: "mutable method called on an immutable instance",
+        explanation: r#"
+A method, which mutates its instance, like `MTreeMap::remove`, is called with
+an immutable instance.
+
+Declare the `self` argument of the enclosing method as `mut self`.
+"#,
+        example: r#"
+use std::collections::MTreeMap;
+
+contract Data {
+    data: MTreeMap<u8, field>;
+
+    pub fn immutable(self) {
+        self.data.remove(42);
+    }
+}
+"#,
+    },
+    Entry {
+        code: "Z0508",
+        title: "non-intrinsic function called with `!`",
+        explanation: r#"
+The `!` specifier is only allowed for the intrinsic functions like `dbg!`,
+which are built into the compiler.
+
+Remove the `!` to call an ordinary function.
+"#,
+        example: r#"
+fn another() {}
+
+fn main() {
+    another!();
+}
+"#,
+    },
+    Entry {
+        code: "Z0509",
+        title: "intrinsic function called without `!`",
+        explanation: r#"
+The intrinsic functions like `dbg!` must be called with the `!` specifier,
+which distinguishes them from the ordinary functions.
+"#,
+        example: r#"
+fn main() {
+    dbg();
+}
+"#,
+    },
+    Entry {
+        code: "Z0510",
+        title: "invalid array function arguments",
+        explanation: r#"
+The standard library array function arguments do not match the array sizes:
+
+- `std::array::truncate` cannot make an array bigger;
+- `std::array::pad` cannot make an array smaller;
+- `std::array::sum` cannot sum an empty array;
+- `std::array::zip` expects two arrays of the same size;
+- the new array length must fit into an array index.
+"#,
+        example: r#"
+fn main() -> [u8; 4] {
+    std::array::truncate([1, 2], 4)
+}
+"#,
+    },
+    Entry {
+        code: "Z0511",
+        title: "invalid unit test function",
+        explanation: r#"
+The unit test functions marked with `#[test]` are called by the test runner
+only, so they must be declared at the module root, cannot be public or
+constant, cannot accept arguments or return values, and cannot be called from
+the application code.
+"#,
+        example: r#"
+#[test]
+fn check() -> u8 {
+    42
+}
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0512",
+        title: "tuple binding of a wrong size",
+        explanation: r#"
+The tuple binding pattern must have as many elements as the bound tuple.
+"#,
+        example: r#"
+fn main() {
+    let (a, b, c): (u8, u8) = (1, 2);
+}
+"#,
+    },
+    Entry {
+        code: "Z0513",
+        title: "`self` is not the first argument",
+        explanation: r#"
+The `self` argument of a method must be the first one.
+"#,
+        example: r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    fn method(value: u8, self) -> u8 {
+        value
+    }
+}
+
+fn main() {
+    let data = Data { value: 42 };
+}
+"#,
+    },
+    Entry {
+        code: "Z0514",
+        title: "tuple argument destructuring",
+        explanation: r#"
+Function arguments cannot be destructured yet.
+
+Bind the tuple to an argument and destructure it with `let` in the function
+body.
+"#,
+        example: r#"
+fn main((a, b): (u8, u8)) {}
+"#,
+    },
+];
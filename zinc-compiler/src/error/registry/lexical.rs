@@ -0,0 +1,89 @@
+//!
+//! The lexical analysis error codes.
+//!
+
+use super::Entry;
+
+///
+/// The lexical analysis error codes, which are in the `Z00xx` range.
+///
+pub static ENTRIES: &[Entry] = &[
+    Entry {
+        code: "Z0001",
+        title: "unterminated block comment",
+        explanation: r#"
+A block comment is opened with `/*`, but the file ends before the closing `*/`.
+
+Add the closing `*/` at the end of the comment, or use the line comments
+starting with `//` instead.
+"#,
+        example: r#"
+fn main() {}
+
+/* the comment is never closed
+"#,
+    },
+    Entry {
+        code: "Z0002",
+        title: "unterminated double quote string",
+        explanation: r#"
+A string literal is opened with `"`, but the file ends before the closing `"`.
+
+Add the closing double quote at the end of the string.
+"#,
+        example: r#"
+fn main() {
+    dbg!("the string is never closed);
+}
+"#,
+    },
+    Entry {
+        code: "Z0003",
+        title: "invalid digit in an integer literal",
+        explanation: r#"
+An integer literal contains a character, which is not a valid digit in its base.
+
+Binary literals starting with `0b` may only contain `0` and `1`, octal literals
+starting with `0o` may only contain digits from `0` to `7`, decimal literals may
+only contain digits from `0` to `9` and an exponent `E`, and hexadecimal
+literals starting with `0x` may only contain digits and letters from `a` to `f`
+in any case. The `_` delimiter is allowed in all of them.
+"#,
+        example: r#"
+fn main() -> u8 {
+    0b101_2
+}
+"#,
+    },
+    Entry {
+        code: "Z0004",
+        title: "invalid character",
+        explanation: r#"
+The source code contains a character, which cannot start any token of the Zinc
+language.
+
+Remove the character, or move it into a comment.
+"#,
+        example: r#"
+fn main() -> u8 {
+    42 @ 1
+}
+"#,
+    },
+    Entry {
+        code: "Z0005",
+        title: "unexpected end of input",
+        explanation: r#"
+A token ends before all its required parts are present. For example, an
+integer literal with the `0x`, `0o`, or `0b` prefix must be followed by at
+least one digit, and an exponent `E` must be followed by the exponent value.
+
+Complete the token or remove its incomplete part.
+"#,
+        example: r#"
+fn main() -> u8 {
+    0x
+}
+"#,
+    },
+];
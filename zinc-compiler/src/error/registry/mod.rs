@@ -0,0 +1,77 @@
+//!
+//! The Zinc compiler error code registry.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod expression;
+pub mod function;
+pub mod lexical;
+pub mod operator;
+pub mod place;
+pub mod project;
+pub mod scope;
+pub mod syntax;
+pub mod r#type;
+
+use std::fmt;
+
+///
+/// The error code registry entry, which is printed by `znc --explain <CODE>`.
+///
+#[derive(Debug)]
+pub struct Entry {
+    /// The stable error code, e.g. `Z0407`.
+    pub code: &'static str,
+    /// The short error description.
+    pub title: &'static str,
+    /// The long-form explanation, which describes the error causes and fixes.
+    pub explanation: &'static str,
+    /// The erroneous code example, which produces the error when compiled as the entry file.
+    pub example: &'static str,
+}
+
+impl Entry {
+    ///
+    /// Finds the registry entry by its `code`, ignoring the code letter case.
+    ///
+    pub fn find(code: &str) -> Option<&'static Self> {
+        Self::all().find(|entry| entry.code.eq_ignore_ascii_case(code))
+    }
+
+    ///
+    /// Returns all the registry entries, ordered by their codes.
+    ///
+    pub fn all() -> impl Iterator<Item = &'static Self> {
+        lexical::ENTRIES
+            .iter()
+            .chain(syntax::ENTRIES.iter())
+            .chain(operator::ENTRIES.iter())
+            .chain(place::ENTRIES.iter())
+            .chain(scope::ENTRIES.iter())
+            .chain(function::ENTRIES.iter())
+            .chain(r#type::ENTRIES.iter())
+            .chain(expression::ENTRIES.iter())
+            .chain(project::ENTRIES.iter())
+    }
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}: {}", self.code, self.title)?;
+        writeln!(f)?;
+        writeln!(f, "{}", self.explanation.trim())?;
+        writeln!(f)?;
+        writeln!(f, "Erroneous code example:")?;
+        writeln!(f)?;
+        for line in self.example.trim_matches('\n').lines() {
+            if line.is_empty() {
+                writeln!(f)?;
+            } else {
+                writeln!(f, "    {}", line)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,299 @@
+//!
+//! The operator and integer error codes.
+//!
+
+use super::Entry;
+
+///
+/// The operator and integer error codes, which are in the `Z02xx` range.
+///
+pub static ENTRIES: &[Entry] = &[
+    Entry {
+        code: "Z0201",
+        title: "assignment to a value",
+        explanation: r#"
+The left-hand side of the assignment operators `=`, `+=`, `-=`, `*=`, `/=`,
+`%=`, `|=`, `^=`, `&=`, `<<=`, and `>>=` must be a memory place, that is, a
+variable, an array element, or a tuple, structure, or contract field.
+
+Values like literals or function call results cannot be assigned to.
+"#,
+        example: r#"
+fn main() {
+    5 = 5;
+}
+"#,
+    },
+    Entry {
+        code: "Z0202",
+        title: "assignment of a non-value",
+        explanation: r#"
+The right-hand side of an assignment operator must be a value, but found an
+item like a type, a module, or a function without a call.
+"#,
+        example: r#"
+type X = u8;
+
+fn main() {
+    let mut value = 0;
+    value = X;
+}
+"#,
+    },
+    Entry {
+        code: "Z0203",
+        title: "non-constant range bound",
+        explanation: r#"
+The operands of the range operators `..` and `..=` must be integer constants,
+as the ranges are only allowed in the `for` loop bounds and array slices,
+which sizes must be known at compile time.
+"#,
+        example: r#"
+fn main() {
+    let value = true ..= 42;
+}
+"#,
+    },
+    Entry {
+        code: "Z0204",
+        title: "logical operator applied to a non-boolean",
+        explanation: r#"
+The logical operators `||`, `^^`, `&&`, and `!` can only be applied to the
+`bool` values.
+
+Use a comparison like `value != 0` to turn an integer into a boolean, or the
+bitwise operators `|`, `^`, `&`, and `~` to work with integers.
+"#,
+        example: r#"
+fn main() {
+    let value = 42 || true;
+}
+"#,
+    },
+    Entry {
+        code: "Z0205",
+        title: "equality operator applied to a non-primitive",
+        explanation: r#"
+The equality operators `==` and `!=` can only compare the primitive values of
+the same type, that is, units, booleans, and integers.
+
+Compare arrays, tuples, and structures element by element.
+"#,
+        example: r#"
+fn main() {
+    let value = [1, 2] == [1, 2];
+}
+"#,
+    },
+    Entry {
+        code: "Z0206",
+        title: "integer operator applied to a non-integer",
+        explanation: r#"
+The arithmetic operators `+`, `-`, `*`, `/`, `%`, the ordering operators `>=`,
+`<=`, `>`, `<`, and the bitwise operators `|`, `^`, `&`, `<<`, `>>`, `~` can
+only be applied to the integer values.
+
+Use a conditional expression like `if flag { 1 } else { 0 }` to turn a boolean
+into an integer.
+"#,
+        example: r#"
+fn main() {
+    let value = true >= 42;
+}
+"#,
+    },
+    Entry {
+        code: "Z0207",
+        title: "non-constant or signed shift amount",
+        explanation: r#"
+The second operand of the bitwise shift operators `<<` and `>>` must be an
+unsigned integer constant, as the number of shifted bits must be known at
+compile time.
+"#,
+        example: r#"
+fn main() {
+    let value = 168 << -2;
+}
+"#,
+    },
+    Entry {
+        code: "Z0208",
+        title: "invalid casting operand",
+        explanation: r#"
+The casting operator `as` expects a value as the first operand and a type as
+the second operand, e.g. `value as u8`.
+"#,
+        example: r#"
+type X = u8;
+
+fn main() {
+    let value = X as field;
+}
+"#,
+    },
+    Entry {
+        code: "Z0209",
+        title: "invalid casting",
+        explanation: r#"
+The value cannot be cast to the type, either explicitly with `as`, or
+implicitly when passed to a binding or argument of another type.
+
+Integers can be cast to other integer types and `field`, and the enumeration
+values without data can be cast to integers and `field`. Other types, including
+`bool` and `field` itself, cannot be cast to another type at all.
+"#,
+        example: r#"
+fn main() {
+    let value = 42;
+    let result: bool = value;
+}
+"#,
+    },
+    Entry {
+        code: "Z0210",
+        title: "invalid index operand",
+        explanation: r#"
+The index operator `[]` can only be applied to arrays, and the index must be an
+integer or a constant range, e.g. `array[1]` or `array[1..3]`.
+
+Tuple elements are accessed with the field access operator, e.g. `tuple.1`.
+"#,
+        example: r#"
+fn main() {
+    let value = (true, false, true)[1];
+}
+"#,
+    },
+    Entry {
+        code: "Z0211",
+        title: "invalid field access operand",
+        explanation: r#"
+The field access operator `.` can only be applied to tuples, structures,
+contracts, and other values with methods, and must be followed by a field
+name, a method name, or a tuple element index.
+"#,
+        example: r#"
+fn main() {
+    let value = [true, true, false].first;
+}
+"#,
+    },
+    Entry {
+        code: "Z0212",
+        title: "invalid path operand",
+        explanation: r#"
+The path resolution operator `::` expects item identifiers as its operands,
+e.g. `std::crypto::sha256` or `List::First`.
+"#,
+        example: r#"
+fn main() {
+    let value = 5::UNDEFINED;
+}
+"#,
+    },
+    Entry {
+        code: "Z0213",
+        title: "invalid structure literal",
+        explanation: r#"
+A structure literal must start with a path to a structure type, followed by
+the structure fields in braces, e.g. `Data { a: 1, b: 2 }`.
+"#,
+        example: r#"
+type X = u8;
+
+fn main() {
+    let value = X { a: 1 };
+}
+"#,
+    },
+    Entry {
+        code: "Z0214",
+        title: "operands of different integer types",
+        explanation: r#"
+The binary integer operators require both operands to have the same type.
+There are no implicit integer conversions in Zinc.
+
+Cast one of the operands to the type of the other one with the `as` operator.
+"#,
+        example: r#"
+fn main() {
+    let integer_64: u64 = 42;
+    let integer_128: u128 = 64;
+    let value = integer_64 == integer_128;
+}
+"#,
+    },
+    Entry {
+        code: "Z0215",
+        title: "constant operation overflow",
+        explanation: r#"
+An operation with constant operands produces a value, which cannot be
+represented by the result type. Constant expressions are evaluated at
+compile time, so the overflow is detected before running the application.
+
+Use a wider integer type for the operands.
+"#,
+        example: r#"
+fn main() {
+    let value = 42 + 255;
+}
+"#,
+    },
+    Entry {
+        code: "Z0216",
+        title: "operator forbidden for the type",
+        explanation: r#"
+Some integer operators are not available for some integer types:
+
+- the division `/`, remainder `%`, and negation `-` are forbidden for the
+  `field` type, as the field elements are not ordered integers;
+- the bitwise operators are forbidden for the `field` and signed integer types.
+
+Use an unsigned integer type for the operands instead.
+"#,
+        example: r#"
+fn main() {
+    let value = 42 as field / 1 as field;
+}
+"#,
+    },
+    Entry {
+        code: "Z0217",
+        title: "division by zero",
+        explanation: r#"
+The constant divisor of the division `/` or remainder `%` operator is zero.
+"#,
+        example: r#"
+fn main() {
+    let value = 42 / 0;
+}
+"#,
+    },
+    Entry {
+        code: "Z0218",
+        title: "integer literal too large",
+        explanation: r#"
+The integer literal is larger than the biggest integer type can hold, that is,
+248 bits for the ordinary integers and 254 bits for `field`.
+"#,
+        example: r#"
+fn main() {
+    let invalid = 0xffffffff_ffffffff_ffffffff_ffffffff_ffffffff_ffffffff_ffffffff_ffffffff;
+}
+"#,
+    },
+    Entry {
+        code: "Z0219",
+        title: "invalid decimal literal",
+        explanation: r#"
+The decimal literal with a fractional part and an exponent must denote an
+integer, that is, the exponent must be large enough to cover all the
+fractional digits, e.g. `42.666_E3`.
+"#,
+        example: r#"
+fn main() {
+    let value = 42.666_E2;
+}
+"#,
+    },
+];
@@ -0,0 +1,172 @@
+//!
+//! The value and memory place error codes.
+//!
+
+use super::Entry;
+
+///
+/// The value and memory place error codes, which are in the `Z03xx` range.
+///
+pub static ENTRIES: &[Entry] = &[
+    Entry {
+        code: "Z0301",
+        title: "array elements of different types",
+        explanation: r#"
+All the elements of an array must have the same type, which is the type of the
+first element.
+
+Use a tuple to group values of different types.
+"#,
+        example: r#"
+fn main() {
+    let array = [1, false];
+}
+"#,
+    },
+    Entry {
+        code: "Z0302",
+        title: "array index out of range",
+        explanation: r#"
+The constant array index is greater than or equal to the array size.
+
+Array indexes start from zero, so the last element of an array of size `N` has
+the index `N - 1`.
+"#,
+        example: r#"
+fn main() {
+    const VALUE: u8 = [1, 2, 3, 4, 5][5];
+}
+"#,
+    },
+    Entry {
+        code: "Z0303",
+        title: "array slice out of range",
+        explanation: r#"
+The array slice bounds must satisfy `0 <= start <= end <= size`, where `size`
+is the array size.
+"#,
+        example: r#"
+fn main() {
+    let array = [1, 2, 3, 4, 5];
+    let slice = array[-(1 as i8) .. 1];
+}
+"#,
+    },
+    Entry {
+        code: "Z0304",
+        title: "uninitialized structure",
+        explanation: r#"
+A structure type name is used as a value, but structure values must be created
+with a structure literal, which initializes all the fields.
+"#,
+        example: r#"
+struct Data {
+    a: u8,
+}
+
+fn main() -> Data { Data }
+"#,
+    },
+    Entry {
+        code: "Z0305",
+        title: "tuple element out of range",
+        explanation: r#"
+The tuple element index is greater than or equal to the number of the tuple
+elements. Tuple element indexes start from zero.
+"#,
+        example: r#"
+fn main() {
+    let tuple = (1, 2, 3);
+    let result = tuple.5;
+}
+"#,
+    },
+    Entry {
+        code: "Z0306",
+        title: "field or method does not exist",
+        explanation: r#"
+The structure or contract has neither a field nor a method with the name.
+
+Check the type declaration and its `impl` blocks for the correct name.
+"#,
+        example: r#"
+struct Data {
+    a: u8,
+}
+
+fn main() {
+    let data = Data {
+        a: 0,
+    };
+    let value = data.b;
+}
+"#,
+    },
+    Entry {
+        code: "Z0307",
+        title: "assignment of a value of another type",
+        explanation: r#"
+The assigned value type differs from the type of the memory place, which is
+fixed at the place declaration.
+
+Cast the value to the place type, or declare a new variable.
+"#,
+        example: r#"
+fn main() {
+    let mut result = 42;
+    result = false;
+}
+"#,
+    },
+    Entry {
+        code: "Z0308",
+        title: "assignment to an immutable variable",
+        explanation: r#"
+Variables are immutable by default, so they can only be assigned once at their
+declaration.
+
+Declare the variable with the `mut` keyword to make it mutable, e.g.
+`let mut result = 42;`.
+"#,
+        example: r#"
+fn main() {
+    let result = 42;
+    result = 64;
+}
+"#,
+    },
+    Entry {
+        code: "Z0309",
+        title: "mutation of an immutable contract field",
+        explanation: r#"
+The implicit contract storage fields like `address` and `balances` are
+maintained by the Zandbox server and cannot be mutated by the contract code.
+"#,
+        example: r#"
+contract Test {
+    pub fn mutator(mut self) {
+        self.address = 42 as u160;
+    }
+}
+"#,
+    },
+    Entry {
+        code: "Z0310",
+        title: "structure literal does not match its type",
+        explanation: r#"
+A structure or contract literal must initialize all the fields of its type in
+the order of the declaration, with the values of the declared field types.
+"#,
+        example: r#"
+struct Data {
+    a: u8,
+}
+
+fn main() {
+    let result = Data {
+        a: true,
+    };
+}
+"#,
+    },
+];
@@ -0,0 +1,84 @@
+//!
+//! The project structure error codes.
+//!
+
+use super::Entry;
+
+///
+/// The project structure error codes, which are in the `Z08xx` range.
+///
+pub static ENTRIES: &[Entry] = &[
+    Entry {
+        code: "Z0801",
+        title: "entry point missing",
+        explanation: r#"
+The `main.zn` entry file must contain either the circuit `main` function, or
+the contract declaration.
+"#,
+        example: r#"
+fn another() -> u8 {
+    42
+}
+"#,
+    },
+    Entry {
+        code: "Z0802",
+        title: "ambiguous entry point",
+        explanation: r#"
+The `main.zn` entry file contains both the circuit `main` function and the
+contract declaration, so the project type cannot be determined.
+
+Keep only one of them, or rename the `main` function.
+"#,
+        example: r#"
+fn main() -> u8 {
+    42
+}
+
+contract Uniswap {
+    pub fn deposit(amount: u248) -> bool { true }
+}
+"#,
+    },
+    Entry {
+        code: "Z0803",
+        title: "constant entry point",
+        explanation: r#"
+The circuit `main` function and the public contract methods are called with
+the runtime input, so they cannot be constant.
+"#,
+        example: r#"
+const fn main() -> u8 {
+    42
+}
+"#,
+    },
+    Entry {
+        code: "Z0804",
+        title: "entry point beyond the entry file",
+        explanation: r#"
+The circuit `main` function and the contract may only be declared in the
+`main.zn` entry file, but one of them is found in another module.
+
+Move the declaration to the entry file, or rename the function.
+"#,
+        example: r#"
+fn main() -> u8 {
+    42
+}
+"#,
+    },
+    Entry {
+        code: "Z0805",
+        title: "module file not found",
+        explanation: r#"
+The `mod name;` statement expects the `name.zn` file or the `name/` directory
+with the `mod.zn` file next to the declaring module.
+"#,
+        example: r#"
+mod unknown;
+
+fn main() {}
+"#,
+    },
+];
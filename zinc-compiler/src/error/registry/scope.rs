@@ -0,0 +1,203 @@
+//!
+//! The type and scope item error codes.
+//!
+
+use super::Entry;
+
+///
+/// The type and scope item error codes, which are in the `Z04xx` range.
+///
+pub static ENTRIES: &[Entry] = &[
+    Entry {
+        code: "Z0401",
+        title: "binding type required",
+        explanation: r#"
+Function arguments must have explicit types, as they are not inferred from the
+function calls.
+"#,
+        example: r#"
+fn main(a: u8, b: field, mut c) -> u8 {
+    42
+}
+"#,
+    },
+    Entry {
+        code: "Z0402",
+        title: "path does not point to a type",
+        explanation: r#"
+The path is used at a type position, but it points to another kind of item,
+e.g. a variable, constant, or function.
+"#,
+        example: r#"
+fn main() {
+    let unknown = 0;
+    let result = 42 as unknown;
+}
+"#,
+    },
+    Entry {
+        code: "Z0403",
+        title: "unexpected generic arguments",
+        explanation: r#"
+Generic arguments are passed to a type, which has no generic parameters.
+"#,
+        example: r#"
+type Array = [u8; 42];
+
+type Invalid = Array<bool>;
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0404",
+        title: "type cannot be instantiated",
+        explanation: r#"
+Some types, like `std::collections::MTreeMap`, can only be used as the types of
+the contract storage fields, since their data is kept in the contract storage
+rather than in the circuit memory.
+
+Declare a contract storage field of the type and access it via `self`.
+"#,
+        example: r#"
+use std::collections::MTreeMap;
+
+fn main(map: MTreeMap<u8, field>) {}
+"#,
+    },
+    Entry {
+        code: "Z0405",
+        title: "function type outside an argument",
+        explanation: r#"
+Function types like `fn(u8) -> u8` may only be used as the function argument
+types, which accept closures and functions.
+
+Call the closure directly instead of binding it to a variable.
+"#,
+        example: r#"
+fn main(x: u8) -> u8 {
+    let f: fn(u8) -> u8 = |a| a;
+    f(x)
+}
+"#,
+    },
+    Entry {
+        code: "Z0406",
+        title: "item redeclared",
+        explanation: r#"
+An item with the same name is already declared in the scope or any of its
+parent scopes. Zinc does not allow shadowing, so every variable, constant,
+type, and function name must be unique within its scope hierarchy.
+
+Rename one of the items.
+"#,
+        example: r#"
+fn main() {
+    let value = 1;
+    let value = 2;
+}
+"#,
+    },
+    Entry {
+        code: "Z0407",
+        title: "item undeclared",
+        explanation: r#"
+The item is not declared in the current scope or any of its parent scopes.
+
+Check the item name for typos, declare the item, or import it with `use`.
+"#,
+        example: r#"
+fn main() {
+    result = 64;
+}
+"#,
+    },
+    Entry {
+        code: "Z0408",
+        title: "item is not a namespace",
+        explanation: r#"
+The path resolution operator `::` can only be applied to modules, structures,
+enumerations, contracts, and traits, which have namespaces with associated
+items.
+"#,
+        example: r#"
+const NOT_NAMESPACE: u8 = 42;
+
+fn main() {
+    let result = NOT_NAMESPACE::UNDEFINED;
+}
+"#,
+    },
+    Entry {
+        code: "Z0409",
+        title: "associated item without its owner",
+        explanation: r#"
+The associated items like methods, associated constants, and contract fields
+must be accessed via their owner, e.g. `Self::CONSTANT`, `self.field`, or
+`Data::method()`.
+"#,
+        example: r#"
+contract Test {
+    a: u8;
+
+    pub fn default(self) -> u8 {
+        a
+    }
+}
+"#,
+    },
+    Entry {
+        code: "Z0410",
+        title: "contract redeclared",
+        explanation: r#"
+A project may contain only one contract, which is declared in the entry file.
+"#,
+        example: r#"
+contract Uniswap {
+    pub fn deposit(amount: u248) -> bool { true }
+}
+
+contract Multiswap {
+    pub fn deposit(amount: u248) -> bool { true }
+}
+"#,
+    },
+    Entry {
+        code: "Z0411",
+        title: "reference loop",
+        explanation: r#"
+The item declarations depend on each other in a loop, so none of them can be
+defined. This is also reported for recursive functions, as the recursion
+depth must be known at compile time in a circuit.
+
+Break the loop, or rewrite the recursion with a bounded loop.
+"#,
+        example: r#"
+const A: u8 = B;
+const B: u8 = A;
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0412",
+        title: "capturing closure passed to a function",
+        explanation: r#"
+A closure, which captures local variables of its enclosing function, cannot be
+passed to another function, since the captured values are only available in
+the scope, where the closure is declared.
+
+Pass the captured values as additional arguments instead.
+"#,
+        example: r#"
+fn apply(x: u8, f: fn(u8) -> u8) -> u8 {
+    f(x)
+}
+
+fn main(x: u8) -> u8 {
+    let offset: u8 = 3;
+    apply(x, |a| a + offset)
+}
+"#,
+    },
+];
@@ -0,0 +1,194 @@
+//!
+//! The syntax analysis error codes.
+//!
+
+use super::Entry;
+
+///
+/// The syntax analysis error codes, which are in the `Z01xx` range.
+///
+pub static ENTRIES: &[Entry] = &[
+    Entry {
+        code: "Z0101",
+        title: "unexpected token",
+        explanation: r#"
+The parser expects one of several tokens at this position, but found another one.
+
+The error message lists the expected tokens. For example, only the items like
+`fn`, `struct`, `enum`, `type`, `const`, `contract`, `mod`, `use`, `impl`, and
+`trait` may be declared at the module level.
+"#,
+        example: r#"
+fn main() {}
+
+42
+"#,
+    },
+    Entry {
+        code: "Z0102",
+        title: "unexpected token after an expression",
+        explanation: r#"
+The parser expects one of several tokens, or a binary operator continuing the
+expression, but found another token.
+
+This usually means a missing `;` at the end of a statement, a missing `,`
+between the elements of a list, or a missing closing bracket.
+"#,
+        example: r#"
+fn main() -> u8 {
+    return 42 value;
+}
+"#,
+    },
+    Entry {
+        code: "Z0103",
+        title: "expected an identifier",
+        explanation: r#"
+The parser expects an item or binding name, but found another token.
+
+Identifiers start with a letter or `_`, and may contain letters, digits, and
+`_`. Keywords cannot be used as identifiers.
+"#,
+        example: r#"
+fn 42() {}
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0104",
+        title: "expected `mut` or an identifier",
+        explanation: r#"
+The parser expects a binding name, optionally preceded by the `mut` keyword,
+but found another token.
+
+Add the binding name, e.g. `let mut value = 42;`.
+"#,
+        example: r#"
+fn main() {
+    let = 42;
+}
+"#,
+    },
+    Entry {
+        code: "Z0105",
+        title: "expected a field identifier",
+        explanation: r#"
+The field access operator `.` must be followed by a structure or contract
+field name, or by a tuple element index.
+"#,
+        example: r#"
+fn main() {
+    let tuple = (1, 2);
+    let value = tuple.+;
+}
+"#,
+    },
+    Entry {
+        code: "Z0106",
+        title: "expected a type",
+        explanation: r#"
+The parser expects a type, but found another token.
+
+Types are the primitive types like `bool`, `u8`, or `field`, the array types
+like `[u8; 4]`, the tuple types like `(u8, bool)`, and the paths to the
+declared types like `Data` or `std::collections::MTreeMap<u8, u8>`.
+"#,
+        example: r#"
+fn main(value: 42) {}
+"#,
+    },
+    Entry {
+        code: "Z0107",
+        title: "expected a type or value",
+        explanation: r#"
+The parser expects either `:` followed by a type, or `=` followed by a value,
+but found another token.
+
+Add the missing type annotation or initializer.
+"#,
+        example: r#"
+fn main() {
+    let value;
+}
+"#,
+    },
+    Entry {
+        code: "Z0108",
+        title: "expected a value",
+        explanation: r#"
+The parser expects `=` followed by a value, but found another token.
+
+Every `let` binding and constant must be initialized at its declaration, so
+add an initializer like `let value: u8 = 42;`.
+"#,
+        example: r#"
+fn main() {
+    let value: u8;
+}
+"#,
+    },
+    Entry {
+        code: "Z0109",
+        title: "expected an expression",
+        explanation: r#"
+The parser expects an expression or an operand of an operator, but found
+another token.
+
+Add the missing expression, or remove the dangling operator.
+"#,
+        example: r#"
+fn main() {
+    let value = ;
+}
+"#,
+    },
+    Entry {
+        code: "Z0110",
+        title: "expected an integer literal",
+        explanation: r#"
+Enumeration variant values must be integer literals, e.g. `A = 1`.
+
+Replace the value with an integer literal.
+"#,
+        example: r#"
+enum List {
+    A = true,
+}
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0111",
+        title: "expected a binding pattern",
+        explanation: r#"
+Function arguments and `let` bindings expect an identifier, `_`, or a tuple
+of those, but found another token.
+
+Replace the pattern with an identifier, e.g. `let value = 42;`.
+"#,
+        example: r#"
+fn main() {
+    let 42 = 42;
+}
+"#,
+    },
+    Entry {
+        code: "Z0112",
+        title: "expected a match pattern",
+        explanation: r#"
+A `match` branch pattern must be an identifier, a boolean or integer literal,
+a path to a constant or enumeration variant, a variant destructuring like
+`Some(value)`, or the wildcard `_`.
+"#,
+        example: r#"
+fn main(value: u8) -> u8 {
+    match value {
+        + => 1,
+        _ => 2,
+    }
+}
+"#,
+    },
+];
@@ -0,0 +1,185 @@
+//!
+//! The error code registry tests.
+//!
+
+use zinc_lexical::Error as LexicalError;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Location;
+use zinc_syntax::Error as SyntaxError;
+
+use crate::error::Error;
+use crate::semantic::scope::Scope;
+
+use super::Entry;
+
+///
+/// Returns the errors, which are declared but not produced by the lexer and parser yet, so their
+/// registry examples cannot be checked by compiling them.
+///
+fn unreachable() -> Vec<Error> {
+    vec![
+        Error::Lexical(LexicalError::unexpected_end(Location::test(1, 1))),
+        Error::Syntax(SyntaxError::ExpectedOneOfOrOperator {
+            location: Location::test(1, 1),
+            expected: "`;`".to_owned(),
+            found: Lexeme::Eof,
+            help: None,
+        }),
+        Error::Syntax(SyntaxError::expected_mut_or_identifier(
+            Location::test(1, 1),
+            Lexeme::Eof,
+            None,
+        )),
+        Error::Syntax(SyntaxError::expected_type_or_value(
+            Location::test(1, 1),
+            Lexeme::Eof,
+            None,
+        )),
+    ]
+}
+
+/// The codes, which examples are compiled as a non-entry module.
+static MODULE_EXAMPLES: &[&str] = &["Z0804"];
+
+#[test]
+fn ok_codes_ordered() {
+    let mut previous: Option<&str> = None;
+    for entry in Entry::all() {
+        assert_eq!(entry.code.len(), 5, "{} is malformed", entry.code);
+        assert!(entry.code.starts_with('Z'), "{} is malformed", entry.code);
+        assert!(
+            entry.code[1..].chars().all(|c| c.is_ascii_digit()),
+            "{} is malformed",
+            entry.code
+        );
+        assert!(!entry.title.is_empty(), "{} has no title", entry.code);
+        assert!(
+            !entry.explanation.trim().is_empty(),
+            "{} has no explanation",
+            entry.code
+        );
+        assert!(
+            !entry.example.trim().is_empty(),
+            "{} has no example",
+            entry.code
+        );
+
+        if let Some(previous) = previous {
+            assert!(
+                previous < entry.code,
+                "{} must follow {}",
+                entry.code,
+                previous
+            );
+        }
+        previous = Some(entry.code);
+    }
+}
+
+#[test]
+fn ok_codes_registered() {
+    for code in Error::CODES.iter() {
+        assert!(
+            Entry::find(code).is_some(),
+            "{} has no registry entry",
+            code
+        );
+    }
+}
+
+#[test]
+fn ok_codes_complete() {
+    let source = include_str!("../mod.rs");
+    let start = source
+        .find("pub fn code(&self)")
+        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+    let end = start
+        + source[start..]
+            .find("debug_assert!")
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+    let mut returned = 0;
+    for arm in source[start..end].split("=> \"").skip(1) {
+        let code = &arm[..5];
+        assert!(
+            Error::CODES.contains(&code),
+            "{} is returned by `Error::code`, but is missing in `Error::CODES`",
+            code
+        );
+        returned += 1;
+    }
+    assert!(
+        returned >= Error::CODES.len(),
+        "The code match is not found"
+    );
+}
+
+#[test]
+fn ok_unreachable() {
+    for error in unreachable().into_iter() {
+        let code = error.code();
+
+        assert!(
+            Entry::find(code).is_some(),
+            "{} has no registry entry",
+            code
+        );
+        assert_eq!(error.diagnostic().code, Some(code));
+    }
+}
+
+#[test]
+fn ok_examples() {
+    let unreachable: Vec<&str> = unreachable().iter().map(Error::code).collect();
+    let mut mismatches = Vec::new();
+
+    for entry in Entry::all() {
+        if unreachable.contains(&entry.code) {
+            continue;
+        }
+
+        let result = if MODULE_EXAMPLES.contains(&entry.code) {
+            crate::semantic::tests::compile_module(
+                entry.example,
+                0,
+                Scope::new_global(zinc_const::file_name::APPLICATION_ENTRY.to_owned()).wrap(),
+                Scope::new_global(zinc_const::file_name::APPLICATION_ENTRY.to_owned()).wrap(),
+                Scope::new_global(zinc_const::file_name::APPLICATION_ENTRY.to_owned()).wrap(),
+            )
+            .map(|_scope| ())
+        } else {
+            crate::semantic::tests::compile_entry(entry.example)
+        };
+
+        match result {
+            Ok(()) => mismatches.push(format!("{} example compiles", entry.code)),
+            Err(error) => {
+                let code = error.code();
+                let diagnostic = error.diagnostic();
+                assert_eq!(diagnostic.code, Some(code));
+                if code != entry.code {
+                    mismatches.push(format!(
+                        "{} example produces {}: {}",
+                        entry.code, code, diagnostic.message
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+#[test]
+fn ok_find() {
+    let entry = Entry::find("z0407").expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(entry.code, "Z0407");
+    assert!(entry.to_string().starts_with("Z0407: item undeclared\n"));
+}
+
+#[test]
+fn error_find_unknown() {
+    assert!(Entry::find("Z9999").is_none());
+    assert!(Entry::find("unused_mut").is_none());
+}
@@ -0,0 +1,240 @@
+//!
+//! The user-defined type and generic error codes.
+//!
+
+use super::Entry;
+
+///
+/// The user-defined type and generic error codes, which are in the `Z06xx` range.
+///
+pub static ENTRIES: &[Entry] = &[
+    Entry {
+        code: "Z0601",
+        title: "duplicate field",
+        explanation: r#"
+A structure or contract declares two fields with the same name.
+
+Rename or remove one of the fields.
+"#,
+        example: r#"
+struct Data {
+    a: u8,
+    b: u8,
+    b: field,
+}
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0602",
+        title: "wrong number of structure generic arguments",
+        explanation: r#"
+A generic structure must be used with as many generic arguments as it declares
+generic parameters, and a non-generic structure cannot be used with generic
+arguments at all.
+"#,
+        example: r#"
+struct Data<T> {
+    value: T,
+}
+
+fn main(data: Data) {}
+"#,
+    },
+    Entry {
+        code: "Z0603",
+        title: "`Option` type not specified",
+        explanation: r#"
+The `Option` type expects exactly one generic argument, which is the payload
+type, e.g. `Option<u8>`. If the payload type cannot be inferred from the value,
+like for `None`, it must be specified in the binding type.
+"#,
+        example: r#"
+fn main() {
+    let value = None;
+}
+"#,
+    },
+    Entry {
+        code: "Z0604",
+        title: "duplicate enumeration variant value",
+        explanation: r#"
+Every enumeration variant must have a unique value, since the values identify
+the variants at runtime.
+"#,
+        example: r#"
+enum List {
+    A = 1,
+    B = 2,
+    C = 2,
+}
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0605",
+        title: "invalid enumeration variant value",
+        explanation: r#"
+The variants of an enumeration without data must either all have explicit
+values, or all have none, while the data-carrying variants cannot have
+explicit values at all.
+"#,
+        example: r#"
+enum Message {
+    Quit = 1,
+    Move(u8, u8),
+}
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0606",
+        title: "duplicate enumeration variant field",
+        explanation: r#"
+A structure-like enumeration variant declares two fields with the same name.
+"#,
+        example: r#"
+enum Message {
+    Quit,
+    Write { length: u8, length: field },
+}
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0607",
+        title: "duplicate generic parameter",
+        explanation: r#"
+An item declares two generic parameters with the same name.
+"#,
+        example: r#"
+fn f<T, T>(a: T) -> T {
+    a
+}
+
+fn main() -> u8 {
+    f(42 as u8)
+}
+"#,
+    },
+    Entry {
+        code: "Z0608",
+        title: "invalid constant generic parameter",
+        explanation: r#"
+Constant generic parameters like `const N: u8` are only allowed for functions,
+and their type must be an integer type.
+"#,
+        example: r#"
+struct Data<const N: u64> {
+    a: [u8; N],
+}
+
+fn main() {}
+"#,
+    },
+    Entry {
+        code: "Z0609",
+        title: "constant generic argument overflow",
+        explanation: r#"
+The inferred value of a constant generic argument, like an array size, does not
+fit into the type of the constant generic parameter.
+
+Use a wider type for the constant generic parameter.
+"#,
+        example: r#"
+fn length<T, const N: u8>(array: [T; N]) -> u8 {
+    N
+}
+
+fn main() -> u8 {
+    length([0; 256])
+}
+"#,
+    },
+    Entry {
+        code: "Z0610",
+        title: "generic argument cannot be inferred",
+        explanation: r#"
+The generic arguments are inferred from the function call arguments, so every
+generic parameter must be used in the argument types, and all its occurrences
+must be inferred as the same type.
+"#,
+        example: r#"
+fn default<T>() -> T {
+    0
+}
+
+fn main() -> u8 {
+    default()
+}
+"#,
+    },
+    Entry {
+        code: "Z0611",
+        title: "generic bound not satisfied",
+        explanation: r#"
+A generic parameter bound must be a trait, and the generic argument type must
+implement all the traits the parameter is bound by.
+"#,
+        example: r#"
+struct Data {}
+
+fn digest<T: Data>(value: T) -> T {
+    value
+}
+
+fn main() -> bool {
+    digest(true)
+}
+"#,
+    },
+    Entry {
+        code: "Z0612",
+        title: "generic entry point",
+        explanation: r#"
+The circuit `main` function and contract methods are the application entry
+points, which input types must be known at compile time, so they cannot have
+generic parameters.
+"#,
+        example: r#"
+fn main<T>(input: T) -> T {
+    input
+}
+"#,
+    },
+    Entry {
+        code: "Z0613",
+        title: "non-constant value in a constant expression",
+        explanation: r#"
+Constants, array sizes, and other constant contexts can only use literals,
+other constants, and constant function calls, since they are evaluated at
+compile time.
+"#,
+        example: r#"
+fn main() {
+    let variable = 42;
+
+    const CONSTANT: u8 = variable;
+}
+"#,
+    },
+    Entry {
+        code: "Z0614",
+        title: "trait used as a value or type",
+        explanation: r#"
+Traits can only be used as the generic parameter bounds and in the `impl ...
+for` statements.
+"#,
+        example: r#"
+trait Empty {}
+
+fn main() {
+    let value = Empty;
+}
+"#,
+    },
+];
@@ -20,11 +20,15 @@ use zinc_build::Type as BuildType;
 
 use crate::generator::expression::operand::constant::integer::Integer as IntegerConstant;
 use crate::generator::expression::operand::place::Place;
+use crate::generator::r#type::Type;
 use crate::generator::state::State;
+use crate::generator::statement::r#let::Statement as LetStatement;
 use crate::generator::IBytecodeWritable;
+use crate::semantic::binding::Binding;
 use crate::semantic::element::access::dot::contract_field::ContractField as ContractFieldAccess;
 use crate::semantic::element::place::element::Element as SemanticPlaceElement;
 use crate::semantic::element::place::memory_type::MemoryType;
+use crate::semantic::element::r#type::Type as SemanticType;
 use zinc_lexical::Location;
 
 use self::element::Element;
@@ -81,6 +85,25 @@ impl Expression {
         self.elements.extend(expression.elements);
     }
 
+    ///
+    /// Sets the payload type of the `None` constants, which are the expression result, to the
+    /// `expected` type, which is known from the context, e.g. from a type annotation.
+    ///
+    pub fn infer_option(&mut self, expected: &SemanticType) {
+        if let Some(r#type) = Type::try_from_semantic(expected) {
+            self.set_option_type(&r#type);
+        }
+    }
+
+    ///
+    /// Sets the payload type of the `None` constants, which are the expression result.
+    ///
+    pub fn set_option_type(&mut self, r#type: &Type) {
+        if let Some(Element::Operand(operand)) = self.elements.last_mut() {
+            operand.set_option_type(r#type);
+        }
+    }
+
     ///
     /// Translates an assignment operator into the bytecode.
     ///
@@ -409,6 +432,188 @@ impl Expression {
         );
     }
 
+    ///
+    /// Translates a `Some(...)` function call into the bytecode.
+    ///
+    /// The payload is already on the evaluation stack, so only the tag is pushed.
+    ///
+    fn call_some(state: Rc<RefCell<State>>, location: Location) {
+        state.borrow_mut().push_instruction(
+            Instruction::Push(zinc_build::Push::new(BigInt::one(), ScalarType::Boolean)),
+            Some(location),
+        );
+    }
+
+    ///
+    /// Translates a data-carrying enumeration variant constructor call into the bytecode.
+    ///
+    /// The payload values are casted to `field`, since each payload slot may hold values of
+    /// different types in different variants. Then, the payload is padded with zeros to the
+    /// enumeration payload size, and the variant discriminant is pushed.
+    ///
+    fn call_variant(
+        state: Rc<RefCell<State>>,
+        input_size: usize,
+        payload_size: usize,
+        bitlength: usize,
+        discriminant: BigInt,
+        location: Location,
+    ) {
+        if input_size > 0 {
+            let address = state.borrow_mut().define_variable(None, input_size);
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_build::Store::new(address, input_size)),
+                Some(location),
+            );
+            for index in 0..input_size {
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_build::Load::new(address + index, 1)),
+                    Some(location),
+                );
+                state.borrow_mut().push_instruction(
+                    Instruction::Cast(zinc_build::Cast::new(ScalarType::Field)),
+                    Some(location),
+                );
+            }
+        }
+
+        for _ in input_size..payload_size {
+            state.borrow_mut().push_instruction(
+                Instruction::Push(zinc_build::Push::new(BigInt::zero(), ScalarType::Field)),
+                Some(location),
+            );
+        }
+
+        IntegerConstant::new(discriminant, false, bitlength).write_all(state);
+    }
+
+    ///
+    /// Translates a closure call into the bytecode.
+    ///
+    /// The arguments are stored to the closure variables, and the closure body is inlined.
+    ///
+    fn call_closure(
+        state: Rc<RefCell<State>>,
+        bindings: Vec<Binding>,
+        body: Self,
+        location: Location,
+    ) {
+        let variable_addresses = state.borrow().variable_addresses();
+
+        LetStatement::new(location, bindings, Self::new()).write_all(state.clone());
+        body.write_all(state.clone());
+
+        state
+            .borrow_mut()
+            .set_variable_addresses(variable_addresses);
+    }
+
+    ///
+    /// Translates a `std::array::map` call into the bytecode.
+    ///
+    /// The array is stored to a temporary variable, and the mapping function is called for each
+    /// of its elements, so the results form the new array on the evaluation stack.
+    ///
+    fn array_map(
+        state: Rc<RefCell<State>>,
+        array_size: usize,
+        element_size: usize,
+        callee: Operator,
+        location: Location,
+    ) {
+        let total_size = array_size * element_size;
+        let address = state.borrow_mut().define_variable(None, total_size);
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_build::Store::new(address, total_size)),
+            Some(location),
+        );
+
+        for index in 0..array_size {
+            state.borrow_mut().push_instruction(
+                Instruction::Load(zinc_build::Load::new(
+                    address + index * element_size,
+                    element_size,
+                )),
+                Some(location),
+            );
+
+            let mut call = Self::new();
+            call.push_operator(location, callee.clone());
+            call.write_all(state.clone());
+        }
+    }
+
+    ///
+    /// Translates a `std::array::fold` call into the bytecode.
+    ///
+    /// The initial value and the array are stored to temporary variables. Then the initial value
+    /// is loaded, and the folding function is called with the accumulator and each array element.
+    ///
+    fn array_fold(
+        state: Rc<RefCell<State>>,
+        array_size: usize,
+        element_size: usize,
+        accumulator_size: usize,
+        callee: Operator,
+        location: Location,
+    ) {
+        let accumulator_address = state.borrow_mut().define_variable(None, accumulator_size);
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_build::Store::new(
+                accumulator_address,
+                accumulator_size,
+            )),
+            Some(location),
+        );
+
+        let total_size = array_size * element_size;
+        let address = state.borrow_mut().define_variable(None, total_size);
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_build::Store::new(address, total_size)),
+            Some(location),
+        );
+
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_build::Load::new(accumulator_address, accumulator_size)),
+            Some(location),
+        );
+        for index in 0..array_size {
+            state.borrow_mut().push_instruction(
+                Instruction::Load(zinc_build::Load::new(
+                    address + index * element_size,
+                    element_size,
+                )),
+                Some(location),
+            );
+
+            let mut call = Self::new();
+            call.push_operator(location, callee.clone());
+            call.write_all(state.clone());
+        }
+    }
+
+    ///
+    /// Translates a `zksync::emit` function call into the bytecode.
+    ///
+    /// The event is declared in the contract metadata, and its index is pushed after the event
+    /// data, so the virtual machine is able to decode the flat event values.
+    ///
+    fn call_emit(state: Rc<RefCell<State>>, name: String, r#type: BuildType, location: Location) {
+        let input_size = r#type.size();
+        let index = state.borrow_mut().define_event(name, r#type);
+
+        IntegerConstant::new(BigInt::from(index), false, zinc_const::bitlength::FIELD)
+            .write_all(state.clone());
+        state.borrow_mut().push_instruction(
+            Instruction::CallLibrary(zinc_build::CallLibrary::new(
+                LibraryFunctionIdentifier::ZksyncEmit,
+                input_size + 1,
+                0,
+            )),
+            Some(location),
+        );
+    }
+
     ///
     /// Translates a standard library function call into the bytecode.
     ///
@@ -761,6 +966,20 @@ impl IBytecodeWritable for Expression {
                     Operator::CallRequire { message } => {
                         Self::call_assert(state.clone(), message, location)
                     }
+                    Operator::CallSome => Self::call_some(state.clone(), location),
+                    Operator::CallVariant {
+                        input_size,
+                        payload_size,
+                        bitlength,
+                        discriminant,
+                    } => Self::call_variant(
+                        state.clone(),
+                        input_size,
+                        payload_size,
+                        bitlength,
+                        discriminant,
+                        location,
+                    ),
                     Operator::CallLibrary {
                         identifier,
                         input_size,
@@ -772,6 +991,32 @@ impl IBytecodeWritable for Expression {
                         output_size,
                         location,
                     ),
+                    Operator::CallEmit { name, r#type } => {
+                        Self::call_emit(state.clone(), name, r#type.into(), location)
+                    }
+                    Operator::CallClosure { bindings, body } => {
+                        Self::call_closure(state.clone(), bindings, body, location)
+                    }
+                    Operator::ArrayMap {
+                        array_size,
+                        element_size,
+                        callee,
+                    } => {
+                        Self::array_map(state.clone(), array_size, element_size, *callee, location)
+                    }
+                    Operator::ArrayFold {
+                        array_size,
+                        element_size,
+                        accumulator_size,
+                        callee,
+                    } => Self::array_fold(
+                        state.clone(),
+                        array_size,
+                        element_size,
+                        accumulator_size,
+                        *callee,
+                        location,
+                    ),
                 },
             }
         }
@@ -7,41 +7,141 @@ pub mod builder;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use num::BigInt;
+use num::Zero;
+
+use zinc_build::Instruction;
+use zinc_build::Type as BuildType;
+use zinc_lexical::Location;
+
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
 use crate::generator::state::State;
+use crate::generator::statement::r#return::Statement as ReturnStatement;
 use crate::generator::statement::Statement;
 use crate::generator::IBytecodeWritable;
 
 ///
 /// The block expression which is translated to Zinc VM bytecode.
 ///
+/// If a statement may interrupt the current loop iteration with `break` or `continue`,
+/// or the function with `return`, the following statements are executed only if the
+/// iteration or the function is still running.
+/// Since the VM discards the evaluation stack changes made in a single-branch conditional,
+/// the guards of a block with a runtime trailing expression get an `else` branch, which
+/// produces zeros if the block has been interrupted. Other trailing expressions leave nothing
+/// on the evaluation stack, so they are written inside of the single-branch guards.
+///
 #[derive(Debug, Clone)]
 pub struct Expression {
     /// The block statements.
     statements: Vec<Statement>,
     /// The optional block expressions, whose type is defaulted to `()` if unset.
     expression: Option<GeneratorExpression>,
+    /// The block expression type, which is only set for runtime expressions.
+    expression_type: Option<Type>,
 }
 
 impl Expression {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(statements: Vec<Statement>, expression: Option<GeneratorExpression>) -> Self {
+    pub fn new(
+        statements: Vec<Statement>,
+        expression: Option<GeneratorExpression>,
+        expression_type: Option<Type>,
+    ) -> Self {
         Self {
             statements,
             expression,
+            expression_type,
+        }
+    }
+
+    ///
+    /// Converts the trailing expression into a `return` statement, so it is only evaluated
+    /// if the function has not returned earlier.
+    ///
+    pub fn into_returning(mut self, location: Location) -> Self {
+        if let Some(expression) = self.expression.take() {
+            self.statements.push(Statement::Return(ReturnStatement::new(
+                location,
+                Some(expression),
+            )));
+        }
+        self.expression_type = None;
+        self
+    }
+
+    ///
+    /// Sets the payload type of the `None` constants, which are the block result.
+    ///
+    pub fn set_option_type(&mut self, r#type: &Type) {
+        if let Some(expression) = self.expression.as_mut() {
+            expression.set_option_type(r#type);
+            self.expression_type = Some(r#type.to_owned());
         }
     }
 }
 
 impl IBytecodeWritable for Expression {
     fn write_all(self, bytecode: Rc<RefCell<State>>) {
+        let mut interruptions = bytecode.borrow().interruptions();
+        let mut guards_count = 0;
+
         for statement in self.statements.into_iter() {
+            if bytecode.borrow().interruptions() > interruptions {
+                interruptions = bytecode.borrow().interruptions();
+
+                let guard_address = bytecode.borrow().guard_address();
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_build::Load::new(guard_address, 1)),
+                    None,
+                );
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::If(zinc_build::If), None);
+                guards_count += 1;
+            }
+
             statement.write_all(bytecode.clone());
         }
-        if let Some(expression) = self.expression {
-            expression.write_all(bytecode);
+
+        match (self.expression, self.expression_type) {
+            (Some(expression), Some(expression_type)) if guards_count > 0 => {
+                expression.write_all(bytecode.clone());
+
+                let expression_type: BuildType = expression_type.into();
+                let scalar_types = expression_type.into_flat_scalar_types();
+                for _ in 0..guards_count {
+                    bytecode
+                        .borrow_mut()
+                        .push_instruction(Instruction::Else(zinc_build::Else), None);
+                    for scalar_type in scalar_types.iter() {
+                        bytecode.borrow_mut().push_instruction(
+                            Instruction::Push(zinc_build::Push::new(
+                                BigInt::zero(),
+                                scalar_type.to_owned(),
+                            )),
+                            None,
+                        );
+                    }
+                    bytecode
+                        .borrow_mut()
+                        .push_instruction(Instruction::EndIf(zinc_build::EndIf), None);
+                }
+            }
+            (expression, _) => {
+                if let Some(expression) = expression {
+                    expression.write_all(bytecode.clone());
+                }
+
+                for _ in 0..guards_count {
+                    bytecode
+                        .borrow_mut()
+                        .push_instruction(Instruction::EndIf(zinc_build::EndIf), None);
+                }
+            }
         }
     }
 }
@@ -11,6 +11,7 @@ use zinc_build::Instruction;
 
 use crate::generator::expression::operand::block::Expression as BlockExpression;
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
 use zinc_lexical::Location;
@@ -47,6 +48,16 @@ impl Expression {
             else_block,
         }
     }
+
+    ///
+    /// Sets the payload type of the `None` constants, which are the branch results.
+    ///
+    pub fn set_option_type(&mut self, r#type: &Type) {
+        self.main_block.set_option_type(r#type);
+        if let Some(else_block) = self.else_block.as_mut() {
+            else_block.set_option_type(r#type);
+        }
+    }
 }
 
 impl IBytecodeWritable for Expression {
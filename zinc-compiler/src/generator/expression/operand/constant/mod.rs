@@ -8,6 +8,15 @@ pub mod integer;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use num::BigInt;
+use num::Zero;
+
+use zinc_build::Instruction;
+use zinc_build::Push;
+use zinc_build::ScalarType;
+use zinc_build::Type as BuildType;
+
+use crate::generator::r#type::Type;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
 use crate::semantic::element::constant::Constant as SemanticConstant;
@@ -26,6 +35,19 @@ pub enum Constant {
     Integer(Integer),
     /// The constant group, which is created from an array, tuple, structure, etc.
     Group(Vec<Self>),
+    /// The `None` constant, which is written as the zeroed payload followed by the `false` tag.
+    None {
+        /// The payload type, which is unknown until it is inferred from the context.
+        payload_type: Option<Type>,
+    },
+    /// The data-carrying enumeration unit variant, which is written as the zeroed payload
+    /// followed by the variant discriminant.
+    Variant {
+        /// The variant discriminant.
+        discriminant: Integer,
+        /// The enumeration payload size.
+        payload_size: usize,
+    },
 }
 
 impl Constant {
@@ -37,7 +59,13 @@ impl Constant {
     pub fn try_from_semantic(constant: &SemanticConstant) -> Option<Self> {
         match constant {
             SemanticConstant::Boolean(inner) => Some(Self::Boolean(Boolean::from_semantic(inner))),
-            SemanticConstant::Integer(inner) => Some(Self::Integer(Integer::from_semantic(inner))),
+            SemanticConstant::Integer(inner) => match inner.enumeration {
+                Some(ref enumeration) if enumeration.is_data_carrying() => Some(Self::Variant {
+                    discriminant: Integer::from_semantic(inner),
+                    payload_size: enumeration.payload_size(),
+                }),
+                _ => Some(Self::Integer(Integer::from_semantic(inner))),
+            },
             SemanticConstant::Array(inner) => {
                 let group: Vec<Self> = inner
                     .values
@@ -74,9 +102,27 @@ impl Constant {
                     Some(Self::Group(group))
                 }
             }
+            SemanticConstant::Option(inner) => Some(Self::None {
+                payload_type: inner
+                    .r#type
+                    .inner
+                    .as_ref()
+                    .and_then(|r#type| Type::try_from_semantic(r#type.as_ref())),
+            }),
             _ => None,
         }
     }
+
+    ///
+    /// Sets the payload type of the `None` constant, if it has not been inferred yet.
+    ///
+    pub fn set_option_type(&mut self, r#type: &Type) {
+        if let (Self::None { payload_type }, Type::Option { r#type }) = (self, r#type) {
+            if payload_type.is_none() {
+                *payload_type = Some(*r#type.to_owned());
+            }
+        }
+    }
 }
 
 impl IBytecodeWritable for Constant {
@@ -89,6 +135,36 @@ impl IBytecodeWritable for Constant {
                     constant.write_all(state.clone());
                 }
             }
+            Self::None { payload_type } => {
+                let scalar_types = match payload_type {
+                    Some(payload_type) => {
+                        let payload_type: BuildType = payload_type.into();
+                        payload_type.into_flat_scalar_types()
+                    }
+                    None => vec![],
+                };
+                for scalar_type in scalar_types.into_iter() {
+                    state.borrow_mut().push_instruction(
+                        Instruction::Push(Push::new(BigInt::zero(), scalar_type)),
+                        None,
+                    );
+                }
+
+                Boolean::new(false).write_all(state);
+            }
+            Self::Variant {
+                discriminant,
+                payload_size,
+            } => {
+                for _ in 0..payload_size {
+                    state.borrow_mut().push_instruction(
+                        Instruction::Push(Push::new(BigInt::zero(), ScalarType::Field)),
+                        None,
+                    );
+                }
+
+                discriminant.write_all(state);
+            }
         }
     }
 }
@@ -0,0 +1,35 @@
+//!
+//! The generator expression match operand branch binding.
+//!
+
+use crate::generator::r#type::Type;
+
+///
+/// The variable, which is bound to a part of the scrutinee payload in a `match` branch.
+///
+#[derive(Debug, Clone)]
+pub struct Binding {
+    /// The bound variable name.
+    pub name: String,
+    /// The bound value offset within the scrutinee.
+    pub offset: usize,
+    /// The bound value type.
+    pub r#type: Type,
+    /// Whether the bound value scalars must be casted from the `field` payload slots, which is
+    /// the case for data-carrying enumerations.
+    pub is_casted: bool,
+}
+
+impl Binding {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, offset: usize, r#type: Type, is_casted: bool) -> Self {
+        Self {
+            name,
+            offset,
+            r#type,
+            is_casted,
+        }
+    }
+}
@@ -3,6 +3,7 @@
 //!
 
 use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::operand::r#match::binding::Binding;
 use crate::generator::expression::operand::r#match::Expression as MatchExpression;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
@@ -19,12 +20,13 @@ pub struct Builder {
     scrutinee: Option<GeneratorExpression>,
     /// The scrutinee (matched) expression type.
     scrutinee_type: Option<Type>,
-    /// The branches ordered array, where each branch consists of a pattern and result expression.
-    branches: Vec<(Constant, GeneratorExpression)>,
+    /// The branches ordered array, where each branch consists of a pattern, result expression,
+    /// and the payload bindings.
+    branches: Vec<(Constant, GeneratorExpression, Vec<Binding>)>,
     /// The binding branch, which is the last fallback branch.
     binding_branch: Option<(GeneratorExpression, String)>,
     /// The wildcard `_` branch, which is the last fallback branch. Ignored if `binding_branch` is set.
-    wildcard_branch: Option<GeneratorExpression>,
+    wildcard_branch: Option<(GeneratorExpression, Vec<Binding>)>,
 }
 
 impl Builder {
@@ -44,10 +46,15 @@ impl Builder {
     }
 
     ///
-    /// Pushes a branch, which consists of a `pattern` and `expression`.
+    /// Pushes a branch, which consists of a `pattern`, `expression`, and the payload bindings.
     ///
-    pub fn push_branch(&mut self, pattern: Constant, expression: GeneratorExpression) {
-        self.branches.push((pattern, expression));
+    pub fn push_branch(
+        &mut self,
+        pattern: Constant,
+        expression: GeneratorExpression,
+        bindings: Vec<Binding>,
+    ) {
+        self.branches.push((pattern, expression, bindings));
     }
 
     ///
@@ -60,8 +67,8 @@ impl Builder {
     ///
     /// Sets the corresponding builder value.
     ///
-    pub fn set_wildcard_branch(&mut self, value: GeneratorExpression) {
-        self.wildcard_branch = Some(value);
+    pub fn set_wildcard_branch(&mut self, expression: GeneratorExpression, bindings: Vec<Binding>) {
+        self.wildcard_branch = Some((expression, bindings));
     }
 
     ///
@@ -2,12 +2,15 @@
 //! The generator expression match operand.
 //!
 
+pub mod binding;
 pub mod builder;
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
 use zinc_build::Instruction;
+use zinc_build::ScalarType;
+use zinc_build::Type as BuildType;
 
 use crate::generator::expression::operand::constant::Constant;
 use crate::generator::expression::Expression as GeneratorExpression;
@@ -16,9 +19,14 @@ use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
 use zinc_lexical::Location;
 
+use self::binding::Binding;
+
 ///
 /// The match expression which is translated to Zinc VM conditional series.
 ///
+/// If the scrutinee is an `Option` or a data-carrying enumeration, the branch patterns are
+/// compared with its tag, and the branches may bind parts of the payload to variables.
+///
 #[derive(Debug, Clone)]
 pub struct Expression {
     /// The `match` expression location.
@@ -27,12 +35,13 @@ pub struct Expression {
     scrutinee: GeneratorExpression,
     /// The scrutinee (matched) expression type.
     scrutinee_type: Type,
-    /// The branches ordered array, where each branch consists of a pattern and result expression.
-    branches: Vec<(Constant, GeneratorExpression)>,
+    /// The branches ordered array, where each branch consists of a pattern, result expression,
+    /// and the payload bindings.
+    branches: Vec<(Constant, GeneratorExpression, Vec<Binding>)>,
     /// The binding branch, which is the last fallback branch.
     binding_branch: Option<(GeneratorExpression, String)>,
     /// The wildcard `_` branch, which is the last fallback branch. Ignored if `binding_branch` is set.
-    wildcard_branch: Option<GeneratorExpression>,
+    wildcard_branch: Option<(GeneratorExpression, Vec<Binding>)>,
 }
 
 impl Expression {
@@ -43,9 +52,9 @@ impl Expression {
         location: Location,
         scrutinee: GeneratorExpression,
         scrutinee_type: Type,
-        branches: Vec<(Constant, GeneratorExpression)>,
+        branches: Vec<(Constant, GeneratorExpression, Vec<Binding>)>,
         binding_branch: Option<(GeneratorExpression, String)>,
-        wildcard_branch: Option<GeneratorExpression>,
+        wildcard_branch: Option<(GeneratorExpression, Vec<Binding>)>,
     ) -> Self {
         Self {
             location,
@@ -56,12 +65,85 @@ impl Expression {
             wildcard_branch,
         }
     }
+
+    ///
+    /// Sets the payload type of the `None` constants, which are the branch results.
+    ///
+    pub fn set_option_type(&mut self, r#type: &Type) {
+        for (_pattern, expression, _bindings) in self.branches.iter_mut() {
+            expression.set_option_type(r#type);
+        }
+        if let Some((expression, _name)) = self.binding_branch.as_mut() {
+            expression.set_option_type(r#type);
+        }
+        if let Some((expression, _bindings)) = self.wildcard_branch.as_mut() {
+            expression.set_option_type(r#type);
+        }
+    }
+
+    ///
+    /// Copies the scrutinee payload parts to the variables, which are bound in the branch.
+    ///
+    /// The data-carrying enumeration payload scalars are stored as `field` elements, so they
+    /// are casted back to their types.
+    ///
+    fn bind_payload(
+        bytecode: Rc<RefCell<State>>,
+        scrutinee_address: usize,
+        bindings: Vec<Binding>,
+        location: Location,
+    ) {
+        for binding in bindings.into_iter() {
+            let size = binding.r#type.size();
+            let address = bytecode
+                .borrow_mut()
+                .define_variable(Some(binding.name), size);
+
+            if binding.is_casted {
+                let r#type: BuildType = binding.r#type.into();
+                for (index, scalar_type) in r#type.into_flat_scalar_types().into_iter().enumerate()
+                {
+                    bytecode.borrow_mut().push_instruction(
+                        Instruction::Load(zinc_build::Load::new(
+                            scrutinee_address + binding.offset + index,
+                            1,
+                        )),
+                        Some(location),
+                    );
+                    if scalar_type != ScalarType::Field {
+                        bytecode.borrow_mut().push_instruction(
+                            Instruction::Cast(zinc_build::Cast::new(scalar_type)),
+                            Some(location),
+                        );
+                    }
+                }
+            } else {
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_build::Load::new(
+                        scrutinee_address + binding.offset,
+                        size,
+                    )),
+                    Some(location),
+                );
+            }
+
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Store(zinc_build::Store::new(address, size)),
+                Some(location),
+            );
+        }
+    }
 }
 
 impl IBytecodeWritable for Expression {
     fn write_all(self, bytecode: Rc<RefCell<State>>) {
         let branch_count = self.branches.len();
         let scrutinee_size = self.scrutinee_type.size();
+        let tag_offset = match self.scrutinee_type {
+            Type::Option { ref r#type } => Some(r#type.size()),
+            Type::DataEnumeration { .. } => Some(scrutinee_size - 1),
+            _ => None,
+        };
 
         let (binding_branch, binding_name) = match self.binding_branch {
             Some((binding_branch, binding_name)) => (Some(binding_branch), Some(binding_name)),
@@ -78,9 +160,14 @@ impl IBytecodeWritable for Expression {
             Some(self.location),
         );
 
-        for (branch_pattern, branch_expression) in self.branches.into_iter() {
+        let (pattern_address, pattern_size) = match tag_offset {
+            Some(tag_offset) => (scrutinee_address + tag_offset, 1),
+            None => (scrutinee_address, scrutinee_size),
+        };
+
+        for (branch_pattern, branch_expression, bindings) in self.branches.into_iter() {
             bytecode.borrow_mut().push_instruction(
-                Instruction::Load(zinc_build::Load::new(scrutinee_address, scrutinee_size)),
+                Instruction::Load(zinc_build::Load::new(pattern_address, pattern_size)),
                 Some(self.location),
             );
             branch_pattern.write_all(bytecode.clone());
@@ -90,6 +177,7 @@ impl IBytecodeWritable for Expression {
             bytecode
                 .borrow_mut()
                 .push_instruction(Instruction::If(zinc_build::If), Some(self.location));
+            Self::bind_payload(bytecode.clone(), scrutinee_address, bindings, self.location);
             branch_expression.write_all(bytecode.clone());
             bytecode
                 .borrow_mut()
@@ -98,7 +186,8 @@ impl IBytecodeWritable for Expression {
 
         if let Some(binding_branch) = binding_branch {
             binding_branch.write_all(bytecode.clone());
-        } else if let Some(wildcard_branch) = self.wildcard_branch {
+        } else if let Some((wildcard_branch, bindings)) = self.wildcard_branch {
+            Self::bind_payload(bytecode.clone(), scrutinee_address, bindings, self.location);
             wildcard_branch.write_all(bytecode.clone());
         }
 
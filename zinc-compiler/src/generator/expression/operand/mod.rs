@@ -19,6 +19,7 @@ use num::BigInt;
 use zinc_build::Instruction;
 
 use crate::generator::expression::operand::constant::integer::Integer as IntegerConstant;
+use crate::generator::r#type::Type;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
 use crate::semantic::element::access::dot::contract_field::ContractField as ContractFieldAccess;
@@ -57,6 +58,21 @@ pub enum Operand {
     Match(MatchExpression),
 }
 
+impl Operand {
+    ///
+    /// Sets the payload type of the `None` constants, which are the operand result.
+    ///
+    pub fn set_option_type(&mut self, r#type: &Type) {
+        match self {
+            Self::Constant(inner) => inner.set_option_type(r#type),
+            Self::Block(inner) => inner.set_option_type(r#type),
+            Self::Conditional(inner) => inner.set_option_type(r#type),
+            Self::Match(inner) => inner.set_option_type(r#type),
+            _ => {}
+        }
+    }
+}
+
 impl IBytecodeWritable for Operand {
     fn write_all(self, state: Rc<RefCell<State>>) {
         match self {
@@ -2,11 +2,14 @@
 //! The generator expression operator.
 //!
 
+use num::BigInt;
+
 use zinc_build::LibraryFunctionIdentifier;
 
 use crate::generator::expression::operand::place::Place;
 use crate::generator::expression::Expression;
 use crate::generator::r#type::Type;
+use crate::semantic::binding::Binding;
 use crate::semantic::element::access::dot::stack_field::StackField as StackFieldAccess;
 use crate::semantic::element::access::index::Index as IndexAccess;
 use crate::semantic::element::r#type::Type as SemanticType;
@@ -285,6 +288,20 @@ pub enum Operator {
         /// The optional error description message.
         message: Option<String>,
     },
+    /// The `Some(...)` function call operator, which pushes the `Option` tag after the payload.
+    CallSome,
+    /// The data-carrying enumeration variant constructor call operator, which pads the payload
+    /// and pushes the variant discriminant after it.
+    CallVariant {
+        /// The variant payload size.
+        input_size: usize,
+        /// The enumeration payload size, which the variant payload is padded to.
+        payload_size: usize,
+        /// The enumeration discriminant bitlength.
+        bitlength: usize,
+        /// The variant discriminant.
+        discriminant: BigInt,
+    },
     /// The standard library function call.
     CallLibrary {
         /// The unique standard library function identifier.
@@ -294,6 +311,40 @@ pub enum Operator {
         /// The function result type size.
         output_size: usize,
     },
+    /// The `zksync::emit` function call, which passes the event index to the standard library.
+    CallEmit {
+        /// The event name, which is the emitted structure identifier.
+        name: String,
+        /// The event data type.
+        r#type: Type,
+    },
+    /// The closure call, whose body is inlined into the caller.
+    CallClosure {
+        /// The closure arguments, which are stored from the evaluation stack before the body.
+        bindings: Vec<Binding>,
+        /// The closure body.
+        body: Expression,
+    },
+    /// The `std::array::map` function call, whose mapping function is inlined for each element.
+    ArrayMap {
+        /// The array size.
+        array_size: usize,
+        /// The array element size.
+        element_size: usize,
+        /// The mapping function call operator.
+        callee: Box<Self>,
+    },
+    /// The `std::array::fold` function call, whose folding function is inlined for each element.
+    ArrayFold {
+        /// The array size.
+        array_size: usize,
+        /// The array element size.
+        element_size: usize,
+        /// The accumulator size.
+        accumulator_size: usize,
+        /// The folding function call operator.
+        callee: Box<Self>,
+    },
 }
 
 impl Operator {
@@ -764,6 +815,30 @@ impl Operator {
         Self::CallRequire { message }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_some() -> Self {
+        Self::CallSome
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_variant(
+        input_size: usize,
+        payload_size: usize,
+        bitlength: usize,
+        discriminant: BigInt,
+    ) -> Self {
+        Self::CallVariant {
+            input_size,
+            payload_size,
+            bitlength,
+            discriminant,
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -779,6 +854,52 @@ impl Operator {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_emit(name: String, r#type: &SemanticType) -> Self {
+        Self::CallEmit {
+            name,
+            r#type: Type::try_from_semantic(r#type)
+                .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_closure(bindings: Vec<Binding>, body: Expression) -> Self {
+        Self::CallClosure { bindings, body }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn array_map(array_size: usize, element_size: usize, callee: Self) -> Self {
+        Self::ArrayMap {
+            array_size,
+            element_size,
+            callee: Box::new(callee),
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn array_fold(
+        array_size: usize,
+        element_size: usize,
+        accumulator_size: usize,
+        callee: Self,
+    ) -> Self {
+        Self::ArrayFold {
+            array_size,
+            element_size,
+            accumulator_size,
+            callee: Box::new(callee),
+        }
+    }
+
     ///
     /// Returns the first operand's integer inferred type, if the type was inferred for the literal
     /// to adopt the other operand's fixed type.
@@ -0,0 +1,37 @@
+//!
+//! The Zinc VM bytecode loop.
+//!
+
+///
+/// The interruptible loop metadata, which is used to write `break` and `continue` statements.
+///
+#[derive(Debug)]
+pub struct Loop {
+    /// The data stack address of the flag, which is cleared by `break`.
+    pub break_address: usize,
+    /// The data stack address of the flag, which is cleared by both `break` and `continue`.
+    pub continue_address: usize,
+    /// The interruptions counter value at the beginning of the loop.
+    pub interruptions: usize,
+    /// The returns counter value at the beginning of the loop.
+    pub returns: usize,
+}
+
+impl Loop {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        break_address: usize,
+        continue_address: usize,
+        interruptions: usize,
+        returns: usize,
+    ) -> Self {
+        Self {
+            break_address,
+            continue_address,
+            interruptions,
+            returns,
+        }
+    }
+}
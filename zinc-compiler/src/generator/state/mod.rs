@@ -3,7 +3,9 @@
 //!
 
 pub mod entry;
+pub mod r#loop;
 pub mod optimizer;
+pub mod r#return;
 pub mod unit_test;
 
 use std::cell::RefCell;
@@ -11,6 +13,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use zinc_build::Application as BuildApplication;
+use zinc_build::ContractEvent;
 use zinc_build::ContractMethod;
 use zinc_build::Instruction;
 use zinc_build::Type as BuildType;
@@ -22,9 +25,13 @@ use zinc_manifest::ProjectType;
 
 use crate::generator::r#type::contract_field::ContractField as ContractFieldType;
 use crate::generator::r#type::Type;
+use crate::lint::index::INDEX as LINT_INDEX;
+use crate::lint::warning::Warning;
 
 use self::entry::Entry;
+use self::r#loop::Loop;
 use self::optimizer::dead_function_code_elimination::Optimizer as DeadFunctionCodeEliminationOptimizer;
+use self::r#return::Return;
 use self::unit_test::UnitTest;
 
 ///
@@ -39,6 +46,8 @@ pub struct State {
     instructions: Vec<Instruction>,
     /// The contract storage structure.
     contract_storage: Option<Vec<ContractFieldType>>,
+    /// The contract events emitted with the `zksync::emit` function.
+    events: Vec<ContractEvent>,
     /// Metadata of each application entry.
     entries: HashMap<usize, Entry>,
     /// Unit tests.
@@ -46,12 +55,23 @@ pub struct State {
 
     /// Bytecode addresses of the functions written to the bytecode.
     function_addresses: HashMap<usize, usize>,
+    /// The locations and names of the functions checked for being unused by the linter.
+    linted_functions: HashMap<usize, (Location, String)>,
     /// Data stack addresses of variables declared at runtime.
     variable_addresses: HashMap<String, usize>,
     /// The pointer which is reset at the beginning of each function.
     data_stack_pointer: usize,
     /// The location pointer used to pass debug information to the VM.
     current_location: Location,
+
+    /// The stack of the interruptible loops being written, where the innermost is the last one.
+    loops: Vec<Loop>,
+    /// The number of `break`, `continue`, and `return` statements written so far.
+    interruptions: usize,
+    /// The early return metadata of the function being written, if it has `return` statements.
+    function_return: Option<Return>,
+    /// The number of `return` statements written so far.
+    returns: usize,
 }
 
 impl State {
@@ -80,13 +100,20 @@ impl State {
 
             instructions: Vec::with_capacity(Self::INSTRUCTIONS_INITIAL_CAPACITY),
             contract_storage: None,
+            events: Vec::new(),
             entries: HashMap::with_capacity(Self::ENTRIES_INITIAL_CAPACITY),
             unit_tests: HashMap::with_capacity(Self::UNIT_TESTS_INITIAL_CAPACITY),
 
             function_addresses: HashMap::with_capacity(Self::FUNCTION_ADDRESSES_INITIAL_CAPACITY),
+            linted_functions: HashMap::with_capacity(Self::FUNCTION_ADDRESSES_INITIAL_CAPACITY),
             variable_addresses: HashMap::with_capacity(Self::VARIABLE_ADDRESSES_INITIAL_CAPACITY),
             data_stack_pointer: 0,
             current_location: Location::default(),
+
+            loops: Vec::new(),
+            interruptions: 0,
+            function_return: None,
+            returns: 0,
         }
     }
 
@@ -113,6 +140,21 @@ impl State {
         self.variable_addresses.get(name).copied()
     }
 
+    ///
+    /// Returns a snapshot of the variable addresses, which is restored after an inlined closure
+    /// body is written, so the closure arguments do not hide the caller variables.
+    ///
+    pub fn variable_addresses(&self) -> HashMap<String, usize> {
+        self.variable_addresses.clone()
+    }
+
+    ///
+    /// Restores the variable addresses snapshot.
+    ///
+    pub fn set_variable_addresses(&mut self, value: HashMap<String, usize>) {
+        self.variable_addresses = value;
+    }
+
     ///
     /// Sets the contract storage field types.
     ///
@@ -120,6 +162,25 @@ impl State {
         self.contract_storage = Some(fields);
     }
 
+    ///
+    /// Declares the contract event, returning its index in the contract metadata.
+    ///
+    /// Events of the same name and type share the index, so the metadata is not duplicated
+    /// if an event is emitted from several places.
+    ///
+    pub fn define_event(&mut self, name: String, r#type: BuildType) -> usize {
+        if let Some(index) = self
+            .events
+            .iter()
+            .position(|event| event.name == name && event.r#type == r#type)
+        {
+            return index;
+        }
+
+        self.events.push(ContractEvent::new(name, r#type));
+        self.events.len() - 1
+    }
+
     ///
     /// Starts a new function, resetting the data stack pointer and writing the
     /// function debug information.
@@ -128,6 +189,7 @@ impl State {
         let address = self.instructions.len();
         self.function_addresses.insert(type_id, address);
         self.data_stack_pointer = 0;
+        self.function_return = None;
 
         self.instructions
             .push(Instruction::FileMarker(zinc_build::FileMarker::new(
@@ -148,6 +210,15 @@ impl State {
         }
     }
 
+    ///
+    /// Marks the function with `type_id` to be reported by the linter, if it is unreachable from
+    /// the application entries and unit tests.
+    ///
+    pub fn lint_function(&mut self, location: Location, type_id: usize, identifier: String) {
+        self.linted_functions
+            .insert(type_id, (location, identifier));
+    }
+
     ///
     /// Starts an entry function, saves its metadata and calls the `start_function` method.
     ///
@@ -202,6 +273,104 @@ impl State {
         start_address
     }
 
+    ///
+    /// Starts an interruptible loop with its `break` and `continue` flag addresses.
+    ///
+    pub fn push_loop(&mut self, break_address: usize, continue_address: usize) {
+        self.loops.push(Loop::new(
+            break_address,
+            continue_address,
+            self.interruptions,
+            self.returns,
+        ));
+    }
+
+    ///
+    /// Finishes the innermost interruptible loop.
+    ///
+    /// The interruptions counter is restored, since `break` and `continue` statements do not
+    /// affect the code after the loop, whereas `return` statements do.
+    ///
+    pub fn pop_loop(&mut self) {
+        let r#loop = self
+            .loops
+            .pop()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        self.interruptions = r#loop.interruptions + (self.returns - r#loop.returns);
+    }
+
+    ///
+    /// Returns the innermost interruptible loop.
+    ///
+    pub fn current_loop(&self) -> Option<&Loop> {
+        self.loops.last()
+    }
+
+    ///
+    /// Returns all the interruptible loops being written, where the innermost is the last one.
+    ///
+    pub fn loops(&self) -> &[Loop] {
+        self.loops.as_slice()
+    }
+
+    ///
+    /// Sets the early return metadata of the function being written.
+    ///
+    pub fn set_function_return(&mut self, value: Return) {
+        self.function_return = Some(value);
+    }
+
+    ///
+    /// Returns the early return metadata of the function being written.
+    ///
+    pub fn function_return(&self) -> Option<&Return> {
+        self.function_return.as_ref()
+    }
+
+    ///
+    /// Registers a `return` statement, which also interrupts the current loop iteration.
+    ///
+    pub fn interrupt_function(&mut self) {
+        self.returns += 1;
+        self.interrupt();
+    }
+
+    ///
+    /// Returns the address of the flag, which must be checked before executing the statements
+    /// following a `break`, `continue`, or `return` statement.
+    ///
+    /// The innermost loop flag is cleared by all of them, whereas the function flag is only
+    /// used outside of loops.
+    ///
+    pub fn guard_address(&self) -> usize {
+        self.loops
+            .last()
+            .map(|r#loop| r#loop.continue_address)
+            .or_else(|| {
+                self.function_return
+                    .as_ref()
+                    .map(|r#return| r#return.running_address)
+            })
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+    }
+
+    ///
+    /// Registers a `break` or `continue` statement.
+    ///
+    pub fn interrupt(&mut self) {
+        self.interruptions += 1;
+    }
+
+    ///
+    /// Returns the number of `break`, `continue`, and `return` statements written so far.
+    ///
+    /// If the value has changed after writing a statement, the rest of the block must be
+    /// executed only if the current loop iteration has not been interrupted.
+    ///
+    pub fn interruptions(&self) -> usize {
+        self.interruptions
+    }
+
     ///
     /// Writes the instruction along with its location debug information.
     ///
@@ -243,6 +412,8 @@ impl State {
         mut self,
         optimize_dead_function_elimination: bool,
     ) -> BuildApplication {
+        self.lint_unused_functions();
+
         match self.contract_storage.take() {
             Some(storage) => {
                 let storage = storage.into_iter().map(|field| field.into()).collect();
@@ -314,6 +485,7 @@ impl State {
                     self.manifest.project.name,
                     storage,
                     methods,
+                    self.events,
                     unit_tests,
                     self.instructions,
                 )
@@ -381,6 +553,27 @@ impl State {
         }
     }
 
+    ///
+    /// Reports the linted functions, which are unreachable from the application entries and
+    /// unit tests.
+    ///
+    fn lint_unused_functions(&mut self) {
+        let mut entry_ids: Vec<usize> = self.entries.keys().copied().collect();
+        entry_ids.extend(self.unit_tests.keys().copied());
+
+        let unreachable = DeadFunctionCodeEliminationOptimizer::unreachable(
+            entry_ids.as_slice(),
+            self.instructions.as_slice(),
+            &self.function_addresses,
+        );
+
+        for type_id in unreachable.into_iter() {
+            if let Some((location, name)) = self.linted_functions.remove(&type_id) {
+                LINT_INDEX.push(Warning::UnusedFunction { location, name });
+            }
+        }
+    }
+
     ///
     /// Prints the bytecode instructions to the terminal.
     ///
@@ -3,6 +3,7 @@
 //!
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 use petgraph::algo::DfsSpace;
 use petgraph::graph::Graph;
@@ -40,6 +41,58 @@ impl Optimizer {
         mut instructions: &mut Vec<Instruction>,
         mut function_addresses: &mut HashMap<usize, usize>,
     ) {
+        let unreachable = Self::unreachable(entry_ids.as_slice(), instructions, function_addresses);
+
+        let mut function_address_shifts =
+            HashMap::<usize, usize>::with_capacity(function_addresses.len());
+        for (type_id, start_address) in function_addresses.iter() {
+            if unreachable.contains(type_id) {
+                let mut removed_count = 0;
+                for address in *start_address..instructions.len() {
+                    let is_end = match instructions.get(address) {
+                        Some(Instruction::Return(_)) => true,
+                        Some(Instruction::Exit(_)) => true,
+                        _ => false,
+                    };
+
+                    instructions[address] = Instruction::NoOperation(zinc_build::NoOperation);
+                    removed_count += 1;
+
+                    if is_end {
+                        break;
+                    }
+                }
+
+                for (type_id, shifted_address) in function_addresses.iter() {
+                    if shifted_address > start_address {
+                        function_address_shifts
+                            .entry(*type_id)
+                            .and_modify(|value| *value += removed_count)
+                            .or_insert(removed_count);
+                    }
+                }
+            }
+        }
+
+        instructions.retain(|instruction| !matches!(instruction, Instruction::NoOperation(_)));
+        Self::set_shifted_call_addresses(
+            &mut instructions,
+            &mut function_addresses,
+            &function_address_shifts,
+        );
+    }
+
+    ///
+    /// Returns the type IDs of the functions, which are not reachable from any of the
+    /// `entry_ids` functions via the call graph.
+    ///
+    /// Is also used by the linter to report the unused functions.
+    ///
+    pub fn unreachable(
+        entry_ids: &[usize],
+        instructions: &[Instruction],
+        function_addresses: &HashMap<usize, usize>,
+    ) -> HashSet<usize> {
         let mut graph = Graph::new();
         let mut function_node_map = HashMap::with_capacity(function_addresses.len());
         for (function_id, _) in function_addresses.iter() {
@@ -73,12 +126,10 @@ impl Optimizer {
         }
 
         let mut graph_workspace = DfsSpace::new(&graph);
-        let mut function_address_shifts =
-            HashMap::<usize, usize>::with_capacity(function_addresses.len());
-        for (type_id, start_address) in function_addresses.iter() {
-            let mut is_isolated = true;
-            for entry_id in entry_ids.iter() {
-                if petgraph::algo::has_path_connecting(
+        let mut unreachable = HashSet::with_capacity(function_addresses.len());
+        for type_id in function_addresses.keys() {
+            let is_isolated = entry_ids.iter().all(|entry_id| {
+                !petgraph::algo::has_path_connecting(
                     &graph,
                     function_node_map
                         .get(entry_id)
@@ -89,46 +140,15 @@ impl Optimizer {
                         .copied()
                         .expect(zinc_const::panic::VALIDATED_DURING_TARGET_CODE_GENERATION),
                     Some(&mut graph_workspace),
-                ) {
-                    is_isolated = false;
-                    break;
-                }
-            }
+                )
+            });
 
             if is_isolated {
-                let mut removed_count = 0;
-                for address in *start_address..instructions.len() {
-                    let is_end = match instructions.get(address) {
-                        Some(Instruction::Return(_)) => true,
-                        Some(Instruction::Exit(_)) => true,
-                        _ => false,
-                    };
-
-                    instructions[address] = Instruction::NoOperation(zinc_build::NoOperation);
-                    removed_count += 1;
-
-                    if is_end {
-                        break;
-                    }
-                }
-
-                for (type_id, shifted_address) in function_addresses.iter() {
-                    if shifted_address > start_address {
-                        function_address_shifts
-                            .entry(*type_id)
-                            .and_modify(|value| *value += removed_count)
-                            .or_insert(removed_count);
-                    }
-                }
+                unreachable.insert(*type_id);
             }
         }
 
-        instructions.retain(|instruction| !matches!(instruction, Instruction::NoOperation(_)));
-        Self::set_shifted_call_addresses(
-            &mut instructions,
-            &mut function_addresses,
-            &function_address_shifts,
-        );
+        unreachable
     }
 
     ///
@@ -0,0 +1,30 @@
+//!
+//! The Zinc VM bytecode function return.
+//!
+
+///
+/// The early return metadata of the function being written, which is used to write
+/// `return` statements.
+///
+#[derive(Debug)]
+pub struct Return {
+    /// The data stack address of the flag, which is cleared by `return`.
+    pub running_address: usize,
+    /// The data stack address of the function result.
+    pub result_address: usize,
+    /// The function result size.
+    pub result_size: usize,
+}
+
+impl Return {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(running_address: usize, result_address: usize, result_size: usize) -> Self {
+        Self {
+            running_address,
+            result_address,
+            result_size,
+        }
+    }
+}
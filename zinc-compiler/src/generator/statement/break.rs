@@ -0,0 +1,55 @@
+//!
+//! The generator `break` statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_build::Instruction;
+use zinc_lexical::Location;
+
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
+use crate::generator::r#type::Type;
+use crate::generator::state::State;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The Zinc VM `break` statement.
+///
+/// Clears both flags of the innermost loop, so the rest of the current iteration and all the
+/// following iterations are not executed.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    /// The statement location in the source code.
+    pub location: Location,
+}
+
+impl Statement {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location) -> Self {
+        Self { location }
+    }
+}
+
+impl IBytecodeWritable for Statement {
+    fn write_all(self, state: Rc<RefCell<State>>) {
+        let (break_address, continue_address) = state
+            .borrow()
+            .current_loop()
+            .map(|r#loop| (r#loop.break_address, r#loop.continue_address))
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        for address in [break_address, continue_address].iter() {
+            BooleanConstant::new(false).write_all(state.clone());
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_build::Store::new(*address, Type::boolean().size())),
+                Some(self.location),
+            );
+        }
+
+        state.borrow_mut().interrupt();
+    }
+}
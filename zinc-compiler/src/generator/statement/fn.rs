@@ -5,13 +5,20 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use num::BigInt;
+use num::Zero;
+
 use zinc_build::Instruction;
+use zinc_build::Type as BuildType;
 use zinc_lexical::Location;
 
 use crate::generator::expression::operand::block::Expression;
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
 use crate::generator::r#type::Type;
+use crate::generator::state::r#return::Return;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::lint::Lint;
 use crate::semantic::analyzer::attribute::Attribute;
 use crate::semantic::binding::Binding;
 use crate::semantic::element::r#type::Type as SemanticType;
@@ -19,6 +26,9 @@ use crate::semantic::element::r#type::Type as SemanticType;
 ///
 /// The Zinc VM function statement.
 ///
+/// If the function has `return` statements, its result is stored to a variable, which is
+/// initialized with zeros and loaded right before the `Return` or `Exit` instruction.
+///
 #[derive(Debug, Clone)]
 pub struct Statement {
     /// The statement location in the source code.
@@ -41,6 +51,8 @@ pub struct Statement {
     pub is_contract_entry: bool,
     /// The function attibutes, e.g. the unit test ones.
     pub attributes: Vec<Attribute>,
+    /// Whether the function body contains `return` statements.
+    pub is_returning: bool,
 }
 
 impl Statement {
@@ -59,6 +71,7 @@ impl Statement {
         is_main: bool,
         is_contract_entry: bool,
         attributes: Vec<Attribute>,
+        is_returning: bool,
     ) -> Self {
         let input_arguments = bindings
             .into_iter()
@@ -81,6 +94,7 @@ impl Statement {
             is_contract_entry,
             is_main,
             attributes,
+            is_returning,
         }
     }
 }
@@ -88,6 +102,7 @@ impl Statement {
 impl IBytecodeWritable for Statement {
     fn write_all(self, state: Rc<RefCell<State>>) {
         let output_size = self.output_type.size();
+        let output_type: BuildType = self.output_type.clone().into();
 
         if self.is_main || self.is_contract_entry {
             state.borrow_mut().start_entry_function(
@@ -107,6 +122,17 @@ impl IBytecodeWritable for Statement {
                 self.attributes.contains(&Attribute::Ignore),
             );
         } else {
+            if !self
+                .attributes
+                .contains(&Attribute::Allow(Lint::UnusedFunctions))
+            {
+                state.borrow_mut().lint_function(
+                    self.location,
+                    self.type_id,
+                    self.identifier.clone(),
+                );
+            }
+
             state
                 .borrow_mut()
                 .start_function(self.location, self.type_id, self.identifier);
@@ -123,8 +149,54 @@ impl IBytecodeWritable for Statement {
             }
         }
 
+        let result_address = if self.is_returning {
+            let result_address = state.borrow_mut().define_variable(None, output_size);
+            if output_size > 0 {
+                for scalar_type in output_type.into_flat_scalar_types().into_iter() {
+                    state.borrow_mut().push_instruction(
+                        Instruction::Push(zinc_build::Push::new(BigInt::zero(), scalar_type)),
+                        Some(self.location),
+                    );
+                }
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_build::Store::new(result_address, output_size)),
+                    Some(self.location),
+                );
+            }
+
+            let running_address = state
+                .borrow_mut()
+                .define_variable(None, Type::boolean().size());
+            BooleanConstant::new(true).write_all(state.clone());
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_build::Store::new(
+                    running_address,
+                    Type::boolean().size(),
+                )),
+                Some(self.location),
+            );
+
+            state.borrow_mut().set_function_return(Return::new(
+                running_address,
+                result_address,
+                output_size,
+            ));
+            Some(result_address)
+        } else {
+            None
+        };
+
         self.body.write_all(state.clone());
 
+        if let Some(result_address) = result_address {
+            if output_size > 0 {
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_build::Load::new(result_address, output_size)),
+                    Some(self.location),
+                );
+            }
+        }
+
         if self.is_main || self.is_contract_entry || self.attributes.contains(&Attribute::Test) {
             state.borrow_mut().push_instruction(
                 Instruction::Exit(zinc_build::Exit::new(output_size)),
@@ -42,6 +42,8 @@ pub struct Statement {
     pub while_condition: Option<GeneratorExpression>,
     /// The loop body.
     pub body: BlockExpression,
+    /// Whether the loop body contains `break` or `continue` statements.
+    pub is_interruptible: bool,
 }
 
 impl Statement {
@@ -59,6 +61,7 @@ impl Statement {
         index_variable_bitlength: usize,
         while_condition: Option<GeneratorExpression>,
         body: BlockExpression,
+        is_interruptible: bool,
     ) -> Self {
         Self {
             location,
@@ -70,6 +73,7 @@ impl Statement {
             index_variable_bitlength,
             while_condition,
             body,
+            is_interruptible,
         }
     }
 }
@@ -93,7 +97,7 @@ impl IBytecodeWritable for Statement {
             Some(self.location),
         );
 
-        let while_allowed_address = if self.while_condition.is_some() {
+        let while_allowed_address = if self.while_condition.is_some() || self.is_interruptible {
             let while_allowed = BooleanConstant::new(true);
             let while_allowed_address = state
                 .borrow_mut()
@@ -108,32 +112,43 @@ impl IBytecodeWritable for Statement {
             None
         };
 
+        let continue_address = match while_allowed_address {
+            Some(while_allowed_address) if self.is_interruptible => {
+                let address = state
+                    .borrow_mut()
+                    .define_variable(None, Type::boolean().size());
+                state.borrow_mut().push_loop(while_allowed_address, address);
+                Some(address)
+            }
+            _ => None,
+        };
+
         state.borrow_mut().push_instruction(
             Instruction::LoopBegin(zinc_build::LoopBegin::new(self.iterations_count)),
             Some(self.location),
         );
 
-        if let (Some(while_condition), Some(while_allowed_address)) =
-            (self.while_condition, while_allowed_address)
-        {
-            while_condition.write_all(state.clone());
-            state
-                .borrow_mut()
-                .push_instruction(Instruction::Not(zinc_build::Not), Some(self.location));
-            state
-                .borrow_mut()
-                .push_instruction(Instruction::If(zinc_build::If), Some(self.location));
-            BooleanConstant::new(false).write_all(state.clone());
-            state.borrow_mut().push_instruction(
-                Instruction::Store(zinc_build::Store::new(
-                    while_allowed_address,
-                    Type::boolean().size(),
-                )),
-                Some(self.location),
-            );
-            state
-                .borrow_mut()
-                .push_instruction(Instruction::EndIf(zinc_build::EndIf), Some(self.location));
+        if let Some(while_allowed_address) = while_allowed_address {
+            if let Some(while_condition) = self.while_condition {
+                while_condition.write_all(state.clone());
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::Not(zinc_build::Not), Some(self.location));
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::If(zinc_build::If), Some(self.location));
+                BooleanConstant::new(false).write_all(state.clone());
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_build::Store::new(
+                        while_allowed_address,
+                        Type::boolean().size(),
+                    )),
+                    Some(self.location),
+                );
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::EndIf(zinc_build::EndIf), Some(self.location));
+            }
 
             state.borrow_mut().push_instruction(
                 Instruction::Load(zinc_build::Load::new(
@@ -145,6 +160,16 @@ impl IBytecodeWritable for Statement {
             state
                 .borrow_mut()
                 .push_instruction(Instruction::If(zinc_build::If), Some(self.location));
+            if let Some(continue_address) = continue_address {
+                BooleanConstant::new(true).write_all(state.clone());
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_build::Store::new(
+                        continue_address,
+                        Type::boolean().size(),
+                    )),
+                    Some(self.location),
+                );
+            }
             self.body.write_all(state.clone());
             state
                 .borrow_mut()
@@ -224,5 +249,9 @@ impl IBytecodeWritable for Statement {
             Instruction::LoopEnd(zinc_build::LoopEnd),
             Some(self.location),
         );
+
+        if continue_address.is_some() {
+            state.borrow_mut().pop_loop();
+        }
     }
 }
@@ -0,0 +1,185 @@
+//!
+//! The generator `loop` and `while` statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_build::Instruction;
+use zinc_lexical::Location;
+
+use crate::generator::expression::operand::block::Expression as BlockExpression;
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
+use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
+use crate::generator::state::State;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The Zinc VM bounded `loop` or `while` statement.
+///
+/// The loop is unrolled `iterations_count` times, and each iteration is executed only if the
+/// loop has not been stopped by the `while` condition or a `break` statement. If the loop is
+/// still running after the last iteration, the bound is exceeded and the execution fails.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    /// The statement location in the source code.
+    pub location: Location,
+    /// The maximal number of loop iterations, specified with the `#[bound(N)]` attribute.
+    pub iterations_count: usize,
+    /// The `while` condition, which is `None` for the `loop` form.
+    pub condition: Option<GeneratorExpression>,
+    /// The loop body.
+    pub body: BlockExpression,
+    /// Whether the loop body contains `break` or `continue` statements.
+    pub is_interruptible: bool,
+}
+
+impl Statement {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        iterations_count: usize,
+        condition: Option<GeneratorExpression>,
+        body: BlockExpression,
+        is_interruptible: bool,
+    ) -> Self {
+        Self {
+            location,
+            iterations_count,
+            condition,
+            body,
+            is_interruptible,
+        }
+    }
+}
+
+impl IBytecodeWritable for Statement {
+    fn write_all(self, state: Rc<RefCell<State>>) {
+        let running_address = state
+            .borrow_mut()
+            .define_variable(None, Type::boolean().size());
+        BooleanConstant::new(true).write_all(state.clone());
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_build::Store::new(
+                running_address,
+                Type::boolean().size(),
+            )),
+            Some(self.location),
+        );
+
+        let continue_address = if self.is_interruptible {
+            let address = state
+                .borrow_mut()
+                .define_variable(None, Type::boolean().size());
+            state.borrow_mut().push_loop(running_address, address);
+            Some(address)
+        } else {
+            None
+        };
+
+        if self.iterations_count > 0 {
+            state.borrow_mut().push_instruction(
+                Instruction::LoopBegin(zinc_build::LoopBegin::new(self.iterations_count)),
+                Some(self.location),
+            );
+
+            if let Some(condition) = self.condition.clone() {
+                condition.write_all(state.clone());
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::Not(zinc_build::Not), Some(self.location));
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::If(zinc_build::If), Some(self.location));
+                BooleanConstant::new(false).write_all(state.clone());
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_build::Store::new(
+                        running_address,
+                        Type::boolean().size(),
+                    )),
+                    Some(self.location),
+                );
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::EndIf(zinc_build::EndIf), Some(self.location));
+            }
+
+            state.borrow_mut().push_instruction(
+                Instruction::Load(zinc_build::Load::new(
+                    running_address,
+                    Type::boolean().size(),
+                )),
+                Some(self.location),
+            );
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::If(zinc_build::If), Some(self.location));
+            if let Some(continue_address) = continue_address {
+                BooleanConstant::new(true).write_all(state.clone());
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_build::Store::new(
+                        continue_address,
+                        Type::boolean().size(),
+                    )),
+                    Some(self.location),
+                );
+            }
+            self.body.write_all(state.clone());
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::EndIf(zinc_build::EndIf), Some(self.location));
+
+            state.borrow_mut().push_instruction(
+                Instruction::LoopEnd(zinc_build::LoopEnd),
+                Some(self.location),
+            );
+        }
+
+        if continue_address.is_some() {
+            state.borrow_mut().pop_loop();
+        }
+
+        let message = Some(format!(
+            "the loop bound of {} iterations is exceeded",
+            self.iterations_count
+        ));
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_build::Load::new(
+                running_address,
+                Type::boolean().size(),
+            )),
+            Some(self.location),
+        );
+        match self.condition {
+            Some(condition) => {
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::If(zinc_build::If), Some(self.location));
+                condition.write_all(state.clone());
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::Not(zinc_build::Not), Some(self.location));
+                state.borrow_mut().push_instruction(
+                    Instruction::Require(zinc_build::Require::new(message)),
+                    Some(self.location),
+                );
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::EndIf(zinc_build::EndIf), Some(self.location));
+            }
+            None => {
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::Not(zinc_build::Not), Some(self.location));
+                state.borrow_mut().push_instruction(
+                    Instruction::Require(zinc_build::Require::new(message)),
+                    Some(self.location),
+                );
+            }
+        }
+    }
+}
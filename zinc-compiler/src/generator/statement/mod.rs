@@ -2,10 +2,14 @@
 //! The generator statement.
 //!
 
+pub mod r#break;
 pub mod contract;
+pub mod r#continue;
 pub mod r#fn;
 pub mod r#for;
 pub mod r#let;
+pub mod r#loop;
+pub mod r#return;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -15,9 +19,13 @@ use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
 
 use self::contract::Statement as ContractStatement;
+use self::r#break::Statement as BreakStatement;
+use self::r#continue::Statement as ContinueStatement;
 use self::r#fn::Statement as FnStatement;
 use self::r#for::Statement as ForStatement;
 use self::r#let::Statement as LetStatement;
+use self::r#loop::Statement as LoopStatement;
+use self::r#return::Statement as ReturnStatement;
 
 ///
 /// Statements translated to the target Zinc VM bytecode.
@@ -32,6 +40,14 @@ pub enum Statement {
     Contract(ContractStatement),
     /// The `for` statement.
     For(ForStatement),
+    /// The bounded `loop` or `while` statement.
+    Loop(LoopStatement),
+    /// The `break` statement.
+    Break(BreakStatement),
+    /// The `continue` statement.
+    Continue(ContinueStatement),
+    /// The `return` statement.
+    Return(ReturnStatement),
     /// The expression statement, which is actually a large class of expression-like statements.
     Expression(Expression),
 }
@@ -43,6 +59,10 @@ impl IBytecodeWritable for Statement {
             Self::Let(inner) => inner.write_all(bytecode),
             Self::Contract(inner) => inner.write_all(bytecode),
             Self::For(inner) => inner.write_all(bytecode),
+            Self::Loop(inner) => inner.write_all(bytecode),
+            Self::Break(inner) => inner.write_all(bytecode),
+            Self::Continue(inner) => inner.write_all(bytecode),
+            Self::Return(inner) => inner.write_all(bytecode),
             Self::Expression(inner) => inner.write_all(bytecode),
         }
     }
@@ -0,0 +1,83 @@
+//!
+//! The generator `return` statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_build::Instruction;
+use zinc_lexical::Location;
+
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
+use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
+use crate::generator::state::State;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The Zinc VM `return` statement.
+///
+/// Since circuits cannot jump, the result is stored to the function result variable, and
+/// the flags of the function and all the enclosing loops are cleared, so the rest of the
+/// function body is not executed.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    /// The statement location in the source code.
+    pub location: Location,
+    /// The returned expression, which is `None` for functions returning `()`.
+    pub expression: Option<GeneratorExpression>,
+}
+
+impl Statement {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, expression: Option<GeneratorExpression>) -> Self {
+        Self {
+            location,
+            expression,
+        }
+    }
+}
+
+impl IBytecodeWritable for Statement {
+    fn write_all(self, state: Rc<RefCell<State>>) {
+        let (running_address, result_address, result_size) = state
+            .borrow()
+            .function_return()
+            .map(|r#return| {
+                (
+                    r#return.running_address,
+                    r#return.result_address,
+                    r#return.result_size,
+                )
+            })
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        if let Some(expression) = self.expression {
+            expression.write_all(state.clone());
+        }
+        if result_size > 0 {
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_build::Store::new(result_address, result_size)),
+                Some(self.location),
+            );
+        }
+
+        let mut addresses = vec![running_address];
+        for r#loop in state.borrow().loops().iter() {
+            addresses.push(r#loop.break_address);
+            addresses.push(r#loop.continue_address);
+        }
+        for address in addresses.into_iter() {
+            BooleanConstant::new(false).write_all(state.clone());
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_build::Store::new(address, Type::boolean().size())),
+                Some(self.location),
+            );
+        }
+
+        state.borrow_mut().interrupt_function();
+    }
+}
@@ -10,6 +10,7 @@ use zinc_build::IntegerType;
 use zinc_build::ScalarType;
 use zinc_build::Type as BuildType;
 
+use crate::semantic::element::r#type::enumeration::payload::Payload as SemanticPayload;
 use crate::semantic::element::r#type::Type as SemanticType;
 use crate::semantic::scope::intrinsic::IntrinsicTypeId;
 
@@ -47,6 +48,14 @@ pub enum Type {
         /// The enumeration variant list.
         variants: Vec<(String, BigInt)>,
     },
+    /// The IR data-carrying enumeration type, which is the payload padded to the largest
+    /// variant size followed by the discriminant.
+    DataEnumeration {
+        /// The enumeration discriminant bitlength.
+        bitlength: usize,
+        /// The enumeration variant list, where each variant payload is a unit, tuple, or structure.
+        variants: Vec<(String, Self)>,
+    },
     /// The IR array type.
     Array {
         /// The array element type.
@@ -76,6 +85,11 @@ pub enum Type {
         /// The value key type.
         value_type: Box<Self>,
     },
+    /// The IR `Option` type, which is the payload followed by a boolean tag.
+    Option {
+        /// The payload type.
+        r#type: Box<Self>,
+    },
 }
 
 impl Type {
@@ -135,6 +149,16 @@ impl Type {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn data_enumeration(bitlength: usize, variants: Vec<(String, Self)>) -> Self {
+        Self::DataEnumeration {
+            bitlength,
+            variants,
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -176,6 +200,15 @@ impl Type {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn option(r#type: Self) -> Self {
+        Self::Option {
+            r#type: Box::new(r#type),
+        }
+    }
+
     ///
     /// The type size in the Zinc VM data stack.
     ///
@@ -187,11 +220,20 @@ impl Type {
             Self::IntegerSigned { .. } => 1,
             Self::Field => 1,
             Self::Enumeration { .. } => 1,
+            Self::DataEnumeration { variants, .. } => {
+                variants
+                    .iter()
+                    .map(|(_name, r#type)| r#type.size())
+                    .max()
+                    .unwrap_or_default()
+                    + 1
+            }
             Self::Array { r#type, size } => r#type.size() * size,
             Self::Tuple { types } => types.iter().map(|r#type| r#type.size()).sum(),
             Self::Structure { fields } => fields.iter().map(|(_name, r#type)| r#type.size()).sum(),
             Self::Contract { fields } => fields.iter().map(|field| field.r#type.size()).sum(),
             Self::Map { .. } => 0,
+            Self::Option { r#type } => r#type.size() + 1,
         }
     }
 
@@ -251,6 +293,29 @@ impl Type {
                     _ => None,
                 }
             }
+            SemanticType::Enumeration(inner) if inner.is_data_carrying() => {
+                let mut variants = Vec::with_capacity(inner.names.len());
+                for (name, payload) in inner.names.iter().zip(inner.payloads.iter()) {
+                    let r#type = match payload {
+                        Some(SemanticPayload::Tuple(types)) => {
+                            Self::tuple(types.iter().filter_map(Self::try_from_semantic).collect())
+                        }
+                        Some(SemanticPayload::Structure(fields)) => Self::structure(
+                            fields
+                                .iter()
+                                .filter_map(|(name, r#type)| {
+                                    Self::try_from_semantic(r#type)
+                                        .map(|r#type| (name.to_owned(), r#type))
+                                })
+                                .collect(),
+                        ),
+                        None => Self::unit(),
+                    };
+                    variants.push((name.to_owned(), r#type));
+                }
+
+                Some(Self::data_enumeration(inner.bitlength, variants))
+            }
             SemanticType::Enumeration(inner) => Some(Self::enumeration(
                 inner.bitlength,
                 inner
@@ -271,6 +336,11 @@ impl Type {
                     _ => None,
                 }
             }
+            SemanticType::Option(inner) => inner
+                .inner
+                .as_ref()
+                .and_then(|r#type| Self::try_from_semantic(r#type.as_ref()))
+                .map(Self::option),
             SemanticType::String(_) => None,
             SemanticType::Range(_) => None,
             SemanticType::RangeInclusive(_) => None,
@@ -304,6 +374,16 @@ impl Into<BuildType> for Type {
                 bitlength,
                 variants,
             },
+            Self::DataEnumeration {
+                bitlength,
+                variants,
+            } => BuildType::DataEnumeration {
+                bitlength,
+                variants: variants
+                    .into_iter()
+                    .map(|(name, r#type)| (name, r#type.into()))
+                    .collect(),
+            },
             Self::Array { r#type, size } => {
                 let element_type: BuildType = (*r#type).into();
                 BuildType::Array(Box::new(element_type), size)
@@ -331,6 +411,10 @@ impl Into<BuildType> for Type {
                     value_type: Box::new(value_type),
                 }
             }
+            Self::Option { r#type } => {
+                let r#type: BuildType = (*r#type).into();
+                BuildType::Option(Box::new(r#type))
+            }
         }
     }
 }
@@ -4,14 +4,24 @@
 
 pub(crate) mod error;
 pub(crate) mod generator;
+pub(crate) mod lint;
 pub(crate) mod semantic;
 pub(crate) mod source;
 
+pub use self::error::diagnostic::Diagnostic;
+pub use self::error::diagnostic::Severity;
+pub use self::error::registry::Entry as ErrorCode;
 pub use self::error::Error;
 pub use self::generator::module::Module;
 pub use self::generator::state::State;
 pub use self::generator::IBytecodeWritable;
+pub use self::lint::index::INDEX as LINT_INDEX;
+pub use self::lint::warning::Warning;
+pub use self::lint::Lint;
 pub use self::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+pub use self::semantic::scope::intrinsic::IntrinsicScope;
+pub use self::semantic::scope::reference::Reference;
+pub use self::semantic::scope::reference::INDEX as REFERENCE_INDEX;
 pub use self::semantic::scope::Scope;
 pub use self::source::directory::Directory as SourceDirectory;
 pub use self::source::error::Error as SourceError;
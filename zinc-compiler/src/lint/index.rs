@@ -0,0 +1,181 @@
+//!
+//! The compiler lint index.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::RwLock;
+
+use lazy_static::lazy_static;
+
+use crate::lint::warning::Warning;
+
+///
+/// The lint index, where the warnings and the item usage data are recorded during the compilation.
+///
+/// The items are identified with their unique IDs, so the index can be shared by several
+/// compilations running at the same time.
+///
+pub struct Index {
+    /// The warnings with the file unique ID as the key.
+    pub warnings: RwLock<HashMap<usize, Vec<Warning>>>,
+    /// The variable item IDs, which have been referenced at least once.
+    pub used: RwLock<HashSet<usize>>,
+    /// The mutable variable item IDs, which have been mutated at least once.
+    pub mutated: RwLock<HashSet<usize>>,
+    /// The contract storage fields written at least once, identified by the contract type ID
+    /// and the field name.
+    pub written: RwLock<HashSet<(usize, String)>>,
+}
+
+impl Default for Index {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+lazy_static! {
+    pub static ref INDEX: Index = Index::new();
+}
+
+impl Index {
+    /// The file hashmap default capacity.
+    const INITIAL_CAPACITY: usize = 64;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self {
+            warnings: RwLock::new(HashMap::with_capacity(Self::INITIAL_CAPACITY)),
+            used: RwLock::new(HashSet::new()),
+            mutated: RwLock::new(HashSet::new()),
+            written: RwLock::new(HashSet::new()),
+        }
+    }
+
+    ///
+    /// Records the `warning`.
+    ///
+    /// Generic functions and closures are analyzed once per call, so the same warning may be
+    /// reported several times, but only the first one is recorded.
+    ///
+    pub fn push(&self, warning: Warning) {
+        let mut warnings = self
+            .warnings
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let file = warnings.entry(warning.location().file).or_default();
+
+        let is_duplicate = file.iter().any(|recorded| {
+            recorded.lint() == warning.lint()
+                && recorded.location().line == warning.location().line
+                && recorded.location().column == warning.location().column
+        });
+        if !is_duplicate {
+            file.push(warning);
+        }
+    }
+
+    ///
+    /// Returns the warnings recorded in the `file`.
+    ///
+    pub fn get(&self, file: usize) -> Vec<Warning> {
+        self.warnings
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .get(&file)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Returns all the recorded warnings sorted by their files and locations.
+    ///
+    pub fn all(&self) -> Vec<Warning> {
+        let mut warnings: Vec<Warning> = self
+            .warnings
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .values()
+            .flatten()
+            .cloned()
+            .collect();
+
+        warnings.sort_by_key(|warning| {
+            let location = warning.location();
+            (location.file, location.line, location.column)
+        });
+        warnings
+    }
+
+    ///
+    /// Removes the warnings recorded in the `file`, which is useful when the file is reanalyzed.
+    ///
+    pub fn remove(&self, file: usize) {
+        self.warnings
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .remove(&file);
+    }
+
+    ///
+    /// Marks the variable with `item_id` as referenced.
+    ///
+    pub fn mark_used(&self, item_id: usize) {
+        self.used
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .insert(item_id);
+    }
+
+    ///
+    /// Checks whether the variable with `item_id` has been referenced.
+    ///
+    pub fn is_used(&self, item_id: usize) -> bool {
+        self.used
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .contains(&item_id)
+    }
+
+    ///
+    /// Marks the variable with `item_id` as mutated.
+    ///
+    pub fn mark_mutated(&self, item_id: usize) {
+        self.mutated
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .insert(item_id);
+    }
+
+    ///
+    /// Checks whether the variable with `item_id` has been mutated.
+    ///
+    pub fn is_mutated(&self, item_id: usize) -> bool {
+        self.mutated
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .contains(&item_id)
+    }
+
+    ///
+    /// Marks the storage field `name` of the contract with `type_id` as written.
+    ///
+    pub fn mark_written(&self, type_id: usize, name: String) {
+        self.written
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .insert((type_id, name));
+    }
+
+    ///
+    /// Checks whether the storage field `name` of the contract with `type_id` has been written.
+    ///
+    pub fn is_written(&self, type_id: usize, name: &str) -> bool {
+        self.written
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .contains(&(type_id, name.to_owned()))
+    }
+}
@@ -0,0 +1,68 @@
+//!
+//! The compiler lints.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod index;
+pub mod warning;
+
+use std::convert::TryFrom;
+use std::fmt;
+
+///
+/// The lint, which is a check reporting suspicious code as a warning without failing the
+/// compilation.
+///
+/// Shadowed bindings are not linted, since redeclaring an item in a nested scope is an error.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// A variable, including a function argument, which is never referenced.
+    UnusedVariables,
+    /// A function, which is unreachable from the application entries and unit tests.
+    UnusedFunctions,
+    /// A mutable variable, which is never mutated.
+    UnusedMut,
+    /// The `field` arithmetic, which silently wraps modulo the field prime.
+    FieldArithmetic,
+    /// A contract storage field, which is never written by the contract methods.
+    UnwrittenStorageFields,
+}
+
+impl Lint {
+    ///
+    /// The lint name, which is used in the `#[allow(...)]` attributes.
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UnusedVariables => "unused_variables",
+            Self::UnusedFunctions => "unused_functions",
+            Self::UnusedMut => "unused_mut",
+            Self::FieldArithmetic => "field_arithmetic",
+            Self::UnwrittenStorageFields => "unwritten_storage_fields",
+        }
+    }
+}
+
+impl TryFrom<&str> for Lint {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "unused_variables" => Self::UnusedVariables,
+            "unused_functions" => Self::UnusedFunctions,
+            "unused_mut" => Self::UnusedMut,
+            "field_arithmetic" => Self::FieldArithmetic,
+            "unwritten_storage_fields" => Self::UnwrittenStorageFields,
+            another => return Err(another.to_owned()),
+        })
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
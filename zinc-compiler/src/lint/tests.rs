@@ -0,0 +1,307 @@
+//!
+//! The compiler lint tests.
+//!
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use zinc_lexical::Location;
+use zinc_lexical::FILE_INDEX;
+use zinc_manifest::Manifest;
+use zinc_manifest::ProjectType;
+
+use crate::generator::state::State;
+use crate::lint::index::INDEX;
+use crate::lint::warning::Warning;
+use crate::source::Source;
+
+///
+/// Compiles the `code` into an application and returns the warnings reported in its file.
+///
+/// The `name` must be unique among the tests, as it is used to find the file in the index.
+///
+fn lint(name: &str, code: &str, project_type: ProjectType) -> Vec<Warning> {
+    let path = PathBuf::from(format!("lint_{}.zn", name));
+
+    let source =
+        Source::test(code, path.clone(), HashMap::new()).expect(zinc_const::panic::TEST_DATA_VALID);
+    let state = source
+        .compile(Manifest::new(name, project_type))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    State::unwrap_rc(state).into_application(true);
+
+    let file = FILE_INDEX
+        .inner
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .iter()
+        .find(|(_id, data)| data.path == path)
+        .map(|(id, _data)| *id)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let mut warnings = INDEX.get(file);
+    warnings.sort_by_key(|warning| {
+        let location = warning.location();
+        (location.line, location.column)
+    });
+    warnings
+}
+
+#[test]
+fn ok_clean() {
+    let input = r#"
+fn double(value: u8) -> u8 {
+    value * 2
+}
+
+fn main(mut value: u8) -> u8 {
+    value += 1;
+    double(value)
+}
+"#;
+
+    let result = lint("ok_clean", input, ProjectType::Circuit);
+
+    assert_eq!(result, vec![]);
+}
+
+#[test]
+fn ok_underscore() {
+    let input = r#"
+fn main(_witness: u8) {
+    let _unused = 42;
+}
+"#;
+
+    let result = lint("ok_underscore", input, ProjectType::Circuit);
+
+    assert_eq!(result, vec![]);
+}
+
+#[test]
+fn ok_allow() {
+    let input = r#"
+#[allow(unused_functions, unused_variables, unused_mut, field_arithmetic)]
+fn helper(value: field) -> field {
+    let mut unused = 42;
+    value + value
+}
+
+fn main() {}
+"#;
+
+    let result = lint("ok_allow", input, ProjectType::Circuit);
+
+    assert_eq!(result, vec![]);
+}
+
+#[test]
+fn ok_storage_field_written() {
+    let input = r#"
+contract Test {
+    pub value: u8;
+
+    pub fn new(initial: u8) -> Self {
+        Self { value: initial }
+    }
+
+    pub fn set(mut self, updated: u8) {
+        self.value = updated;
+    }
+}
+"#;
+
+    let result = lint("ok_storage_field_written", input, ProjectType::Contract);
+
+    assert_eq!(result, vec![]);
+}
+
+#[test]
+fn ok_storage_field_mutated_by_method() {
+    let input = r#"
+use std::collections::MTreeMap;
+
+contract Test {
+    pub values: MTreeMap<u8, u8>;
+
+    pub fn new() -> Self {
+        Self { values: MTreeMap }
+    }
+
+    pub fn set(mut self, key: u8, value: u8) {
+        self.values.insert(key, value);
+    }
+}
+"#;
+
+    let result = lint(
+        "ok_storage_field_mutated_by_method",
+        input,
+        ProjectType::Contract,
+    );
+
+    assert_eq!(result, vec![]);
+}
+
+#[test]
+fn warning_unused_variable() {
+    let input = r#"
+fn main(witness: u8) -> u8 {
+    let value = 42;
+    witness
+}
+"#;
+
+    let result = lint("warning_unused_variable", input, ProjectType::Circuit);
+
+    assert_eq!(
+        result,
+        vec![Warning::UnusedVariable {
+            location: Location::test(3, 9),
+            name: "value".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn warning_unused_argument() {
+    let input = r#"
+fn main(witness: u8) {}
+"#;
+
+    let result = lint("warning_unused_argument", input, ProjectType::Circuit);
+
+    assert_eq!(
+        result,
+        vec![Warning::UnusedVariable {
+            location: Location::test(2, 9),
+            name: "witness".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn warning_unused_mut() {
+    let input = r#"
+fn main() -> u8 {
+    let mut value = 42;
+    value
+}
+"#;
+
+    let result = lint("warning_unused_mut", input, ProjectType::Circuit);
+
+    assert_eq!(
+        result,
+        vec![Warning::UnusedMut {
+            location: Location::test(3, 13),
+            name: "value".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn warning_unused_function() {
+    let input = r#"
+fn unused() -> u8 {
+    42
+}
+
+fn main() {}
+"#;
+
+    let result = lint("warning_unused_function", input, ProjectType::Circuit);
+
+    assert_eq!(
+        result,
+        vec![Warning::UnusedFunction {
+            location: Location::test(2, 1),
+            name: "unused".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn warning_field_arithmetic() {
+    let input = r#"
+fn main(a: field, b: field) -> field {
+    let mut c = a * b;
+    c += a;
+    c
+}
+"#;
+
+    let result = lint("warning_field_arithmetic", input, ProjectType::Circuit);
+
+    assert_eq!(
+        result,
+        vec![
+            Warning::FieldArithmetic {
+                location: Location::test(3, 19),
+                operator: "*",
+            },
+            Warning::FieldArithmetic {
+                location: Location::test(4, 5),
+                operator: "+",
+            },
+        ]
+    );
+}
+
+#[test]
+fn warning_unwritten_storage_field() {
+    let input = r#"
+contract Test {
+    pub value: u8;
+
+    pub fn new(initial: u8) -> Self {
+        Self { value: initial }
+    }
+
+    pub fn get(self) -> u8 {
+        self.value
+    }
+}
+"#;
+
+    let result = lint(
+        "warning_unwritten_storage_field",
+        input,
+        ProjectType::Contract,
+    );
+
+    assert_eq!(
+        result,
+        vec![Warning::UnwrittenStorageField {
+            location: Location::test(3, 9),
+            name: "value".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn ok_json() {
+    let input = r#"
+fn main() -> u8 {
+    let mut value = 42;
+    value
+}
+"#;
+
+    let result = lint("ok_json", input, ProjectType::Circuit);
+
+    let json = result
+        .into_iter()
+        .next()
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .diagnostic()
+        .to_json();
+
+    assert_eq!(json["severity"], "warning");
+    assert_eq!(json["code"], "unused_mut");
+    assert_eq!(json["message"], "variable `value` does not need to be mutable");
+    assert_eq!(json["spans"][0]["file"], "lint_ok_json.zn");
+    assert_eq!(json["spans"][0]["line"], 3);
+    assert_eq!(json["spans"][0]["column"], 13);
+    assert_eq!(json["spans"][0]["is_primary"], true);
+}
@@ -0,0 +1,142 @@
+//!
+//! The compiler lint warning.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::diagnostic::Diagnostic;
+use crate::lint::Lint;
+
+///
+/// The compiler lint warning, which does not prevent the application from being compiled.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    /// The variable is never referenced.
+    UnusedVariable {
+        /// The variable declaration location.
+        location: Location,
+        /// The variable name.
+        name: String,
+    },
+    /// The function is unreachable from the application entries and unit tests.
+    UnusedFunction {
+        /// The function declaration location.
+        location: Location,
+        /// The function name.
+        name: String,
+    },
+    /// The variable is declared as mutable, but is never mutated.
+    UnusedMut {
+        /// The variable declaration location.
+        location: Location,
+        /// The variable name.
+        name: String,
+    },
+    /// The `field` arithmetic operation, which silently wraps modulo the field prime.
+    FieldArithmetic {
+        /// The operator location.
+        location: Location,
+        /// The operator symbol.
+        operator: &'static str,
+    },
+    /// The contract storage field is never written by the contract methods.
+    UnwrittenStorageField {
+        /// The field declaration location.
+        location: Location,
+        /// The field name.
+        name: String,
+    },
+}
+
+impl Warning {
+    ///
+    /// Returns the lint which has produced the warning.
+    ///
+    pub fn lint(&self) -> Lint {
+        match self {
+            Self::UnusedVariable { .. } => Lint::UnusedVariables,
+            Self::UnusedFunction { .. } => Lint::UnusedFunctions,
+            Self::UnusedMut { .. } => Lint::UnusedMut,
+            Self::FieldArithmetic { .. } => Lint::FieldArithmetic,
+            Self::UnwrittenStorageField { .. } => Lint::UnwrittenStorageFields,
+        }
+    }
+
+    ///
+    /// Returns the warning location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::UnusedVariable { location, .. } => *location,
+            Self::UnusedFunction { location, .. } => *location,
+            Self::UnusedMut { location, .. } => *location,
+            Self::FieldArithmetic { location, .. } => *location,
+            Self::UnwrittenStorageField { location, .. } => *location,
+        }
+    }
+
+    ///
+    /// Formats the warning into the user-friendly readable output.
+    ///
+    pub fn format(self) -> String {
+        self.diagnostic().format()
+    }
+
+    ///
+    /// Extracts the warning data for rendering or sending to the editor integrations.
+    ///
+    pub fn diagnostic(self) -> Diagnostic {
+        let lint = self.lint();
+
+        let (message, location, help) = match self {
+            Self::UnusedVariable { location, name } => (
+                format!("unused variable `{}`", name),
+                location,
+                format!(
+                    "if this is intentional, prefix it with an underscore: `_{}`",
+                    name
+                ),
+            ),
+            Self::UnusedFunction { location, name } => (
+                format!("function `{}` is never used", name),
+                location,
+                "the function is unreachable from the application entries and unit tests"
+                    .to_owned(),
+            ),
+            Self::UnusedMut { location, name } => (
+                format!("variable `{}` does not need to be mutable", name),
+                location,
+                "remove the `mut` keyword".to_owned(),
+            ),
+            Self::FieldArithmetic { location, operator } => (
+                format!(
+                    "the `field` operator `{}` silently wraps modulo the field prime",
+                    operator
+                ),
+                location,
+                "use an integer type to have the overflow checked".to_owned(),
+            ),
+            Self::UnwrittenStorageField { location, name } => (
+                format!("storage field `{}` is never written", name),
+                location,
+                "consider a contract constant if the value never changes after the construction"
+                    .to_owned(),
+            ),
+        };
+
+        Diagnostic::with_location(
+            message.as_str(),
+            location,
+            Some(
+                format!(
+                    "{}, or suppress the warning with `#[allow({})]`",
+                    help, lint
+                )
+                .as_str(),
+            ),
+        )
+        .into_warning()
+        .with_code(lint.name())
+    }
+}
@@ -16,4 +16,21 @@ pub enum Error {
         /// The invalid stringified attribute.
         found: String,
     },
+    /// The `#[allow(...)]` attribute has no arguments.
+    AllowExpectedLints {
+        /// The error location data.
+        location: Location,
+    },
+    /// The `#[allow(...)]` attribute argument is not a lint name.
+    AllowExpectedLintName {
+        /// The error location data.
+        location: Location,
+    },
+    /// The lint is unknown. Check the known lint list for more information.
+    LintUnknown {
+        /// The error location data.
+        location: Location,
+        /// The invalid lint name.
+        found: String,
+    },
 }
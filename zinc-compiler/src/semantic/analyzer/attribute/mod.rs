@@ -2,11 +2,18 @@
 //! The semantic attribute.
 //!
 
+#[cfg(test)]
+mod tests;
+
 pub mod error;
 
 use std::convert::TryFrom;
 
 use zinc_syntax::Attribute as SyntaxAttribute;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionTreeNode;
+
+use crate::lint::Lint;
 
 use self::error::Error;
 
@@ -21,29 +28,57 @@ pub enum Attribute {
     ShouldPanic,
     /// The `#[ignore]` attribute.
     Ignore,
+    /// The `#[allow(...)]` attribute, which is converted into one value per lint.
+    Allow(Lint),
 }
 
 impl Attribute {
+    /// The lint suppressing attribute identifier.
+    pub const ALLOW_IDENTIFIER: &'static str = "allow";
+
     ///
-    /// If the attribute is related to unit tests.
+    /// Converts the syntax attribute into the semantic ones.
     ///
-    pub fn is_test(&self) -> bool {
-        match self {
-            Self::Test => true,
-            Self::ShouldPanic => true,
-            Self::Ignore => true,
-        }
-    }
-}
+    /// The `#[allow(...)]` attribute may contain several lints, so a semantic attribute is
+    /// created for each of them.
+    ///
+    pub fn try_from_syntax(value: SyntaxAttribute) -> Result<Vec<Self>, Error> {
+        Ok(match value.identifier.name.as_str() {
+            "test" => vec![Self::Test],
+            "should_panic" => vec![Self::ShouldPanic],
+            "ignore" => vec![Self::Ignore],
+            Self::ALLOW_IDENTIFIER => {
+                if value.arguments.is_empty() {
+                    return Err(Error::AllowExpectedLints {
+                        location: value.location,
+                    });
+                }
 
-impl TryFrom<SyntaxAttribute> for Attribute {
-    type Error = Error;
+                let mut attributes = Vec::with_capacity(value.arguments.len());
+                for argument in value.arguments.into_iter() {
+                    let identifier = match (*argument.value, argument.left, argument.right) {
+                        (
+                            ExpressionTreeNode::Operand(ExpressionOperand::Identifier(identifier)),
+                            None,
+                            None,
+                        ) => identifier,
+                        _ => {
+                            return Err(Error::AllowExpectedLintName {
+                                location: argument.location,
+                            })
+                        }
+                    };
 
-    fn try_from(value: SyntaxAttribute) -> Result<Self, Self::Error> {
-        Ok(match value.identifier.name.as_str() {
-            "test" => Self::Test,
-            "should_panic" => Self::ShouldPanic,
-            "ignore" => Self::Ignore,
+                    let lint = Lint::try_from(identifier.name.as_str()).map_err(|found| {
+                        Error::LintUnknown {
+                            location: identifier.location,
+                            found,
+                        }
+                    })?;
+                    attributes.push(Self::Allow(lint));
+                }
+                attributes
+            }
             _ => {
                 return Err(Error::Unknown {
                     location: value.identifier.location,
@@ -52,4 +87,29 @@ impl TryFrom<SyntaxAttribute> for Attribute {
             }
         })
     }
+
+    ///
+    /// If the attribute is related to unit tests.
+    ///
+    pub fn is_test(&self) -> bool {
+        match self {
+            Self::Test => true,
+            Self::ShouldPanic => true,
+            Self::Ignore => true,
+            Self::Allow(_) => false,
+        }
+    }
+
+    ///
+    /// Returns the lints suppressed with the `#[allow(...)]` attributes.
+    ///
+    pub fn allowed_lints(attributes: &[Self]) -> Vec<Lint> {
+        attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Self::Allow(lint) => Some(*lint),
+                _ => None,
+            })
+            .collect()
+    }
 }
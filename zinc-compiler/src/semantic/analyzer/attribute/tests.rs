@@ -0,0 +1,136 @@
+//!
+//! The semantic attribute tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::analyzer::attribute::error::Error as AttributeError;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_allow_function() {
+    let input = r#"
+#[allow(unused_variables, unused_mut)]
+fn unused(mut value: u8) {}
+
+fn main() {}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_allow_storage_field() {
+    let input = r#"
+contract Test {
+    #[allow(unwritten_storage_fields)]
+    pub value: u8;
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_unknown() {
+    let input = r#"
+#[inline]
+fn inlined() {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::Unknown {
+            location: Location::test(2, 3),
+            found: "inline".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unknown_storage_field() {
+    let input = r#"
+contract Test {
+    #[test]
+    pub value: u8;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::Unknown {
+            location: Location::test(3, 7),
+            found: "test".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_allow_expected_lints() {
+    let input = r#"
+#[allow]
+fn unused() {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::AllowExpectedLints {
+            location: Location::test(2, 1),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_allow_expected_lint_name() {
+    let input = r#"
+#[allow(unused_variables = true)]
+fn unused() {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::AllowExpectedLintName {
+            location: Location::test(2, 26),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_lint_unknown() {
+    let input = r#"
+#[allow(dead_code)]
+fn unused() {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::LintUnknown {
+            location: Location::test(2, 9),
+            found: "dead_code".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -10,12 +10,18 @@ use zinc_syntax::FunctionLocalStatement;
 
 use crate::generator::expression::operand::block::builder::Builder as GeneratorBlockExpressionBuilder;
 use crate::generator::expression::operand::block::Expression as GeneratorBlockExpression;
+use crate::generator::r#type::Type as GeneratorType;
+use crate::generator::statement::r#break::Statement as GeneratorBreakStatement;
+use crate::generator::statement::r#continue::Statement as GeneratorContinueStatement;
 use crate::generator::statement::Statement as GeneratorStatement;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::analyzer::statement::r#const::Analyzer as ConstStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#for::Analyzer as ForStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#let::Analyzer as LetStatementAnalyzer;
+use crate::semantic::analyzer::statement::r#loop::Analyzer as LoopStatementAnalyzer;
+use crate::semantic::analyzer::statement::r#return::Analyzer as ReturnStatementAnalyzer;
+use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::value::unit::Unit as UnitValue;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
@@ -59,6 +65,24 @@ impl Analyzer {
                 FunctionLocalStatement::For(statement) => Some(GeneratorStatement::For(
                     ForStatementAnalyzer::define(scope_stack.top(), statement)?,
                 )),
+                FunctionLocalStatement::Loop(statement) => Some(GeneratorStatement::Loop(
+                    LoopStatementAnalyzer::define(scope_stack.top(), statement)?,
+                )),
+                FunctionLocalStatement::Break(location) => {
+                    LoopStatementAnalyzer::interrupt(scope_stack.top(), location, true)?;
+                    Some(GeneratorStatement::Break(GeneratorBreakStatement::new(
+                        location,
+                    )))
+                }
+                FunctionLocalStatement::Continue(location) => {
+                    LoopStatementAnalyzer::interrupt(scope_stack.top(), location, false)?;
+                    Some(GeneratorStatement::Continue(
+                        GeneratorContinueStatement::new(location),
+                    ))
+                }
+                FunctionLocalStatement::Return(statement) => Some(GeneratorStatement::Return(
+                    ReturnStatementAnalyzer::define(scope_stack.top(), statement, rule)?,
+                )),
                 FunctionLocalStatement::Expression(expression) => {
                     let (_result, expression) =
                         ExpressionAnalyzer::new(scope_stack.top(), rule).analyze(expression)?;
@@ -77,7 +101,30 @@ impl Analyzer {
             Some(expression) => {
                 let (element, expression) =
                     ExpressionAnalyzer::new(scope_stack.top(), rule).analyze(*expression)?;
-                builder.set_expression(expression);
+                let expression_type = match element {
+                    Element::Value(Value::Unit(_)) => {
+                        builder.push_statement(GeneratorStatement::Expression(expression));
+                        None
+                    }
+                    Element::Value(ref value) => {
+                        builder.set_expression(expression);
+                        Some(value.r#type())
+                    }
+                    Element::Constant(ref constant) => {
+                        builder.set_expression(expression);
+                        Some(constant.r#type())
+                    }
+                    _ => {
+                        builder.set_expression(expression);
+                        None
+                    }
+                };
+                if let Some(r#type) = expression_type
+                    .as_ref()
+                    .and_then(GeneratorType::try_from_semantic)
+                {
+                    builder.set_expression_type(r#type);
+                }
                 element
             }
             None => Element::Value(Value::Unit(UnitValue::new(Some(block.location)))),
@@ -11,10 +11,14 @@ use crate::generator::expression::element::Element as GeneratorExpressionElement
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
+use crate::semantic::element::argument_list::ArgumentList;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionError;
 use crate::semantic::element::r#type::function::intrinsic::error::Error as IntrinsicFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_fold::Function as StdArrayFoldFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::Function as StandardLibraryFunction;
+use crate::semantic::element::r#type::function::intrinsic::zksync::Function as ZkSyncLibraryFunction;
 use crate::semantic::element::r#type::function::intrinsic::Function as IntrinsicFunctionType;
 use crate::semantic::element::r#type::function::test::error::Error as TestFunctionError;
 use crate::semantic::element::r#type::function::Function as FunctionType;
@@ -110,10 +114,32 @@ impl Analyzer {
         }
 
         let mut input_size = 0;
+        let mut argument_types = Vec::with_capacity(argument_list.arguments.len());
         for element in argument_list.arguments.iter() {
-            input_size += Type::from_element(element, scope.clone())?.size();
+            let r#type = Type::from_element(element, scope.clone())?;
+            input_size += r#type.size();
+            argument_types.push(r#type);
         }
 
+        let function = match function {
+            FunctionType::Generic(function) => {
+                let function = function.instantiate(
+                    location,
+                    argument_list.location,
+                    argument_types.clone(),
+                )?;
+
+                let mut index = 0;
+                argument_list.arguments.retain(|_| {
+                    index += 1;
+                    !matches!(argument_types[index - 1], Type::Function(_))
+                });
+
+                function
+            }
+            function => function,
+        };
+
         let (element, intermediate) = match function {
             FunctionType::Intrinsic(function) => {
                 if function.requires_exclamation_mark() && !matches!(call_type, CallType::MacroLike)
@@ -174,6 +200,145 @@ impl Analyzer {
                             },
                         )
                     }
+                    IntrinsicFunctionType::Some(function) => {
+                        let return_type = function
+                            .call(function_location.unwrap_or(location), argument_list)
+                            .map_err(|error| {
+                                Error::Element(ElementError::Type(TypeError::Function(error)))
+                            })?;
+
+                        let element = Element::Value(
+                            Value::try_from_type(&return_type, false, None)
+                                .map_err(ElementError::Value)
+                                .map_err(Error::Element)?,
+                        );
+
+                        let intermediate = GeneratorExpressionOperator::call_some();
+
+                        (
+                            element,
+                            GeneratorExpressionElement::Operator {
+                                location: function_location.unwrap_or(location),
+                                operator: intermediate,
+                            },
+                        )
+                    }
+                    IntrinsicFunctionType::StandardLibrary(StandardLibraryFunction::ArrayMap(
+                        function,
+                    )) => {
+                        if let CallType::MacroLike = call_type {
+                            return Err(Error::Element(ElementError::Type(TypeError::Function(
+                                FunctionError::Intrinsic(IntrinsicFunctionError::Unknown {
+                                    location: function_location.unwrap_or(location),
+                                    function: function.identifier.to_owned(),
+                                }),
+                            ))));
+                        }
+
+                        let argument_list =
+                            Self::resolve_function_arguments(argument_list, argument_types);
+                        let (element_type, array_size, callee) = function
+                            .call(function_location.unwrap_or(location), argument_list)
+                            .map_err(|error| {
+                                Error::Element(ElementError::Type(TypeError::Function(error)))
+                            })?;
+
+                        let (return_type, callee) = Self::call_function_argument(
+                            callee,
+                            location,
+                            vec![element_type.clone()],
+                        )?;
+
+                        let element = Element::Value(
+                            Value::try_from_type(
+                                &Type::array(Some(location), return_type, array_size),
+                                false,
+                                None,
+                            )
+                            .map_err(ElementError::Value)
+                            .map_err(Error::Element)?,
+                        );
+
+                        let intermediate = GeneratorExpressionOperator::array_map(
+                            array_size,
+                            element_type.size(),
+                            callee,
+                        );
+
+                        (
+                            element,
+                            GeneratorExpressionElement::Operator {
+                                location: function_location.unwrap_or(location),
+                                operator: intermediate,
+                            },
+                        )
+                    }
+                    IntrinsicFunctionType::StandardLibrary(StandardLibraryFunction::ArrayFold(
+                        function,
+                    )) => {
+                        if let CallType::MacroLike = call_type {
+                            return Err(Error::Element(ElementError::Type(TypeError::Function(
+                                FunctionError::Intrinsic(IntrinsicFunctionError::Unknown {
+                                    location: function_location.unwrap_or(location),
+                                    function: function.identifier.to_owned(),
+                                }),
+                            ))));
+                        }
+
+                        let identifier = function.identifier;
+                        let argument_list =
+                            Self::resolve_function_arguments(argument_list, argument_types);
+                        let (element_type, array_size, accumulator_type, callee) = function
+                            .call(function_location.unwrap_or(location), argument_list)
+                            .map_err(|error| {
+                                Error::Element(ElementError::Type(TypeError::Function(error)))
+                            })?;
+
+                        let (return_type, callee) = Self::call_function_argument(
+                            callee,
+                            location,
+                            vec![accumulator_type.clone(), element_type.clone()],
+                        )?;
+                        if return_type != accumulator_type {
+                            return Err(Error::Element(ElementError::Type(TypeError::Function(
+                                FunctionError::ArgumentType {
+                                    location,
+                                    function: identifier.to_owned(),
+                                    name: "f".to_owned(),
+                                    position: StdArrayFoldFunction::ARGUMENT_INDEX_FUNCTION + 1,
+                                    expected: format!(
+                                        "fn({}, {}) -> {}",
+                                        accumulator_type, element_type, accumulator_type
+                                    ),
+                                    found: format!(
+                                        "fn({}, {}) -> {}",
+                                        accumulator_type, element_type, return_type
+                                    ),
+                                },
+                            ))));
+                        }
+
+                        let element = Element::Value(
+                            Value::try_from_type(&return_type, false, None)
+                                .map_err(ElementError::Value)
+                                .map_err(Error::Element)?,
+                        );
+
+                        let intermediate = GeneratorExpressionOperator::array_fold(
+                            array_size,
+                            element_type.size(),
+                            accumulator_type.size(),
+                            callee,
+                        );
+
+                        (
+                            element,
+                            GeneratorExpressionElement::Operator {
+                                location: function_location.unwrap_or(location),
+                                operator: intermediate,
+                            },
+                        )
+                    }
                     IntrinsicFunctionType::StandardLibrary(function) => {
                         if let CallType::MacroLike = call_type {
                             return Err(Error::Element(ElementError::Type(TypeError::Function(
@@ -212,6 +377,41 @@ impl Analyzer {
                             },
                         )
                     }
+                    IntrinsicFunctionType::ZkSyncLibrary(ZkSyncLibraryFunction::Emit(function)) => {
+                        if let CallType::MacroLike = call_type {
+                            return Err(Error::Element(ElementError::Type(TypeError::Function(
+                                FunctionError::Intrinsic(IntrinsicFunctionError::Unknown {
+                                    location: function_location.unwrap_or(location),
+                                    function: function.identifier.to_owned(),
+                                }),
+                            ))));
+                        }
+
+                        let (return_type, event) = function
+                            .call(function_location.unwrap_or(location), argument_list)
+                            .map_err(|error| {
+                                Error::Element(ElementError::Type(TypeError::Function(error)))
+                            })?;
+
+                        let element = Element::Value(
+                            Value::try_from_type(&return_type, false, None)
+                                .map_err(ElementError::Value)
+                                .map_err(Error::Element)?,
+                        );
+
+                        let intermediate = GeneratorExpressionOperator::call_emit(
+                            event.identifier.clone(),
+                            &Type::Structure(event),
+                        );
+
+                        (
+                            element,
+                            GeneratorExpressionElement::Operator {
+                                location: function_location.unwrap_or(location),
+                                operator: intermediate,
+                            },
+                        )
+                    }
                     IntrinsicFunctionType::ZkSyncLibrary(function) => {
                         if let CallType::MacroLike = call_type {
                             return Err(Error::Element(ElementError::Type(TypeError::Function(
@@ -318,8 +518,160 @@ impl Analyzer {
                     }),
                 ))));
             }
+            FunctionType::Variant(function) => {
+                if let CallType::MacroLike = call_type {
+                    return Err(Error::Element(ElementError::Type(TypeError::Function(
+                        FunctionError::Intrinsic(IntrinsicFunctionError::Unknown {
+                            location,
+                            function: function.identifier,
+                        }),
+                    ))));
+                }
+
+                let payload_size = function.enumeration.payload_size();
+                let bitlength = function.enumeration.bitlength;
+                let discriminant = function.discriminant.to_owned();
+
+                let return_type = function.call(argument_list).map_err(|error| {
+                    Error::Element(ElementError::Type(TypeError::Function(error)))
+                })?;
+
+                let element = Element::Value(
+                    Value::try_from_type(&return_type, false, None)
+                        .map_err(ElementError::Value)
+                        .map_err(Error::Element)?,
+                );
+
+                let intermediate = GeneratorExpressionOperator::call_variant(
+                    input_size,
+                    payload_size,
+                    bitlength,
+                    discriminant,
+                );
+
+                (
+                    element,
+                    GeneratorExpressionElement::Operator {
+                        location: function_location.unwrap_or(location),
+                        operator: intermediate,
+                    },
+                )
+            }
+            FunctionType::Closure(function) => {
+                if let CallType::MacroLike = call_type {
+                    return Err(Error::Element(ElementError::Type(TypeError::Function(
+                        FunctionError::Intrinsic(IntrinsicFunctionError::Unknown {
+                            location,
+                            function: function.identifier,
+                        }),
+                    ))));
+                }
+
+                let location = function.location;
+
+                let (return_type, bindings, body) = function.call(argument_list)?;
+
+                let element = Element::Value(
+                    Value::try_from_type(&return_type, false, None)
+                        .map_err(ElementError::Value)
+                        .map_err(Error::Element)?,
+                );
+
+                let intermediate = GeneratorExpressionOperator::call_closure(bindings, body);
+
+                (
+                    element,
+                    GeneratorExpressionElement::Operator {
+                        location,
+                        operator: intermediate,
+                    },
+                )
+            }
+            FunctionType::Generic(_) => {
+                panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+            }
         };
 
         Ok((element, intermediate))
     }
+
+    ///
+    /// Replaces the arguments, which are paths to functions, with the function types, so they
+    /// can be validated by intrinsic functions accepting function arguments.
+    ///
+    fn resolve_function_arguments(
+        mut argument_list: ArgumentList,
+        argument_types: Vec<Type>,
+    ) -> ArgumentList {
+        for (argument, r#type) in argument_list
+            .arguments
+            .iter_mut()
+            .zip(argument_types.into_iter())
+        {
+            if let Type::Function(_) = r#type {
+                *argument = Element::Type(r#type);
+            }
+        }
+
+        argument_list
+    }
+
+    ///
+    /// Calls the function passed to an intrinsic function with arguments of `argument_types`,
+    /// which are expected on the evaluation stack.
+    ///
+    /// Returns the function result type and the call intermediate representation, which is
+    /// inlined by the intrinsic function.
+    ///
+    fn call_function_argument(
+        function: FunctionType,
+        location: Location,
+        argument_types: Vec<Type>,
+    ) -> Result<(Type, GeneratorExpressionOperator), Error> {
+        let mut input_size = 0;
+        let mut arguments = Vec::with_capacity(argument_types.len());
+        for r#type in argument_types.iter() {
+            input_size += r#type.size();
+            arguments.push(Element::Value(
+                Value::try_from_type(r#type, false, Some(location))
+                    .map_err(ElementError::Value)
+                    .map_err(Error::Element)?,
+            ));
+        }
+        let argument_list = ArgumentList::new(location, arguments);
+
+        match function {
+            FunctionType::Runtime(function) => {
+                let type_id = function.type_id;
+
+                let return_type = function.call(argument_list).map_err(|error| {
+                    Error::Element(ElementError::Type(TypeError::Function(error)))
+                })?;
+
+                Ok((
+                    return_type,
+                    GeneratorExpressionOperator::call(type_id, input_size),
+                ))
+            }
+            FunctionType::Generic(function) => {
+                let function = function.instantiate(location, location, argument_types.clone())?;
+
+                Self::call_function_argument(function, location, argument_types)
+            }
+            FunctionType::Closure(function) => {
+                let (return_type, bindings, body) = function.call(argument_list)?;
+
+                Ok((
+                    return_type,
+                    GeneratorExpressionOperator::call_closure(bindings, body),
+                ))
+            }
+            function => Err(Error::Element(ElementError::Type(TypeError::Function(
+                FunctionError::NonCallable {
+                    location,
+                    name: function.to_string(),
+                },
+            )))),
+        }
+    }
 }
@@ -14,6 +14,7 @@ use zinc_syntax::ConditionalExpression;
 
 use crate::generator::expression::operand::conditional::builder::Builder as GeneratorConditionalExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
 use crate::semantic::analyzer::expression::conditional::error::Error as ConditionalExpressionError;
 use crate::semantic::analyzer::expression::error::Error as ExpressionError;
@@ -112,7 +113,7 @@ impl Analyzer {
         scope_stack.pop();
         builder.set_main_block(main_block);
 
-        let else_type = if let Some(else_block) = conditional.else_block {
+        let (else_result, else_type) = if let Some(else_block) = conditional.else_block {
             scope_stack.push(None);
             let (else_result, else_block) =
                 BlockAnalyzer::analyze(scope_stack.top(), else_block, TranslationRule::Value)?;
@@ -120,25 +121,37 @@ impl Analyzer {
             scope_stack.pop();
             builder.set_else_block(else_block);
 
-            else_type
+            (Some(else_result), else_type)
         } else {
-            Type::unit(None)
+            (None, Type::unit(None))
         };
 
-        if main_type != else_type {
-            return Err(Error::Expression(ExpressionError::Conditional(
-                ConditionalExpressionError::BranchTypesMismatch {
-                    location: main_expression_location,
-                    expected: main_type.to_string(),
-                    found: else_type.to_string(),
-                    reference: else_expression_location,
-                },
-            )));
-        }
-
-        let element = main_result;
+        let (element, result_type) = match else_result {
+            Some(else_result) if main_type.is_none_inferable(&else_type) => {
+                (else_result, else_type)
+            }
+            _ if else_type.is_none_inferable(&main_type) => (main_result, main_type),
+            _ if main_type != else_type => {
+                return Err(Error::Expression(ExpressionError::Conditional(
+                    ConditionalExpressionError::BranchTypesMismatch {
+                        location: main_expression_location,
+                        expected: main_type.to_string(),
+                        found: else_type.to_string(),
+                        reference: else_expression_location,
+                    },
+                )));
+            }
+            _ => (main_result, main_type),
+        };
 
-        let intermediate = GeneratorExpressionOperand::Conditional(builder.finish());
+        let mut intermediate = GeneratorExpressionOperand::Conditional(builder.finish());
+        if let Type::Option(ref inner) = result_type {
+            if inner.is_inferred() {
+                if let Some(r#type) = GeneratorType::try_from_semantic(&result_type) {
+                    intermediate.set_option_type(&r#type);
+                }
+            }
+        }
 
         Ok((element, intermediate))
     }
@@ -18,6 +18,13 @@ pub enum Error {
         /// The strigified invalid element.
         found: String,
     },
+    /// A trait is used as an expression operand, though it is neither a value nor a type.
+    TraitAsOperand {
+        /// The error location data.
+        location: Location,
+        /// The trait identifier.
+        name: String,
+    },
 
     /// The `match` expression error. See the inner element description.
     Match(MatchExpressionError),
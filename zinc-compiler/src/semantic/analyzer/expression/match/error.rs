@@ -9,7 +9,8 @@ use zinc_lexical::Location;
 ///
 #[derive(Debug, PartialEq)]
 pub enum Error {
-    /// Only primitive types can act as scrutinee types (be matched) for now.
+    /// Only primitive, `Option`, and data-carrying enumeration types can act as scrutinee types
+    /// (be matched) for now.
     ScrutineeInvalidType {
         /// The error location data.
         location: Location,
@@ -61,6 +62,56 @@ pub enum Error {
         /// The first branch location, which helps user to find the error.
         reference: Location,
     },
+    /// Only the `Some` variant of the `Option` type and data-carrying enumeration variants can act
+    /// as tuple-like and structure-like branch patterns.
+    BranchPatternExpectedVariant {
+        /// The error location data.
+        location: Location,
+        /// The invalid path, which is actually found.
+        found: String,
+    },
+    /// The tuple-like and structure-like branch patterns must only contain binding or wildcard `_`
+    /// nested patterns.
+    BranchPatternNestedExpectedBinding {
+        /// The error location data.
+        location: Location,
+    },
+    /// The tuple-like branch pattern nested pattern count does not match the variant field count.
+    BranchPatternVariantFieldCount {
+        /// The error location data.
+        location: Location,
+        /// The variant name.
+        variant: String,
+        /// The expected number of the nested patterns.
+        expected: usize,
+        /// The actual number of the nested patterns.
+        found: usize,
+    },
+    /// The structure-like branch pattern contains a field, which does not exist in the variant.
+    BranchPatternVariantFieldUnknown {
+        /// The error location data.
+        location: Location,
+        /// The variant name.
+        variant: String,
+        /// The unknown field name.
+        field: String,
+    },
+    /// The structure-like branch pattern does not cover a variant field and has no `..` rest pattern.
+    BranchPatternVariantFieldMissing {
+        /// The error location data.
+        location: Location,
+        /// The variant name.
+        variant: String,
+        /// The missing field name.
+        field: String,
+    },
+    /// A tuple-like pattern is used for a structure-like variant, or vice versa.
+    BranchPatternVariantKindMismatch {
+        /// The error location data.
+        location: Location,
+        /// The variant name.
+        variant: String,
+    },
     /// Some branch pattern occurs more than once in the `match` expression.
     BranchDuplicate {
         /// The error location data.
@@ -12,10 +12,18 @@ use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::rc::Rc;
 
+use zinc_lexical::Location;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::Identifier;
 use zinc_syntax::MatchExpression;
+use zinc_syntax::MatchPattern;
 use zinc_syntax::MatchPatternVariant;
 
+use crate::generator::expression::operand::constant::integer::Integer as GeneratorIntegerConstant;
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
+use crate::generator::expression::operand::r#match::binding::Binding as GeneratorMatchBinding;
 use crate::generator::expression::operand::r#match::builder::Builder as GeneratorMatchExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::r#type::Type as GeneratorType;
@@ -27,10 +35,15 @@ use crate::semantic::casting::Caster;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::option::Optional as OptionConstant;
 use crate::semantic::element::constant::unit::Unit as UnitConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::enumeration::payload::Payload;
+use crate::semantic::element::r#type::function::intrinsic::Function as IntrinsicFunctionType;
+use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::option::Optional as OptionType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
@@ -88,7 +101,11 @@ impl Analyzer {
             ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
                 .analyze(r#match.scrutinee)?;
         let scrutinee_type = Type::from_element(&scrutinee_result, scope_stack.top())?;
-        if scrutinee_type.is_scalar() {
+        let is_option = matches!(scrutinee_type, Type::Option(ref inner) if inner.is_inferred());
+        let is_data_enumeration =
+            matches!(scrutinee_type, Type::Enumeration(ref inner) if inner.is_data_carrying());
+        let is_boolean_tag = is_option || matches!(scrutinee_type, Type::Boolean(_));
+        if scrutinee_type.is_scalar() || is_option || is_data_enumeration {
             builder.set_scrutinee(
                 scrutinee_expression,
                 GeneratorType::try_from_semantic(&scrutinee_type)
@@ -126,7 +143,19 @@ impl Analyzer {
                 )));
             }
 
-            let result = match pattern.variant {
+            let variant = match pattern.variant {
+                MatchPatternVariant::Binding(identifier)
+                    if is_option && identifier.name == OptionConstant::IDENTIFIER_NONE =>
+                {
+                    MatchPatternVariant::Path(ExpressionTree::new(
+                        identifier.location,
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(identifier)),
+                    ))
+                }
+                variant => variant,
+            };
+
+            let result = match variant {
                 MatchPatternVariant::BooleanLiteral(boolean) => {
                     let location = boolean.location;
 
@@ -163,9 +192,9 @@ impl Analyzer {
 
                     if exhausting_data.has_exhausted_boolean() {
                         is_exhausted = true;
-                        builder.set_wildcard_branch(branch);
+                        builder.set_wildcard_branch(branch, vec![]);
                     } else {
-                        builder.push_branch(constant, branch);
+                        builder.push_branch(constant, branch, vec![]);
                     }
 
                     result
@@ -208,9 +237,9 @@ impl Analyzer {
 
                     if exhausting_data.has_exhausted_integer() {
                         is_exhausted = true;
-                        builder.set_wildcard_branch(branch);
+                        builder.set_wildcard_branch(branch, vec![]);
                     } else {
-                        builder.push_branch(constant, branch);
+                        builder.push_branch(constant, branch, vec![]);
                     }
 
                     result
@@ -248,29 +277,133 @@ impl Analyzer {
                                 )));
                             }
                         };
-                    let pattern_type = constant.r#type();
-                    if pattern_type != scrutinee_type {
+                    let constant = match constant {
+                        Constant::Option(_) if is_option => {
+                            if let Some(duplicate) = exhausting_data.insert_boolean(false, location)
+                            {
+                                return Err(Error::Expression(ExpressionError::Match(
+                                    MatchExpressionError::BranchDuplicate {
+                                        location,
+                                        reference: duplicate,
+                                    },
+                                )));
+                            }
+
+                            Constant::Boolean(BooleanConstant::new(location, false))
+                        }
+                        constant => {
+                            let pattern_type = constant.r#type();
+                            if pattern_type != scrutinee_type {
+                                return Err(Error::Expression(ExpressionError::Match(
+                                    MatchExpressionError::BranchPatternInvalidType {
+                                        location: pattern_location,
+                                        expected: scrutinee_type.to_string(),
+                                        found: pattern_type.to_string(),
+                                        reference: scrutinee_location,
+                                    },
+                                )));
+                            }
+
+                            constant
+                        }
+                    };
+
+                    let constant = match constant {
+                        Constant::Integer(ref integer) if is_data_enumeration => {
+                            GeneratorConstant::Integer(GeneratorIntegerConstant::from_semantic(
+                                integer,
+                            ))
+                        }
+                        constant => GeneratorConstant::try_from_semantic(&constant)
+                            .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                    };
+                    let (result, branch) =
+                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
+                            .analyze(expression)?;
+
+                    if (is_boolean_tag && exhausting_data.has_exhausted_boolean())
+                        || (!is_boolean_tag && exhausting_data.has_exhausted_integer())
+                    {
+                        is_exhausted = true;
+                        builder.set_wildcard_branch(branch, vec![]);
+                    } else {
+                        builder.push_branch(constant, branch, vec![]);
+                    }
+
+                    result
+                }
+                variant @ MatchPatternVariant::TupleStruct { .. }
+                | variant @ MatchPatternVariant::Structure { .. } => {
+                    let (tag, bindings, is_casted) = Self::variant_pattern(
+                        scope_stack.top(),
+                        variant,
+                        &scrutinee_type,
+                        scrutinee_location,
+                        pattern_location,
+                    )?;
+
+                    let duplicate = match tag {
+                        Constant::Boolean(ref boolean) => {
+                            exhausting_data.insert_boolean(boolean.inner, pattern_location)
+                        }
+                        Constant::Integer(ref integer) => exhausting_data.insert_integer(
+                            integer.value.to_owned(),
+                            integer.enumeration.to_owned(),
+                            pattern_location,
+                        ),
+                        _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                    };
+                    if let Some(duplicate) = duplicate {
                         return Err(Error::Expression(ExpressionError::Match(
-                            MatchExpressionError::BranchPatternInvalidType {
+                            MatchExpressionError::BranchDuplicate {
                                 location: pattern_location,
-                                expected: scrutinee_type.to_string(),
-                                found: pattern_type.to_string(),
-                                reference: scrutinee_location,
+                                reference: duplicate,
                             },
                         )));
                     }
 
-                    let constant = GeneratorConstant::try_from_semantic(&constant)
-                        .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
+                    scope_stack.push(None);
+                    for (identifier, r#type, _offset) in bindings.iter() {
+                        Scope::define_variable(
+                            scope_stack.top(),
+                            identifier.to_owned(),
+                            false,
+                            r#type.to_owned(),
+                            MemoryType::Stack,
+                        )?;
+                    }
                     let (result, branch) =
                         ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
                             .analyze(expression)?;
+                    scope_stack.pop();
 
-                    if exhausting_data.has_exhausted_integer() {
+                    let constant = match tag {
+                        Constant::Integer(ref integer) => GeneratorConstant::Integer(
+                            GeneratorIntegerConstant::from_semantic(integer),
+                        ),
+                        tag => GeneratorConstant::try_from_semantic(&tag)
+                            .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                    };
+                    let bindings = bindings
+                        .into_iter()
+                        .map(|(identifier, r#type, offset)| {
+                            GeneratorMatchBinding::new(
+                                identifier.name,
+                                offset,
+                                GeneratorType::try_from_semantic(&r#type)
+                                    .unwrap_or_else(GeneratorType::unit),
+                                is_casted,
+                            )
+                        })
+                        .collect();
+
+                    if (is_boolean_tag && exhausting_data.has_exhausted_boolean())
+                        || (!is_boolean_tag && exhausting_data.has_exhausted_integer())
+                    {
                         is_exhausted = true;
-                        builder.set_wildcard_branch(branch);
+                        builder.set_wildcard_branch(branch, bindings);
                     } else {
-                        builder.push_branch(constant, branch);
+                        builder.push_branch(constant, branch, bindings);
                     }
 
                     result
@@ -301,29 +434,32 @@ impl Analyzer {
                         ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
                             .analyze(expression)?;
 
-                    builder.set_wildcard_branch(branch);
+                    builder.set_wildcard_branch(branch, vec![]);
 
                     result
                 }
             };
 
             let result_type = Type::from_element(&result, scope_stack.top())?;
-            if let Some(ref match_result) = match_result {
-                let match_result_type = Type::from_element(match_result, scope_stack.top())?;
-                if result_type != match_result_type {
-                    return Err(Error::Expression(ExpressionError::Match(
-                        MatchExpressionError::BranchExpressionInvalidType {
-                            location: expression_location,
-                            expected: match_result_type.to_string(),
-                            found: result_type.to_string(),
-                            reference: first_branch_expression_location,
-                        },
-                    )));
+            match match_result {
+                Some(ref current) => {
+                    let match_result_type = Type::from_element(current, scope_stack.top())?;
+                    if match_result_type.is_none_inferable(&result_type) {
+                        match_result = Some(result);
+                    } else if result_type != match_result_type
+                        && !result_type.is_none_inferable(&match_result_type)
+                    {
+                        return Err(Error::Expression(ExpressionError::Match(
+                            MatchExpressionError::BranchExpressionInvalidType {
+                                location: expression_location,
+                                expected: match_result_type.to_string(),
+                                found: result_type.to_string(),
+                                reference: first_branch_expression_location,
+                            },
+                        )));
+                    }
                 }
-            }
-
-            if match_result.is_none() {
-                match_result = Some(result);
+                None => match_result = Some(result),
             }
         }
 
@@ -338,11 +474,215 @@ impl Analyzer {
             None => Element::Constant(Constant::Unit(UnitConstant::new(location))),
         };
 
-        let intermediate = GeneratorExpressionOperand::Match(builder.finish());
+        let mut intermediate = GeneratorExpressionOperand::Match(builder.finish());
+        if let Some(r#type) =
+            GeneratorType::try_from_semantic(&Type::from_element(&element, scope_stack.top())?)
+                .filter(|r#type| matches!(r#type, GeneratorType::Option { .. }))
+        {
+            intermediate.set_option_type(&r#type);
+        }
 
         Ok((element, intermediate))
     }
 
+    ///
+    /// Checks whether the tuple-like or structure-like pattern path is `Some` or a data-carrying
+    /// enumeration variant, which matches the scrutinee type.
+    ///
+    /// Returns the pattern tag, the payload bindings with their types and offsets, and whether the
+    /// payload scalars must be casted from the `field` payload slots.
+    ///
+    fn variant_pattern(
+        scope: Rc<RefCell<Scope>>,
+        variant: MatchPatternVariant,
+        scrutinee_type: &Type,
+        scrutinee_location: Location,
+        location: Location,
+    ) -> Result<(Constant, Vec<(Identifier, Type, usize)>, bool), Error> {
+        let (path, patterns, fields) = match variant {
+            MatchPatternVariant::TupleStruct { path, patterns } => (path, Some(patterns), None),
+            MatchPatternVariant::Structure {
+                path,
+                fields,
+                has_rest,
+            } => (path, None, Some((fields, has_rest))),
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+        };
+
+        let path_location = path.location;
+        let (variant_name, tag, payload, is_casted) =
+            match ExpressionAnalyzer::new(scope, TranslationRule::Type).analyze(path)? {
+                (
+                    Element::Type(Type::Function(FunctionType::Intrinsic(
+                        IntrinsicFunctionType::Some(function),
+                    ))),
+                    _,
+                ) => {
+                    let payload_type = match scrutinee_type {
+                        Type::Option(ref inner) if inner.is_inferred() => inner
+                            .inner
+                            .as_ref()
+                            .map(|r#type| *r#type.to_owned())
+                            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                        scrutinee_type => {
+                            return Err(Error::Expression(ExpressionError::Match(
+                                MatchExpressionError::BranchPatternInvalidType {
+                                    location,
+                                    expected: scrutinee_type.to_string(),
+                                    found: Type::Option(OptionType::new(None, None)).to_string(),
+                                    reference: scrutinee_location,
+                                },
+                            )));
+                        }
+                    };
+
+                    (
+                        function.identifier.to_owned(),
+                        Constant::Boolean(BooleanConstant::new(location, true)),
+                        Payload::Tuple(vec![payload_type]),
+                        false,
+                    )
+                }
+                (Element::Type(Type::Function(FunctionType::Variant(function))), _) => {
+                    let enumeration_type = Type::Enumeration(function.enumeration.to_owned());
+                    if &enumeration_type != scrutinee_type {
+                        return Err(Error::Expression(ExpressionError::Match(
+                            MatchExpressionError::BranchPatternInvalidType {
+                                location,
+                                expected: scrutinee_type.to_string(),
+                                found: enumeration_type.to_string(),
+                                reference: scrutinee_location,
+                            },
+                        )));
+                    }
+
+                    let mut tag = IntegerConstant::new(
+                        location,
+                        function.discriminant.to_owned(),
+                        false,
+                        function.enumeration.bitlength,
+                        false,
+                    );
+                    tag.set_enumeration(function.enumeration.to_owned());
+
+                    (
+                        format!(
+                            "{}::{}",
+                            function.enumeration.identifier, function.identifier
+                        ),
+                        Constant::Integer(tag),
+                        function.payload,
+                        true,
+                    )
+                }
+                (element, _intermediate) => {
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternExpectedVariant {
+                            location: path_location,
+                            found: element.to_string(),
+                        },
+                    )));
+                }
+            };
+
+        let mut bindings = Vec::new();
+        match (patterns, fields, payload) {
+            (Some(patterns), None, Payload::Tuple(types)) => {
+                if patterns.len() != types.len() {
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternVariantFieldCount {
+                            location,
+                            variant: variant_name,
+                            expected: types.len(),
+                            found: patterns.len(),
+                        },
+                    )));
+                }
+
+                let mut offset = 0;
+                for (pattern, r#type) in patterns.into_iter().zip(types) {
+                    let size = r#type.size();
+                    if let Some(identifier) = Self::nested_pattern(pattern)? {
+                        bindings.push((identifier, r#type, offset));
+                    }
+                    offset += size;
+                }
+            }
+            (None, Some((fields, has_rest)), Payload::Structure(types)) => {
+                let mut is_mentioned = vec![false; types.len()];
+                for (identifier, pattern) in fields.into_iter() {
+                    let index = match types
+                        .iter()
+                        .position(|(name, _type)| name == &identifier.name)
+                    {
+                        Some(index) => index,
+                        None => {
+                            return Err(Error::Expression(ExpressionError::Match(
+                                MatchExpressionError::BranchPatternVariantFieldUnknown {
+                                    location: identifier.location,
+                                    variant: variant_name,
+                                    field: identifier.name,
+                                },
+                            )));
+                        }
+                    };
+                    is_mentioned[index] = true;
+
+                    let offset = types[..index]
+                        .iter()
+                        .map(|(_name, r#type)| r#type.size())
+                        .sum();
+                    if let Some(identifier) = Self::nested_pattern(pattern)? {
+                        bindings.push((identifier, types[index].1.to_owned(), offset));
+                    }
+                }
+
+                if !has_rest {
+                    if let Some(((name, _type), _)) = types
+                        .iter()
+                        .zip(is_mentioned)
+                        .find(|(_field, is_mentioned)| !is_mentioned)
+                    {
+                        return Err(Error::Expression(ExpressionError::Match(
+                            MatchExpressionError::BranchPatternVariantFieldMissing {
+                                location,
+                                variant: variant_name,
+                                field: name.to_owned(),
+                            },
+                        )));
+                    }
+                }
+            }
+            _ => {
+                return Err(Error::Expression(ExpressionError::Match(
+                    MatchExpressionError::BranchPatternVariantKindMismatch {
+                        location,
+                        variant: variant_name,
+                    },
+                )));
+            }
+        }
+
+        Ok((tag, bindings, is_casted))
+    }
+
+    ///
+    /// Checks whether the nested pattern is a binding or wildcard `_`.
+    ///
+    /// Returns the binding identifier, if the value is bound.
+    ///
+    fn nested_pattern(pattern: MatchPattern) -> Result<Option<Identifier>, Error> {
+        match pattern.variant {
+            MatchPatternVariant::Binding(identifier) => Ok(Some(identifier)),
+            MatchPatternVariant::Wildcard => Ok(None),
+            _ => Err(Error::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternNestedExpectedBinding {
+                    location: pattern.location,
+                },
+            ))),
+        }
+    }
+
     ///
     /// Returns the constant match semantic element.
     ///
@@ -571,6 +911,16 @@ impl Analyzer {
 
                     result
                 }
+                MatchPatternVariant::TupleStruct { .. } | MatchPatternVariant::Structure { .. } => {
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternInvalidType {
+                            location: pattern_location,
+                            expected: scrutinee_type.to_string(),
+                            found: Type::Option(OptionType::new(None, None)).to_string(),
+                            reference: scrutinee_location,
+                        },
+                    )));
+                }
                 MatchPatternVariant::Binding(identifier) => {
                     is_exhausted = true;
 
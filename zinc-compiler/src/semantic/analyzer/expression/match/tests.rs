@@ -416,3 +416,297 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_option() {
+    let input = r#"
+fn main(value: Option<u8>) -> u8 {
+    match value {
+        Some(inner) => inner,
+        None => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_option_wildcard() {
+    let input = r#"
+fn main(value: Option<u8>) -> bool {
+    match value {
+        Some(_) => true,
+        _ => false,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_option_result_none() {
+    let input = r#"
+fn main(value: Option<u8>) -> Option<u8> {
+    match value {
+        Some(inner) => Some(inner + 1),
+        None => None,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_branch_pattern_expected_variant() {
+    let input = r#"
+type Data = field;
+
+fn main(value: Option<u8>) -> u8 {
+    match value {
+        Data(inner) => inner,
+        None => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchPatternExpectedVariant {
+            location: Location::test(6, 9),
+            found: Element::Type(Type::field(None)).to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_variant_field_count_option() {
+    let input = r#"
+fn main(value: Option<u8>) -> u8 {
+    match value {
+        Some(inner, other) => inner,
+        None => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchPatternVariantFieldCount {
+            location: Location::test(4, 9),
+            variant: "Some".to_owned(),
+            expected: 1,
+            found: 2,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_duplicate_option() {
+    let input = r#"
+fn main(value: Option<u8>) -> u8 {
+    match value {
+        None => 0,
+        None => 1,
+        Some(inner) => inner,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchDuplicate {
+            location: Location::test(5, 9),
+            reference: Location::test(4, 9),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_data_enumeration() {
+    let input = r#"
+enum Message {
+    Quit,
+    Move(u8, u8),
+    Write { length: u8, checksum: field },
+}
+
+fn main(message: Message) -> field {
+    match message {
+        Message::Quit => 0 as field,
+        Message::Move(x, _) => x as field,
+        Message::Write { checksum, .. } => checksum,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_data_enumeration_wildcard() {
+    let input = r#"
+enum Message {
+    Quit,
+    Move(u8, u8),
+    Write { length: u8, checksum: field },
+}
+
+fn main(message: Message) -> u8 {
+    match message {
+        Message::Write { length: value, checksum: _ } => value,
+        _ => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_branch_pattern_variant_field_count() {
+    let input = r#"
+enum Message {
+    Quit,
+    Move(u8, u8),
+}
+
+fn main(message: Message) -> u8 {
+    match message {
+        Message::Move(x) => x,
+        _ => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchPatternVariantFieldCount {
+            location: Location::test(9, 9),
+            variant: "Message::Move".to_owned(),
+            expected: 2,
+            found: 1,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_variant_field_unknown() {
+    let input = r#"
+enum Message {
+    Quit,
+    Write { length: u8 },
+}
+
+fn main(message: Message) -> u8 {
+    match message {
+        Message::Write { size, .. } => size,
+        _ => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchPatternVariantFieldUnknown {
+            location: Location::test(9, 26),
+            variant: "Message::Write".to_owned(),
+            field: "size".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_variant_field_missing() {
+    let input = r#"
+enum Message {
+    Quit,
+    Write { length: u8, checksum: field },
+}
+
+fn main(message: Message) -> u8 {
+    match message {
+        Message::Write { length } => length,
+        _ => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchPatternVariantFieldMissing {
+            location: Location::test(9, 9),
+            variant: "Message::Write".to_owned(),
+            field: "checksum".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_variant_kind_mismatch() {
+    let input = r#"
+enum Message {
+    Quit,
+    Move(u8, u8),
+}
+
+fn main(message: Message) -> u8 {
+    match message {
+        Message::Move { x, y } => x,
+        _ => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchPatternVariantKindMismatch {
+            location: Location::test(9, 9),
+            variant: "Message::Move".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_enumeration_partial_with_wildcard() {
+    let input = r#"
+enum List {
+    A = 0,
+    B = 1,
+    C = 2,
+}
+
+fn main(value: List) -> u8 {
+    match value {
+        List::A => 1,
+        List::B => 2,
+        _ => 3,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
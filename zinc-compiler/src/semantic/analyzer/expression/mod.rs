@@ -48,6 +48,8 @@ use crate::generator::expression::operand::constant::Constant as GeneratorExpres
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::lint::index::INDEX as LINT_INDEX;
+use crate::lint::warning::Warning;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::element::access::dot::Dot as DotAccess;
 use crate::semantic::element::constant::unit::Unit as UnitConstant;
@@ -57,11 +59,13 @@ use crate::semantic::element::place::element::Element as PlaceElement;
 use crate::semantic::element::place::error::Error as PlaceError;
 use crate::semantic::element::place::Place;
 use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::unit::Unit as UnitValue;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 
@@ -571,7 +575,7 @@ impl Analyzer {
                     self.left_local(tree.left, operator, rule)?;
                     self.right_local(tree.right, operator, rule)?;
 
-                    self.structure()?;
+                    self.structure(tree.location)?;
                 }
             },
         }
@@ -731,6 +735,9 @@ impl Analyzer {
             )));
         }
 
+        self.lint_mutation(&place);
+        self.lint_field_arithmetic(place.identifier.location, &operator, &place.r#type);
+
         self.evaluation_stack
             .push(StackElement::Evaluated(Element::Value(Value::Unit(
                 UnitValue::new(location),
@@ -764,6 +771,9 @@ impl Analyzer {
         )?;
 
         let (result, operator) = callback(operand_1, operand_2).map_err(Error::Element)?;
+        if let Element::Value(ref value) = result {
+            self.lint_field_arithmetic(location, &operator, &value.r#type());
+        }
         self.evaluation_stack.push(StackElement::Evaluated(result));
 
         self.intermediate.append_expression(intermediate_1);
@@ -802,6 +812,61 @@ impl Analyzer {
         Ok(())
     }
 
+    ///
+    /// Records the `place` mutation for the linter, that is, marks its variable as mutated and
+    /// the contract storage field, if the place is one, as written.
+    ///
+    fn lint_mutation(&self, place: &Place) {
+        let item = match self
+            .scope_stack
+            .top()
+            .borrow()
+            .resolve_item(&place.identifier, true)
+        {
+            Ok(item) => item,
+            Err(_) => return,
+        };
+
+        if let ScopeItem::Variable(ref variable) = *item.borrow() {
+            LINT_INDEX.mark_mutated(variable.item_id);
+
+            if let Type::Contract(ref contract) = variable.r#type {
+                let field = place.elements.iter().find_map(|element| match element {
+                    PlaceElement::ContractField { access } => Some(access.name.to_owned()),
+                    _ => None,
+                });
+                if let Some(field) = field {
+                    LINT_INDEX.mark_written(contract.type_id, field);
+                }
+            }
+        };
+    }
+
+    ///
+    /// Reports the `field` arithmetic `operator` at `location`, since the `field` values silently
+    /// wrap modulo the field prime instead of being checked for overflow.
+    ///
+    fn lint_field_arithmetic(
+        &self,
+        location: Location,
+        operator: &GeneratorExpressionOperator,
+        r#type: &Type,
+    ) {
+        if let Type::Field(_) = r#type {
+            let operator = match operator {
+                GeneratorExpressionOperator::Addition { .. } => "+",
+                GeneratorExpressionOperator::Subtraction { .. } => "-",
+                GeneratorExpressionOperator::Multiplication { .. } => "*",
+                _ => return,
+            };
+
+            self.scope_stack
+                .top()
+                .borrow()
+                .warn(Warning::FieldArithmetic { location, operator });
+        }
+    }
+
     ///
     /// Analyzes the range operation, returns the range start value as the IR expression operand.
     ///
@@ -1004,6 +1069,13 @@ impl Analyzer {
             DotAccess::Method { instance } => {
                 let (instance, is_mutable) = if let Element::Place(instance) = *instance {
                     let is_mutable = instance.is_mutable;
+                    if is_mutable {
+                        if let Element::Type(Type::Function(ref function)) = result {
+                            if function.is_mutable() {
+                                self.lint_mutation(&instance);
+                            }
+                        }
+                    }
 
                     let (instance, intermedidate) = Self::evaluate(
                         self.scope_stack.top(),
@@ -1082,22 +1154,78 @@ impl Analyzer {
     ///
     /// Analyzes the structure initialization operation.
     ///
-    fn structure(&mut self) -> Result<(), Error> {
+    fn structure(&mut self, location: Location) -> Result<(), Error> {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
             self.evaluation_stack.pop(),
             self.rule,
         )?;
-        let (operand_1, _) = Self::evaluate(
+        let (mut operand_1, _) = Self::evaluate(
             self.scope_stack.top(),
             self.evaluation_stack.pop(),
             TranslationRule::Type,
         )?;
 
+        if let Element::Type(Type::Structure(ref mut structure)) = operand_1 {
+            if let Some(template) = structure.template.to_owned() {
+                let location = operand_2
+                    .location()
+                    .or(structure.location)
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                let fields: Vec<(String, Type)> = match operand_2 {
+                    Element::Value(Value::Structure(ref value)) => value
+                        .fields
+                        .iter()
+                        .map(|(name, _location, r#type)| (name.to_owned(), r#type.to_owned()))
+                        .collect(),
+                    Element::Constant(Constant::Structure(ref constant)) => constant
+                        .values
+                        .iter()
+                        .map(|(identifier, constant)| {
+                            (identifier.name.to_owned(), constant.r#type())
+                        })
+                        .collect(),
+                    _ => vec![],
+                };
+
+                let arguments = template.infer_from_fields(location, fields.as_slice())?;
+                *structure = template.instantiate(location, arguments)?;
+            }
+        }
+
+        let variant = match operand_1 {
+            Element::Type(Type::Function(FunctionType::Variant(ref function))) => Some((
+                match operand_2 {
+                    Element::Value(Value::Structure(ref value)) => value
+                        .fields
+                        .iter()
+                        .map(|(_name, _location, r#type)| r#type.size())
+                        .sum(),
+                    _ => 0,
+                },
+                function.enumeration.payload_size(),
+                function.enumeration.bitlength,
+                function.discriminant.to_owned(),
+            )),
+            _ => None,
+        };
+
         let result = Element::structure(operand_1, operand_2, self.scope_stack.top())
             .map_err(Error::Element)?;
         self.evaluation_stack.push(StackElement::Evaluated(result));
 
+        if let Some((input_size, payload_size, bitlength, discriminant)) = variant {
+            self.intermediate.push_operator(
+                location,
+                GeneratorExpressionOperator::call_variant(
+                    input_size,
+                    payload_size,
+                    bitlength,
+                    discriminant,
+                ),
+            );
+        }
+
         Ok(())
     }
 
@@ -1145,6 +1273,10 @@ impl Analyzer {
                     ConditionalAnalyzer::analyze(scope, inner, rule)
                 }
                 ExpressionOperand::Match(inner) => MatchAnalyzer::analyze(scope, inner, rule),
+                ExpressionOperand::Closure(inner) => Ok((
+                    Element::Type(Type::closure_function(inner.location, inner, scope)),
+                    None,
+                )),
             },
             StackElement::Evaluated(inner) => match inner {
                 Element::Path(path) => PathTranslator::translate(scope, path, rule),
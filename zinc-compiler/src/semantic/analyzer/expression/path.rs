@@ -90,6 +90,12 @@ impl Translator {
                     Ok((Element::Type(r#type), None))
                 }
                 ScopeItem::Module(_) => Ok((Element::Module(path_last_identifier), None)),
+                ScopeItem::Trait(ref r#trait) => {
+                    Err(Error::Expression(ExpressionError::TraitAsOperand {
+                        location,
+                        name: r#trait.identifier.to_owned(),
+                    }))
+                }
             },
             TranslationRule::Value => match *Scope::resolve_path(scope, &path)?.borrow() {
                 ScopeItem::Variable(ref variable) => {
@@ -173,6 +179,12 @@ impl Translator {
                     Ok((Element::Type(r#type), None))
                 }
                 ScopeItem::Module(_) => Ok((Element::Module(path_last_identifier), None)),
+                ScopeItem::Trait(ref r#trait) => {
+                    Err(Error::Expression(ExpressionError::TraitAsOperand {
+                        location,
+                        name: r#trait.identifier.to_owned(),
+                    }))
+                }
             },
             TranslationRule::Constant => match *Scope::resolve_path(scope, &path)?.borrow() {
                 ScopeItem::Constant(ref constant) => {
@@ -35,6 +35,9 @@ impl Analyzer {
     /// `<module>.zn` in the module directory. For example, `mod foo;` will look for a file called
     /// `./foo.zn` and yield an error if it is absent.
     ///
+    /// The implementations are declared after the other hoisted items, since they may reference
+    /// the types and traits declared below them.
+    ///
     /// Returns the module without the hoisted statements and the implementation scopes which
     /// must be defined forcibly.
     ///
@@ -47,7 +50,7 @@ impl Analyzer {
         is_entry: bool,
     ) -> Result<(SyntaxModule, Vec<Rc<RefCell<Scope>>>), Error> {
         let mut instant_statement = Vec::with_capacity(module.statements.len());
-        let mut implementation_scopes = Vec::with_capacity(module.statements.len());
+        let mut implementation_statements = Vec::with_capacity(module.statements.len());
 
         for hoisted_statement in module.statements.into_iter() {
             match hoisted_statement {
@@ -132,8 +135,10 @@ impl Analyzer {
                     }
                 }
                 ModuleLocalStatement::Impl(statement) => {
-                    let scope = ImplStatementAnalyzer::declare(scope.clone(), statement)?;
-                    implementation_scopes.push(scope);
+                    implementation_statements.push(statement);
+                }
+                ModuleLocalStatement::Trait(statement) => {
+                    Scope::declare_trait(scope.clone(), statement)?;
                 }
                 ModuleLocalStatement::Use(statement) => {
                     instant_statement.push(ModuleLocalStatement::Use(statement))
@@ -142,6 +147,12 @@ impl Analyzer {
             }
         }
 
+        let mut implementation_scopes = Vec::with_capacity(implementation_statements.len());
+        for statement in implementation_statements.into_iter() {
+            let scope = ImplStatementAnalyzer::declare(scope.clone(), statement)?;
+            implementation_scopes.push(scope);
+        }
+
         module.statements = instant_statement;
 
         Ok((module, implementation_scopes))
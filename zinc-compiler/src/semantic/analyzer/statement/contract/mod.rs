@@ -13,6 +13,9 @@ use zinc_syntax::ContractStatement;
 use zinc_syntax::Identifier;
 
 use crate::generator::statement::contract::Statement as GeneratorContractStatement;
+use crate::lint::index::INDEX as LINT_INDEX;
+use crate::lint::warning::Warning;
+use crate::lint::Lint;
 use crate::semantic::analyzer::statement::field::Analyzer as FieldStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#fn::Context as FnStatementAnalyzerContext;
 use crate::semantic::element::r#type::contract::field::Field as ContractFieldType;
@@ -96,13 +99,17 @@ impl Analyzer {
             ),
         );
 
+        let mut linted_fields = Vec::with_capacity(statement.statements.len());
         for instant_statement in statement.statements.into_iter() {
             if let ContractLocalStatement::Field(statement) = instant_statement {
-                FieldStatementAnalyzer::define(
+                let allowed_lints = FieldStatementAnalyzer::define(
                     scope.clone(),
                     statement.clone(),
                     storage_fields.len(),
                 )?;
+                if !allowed_lints.contains(&Lint::UnwrittenStorageFields) {
+                    linted_fields.push(statement.identifier.to_owned());
+                }
 
                 let field = ContractFieldType::try_from_syntax(statement, scope.clone())?;
 
@@ -119,6 +126,17 @@ impl Analyzer {
 
         scope.borrow().define()?;
 
+        if let Type::Contract(ref contract) = r#type {
+            for field in linted_fields.into_iter() {
+                if !LINT_INDEX.is_written(contract.type_id, field.name.as_str()) {
+                    LINT_INDEX.push(Warning::UnwrittenStorageField {
+                        location: field.location,
+                        name: field.name,
+                    });
+                }
+            }
+        }
+
         let intermediate = GeneratorContractStatement::new(location, storage_fields);
 
         Ok((r#type, intermediate))
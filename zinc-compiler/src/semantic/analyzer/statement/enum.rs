@@ -7,6 +7,8 @@ use std::rc::Rc;
 
 use zinc_syntax::EnumStatement;
 
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 use crate::semantic::scope::Scope;
@@ -29,6 +31,15 @@ impl Analyzer {
             Some(scope),
         )?;
 
+        if !r#type.is_instantiatable(false) {
+            return Err(Error::Element(ElementError::Type(
+                TypeError::InstantiationForbidden {
+                    location: statement.location,
+                    found: r#type.to_string(),
+                },
+            )));
+        }
+
         Ok(r#type)
     }
 }
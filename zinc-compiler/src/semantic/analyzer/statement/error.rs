@@ -4,6 +4,8 @@
 
 use crate::semantic::analyzer::statement::r#for::error::Error as ForStatementError;
 use crate::semantic::analyzer::statement::r#impl::error::Error as ImplStatementError;
+use crate::semantic::analyzer::statement::r#loop::error::Error as LoopStatementError;
+use crate::semantic::analyzer::statement::r#return::error::Error as ReturnStatementError;
 use crate::semantic::analyzer::statement::r#use::error::Error as UseStatementError;
 
 ///
@@ -13,6 +15,10 @@ use crate::semantic::analyzer::statement::r#use::error::Error as UseStatementErr
 pub enum Error {
     /// The `for` statement analysis error.
     For(ForStatementError),
+    /// The `loop` and `while` statement analysis error.
+    Loop(LoopStatementError),
+    /// The `return` statement analysis error.
+    Return(ReturnStatementError),
     /// The `impl` statement analysis error.
     Impl(ImplStatementError),
     /// The `use` statement analysis error.
@@ -7,6 +7,9 @@ use std::rc::Rc;
 
 use zinc_syntax::FieldStatement;
 
+use crate::lint::Lint;
+use crate::semantic::analyzer::attribute::error::Error as AttributeError;
+use crate::semantic::analyzer::attribute::Attribute;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::Type;
@@ -22,11 +25,27 @@ impl Analyzer {
     ///
     /// Defines a contract storage field.
     ///
+    /// Returns the lints suppressed with the field `#[allow(...)]` attributes, which are the only
+    /// attributes allowed for storage fields.
+    ///
     pub fn define(
         scope: Rc<RefCell<Scope>>,
         statement: FieldStatement,
         index: usize,
-    ) -> Result<(), Error> {
+    ) -> Result<Vec<Lint>, Error> {
+        let mut attributes = Vec::with_capacity(statement.attributes.len());
+        for attribute in statement.attributes.into_iter() {
+            let identifier = attribute.identifier.to_owned();
+            if identifier.name != Attribute::ALLOW_IDENTIFIER {
+                return Err(Error::Attribute(AttributeError::Unknown {
+                    location: identifier.location,
+                    found: identifier.name,
+                }));
+            }
+
+            attributes.extend(Attribute::try_from_syntax(attribute).map_err(Error::Attribute)?);
+        }
+
         let r#type = Type::try_from_syntax(statement.r#type, scope.clone())?;
 
         if !r#type.is_instantiatable(true) {
@@ -48,6 +67,6 @@ impl Analyzer {
             false,
         )?;
 
-        Ok(())
+        Ok(Attribute::allowed_lints(attributes.as_slice()))
     }
 }
@@ -3,11 +3,13 @@
 //!
 
 use std::cell::RefCell;
-use std::convert::TryFrom;
 use std::rc::Rc;
 
+use zinc_syntax::BlockExpression;
 use zinc_syntax::FnStatement;
+use zinc_syntax::FunctionLocalStatement;
 
+use crate::generator::r#type::Type as GeneratorType;
 use crate::generator::statement::r#fn::Statement as GeneratorFunctionStatement;
 use crate::semantic::analyzer::attribute::Attribute;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
@@ -17,8 +19,11 @@ use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionError;
 use crate::semantic::element::r#type::function::test::error::Error as TestFunctionError;
+use crate::semantic::element::r#type::generic::error::Error as GenericError;
+use crate::semantic::element::r#type::generic::Generics;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
+use crate::semantic::scope::function_body::FunctionBody;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 
@@ -46,7 +51,7 @@ impl Analyzer {
     ///
     pub fn define(
         scope: Rc<RefCell<Scope>>,
-        mut statement: FnStatement,
+        statement: FnStatement,
         context: Context,
     ) -> Result<(Type, Option<GeneratorFunctionStatement>), Error> {
         if let Context::Contract = context {
@@ -58,9 +63,13 @@ impl Analyzer {
         }
 
         let mut attributes = Vec::with_capacity(statement.attributes.len());
-        for attribute in statement.attributes.drain(..).into_iter() {
-            let attribute = Attribute::try_from(attribute).map_err(Error::Attribute)?;
-            attributes.push(attribute);
+        for attribute in statement.attributes.iter().cloned() {
+            attributes.extend(Attribute::try_from_syntax(attribute).map_err(Error::Attribute)?);
+        }
+
+        if statement.is_generic() {
+            return Self::generic(scope, statement, context, attributes)
+                .map(|r#type| (r#type, None));
         }
 
         if attributes.contains(&Attribute::Test) {
@@ -85,17 +94,30 @@ impl Analyzer {
         context: Context,
         attributes: Vec<Attribute>,
     ) -> Result<(Type, GeneratorFunctionStatement), Error> {
+        let expected_type = match statement.return_type {
+            Some(ref r#type) => Type::try_from_syntax(r#type.to_owned(), scope.clone())?,
+            None => Type::unit(None),
+        };
+
         let mut scope_stack = ScopeStack::new(scope);
-        scope_stack.push(Some(statement.identifier.name.clone()));
+        scope_stack.push_function(
+            statement.identifier.name.clone(),
+            FunctionBody::new(
+                statement.identifier.name.clone(),
+                expected_type.clone(),
+                statement
+                    .return_type
+                    .as_ref()
+                    .map(|r#type| r#type.location)
+                    .unwrap_or(statement.location),
+                false,
+                Attribute::allowed_lints(attributes.as_slice()),
+            ),
+        );
 
         let bindings =
             Binder::bind_arguments(statement.argument_bindings, context, scope_stack.top())?;
 
-        let expected_type = match statement.return_type {
-            Some(ref r#type) => Type::try_from_syntax(r#type.to_owned(), scope_stack.top())?,
-            None => Type::unit(None),
-        };
-
         if !expected_type.is_instantiatable(false) {
             return Err(Error::Element(ElementError::Type(
                 TypeError::InstantiationForbidden {
@@ -123,12 +145,27 @@ impl Analyzer {
                 .unwrap_or(statement.location),
         };
 
-        let (result, intermediate) =
+        let is_return_terminated = Self::is_return_terminated(&statement.body);
+        let (result, mut intermediate) =
             BlockAnalyzer::analyze(scope_stack.top(), statement.body, TranslationRule::Value)?;
+        let is_returning = scope_stack.top().borrow().is_returned();
         scope_stack.pop();
 
         let result_type = Type::from_element(&result, scope_stack.top())?;
-        if expected_type != result_type {
+        let is_none_inferable = result_type.is_none_inferable(&expected_type);
+        if is_none_inferable {
+            if let Some(r#type) = GeneratorType::try_from_semantic(&expected_type) {
+                intermediate.set_option_type(&r#type);
+            }
+        }
+
+        let intermediate = if is_returning {
+            intermediate.into_returning(return_expression_location)
+        } else {
+            intermediate
+        };
+
+        if expected_type != result_type && !is_none_inferable && !is_return_terminated {
             return Err(Error::Element(ElementError::Type(TypeError::Function(
                 FunctionError::ReturnType {
                     location: return_expression_location,
@@ -175,6 +212,7 @@ impl Analyzer {
             is_contract_entry,
             is_main,
             attributes,
+            is_returning,
         );
 
         Ok((r#type, intermediate))
@@ -187,19 +225,32 @@ impl Analyzer {
         scope: Rc<RefCell<Scope>>,
         statement: FnStatement,
         context: Context,
-        _attributes: Vec<Attribute>,
+        attributes: Vec<Attribute>,
     ) -> Result<Type, Error> {
+        let expected_type = match statement.return_type {
+            Some(ref r#type) => Type::try_from_syntax(r#type.to_owned(), scope.clone())?,
+            None => Type::unit(None),
+        };
+
         let mut scope_stack = ScopeStack::new(scope);
-        scope_stack.push(Some(statement.identifier.name.clone()));
+        scope_stack.push_function(
+            statement.identifier.name.clone(),
+            FunctionBody::new(
+                statement.identifier.name.clone(),
+                expected_type.clone(),
+                statement
+                    .return_type
+                    .as_ref()
+                    .map(|r#type| r#type.location)
+                    .unwrap_or(statement.location),
+                true,
+                Attribute::allowed_lints(attributes.as_slice()),
+            ),
+        );
 
         let bindings =
             Binder::bind_arguments(statement.argument_bindings, context, scope_stack.top())?;
 
-        let expected_type = match statement.return_type {
-            Some(ref r#type) => Type::try_from_syntax(r#type.to_owned(), scope_stack.top())?,
-            None => Type::unit(None),
-        };
-
         if !expected_type.is_instantiatable(false) {
             return Err(Error::Element(ElementError::Type(
                 TypeError::InstantiationForbidden {
@@ -259,6 +310,45 @@ impl Analyzer {
         ))
     }
 
+    ///
+    /// Declares a generic function, which body is analyzed upon each instantiation.
+    ///
+    fn generic(
+        scope: Rc<RefCell<Scope>>,
+        statement: FnStatement,
+        context: Context,
+        attributes: Vec<Attribute>,
+    ) -> Result<Type, Error> {
+        let is_entry = match context {
+            Context::Contract => statement.is_public,
+            Context::Module => {
+                statement.identifier.name.as_str() == zinc_const::source::FUNCTION_MAIN_IDENTIFIER
+            }
+            Context::Implementation => false,
+        };
+        if is_entry || attributes.contains(&Attribute::Test) {
+            return Err(Generics::error(GenericError::EntryPoint {
+                location: statement.location,
+                function: statement.identifier.name,
+            }));
+        }
+
+        let generics = Generics::new(
+            statement.identifier.name.clone(),
+            statement.generics.clone(),
+        )
+        .map_err(Generics::error)?;
+
+        Ok(Type::generic_function(
+            statement.location,
+            statement.identifier.name.clone(),
+            generics,
+            statement,
+            context,
+            scope,
+        ))
+    }
+
     ///
     /// Analyzes a test function statement and returns its IR for the next compiler phase.
     ///
@@ -320,9 +410,19 @@ impl Analyzer {
             ))));
         }
 
-        scope_stack.push(Some(statement.identifier.name.clone()));
+        scope_stack.push_function(
+            statement.identifier.name.clone(),
+            FunctionBody::new(
+                statement.identifier.name.clone(),
+                Type::unit(None),
+                statement.location,
+                false,
+                Attribute::allowed_lints(attributes.as_slice()),
+            ),
+        );
         let (_result, intermediate) =
             BlockAnalyzer::analyze(scope_stack.top(), statement.body, TranslationRule::Value)?;
+        let is_returning = scope_stack.top().borrow().is_returned();
         scope_stack.pop();
 
         let (r#type, type_id) =
@@ -339,8 +439,21 @@ impl Analyzer {
             false,
             false,
             attributes,
+            is_returning,
         );
 
         Ok((r#type, intermediate))
     }
+
+    ///
+    /// Checks whether the function `body` is terminated with a `return` statement, in which
+    /// case the body block itself is of the `()` type.
+    ///
+    fn is_return_terminated(body: &BlockExpression) -> bool {
+        body.expression.is_none()
+            && match body.statements.last() {
+                Some(FunctionLocalStatement::Return(_)) => true,
+                _ => false,
+            }
+    }
 }
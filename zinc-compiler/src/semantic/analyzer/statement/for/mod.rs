@@ -79,7 +79,7 @@ impl Analyzer {
                 }
             };
 
-        scope_stack.push(None);
+        scope_stack.push_loop(None);
 
         let index_location = statement.index_identifier.location;
         let index_identifier = statement.index_identifier.name.to_owned();
@@ -116,6 +116,7 @@ impl Analyzer {
 
         let (_element, body) =
             BlockAnalyzer::analyze(scope_stack.top(), statement.block, TranslationRule::Value)?;
+        let is_interruptible = scope_stack.top().borrow().is_interrupted();
 
         scope_stack.pop();
 
@@ -149,6 +150,7 @@ impl Analyzer {
             index_bitlength,
             while_condition,
             body,
+            is_interruptible,
         ))
     }
 }
@@ -116,6 +116,27 @@ fn main() {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_break_continue() {
+    let input = r#"
+fn main() -> u8 {
+    let mut sum = 0;
+    for i in 0..10 {
+        if i == 8 {
+            break;
+        }
+        if i % 2 == 0 {
+            continue;
+        }
+        sum += i;
+    }
+    sum
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_bounds_expected_constant_range_expression() {
     let input = r#"
@@ -16,4 +16,55 @@ pub enum Error {
         /// The invalid type identifier.
         found: String,
     },
+    /// Only a trait can be implemented for a type, but another item was found.
+    ExpectedTrait {
+        /// The invalid item location in the code.
+        location: Location,
+        /// The invalid item identifier.
+        found: String,
+    },
+    /// The trait implementation does not implement a required trait method.
+    MissingTraitMethod {
+        /// The implementation location in the code.
+        location: Location,
+        /// The trait identifier.
+        r#trait: String,
+        /// The missing method identifier.
+        method: String,
+        /// The location of the method signature in the trait.
+        reference: Location,
+    },
+    /// The trait implementation declares a method, which is not a part of the trait.
+    UnexpectedTraitMethod {
+        /// The method identifier location in the code.
+        location: Location,
+        /// The trait identifier.
+        r#trait: String,
+        /// The unexpected method identifier.
+        method: String,
+    },
+    /// The trait method is implemented with another number of arguments.
+    TraitMethodArgumentCount {
+        /// The method location in the code.
+        location: Location,
+        /// The trait identifier.
+        r#trait: String,
+        /// The method identifier.
+        method: String,
+        /// The number of arguments in the trait method.
+        expected: usize,
+        /// The number of arguments in the implemented method.
+        found: usize,
+        /// The location of the method in the trait.
+        reference: Location,
+    },
+    /// The trait has been already implemented for the type.
+    DuplicateTraitImplementation {
+        /// The implementation location in the code.
+        location: Location,
+        /// The trait identifier.
+        r#trait: String,
+        /// The type identifier.
+        r#type: String,
+    },
 }
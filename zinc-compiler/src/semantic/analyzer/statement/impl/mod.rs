@@ -11,6 +11,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use zinc_lexical::Keyword;
+use zinc_syntax::FnStatement;
 use zinc_syntax::ImplStatement;
 use zinc_syntax::ImplementationLocalStatement;
 
@@ -20,6 +21,7 @@ use crate::semantic::analyzer::statement::r#impl::error::Error as ImplStatementE
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 use crate::semantic::scope::error::Error as ScopeError;
+use crate::semantic::scope::item::r#trait::Trait as ScopeTraitItem;
 use crate::semantic::scope::item::r#type::state::State as ScopeTypeItemState;
 use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementVariant;
 use crate::semantic::scope::item::r#type::statement::Statement as ScopeTypeItemStatement;
@@ -38,12 +40,32 @@ impl Analyzer {
     ///
     /// Also declares the `Self` alias for the type being implemented.
     ///
+    /// If a trait is implemented, checks the methods against the trait ones and declares the
+    /// default methods, which are not overridden by the implementation.
+    ///
     pub fn declare(
         scope: Rc<RefCell<Scope>>,
         statement: ImplStatement,
     ) -> Result<Rc<RefCell<Scope>>, Error> {
         let identifier_location = statement.identifier.location;
 
+        let r#trait = match statement.trait_identifier {
+            Some(ref identifier) => {
+                match *scope.borrow().resolve_item(identifier, true)?.borrow() {
+                    ScopeItem::Trait(ref inner) => Some(inner.to_owned()),
+                    ref _item => {
+                        return Err(Error::Statement(StatementError::Impl(
+                            ImplStatementError::ExpectedTrait {
+                                location: identifier.location,
+                                found: identifier.name.to_owned(),
+                            },
+                        )))
+                    }
+                }
+            }
+            None => None,
+        };
+
         let item = scope.borrow().resolve_item(&statement.identifier, true)?;
 
         let scope = match *item.borrow() {
@@ -95,12 +117,18 @@ impl Analyzer {
 
         Scope::insert_item(scope.clone(), Keyword::SelfUppercase.to_string(), item);
 
+        if let Some(ref r#trait) = r#trait {
+            Self::check_trait(scope.clone(), r#trait, &statement)?;
+        }
+
+        let mut implemented = Vec::with_capacity(statement.statements.len());
         for hoisted_statement in statement.statements.into_iter() {
             match hoisted_statement {
                 ImplementationLocalStatement::Const(statement) => {
                     Scope::declare_constant(scope.clone(), statement, true)?;
                 }
                 ImplementationLocalStatement::Fn(statement) => {
+                    implemented.push(statement.identifier.name.to_owned());
                     Scope::declare_type(
                         scope.clone(),
                         TypeStatementVariant::Fn(
@@ -114,6 +142,106 @@ impl Analyzer {
             }
         }
 
+        if let Some(r#trait) = r#trait {
+            for method in r#trait.defaults().into_iter() {
+                if implemented.contains(&method.identifier.name) {
+                    continue;
+                }
+
+                Scope::declare_type(
+                    scope.clone(),
+                    TypeStatementVariant::Fn(
+                        method.to_owned(),
+                        FnStatementAnalyzerContext::Implementation,
+                    ),
+                    true,
+                )?;
+            }
+        }
+
         Ok(scope)
     }
+
+    ///
+    /// Checks the implementation methods against the trait ones and registers the type as the
+    /// trait implementor.
+    ///
+    fn check_trait(
+        scope: Rc<RefCell<Scope>>,
+        r#trait: &ScopeTraitItem,
+        statement: &ImplStatement,
+    ) -> Result<(), Error> {
+        let methods: Vec<&FnStatement> = statement
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                ImplementationLocalStatement::Fn(inner) => Some(inner),
+                _ => None,
+            })
+            .collect();
+        let declared: Vec<&FnStatement> = r#trait
+            .signatures()
+            .into_iter()
+            .chain(r#trait.defaults().into_iter())
+            .collect();
+
+        for method in methods.iter() {
+            let expected = match declared
+                .iter()
+                .find(|declared| declared.identifier.name == method.identifier.name)
+            {
+                Some(declared) => declared,
+                None => {
+                    return Err(Error::Statement(StatementError::Impl(
+                        ImplStatementError::UnexpectedTraitMethod {
+                            location: method.identifier.location,
+                            r#trait: r#trait.identifier.to_owned(),
+                            method: method.identifier.name.to_owned(),
+                        },
+                    )))
+                }
+            };
+
+            if method.argument_bindings.len() != expected.argument_bindings.len() {
+                return Err(Error::Statement(StatementError::Impl(
+                    ImplStatementError::TraitMethodArgumentCount {
+                        location: method.location,
+                        r#trait: r#trait.identifier.to_owned(),
+                        method: method.identifier.name.to_owned(),
+                        expected: expected.argument_bindings.len(),
+                        found: method.argument_bindings.len(),
+                        reference: expected.location,
+                    },
+                )));
+            }
+        }
+
+        for signature in r#trait.signatures().into_iter() {
+            if !methods
+                .iter()
+                .any(|method| method.identifier.name == signature.identifier.name)
+            {
+                return Err(Error::Statement(StatementError::Impl(
+                    ImplStatementError::MissingTraitMethod {
+                        location: statement.location,
+                        r#trait: r#trait.identifier.to_owned(),
+                        method: signature.identifier.name.to_owned(),
+                        reference: signature.location,
+                    },
+                )));
+            }
+        }
+
+        if !r#trait.add_implementor(scope) {
+            return Err(Error::Statement(StatementError::Impl(
+                ImplStatementError::DuplicateTraitImplementation {
+                    location: statement.location,
+                    r#trait: r#trait.identifier.to_owned(),
+                    r#type: statement.identifier.name.to_owned(),
+                },
+            )));
+        }
+
+        Ok(())
+    }
 }
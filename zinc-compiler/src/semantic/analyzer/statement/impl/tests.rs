@@ -75,3 +75,175 @@ fn main() {}
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_trait_default_method() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> field;
+
+    fn double_hash(self) -> field {
+        self.hash() * 2 as field
+    }
+}
+
+struct Data {
+    value: u8,
+}
+
+impl Hashable for Data {
+    fn hash(self) -> field {
+        self.value as field
+    }
+}
+
+fn main() -> field {
+    let data = Data { value: 42 };
+    data.double_hash()
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_expected_trait() {
+    let input = r#"
+struct Hashable {}
+
+struct Data {}
+
+impl Hashable for Data {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Impl(ImplStatementError::ExpectedTrait {
+            location: Location::test(6, 6),
+            found: "Hashable".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_missing_trait_method() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> field;
+}
+
+struct Data {}
+
+impl Hashable for Data {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Impl(ImplStatementError::MissingTraitMethod {
+            location: Location::test(8, 1),
+            r#trait: "Hashable".to_owned(),
+            method: "hash".to_owned(),
+            reference: Location::test(3, 5),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unexpected_trait_method() {
+    let input = r#"
+trait Hashable {}
+
+struct Data {}
+
+impl Hashable for Data {
+    fn hash(self) -> field {
+        0
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Impl(ImplStatementError::UnexpectedTraitMethod {
+            location: Location::test(7, 8),
+            r#trait: "Hashable".to_owned(),
+            method: "hash".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_trait_method_argument_count() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> field;
+}
+
+struct Data {}
+
+impl Hashable for Data {
+    fn hash(self, salt: field) -> field {
+        salt
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Impl(ImplStatementError::TraitMethodArgumentCount {
+            location: Location::test(9, 5),
+            r#trait: "Hashable".to_owned(),
+            method: "hash".to_owned(),
+            expected: 1,
+            found: 2,
+            reference: Location::test(3, 5),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_duplicate_trait_implementation() {
+    let input = r#"
+trait Empty {}
+
+struct Data {}
+
+impl Empty for Data {}
+
+impl Empty for Data {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Impl(ImplStatementError::DuplicateTraitImplementation {
+            location: Location::test(8, 1),
+            r#trait: "Empty".to_owned(),
+            r#type: "Data".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -5,6 +5,7 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_syntax::BindingPatternVariant;
 use zinc_syntax::LetStatement;
 
 use crate::generator::statement::r#let::Statement as GeneratorDeclarationStatement;
@@ -13,6 +14,9 @@ use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::binding::Binder;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::option::error::Error as OptionTypeError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
@@ -28,23 +32,63 @@ impl Analyzer {
     ///
     /// Defines a variable and returns its IR for the next compiler phase.
     ///
+    /// Closures are not values, so a closure bound to a simple immutable binding is declared
+    /// as a compile-time item, which can be called later in the scope.
+    ///
     pub fn define(
         scope: Rc<RefCell<Scope>>,
         statement: LetStatement,
     ) -> Result<Option<GeneratorDeclarationStatement>, Error> {
-        let (element, expression) = ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
-            .analyze(statement.expression)?;
+        let (element, mut expression) =
+            ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
+                .analyze(statement.expression)?;
+
+        if let (
+            Element::Type(Type::Function(FunctionType::Closure(_))),
+            BindingPatternVariant::Binding {
+                ref identifier,
+                is_mutable: false,
+            },
+            None,
+        ) = (
+            &element,
+            &statement.binding.pattern.variant,
+            &statement.binding.r#type,
+        ) {
+            let r#type = Type::from_element(&element, scope.clone())?;
+            Scope::define_type(scope, identifier.to_owned(), r#type, false, None)?;
+            return Ok(None);
+        }
 
         let r#type = if let Some(r#type) = statement.binding.r#type {
             let r#type = Type::try_from_syntax(r#type, scope.clone())?;
-            element
-                .cast(Element::Type(r#type.clone()))
-                .map_err(Error::Element)?;
+            let is_none_inferable = match element {
+                Element::Value(ref value) => value.r#type().is_none_inferable(&r#type),
+                Element::Constant(ref constant) => constant.r#type().is_none_inferable(&r#type),
+                _ => false,
+            };
+            if is_none_inferable {
+                expression.infer_option(&r#type);
+            } else {
+                element
+                    .cast(Element::Type(r#type.clone()))
+                    .map_err(Error::Element)?;
+            }
             r#type
         } else {
             Type::from_element(&element, scope.clone())?
         };
 
+        if let Type::Option(ref inner) = r#type {
+            if !inner.is_inferred() {
+                return Err(Error::Element(ElementError::Type(TypeError::Option(
+                    OptionTypeError::PayloadTypeNotInferred {
+                        location: statement.binding.location,
+                    },
+                ))));
+            }
+        }
+
         if !r#type.is_instantiatable(false) {
             return Err(Error::Element(ElementError::Type(
                 TypeError::InstantiationForbidden {
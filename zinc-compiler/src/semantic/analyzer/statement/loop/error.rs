@@ -0,0 +1,60 @@
+//!
+//! The semantic analyzer `loop` and `while` statement error.
+//!
+
+use zinc_lexical::Location;
+
+///
+/// The semantic analyzer `loop` and `while` statement error.
+///
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The loop has no `#[bound(N)]` attribute.
+    BoundMissing {
+        /// The loop statement location.
+        location: Location,
+    },
+    /// The loop has more than one `#[bound(N)]` attribute.
+    BoundDuplicate {
+        /// The duplicate attribute location.
+        location: Location,
+        /// The location of the first attribute.
+        reference: Location,
+    },
+    /// The `#[bound(N)]` attribute has not exactly one argument.
+    BoundExpectedSingleArgument {
+        /// The attribute location.
+        location: Location,
+        /// The number of arguments found.
+        found: usize,
+    },
+    /// The `#[bound(N)]` attribute argument is not a constant integer.
+    BoundExpectedConstantInteger {
+        /// The argument expression location.
+        location: Location,
+        /// The stringified invalid argument element.
+        found: String,
+    },
+    /// The `while` condition is not of boolean type.
+    WhileExpectedBooleanCondition {
+        /// The condition expression location.
+        location: Location,
+        /// The stringified invalid condition type.
+        found: String,
+    },
+    /// The `loop` body has no `break` statement, so it can never terminate.
+    ExpectedBreak {
+        /// The loop statement location.
+        location: Location,
+    },
+    /// The `break` statement is used outside of a loop body.
+    BreakOutsideLoop {
+        /// The statement location.
+        location: Location,
+    },
+    /// The `continue` statement is used outside of a loop body.
+    ContinueOutsideLoop {
+        /// The statement location.
+        location: Location,
+    },
+}
@@ -0,0 +1,185 @@
+//!
+//! The `loop` and `while` statement semantic analyzer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::Attribute as SyntaxAttribute;
+use zinc_syntax::LoopStatement;
+
+use crate::generator::statement::r#loop::Statement as GeneratorLoopStatement;
+use crate::semantic::analyzer::attribute::error::Error as AttributeError;
+use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::analyzer::statement::error::Error as StatementError;
+use crate::semantic::analyzer::statement::r#loop::error::Error as LoopStatementError;
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::stack::Stack as ScopeStack;
+use crate::semantic::scope::Scope;
+
+/// The loop iterations bound attribute identifier.
+pub static BOUND_ATTRIBUTE_IDENTIFIER: &str = "bound";
+
+///
+/// The `loop` and `while` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Defines a bounded `loop` or `while` and returns its IR for the next compiler phase.
+    ///
+    pub fn define(
+        scope: Rc<RefCell<Scope>>,
+        statement: LoopStatement,
+    ) -> Result<GeneratorLoopStatement, Error> {
+        let location = statement.location;
+
+        let iterations_count = Self::bound(scope.clone(), location, statement.attributes)?;
+
+        let condition = match statement.condition {
+            Some(expression) => {
+                let location = expression.location;
+                let (element, intermediate) =
+                    ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
+                        .analyze(expression)?;
+
+                match Type::from_element(&element, scope.clone())? {
+                    Type::Boolean(_) => {}
+                    r#type => {
+                        return Err(Error::Statement(StatementError::Loop(
+                            LoopStatementError::WhileExpectedBooleanCondition {
+                                location,
+                                found: r#type.to_string(),
+                            },
+                        )));
+                    }
+                }
+
+                Some(intermediate)
+            }
+            None => None,
+        };
+
+        let mut scope_stack = ScopeStack::new(scope);
+        scope_stack.push_loop(None);
+        let (_element, body) =
+            BlockAnalyzer::analyze(scope_stack.top(), statement.block, TranslationRule::Value)?;
+        let is_interruptible = scope_stack.top().borrow().is_interrupted();
+        scope_stack.pop();
+
+        if condition.is_none() && !is_interruptible {
+            return Err(Error::Statement(StatementError::Loop(
+                LoopStatementError::ExpectedBreak { location },
+            )));
+        }
+
+        Ok(GeneratorLoopStatement::new(
+            location,
+            iterations_count,
+            condition,
+            body,
+            is_interruptible,
+        ))
+    }
+
+    ///
+    /// Checks whether the `break` or `continue` statement is used inside a loop body.
+    ///
+    pub fn interrupt(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        is_break: bool,
+    ) -> Result<(), Error> {
+        if Scope::interrupt_loop(scope) {
+            return Ok(());
+        }
+
+        Err(Error::Statement(StatementError::Loop(if is_break {
+            LoopStatementError::BreakOutsideLoop { location }
+        } else {
+            LoopStatementError::ContinueOutsideLoop { location }
+        })))
+    }
+
+    ///
+    /// Evaluates the mandatory `#[bound(N)]` attribute, which is the maximal number of
+    /// the loop iterations.
+    ///
+    fn bound(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        attributes: Vec<SyntaxAttribute>,
+    ) -> Result<usize, Error> {
+        let mut bound: Option<(Location, usize)> = None;
+
+        for mut attribute in attributes.into_iter() {
+            if attribute.identifier.name.as_str() != BOUND_ATTRIBUTE_IDENTIFIER {
+                return Err(Error::Attribute(AttributeError::Unknown {
+                    location: attribute.identifier.location,
+                    found: attribute.identifier.name,
+                }));
+            }
+
+            if let Some((reference, _)) = bound {
+                return Err(Error::Statement(StatementError::Loop(
+                    LoopStatementError::BoundDuplicate {
+                        location: attribute.location,
+                        reference,
+                    },
+                )));
+            }
+
+            if attribute.arguments.len() != 1 {
+                return Err(Error::Statement(StatementError::Loop(
+                    LoopStatementError::BoundExpectedSingleArgument {
+                        location: attribute.location,
+                        found: attribute.arguments.len(),
+                    },
+                )));
+            }
+            let expression = attribute.arguments.remove(0);
+            let expression_location = expression.location;
+
+            let value = match ExpressionAnalyzer::new(scope.clone(), TranslationRule::Constant)
+                .analyze(expression)?
+            {
+                (Element::Constant(Constant::Integer(integer)), _intermediate) => {
+                    integer.to_usize().map_err(|error| {
+                        Error::Element(ElementError::Constant(ConstantError::Integer(error)))
+                    })?
+                }
+                (element, _intermediate) => {
+                    return Err(Error::Statement(StatementError::Loop(
+                        LoopStatementError::BoundExpectedConstantInteger {
+                            location: expression_location,
+                            found: element.to_string(),
+                        },
+                    )));
+                }
+            };
+
+            bound = Some((attribute.location, value));
+        }
+
+        match bound {
+            Some((_location, value)) => Ok(value),
+            None => Err(Error::Statement(StatementError::Loop(
+                LoopStatementError::BoundMissing { location },
+            ))),
+        }
+    }
+}
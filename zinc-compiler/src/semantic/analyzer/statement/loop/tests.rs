@@ -0,0 +1,283 @@
+//!
+//! The `loop` and `while` statement tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::analyzer::attribute::error::Error as AttributeError;
+use crate::semantic::analyzer::statement::error::Error as StatementError;
+use crate::semantic::analyzer::statement::r#loop::error::Error as LoopStatementError;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_loop() {
+    let input = r#"
+fn main() -> u8 {
+    let mut value = 1;
+    #[bound(8)]
+    loop {
+        value *= 2;
+        if value > 100 {
+            break;
+        }
+    }
+    value
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_while() {
+    let input = r#"
+fn main(x: u8) -> u8 {
+    let mut value = 1;
+    #[bound(8)]
+    while value < x {
+        value *= 2;
+    }
+    value
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_while_constant_parameter_bound() {
+    let input = r#"
+fn index_of<const N: usize>(array: [u8; N], value: u8) -> u8 {
+    let mut index = 0;
+    #[bound(N)]
+    while index < N as u8 {
+        if array[index] == value {
+            break;
+        }
+        index += 1;
+    }
+    index
+}
+
+fn main() -> u8 {
+    index_of([1, 2, 3, 4], 3)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_bound_missing() {
+    let input = r#"
+fn main() {
+    let mut value = 1;
+    while value < 100 {
+        value *= 2;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Loop(LoopStatementError::BoundMissing {
+            location: Location::test(4, 5),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bound_duplicate() {
+    let input = r#"
+fn main() {
+    let mut value = 1;
+    #[bound(8)]
+    #[bound(16)]
+    while value < 100 {
+        value *= 2;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Loop(LoopStatementError::BoundDuplicate {
+            location: Location::test(5, 5),
+            reference: Location::test(4, 5),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bound_expected_single_argument() {
+    let input = r#"
+fn main() {
+    let mut value = 1;
+    #[bound]
+    while value < 100 {
+        value *= 2;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Loop(LoopStatementError::BoundExpectedSingleArgument {
+            location: Location::test(4, 5),
+            found: 0,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bound_expected_constant_integer() {
+    let input = r#"
+fn main() {
+    let mut value = 1;
+    #[bound(true)]
+    while value < 100 {
+        value *= 2;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Loop(LoopStatementError::BoundExpectedConstantInteger {
+            location: Location::test(4, 13),
+            found: Element::Constant(Constant::Boolean(BooleanConstant::new(
+                Location::test(4, 13),
+                true,
+            )))
+            .to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_attribute_unknown() {
+    let input = r#"
+fn main() {
+    let mut value = 1;
+    #[unroll]
+    while value < 100 {
+        value *= 2;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::Unknown {
+            location: Location::test(4, 7),
+            found: "unroll".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_while_expected_boolean_condition() {
+    let input = r#"
+fn main() {
+    let mut value = 1;
+    #[bound(8)]
+    while 42 {
+        value *= 2;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Loop(LoopStatementError::WhileExpectedBooleanCondition {
+            location: Location::test(5, 11),
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_expected_break() {
+    let input = r#"
+fn main() {
+    let mut value = 1;
+    #[bound(8)]
+    loop {
+        value *= 2;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Loop(LoopStatementError::ExpectedBreak {
+            location: Location::test(5, 5),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_break_outside_loop() {
+    let input = r#"
+fn main() {
+    break;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Loop(LoopStatementError::BreakOutsideLoop {
+            location: Location::test(3, 5),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_continue_outside_loop() {
+    let input = r#"
+fn main() {
+    if true {
+        continue;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Loop(LoopStatementError::ContinueOutsideLoop {
+            location: Location::test(4, 9),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -11,7 +11,9 @@ pub mod r#fn;
 pub mod r#for;
 pub mod r#impl;
 pub mod r#let;
+pub mod r#loop;
 pub mod module;
+pub mod r#return;
 pub mod r#struct;
 pub mod r#type;
 pub mod r#use;
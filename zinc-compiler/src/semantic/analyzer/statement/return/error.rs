@@ -0,0 +1,24 @@
+//!
+//! The semantic analyzer `return` statement error.
+//!
+
+use zinc_lexical::Location;
+
+///
+/// The semantic analyzer `return` statement error.
+///
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The `return` statement is used outside of a runtime function body, e.g. in a constant.
+    OutsideFunction {
+        /// The statement location.
+        location: Location,
+    },
+    /// The `return` statement is used in a constant function body.
+    ConstantFunction {
+        /// The statement location.
+        location: Location,
+        /// The constant function name.
+        function: String,
+    },
+}
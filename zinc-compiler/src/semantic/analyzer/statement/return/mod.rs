@@ -0,0 +1,95 @@
+//!
+//! The `return` statement semantic analyzer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_syntax::ReturnStatement;
+
+use crate::generator::statement::r#return::Statement as GeneratorReturnStatement;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::analyzer::statement::error::Error as StatementError;
+use crate::semantic::analyzer::statement::r#return::error::Error as ReturnStatementError;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+///
+/// The `return` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Defines a `return` statement and returns its IR for the next compiler phase.
+    ///
+    /// The returned value type is checked against the enclosing function result type.
+    ///
+    pub fn define(
+        scope: Rc<RefCell<Scope>>,
+        statement: ReturnStatement,
+        rule: TranslationRule,
+    ) -> Result<GeneratorReturnStatement, Error> {
+        let location = statement.location;
+
+        let function = match rule {
+            TranslationRule::Constant => None,
+            _ => Scope::return_from(scope.clone()),
+        }
+        .ok_or(Error::Statement(StatementError::Return(
+            ReturnStatementError::OutsideFunction { location },
+        )))?;
+
+        if function.is_constant {
+            return Err(Error::Statement(StatementError::Return(
+                ReturnStatementError::ConstantFunction {
+                    location,
+                    function: function.identifier,
+                },
+            )));
+        }
+
+        let (result_type, result_location, mut expression) = match statement.expression {
+            Some(expression) => {
+                let location = expression.location;
+                let (element, intermediate) =
+                    ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
+                        .analyze(expression)?;
+                (
+                    Type::from_element(&element, scope)?,
+                    location,
+                    Some(intermediate),
+                )
+            }
+            None => (Type::unit(None), location, None),
+        };
+
+        if result_type.is_none_inferable(&function.output_type) {
+            if let Some(expression) = expression.as_mut() {
+                expression.infer_option(&function.output_type);
+            }
+        } else if result_type != function.output_type {
+            return Err(Error::Element(ElementError::Type(TypeError::Function(
+                FunctionError::ReturnType {
+                    location: result_location,
+                    function: function.identifier,
+                    expected: function.output_type.to_string(),
+                    found: result_type.to_string(),
+                    reference: function.output_location,
+                },
+            ))));
+        }
+
+        Ok(GeneratorReturnStatement::new(location, expression))
+    }
+}
@@ -0,0 +1,206 @@
+//!
+//! The `return` statement tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::analyzer::statement::error::Error as StatementError;
+use crate::semantic::analyzer::statement::r#return::error::Error as ReturnStatementError;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_guard_clause() {
+    let input = r#"
+fn main(x: u8) -> u8 {
+    if x == 0 {
+        return 42;
+    }
+    x * 2
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_return_terminated() {
+    let input = r#"
+fn main(x: u8) -> u8 {
+    if x > 100 {
+        return 100;
+    }
+    let y = x + 1;
+    return y;
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_unit() {
+    let input = r#"
+fn check(x: u8) {
+    if x > 10 {
+        return;
+    }
+    require(x != 5);
+}
+
+fn main(x: u8) {
+    check(x);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_loops() {
+    let input = r#"
+fn find(array: [u8; 4], value: u8) -> u8 {
+    for i in 0..4 {
+        let mut index = 0;
+        #[bound(4)]
+        loop {
+            if array[index] == value {
+                return i;
+            }
+            index += 1;
+        }
+    }
+    255
+}
+
+fn main(x: u8) -> u8 {
+    find([1, 2, 3, 4], x)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_nested_block() {
+    let input = r#"
+fn main(x: u8) -> u8 {
+    let y = {
+        if x > 5 {
+            return 1;
+        }
+        let z = x * 2;
+        z
+    };
+    y + 1
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_type_mismatch() {
+    let input = r#"
+fn main(x: u8) -> u8 {
+    if x == 0 {
+        return true;
+    }
+    x
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ReturnType {
+            location: Location::test(4, 16),
+            function: "main".to_owned(),
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            found: Type::boolean(None).to_string(),
+            reference: Location::test(2, 19),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_type_mismatch_unit() {
+    let input = r#"
+fn main(x: u8) -> u8 {
+    if x == 0 {
+        return;
+    }
+    x
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ReturnType {
+            location: Location::test(4, 9),
+            function: "main".to_owned(),
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            found: Type::unit(None).to_string(),
+            reference: Location::test(2, 19),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_outside_function() {
+    let input = r#"
+const VALUE: u8 = {
+    return 42;
+};
+
+fn main() -> u8 {
+    VALUE
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Return(ReturnStatementError::OutsideFunction {
+            location: Location::test(3, 5),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_constant_function() {
+    let input = r#"
+const fn double(x: u8) -> u8 {
+    if x > 100 {
+        return 255;
+    }
+    x * 2
+}
+
+fn main() -> u8 {
+    double(42)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Return(ReturnStatementError::ConstantFunction {
+            location: Location::test(4, 9),
+            function: "double".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -9,7 +9,10 @@ use zinc_syntax::StructStatement;
 
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::generic::error::Error as GenericError;
+use crate::semantic::element::r#type::generic::Generics;
 use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
+use crate::semantic::element::r#type::structure::template::Template as StructureTemplate;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 use crate::semantic::scope::Scope;
@@ -24,6 +27,10 @@ impl Analyzer {
     /// Defines a compile-time only structure type.
     ///
     pub fn define(scope: Rc<RefCell<Scope>>, statement: StructStatement) -> Result<Type, Error> {
+        if statement.is_generic() {
+            return Self::generic(scope, statement);
+        }
+
         let mut fields: Vec<(String, Type)> = Vec::with_capacity(statement.fields.len());
         for field in statement.fields.into_iter() {
             if fields
@@ -64,4 +71,34 @@ impl Analyzer {
 
         Ok(r#type)
     }
+
+    ///
+    /// Declares a generic structure template, which is analyzed upon each instantiation.
+    ///
+    fn generic(scope: Rc<RefCell<Scope>>, statement: StructStatement) -> Result<Type, Error> {
+        if let Some(parameter) = statement
+            .generics
+            .iter()
+            .find(|parameter| parameter.is_constant())
+        {
+            return Err(Generics::error(GenericError::ConstantParameterForbidden {
+                location: parameter.location,
+                item: statement.identifier.name,
+                name: parameter.identifier.name.to_owned(),
+            }));
+        }
+
+        let generics = Generics::new(
+            statement.identifier.name.clone(),
+            statement.generics.clone(),
+        )
+        .map_err(Generics::error)?;
+
+        Ok(Type::structure_template(
+            statement.location,
+            statement.identifier.name.clone(),
+            StructureTemplate::new(generics, statement, scope.clone()),
+            scope,
+        ))
+    }
 }
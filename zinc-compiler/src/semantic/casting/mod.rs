@@ -45,9 +45,15 @@ impl Caster {
             (Type::IntegerSigned { .. }, Type::IntegerSigned { .. }) => Ok(()),
             (Type::IntegerSigned { .. }, Type::IntegerUnsigned { .. }) => Ok(()),
             (Type::IntegerSigned { .. }, Type::Field(_)) => Ok(()),
-            (Type::Enumeration(_), Type::IntegerSigned { .. }) => Ok(()),
-            (Type::Enumeration(_), Type::IntegerUnsigned { .. }) => Ok(()),
-            (Type::Enumeration(_), Type::Field(_)) => Ok(()),
+            (Type::Enumeration(inner), Type::IntegerSigned { .. }) if !inner.is_data_carrying() => {
+                Ok(())
+            }
+            (Type::Enumeration(inner), Type::IntegerUnsigned { .. })
+                if !inner.is_data_carrying() =>
+            {
+                Ok(())
+            }
+            (Type::Enumeration(inner), Type::Field(_)) if !inner.is_data_carrying() => Ok(()),
             (from, to) if from == to => Ok(()),
 
             (from @ Type::IntegerUnsigned { .. }, to) => Err(Error::CastingToInvalidType {
@@ -9,6 +9,7 @@ pub mod array;
 pub mod boolean;
 pub mod error;
 pub mod integer;
+pub mod option;
 pub mod range;
 pub mod range_inclusive;
 pub mod string;
@@ -46,6 +47,7 @@ use self::array::Array;
 use self::boolean::Boolean;
 use self::error::Error;
 use self::integer::Integer;
+use self::option::Optional;
 use self::range::Range;
 use self::range_inclusive::RangeInclusive;
 use self::string::String;
@@ -78,6 +80,8 @@ pub enum Constant {
     Tuple(Tuple),
     /// The structure type value.
     Structure(Structure),
+    /// The `Option` type value, which is always `None` at compile time.
+    Option(Optional),
 }
 
 impl Constant {
@@ -768,6 +772,7 @@ impl Constant {
             Self::Array(inner) => inner.location = value,
             Self::Tuple(inner) => inner.location = value,
             Self::Structure(inner) => inner.location = value,
+            Self::Option(inner) => inner.location = value,
         }
     }
 
@@ -785,6 +790,7 @@ impl Constant {
             Self::Array(inner) => inner.location,
             Self::Tuple(inner) => inner.location,
             Self::Structure(inner) => inner.location,
+            Self::Option(inner) => inner.location,
         }
     }
 
@@ -811,6 +817,7 @@ impl ITyped for Constant {
             Self::Array(inner) => inner.r#type(),
             Self::Tuple(inner) => inner.r#type(),
             Self::Structure(inner) => inner.r#type(),
+            Self::Option(inner) => inner.r#type(),
         }
     }
 
@@ -832,6 +839,7 @@ impl ITyped for Constant {
             (Self::Structure(inner_1), Self::Structure(inner_2)) => {
                 inner_1.has_the_same_type_as(inner_2)
             }
+            (Self::Option(inner_1), Self::Option(inner_2)) => inner_1.has_the_same_type_as(inner_2),
             _ => false,
         }
     }
@@ -849,6 +857,7 @@ impl fmt::Display for Constant {
             Self::Array(inner) => write!(f, "array {}", inner),
             Self::Tuple(inner) => write!(f, "tuple {}", inner),
             Self::Structure(inner) => write!(f, "structure {}", inner),
+            Self::Option(inner) => write!(f, "option {}", inner),
         }
     }
 }
@@ -0,0 +1,55 @@
+//!
+//! The semantic analyzer constant `Option` element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::option::Optional as OptionalType;
+use crate::semantic::element::r#type::Type;
+
+///
+/// The `None` constant, which is the only compile-time `Option` value.
+///
+/// The payload type is unknown, until it is inferred from the context where `None` is used.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Optional {
+    /// The constant location in the code.
+    pub location: Location,
+    /// The constant type.
+    pub r#type: OptionalType,
+}
+
+impl Optional {
+    /// The intrinsic constant identifier.
+    pub const IDENTIFIER_NONE: &'static str = "None";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_none(location: Location) -> Self {
+        Self {
+            location,
+            r#type: OptionalType::new(None, None),
+        }
+    }
+}
+
+impl ITyped for Optional {
+    fn r#type(&self) -> Type {
+        Type::Option(self.r#type.to_owned())
+    }
+
+    fn has_the_same_type_as(&self, other: &Self) -> bool {
+        self.r#type == other.r#type
+    }
+}
+
+impl fmt::Display for Optional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'", Self::IDENTIFIER_NONE)
+    }
+}
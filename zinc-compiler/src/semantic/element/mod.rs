@@ -35,7 +35,10 @@ use zinc_syntax::Identifier;
 
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::r#type::enumeration::payload::Payload;
+use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::value::enumeration::Enumeration as EnumerationValue;
 use crate::semantic::element::value::error::Error as ValueError;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::item::Item as ScopeItem;
@@ -1916,6 +1919,44 @@ impl Element {
                     found: element.to_string(),
                 }),
             },
+            Element::Type(Type::Function(FunctionType::Variant(function))) => {
+                let fields = match function.payload {
+                    Payload::Structure(ref fields) => fields,
+                    Payload::Tuple(_) => {
+                        return Err(Error::OperatorStructureFirstOperandExpectedType {
+                            location: function.location,
+                            found: function.to_string(),
+                        })
+                    }
+                };
+
+                match other {
+                    Element::Value(Value::Structure(structure)) => {
+                        structure
+                            .validate_fields(
+                                format!(
+                                    "{}::{}",
+                                    function.enumeration.identifier, function.identifier
+                                )
+                                .as_str(),
+                                fields.as_slice(),
+                            )
+                            .map_err(ValueError::Structure)
+                            .map_err(Error::Value)?;
+
+                        Ok(Self::Value(Value::Enumeration(EnumerationValue::new(
+                            structure.location,
+                            function.enumeration,
+                        ))))
+                    }
+                    element => Err(Error::OperatorStructureSecondOperandExpectedLiteral {
+                        location: element
+                            .location()
+                            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        found: element.to_string(),
+                    }),
+                }
+            }
             element => Err(Error::OperatorStructureFirstOperandExpectedType {
                 location: element
                     .location()
@@ -22,4 +22,33 @@ pub enum Error {
         /// The duplicate variant value.
         variant_value: BigInt,
     },
+    /// A variant value is specified in an enumeration, whose variants carry data.
+    VariantValueWithData {
+        /// The variant location.
+        location: Location,
+        /// The enumeration type name.
+        type_identifier: String,
+        /// The variant name.
+        variant_name: String,
+    },
+    /// A variant value is missing in a C-like enumeration, whose variants do not carry data.
+    VariantValueMissing {
+        /// The variant location.
+        location: Location,
+        /// The enumeration type name.
+        type_identifier: String,
+        /// The variant name.
+        variant_name: String,
+    },
+    /// A structure-like variant field with the same name occurs for the second time.
+    DuplicateVariantField {
+        /// The duplicate field location.
+        location: Location,
+        /// The enumeration type name.
+        type_identifier: String,
+        /// The variant name.
+        variant_name: String,
+        /// The duplicate field name.
+        field_name: String,
+    },
 }
@@ -6,6 +6,7 @@
 mod tests;
 
 pub mod error;
+pub mod payload;
 
 use std::cell::RefCell;
 use std::convert::TryFrom;
@@ -16,16 +17,21 @@ use num::BigInt;
 
 use zinc_lexical::Location;
 use zinc_syntax::Variant;
+use zinc_syntax::VariantData;
 
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::variant::Function as VariantFunction;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::Scope;
 
 use self::error::Error;
+use self::payload::Payload;
 
 ///
 /// Describes an enumeration type.
@@ -48,6 +54,8 @@ pub struct Enumeration {
     pub names: Vec<String>,
     /// The ordered list of the variant values.
     pub values: Vec<BigInt>,
+    /// The list of the variant payloads, which is parallel to the variant names.
+    pub payloads: Vec<Option<Payload>>,
     /// The ordered list of the enumeration generic type arguments.
     pub generics: Vec<String>,
     /// The enumeration scope, where its methods and associated items are declared.
@@ -58,6 +66,10 @@ impl Enumeration {
     ///
     /// A shortcut constructor.
     ///
+    /// If any of the variants carries data, the enumeration becomes data-carrying. In such case,
+    /// the variant values are their indexes, which are used as discriminants, and cannot be
+    /// specified explicitly. Otherwise, all the variants must have explicit values.
+    ///
     pub fn new(
         location: Location,
         identifier: String,
@@ -68,12 +80,79 @@ impl Enumeration {
     ) -> Result<Self, SemanticError> {
         let scope = scope.unwrap_or_else(|| Scope::new(identifier.clone(), None).wrap());
 
+        let is_data_carrying = variants.iter().any(|variant| variant.data.is_some());
+
         let mut variants_bigint = Vec::with_capacity(variants.len());
-        for variant in variants.iter() {
-            let value = IntegerConstant::try_from(&variant.literal).map_err(|error| {
-                SemanticError::Element(ElementError::Constant(ConstantError::Integer(error)))
-            })?;
-            variants_bigint.push((variant.identifier.to_owned(), value.value.to_owned()));
+        let mut payloads = Vec::with_capacity(variants.len());
+        for (index, variant) in variants.iter().enumerate() {
+            let value = match (&variant.literal, is_data_carrying) {
+                (Some(literal), false) => {
+                    IntegerConstant::try_from(literal)
+                        .map_err(|error| {
+                            SemanticError::Element(ElementError::Constant(ConstantError::Integer(
+                                error,
+                            )))
+                        })?
+                        .value
+                }
+                (None, true) => BigInt::from(index),
+                (Some(_literal), true) => {
+                    return Err(SemanticError::Element(ElementError::Type(
+                        TypeError::Enumeration(Error::VariantValueWithData {
+                            location: variant.identifier.location,
+                            type_identifier: identifier,
+                            variant_name: variant.identifier.name.to_owned(),
+                        }),
+                    )));
+                }
+                (None, false) => {
+                    return Err(SemanticError::Element(ElementError::Type(
+                        TypeError::Enumeration(Error::VariantValueMissing {
+                            location: variant.identifier.location,
+                            type_identifier: identifier,
+                            variant_name: variant.identifier.name.to_owned(),
+                        }),
+                    )));
+                }
+            };
+            variants_bigint.push((variant.identifier.to_owned(), value));
+
+            let payload = match variant.data {
+                Some(VariantData::Tuple(ref types)) => {
+                    let mut payload_types = Vec::with_capacity(types.len());
+                    for r#type in types.iter() {
+                        payload_types
+                            .push(Type::try_from_syntax(r#type.to_owned(), scope.clone())?);
+                    }
+                    Some(Payload::Tuple(payload_types))
+                }
+                Some(VariantData::Structure(ref fields)) => {
+                    let mut payload_fields: Vec<(String, Type)> = Vec::with_capacity(fields.len());
+                    for field in fields.iter() {
+                        if payload_fields
+                            .iter()
+                            .any(|(name, _type)| name == &field.identifier.name)
+                        {
+                            return Err(SemanticError::Element(ElementError::Type(
+                                TypeError::Enumeration(Error::DuplicateVariantField {
+                                    location: field.location,
+                                    type_identifier: identifier,
+                                    variant_name: variant.identifier.name.to_owned(),
+                                    field_name: field.identifier.name.to_owned(),
+                                }),
+                            )));
+                        }
+
+                        payload_fields.push((
+                            field.identifier.name.to_owned(),
+                            Type::try_from_syntax(field.r#type.to_owned(), scope.clone())?,
+                        ));
+                    }
+                    Some(Payload::Structure(payload_fields))
+                }
+                None => None,
+            };
+            payloads.push(payload);
         }
         let names: Vec<String> = variants_bigint
             .iter()
@@ -117,25 +196,80 @@ impl Enumeration {
             bitlength: minimal_bitlength,
             names,
             values: bigints,
+            payloads: payloads.clone(),
             generics,
             scope: scope.clone(),
         };
 
-        for (identifier, value) in variants_bigint.into_iter() {
+        for ((identifier, value), payload) in variants_bigint.into_iter().zip(payloads) {
             let identifier_location = identifier.location;
 
-            let mut constant =
-                IntegerConstant::new(identifier_location, value, false, minimal_bitlength, false);
+            match payload {
+                Some(payload) => {
+                    let function = VariantFunction::new(
+                        identifier_location,
+                        identifier.name.to_owned(),
+                        enumeration.clone(),
+                        value,
+                        payload,
+                    );
+
+                    Scope::define_type(
+                        scope.clone(),
+                        identifier,
+                        Type::Function(FunctionType::Variant(function)),
+                        true,
+                        None,
+                    )?;
+                }
+                None => {
+                    let mut constant = IntegerConstant::new(
+                        identifier_location,
+                        value,
+                        false,
+                        minimal_bitlength,
+                        false,
+                    );
 
-            constant.set_enumeration(enumeration.clone());
+                    constant.set_enumeration(enumeration.clone());
 
-            Scope::define_variant(scope.clone(), identifier, Constant::Integer(constant))?;
+                    Scope::define_variant(scope.clone(), identifier, Constant::Integer(constant))?;
+                }
+            }
         }
 
         enumeration.values.sort();
 
         Ok(enumeration)
     }
+
+    ///
+    /// Checks whether any of the enumeration variants carries data.
+    ///
+    /// Such enumerations are represented with the payload padded to the largest variant
+    /// payload size, followed by the discriminant.
+    ///
+    pub fn is_data_carrying(&self) -> bool {
+        self.payloads.iter().any(Option::is_some)
+    }
+
+    ///
+    /// The size of the largest variant payload, which is the size of the union-like payload area.
+    ///
+    pub fn payload_size(&self) -> usize {
+        self.payloads
+            .iter()
+            .filter_map(|payload| payload.as_ref().map(Payload::size))
+            .max()
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Returns the variant index by its `name`.
+    ///
+    pub fn variant_index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|variant| variant == name)
+    }
 }
 
 impl PartialEq<Self> for Enumeration {
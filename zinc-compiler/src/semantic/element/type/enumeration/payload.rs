@@ -0,0 +1,68 @@
+//!
+//! The semantic analyzer enumeration variant payload.
+//!
+
+use std::fmt;
+
+use crate::semantic::element::r#type::Type;
+
+///
+/// The data carried by an enumeration variant.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Payload {
+    /// The tuple-like variant payload, e.g. `Transfer(u64, u248)`.
+    Tuple(Vec<Type>),
+    /// The structure-like variant payload, e.g. `Set { key: u8, value: field }`.
+    Structure(Vec<(String, Type)>),
+}
+
+impl Payload {
+    ///
+    /// The payload size in the Zinc VM data stack.
+    ///
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Tuple(types) => types.iter().map(|r#type| r#type.size()).sum(),
+            Self::Structure(fields) => fields.iter().map(|(_name, r#type)| r#type.size()).sum(),
+        }
+    }
+
+    ///
+    /// Returns the payload element types in the declaration order.
+    ///
+    pub fn types(&self) -> Vec<Type> {
+        match self {
+            Self::Tuple(types) => types.to_owned(),
+            Self::Structure(fields) => fields
+                .iter()
+                .map(|(_name, r#type)| r#type.to_owned())
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for Payload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tuple(types) => write!(
+                f,
+                "({})",
+                types
+                    .iter()
+                    .map(|r#type| r#type.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::Structure(fields) => write!(
+                f,
+                " {{ {} }}",
+                fields
+                    .iter()
+                    .map(|(name, r#type)| format!("{}: {}", name, r#type))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
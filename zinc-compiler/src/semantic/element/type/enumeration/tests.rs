@@ -38,3 +38,99 @@ fn main() -> u8 {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_data_carrying() {
+    let input = r#"
+enum Message {
+    Quit,
+    Move(u8, u8),
+    Write { length: u8, checksum: field },
+}
+
+fn main() -> u8 {
+    42
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_variant_value_with_data() {
+    let input = r#"
+enum Message {
+    Quit = 1,
+    Move(u8, u8),
+}
+
+fn main() -> u8 {
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Enumeration(EnumerationTypeError::VariantValueWithData {
+            location: Location::test(3, 5),
+            type_identifier: "Message".to_owned(),
+            variant_name: "Quit".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_variant_value_missing() {
+    let input = r#"
+enum List {
+    A = 1,
+    B,
+}
+
+fn main() -> u8 {
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Enumeration(EnumerationTypeError::VariantValueMissing {
+            location: Location::test(4, 5),
+            type_identifier: "List".to_owned(),
+            variant_name: "B".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_duplicate_variant_field() {
+    let input = r#"
+enum Message {
+    Quit,
+    Write { length: u8, length: field },
+}
+
+fn main() -> u8 {
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Enumeration(EnumerationTypeError::DuplicateVariantField {
+            location: Location::test(4, 25),
+            type_identifier: "Message".to_owned(),
+            variant_name: "Write".to_owned(),
+            field_name: "length".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -5,6 +5,8 @@
 use crate::semantic::element::r#type::contract::error::Error as ContractTypeError;
 use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::generic::error::Error as GenericError;
+use crate::semantic::element::r#type::option::error::Error as OptionTypeError;
 use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
 use zinc_lexical::Location;
 
@@ -41,6 +43,11 @@ pub enum Error {
         /// The found type.
         found: String,
     },
+    /// The `fn(..) -> ..` type is only allowed for function arguments.
+    FunctionTypeOutsideArgument {
+        /// The error location.
+        location: Location,
+    },
 
     /// The function type error. See the inner element description.
     Function(FunctionError),
@@ -50,4 +57,8 @@ pub enum Error {
     Enumeration(EnumerationTypeError),
     /// The contract type error. See the inner element description.
     Contract(ContractTypeError),
+    /// The `Option` type error. See the inner element description.
+    Option(OptionTypeError),
+    /// The generic item error. See the inner element description.
+    Generic(GenericError),
 }
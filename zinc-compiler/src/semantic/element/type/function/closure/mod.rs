@@ -0,0 +1,240 @@
+//!
+//! The semantic analyzer closure function element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::BindingPatternVariant;
+use zinc_syntax::ClosureExpression;
+
+use crate::generator::expression::Expression as GeneratorExpression;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::binding::Binder;
+use crate::semantic::binding::Binding;
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::memory_type::MemoryType;
+use crate::semantic::scope::Scope;
+
+///
+/// The semantic analyzer closure function element.
+///
+/// Closures are not values and do not exist at runtime. Instead, the closure body is analyzed
+/// anew at each call site, where the argument types are taken from the actual arguments, and
+/// its intermediate representation is inlined into the caller.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the closure is referenced.
+    pub location: Location,
+    /// The closure identifier, which is used in error messages.
+    pub identifier: String,
+    /// The unique closure type ID.
+    pub type_id: usize,
+    /// The closure syntax representation.
+    pub expression: ClosureExpression,
+    /// The scope, where the closure is declared.
+    pub scope: Rc<RefCell<Scope>>,
+    /// The argument types, if the closure is bound to a function-typed parameter.
+    pub argument_types: Option<Vec<Type>>,
+    /// The result type, if the closure is bound to a function-typed parameter.
+    pub return_type: Option<Box<Type>>,
+}
+
+impl Function {
+    /// The closure identifier used in error messages.
+    pub const IDENTIFIER: &'static str = "<closure>";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        type_id: usize,
+        expression: ClosureExpression,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        Self {
+            location,
+            identifier: Self::IDENTIFIER.to_owned(),
+            type_id,
+            expression,
+            scope,
+            argument_types: None,
+            return_type: None,
+        }
+    }
+
+    ///
+    /// Binds the closure to a function-typed parameter with the specified signature.
+    ///
+    /// The bound closure is called from another function, so its body cannot capture the local
+    /// variables of the function, where the closure is declared.
+    ///
+    pub fn bind(
+        mut self,
+        identifier: String,
+        argument_types: Vec<Type>,
+        return_type: Type,
+    ) -> Self {
+        self.identifier = identifier;
+        self.argument_types = Some(argument_types);
+        self.return_type = Some(Box::new(return_type));
+        self
+    }
+
+    ///
+    /// Whether the closure is bound to a function-typed parameter.
+    ///
+    pub fn is_bound(&self) -> bool {
+        self.argument_types.is_some()
+    }
+
+    ///
+    /// Calls the closure with the `argument_list`, validating the call.
+    ///
+    /// Returns the result type, the argument bindings, which are stored from the evaluation stack
+    /// before the body is evaluated, and the body intermediate representation.
+    ///
+    pub fn call(
+        self,
+        argument_list: ArgumentList,
+    ) -> Result<(Type, Vec<Binding>, GeneratorExpression), SemanticError> {
+        let scope = Scope::new_closure(
+            self.identifier.to_owned(),
+            self.scope.clone(),
+            !self.is_bound(),
+        );
+
+        if argument_list.arguments.len() != self.expression.arguments.len() {
+            return Err(Self::error(Error::ArgumentCount {
+                location: self.location,
+                function: self.identifier.to_owned(),
+                expected: self.expression.arguments.len(),
+                found: argument_list.arguments.len(),
+                reference: Some(argument_list.location),
+            }));
+        }
+
+        let mut bindings = Vec::with_capacity(argument_list.arguments.len());
+        for (index, (binding, element)) in self
+            .expression
+            .arguments
+            .into_iter()
+            .zip(argument_list.arguments.into_iter())
+            .enumerate()
+        {
+            let location = element.location();
+
+            let actual = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Self::error(Error::ArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    }))
+                }
+            };
+
+            let mut expected = Vec::with_capacity(2);
+            if let Some(r#type) = binding.r#type {
+                expected.push(Type::try_from_syntax(r#type, self.scope.clone())?);
+            }
+            if let Some(r#type) = self
+                .argument_types
+                .as_ref()
+                .and_then(|types| types.get(index))
+            {
+                expected.push(r#type.to_owned());
+            }
+            for expected in expected.into_iter() {
+                if actual != expected {
+                    return Err(Self::error(Error::ArgumentType {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        name: match binding.pattern.variant {
+                            BindingPatternVariant::Binding { ref identifier, .. } => {
+                                identifier.name.to_owned()
+                            }
+                            _ => index.to_string(),
+                        },
+                        position: index + 1,
+                        expected: expected.to_string(),
+                        found: actual.to_string(),
+                    }));
+                }
+            }
+
+            bindings.extend(Binder::bind_variables(
+                binding.pattern,
+                actual,
+                MemoryType::Stack,
+                scope.clone(),
+            )?);
+        }
+
+        let body_location = self.expression.body.location;
+        let (element, body) = ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
+            .analyze(*self.expression.body)?;
+        scope.borrow().lint_variables();
+        let r#type = Type::from_element(&element, scope)?;
+
+        if let Some(expected) = self.return_type {
+            if r#type != *expected {
+                return Err(Self::error(Error::ReturnType {
+                    location: body_location,
+                    function: self.identifier,
+                    expected: expected.to_string(),
+                    found: r#type.to_string(),
+                    reference: self.expression.location,
+                }));
+            }
+        }
+
+        Ok((r#type, bindings, body))
+    }
+
+    ///
+    /// Wraps the function error into the semantic one.
+    ///
+    fn error(error: Error) -> SemanticError {
+        SemanticError::Element(ElementError::Type(TypeError::Function(error)))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "|{}|",
+            self.expression
+                .arguments
+                .iter()
+                .map(|binding| match binding.pattern.variant {
+                    BindingPatternVariant::Binding { ref identifier, .. } => {
+                        identifier.name.to_owned()
+                    }
+                    BindingPatternVariant::BindingList { .. } => "(..)".to_owned(),
+                    BindingPatternVariant::Wildcard => "_".to_owned(),
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
@@ -0,0 +1,248 @@
+//!
+//! The closure function tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::analyzer::statement::error::Error as StatementError;
+use crate::semantic::analyzer::statement::r#return::error::Error as ReturnStatementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::Error as ElementError;
+use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::error::Error as ScopeError;
+
+#[test]
+fn ok_inline() {
+    let input = r#"
+fn main(x: u8) -> u8 {
+    (|a, b| a * b)(x, 2)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_capturing() {
+    let input = r#"
+fn main(x: u8) -> u8 {
+    let offset: u8 = 3;
+    let add = |a: u8| a + offset;
+    add(x) + add(1)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_function_argument() {
+    let input = r#"
+fn apply(x: u8, f: fn(u8) -> u8) -> u8 {
+    f(x)
+}
+
+fn main(x: u8) -> u8 {
+    let double = |a| a * 2;
+    apply(x, double) + apply(x, |a| a + 1)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_function_argument_generic() {
+    let input = r#"
+fn twice<T>(x: T, f: fn(T) -> T) -> T {
+    f(f(x))
+}
+
+fn increment(x: u8) -> u8 {
+    x + 1
+}
+
+fn main(x: u8) -> u8 {
+    twice(x, increment)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_argument_count() {
+    let input = r#"
+fn main(x: u8) -> u8 {
+    let add = |a: u8, b: u8| a + b;
+    add(x)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(4, 5),
+            function: "<closure>".to_owned(),
+            expected: 2,
+            found: 1,
+            reference: Some(Location::test(4, 8)),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_type() {
+    let input = r#"
+fn main(x: u8) -> u8 {
+    let negate = |a: bool| !a;
+    negate(x)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(4, 12),
+            function: "<closure>".to_owned(),
+            name: "a".to_owned(),
+            position: 1,
+            expected: "bool".to_owned(),
+            found: "u8".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_return_type() {
+    let input = r#"
+fn apply(x: u8, f: fn(u8) -> u8) -> u8 {
+    f(x)
+}
+
+fn main(x: u8) -> u8 {
+    apply(x, |a| a == 0)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ReturnType {
+            location: Location::test(7, 20),
+            function: "f".to_owned(),
+            expected: "u8".to_owned(),
+            found: "bool".to_owned(),
+            reference: Location::test(7, 14),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_function_signature() {
+    let input = r#"
+fn apply(x: u8, f: fn(u8) -> u8) -> u8 {
+    f(x)
+}
+
+fn check(x: u8) -> bool {
+    x == 0
+}
+
+fn main(x: u8) -> u8 {
+    apply(x, check)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(11, 10),
+            function: "apply".to_owned(),
+            name: "f".to_owned(),
+            position: 2,
+            expected: "fn(u8) -> u8".to_owned(),
+            found: "fn check(x: u8) -> bool".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_capture_passed_to_function() {
+    let input = r#"
+fn apply(x: u8, f: fn(u8) -> u8) -> u8 {
+    f(x)
+}
+
+fn main(x: u8) -> u8 {
+    let offset: u8 = 3;
+    apply(x, |a| a + offset)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        ScopeError::ClosureCapture {
+            location: Location::test(8, 22),
+            name: "offset".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_return_in_closure() {
+    let input = r#"
+fn main(x: u8) -> u8 {
+    let f = |a: u8| {
+        return a;
+    };
+    f(x)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Return(ReturnStatementError::OutsideFunction {
+            location: Location::test(4, 9),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_function_type_outside_argument() {
+    let input = r#"
+fn main(x: u8) -> u8 {
+    let f: fn(u8) -> u8 = |a| a;
+    f(x)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::FunctionTypeOutsideArgument {
+            location: Location::test(3, 12),
+        },
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,439 @@
+//!
+//! The semantic analyzer generic function element.
+//!
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::Binding;
+use zinc_syntax::BindingPatternVariant;
+use zinc_syntax::FnStatement;
+use zinc_syntax::Type as SyntaxType;
+use zinc_syntax::TypeVariant as SyntaxTypeVariant;
+
+use crate::generator::statement::Statement as GeneratorStatement;
+use crate::semantic::analyzer::statement::r#fn::Analyzer as FnStatementAnalyzer;
+use crate::semantic::analyzer::statement::r#fn::Context as FnStatementAnalyzerContext;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::generic::Argument as GenericArgument;
+use crate::semantic::element::r#type::generic::Generics;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::error::Error as ScopeError;
+use crate::semantic::scope::Scope;
+
+///
+/// The generic function instance, which is analyzed once for each distinct argument list.
+///
+#[derive(Debug, Clone)]
+pub struct Instance {
+    /// The inferred generic arguments.
+    pub arguments: Vec<GenericArgument>,
+    /// The type IDs of the functions passed to the function-typed arguments.
+    pub functions: Vec<Option<usize>>,
+    /// The instance function type. `None` while the instance is being analyzed.
+    pub function: Option<FunctionType>,
+    /// The instance intermediate representation, if exists.
+    pub intermediate: Option<GeneratorStatement>,
+}
+
+///
+/// The semantic analyzer generic function element.
+///
+/// The function body is not analyzed until the function is called. Each call infers the generic
+/// arguments from the actual argument types and instantiates an ordinar function, which is
+/// cached and shared between all calls with the same generic arguments.
+///
+/// Functions with function-typed arguments are also instantiated this way. The passed closures
+/// and functions are bound to the argument names in the instance scope, so each distinct set of
+/// passed functions produces a separate instance, where the closures are inlined.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Location,
+    /// The function identifier.
+    pub identifier: String,
+    /// The unique function type ID.
+    pub type_id: usize,
+    /// The function generic parameters.
+    pub generics: Generics,
+    /// The function syntax representation, which is analyzed upon each instantiation.
+    pub statement: FnStatement,
+    /// The context, where the function is declared.
+    pub context: FnStatementAnalyzerContext,
+    /// The scope, where the function is declared.
+    pub scope: Rc<RefCell<Scope>>,
+    /// The function instances, which are shared between the function type copies.
+    pub instances: Rc<RefCell<Vec<Instance>>>,
+}
+
+impl Function {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        identifier: String,
+        type_id: usize,
+        generics: Generics,
+        statement: FnStatement,
+        context: FnStatementAnalyzerContext,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        Self {
+            location,
+            identifier,
+            type_id,
+            generics,
+            statement,
+            context,
+            scope,
+            instances: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    ///
+    /// Whether the function must be called from mutable context.
+    ///
+    pub fn is_mutable(&self) -> bool {
+        match self
+            .statement
+            .argument_bindings
+            .first()
+            .map(|binding| &binding.pattern.variant)
+        {
+            Some(BindingPatternVariant::Binding {
+                identifier,
+                is_mutable,
+            }) => identifier.is_self_lowercase() && *is_mutable,
+            _ => false,
+        }
+    }
+
+    ///
+    /// Infers the generic arguments from the actual argument `types` and returns the function
+    /// instance, analyzing it if it has not been instantiated yet.
+    ///
+    /// The `location` is the call location, and the `reference` is the argument list one.
+    ///
+    pub fn instantiate(
+        &self,
+        location: Location,
+        reference: Location,
+        types: Vec<Type>,
+    ) -> Result<FunctionType, SemanticError> {
+        if types.len() != self.statement.argument_bindings.len() {
+            return Err(SemanticError::Element(ElementError::Type(
+                TypeError::Function(Error::ArgumentCount {
+                    location: self.location,
+                    function: self.identifier.to_owned(),
+                    expected: self.statement.argument_bindings.len(),
+                    found: types.len(),
+                    reference: Some(reference),
+                }),
+            )));
+        }
+
+        let mut inferred = HashMap::with_capacity(self.generics.parameters.len());
+        for (binding, r#type) in self.statement.argument_bindings.iter().zip(types.iter()) {
+            if let Some(ref formal) = binding.r#type {
+                self.generics
+                    .infer(location, formal, r#type, &mut inferred)
+                    .map_err(Generics::error)?;
+            }
+        }
+        let arguments = self
+            .generics
+            .collect(location, inferred)
+            .map_err(Generics::error)?;
+
+        let functions: Vec<Option<usize>> = self
+            .statement
+            .argument_bindings
+            .iter()
+            .zip(types.iter())
+            .filter(|(binding, _type)| Self::is_function_binding(binding))
+            .map(|(_binding, r#type)| match r#type {
+                Type::Function(FunctionType::Runtime(function)) => Some(function.type_id),
+                Type::Function(FunctionType::Generic(function)) => Some(function.type_id),
+                Type::Function(FunctionType::Closure(function)) => Some(function.type_id),
+                _ => None,
+            })
+            .collect();
+
+        if let Some(instance) = self
+            .instances
+            .borrow()
+            .iter()
+            .find(|instance| instance.arguments == arguments && instance.functions == functions)
+        {
+            return match instance.function {
+                Some(ref function) => Ok(function.to_owned()),
+                None => Err(SemanticError::Scope(ScopeError::ReferenceLoop { location })),
+            };
+        }
+
+        self.instances.borrow_mut().push(Instance {
+            arguments: arguments.clone(),
+            functions: functions.clone(),
+            function: None,
+            intermediate: None,
+        });
+
+        let result = self.define_instance(location, arguments.as_slice(), types);
+
+        let mut instances = self.instances.borrow_mut();
+        let index = instances
+            .iter()
+            .position(|instance| instance.arguments == arguments && instance.functions == functions)
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        match result {
+            Ok((function, intermediate)) => {
+                instances[index].function = Some(function.clone());
+                instances[index].intermediate = intermediate;
+                Ok(function)
+            }
+            Err(error) => {
+                instances.remove(index);
+                Err(error)
+            }
+        }
+    }
+
+    ///
+    /// Extracts the intermediate representation of all the function instances.
+    ///
+    pub fn get_intermediate(&self) -> Vec<GeneratorStatement> {
+        self.instances
+            .borrow()
+            .iter()
+            .filter_map(|instance| instance.intermediate.to_owned())
+            .collect()
+    }
+
+    ///
+    /// Analyzes the function instance with the generic parameters bound to the `arguments`.
+    ///
+    /// The function-typed arguments are removed from the instance signature, and the passed
+    /// functions from `types` are declared in the instance scope instead.
+    ///
+    fn define_instance(
+        &self,
+        location: Location,
+        arguments: &[GenericArgument],
+        types: Vec<Type>,
+    ) -> Result<(FunctionType, Option<GeneratorStatement>), SemanticError> {
+        let scope = self
+            .generics
+            .define_arguments(location, self.scope.clone(), arguments)?;
+
+        let mut statement = self.statement.clone();
+        statement.identifier.name = self.generics.instance_identifier(arguments);
+        statement.generics.clear();
+
+        let mut bindings = Vec::with_capacity(statement.argument_bindings.len());
+        for (index, (binding, r#type)) in statement
+            .argument_bindings
+            .drain(..)
+            .zip(types.into_iter())
+            .enumerate()
+        {
+            let (argument_types, return_type) = match binding.r#type {
+                Some(SyntaxType {
+                    variant:
+                        SyntaxTypeVariant::Function {
+                            ref arguments,
+                            ref return_type,
+                        },
+                    ..
+                }) => (arguments.to_owned(), return_type.to_owned()),
+                _ => {
+                    bindings.push(binding);
+                    continue;
+                }
+            };
+
+            let mut expected_arguments = Vec::with_capacity(argument_types.len());
+            for argument_type in argument_types.into_iter() {
+                expected_arguments.push(Type::try_from_syntax(argument_type, scope.clone())?);
+            }
+            let expected_return = Type::try_from_syntax(*return_type, scope.clone())?;
+
+            let identifier = match binding.pattern.variant {
+                BindingPatternVariant::Binding { identifier, .. } => Some(identifier),
+                _ => None,
+            };
+
+            let function = self.bind_function(
+                location,
+                identifier
+                    .as_ref()
+                    .map(|identifier| identifier.name.to_owned())
+                    .unwrap_or_else(|| index.to_string()),
+                index + 1,
+                expected_arguments,
+                expected_return,
+                r#type,
+            )?;
+
+            if let Some(identifier) = identifier {
+                Scope::define_type(
+                    scope.clone(),
+                    identifier,
+                    Type::Function(function),
+                    false,
+                    None,
+                )?;
+            }
+        }
+        statement.argument_bindings = bindings;
+
+        match FnStatementAnalyzer::define(scope, statement, self.context)? {
+            (Type::Function(function), intermediate) => {
+                Ok((function, intermediate.map(GeneratorStatement::Fn)))
+            }
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
+
+    ///
+    /// Checks the function passed to the function-typed argument against the argument
+    /// signature and returns the function to be declared in the instance scope.
+    ///
+    /// Closures are bound to the signature and type-checked at their call sites. Generic
+    /// functions are instantiated with the signature argument types.
+    ///
+    fn bind_function(
+        &self,
+        location: Location,
+        name: String,
+        position: usize,
+        argument_types: Vec<Type>,
+        return_type: Type,
+        r#type: Type,
+    ) -> Result<FunctionType, SemanticError> {
+        let expected = format!(
+            "fn({}) -> {}",
+            argument_types
+                .iter()
+                .map(|r#type| r#type.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            return_type,
+        );
+
+        let function = match r#type {
+            Type::Function(FunctionType::Closure(function)) => {
+                return Ok(FunctionType::Closure(function.bind(
+                    name,
+                    argument_types,
+                    return_type,
+                )))
+            }
+            Type::Function(FunctionType::Generic(function)) => {
+                match function.instantiate(location, location, argument_types.clone())? {
+                    FunctionType::Runtime(function) => function,
+                    function => {
+                        return Err(Self::argument_type_error(
+                            location,
+                            self.identifier.to_owned(),
+                            name,
+                            position,
+                            expected,
+                            function.to_string(),
+                        ))
+                    }
+                }
+            }
+            Type::Function(FunctionType::Runtime(function)) => function,
+            r#type => {
+                return Err(Self::argument_type_error(
+                    location,
+                    self.identifier.to_owned(),
+                    name,
+                    position,
+                    expected,
+                    r#type.to_string(),
+                ))
+            }
+        };
+
+        let is_signature_matching = function.bindings.len() == argument_types.len()
+            && function
+                .bindings
+                .iter()
+                .zip(argument_types.iter())
+                .all(|(binding, r#type)| &binding.r#type == r#type)
+            && function.return_type.as_ref() == &return_type;
+        if !is_signature_matching {
+            return Err(Self::argument_type_error(
+                location,
+                self.identifier.to_owned(),
+                name,
+                position,
+                expected,
+                function.to_string(),
+            ));
+        }
+
+        Ok(FunctionType::Runtime(function))
+    }
+
+    ///
+    /// Whether the argument is function-typed and is bound at instantiation.
+    ///
+    fn is_function_binding(binding: &Binding) -> bool {
+        matches!(
+            binding.r#type,
+            Some(SyntaxType {
+                variant: SyntaxTypeVariant::Function { .. },
+                ..
+            })
+        )
+    }
+
+    ///
+    /// Creates the function-typed argument mismatch error.
+    ///
+    fn argument_type_error(
+        location: Location,
+        function: String,
+        name: String,
+        position: usize,
+        expected: String,
+        found: String,
+    ) -> SemanticError {
+        SemanticError::Element(ElementError::Type(TypeError::Function(
+            Error::ArgumentType {
+                location,
+                function,
+                name,
+                position,
+                expected,
+                found,
+            },
+        )))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.generics.parameters.is_empty() {
+            return write!(f, "fn {}(..)", self.identifier);
+        }
+
+        write!(
+            f,
+            "fn {}<{}>(..)",
+            self.identifier,
+            self.generics.names().join(", "),
+        )
+    }
+}
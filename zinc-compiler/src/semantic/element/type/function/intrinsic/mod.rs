@@ -8,6 +8,7 @@ mod tests;
 pub mod debug;
 pub mod error;
 pub mod require;
+pub mod some;
 pub mod stdlib;
 pub mod zksync;
 
@@ -19,9 +20,17 @@ use zinc_lexical::Location;
 
 use self::debug::Function as DebugFunction;
 use self::require::Function as RequireFunction;
+use self::some::Function as SomeFunction;
+use self::stdlib::array_contains::Function as StdArrayContainsFunction;
+use self::stdlib::array_fold::Function as StdArrayFoldFunction;
+use self::stdlib::array_index_of::Function as StdArrayIndexOfFunction;
+use self::stdlib::array_map::Function as StdArrayMapFunction;
 use self::stdlib::array_pad::Function as StdArrayPadFunction;
 use self::stdlib::array_reverse::Function as StdArrayReverseFunction;
+use self::stdlib::array_sort::Function as StdArraySortFunction;
+use self::stdlib::array_sum::Function as StdArraySumFunction;
 use self::stdlib::array_truncate::Function as StdArrayTruncateFunction;
+use self::stdlib::array_zip::Function as StdArrayZipFunction;
 use self::stdlib::collections_mtreemap_contains::Function as StdCollectionsMTreeMapContainsFunction;
 use self::stdlib::collections_mtreemap_get::Function as StdCollectionsMTreeMapGetFunction;
 use self::stdlib::collections_mtreemap_insert::Function as StdCollectionsMTreeMapInsertFunction;
@@ -31,10 +40,13 @@ use self::stdlib::convert_from_bits_signed::Function as StdConvertFromBitsSigned
 use self::stdlib::convert_from_bits_unsigned::Function as StdConvertFromBitsUnsignedFunction;
 use self::stdlib::convert_to_bits::Function as StdConvertToBitsFunction;
 use self::stdlib::crypto_pedersen::Function as StdConvertPedersenFunction;
+use self::stdlib::crypto_poseidon::Function as StdCryptoPoseidonFunction;
+use self::stdlib::crypto_rescue::Function as StdCryptoRescueFunction;
 use self::stdlib::crypto_schnorr_signature_verify::Function as StdCryptoSchnorrSignatureVerifyFunction;
 use self::stdlib::crypto_sha256::Function as StdCryptoSha256Function;
 use self::stdlib::ff_invert::Function as StdFfInvertFunction;
 use self::stdlib::Function as StandardLibraryFunction;
+use self::zksync::emit::Function as ZkSyncEmitFunction;
 use self::zksync::transfer::Function as ZkSyncTransferFunction;
 use self::zksync::Function as ZkSyncLibraryFunction;
 
@@ -47,6 +59,8 @@ pub enum Function {
     Require(RequireFunction),
     /// The `dbg!(...)` function. See the inner element description.
     Debug(DebugFunction),
+    /// The `Some(...)` function. See the inner element description.
+    Some(SomeFunction),
     /// The standard library function. See the inner element description.
     StandardLibrary(StandardLibraryFunction),
     /// The zkSync library function. See the inner element description.
@@ -68,6 +82,13 @@ impl Function {
         Self::Debug(DebugFunction::default())
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_some() -> Self {
+        Self::Some(SomeFunction::default())
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -79,6 +100,12 @@ impl Function {
             LibraryFunctionIdentifier::CryptoPedersen => Self::StandardLibrary(
                 StandardLibraryFunction::CryptoPedersen(StdConvertPedersenFunction::default()),
             ),
+            LibraryFunctionIdentifier::CryptoPoseidon => Self::StandardLibrary(
+                StandardLibraryFunction::CryptoPoseidon(StdCryptoPoseidonFunction::default()),
+            ),
+            LibraryFunctionIdentifier::CryptoRescue => Self::StandardLibrary(
+                StandardLibraryFunction::CryptoRescue(StdCryptoRescueFunction::default()),
+            ),
             LibraryFunctionIdentifier::CryptoSchnorrSignatureVerify => {
                 Self::StandardLibrary(StandardLibraryFunction::CryptoSchnorrSignatureVerify(
                     StdCryptoSchnorrSignatureVerifyFunction::default(),
@@ -113,6 +140,27 @@ impl Function {
             LibraryFunctionIdentifier::ArrayPad => Self::StandardLibrary(
                 StandardLibraryFunction::ArrayPad(StdArrayPadFunction::default()),
             ),
+            LibraryFunctionIdentifier::ArrayMap => Self::StandardLibrary(
+                StandardLibraryFunction::ArrayMap(StdArrayMapFunction::default()),
+            ),
+            LibraryFunctionIdentifier::ArrayFold => Self::StandardLibrary(
+                StandardLibraryFunction::ArrayFold(StdArrayFoldFunction::default()),
+            ),
+            LibraryFunctionIdentifier::ArrayZip => Self::StandardLibrary(
+                StandardLibraryFunction::ArrayZip(StdArrayZipFunction::default()),
+            ),
+            LibraryFunctionIdentifier::ArrayContains => Self::StandardLibrary(
+                StandardLibraryFunction::ArrayContains(StdArrayContainsFunction::default()),
+            ),
+            LibraryFunctionIdentifier::ArrayIndexOf => Self::StandardLibrary(
+                StandardLibraryFunction::ArrayIndexOf(StdArrayIndexOfFunction::default()),
+            ),
+            LibraryFunctionIdentifier::ArraySum => Self::StandardLibrary(
+                StandardLibraryFunction::ArraySum(StdArraySumFunction::default()),
+            ),
+            LibraryFunctionIdentifier::ArraySort => Self::StandardLibrary(
+                StandardLibraryFunction::ArraySort(StdArraySortFunction::default()),
+            ),
 
             LibraryFunctionIdentifier::FfInvert => Self::StandardLibrary(
                 StandardLibraryFunction::FfInvert(StdFfInvertFunction::default()),
@@ -121,6 +169,9 @@ impl Function {
             LibraryFunctionIdentifier::ZksyncTransfer => Self::ZkSyncLibrary(
                 ZkSyncLibraryFunction::Transfer(ZkSyncTransferFunction::default()),
             ),
+            LibraryFunctionIdentifier::ZksyncEmit => Self::ZkSyncLibrary(
+                ZkSyncLibraryFunction::Emit(ZkSyncEmitFunction::default()),
+            ),
 
             LibraryFunctionIdentifier::CollectionsMTreeMapGet => {
                 Self::StandardLibrary(StandardLibraryFunction::CollectionsMTreeMapGet(
@@ -159,6 +210,7 @@ impl Function {
         match self {
             Self::Require(_) => false,
             Self::Debug(_) => false,
+            Self::Some(_) => false,
             Self::StandardLibrary(inner) => inner.is_mutable(),
             Self::ZkSyncLibrary(inner) => inner.is_mutable(),
         }
@@ -171,6 +223,7 @@ impl Function {
         match self {
             Self::Require(inner) => inner.identifier,
             Self::Debug(inner) => inner.identifier,
+            Self::Some(inner) => inner.identifier,
             Self::StandardLibrary(inner) => inner.identifier(),
             Self::ZkSyncLibrary(inner) => inner.identifier(),
         }
@@ -183,6 +236,7 @@ impl Function {
         match self {
             Self::Require(inner) => inner.location = Some(location),
             Self::Debug(inner) => inner.location = Some(location),
+            Self::Some(inner) => inner.location = Some(location),
             Self::StandardLibrary(inner) => inner.set_location(location),
             Self::ZkSyncLibrary(inner) => inner.set_location(location),
         }
@@ -195,6 +249,7 @@ impl Function {
         match self {
             Self::Require(inner) => inner.location,
             Self::Debug(inner) => inner.location,
+            Self::Some(inner) => inner.location,
             Self::StandardLibrary(inner) => inner.location(),
             Self::ZkSyncLibrary(inner) => inner.location(),
         }
@@ -206,6 +261,7 @@ impl fmt::Display for Function {
         match self {
             Self::Require(inner) => write!(f, "{}", inner),
             Self::Debug(inner) => write!(f, "{}", inner),
+            Self::Some(inner) => write!(f, "{}", inner),
             Self::StandardLibrary(inner) => write!(f, "std::{}", inner),
             Self::ZkSyncLibrary(inner) => write!(f, "zksync::{}", inner),
         }
@@ -0,0 +1,114 @@
+//!
+//! The intrinsic function tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::function::intrinsic::some::Function as SomeFunction;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Error as ElementError;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_value() {
+    let input = r#"
+fn main(value: u8) -> Option<u8> {
+    Some(value)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_structure() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: field,
+}
+
+fn main() -> Option<Data> {
+    Some(Data { a: 1, b: 2 as field })
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    let value = Some();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(3, 17),
+            function: SomeFunction::IDENTIFIER.to_owned(),
+            expected: SomeFunction::ARGUMENT_COUNT,
+            found: SomeFunction::ARGUMENT_COUNT - 1,
+            reference: None,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_count_greater() {
+    let input = r#"
+fn main() {
+    let value = Some(1, 2);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(3, 17),
+            function: SomeFunction::IDENTIFIER.to_owned(),
+            expected: SomeFunction::ARGUMENT_COUNT,
+            found: SomeFunction::ARGUMENT_COUNT + 1,
+            reference: None,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_type() {
+    let input = r#"
+fn main() {
+    let value = Some(0..10);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(3, 22),
+            function: SomeFunction::IDENTIFIER.to_owned(),
+            name: "value".to_owned(),
+            position: SomeFunction::ARGUMENT_INDEX_VALUE + 1,
+            expected: "{value}".to_owned(),
+            found: Type::range(
+                None,
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+            )
+            .to_string(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,190 @@
+//!
+//! The semantic analyzer standard library `std::array::fold` function element.
+//!
+
+use std::fmt;
+use std::ops::Deref;
+
+use zinc_build::LibraryFunctionIdentifier;
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The semantic analyzer standard library `std::array::fold` function element.
+///
+/// The function is not called at runtime. Instead, the folding function is inlined by the
+/// compiler for each array element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The unique intrinsic function identifier.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            library_identifier: LibraryFunctionIdentifier::ArrayFold,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "fold";
+
+    /// The position of the `array` argument in the function argument list.
+    pub const ARGUMENT_INDEX_ARRAY: usize = 0;
+
+    /// The position of the `initial` argument in the function argument list.
+    pub const ARGUMENT_INDEX_INITIAL: usize = 1;
+
+    /// The position of the `f` argument in the function argument list.
+    pub const ARGUMENT_INDEX_FUNCTION: usize = 2;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 3;
+
+    ///
+    /// Validates the call with the `argument_list`.
+    ///
+    /// Returns the array element type, the array size, the accumulator type, and the folding
+    /// function, which must be called for each array element by the caller.
+    ///
+    pub fn call(
+        self,
+        location: Location,
+        argument_list: ArgumentList,
+    ) -> Result<(Type, usize, Type, FunctionType), Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let element_location = element.location();
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                Element::Type(r#type @ Type::Function(_)) => r#type,
+                element => {
+                    return Err(Error::ArgumentNotEvaluable {
+                        location: element_location.unwrap_or(location),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, element_location));
+        }
+
+        let (element_type, size) = match actual_params.get(Self::ARGUMENT_INDEX_ARRAY) {
+            Some((Type::Array(array), _location)) => (array.r#type.deref().to_owned(), array.size),
+            Some((r#type, argument_location)) => {
+                return Err(Error::ArgumentType {
+                    location: argument_location.unwrap_or(location),
+                    function: self.identifier.to_owned(),
+                    name: "array".to_owned(),
+                    position: Self::ARGUMENT_INDEX_ARRAY + 1,
+                    expected: "[T; N]".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::ArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        let accumulator_type = match actual_params.get(Self::ARGUMENT_INDEX_INITIAL) {
+            Some((r#type, _location)) if r#type.is_instantiatable(false) => r#type.to_owned(),
+            Some((r#type, argument_location)) => {
+                return Err(Error::ArgumentType {
+                    location: argument_location.unwrap_or(location),
+                    function: self.identifier.to_owned(),
+                    name: "initial".to_owned(),
+                    position: Self::ARGUMENT_INDEX_INITIAL + 1,
+                    expected: "A".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::ArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        let function = match actual_params.get(Self::ARGUMENT_INDEX_FUNCTION) {
+            Some((Type::Function(function @ FunctionType::Runtime(_)), _location))
+            | Some((Type::Function(function @ FunctionType::Generic(_)), _location))
+            | Some((Type::Function(function @ FunctionType::Closure(_)), _location)) => {
+                function.to_owned()
+            }
+            Some((r#type, argument_location)) => {
+                return Err(Error::ArgumentType {
+                    location: argument_location.unwrap_or(location),
+                    function: self.identifier.to_owned(),
+                    name: "f".to_owned(),
+                    position: Self::ARGUMENT_INDEX_FUNCTION + 1,
+                    expected: format!(
+                        "fn({}, {}) -> {}",
+                        accumulator_type, element_type, accumulator_type
+                    ),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::ArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::ArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        Ok((element_type, size, accumulator_type, function))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "array::{}(array: [T; N], initial: A, f: fn(A, T) -> A) -> A",
+            self.identifier,
+        )
+    }
+}
@@ -0,0 +1,165 @@
+//!
+//! The semantic analyzer standard library `std::array::zip` function element.
+//!
+
+use std::fmt;
+use std::ops::Deref;
+
+use zinc_build::LibraryFunctionIdentifier;
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::function::intrinsic::error::Error as IntrinsicError;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::error::Error as StdlibError;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The semantic analyzer standard library `std::array::zip` function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The unique intrinsic function identifier.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            library_identifier: LibraryFunctionIdentifier::ArrayZip,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "zip";
+
+    /// The position of the `first` argument in the function argument list.
+    pub const ARGUMENT_INDEX_FIRST: usize = 0;
+
+    /// The position of the `second` argument in the function argument list.
+    pub const ARGUMENT_INDEX_SECOND: usize = 1;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::ArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, location));
+        }
+
+        let (first_type, first_size) = match actual_params.get(Self::ARGUMENT_INDEX_FIRST) {
+            Some((Type::Array(array), _location)) if array.r#type.is_scalar() => {
+                (array.r#type.deref().to_owned(), array.size)
+            }
+            Some((r#type, location)) => {
+                return Err(Error::ArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "first".to_owned(),
+                    position: Self::ARGUMENT_INDEX_FIRST + 1,
+                    expected: "[{scalar}; N]".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::ArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        let (second_type, second_size) = match actual_params.get(Self::ARGUMENT_INDEX_SECOND) {
+            Some((Type::Array(array), _location)) if array.r#type.is_scalar() => {
+                (array.r#type.deref().to_owned(), array.size)
+            }
+            Some((r#type, location)) => {
+                return Err(Error::ArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "second".to_owned(),
+                    position: Self::ARGUMENT_INDEX_SECOND + 1,
+                    expected: "[{scalar}; N]".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::ArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::ArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        if first_size != second_size {
+            return Err(Error::Intrinsic(IntrinsicError::StandardLibrary(
+                StdlibError::ArrayZipSizeMismatch {
+                    location,
+                    first: first_size,
+                    second: second_size,
+                },
+            )));
+        }
+
+        Ok(Type::array(
+            Some(location),
+            Type::tuple(Some(location), vec![first_type, second_type]),
+            first_size,
+        ))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "array::{}(first: [T; N], second: [U; N]) -> [(T, U); N]",
+            self.identifier,
+        )
+    }
+}
@@ -173,10 +173,7 @@ impl Function {
             });
         }
 
-        Ok(Type::tuple(
-            None,
-            vec![value_type.to_owned(), Type::boolean(None)],
-        ))
+        Ok(Type::option(None, value_type.to_owned()))
     }
 }
 
@@ -184,7 +181,7 @@ impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "collections::MTreeMap<K, V>::{}(key: K, value: V) -> (V, bool)",
+            "collections::MTreeMap<K, V>::{}(key: K, value: V) -> Option<V>",
             self.identifier
         )
     }
@@ -34,4 +34,18 @@ pub enum Error {
         /// The stringified new length argument value.
         value: String,
     },
+    /// An empty array is summed, so there is no value of the element type to return.
+    ArraySumEmpty {
+        /// The error location data.
+        location: Location,
+    },
+    /// Two arrays of different sizes are zipped.
+    ArrayZipSizeMismatch {
+        /// The error location data.
+        location: Location,
+        /// The first array size.
+        first: usize,
+        /// The second array size.
+        second: usize,
+    },
 }
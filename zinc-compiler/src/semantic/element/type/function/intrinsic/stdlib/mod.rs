@@ -5,9 +5,16 @@
 #[cfg(test)]
 mod tests;
 
+pub mod array_contains;
+pub mod array_fold;
+pub mod array_index_of;
+pub mod array_map;
 pub mod array_pad;
 pub mod array_reverse;
+pub mod array_sort;
+pub mod array_sum;
 pub mod array_truncate;
+pub mod array_zip;
 pub mod collections_mtreemap_contains;
 pub mod collections_mtreemap_get;
 pub mod collections_mtreemap_insert;
@@ -17,6 +24,8 @@ pub mod convert_from_bits_signed;
 pub mod convert_from_bits_unsigned;
 pub mod convert_to_bits;
 pub mod crypto_pedersen;
+pub mod crypto_poseidon;
+pub mod crypto_rescue;
 pub mod crypto_schnorr_signature_verify;
 pub mod crypto_sha256;
 pub mod error;
@@ -31,9 +40,16 @@ use crate::semantic::element::argument_list::ArgumentList;
 use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::Type;
 
+use self::array_contains::Function as ArrayContainsFunction;
+use self::array_fold::Function as ArrayFoldFunction;
+use self::array_index_of::Function as ArrayIndexOfFunction;
+use self::array_map::Function as ArrayMapFunction;
 use self::array_pad::Function as ArrayPadFunction;
 use self::array_reverse::Function as ArrayReverseFunction;
+use self::array_sort::Function as ArraySortFunction;
+use self::array_sum::Function as ArraySumFunction;
 use self::array_truncate::Function as ArrayTruncateFunction;
+use self::array_zip::Function as ArrayZipFunction;
 use self::collections_mtreemap_contains::Function as MTreeMapContainsFunction;
 use self::collections_mtreemap_get::Function as MTreeMapGetFunction;
 use self::collections_mtreemap_insert::Function as MTreeMapInsertFunction;
@@ -43,6 +59,8 @@ use self::convert_from_bits_signed::Function as FromBitsSignedFunction;
 use self::convert_from_bits_unsigned::Function as FromBitsUnsignedFunction;
 use self::convert_to_bits::Function as ToBitsFunction;
 use self::crypto_pedersen::Function as PedersenFunction;
+use self::crypto_poseidon::Function as PoseidonFunction;
+use self::crypto_rescue::Function as RescueFunction;
 use self::crypto_schnorr_signature_verify::Function as SchnorrSignatureVerifyFunction;
 use self::crypto_sha256::Function as Sha256Function;
 use self::ff_invert::Function as FfInvertFunction;
@@ -56,6 +74,10 @@ pub enum Function {
     CryptoSha256(Sha256Function),
     /// The `std::crypto::pedersen` function variant.
     CryptoPedersen(PedersenFunction),
+    /// The `std::crypto::poseidon` function variant.
+    CryptoPoseidon(PoseidonFunction),
+    /// The `std::crypto::rescue` function variant.
+    CryptoRescue(RescueFunction),
     /// The `std::crypto::schnorr::Signature::verify` function variant.
     CryptoSchnorrSignatureVerify(SchnorrSignatureVerifyFunction),
 
@@ -74,6 +96,20 @@ pub enum Function {
     ArrayTruncate(ArrayTruncateFunction),
     /// The `std::array::pad` function variant.
     ArrayPad(ArrayPadFunction),
+    /// The `std::array::map` function variant.
+    ArrayMap(ArrayMapFunction),
+    /// The `std::array::fold` function variant.
+    ArrayFold(ArrayFoldFunction),
+    /// The `std::array::zip` function variant.
+    ArrayZip(ArrayZipFunction),
+    /// The `std::array::contains` function variant.
+    ArrayContains(ArrayContainsFunction),
+    /// The `std::array::index_of` function variant.
+    ArrayIndexOf(ArrayIndexOfFunction),
+    /// The `std::array::sum` function variant.
+    ArraySum(ArraySumFunction),
+    /// The `std::array::sort` function variant.
+    ArraySort(ArraySortFunction),
 
     /// The `std::ff::invert` function variant.
     FfInvert(FfInvertFunction),
@@ -92,10 +128,14 @@ impl Function {
     ///
     /// Calls the function with the `argument_list`, validating the call.
     ///
+    /// The `map` and `fold` functions are inlined by the caller, so they are validated separately.
+    ///
     pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
         match self {
             Self::CryptoSha256(inner) => inner.call(location, argument_list),
             Self::CryptoPedersen(inner) => inner.call(location, argument_list),
+            Self::CryptoPoseidon(inner) => inner.call(location, argument_list),
+            Self::CryptoRescue(inner) => inner.call(location, argument_list),
             Self::CryptoSchnorrSignatureVerify(inner) => inner.call(location, argument_list),
 
             Self::ConvertToBits(inner) => inner.call(location, argument_list),
@@ -106,6 +146,13 @@ impl Function {
             Self::ArrayReverse(inner) => inner.call(location, argument_list),
             Self::ArrayTruncate(inner) => inner.call(location, argument_list),
             Self::ArrayPad(inner) => inner.call(location, argument_list),
+            Self::ArrayMap(_) => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            Self::ArrayFold(_) => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            Self::ArrayZip(inner) => inner.call(location, argument_list),
+            Self::ArrayContains(inner) => inner.call(location, argument_list),
+            Self::ArrayIndexOf(inner) => inner.call(location, argument_list),
+            Self::ArraySum(inner) => inner.call(location, argument_list),
+            Self::ArraySort(inner) => inner.call(location, argument_list),
 
             Self::FfInvert(inner) => inner.call(location, argument_list),
 
@@ -123,6 +170,8 @@ impl Function {
         match self {
             Self::CryptoSha256(inner) => inner.identifier,
             Self::CryptoPedersen(inner) => inner.identifier,
+            Self::CryptoPoseidon(inner) => inner.identifier,
+            Self::CryptoRescue(inner) => inner.identifier,
             Self::CryptoSchnorrSignatureVerify(inner) => inner.identifier,
 
             Self::ConvertToBits(inner) => inner.identifier,
@@ -133,6 +182,13 @@ impl Function {
             Self::ArrayReverse(inner) => inner.identifier,
             Self::ArrayTruncate(inner) => inner.identifier,
             Self::ArrayPad(inner) => inner.identifier,
+            Self::ArrayMap(inner) => inner.identifier,
+            Self::ArrayFold(inner) => inner.identifier,
+            Self::ArrayZip(inner) => inner.identifier,
+            Self::ArrayContains(inner) => inner.identifier,
+            Self::ArrayIndexOf(inner) => inner.identifier,
+            Self::ArraySum(inner) => inner.identifier,
+            Self::ArraySort(inner) => inner.identifier,
 
             Self::FfInvert(inner) => inner.identifier,
 
@@ -150,6 +206,8 @@ impl Function {
         match self {
             Self::CryptoSha256(inner) => inner.library_identifier,
             Self::CryptoPedersen(inner) => inner.library_identifier,
+            Self::CryptoPoseidon(inner) => inner.library_identifier,
+            Self::CryptoRescue(inner) => inner.library_identifier,
             Self::CryptoSchnorrSignatureVerify(inner) => inner.library_identifier,
 
             Self::ConvertToBits(inner) => inner.library_identifier,
@@ -160,6 +218,13 @@ impl Function {
             Self::ArrayReverse(inner) => inner.library_identifier,
             Self::ArrayTruncate(inner) => inner.library_identifier,
             Self::ArrayPad(inner) => inner.library_identifier,
+            Self::ArrayMap(inner) => inner.library_identifier,
+            Self::ArrayFold(inner) => inner.library_identifier,
+            Self::ArrayZip(inner) => inner.library_identifier,
+            Self::ArrayContains(inner) => inner.library_identifier,
+            Self::ArrayIndexOf(inner) => inner.library_identifier,
+            Self::ArraySum(inner) => inner.library_identifier,
+            Self::ArraySort(inner) => inner.library_identifier,
 
             Self::FfInvert(inner) => inner.library_identifier,
 
@@ -177,6 +242,8 @@ impl Function {
         match self {
             Self::CryptoSha256(_) => false,
             Self::CryptoPedersen(_) => false,
+            Self::CryptoPoseidon(_) => false,
+            Self::CryptoRescue(_) => false,
             Self::CryptoSchnorrSignatureVerify(_) => false,
 
             Self::ConvertToBits(_) => false,
@@ -187,6 +254,13 @@ impl Function {
             Self::ArrayReverse(_) => false,
             Self::ArrayTruncate(_) => false,
             Self::ArrayPad(_) => false,
+            Self::ArrayMap(_) => false,
+            Self::ArrayFold(_) => false,
+            Self::ArrayZip(_) => false,
+            Self::ArrayContains(_) => false,
+            Self::ArrayIndexOf(_) => false,
+            Self::ArraySum(_) => false,
+            Self::ArraySort(_) => false,
 
             Self::FfInvert(_) => false,
 
@@ -204,6 +278,8 @@ impl Function {
         match self {
             Self::CryptoSha256(inner) => inner.location = Some(location),
             Self::CryptoPedersen(inner) => inner.location = Some(location),
+            Self::CryptoPoseidon(inner) => inner.location = Some(location),
+            Self::CryptoRescue(inner) => inner.location = Some(location),
             Self::CryptoSchnorrSignatureVerify(inner) => inner.location = Some(location),
 
             Self::ConvertToBits(inner) => inner.location = Some(location),
@@ -214,6 +290,13 @@ impl Function {
             Self::ArrayReverse(inner) => inner.location = Some(location),
             Self::ArrayTruncate(inner) => inner.location = Some(location),
             Self::ArrayPad(inner) => inner.location = Some(location),
+            Self::ArrayMap(inner) => inner.location = Some(location),
+            Self::ArrayFold(inner) => inner.location = Some(location),
+            Self::ArrayZip(inner) => inner.location = Some(location),
+            Self::ArrayContains(inner) => inner.location = Some(location),
+            Self::ArrayIndexOf(inner) => inner.location = Some(location),
+            Self::ArraySum(inner) => inner.location = Some(location),
+            Self::ArraySort(inner) => inner.location = Some(location),
 
             Self::FfInvert(inner) => inner.location = Some(location),
 
@@ -231,6 +314,8 @@ impl Function {
         match self {
             Self::CryptoSha256(inner) => inner.location,
             Self::CryptoPedersen(inner) => inner.location,
+            Self::CryptoPoseidon(inner) => inner.location,
+            Self::CryptoRescue(inner) => inner.location,
             Self::CryptoSchnorrSignatureVerify(inner) => inner.location,
 
             Self::ConvertToBits(inner) => inner.location,
@@ -241,6 +326,13 @@ impl Function {
             Self::ArrayReverse(inner) => inner.location,
             Self::ArrayTruncate(inner) => inner.location,
             Self::ArrayPad(inner) => inner.location,
+            Self::ArrayMap(inner) => inner.location,
+            Self::ArrayFold(inner) => inner.location,
+            Self::ArrayZip(inner) => inner.location,
+            Self::ArrayContains(inner) => inner.location,
+            Self::ArrayIndexOf(inner) => inner.location,
+            Self::ArraySum(inner) => inner.location,
+            Self::ArraySort(inner) => inner.location,
 
             Self::FfInvert(inner) => inner.location,
 
@@ -257,6 +349,8 @@ impl fmt::Display for Function {
         match self {
             Self::CryptoSha256(inner) => write!(f, "{}", inner),
             Self::CryptoPedersen(inner) => write!(f, "{}", inner),
+            Self::CryptoPoseidon(inner) => write!(f, "{}", inner),
+            Self::CryptoRescue(inner) => write!(f, "{}", inner),
             Self::CryptoSchnorrSignatureVerify(inner) => write!(f, "{}", inner),
 
             Self::ConvertToBits(inner) => write!(f, "{}", inner),
@@ -267,6 +361,13 @@ impl fmt::Display for Function {
             Self::ArrayReverse(inner) => write!(f, "{}", inner),
             Self::ArrayTruncate(inner) => write!(f, "{}", inner),
             Self::ArrayPad(inner) => write!(f, "{}", inner),
+            Self::ArrayMap(inner) => write!(f, "{}", inner),
+            Self::ArrayFold(inner) => write!(f, "{}", inner),
+            Self::ArrayZip(inner) => write!(f, "{}", inner),
+            Self::ArrayContains(inner) => write!(f, "{}", inner),
+            Self::ArrayIndexOf(inner) => write!(f, "{}", inner),
+            Self::ArraySum(inner) => write!(f, "{}", inner),
+            Self::ArraySort(inner) => write!(f, "{}", inner),
 
             Self::FfInvert(inner) => write!(f, "{}", inner),
 
@@ -18,7 +18,11 @@ use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_m
 use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_contains::Function as CollectionsMTreeMapContainsFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_insert::Function as CollectionsMTreeMapInsertFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_remove::Function as CollectionsMTreeMapRemoveFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_contains::Function as ArrayContainsFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_fold::Function as ArrayFoldFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_map::Function as ArrayMapFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::array_pad::Function as ArrayPadFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_sort::Function as ArraySortFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::array_reverse::Function as ArrayReverseFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::array_truncate::Function as ArrayTruncateFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bits_field::Function as ConvertFromBitsFieldFunction;
@@ -26,6 +30,8 @@ use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bits_unsigned::Function as ConvertFromBitsUnsignedFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_to_bits::Function as ConvertToBitsFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_pedersen::Function as CryptoPedersenFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_poseidon::Function as CryptoPoseidonFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_rescue::Function as CryptoRescueFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_schnorr_signature_verify::Function as CryptoSchnorrSignatureVerifyFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_sha256::Function as CryptoSha256Function;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::error::Error as StandardLibraryFunctionError;
@@ -304,6 +310,100 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_crypto_poseidon_argument_count_greater() {
+    let input = r#"
+fn main() {
+    std::crypto::poseidon([0 as field; 2], 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(3, 5),
+            function: CryptoPoseidonFunction::IDENTIFIER.to_owned(),
+            expected: CryptoPoseidonFunction::ARGUMENT_COUNT,
+            found: CryptoPoseidonFunction::ARGUMENT_COUNT + 1,
+            reference: None,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_poseidon_argument_1_preimage_expected_field_array() {
+    let input = r#"
+fn main() {
+    std::crypto::poseidon([true; 2]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(3, 27),
+            function: CryptoPoseidonFunction::IDENTIFIER.to_owned(),
+            name: "preimage".to_owned(),
+            position: CryptoPoseidonFunction::ARGUMENT_INDEX_PREIMAGE + 1,
+            expected: "[field; N], N > 0".to_owned(),
+            found: Type::array(Some(Location::test(3, 27)), Type::boolean(None), 2).to_string(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_rescue_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::crypto::rescue();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(3, 5),
+            function: CryptoRescueFunction::IDENTIFIER.to_owned(),
+            expected: CryptoRescueFunction::ARGUMENT_COUNT,
+            found: CryptoRescueFunction::ARGUMENT_COUNT - 1,
+            reference: None,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_rescue_argument_1_preimage_expected_field_array_not_empty() {
+    let input = r#"
+fn main() {
+    std::crypto::rescue([0 as field; 0]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(3, 25),
+            function: CryptoRescueFunction::IDENTIFIER.to_owned(),
+            name: "preimage".to_owned(),
+            position: CryptoRescueFunction::ARGUMENT_INDEX_PREIMAGE + 1,
+            expected: "[field; N], N > 0".to_owned(),
+            found: Type::array(Some(Location::test(3, 25)), Type::field(None), 0).to_string(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_crypto_pedersen_argument_1_preimage_expected_bit_array_size_limit() {
     let input = r#"
@@ -1525,6 +1625,174 @@ fn main() -> [u8; 4] {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_array_map_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::array::map([1, 2, 3]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(3, 5),
+            function: ArrayMapFunction::IDENTIFIER.to_owned(),
+            expected: ArrayMapFunction::ARGUMENT_COUNT,
+            found: ArrayMapFunction::ARGUMENT_COUNT - 1,
+            reference: None,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_map_argument_2_f_expected_function() {
+    let input = r#"
+fn main() {
+    std::array::map([1, 2, 3], 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(3, 32),
+            function: ArrayMapFunction::IDENTIFIER.to_owned(),
+            name: "f".to_owned(),
+            position: ArrayMapFunction::ARGUMENT_INDEX_FUNCTION + 1,
+            expected: format!(
+                "fn({}) -> R",
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE)
+            ),
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_fold_argument_3_f_expected_accumulator_result() {
+    let input = r#"
+fn main() {
+    std::array::fold([1, 2, 3], 0 as u16, |acc, x| x);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(3, 21),
+            function: ArrayFoldFunction::IDENTIFIER.to_owned(),
+            name: "f".to_owned(),
+            position: ArrayFoldFunction::ARGUMENT_INDEX_FUNCTION + 1,
+            expected: "fn(u16, u8) -> u16".to_owned(),
+            found: "fn(u16, u8) -> u8".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_contains_argument_2_value_expected_element() {
+    let input = r#"
+fn main() {
+    std::array::contains([1, 2, 3], true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(3, 37),
+            function: ArrayContainsFunction::IDENTIFIER.to_owned(),
+            name: "value".to_owned(),
+            position: ArrayContainsFunction::ARGUMENT_INDEX_VALUE + 1,
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            found: Type::boolean(None).to_string(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_sort_argument_1_array_expected_integer_array() {
+    let input = r#"
+fn main() {
+    std::array::sort([true, false]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(3, 22),
+            function: ArraySortFunction::IDENTIFIER.to_owned(),
+            name: "array".to_owned(),
+            position: ArraySortFunction::ARGUMENT_INDEX_ARRAY + 1,
+            expected: "[{integer}; N]".to_owned(),
+            found: Type::array(Some(Location::test(3, 22)), Type::boolean(None), 2).to_string(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_sum_empty() {
+    let input = r#"
+fn main(array: [u8; 0]) -> u8 {
+    std::array::sum(array)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::Intrinsic(
+            IntrinsicFunctionError::StandardLibrary(StandardLibraryFunctionError::ArraySumEmpty {
+                location: Location::test(3, 5),
+            }),
+        )),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_zip_size_mismatch() {
+    let input = r#"
+fn main() {
+    std::array::zip([1, 2, 3], [true, false]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::Intrinsic(
+            IntrinsicFunctionError::StandardLibrary(
+                StandardLibraryFunctionError::ArrayZipSizeMismatch {
+                    location: Location::test(3, 5),
+                    first: 3,
+                    second: 2,
+                },
+            ),
+        )),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_ff_invert_argument_count_lesser() {
     let input = r#"
@@ -5,6 +5,7 @@
 #[cfg(test)]
 mod tests;
 
+pub mod emit;
 pub mod transfer;
 
 use std::fmt;
@@ -16,6 +17,7 @@ use crate::semantic::element::argument_list::ArgumentList;
 use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::Type;
 
+use self::emit::Function as EmitFunction;
 use self::transfer::Function as TransferFunction;
 
 ///
@@ -25,6 +27,8 @@ use self::transfer::Function as TransferFunction;
 pub enum Function {
     /// The `zksync::transfer` function variant.
     Transfer(TransferFunction),
+    /// The `zksync::emit` function variant.
+    Emit(EmitFunction),
 }
 
 impl Function {
@@ -34,6 +38,9 @@ impl Function {
     pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
         match self {
             Self::Transfer(inner) => inner.call(location, argument_list),
+            Self::Emit(inner) => inner
+                .call(location, argument_list)
+                .map(|(r#type, _event)| r#type),
         }
     }
 
@@ -43,6 +50,7 @@ impl Function {
     pub fn identifier(&self) -> &'static str {
         match self {
             Self::Transfer(inner) => inner.identifier,
+            Self::Emit(inner) => inner.identifier,
         }
     }
 
@@ -52,6 +60,7 @@ impl Function {
     pub fn library_identifier(&self) -> LibraryFunctionIdentifier {
         match self {
             Self::Transfer(inner) => inner.library_identifier,
+            Self::Emit(inner) => inner.library_identifier,
         }
     }
 
@@ -61,6 +70,7 @@ impl Function {
     pub fn is_mutable(&self) -> bool {
         match self {
             Self::Transfer(_) => true,
+            Self::Emit(_) => true,
         }
     }
 
@@ -70,6 +80,7 @@ impl Function {
     pub fn set_location(&mut self, location: Location) {
         match self {
             Self::Transfer(inner) => inner.location = Some(location),
+            Self::Emit(inner) => inner.location = Some(location),
         }
     }
 
@@ -79,6 +90,7 @@ impl Function {
     pub fn location(&self) -> Option<Location> {
         match self {
             Self::Transfer(inner) => inner.location,
+            Self::Emit(inner) => inner.location,
         }
     }
 }
@@ -87,6 +99,7 @@ impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Transfer(inner) => write!(f, "{}", inner),
+            Self::Emit(inner) => write!(f, "{}", inner),
         }
     }
 }
@@ -7,11 +7,133 @@ use zinc_lexical::Location;
 use crate::error::Error;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::function::intrinsic::zksync::emit::Function as ZksyncEmitFunction;
 use crate::semantic::element::r#type::function::intrinsic::zksync::transfer::Function as ZksyncTransferFunction;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Error as ElementError;
 use crate::semantic::error::Error as SemanticError;
 
+#[test]
+fn ok_emit() {
+    let input = r#"
+struct Deposited {
+    sender: u160,
+    amount: u248,
+}
+
+fn main() {
+    zksync::emit(Deposited {
+        sender: 0x42 as u160,
+        amount: 500 as u248,
+    });
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_emit_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    zksync::emit();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(3, 5),
+            function: ZksyncEmitFunction::IDENTIFIER.to_owned(),
+            expected: ZksyncEmitFunction::ARGUMENT_COUNT,
+            found: ZksyncEmitFunction::ARGUMENT_COUNT - 1,
+            reference: None,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_emit_argument_count_greater() {
+    let input = r#"
+struct Deposited {
+    amount: u248,
+}
+
+fn main() {
+    zksync::emit(Deposited { amount: 500 as u248 }, 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(7, 5),
+            function: ZksyncEmitFunction::IDENTIFIER.to_owned(),
+            expected: ZksyncEmitFunction::ARGUMENT_COUNT,
+            found: ZksyncEmitFunction::ARGUMENT_COUNT + 1,
+            reference: None,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_emit_argument_1_event_expected_structure() {
+    let input = r#"
+fn main() {
+    zksync::emit(500 as u248);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(3, 18),
+            function: ZksyncEmitFunction::IDENTIFIER.to_owned(),
+            name: "event".to_owned(),
+            position: ZksyncEmitFunction::ARGUMENT_INDEX_EVENT + 1,
+            expected: "{structure}".to_owned(),
+            found: Type::integer_unsigned(None, zinc_const::bitlength::INTEGER_MAX).to_string(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_emit_argument_1_event_expected_structure_with_fields() {
+    let input = r#"
+struct Empty {
+    unit: (),
+}
+
+fn main() {
+    zksync::emit(Empty { unit: () });
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(7, 24),
+            function: ZksyncEmitFunction::IDENTIFIER.to_owned(),
+            name: "event".to_owned(),
+            position: ZksyncEmitFunction::ARGUMENT_INDEX_EVENT + 1,
+            expected: "{structure with fields}".to_owned(),
+            found: "structure Empty".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_transfer_argument_count_lesser() {
     let input = r#"
@@ -2,11 +2,14 @@
 //! The semantic analyzer function element.
 //!
 
+pub mod closure;
 pub mod constant;
 pub mod error;
+pub mod generic;
 pub mod intrinsic;
 pub mod runtime;
 pub mod test;
+pub mod variant;
 
 use std::fmt;
 
@@ -17,18 +20,21 @@ use zinc_syntax::BlockExpression;
 use crate::semantic::binding::Binding;
 use crate::semantic::element::r#type::Type;
 
+use self::closure::Function as ClosureFunction;
 use self::constant::Function as ConstantFunction;
+use self::generic::Function as GenericFunction;
 use self::intrinsic::Function as IntrinsicFunction;
 use self::runtime::Function as RuntimeFunction;
 use self::test::Function as TestFunction;
+use self::variant::Function as VariantFunction;
 
 ///
 /// Describes a function, which is a special type.
 ///
 #[derive(Debug, Clone)]
 pub enum Function {
-    /// The `dbg!` function, which must be called with the `!` specifier, and the `require` function.
-    /// These correspond to some special VM instructions.
+    /// The `dbg!` function, which must be called with the `!` specifier, the `require` function,
+    /// and the `Some` function. These correspond to some special VM instructions.
     /// Also, standard library and zkSync library functions, which are declared in a virtual intrinsic
     /// scope and implemented in the VM as intrinsic function calls.
     Intrinsic(IntrinsicFunction),
@@ -41,6 +47,14 @@ pub enum Function {
     /// Unit test functions. They produce the intermediate representation and are run as separate
     /// entry points in the special test mode.
     Test(TestFunction),
+    /// Generic functions, which are instantiated as runtime or constant ones upon each call with
+    /// a distinct set of generic arguments.
+    Generic(GenericFunction),
+    /// Data-carrying enumeration variant constructors, which are declared for each tuple-like
+    /// and structure-like variant.
+    Variant(VariantFunction),
+    /// Closures, which are analyzed and inlined at each call site and do not exist at runtime.
+    Closure(ClosureFunction),
 }
 
 impl Function {
@@ -58,6 +72,13 @@ impl Function {
         Self::Intrinsic(IntrinsicFunction::new_require())
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_some() -> Self {
+        Self::Intrinsic(IntrinsicFunction::new_some())
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -121,6 +142,9 @@ impl Function {
             Self::Runtime(inner) => inner.identifier.to_owned(),
             Self::Constant(inner) => inner.identifier.to_owned(),
             Self::Test(inner) => inner.identifier.to_owned(),
+            Self::Generic(inner) => inner.identifier.to_owned(),
+            Self::Variant(inner) => inner.identifier.to_owned(),
+            Self::Closure(inner) => inner.identifier.to_owned(),
         }
     }
 
@@ -133,6 +157,9 @@ impl Function {
             Self::Runtime(inner) => inner.is_mutable(),
             Self::Constant(inner) => inner.is_mutable(),
             Self::Test(_) => false,
+            Self::Generic(inner) => inner.is_mutable(),
+            Self::Variant(_) => false,
+            Self::Closure(_) => false,
         }
     }
 
@@ -145,6 +172,9 @@ impl Function {
             Self::Runtime(inner) => inner.location = value,
             Self::Constant(inner) => inner.location = value,
             Self::Test(inner) => inner.location = value,
+            Self::Generic(inner) => inner.location = value,
+            Self::Variant(inner) => inner.location = value,
+            Self::Closure(inner) => inner.location = value,
         }
     }
 
@@ -157,6 +187,9 @@ impl Function {
             Self::Runtime(inner) => Some(inner.location),
             Self::Constant(inner) => Some(inner.location),
             Self::Test(inner) => Some(inner.location),
+            Self::Generic(inner) => Some(inner.location),
+            Self::Variant(inner) => Some(inner.location),
+            Self::Closure(inner) => Some(inner.location),
         }
     }
 }
@@ -168,6 +201,9 @@ impl fmt::Display for Function {
             Self::Runtime(inner) => write!(f, "{}", inner),
             Self::Constant(inner) => write!(f, "{}", inner),
             Self::Test(inner) => write!(f, "{}", inner),
+            Self::Generic(inner) => write!(f, "{}", inner),
+            Self::Variant(inner) => write!(f, "{}", inner),
+            Self::Closure(inner) => write!(f, "{}", inner),
         }
     }
 }
@@ -0,0 +1,137 @@
+//!
+//! The semantic analyzer enumeration variant constructor function element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+use num::BigInt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::r#type::enumeration::payload::Payload;
+use crate::semantic::element::r#type::enumeration::Enumeration;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The semantic analyzer enumeration variant constructor function element.
+///
+/// Is declared for each data-carrying enumeration variant, e.g. `Message::Transfer(u64, u248)`.
+/// The arguments are left on the data stack as the payload, which is padded to the enumeration
+/// payload size, and the variant discriminant is pushed after it.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The variant location.
+    pub location: Location,
+    /// The variant identifier.
+    pub identifier: String,
+    /// The enumeration type, which the variant belongs to.
+    pub enumeration: Enumeration,
+    /// The variant discriminant, that is, its index in the enumeration.
+    pub discriminant: BigInt,
+    /// The variant payload.
+    pub payload: Payload,
+}
+
+impl Function {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        identifier: String,
+        enumeration: Enumeration,
+        discriminant: BigInt,
+        payload: Payload,
+    ) -> Self {
+        Self {
+            location,
+            identifier,
+            enumeration,
+            discriminant,
+            payload,
+        }
+    }
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    /// Only tuple-like variants can be called, whereas structure-like ones are constructed
+    /// with the structure literal syntax.
+    ///
+    pub fn call(self, argument_list: ArgumentList) -> Result<Type, Error> {
+        let types = match self.payload {
+            Payload::Tuple(ref types) => types.to_owned(),
+            Payload::Structure(_) => {
+                return Err(Error::NonCallable {
+                    location: argument_list.location,
+                    name: self.to_string(),
+                })
+            }
+        };
+
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::ArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, location));
+        }
+
+        if actual_params.len() != types.len() {
+            return Err(Error::ArgumentCount {
+                location: self.location,
+                function: self.identifier.to_owned(),
+                expected: types.len(),
+                found: actual_params.len(),
+                reference: Some(argument_list.location),
+            });
+        }
+
+        for (index, (expected, (actual, location))) in
+            types.into_iter().zip(actual_params).enumerate()
+        {
+            if actual != expected {
+                return Err(Error::ArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: index.to_string(),
+                    position: index + 1,
+                    expected: expected.to_string(),
+                    found: actual.to_string(),
+                });
+            }
+        }
+
+        Ok(Type::Enumeration(self.enumeration))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}::{}{}",
+            self.enumeration.identifier, self.identifier, self.payload
+        )
+    }
+}
@@ -0,0 +1,128 @@
+//!
+//! The enumeration variant constructor function tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Error as ElementError;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_tuple() {
+    let input = r#"
+enum Message {
+    Quit,
+    Move(u8, u8),
+    Write { length: u8, checksum: field },
+}
+
+fn main() -> Message {
+    Message::Move(1, 2)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_structure() {
+    let input = r#"
+enum Message {
+    Quit,
+    Move(u8, u8),
+    Write { length: u8, checksum: field },
+}
+
+fn main() -> Message {
+    Message::Write { length: 10, checksum: 42 as field }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_argument_count() {
+    let input = r#"
+enum Message {
+    Quit,
+    Move(u8, u8),
+}
+
+fn main() -> Message {
+    Message::Move(1)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(8, 5),
+            function: "Move".to_owned(),
+            expected: 2,
+            found: 1,
+            reference: Some(Location::test(8, 18)),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_type() {
+    let input = r#"
+enum Message {
+    Quit,
+    Move(u8, u8),
+}
+
+fn main() -> Message {
+    Message::Move(1, true)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(8, 22),
+            function: "Move".to_owned(),
+            name: "1".to_owned(),
+            position: 2,
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            found: Type::boolean(None).to_string(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_non_callable_structure() {
+    let input = r#"
+enum Message {
+    Quit,
+    Write { length: u8 },
+}
+
+fn main() -> Message {
+    Message::Write(10)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::NonCallable {
+            location: Location::test(8, 19),
+            name: "Message::Write { length: u8 }".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
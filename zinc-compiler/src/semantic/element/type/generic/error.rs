@@ -0,0 +1,99 @@
+//!
+//! The semantic analyzer generic item error.
+//!
+
+use zinc_lexical::Location;
+
+///
+/// The semantic analyzer generic item error.
+///
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// A generic parameter with the same name occurs for the second time.
+    DuplicateParameter {
+        /// The duplicate parameter location.
+        location: Location,
+        /// The generic item identifier.
+        item: String,
+        /// The duplicate parameter name.
+        name: String,
+    },
+    /// Constant generic parameters are only allowed in functions.
+    ConstantParameterForbidden {
+        /// The constant parameter location.
+        location: Location,
+        /// The generic item identifier.
+        item: String,
+        /// The constant parameter name.
+        name: String,
+    },
+    /// The constant generic parameter type is not an integer one.
+    ConstantParameterExpectedInteger {
+        /// The constant parameter type location.
+        location: Location,
+        /// The constant parameter name.
+        name: String,
+        /// The stringified invalid type.
+        found: String,
+    },
+    /// The inferred constant generic argument does not fit into the parameter type.
+    ConstantArgumentOverflow {
+        /// The error location data.
+        location: Location,
+        /// The constant parameter name.
+        name: String,
+        /// The inferred argument value.
+        value: usize,
+        /// The stringified constant parameter type.
+        r#type: String,
+    },
+    /// The generic parameter cannot be inferred from the actual arguments.
+    CannotInfer {
+        /// The error location data.
+        location: Location,
+        /// The generic item identifier.
+        item: String,
+        /// The parameter, whose argument cannot be inferred.
+        name: String,
+    },
+    /// The generic parameter was inferred as two different arguments.
+    InferenceConflict {
+        /// The error location data.
+        location: Location,
+        /// The generic item identifier.
+        item: String,
+        /// The parameter with conflicting arguments.
+        name: String,
+        /// The stringified argument inferred first.
+        expected: String,
+        /// The stringified conflicting argument.
+        found: String,
+    },
+    /// The generic parameter bound is not a trait.
+    BoundExpectedTrait {
+        /// The bound location in the code.
+        location: Location,
+        /// The bound identifier.
+        found: String,
+    },
+    /// The generic argument type does not implement the trait required by the parameter bound.
+    BoundNotSatisfied {
+        /// The error location data.
+        location: Location,
+        /// The generic item identifier.
+        item: String,
+        /// The bounded parameter.
+        name: String,
+        /// The stringified argument type.
+        r#type: String,
+        /// The trait, which is not implemented for the type.
+        r#trait: String,
+    },
+    /// The program entry point cannot be generic, since it is never instantiated.
+    EntryPoint {
+        /// The entry function location.
+        location: Location,
+        /// The entry function identifier.
+        function: String,
+    },
+}
@@ -0,0 +1,426 @@
+//!
+//! The semantic analyzer generic item element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use num::BigInt;
+
+use zinc_lexical::Location;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::GenericParameter;
+use zinc_syntax::Type as SyntaxType;
+use zinc_syntax::TypeVariant as SyntaxTypeVariant;
+
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::item::constant::Constant as ScopeConstantItem;
+use crate::semantic::scope::item::r#type::Type as ScopeTypeItem;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::Scope;
+
+use self::error::Error;
+
+/// The constant parameter type, which is an alias of the array index type, e.g. `const N: usize`.
+pub static INDEX_TYPE_IDENTIFIER: &str = "usize";
+
+///
+/// The generic item actual argument, which is inferred from the usage context.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Argument {
+    /// The type argument, e.g. `T` bound to `u8`.
+    Type(Type),
+    /// The constant argument, e.g. `N` bound to `4`.
+    Constant(usize),
+}
+
+impl fmt::Display for Argument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Type(Type::Structure(inner)) => write!(f, "{}", inner.identifier),
+            Self::Type(Type::Enumeration(inner)) => write!(f, "{}", inner.identifier),
+            Self::Type(Type::Array(inner)) => {
+                write!(f, "[{}; {}]", Self::Type(*inner.r#type.clone()), inner.size)
+            }
+            Self::Type(inner) => write!(f, "{}", inner),
+            Self::Constant(inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+///
+/// The generic item formal parameters list.
+///
+/// Is shared by generic functions and structures, which are monomorphized into a separate
+/// instance for each distinct list of actual arguments.
+///
+#[derive(Debug, Clone)]
+pub struct Generics {
+    /// The generic item identifier.
+    pub identifier: String,
+    /// The ordered formal parameters list.
+    pub parameters: Vec<GenericParameter>,
+}
+
+impl Generics {
+    ///
+    /// Validates the formal parameters list.
+    ///
+    pub fn new(identifier: String, parameters: Vec<GenericParameter>) -> Result<Self, Error> {
+        for (index, parameter) in parameters.iter().enumerate() {
+            if parameters[..index]
+                .iter()
+                .any(|previous| previous.identifier.name == parameter.identifier.name)
+            {
+                return Err(Error::DuplicateParameter {
+                    location: parameter.identifier.location,
+                    item: identifier,
+                    name: parameter.identifier.name.to_owned(),
+                });
+            }
+        }
+
+        Ok(Self {
+            identifier,
+            parameters,
+        })
+    }
+
+    ///
+    /// Returns the ordered list of the parameter names.
+    ///
+    pub fn names(&self) -> Vec<String> {
+        self.parameters
+            .iter()
+            .map(|parameter| parameter.identifier.name.to_owned())
+            .collect()
+    }
+
+    ///
+    /// Infers the parameters, which occur in the `formal` syntax type, from the `actual` type.
+    ///
+    /// Parts of the types which do not mention any parameter are ignored here, since they are
+    /// checked later when the instance is analyzed.
+    ///
+    pub fn infer(
+        &self,
+        location: Location,
+        formal: &SyntaxType,
+        actual: &Type,
+        arguments: &mut HashMap<String, Argument>,
+    ) -> Result<(), Error> {
+        match (&formal.variant, actual) {
+            (SyntaxTypeVariant::Alias { path, generics }, actual) => {
+                if let Some(name) = self.parameter_name(path, false) {
+                    return self.bind(location, name, Argument::Type(actual.to_owned()), arguments);
+                }
+
+                match (generics, actual) {
+                    (Some(formal_generics), Type::Structure(structure)) => {
+                        if let (Some(names), Some(params)) =
+                            (structure.generics.as_ref(), structure.params.as_ref())
+                        {
+                            for (formal, name) in formal_generics.iter().zip(names.iter()) {
+                                if let Some(actual) = params.get(name) {
+                                    self.infer(location, formal, actual, arguments)?;
+                                }
+                            }
+                        }
+                    }
+                    (Some(formal_generics), Type::Option(option)) => {
+                        if let (Some(formal), Some(actual)) =
+                            (formal_generics.first(), option.inner.as_ref())
+                        {
+                            self.infer(location, formal, actual.as_ref(), arguments)?;
+                        }
+                    }
+                    _ => {}
+                }
+
+                Ok(())
+            }
+            (SyntaxTypeVariant::Array { inner, size }, Type::Array(actual)) => {
+                self.infer(location, inner.as_ref(), actual.r#type.as_ref(), arguments)?;
+
+                if let Some(name) = self.parameter_name(size, true) {
+                    self.bind(location, name, Argument::Constant(actual.size), arguments)?;
+                }
+
+                Ok(())
+            }
+            (SyntaxTypeVariant::Tuple { inners }, Type::Tuple(actual)) => {
+                for (formal, actual) in inners.iter().zip(actual.types.iter()) {
+                    self.infer(location, formal, actual, arguments)?;
+                }
+
+                Ok(())
+            }
+            (
+                SyntaxTypeVariant::Function {
+                    arguments: formal_arguments,
+                    return_type,
+                },
+                Type::Function(FunctionType::Runtime(function)),
+            ) => {
+                for (formal, binding) in formal_arguments.iter().zip(function.bindings.iter()) {
+                    self.infer(location, formal, &binding.r#type, arguments)?;
+                }
+                self.infer(
+                    location,
+                    return_type.as_ref(),
+                    function.return_type.as_ref(),
+                    arguments,
+                )
+            }
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Collects the inferred arguments in the order of the formal parameters.
+    ///
+    pub fn collect(
+        &self,
+        location: Location,
+        mut arguments: HashMap<String, Argument>,
+    ) -> Result<Vec<Argument>, Error> {
+        let mut result = Vec::with_capacity(self.parameters.len());
+        for parameter in self.parameters.iter() {
+            match arguments.remove(parameter.identifier.name.as_str()) {
+                Some(argument) => result.push(argument),
+                None => {
+                    return Err(Error::CannotInfer {
+                        location,
+                        item: self.identifier.to_owned(),
+                        name: parameter.identifier.name.to_owned(),
+                    })
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    ///
+    /// Returns the instance identifier, e.g. `max<u8, 4>`.
+    ///
+    pub fn instance_identifier(&self, arguments: &[Argument]) -> String {
+        if arguments.is_empty() {
+            return self.identifier.to_owned();
+        }
+
+        format!(
+            "{}<{}>",
+            self.identifier,
+            arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+
+    ///
+    /// Creates the instance scope, where the parameters are declared as the actual `arguments`.
+    ///
+    /// Type parameters become type aliases, and constant ones become integer constants.
+    ///
+    pub fn define_arguments(
+        &self,
+        location: Location,
+        scope: Rc<RefCell<Scope>>,
+        arguments: &[Argument],
+    ) -> Result<Rc<RefCell<Scope>>, SemanticError> {
+        let instance_identifier = self.instance_identifier(arguments);
+        let instance_scope = Scope::new_child(instance_identifier, scope.clone());
+
+        for (parameter, argument) in self.parameters.iter().zip(arguments.iter()) {
+            let name = parameter.identifier.name.to_owned();
+
+            let item = match (parameter.r#type.as_ref(), argument) {
+                (None, Argument::Type(r#type)) => {
+                    self.check_bounds(location, scope.clone(), parameter, r#type)?;
+
+                    ScopeItem::Type(ScopeTypeItem::new_defined(
+                        Some(parameter.location),
+                        r#type.to_owned(),
+                        false,
+                        false,
+                        None,
+                    ))
+                }
+                (Some(r#type), Argument::Constant(value)) => {
+                    let r#type = match r#type.variant {
+                        SyntaxTypeVariant::Alias { ref path, .. }
+                            if Self::is_identifier(path, INDEX_TYPE_IDENTIFIER) =>
+                        {
+                            Type::integer_unsigned(
+                                Some(r#type.location),
+                                zinc_const::bitlength::INDEX,
+                            )
+                        }
+                        _ => Type::try_from_syntax(r#type.to_owned(), scope.clone())?,
+                    };
+                    let (is_signed, bitlength) = match r#type {
+                        Type::IntegerUnsigned { bitlength, .. } => (false, bitlength),
+                        Type::IntegerSigned { bitlength, .. } => (true, bitlength),
+                        Type::Field(_) => (false, zinc_const::bitlength::FIELD),
+                        r#type => {
+                            return Err(Self::error(Error::ConstantParameterExpectedInteger {
+                                location: r#type.location().unwrap_or(parameter.location),
+                                name,
+                                found: r#type.to_string(),
+                            }))
+                        }
+                    };
+
+                    let constant = BigInt::from(*value);
+                    match zinc_math::infer_minimal_bitlength(&constant, is_signed) {
+                        Ok(minimal) if minimal <= bitlength => {}
+                        _ => {
+                            return Err(Self::error(Error::ConstantArgumentOverflow {
+                                location,
+                                name,
+                                value: *value,
+                                r#type: r#type.to_string(),
+                            }))
+                        }
+                    }
+
+                    ScopeItem::Constant(ScopeConstantItem::new_defined(
+                        parameter.location,
+                        Constant::Integer(IntegerConstant::new(
+                            parameter.location,
+                            constant,
+                            is_signed,
+                            bitlength,
+                            false,
+                        )),
+                        false,
+                    ))
+                }
+                _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            };
+
+            Scope::insert_item(instance_scope.clone(), name, item.wrap());
+        }
+
+        Ok(instance_scope)
+    }
+
+    ///
+    /// Checks if the type argument implements the traits required by the parameter bounds.
+    ///
+    /// The traits are resolved in the `scope`, where the generic item is declared.
+    ///
+    fn check_bounds(
+        &self,
+        location: Location,
+        scope: Rc<RefCell<Scope>>,
+        parameter: &GenericParameter,
+        r#type: &Type,
+    ) -> Result<(), SemanticError> {
+        for bound in parameter.bounds.iter() {
+            let item = scope.borrow().resolve_item(bound, true)?;
+            let is_satisfied = match *item.borrow() {
+                ScopeItem::Trait(ref r#trait) => r#trait.is_implemented_by(r#type),
+                ref _item => {
+                    return Err(Self::error(Error::BoundExpectedTrait {
+                        location: bound.location,
+                        found: bound.name.to_owned(),
+                    }))
+                }
+            };
+
+            if !is_satisfied {
+                return Err(Self::error(Error::BoundNotSatisfied {
+                    location,
+                    item: self.identifier.to_owned(),
+                    name: parameter.identifier.name.to_owned(),
+                    r#type: Argument::Type(r#type.to_owned()).to_string(),
+                    r#trait: bound.name.to_owned(),
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Wraps the generic error into the semantic one.
+    ///
+    pub fn error(error: Error) -> SemanticError {
+        SemanticError::Element(ElementError::Type(TypeError::Generic(error)))
+    }
+
+    ///
+    /// Binds the parameter `name` to the `argument`, checking the previously inferred value.
+    ///
+    fn bind(
+        &self,
+        location: Location,
+        name: String,
+        argument: Argument,
+        arguments: &mut HashMap<String, Argument>,
+    ) -> Result<(), Error> {
+        match arguments.get(name.as_str()) {
+            Some(previous) if previous != &argument => Err(Error::InferenceConflict {
+                location,
+                item: self.identifier.to_owned(),
+                name,
+                expected: previous.to_string(),
+                found: argument.to_string(),
+            }),
+            Some(_) => Ok(()),
+            None => {
+                arguments.insert(name, argument);
+                Ok(())
+            }
+        }
+    }
+
+    ///
+    /// Returns the parameter name if the `path` is a single identifier naming a parameter
+    /// of the specified kind.
+    ///
+    fn parameter_name(&self, path: &ExpressionTree, is_constant: bool) -> Option<String> {
+        self.parameters
+            .iter()
+            .find(|parameter| {
+                parameter.is_constant() == is_constant
+                    && Self::is_identifier(path, parameter.identifier.name.as_str())
+            })
+            .map(|parameter| parameter.identifier.name.to_owned())
+    }
+
+    ///
+    /// Checks whether the `path` is a single identifier with the specified `name`.
+    ///
+    fn is_identifier(path: &ExpressionTree, name: &str) -> bool {
+        if path.left.is_some() || path.right.is_some() {
+            return false;
+        }
+
+        match *path.value {
+            ExpressionTreeNode::Operand(ExpressionOperand::Identifier(ref identifier)) => {
+                identifier.name == name
+            }
+            _ => false,
+        }
+    }
+}
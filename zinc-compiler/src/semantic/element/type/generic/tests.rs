@@ -0,0 +1,326 @@
+//!
+//! The semantic analyzer generic item element tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::generic::error::Error as GenericError;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_function_multiple_instances() {
+    let input = r#"
+fn max<T>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
+
+fn main() -> (u8, u64) {
+    (max(1 as u8, 2 as u8), max(3 as u64, 4 as u64))
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_function_constant_parameter() {
+    let input = r#"
+fn first<T, const N: usize>(array: [T; N]) -> T {
+    array[N - 1 - (N - 1)]
+}
+
+fn main() -> (u8, bool) {
+    (first([1, 2, 3]), first([true, false]))
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_structure() {
+    let input = r#"
+struct Pair<T> {
+    a: T,
+    b: T,
+}
+
+fn swap<T>(pair: Pair<T>) -> Pair<T> {
+    Pair { a: pair.b, b: pair.a }
+}
+
+fn main() -> u8 {
+    let pair: Pair<u8> = Pair { a: 1 as u8, b: 2 as u8 };
+    swap(pair).a
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_bound() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> field;
+}
+
+struct Data {
+    value: u8,
+}
+
+impl Hashable for Data {
+    fn hash(self) -> field {
+        self.value as field
+    }
+}
+
+fn digest<T: Hashable>(value: T) -> field {
+    value.hash()
+}
+
+fn main() -> field {
+    digest(Data { value: 42 })
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_duplicate_parameter() {
+    let input = r#"
+fn f<T, T>(a: T) -> T {
+    a
+}
+
+fn main() -> u8 {
+    f(42 as u8)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Generic(GenericError::DuplicateParameter {
+            location: Location::test(2, 9),
+            item: "f".to_owned(),
+            name: "T".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_constant_parameter_forbidden() {
+    let input = r#"
+struct Data<const N: u64> {
+    a: [u8; N],
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Generic(GenericError::ConstantParameterForbidden {
+            location: Location::test(2, 13),
+            item: "Data".to_owned(),
+            name: "N".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_cannot_infer() {
+    let input = r#"
+fn default<T>() -> T {
+    0
+}
+
+fn main() -> u8 {
+    default()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Generic(GenericError::CannotInfer {
+            location: Location::test(7, 12),
+            item: "default".to_owned(),
+            name: "T".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_inference_conflict() {
+    let input = r#"
+fn max<T>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
+
+fn main() -> u8 {
+    max(1 as u8, true)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Generic(GenericError::InferenceConflict {
+            location: Location::test(7, 8),
+            item: "max".to_owned(),
+            name: "T".to_owned(),
+            expected: "u8".to_owned(),
+            found: "bool".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_constant_argument_overflow() {
+    let input = r#"
+fn length<T, const N: u8>(array: [T; N]) -> u8 {
+    N
+}
+
+fn main() -> u8 {
+    length([0; 256])
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Generic(GenericError::ConstantArgumentOverflow {
+            location: Location::test(7, 11),
+            name: "N".to_owned(),
+            value: 256,
+            r#type: "u8".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_instance_return_type() {
+    let input = r#"
+fn identity<T>(a: T) -> u8 {
+    a
+}
+
+fn main() -> u8 {
+    identity(true)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ReturnType {
+            location: Location::test(3, 5),
+            function: "identity<bool>".to_owned(),
+            expected: "u8".to_owned(),
+            found: "bool".to_owned(),
+            reference: Location::test(2, 25),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_entry_point() {
+    let input = r#"
+fn main<T>(input: T) -> T {
+    input
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Generic(GenericError::EntryPoint {
+            location: Location::test(2, 1),
+            function: "main".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bound_expected_trait() {
+    let input = r#"
+struct Data {}
+
+fn digest<T: Data>(value: T) -> T {
+    value
+}
+
+fn main() -> bool {
+    digest(true)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Generic(GenericError::BoundExpectedTrait {
+            location: Location::test(4, 14),
+            found: "Data".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bound_not_satisfied() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> field;
+}
+
+struct Data {
+    value: u8,
+}
+
+fn digest<T: Hashable>(value: T) -> field {
+    value.hash()
+}
+
+fn main() -> field {
+    digest(Data { value: 42 })
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Generic(GenericError::BoundNotSatisfied {
+            location: Location::test(15, 11),
+            item: "digest".to_owned(),
+            name: "T".to_owned(),
+            r#type: "Data".to_owned(),
+            r#trait: "Hashable".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
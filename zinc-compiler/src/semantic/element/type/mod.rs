@@ -10,7 +10,9 @@ pub mod contract;
 pub mod enumeration;
 pub mod error;
 pub mod function;
+pub mod generic;
 pub mod i_typed;
+pub mod option;
 pub mod range;
 pub mod range_inclusive;
 pub mod structure;
@@ -23,6 +25,8 @@ use std::rc::Rc;
 
 use zinc_lexical::Location;
 use zinc_syntax::BlockExpression;
+use zinc_syntax::ClosureExpression;
+use zinc_syntax::FnStatement;
 use zinc_syntax::Type as SyntaxType;
 use zinc_syntax::TypeVariant as SyntaxTypeVariant;
 use zinc_syntax::Variant;
@@ -30,6 +34,7 @@ use zinc_syntax::Variant;
 use crate::semantic::analyzer::expression::error::Error as ExpressionError;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::analyzer::statement::r#fn::Context as FnStatementAnalyzerContext;
 use crate::semantic::binding::Binding;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::Constant;
@@ -46,10 +51,16 @@ use self::array::Array;
 use self::contract::field::Field as ContractField;
 use self::contract::Contract;
 use self::enumeration::Enumeration;
+use self::function::closure::Function as ClosureFunction;
+use self::function::generic::Function as GenericFunction;
 use self::function::Function;
+use self::generic::Argument as GenericArgument;
+use self::generic::Generics;
 use self::i_typed::ITyped;
+use self::option::Optional;
 use self::range::Range;
 use self::range_inclusive::RangeInclusive;
+use self::structure::template::Template as StructureTemplate;
 use self::structure::Structure;
 use self::tuple::Tuple;
 
@@ -90,6 +101,8 @@ pub enum Type {
     Array(Array),
     /// The ordinar tuple type.
     Tuple(Tuple),
+    /// The built-in `Option` type, which is either `Some(value)` or `None`.
+    Option(Optional),
     /// The ordinar structure type declared with a `struct` statement.
     Structure(Structure),
     /// The ordinar enumeration type declared with an `enum` statement.
@@ -203,6 +216,13 @@ impl Type {
         Self::Tuple(Tuple::new(location, types))
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn option(location: Option<Location>, r#type: Self) -> Self {
+        Self::Option(Optional::new(location, Some(r#type)))
+    }
+
     ///
     /// A helper type constructor, which allocates a unique sequence ID for the type.
     ///
@@ -220,6 +240,33 @@ impl Type {
         ))
     }
 
+    ///
+    /// A helper type constructor, which allocates a unique sequence ID for the type.
+    ///
+    /// The generic structure template is instantiated upon usage with the generic arguments.
+    ///
+    pub fn structure_template(
+        location: Location,
+        identifier: String,
+        template: StructureTemplate,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        let type_id = TYPE_INDEX.next(format!("structure {}", identifier));
+
+        let mut structure = Structure::new(
+            Some(location),
+            identifier,
+            type_id,
+            vec![],
+            Some(template.generics.names()),
+            None,
+            Some(scope),
+        );
+        structure.template = Some(Rc::new(template));
+
+        Self::Structure(structure)
+    }
+
     ///
     /// A helper type constructor, which allocates a unique sequence ID for the type.
     ///
@@ -281,6 +328,24 @@ impl Type {
         ))
     }
 
+    ///
+    /// A helper type constructor, which allocates a unique sequence ID for the type.
+    ///
+    pub fn generic_function(
+        location: Location,
+        identifier: String,
+        generics: Generics,
+        statement: FnStatement,
+        context: FnStatementAnalyzerContext,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        let type_id = TYPE_INDEX.next(format!("function {}", identifier));
+
+        Self::Function(Function::Generic(GenericFunction::new(
+            location, identifier, type_id, generics, statement, context, scope,
+        )))
+    }
+
     ///
     /// A helper type constructor, which allocates a unique sequence ID for the type.
     ///
@@ -293,6 +358,21 @@ impl Type {
         )
     }
 
+    ///
+    /// A helper type constructor, which allocates a unique sequence ID for the type.
+    ///
+    pub fn closure_function(
+        location: Location,
+        expression: ClosureExpression,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        let type_id = TYPE_INDEX.next(format!("closure {}", location));
+
+        Self::Function(Function::Closure(ClosureFunction::new(
+            location, type_id, expression, scope,
+        )))
+    }
+
     ///
     /// A helper type constructor, which allocates a unique sequence ID for the type.
     ///
@@ -313,6 +393,8 @@ impl Type {
     /// The contract's size is zero, since its fields are stored in the contract storage, but not
     /// in the data stack.
     ///
+    /// The `Option` size is its payload size plus the boolean tag.
+    ///
     pub fn size(&self) -> usize {
         match self {
             Self::Unit(_) => 0,
@@ -325,11 +407,20 @@ impl Type {
             Self::RangeInclusive(_) => 0,
             Self::Array(inner) => inner.r#type.size() * inner.size,
             Self::Tuple(inner) => inner.types.iter().map(|r#type| r#type.size()).sum(),
+            Self::Option(inner) => {
+                inner
+                    .inner
+                    .as_ref()
+                    .map(|r#type| r#type.size())
+                    .unwrap_or_default()
+                    + 1
+            }
             Self::Structure(inner) => inner
                 .fields
                 .iter()
                 .map(|(_name, r#type)| r#type.size())
                 .sum(),
+            Self::Enumeration(inner) if inner.is_data_carrying() => inner.payload_size() + 1,
             Self::Enumeration(_inner) => 1,
             Self::Contract(_inner) => 0,
             Self::Function(_inner) => 0,
@@ -345,7 +436,7 @@ impl Type {
             Self::IntegerUnsigned { .. } => true,
             Self::IntegerSigned { .. } => true,
             Self::Field(_) => true,
-            Self::Enumeration { .. } => true,
+            Self::Enumeration(inner) => !inner.is_data_carrying(),
             _ => false,
         }
     }
@@ -359,7 +450,7 @@ impl Type {
             Self::Boolean(_) => true,
             Self::IntegerUnsigned { .. } => true,
             Self::Field(_) => true,
-            Self::Enumeration { .. } => true,
+            Self::Enumeration(inner) => !inner.is_data_carrying(),
             _ => false,
         }
     }
@@ -378,7 +469,7 @@ impl Type {
         match self {
             Self::IntegerUnsigned { .. } => true,
             Self::Field(_) => true,
-            Self::Enumeration { .. } => true,
+            Self::Enumeration(inner) => !inner.is_data_carrying(),
             _ => false,
         }
     }
@@ -422,6 +513,7 @@ impl Type {
         match self {
             Self::Function(Function::Runtime(_)) => true,
             Self::Function(Function::Constant(_)) => true,
+            Self::Function(Function::Generic(_)) => true,
             _ => false,
         }
     }
@@ -446,6 +538,11 @@ impl Type {
                 .types
                 .iter()
                 .all(|r#type| Self::is_instantiatable(r#type, false)),
+            Self::Option(inner) => inner
+                .inner
+                .as_ref()
+                .map(|r#type| r#type.is_instantiatable(false))
+                .unwrap_or_default(),
             Self::Structure(inner) => {
                 (is_contract_field || !self.is_mtreemap())
                     && inner
@@ -461,7 +558,12 @@ impl Type {
                         .map(|(_name, r#type)| r#type)
                         .all(|r#type| Self::is_instantiatable(r#type, false))
             }
-            Self::Enumeration(_) => true,
+            Self::Enumeration(inner) => inner
+                .payloads
+                .iter()
+                .flatten()
+                .flat_map(|payload| payload.types())
+                .all(|r#type| Self::is_instantiatable(&r#type, false)),
             Self::Function(_) => false,
             Self::Contract(inner) => inner
                 .fields
@@ -482,6 +584,19 @@ impl Type {
         }
     }
 
+    ///
+    /// Checks if the type is the `None` value type, whose payload type can be inferred from
+    /// the `expected` one.
+    ///
+    pub fn is_none_inferable(&self, expected: &Self) -> bool {
+        match (self, expected) {
+            (Self::Option(inner), Self::Option(expected)) => {
+                !inner.is_inferred() && expected.is_inferred()
+            }
+            _ => false,
+        }
+    }
+
     ///
     /// Sets the generic arguments for the type.
     ///
@@ -493,11 +608,35 @@ impl Type {
         generics: Option<Vec<Type>>,
     ) -> Result<(), Error> {
         match self {
+            Self::Structure(inner) if inner.template.is_some() => {
+                let template = inner
+                    .template
+                    .to_owned()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+                match generics {
+                    Some(generics) if generics.len() == template.generics.parameters.len() => {
+                        let arguments = generics.into_iter().map(GenericArgument::Type).collect();
+                        *inner = template.instantiate(location, arguments)?;
+                        Ok(())
+                    }
+                    generics => inner
+                        .set_generics(location, generics)
+                        .map_err(TypeError::Structure)
+                        .map_err(ElementError::Type)
+                        .map_err(Error::Element),
+                }
+            }
             Self::Structure(inner) => inner
                 .set_generics(location, generics)
                 .map_err(TypeError::Structure)
                 .map_err(ElementError::Type)
                 .map_err(Error::Element),
+            Self::Option(inner) => inner
+                .set_generics(location, generics)
+                .map_err(TypeError::Option)
+                .map_err(ElementError::Type)
+                .map_err(Error::Element),
             ref r#type if generics.is_some() => Err(Error::Element(ElementError::Type(
                 TypeError::UnexpectedGenerics {
                     location: self.location().unwrap_or(location),
@@ -556,6 +695,11 @@ impl Type {
                 }
                 Self::tuple(Some(location), types)
             }
+            SyntaxTypeVariant::Function { .. } => {
+                return Err(Error::Element(ElementError::Type(
+                    TypeError::FunctionTypeOutsideArgument { location },
+                )));
+            }
             SyntaxTypeVariant::Alias { path, generics } => {
                 let location = path.location;
                 match ExpressionAnalyzer::new(scope.clone(), TranslationRule::Type).analyze(path)? {
@@ -640,6 +784,7 @@ impl Type {
             Self::RangeInclusive(inner) => inner.location = Some(value),
             Self::Array(inner) => inner.location = Some(value),
             Self::Tuple(inner) => inner.location = Some(value),
+            Self::Option(inner) => inner.location = Some(value),
             Self::Structure(inner) => inner.location = Some(value),
             Self::Enumeration(inner) => inner.location = Some(value),
             Self::Function(inner) => inner.set_location(value),
@@ -662,6 +807,7 @@ impl Type {
             Self::RangeInclusive(inner) => inner.location,
             Self::Array(inner) => inner.location,
             Self::Tuple(inner) => inner.location,
+            Self::Option(inner) => inner.location,
             Self::Structure(inner) => inner.location,
             Self::Enumeration(inner) => inner.location,
             Self::Function(inner) => inner.location(),
@@ -693,6 +839,7 @@ impl PartialEq<Type> for Type {
                 inner_1.r#type == inner_2.r#type && inner_1.size == inner_2.size
             }
             (Self::Tuple(inner_1), Self::Tuple(inner_2)) => inner_1.types == inner_2.types,
+            (Self::Option(inner_1), Self::Option(inner_2)) => inner_1 == inner_2,
             (Self::Structure(inner_1), Self::Structure(inner_2)) => inner_1 == inner_2,
             (Self::Enumeration(inner_1), Self::Enumeration(inner_2)) => inner_1 == inner_2,
             (Self::Contract(inner_1), Self::Contract(inner_2)) => inner_1 == inner_2,
@@ -714,6 +861,7 @@ impl fmt::Display for Type {
             Self::RangeInclusive(inner) => write!(f, "range inclusive {}", inner),
             Self::Array(inner) => write!(f, "array {}", inner),
             Self::Tuple(inner) => write!(f, "tuple {}", inner),
+            Self::Option(inner) => write!(f, "{}", inner),
             Self::Structure(inner) => write!(f, "structure {}", inner),
             Self::Enumeration(inner) => write!(f, "enumeration {}", inner),
             Self::Function(inner) => write!(f, "function {}", inner),
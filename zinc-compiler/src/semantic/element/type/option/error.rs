@@ -0,0 +1,30 @@
+//!
+//! The semantic analyzer `Option` type element error.
+//!
+
+use zinc_lexical::Location;
+
+///
+/// The semantic analyzer `Option` type element error.
+///
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The `Option` type is used without its payload type, e.g. `Option` instead of `Option<u8>`.
+    ExpectedGenerics {
+        /// The type location.
+        location: Location,
+    },
+    /// The `Option` type got more than one generic argument.
+    InvalidGenericsNumber {
+        /// The type location.
+        location: Location,
+        /// The number of found generic arguments.
+        found: usize,
+    },
+    /// The `None` payload type cannot be inferred from the context, so it must be specified
+    /// explicitly, e.g. with a `let` statement type annotation.
+    PayloadTypeNotInferred {
+        /// The `None` value location.
+        location: Location,
+    },
+}
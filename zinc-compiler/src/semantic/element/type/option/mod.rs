@@ -0,0 +1,92 @@
+//!
+//! The semantic analyzer `Option` type element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::r#type::Type;
+
+use self::error::Error;
+
+///
+/// The semantic analyzer `Option` type element.
+///
+/// The payload type is unknown for the bare intrinsic `Option` type, until it is set via the
+/// generic arguments, and for the `None` value, until it is inferred from the context.
+///
+#[derive(Debug, Clone)]
+pub struct Optional {
+    /// The type location in the code.
+    pub location: Option<Location>,
+    /// The payload type.
+    pub inner: Option<Box<Type>>,
+}
+
+impl Optional {
+    /// The intrinsic type identifier.
+    pub const IDENTIFIER: &'static str = "Option";
+
+    /// The number of generic arguments, which is the payload type only.
+    pub const GENERICS_COUNT: usize = 1;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Option<Location>, inner: Option<Type>) -> Self {
+        Self {
+            location,
+            inner: inner.map(Box::new),
+        }
+    }
+
+    ///
+    /// Whether the payload type is known.
+    ///
+    pub fn is_inferred(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    ///
+    /// Sets the payload type from the generic arguments.
+    ///
+    pub fn set_generics(
+        &mut self,
+        location: Location,
+        generics: Option<Vec<Type>>,
+    ) -> Result<(), Error> {
+        match generics {
+            Some(mut generics) if generics.len() == Self::GENERICS_COUNT => {
+                self.inner = Some(Box::new(generics.remove(0)));
+                Ok(())
+            }
+            Some(generics) => Err(Error::InvalidGenericsNumber {
+                location,
+                found: generics.len(),
+            }),
+            None if self.inner.is_some() => Ok(()),
+            None => Err(Error::ExpectedGenerics { location }),
+        }
+    }
+}
+
+impl PartialEq<Self> for Optional {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl fmt::Display for Optional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.inner {
+            Some(ref inner) => write!(f, "{}<{}>", Self::IDENTIFIER, inner),
+            None => write!(f, "{}<_>", Self::IDENTIFIER),
+        }
+    }
+}
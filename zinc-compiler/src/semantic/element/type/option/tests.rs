@@ -0,0 +1,92 @@
+//!
+//! The semantic analyzer `Option` type element tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::option::error::Error as OptionTypeError;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_none_annotated() {
+    let input = r#"
+fn main() -> Option<u8> {
+    let value: Option<u8> = None;
+    value
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_none_returned() {
+    let input = r#"
+fn main(condition: bool) -> Option<u8> {
+    if condition {
+        Some(42)
+    } else {
+        None
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_expected_generics() {
+    let input = r#"
+fn main(value: Option) {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Option(OptionTypeError::ExpectedGenerics {
+            location: Location::test(2, 16),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_invalid_generics_number() {
+    let input = r#"
+fn main(value: Option<u8, bool>) {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Option(OptionTypeError::InvalidGenericsNumber {
+            location: Location::test(2, 16),
+            found: 2,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payload_type_not_inferred() {
+    let input = r#"
+fn main() {
+    let value = None;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Option(OptionTypeError::PayloadTypeNotInferred {
+            location: Location::test(3, 9),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -6,6 +6,7 @@
 mod tests;
 
 pub mod error;
+pub mod template;
 
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -18,6 +19,7 @@ use crate::semantic::element::r#type::Type;
 use crate::semantic::scope::Scope;
 
 use self::error::Error;
+use self::template::Template;
 
 ///
 /// Describes a structure type.
@@ -42,6 +44,9 @@ pub struct Structure {
     pub params: Option<HashMap<String, Type>>,
     /// The structure scope, where its methods and associated items are declared.
     pub scope: Rc<RefCell<Scope>>,
+    /// The generic structure template, which is set if the structure must be instantiated
+    /// with generic arguments before usage.
+    pub template: Option<Rc<Template>>,
 }
 
 impl Structure {
@@ -67,6 +72,7 @@ impl Structure {
             generics,
             params,
             scope,
+            template: None,
         }
     }
 
@@ -97,6 +103,7 @@ impl Structure {
 
                 Ok(())
             }
+            (Some(_names), None) if self.params.is_some() => Ok(()),
             (Some(names), None) => Err(Error::ExpectedGenerics {
                 location,
                 type_identifier: self.identifier.to_owned(),
@@ -0,0 +1,150 @@
+//!
+//! The semantic analyzer generic structure template.
+//!
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::StructStatement;
+
+use crate::semantic::analyzer::statement::r#struct::Analyzer as StructStatementAnalyzer;
+use crate::semantic::element::r#type::generic::Argument as GenericArgument;
+use crate::semantic::element::r#type::generic::Generics;
+use crate::semantic::element::r#type::structure::Structure;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::error::Error as ScopeError;
+use crate::semantic::scope::Scope;
+
+///
+/// The generic structure template, which is instantiated as an ordinar structure type for each
+/// distinct list of generic arguments.
+///
+#[derive(Debug)]
+pub struct Template {
+    /// The structure generic parameters.
+    pub generics: Generics,
+    /// The structure syntax representation, which is analyzed upon each instantiation.
+    pub statement: StructStatement,
+    /// The structure scope, where the template is declared.
+    pub scope: Rc<RefCell<Scope>>,
+    /// The structure instances. The type is `None` while the instance is being analyzed.
+    pub instances: RefCell<Vec<(Vec<GenericArgument>, Option<Structure>)>>,
+}
+
+impl Template {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(generics: Generics, statement: StructStatement, scope: Rc<RefCell<Scope>>) -> Self {
+        Self {
+            generics,
+            statement,
+            scope,
+            instances: RefCell::new(Vec::new()),
+        }
+    }
+
+    ///
+    /// Infers the generic arguments from the structure literal `fields` types.
+    ///
+    pub fn infer_from_fields(
+        &self,
+        location: Location,
+        fields: &[(String, Type)],
+    ) -> Result<Vec<GenericArgument>, SemanticError> {
+        let mut inferred = HashMap::with_capacity(self.generics.parameters.len());
+        for (name, r#type) in fields.iter() {
+            if let Some(field) = self
+                .statement
+                .fields
+                .iter()
+                .find(|field| &field.identifier.name == name)
+            {
+                self.generics
+                    .infer(location, &field.r#type, r#type, &mut inferred)
+                    .map_err(Generics::error)?;
+            }
+        }
+
+        self.generics
+            .collect(location, inferred)
+            .map_err(Generics::error)
+    }
+
+    ///
+    /// Returns the structure instance, analyzing it if it has not been instantiated yet.
+    ///
+    pub fn instantiate(
+        &self,
+        location: Location,
+        arguments: Vec<GenericArgument>,
+    ) -> Result<Structure, SemanticError> {
+        if let Some((_arguments, instance)) = self
+            .instances
+            .borrow()
+            .iter()
+            .find(|(instance_arguments, _instance)| instance_arguments == &arguments)
+        {
+            return match instance {
+                Some(instance) => Ok(instance.to_owned()),
+                None => Err(SemanticError::Scope(ScopeError::ReferenceLoop { location })),
+            };
+        }
+
+        self.instances.borrow_mut().push((arguments.clone(), None));
+
+        let result = self.define_instance(location, arguments.as_slice());
+
+        let mut instances = self.instances.borrow_mut();
+        let index = instances
+            .iter()
+            .position(|(instance_arguments, _instance)| instance_arguments == &arguments)
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        match result {
+            Ok(instance) => {
+                instances[index].1 = Some(instance.clone());
+                Ok(instance)
+            }
+            Err(error) => {
+                instances.remove(index);
+                Err(error)
+            }
+        }
+    }
+
+    ///
+    /// Analyzes the structure instance with the generic parameters bound to the `arguments`.
+    ///
+    fn define_instance(
+        &self,
+        location: Location,
+        arguments: &[GenericArgument],
+    ) -> Result<Structure, SemanticError> {
+        let scope = self
+            .generics
+            .define_arguments(location, self.scope.clone(), arguments)?;
+
+        let mut statement = self.statement.clone();
+        statement.identifier.name = self.generics.instance_identifier(arguments);
+        statement.generics.clear();
+
+        match StructStatementAnalyzer::define(scope, statement)? {
+            Type::Structure(mut structure) => {
+                let mut params = HashMap::with_capacity(arguments.len());
+                for (name, argument) in self.generics.names().into_iter().zip(arguments.iter()) {
+                    if let GenericArgument::Type(r#type) = argument {
+                        params.insert(name, r#type.to_owned());
+                    }
+                }
+
+                structure.generics = Some(self.generics.names());
+                structure.params = Some(params);
+                Ok(structure)
+            }
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
+}
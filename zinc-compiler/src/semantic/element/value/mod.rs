@@ -8,8 +8,10 @@ mod tests;
 pub mod array;
 pub mod boolean;
 pub mod contract;
+pub mod enumeration;
 pub mod error;
 pub mod integer;
+pub mod option;
 pub mod structure;
 pub mod tuple;
 pub mod unit;
@@ -44,8 +46,10 @@ use crate::semantic::element::tuple_index::TupleIndex;
 use self::array::Array;
 use self::boolean::Boolean;
 use self::contract::Contract;
+use self::enumeration::Enumeration;
 use self::error::Error;
 use self::integer::Integer;
+use self::option::Optional;
 use self::structure::Structure;
 use self::tuple::Tuple;
 use self::unit::Unit;
@@ -72,6 +76,10 @@ pub enum Value {
     Structure(Structure),
     /// The contract type value.
     Contract(Contract),
+    /// The `Option` type value.
+    Option(Optional),
+    /// The data-carrying enumeration type value.
+    Enumeration(Enumeration),
 }
 
 impl Value {
@@ -871,6 +879,9 @@ impl Value {
                 location.or_else(|| inner.location),
                 inner.to_owned(),
             )),
+            Type::Enumeration(inner) if inner.is_data_carrying() => Self::Enumeration(
+                Enumeration::new(location.or_else(|| inner.location), inner.to_owned()),
+            ),
             Type::Enumeration(inner) => {
                 let mut integer = Integer::new(
                     location.or_else(|| inner.location),
@@ -885,6 +896,10 @@ impl Value {
                 location.or_else(|| Some(inner.location)),
                 inner.to_owned(),
             )),
+            Type::Option(inner) => Self::Option(Optional::new(
+                location.or_else(|| inner.location),
+                inner.to_owned(),
+            )),
             _ => panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
         })
     }
@@ -913,6 +928,8 @@ impl Value {
             Self::Tuple(inner) => inner.location,
             Self::Structure(inner) => inner.location,
             Self::Contract(inner) => inner.location,
+            Self::Option(inner) => inner.location,
+            Self::Enumeration(inner) => inner.location,
         }
     }
 }
@@ -927,6 +944,8 @@ impl ITyped for Value {
             Self::Tuple(inner) => inner.r#type(),
             Self::Structure(inner) => inner.r#type(),
             Self::Contract(inner) => inner.r#type(),
+            Self::Option(inner) => inner.r#type(),
+            Self::Enumeration(inner) => inner.r#type(),
         }
     }
 
@@ -945,6 +964,10 @@ impl ITyped for Value {
             (Self::Contract(value_1), Self::Contract(value_2)) => {
                 value_1.has_the_same_type_as(value_2)
             }
+            (Self::Option(value_1), Self::Option(value_2)) => value_1.has_the_same_type_as(value_2),
+            (Self::Enumeration(value_1), Self::Enumeration(value_2)) => {
+                value_1.has_the_same_type_as(value_2)
+            }
             _ => false,
         }
     }
@@ -960,6 +983,8 @@ impl fmt::Display for Value {
             Self::Tuple(inner) => write!(f, "tuple {}", inner),
             Self::Structure(inner) => write!(f, "structure {}", inner),
             Self::Contract(inner) => write!(f, "contract {}", inner),
+            Self::Option(inner) => write!(f, "option {}", inner),
+            Self::Enumeration(inner) => write!(f, "enumeration {}", inner),
         }
     }
 }
@@ -0,0 +1,47 @@
+//!
+//! The semantic analyzer `Option` value element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::option::Optional as OptionalType;
+use crate::semantic::element::r#type::Type;
+
+///
+/// The `Option` value, which is either `Some(value)` or `None` only known at runtime.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Optional {
+    /// The value location in the code.
+    pub location: Option<Location>,
+    /// The value type.
+    pub r#type: OptionalType,
+}
+
+impl Optional {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Option<Location>, r#type: OptionalType) -> Self {
+        Self { location, r#type }
+    }
+}
+
+impl ITyped for Optional {
+    fn r#type(&self) -> Type {
+        Type::Option(self.r#type.to_owned())
+    }
+
+    fn has_the_same_type_as(&self, other: &Self) -> bool {
+        self.r#type == other.r#type
+    }
+}
+
+impl fmt::Display for Optional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'", self.r#type)
+    }
+}
@@ -62,4 +62,13 @@ pub enum Error {
         /// The position of the provided structure field.
         found: usize,
     },
+    /// A field of the structure type is not initialized in the structure literal.
+    FieldMissing {
+        /// The error location data.
+        location: Location,
+        /// The stringified structure type.
+        type_identifier: String,
+        /// The name of the missing field.
+        field_name: String,
+    },
 }
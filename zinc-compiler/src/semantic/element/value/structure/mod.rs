@@ -85,13 +85,30 @@ impl Structure {
     /// Sets the structure type and checks if the pushed field types match it.
     ///
     pub fn validate(&mut self, expected: StructureType) -> Result<(), Error> {
+        self.validate_fields(expected.identifier.as_str(), expected.fields.as_slice())?;
+
+        self.r#type = Some(expected);
+
+        Ok(())
+    }
+
+    ///
+    /// Checks if the pushed field names and types match the `expected` ones.
+    ///
+    /// Is also used to validate the structure-like enumeration variant literals.
+    ///
+    pub fn validate_fields(
+        &self,
+        type_identifier: &str,
+        expected: &[(String, Type)],
+    ) -> Result<(), Error> {
         for (index, (name, location, r#type)) in self.fields.iter().enumerate() {
-            match expected.fields.get(index) {
+            match expected.get(index) {
                 Some((expected_name, expected_type)) => {
                     if name != expected_name {
                         return Err(Error::FieldExpected {
                             location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                            type_identifier: expected.identifier.to_owned(),
+                            type_identifier: type_identifier.to_owned(),
                             position: index + 1,
                             expected: expected_name.to_owned(),
                             found: name.to_owned(),
@@ -101,7 +118,7 @@ impl Structure {
                     if r#type != expected_type {
                         return Err(Error::FieldInvalidType {
                             location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                            type_identifier: expected.identifier.to_owned(),
+                            type_identifier: type_identifier.to_owned(),
                             field_name: expected_name.to_owned(),
                             expected: expected_type.to_string(),
                             found: r#type.to_string(),
@@ -111,15 +128,21 @@ impl Structure {
                 None => {
                     return Err(Error::FieldOutOfRange {
                         location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                        type_identifier: expected.identifier.to_owned(),
-                        expected: expected.fields.len(),
+                        type_identifier: type_identifier.to_owned(),
+                        expected: expected.len(),
                         found: index + 1,
                     });
                 }
             }
         }
 
-        self.r#type = Some(expected);
+        if let Some((name, _type)) = expected.get(self.fields.len()) {
+            return Err(Error::FieldMissing {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                type_identifier: type_identifier.to_owned(),
+                field_name: name.to_owned(),
+            });
+        }
 
         Ok(())
     }
@@ -3,7 +3,7 @@
 //!
 
 #[cfg(test)]
-mod tests;
+pub(crate) mod tests;
 
 pub mod analyzer;
 pub mod binding;
@@ -53,4 +53,12 @@ pub enum Error {
         /// The error location data.
         location: Location,
     },
+    /// A closure, which is passed to another function, captures a local variable of the function
+    /// where it is declared. Such closures can only be called within their declaring function.
+    ClosureCapture {
+        /// The error location data.
+        location: Location,
+        /// The captured variable name.
+        name: String,
+    },
 }
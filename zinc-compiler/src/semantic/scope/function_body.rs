@@ -0,0 +1,49 @@
+//!
+//! The semantic analyzer scope function body.
+//!
+
+use zinc_lexical::Location;
+
+use crate::lint::Lint;
+use crate::semantic::element::r#type::Type;
+
+///
+/// The function body metadata, which is used to check the `return` statements.
+///
+#[derive(Debug, Clone)]
+pub struct FunctionBody {
+    /// The function identifier.
+    pub identifier: String,
+    /// The declared function result type.
+    pub output_type: Type,
+    /// The location of the result type, or of the function if the type is omitted.
+    pub output_location: Location,
+    /// Whether the function is a constant one, which cannot contain `return` statements.
+    pub is_constant: bool,
+    /// Whether the function body contains a `return` statement.
+    pub is_returned: bool,
+    /// The lints suppressed with the function `#[allow(...)]` attributes.
+    pub allowed_lints: Vec<Lint>,
+}
+
+impl FunctionBody {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        identifier: String,
+        output_type: Type,
+        output_location: Location,
+        is_constant: bool,
+        allowed_lints: Vec<Lint>,
+    ) -> Self {
+        Self {
+            identifier,
+            output_type,
+            output_location,
+            is_constant,
+            is_returned: false,
+            allowed_lints,
+        }
+    }
+}
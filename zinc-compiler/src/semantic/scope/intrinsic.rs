@@ -6,10 +6,16 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use zinc_build::LibraryFunctionIdentifier;
+use zinc_lexical::Keyword;
+use zinc_lexical::Location;
 
+use crate::semantic::element::constant::option::Optional as OptionConstant;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::option::Optional as OptionType;
 use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::scope::item::constant::Constant as ScopeConstantItem;
 use crate::semantic::scope::item::module::Module as ScopeModuleItem;
 use crate::semantic::scope::item::r#type::Type as ScopeTypeItem;
 use crate::semantic::scope::item::variable::Variable as ScopeVariableItem;
@@ -20,7 +26,8 @@ use crate::semantic::scope::Scope;
 ///
 /// An intrinsic items set instance creator.
 ///
-/// The intrinsic items are functions `dbg!` and `require` and the `std` and `zksync` libraries.
+/// The intrinsic items are functions `dbg!` and `require`, the `Option` type with its `Some`
+/// and `None` variants, and the `std` and `zksync` libraries.
 ///
 #[derive(Debug)]
 pub struct IntrinsicScope {}
@@ -68,6 +75,38 @@ impl IntrinsicScope {
             .wrap(),
         );
 
+        Scope::insert_item(
+            scope.clone(),
+            OptionType::IDENTIFIER.to_owned(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(
+                Type::Option(OptionType::new(None, None)),
+                false,
+            ))
+            .wrap(),
+        );
+
+        let function_some = FunctionType::new_some();
+        Scope::insert_item(
+            scope.clone(),
+            function_some.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(
+                Type::Function(function_some),
+                false,
+            ))
+            .wrap(),
+        );
+
+        Scope::insert_item(
+            scope.clone(),
+            OptionConstant::IDENTIFIER_NONE.to_owned(),
+            ScopeItem::Constant(ScopeConstantItem::new_defined(
+                Location::default(),
+                Constant::Option(OptionConstant::new_none(Location::default())),
+                false,
+            ))
+            .wrap(),
+        );
+
         Scope::insert_item(
             scope.clone(),
             "std".to_owned(),
@@ -91,6 +130,50 @@ impl IntrinsicScope {
         scope
     }
 
+    ///
+    /// Lists the names and descriptions of the intrinsic items declared at `path`, e.g.
+    /// `["std", "crypto"]`, sorted by name.
+    ///
+    /// Returns an empty list if the path does not point to an intrinsic module or type.
+    ///
+    pub fn complete(path: &[&str]) -> Vec<(String, String)> {
+        let mut scope = Self::initialize();
+
+        for name in path.iter() {
+            let item = match scope.borrow().items.borrow().get(*name) {
+                Some(item) => item.to_owned(),
+                None => return vec![],
+            };
+
+            let next = match *item.borrow() {
+                ScopeItem::Module(ref module) => module.scope().ok(),
+                ScopeItem::Type(ref r#type) => match r#type.define() {
+                    Ok(Type::Structure(inner)) => Some(inner.scope),
+                    Ok(Type::Enumeration(inner)) => Some(inner.scope),
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            scope = match next {
+                Some(next) => next,
+                None => return vec![],
+            };
+        }
+
+        let self_alias = Keyword::SelfUppercase.to_string();
+        let mut items: Vec<(String, String)> = scope
+            .borrow()
+            .items
+            .borrow()
+            .iter()
+            .filter(|(name, _item)| **name != self_alias)
+            .map(|(name, item)| (name.to_owned(), item.borrow().to_string()))
+            .collect();
+        items.sort();
+        items
+    }
+
     ///
     /// Initializes the `std` module scope.
     ///
@@ -154,6 +237,8 @@ impl IntrinsicScope {
 
         let sha256 = FunctionType::new_library(LibraryFunctionIdentifier::CryptoSha256);
         let pedersen = FunctionType::new_library(LibraryFunctionIdentifier::CryptoPedersen);
+        let poseidon = FunctionType::new_library(LibraryFunctionIdentifier::CryptoPoseidon);
+        let rescue = FunctionType::new_library(LibraryFunctionIdentifier::CryptoRescue);
 
         let schnorr_scope = Scope::new_intrinsic("schnorr").wrap();
         let schnorr_signature_scope = Scope::new_intrinsic("Signature").wrap();
@@ -224,6 +309,16 @@ impl IntrinsicScope {
             pedersen.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(pedersen), false)).wrap(),
         );
+        Scope::insert_item(
+            scope.clone(),
+            poseidon.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(poseidon), false)).wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            rescue.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(rescue), false)).wrap(),
+        );
         Scope::insert_item(
             scope.clone(),
             ecc_scope.borrow().name(),
@@ -305,6 +400,13 @@ impl IntrinsicScope {
         let reverse = FunctionType::new_library(LibraryFunctionIdentifier::ArrayReverse);
         let truncate = FunctionType::new_library(LibraryFunctionIdentifier::ArrayTruncate);
         let pad = FunctionType::new_library(LibraryFunctionIdentifier::ArrayPad);
+        let map = FunctionType::new_library(LibraryFunctionIdentifier::ArrayMap);
+        let fold = FunctionType::new_library(LibraryFunctionIdentifier::ArrayFold);
+        let zip = FunctionType::new_library(LibraryFunctionIdentifier::ArrayZip);
+        let contains = FunctionType::new_library(LibraryFunctionIdentifier::ArrayContains);
+        let index_of = FunctionType::new_library(LibraryFunctionIdentifier::ArrayIndexOf);
+        let sum = FunctionType::new_library(LibraryFunctionIdentifier::ArraySum);
+        let sort = FunctionType::new_library(LibraryFunctionIdentifier::ArraySort);
 
         Scope::insert_item(
             scope.clone(),
@@ -321,6 +423,41 @@ impl IntrinsicScope {
             pad.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(pad), false)).wrap(),
         );
+        Scope::insert_item(
+            scope.clone(),
+            map.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(map), false)).wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            fold.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(fold), false)).wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            zip.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(zip), false)).wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            contains.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(contains), false)).wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            index_of.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(index_of), false)).wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            sum.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(sum), false)).wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            sort.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(sort), false)).wrap(),
+        );
 
         scope
     }
@@ -430,6 +567,14 @@ impl IntrinsicScope {
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(transfer), false)).wrap(),
         );
 
+        let emit = FunctionType::new_library(LibraryFunctionIdentifier::ZksyncEmit);
+
+        Scope::insert_item(
+            scope.clone(),
+            emit.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(emit), false)).wrap(),
+        );
+
         let transaction_type = StructureType::new(
             None,
             "Transaction".to_owned(),
@@ -6,6 +6,7 @@ pub mod constant;
 pub mod field;
 pub mod index;
 pub mod module;
+pub mod r#trait;
 pub mod r#type;
 pub mod variable;
 pub mod variant;
@@ -21,6 +22,7 @@ use zinc_lexical::Location;
 use self::constant::Constant;
 use self::field::Field;
 use self::module::Module;
+use self::r#trait::Trait;
 use self::r#type::Type;
 use self::variable::Variable;
 use self::variant::Variant;
@@ -69,6 +71,8 @@ pub enum Item {
     Type(Type),
     /// The module item. See the inner element description.
     Module(Module),
+    /// The trait item. See the inner element description.
+    Trait(Trait),
 }
 
 impl Item {
@@ -98,6 +102,7 @@ impl Item {
             Self::Module(inner) => {
                 inner.define()?;
             }
+            Self::Trait(_) => {}
         }
 
         Ok(())
@@ -131,6 +136,7 @@ impl Item {
             Self::Variant(inner) => inner.is_associated,
             Self::Type(inner) => inner.is_associated,
             Self::Module(_) => false,
+            Self::Trait(_) => false,
         }
     }
 
@@ -145,6 +151,7 @@ impl Item {
             Self::Variant(inner) => Some(inner.location),
             Self::Type(inner) => inner.location,
             Self::Module(inner) => inner.location,
+            Self::Trait(inner) => Some(inner.location),
         }
     }
 
@@ -159,6 +166,7 @@ impl Item {
             Self::Variant(inner) => inner.item_id,
             Self::Type(inner) => inner.item_id,
             Self::Module(inner) => inner.item_id,
+            Self::Trait(inner) => inner.item_id,
         }
     }
 
@@ -173,6 +181,7 @@ impl Item {
             Self::Variant(_) => vec![],
             Self::Type(inner) => inner.get_intermediate(),
             Self::Module(inner) => inner.get_intermediate(),
+            Self::Trait(_) => vec![],
         }
     }
 }
@@ -186,6 +195,7 @@ impl fmt::Display for Item {
             Self::Variant(inner) => write!(f, "variant {}", inner),
             Self::Type(inner) => write!(f, "type {}", inner),
             Self::Module(inner) => write!(f, "module {}", inner),
+            Self::Trait(inner) => write!(f, "trait {}", inner),
         }
     }
 }
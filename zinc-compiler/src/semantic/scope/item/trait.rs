@@ -0,0 +1,135 @@
+//!
+//! The semantic analyzer scope trait item.
+//!
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::FnStatement;
+use zinc_syntax::TraitLocalStatement;
+use zinc_syntax::TraitStatement;
+
+use crate::semantic::element::r#type::Type;
+use crate::semantic::scope::item::index::INDEX as ITEM_INDEX;
+use crate::semantic::scope::Scope;
+
+///
+/// The trait item, declared using a `trait` statement.
+///
+/// Traits are resolved statically, so the item only keeps the syntax representation of the
+/// methods and the list of types implementing the trait, which is checked by generic bounds.
+///
+#[derive(Debug, Clone)]
+pub struct Trait {
+    /// The location, where the trait is declared.
+    pub location: Location,
+    /// The unique item ID, allocated upon declaration.
+    pub item_id: usize,
+    /// The trait identifier.
+    pub identifier: String,
+    /// The trait syntax representation.
+    pub statement: TraitStatement,
+    /// The scopes of the structures and enumerations, which implement the trait.
+    pub implementors: Rc<RefCell<Vec<Rc<RefCell<Scope>>>>>,
+}
+
+impl Trait {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(statement: TraitStatement) -> Self {
+        let item_id = ITEM_INDEX.next(format!("trait {}", statement.identifier.name));
+
+        Self {
+            location: statement.location,
+            item_id,
+            identifier: statement.identifier.name.to_owned(),
+            statement,
+            implementors: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    ///
+    /// Returns the required method signatures, which must be implemented by each type.
+    ///
+    pub fn signatures(&self) -> Vec<&FnStatement> {
+        self.statement
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                TraitLocalStatement::Signature(inner) => Some(inner),
+                _ => None,
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the default methods, which are declared in each type not overriding them.
+    ///
+    pub fn defaults(&self) -> Vec<&FnStatement> {
+        self.statement
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                TraitLocalStatement::Fn(inner) => Some(inner),
+                _ => None,
+            })
+            .collect()
+    }
+
+    ///
+    /// Registers the structure or enumeration `scope` as the trait implementor.
+    ///
+    /// Returns `false` if the trait has been already implemented for the type.
+    ///
+    pub fn add_implementor(&self, scope: Rc<RefCell<Scope>>) -> bool {
+        if self.is_implementor(&scope) {
+            return false;
+        }
+
+        self.implementors.borrow_mut().push(scope);
+        true
+    }
+
+    ///
+    /// Checks if the trait is implemented for the type.
+    ///
+    /// Generic structure instances are analyzed in a child scope of their template, so the
+    /// template scope is checked as well.
+    ///
+    pub fn is_implemented_by(&self, r#type: &Type) -> bool {
+        let scope = match r#type {
+            Type::Structure(inner) => inner.scope.to_owned(),
+            Type::Enumeration(inner) => inner.scope.to_owned(),
+            _ => return false,
+        };
+
+        if self.is_implementor(&scope) {
+            return true;
+        }
+
+        let parent = scope.borrow().parent();
+        match parent {
+            Some(parent) => self.is_implementor(&parent),
+            None => false,
+        }
+    }
+
+    ///
+    /// Checks if the `scope` is registered as the trait implementor.
+    ///
+    fn is_implementor(&self, scope: &Rc<RefCell<Scope>>) -> bool {
+        self.implementors
+            .borrow()
+            .iter()
+            .any(|implementor| Rc::ptr_eq(implementor, scope))
+    }
+}
+
+impl fmt::Display for Trait {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.identifier)
+    }
+}
@@ -6,6 +6,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::generator::statement::Statement as GeneratorStatement;
+use crate::semantic::element::r#type::function::Function as FunctionElement;
 use crate::semantic::element::r#type::Type as TypeElement;
 use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementVariant;
 use crate::semantic::scope::Scope;
@@ -41,6 +42,9 @@ impl State {
                 inner,
                 intermediate,
             } => match inner {
+                TypeElement::Function(FunctionElement::Generic(ref inner)) => {
+                    inner.get_intermediate()
+                }
                 TypeElement::Function(_) => match intermediate.to_owned().take() {
                     Some(intermediate) => vec![intermediate],
                     None => vec![],
@@ -6,9 +6,11 @@
 mod tests;
 
 pub mod error;
+pub mod function_body;
 pub mod intrinsic;
 pub mod item;
 pub mod memory_type;
+pub mod reference;
 pub mod stack;
 
 use std::cell::RefCell;
@@ -21,8 +23,12 @@ use zinc_lexical::Location;
 use zinc_syntax::ConstStatement;
 use zinc_syntax::ContractStatement;
 use zinc_syntax::Identifier;
+use zinc_syntax::TraitStatement;
 
 use crate::generator::statement::Statement as GeneratorStatement;
+use crate::lint::index::INDEX as LINT_INDEX;
+use crate::lint::warning::Warning;
+use crate::lint::Lint;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::path::Path;
 use crate::semantic::element::r#type::Type;
@@ -31,16 +37,19 @@ use crate::semantic::scope::intrinsic::IntrinsicTypeId;
 use crate::source::Source;
 
 use self::error::Error;
+use self::function_body::FunctionBody;
 use self::intrinsic::IntrinsicScope;
 use self::item::constant::Constant as ConstantItem;
 use self::item::field::Field as FieldItem;
 use self::item::module::Module as ModuleItem;
+use self::item::r#trait::Trait as TraitItem;
 use self::item::r#type::statement::Statement as TypeStatementVariant;
 use self::item::r#type::Type as TypeItem;
 use self::item::variable::Variable as VariableItem;
 use self::item::variant::Variant as VariantItem;
 use self::item::Item;
 use self::memory_type::MemoryType;
+use self::reference::INDEX as REFERENCE_INDEX;
 
 ///
 /// A scope consists of a hashmap of the declared items and a reference to its parent.
@@ -59,6 +68,16 @@ pub struct Scope {
     items: RefCell<HashMap<String, Rc<RefCell<Item>>>>,
     /// Whether the scope is the intrinsic one, that is, the root scope with intrinsic items.
     is_built_in: bool,
+    /// Whether the scope is a loop body, which may be interrupted with `break` or `continue`.
+    is_loop: bool,
+    /// Whether the loop body contains a `break` or `continue` statement.
+    is_interrupted: bool,
+    /// The function metadata, which is only set for function body scopes.
+    function: Option<FunctionBody>,
+    /// Whether the scope is a closure body, which `return`, `break`, and `continue` cannot leave.
+    is_closure: bool,
+    /// Whether the closure body cannot access the local variables of its declaring function.
+    is_capturing_forbidden: bool,
 }
 
 impl Scope {
@@ -77,6 +96,11 @@ impl Scope {
             parent,
             items: RefCell::new(HashMap::with_capacity(Self::ITEMS_INITIAL_CAPACITY)),
             is_built_in: false,
+            is_loop: false,
+            is_interrupted: false,
+            function: None,
+            is_closure: false,
+            is_capturing_forbidden: false,
         }
     }
 
@@ -89,6 +113,11 @@ impl Scope {
             parent: Some(IntrinsicScope::initialize()),
             items: RefCell::new(HashMap::with_capacity(Self::ITEMS_INITIAL_CAPACITY)),
             is_built_in: false,
+            is_loop: false,
+            is_interrupted: false,
+            function: None,
+            is_closure: false,
+            is_capturing_forbidden: false,
         }
     }
 
@@ -101,6 +130,11 @@ impl Scope {
             parent: None,
             items: RefCell::new(HashMap::with_capacity(Self::ITEMS_INITIAL_CAPACITY)),
             is_built_in: true,
+            is_loop: false,
+            is_interrupted: false,
+            function: None,
+            is_closure: false,
+            is_capturing_forbidden: false,
         }
     }
 
@@ -111,6 +145,46 @@ impl Scope {
         Self::new(name, Some(parent)).wrap()
     }
 
+    ///
+    /// Creates a loop body scope with `parent` as its parent.
+    ///
+    pub fn new_loop(name: String, parent: Rc<RefCell<Scope>>) -> Rc<RefCell<Self>> {
+        let mut scope = Self::new(name, Some(parent));
+        scope.is_loop = true;
+        scope.wrap()
+    }
+
+    ///
+    /// Creates a function body scope with `parent` as its parent.
+    ///
+    pub fn new_function(
+        name: String,
+        parent: Rc<RefCell<Scope>>,
+        function: FunctionBody,
+    ) -> Rc<RefCell<Self>> {
+        let mut scope = Self::new(name, Some(parent));
+        scope.function = Some(function);
+        scope.wrap()
+    }
+
+    ///
+    /// Creates a closure body scope with `parent` as its parent, which is the closure
+    /// declaration scope.
+    ///
+    /// If the closure is called outside of its declaring function, its body cannot capture the
+    /// local variables of that function.
+    ///
+    pub fn new_closure(
+        name: String,
+        parent: Rc<RefCell<Scope>>,
+        is_capturing_allowed: bool,
+    ) -> Rc<RefCell<Self>> {
+        let mut scope = Self::new(name, Some(parent));
+        scope.is_closure = true;
+        scope.is_capturing_forbidden = !is_capturing_allowed;
+        scope.wrap()
+    }
+
     ///
     /// Returns the scope parent.
     ///
@@ -416,6 +490,29 @@ impl Scope {
         Scope::declare_type(scope, TypeStatementVariant::Contract(statement), false)
     }
 
+    ///
+    /// Declares a trait, saving its methods to check and declare them in the implementations.
+    ///
+    pub fn declare_trait(
+        scope: Rc<RefCell<Scope>>,
+        statement: TraitStatement,
+    ) -> Result<(), SemanticError> {
+        if let Ok(item) = scope.borrow().resolve_item(&statement.identifier, true) {
+            return Err(SemanticError::Scope(Error::ItemRedeclared {
+                location: statement.location,
+                name: statement.identifier.name.clone(),
+                reference: item.borrow().location(),
+            }));
+        }
+
+        let name = statement.identifier.name.clone();
+        let item = Item::Trait(TraitItem::new(statement));
+
+        scope.borrow().items.borrow_mut().insert(name, item.wrap());
+
+        Ok(())
+    }
+
     ///
     /// Declares a module, saving its representation to define itself later during the second
     /// pass or referencing for the first time.
@@ -501,6 +598,10 @@ impl Scope {
                 .borrow()
                 .resolve_item(identifier, is_element_first)?;
             item.borrow().define()?;
+            REFERENCE_INDEX.insert(identifier, &item.borrow());
+            if let Item::Variable(ref variable) = *item.borrow() {
+                LINT_INDEX.mark_used(variable.item_id);
+            }
 
             if path.elements.len() == 1 && item.borrow().is_associated() {
                 return Err(SemanticError::Scope(Error::AssociatedItemWithoutOwner {
@@ -557,7 +658,16 @@ impl Scope {
             Some(item) => Ok(item.to_owned()),
             None => match self.parent {
                 Some(ref parent) if recursive => {
-                    parent.borrow().resolve_item(identifier, recursive)
+                    let item = parent.borrow().resolve_item(identifier, recursive)?;
+                    if self.is_capturing_forbidden {
+                        if let Item::Variable(_) = *item.borrow() {
+                            return Err(SemanticError::Scope(Error::ClosureCapture {
+                                location: identifier.location,
+                                name: identifier.name.to_owned(),
+                            }));
+                        }
+                    }
+                    Ok(item)
                 }
                 Some(_) | None => Err(SemanticError::Scope(Error::ItemUndeclared {
                     location: identifier.location,
@@ -654,6 +764,148 @@ impl Scope {
             .collect()
     }
 
+    ///
+    /// Marks the innermost loop enclosing the `scope` as interrupted.
+    ///
+    /// Returns `false` if the `scope` is not enclosed in a loop within the current function or
+    /// closure body.
+    ///
+    pub fn interrupt_loop(scope: Rc<RefCell<Scope>>) -> bool {
+        let mut current = Some(scope);
+        while let Some(scope) = current {
+            if scope.borrow().is_closure {
+                return false;
+            }
+
+            if scope.borrow().is_loop {
+                scope.borrow_mut().is_interrupted = true;
+                return true;
+            }
+
+            current = scope.borrow().parent();
+        }
+
+        false
+    }
+
+    ///
+    /// Whether the loop body contains a `break` or `continue` statement.
+    ///
+    pub fn is_interrupted(&self) -> bool {
+        self.is_interrupted
+    }
+
+    ///
+    /// Marks the function enclosing the `scope` as returned, along with all the loops
+    /// between them, since a `return` statement interrupts each of them.
+    ///
+    /// Returns the function metadata, or `None` if the `scope` is not enclosed in a function,
+    /// or is enclosed in a closure body.
+    ///
+    pub fn return_from(scope: Rc<RefCell<Scope>>) -> Option<FunctionBody> {
+        let mut loops = Vec::new();
+        let mut current = Some(scope);
+        while let Some(scope) = current {
+            if let Some(ref mut function) = scope.borrow_mut().function {
+                if !function.is_constant {
+                    function.is_returned = true;
+                    for r#loop in loops.into_iter() {
+                        Scope::interrupt_loop(r#loop);
+                    }
+                }
+                return Some(function.to_owned());
+            }
+
+            if scope.borrow().is_closure {
+                return None;
+            }
+
+            if scope.borrow().is_loop {
+                loops.push(scope.clone());
+            }
+
+            current = scope.borrow().parent();
+        }
+
+        None
+    }
+
+    ///
+    /// Whether the function body contains a `return` statement.
+    ///
+    pub fn is_returned(&self) -> bool {
+        self.function
+            .as_ref()
+            .map(|function| function.is_returned)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Checks whether the `lint` is suppressed with an `#[allow(...)]` attribute of one of the
+    /// enclosing functions.
+    ///
+    pub fn is_lint_allowed(&self, lint: Lint) -> bool {
+        if let Some(ref function) = self.function {
+            if function.allowed_lints.contains(&lint) {
+                return true;
+            }
+        }
+
+        match self.parent {
+            Some(ref parent) => parent.borrow().is_lint_allowed(lint),
+            None => false,
+        }
+    }
+
+    ///
+    /// Records the lint `warning`, unless its lint is suppressed in the scope.
+    ///
+    pub fn warn(&self, warning: Warning) {
+        if !self.is_lint_allowed(warning.lint()) {
+            LINT_INDEX.push(warning);
+        }
+    }
+
+    ///
+    /// Reports the variables declared at the current scope level, which are never referenced
+    /// or are declared as mutable, but never mutated.
+    ///
+    /// Is called when the scope is left. Implicit variables, the `self` alias, and the variables
+    /// whose names start with an underscore are not reported.
+    ///
+    pub fn lint_variables(&self) {
+        for item in self.items.borrow().values() {
+            let variable = match *item.borrow() {
+                Item::Variable(ref variable) => variable.to_owned(),
+                _ => continue,
+            };
+
+            let location = match variable.location {
+                Some(location) => location,
+                None => continue,
+            };
+            if variable.identifier.starts_with('_')
+                || variable.identifier == Keyword::SelfLowercase.to_string()
+            {
+                continue;
+            }
+
+            if !LINT_INDEX.is_used(variable.item_id) {
+                self.warn(Warning::UnusedVariable {
+                    location,
+                    name: variable.identifier.to_owned(),
+                });
+            }
+
+            if variable.is_mutable && !LINT_INDEX.is_mutated(variable.item_id) {
+                self.warn(Warning::UnusedMut {
+                    location,
+                    name: variable.identifier,
+                });
+            }
+        }
+    }
+
     ///
     /// Returns the scope name.
     ///
@@ -0,0 +1,124 @@
+//!
+//! The semantic analyzer scope item reference index.
+//!
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use lazy_static::lazy_static;
+
+use zinc_lexical::Location;
+use zinc_syntax::Identifier;
+
+use crate::semantic::scope::item::Item;
+
+///
+/// The resolved identifier, which refers to a declared item.
+///
+#[derive(Debug, Clone)]
+pub struct Reference {
+    /// The identifier location.
+    pub location: Location,
+    /// The identifier length in characters.
+    pub length: usize,
+    /// The location, where the referenced item is declared. `None` for intrinsic items.
+    pub declaration: Option<Location>,
+    /// The referenced item description with its type.
+    pub description: String,
+}
+
+impl Reference {
+    ///
+    /// Checks whether the identifier covers the `line` and `column` position.
+    ///
+    pub fn contains(&self, line: usize, column: usize) -> bool {
+        self.location.line == line
+            && self.location.column <= column
+            && column < self.location.column + self.length
+    }
+}
+
+///
+/// The reference index, where every identifier resolved during the semantic analysis is recorded.
+///
+/// The index is used by the editor integrations to find the item declarations and types.
+///
+pub struct Index {
+    /// The inner reference storage with the file unique ID as the key.
+    pub inner: RwLock<HashMap<usize, Vec<Reference>>>,
+}
+
+impl Default for Index {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+lazy_static! {
+    pub static ref INDEX: Index = Index::new();
+}
+
+impl Index {
+    /// The file hashmap default capacity.
+    const INITIAL_CAPACITY: usize = 64;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self {
+            inner: RwLock::new(HashMap::with_capacity(Self::INITIAL_CAPACITY)),
+        }
+    }
+
+    ///
+    /// Records the `identifier` resolved to the defined `item`.
+    ///
+    pub fn insert(&self, identifier: &Identifier, item: &Item) {
+        let description = match item {
+            Item::Variable(inner) => format!(
+                "let {}{}: {}",
+                if inner.is_mutable { "mut " } else { "" },
+                inner.identifier,
+                inner.r#type
+            ),
+            Item::Field(inner) => format!("field {}: {}", inner.identifier, inner.r#type),
+            item => item.to_string(),
+        };
+
+        self.inner
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .entry(identifier.location.file)
+            .or_default()
+            .push(Reference {
+                location: identifier.location,
+                length: identifier.name.chars().count(),
+                declaration: item.location(),
+                description,
+            });
+    }
+
+    ///
+    /// Finds the reference at the `line` and `column` position in the `file`.
+    ///
+    pub fn find(&self, file: usize, line: usize, column: usize) -> Option<Reference> {
+        self.inner
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .get(&file)?
+            .iter()
+            .find(|reference| reference.contains(line, column))
+            .cloned()
+    }
+
+    ///
+    /// Removes the references recorded in the `file`, which is useful when the file is reanalyzed.
+    ///
+    pub fn remove(&self, file: usize) {
+        self.inner
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .remove(&file);
+    }
+}
@@ -5,6 +5,7 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use crate::semantic::scope::function_body::FunctionBody;
 use crate::semantic::scope::Scope;
 
 ///
@@ -54,11 +55,35 @@ impl Stack {
     }
 
     ///
-    /// Removes the deepest scope from the current hierarchy.
+    /// Pushes the current scope deeper and initializes a loop body scope with it as the parent.
+    ///
+    pub fn push_loop(&mut self, name: Option<String>) {
+        let name = match name {
+            Some(name) => format!("{} {}", self.top().borrow().name, name),
+            None => format!("{} => {}", self.top().borrow().name, "loop"),
+        };
+
+        self.elements.push(Scope::new_loop(name, self.top()));
+    }
+
+    ///
+    /// Pushes the current scope deeper and initializes a function body scope with it as the parent.
+    ///
+    pub fn push_function(&mut self, name: String, function: FunctionBody) {
+        let name = format!("{} {}", self.top().borrow().name, name);
+
+        self.elements
+            .push(Scope::new_function(name, self.top(), function));
+    }
+
+    ///
+    /// Removes the deepest scope from the current hierarchy, reporting its unused variables.
     ///
     pub fn pop(&mut self) {
         self.elements
             .pop()
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+            .borrow()
+            .lint_variables();
     }
 }
@@ -6,8 +6,11 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 
 use crate::error::Error;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::error::Error as ScopeError;
+use crate::semantic::scope::intrinsic::IntrinsicScope;
+use crate::semantic::scope::reference::INDEX as REFERENCE_INDEX;
 use crate::source::Source;
 use zinc_lexical::Keyword;
 use zinc_lexical::Location;
@@ -1132,3 +1135,49 @@ fn main() -> u8 { Call { value: 42 }.call() }
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_reference_recorded() {
+    let input = r#"
+const VALUE: u8 = 42;
+
+fn main() {
+    let mut result = VALUE;
+    result += 1;
+}
+"#;
+
+    let source = Source::test(input, PathBuf::from("test.zn"), HashMap::new())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let file = match source {
+        Source::File(ref inner) => inner.tree.statements[0].location().file,
+        Source::Directory(ref inner) => inner.entry.tree.statements[0].location().file,
+    };
+    EntryAnalyzer::define(source).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let constant = REFERENCE_INDEX
+        .find(file, 5, 22)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(constant.declaration, Some(Location::test(2, 7)));
+    assert_eq!(constant.description, "constant integer '42' of type 'u8'");
+
+    let variable = REFERENCE_INDEX
+        .find(file, 6, 5)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(variable.declaration, Some(Location::test(5, 13)));
+    assert_eq!(variable.description, "let mut result: u8");
+
+    assert!(REFERENCE_INDEX.find(file, 6, 12).is_none());
+}
+
+#[test]
+fn ok_intrinsic_completion() {
+    let names: Vec<String> = IntrinsicScope::complete(&["std", "crypto"])
+        .into_iter()
+        .map(|(name, _description)| name)
+        .collect();
+
+    assert!(names.contains(&"sha256".to_owned()));
+    assert!(names.contains(&"ecc".to_owned()));
+    assert!(IntrinsicScope::complete(&["std", "unknown"]).is_empty());
+}
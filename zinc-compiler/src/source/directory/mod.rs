@@ -158,7 +158,7 @@ impl Directory {
     pub fn compile(self, manifest: Manifest) -> Result<Rc<RefCell<State>>, SourceError> {
         let scope = EntryAnalyzer::define(Source::Directory(self))
             .map_err(CompilerError::Semantic)
-            .map_err(|error| error.format())
+            .map_err(CompilerError::diagnostic)
             .map_err(SourceError::Compiling)?;
 
         let state = State::new(manifest).wrap();
@@ -5,6 +5,7 @@
 use std::fmt;
 use std::io;
 
+use crate::error::diagnostic::Diagnostic;
 use crate::source::directory::error::Error as DirectoryError;
 use crate::source::file::error::Error as FileError;
 
@@ -23,8 +24,8 @@ pub enum Error {
     /// The source code directory error.
     Directory(DirectoryError),
 
-    /// The source code compiler analysis error, formatted as string.
-    Compiling(String),
+    /// The source code compiler analysis error, extracted for rendering.
+    Compiling(Diagnostic),
 }
 
 impl fmt::Display for Error {
@@ -36,7 +37,7 @@ impl fmt::Display for Error {
             Self::File(inner) => write!(f, "file: {}", inner),
             Self::Directory(inner) => write!(f, "directory: {}", inner),
 
-            Self::Compiling(inner) => write!(f, "{}", inner),
+            Self::Compiling(inner) => write!(f, "{}", inner.format()),
         }
     }
 }
@@ -61,7 +61,7 @@ impl File {
                 next_file_id,
             )
             .map_err(CompilerError::from)
-            .map_err(|error| error.format())
+            .map_err(CompilerError::diagnostic)
             .map_err(SourceError::Compiling)?;
 
         Ok(Self {
@@ -121,7 +121,7 @@ impl File {
                 next_file_id,
             )
             .map_err(CompilerError::from)
-            .map_err(|error| error.format())
+            .map_err(CompilerError::diagnostic)
             .map_err(SourceError::Compiling)?;
 
         Ok(Self {
@@ -138,7 +138,7 @@ impl File {
     pub fn compile(self, manifest: Manifest) -> Result<Rc<RefCell<State>>, SourceError> {
         let scope = EntryAnalyzer::define(Source::File(self))
             .map_err(CompilerError::Semantic)
-            .map_err(|error| error.format())
+            .map_err(CompilerError::diagnostic)
             .map_err(SourceError::Compiling)?;
 
         let state = State::new(manifest).wrap();
@@ -172,15 +172,13 @@ impl File {
     /// Initializes a test module file.
     ///
     pub fn test(code: &str, path: PathBuf) -> Result<Self, CompilerError> {
-        let next_file_id = FILE_INDEX.peek();
+        let file_id = FILE_INDEX.next(&path, code.to_owned());
 
-        let tree = Parser::default().parse(code, next_file_id)?;
-
-        FILE_INDEX.next(&path, code.to_owned());
+        let tree = Parser::default().parse(code, file_id)?;
 
         Ok(Self {
             path,
-            name: format!("test_#{}", next_file_id),
+            name: format!("test_#{}", file_id),
             tree,
         })
     }
@@ -6,6 +6,8 @@ use std::path::PathBuf;
 
 use structopt::StructOpt;
 
+use crate::message_format::MessageFormat;
+
 ///
 /// The Zinc compiler arguments.
 ///
@@ -47,6 +49,14 @@ pub struct Arguments {
     /// Enables the dead function code elimination optimization.
     #[structopt(long = "opt-dfe")]
     pub optimize_dead_function_elimination: bool,
+
+    /// The errors and warnings format, either `human` or `json`.
+    #[structopt(long = "message-format", default_value = "human")]
+    pub message_format: MessageFormat,
+
+    /// Prints the detailed explanation of an error code, e.g. `Z0412`, and exits.
+    #[structopt(long = "explain")]
+    pub explain: Option<String>,
 }
 
 impl Arguments {
@@ -6,12 +6,15 @@ use std::ffi::OsString;
 use std::fmt;
 use std::io;
 
+use zinc_compiler::Diagnostic;
 use zinc_compiler::SourceError;
 
 ///
 /// The Zinc compiler binary error.
 ///
 pub enum Error {
+    /// The `--explain` error code is not in the registry.
+    ErrorCodeUnknown(String),
     /// The manifest file error.
     Manifest(zinc_manifest::Error),
     /// The Zinc source code error.
@@ -24,6 +27,21 @@ pub enum Error {
     InputTemplateWriting(OsString, OutputError),
 }
 
+impl Error {
+    ///
+    /// Extracts the error data for the machine-readable output.
+    ///
+    /// Only the source code errors are located, and the other ones are converted into
+    /// locationless diagnostics.
+    ///
+    pub fn diagnostic(self) -> Diagnostic {
+        match self {
+            Self::Source(SourceError::Compiling(diagnostic)) => diagnostic,
+            error => Diagnostic::new(error.to_string().as_str(), None),
+        }
+    }
+}
+
 impl From<SourceError> for Error {
     fn from(error: SourceError) -> Self {
         Self::Source(error)
@@ -33,6 +51,7 @@ impl From<SourceError> for Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::ErrorCodeUnknown(code) => write!(f, "error code `{}` is unknown", code),
             Self::Manifest(inner) => write!(f, "{}", inner),
             Self::Source(inner) => write!(f, "{}", inner),
             Self::DirectoryCreating(path, inner) => {
@@ -0,0 +1,62 @@
+//!
+//! The Zinc compiler message format.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+use zinc_compiler::Diagnostic;
+
+///
+/// The format of the errors and warnings printed by the compiler.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MessageFormat {
+    /// The colored text printed to the standard error.
+    Human,
+    /// A JSON object per line printed to the standard output, which is consumed by the editor
+    /// integrations and CI annotators.
+    Json,
+}
+
+impl MessageFormat {
+    ///
+    /// Prints the `diagnostic` in the format.
+    ///
+    pub fn print(self, diagnostic: Diagnostic) {
+        match self {
+            Self::Human => eprintln!("{}", diagnostic.format()),
+            Self::Json => println!("{}", diagnostic.to_json()),
+        }
+    }
+}
+
+impl Default for MessageFormat {
+    fn default() -> Self {
+        Self::Human
+    }
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            another => Err(format!(
+                "unknown message format `{}`, expected `human` or `json`",
+                another
+            )),
+        }
+    }
+}
+
+impl fmt::Display for MessageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Human => write!(f, "human"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
@@ -4,6 +4,7 @@
 
 mod arguments;
 mod error;
+mod message_format;
 
 use std::convert::TryFrom;
 use std::fs;
@@ -13,22 +14,31 @@ use std::process;
 use std::thread;
 
 use zinc_build::Build;
+use zinc_compiler::ErrorCode;
 use zinc_compiler::Source;
 use zinc_compiler::State;
+use zinc_compiler::LINT_INDEX;
 use zinc_manifest::Manifest;
 
 use self::arguments::Arguments;
 use self::error::Error;
 use self::error::OutputError;
+use self::message_format::MessageFormat;
 
 ///
 /// The application entry point.
 ///
 fn main() {
-    process::exit(match main_inner() {
+    let args = Arguments::new();
+    let message_format = args.message_format;
+
+    process::exit(match main_inner(args) {
         Ok(()) => zinc_const::exit_code::SUCCESS,
         Err(error) => {
-            eprintln!("{}", error);
+            match message_format {
+                MessageFormat::Human => eprintln!("{}", error),
+                MessageFormat::Json => message_format.print(error.diagnostic()),
+            }
             zinc_const::exit_code::FAILURE
         }
     })
@@ -37,15 +47,20 @@ fn main() {
 ///
 /// The auxiliary `main` function to facilitate the `?` error conversion operator.
 ///
-fn main_inner() -> Result<(), Error> {
-    let args = Arguments::new();
-
+fn main_inner(args: Arguments) -> Result<(), Error> {
     zinc_logger::initialize(zinc_const::app_name::COMPILER, args.verbosity);
 
+    if let Some(code) = args.explain {
+        let entry = ErrorCode::find(code.as_str()).ok_or(Error::ErrorCodeUnknown(code))?;
+        println!("{}", entry);
+        return Ok(());
+    }
+
     let manifest = Manifest::try_from(&args.manifest_path).map_err(Error::Manifest)?;
 
     let source_directory_path = args.source_directory_path;
     let optimize_dead_function_elimination = args.optimize_dead_function_elimination;
+    let message_format = args.message_format;
     let build = thread::Builder::new()
         .stack_size(zinc_const::limit::COMPILER_STACK_SIZE)
         .spawn(move || -> Result<Build, Error> {
@@ -53,6 +68,9 @@ fn main_inner() -> Result<(), Error> {
             let state = source.compile(manifest)?;
             let application =
                 State::unwrap_rc(state).into_application(optimize_dead_function_elimination);
+            for warning in LINT_INDEX.all().into_iter() {
+                message_format.print(warning.diagnostic());
+            }
             Ok(application.into_build())
         })
         .expect(zinc_const::panic::SYNCHRONIZATION)
@@ -14,5 +14,11 @@ pub static TESTER: &str = "zinc-tester";
 /// The virtual machine default application name.
 pub static VIRTUAL_MACHINE: &str = "zvm";
 
+/// The Debug Adapter Protocol server default application name.
+pub static DEBUG_ADAPTER: &str = "zinc-dap";
+
+/// The Language Server Protocol server default application name.
+pub static LANGUAGE_SERVER: &str = "zinc-lsp";
+
 /// The Zandbox server default application name.
 pub static ZANDBOX: &str = "zandbox";
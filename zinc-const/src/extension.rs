@@ -13,3 +13,12 @@ pub static BINARY: &str = "znb";
 
 /// The JSON data file extension.
 pub static JSON: &str = "json";
+
+/// The proving backend record file extension.
+pub static BACKEND: &str = "backend";
+
+/// The Solidity source code file extension.
+pub static SOLIDITY: &str = "sol";
+
+/// The folded call stacks file extension.
+pub static FOLDED: &str = "folded";
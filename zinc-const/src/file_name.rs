@@ -28,3 +28,9 @@ pub static VERIFYING_KEY: &str = "verifying_key";
 
 /// The private key file default name (testnet only!).
 pub static PRIVATE_KEY: &str = "private_key";
+
+/// The Ethereum verifier contract file default name.
+pub static VERIFIER: &str = "verifier";
+
+/// The constraint profile folded call stacks file default name.
+pub static PROFILE: &str = "profile";
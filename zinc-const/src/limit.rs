@@ -19,3 +19,16 @@ pub const COMPILER_STACK_SIZE: usize = 64 * 1024 * 1024;
 
 /// The JSON payload limit to fit large contract source code.
 pub static JSON_PAYLOAD: usize = 16 * 1024 * 1024;
+
+/// The default number of the contract events returned by Zandbox in a single page.
+pub const EVENTS_PAGE_SIZE: u32 = 100;
+
+/// The maximal number of the contract events returned by Zandbox in a single page.
+pub const EVENTS_PAGE_SIZE_MAX: u32 = 1000;
+
+/// The maximal number of Zandbox contract method runs, if the contract storage is concurrently
+/// changed by another Zandbox instance during the call.
+pub const CALL_ATTEMPTS: usize = 3;
+
+/// The default maximal line width of the formatted source code.
+pub const FORMATTER_WIDTH: usize = 100;
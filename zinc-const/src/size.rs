@@ -16,3 +16,6 @@ pub const ETH_PRIVATE_KEY: usize = 32;
 
 /// The ETH signature size.
 pub const ETH_SIGNATURE: usize = 32;
+
+/// The EVM word size.
+pub const ETH_WORD: usize = 32;
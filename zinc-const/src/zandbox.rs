@@ -22,3 +22,31 @@ pub static CONTRACT_FEE_URL: &str = "/api/v1/contract/fee";
 
 /// The contract call URL.
 pub static CONTRACT_CALL_URL: &str = "/api/v1/contract/call";
+
+/// The contract storage snapshot URL.
+pub static CONTRACT_STORAGE_URL: &str = "/api/v1/contract/storage";
+
+/// The contract storage snapshots difference URL.
+pub static CONTRACT_STORAGE_DIFF_URL: &str = "/api/v1/contract/storage/diff";
+
+/// The contract events URL.
+pub static CONTRACT_EVENTS_URL: &str = "/api/v1/contract/events";
+
+/// The contract events WebSocket subscription URL.
+pub static CONTRACT_EVENTS_SUBSCRIBE_URL: &str = "/api/v1/contract/events/subscribe";
+
+/// The network name of the local zkSync mock, which is hosted by Zandbox.
+pub static NETWORK_LOCALHOST_MOCK: &str = "localhost-mock";
+
+/// The local zkSync mock binding host, which is the zkSync `localhost` network JSON-RPC host.
+pub static ZKSYNC_MOCK_HOST: &str = "127.0.0.1";
+
+/// The local zkSync mock binding port, which is the zkSync `localhost` network JSON-RPC port.
+pub const ZKSYNC_MOCK_PORT: u16 = 3030;
+
+/// The amount credited by the local zkSync mock to an account by the `zargo faucet` command.
+pub static ZKSYNC_MOCK_FAUCET_AMOUNT: &str = "1000000000000000000000";
+
+/// The contract storage snapshot reservation lifetime in seconds. It must be much longer than the
+/// zkSync transaction commit timeout, since a timed out transaction batch can still be committed.
+pub const SNAPSHOT_RESERVATION_LIFETIME: u64 = 600;
@@ -0,0 +1,148 @@
+//!
+//! The Zinc Debug Adapter Protocol server error.
+//!
+
+use std::io;
+
+use failure::Fail;
+use serde_json::Value as JsonValue;
+
+use zinc_build::ValueError as BuildValueError;
+use zinc_zksync::TransactionMsgError;
+
+use zinc_vm::RuntimeError;
+
+///
+/// The Zinc Debug Adapter Protocol server error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The standard input or output error.
+    #[fail(display = "input/output: {}", _0)]
+    Stdio(io::Error),
+
+    /// The file input output error.
+    #[fail(display = "{}: {}", path, error)]
+    IO {
+        /// The inner `std` error.
+        error: io::Error,
+        /// The path where the error has happened.
+        path: String,
+    },
+
+    /// The protocol message is malformed.
+    #[fail(display = "invalid protocol message: {}", _0)]
+    Protocol(String),
+
+    /// The bytecode execution runtime error.
+    #[fail(display = "runtime error: {}", _0)]
+    Runtime(RuntimeError),
+
+    /// The JSON decoding error.
+    #[fail(display = "failed to parse json: {}", _0)]
+    JsonDecoding(serde_json::Error),
+
+    /// The JSON input data does not match the bytecode application input types metadata.
+    #[fail(display = "invalid json structure: {}", _0)]
+    JsonValue(BuildValueError),
+
+    /// The bytecode deserialization error.
+    #[fail(display = "failed to decode an application: {}", _0)]
+    ApplicationDecoding(String),
+
+    /// The input data is invalid.
+    #[fail(
+        display = "the input data is invalid: expected for `{}`, found for `{}`",
+        expected, found
+    )]
+    InputDataInvalid {
+        /// The expected project type.
+        expected: String,
+        /// The found project type.
+        found: String,
+    },
+
+    /// The method name is not specified.
+    #[fail(display = "method name is missing")]
+    MethodNameNotFound,
+
+    /// The method does not exist in the contract.
+    #[fail(display = "method `{}` not found", name)]
+    MethodNotFound {
+        /// The method name.
+        name: String,
+    },
+
+    /// The method arguments are not present in the input data.
+    #[fail(display = "method `{}` arguments not found", name)]
+    MethodArgumentsNotFound {
+        /// The method name.
+        name: String,
+    },
+
+    /// The transaction JSON is invalid.
+    #[fail(display = "transaction is invalid: {}", inner)]
+    InvalidTransaction {
+        /// The inner transaction error.
+        inner: TransactionMsgError,
+        /// The invalid transaction JSON.
+        found: JsonValue,
+    },
+
+    /// The contract storage JSON is invalid.
+    #[fail(display = "contract storage must be an array, but found `{}`", found)]
+    InvalidContractStorageFormat {
+        /// The invalid storage JSON.
+        found: JsonValue,
+    },
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Stdio(error)
+    }
+}
+
+impl From<RuntimeError> for Error {
+    fn from(error: RuntimeError) -> Self {
+        Error::Runtime(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::JsonDecoding(error)
+    }
+}
+
+impl From<BuildValueError> for Error {
+    fn from(error: BuildValueError) -> Self {
+        Error::JsonValue(error)
+    }
+}
+
+///
+/// The trait for providing the path to IO errors.
+///
+pub trait IErrorPath<T> {
+    ///
+    /// Is used to simplify the `.map_err(...)` boilerplate code.
+    ///
+    fn error_with_path<P, F>(self, path: F) -> Result<T, Error>
+    where
+        P: Into<String>,
+        F: FnOnce() -> P;
+}
+
+impl<T> IErrorPath<T> for Result<T, io::Error> {
+    fn error_with_path<P, F>(self, path: F) -> Result<T, Error>
+    where
+        P: Into<String>,
+        F: FnOnce() -> P,
+    {
+        self.map_err(|error| Error::IO {
+            error,
+            path: path().into(),
+        })
+    }
+}
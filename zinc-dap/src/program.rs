@@ -0,0 +1,230 @@
+//!
+//! The debugged program.
+//!
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fs;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde_json::Value as JsonValue;
+
+use zinc_build::Application as BuildApplication;
+use zinc_build::ContractFieldValue as BuildContractFieldValue;
+use zinc_build::InputBuild;
+use zinc_build::Instruction;
+use zinc_build::Value as BuildValue;
+use zinc_zksync::TransactionMsg;
+
+use zinc_vm::Bn256;
+use zinc_vm::CircuitFacade;
+use zinc_vm::ContractFacade;
+use zinc_vm::ContractInput;
+use zinc_vm::IDebugger;
+
+use crate::error::Error;
+use crate::error::IErrorPath;
+use crate::protocol::request::LaunchArguments;
+
+///
+/// The application bytecode with its input data.
+///
+pub struct Program {
+    /// The bytecode application.
+    application: BuildApplication,
+    /// The input data.
+    input: InputBuild,
+    /// The method name to call, if the application is a contract.
+    method: Option<String>,
+}
+
+impl Program {
+    ///
+    /// Reads the bytecode and input files specified in the `launch` request.
+    ///
+    pub fn new(arguments: &LaunchArguments) -> Result<Self, Error> {
+        let bytecode = fs::read(&arguments.binary).error_with_path(|| arguments.binary.as_str())?;
+        let application = BuildApplication::try_from_slice(bytecode.as_slice())
+            .map_err(Error::ApplicationDecoding)?;
+
+        let input_template =
+            fs::read_to_string(&arguments.input).error_with_path(|| arguments.input.as_str())?;
+        let input: InputBuild = serde_json::from_str(input_template.as_str())?;
+
+        if let BuildApplication::Contract(ref contract) = application {
+            let method_name = arguments.method.as_ref().ok_or(Error::MethodNameNotFound)?;
+            if !contract.methods.contains_key(method_name) {
+                return Err(Error::MethodNotFound {
+                    name: method_name.to_owned(),
+                });
+            }
+        }
+
+        Ok(Self {
+            application,
+            input,
+            method: arguments.method.clone(),
+        })
+    }
+
+    ///
+    /// Returns the bytecode instructions.
+    ///
+    pub fn instructions(&self) -> &[Instruction] {
+        match self.application {
+            BuildApplication::Circuit(ref circuit) => circuit.instructions.as_slice(),
+            BuildApplication::Contract(ref contract) => contract.instructions.as_slice(),
+        }
+    }
+
+    ///
+    /// Returns the address of the first executed instruction.
+    ///
+    pub fn entry(&self) -> usize {
+        match self.application {
+            BuildApplication::Circuit(ref circuit) => circuit.address,
+            BuildApplication::Contract(ref contract) => self
+                .method
+                .as_ref()
+                .and_then(|method| contract.methods.get(method))
+                .map(|method| method.address)
+                .unwrap_or_default(),
+        }
+    }
+
+    ///
+    /// Returns the contract storage field names, which are empty for circuits.
+    ///
+    pub fn storage_fields(&self) -> Vec<String> {
+        match self.application {
+            BuildApplication::Circuit(_) => vec![],
+            BuildApplication::Contract(ref contract) => contract
+                .storage
+                .iter()
+                .map(|field| field.name.to_owned())
+                .collect(),
+        }
+    }
+
+    ///
+    /// Collects the source code lines, which have the line markers in the bytecode.
+    ///
+    /// Only such lines can be used as breakpoints.
+    ///
+    pub fn lines(&self) -> HashSet<(PathBuf, usize)> {
+        let mut lines = HashSet::new();
+
+        let mut file = None;
+        for instruction in self.instructions().iter() {
+            match instruction {
+                Instruction::FileMarker(marker) => file = Some(normalize(marker.file.as_str())),
+                Instruction::LineMarker(marker) => {
+                    if let Some(ref file) = file {
+                        lines.insert((file.to_owned(), marker.line));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        lines
+    }
+
+    ///
+    /// Runs the program, calling the `debugger` after each instruction.
+    ///
+    pub fn run<D>(self, debugger: &mut D) -> Result<(), Error>
+    where
+        D: IDebugger<Bn256>,
+    {
+        match self.application {
+            BuildApplication::Circuit(circuit) => match self.input {
+                InputBuild::Circuit { arguments } => {
+                    let input_type = circuit.input.clone();
+                    let arguments = BuildValue::try_from_typed_json(arguments, input_type)?;
+
+                    CircuitFacade::new(circuit).debug::<Bn256, _>(arguments, debugger)?;
+                }
+                InputBuild::Contract { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "circuit".to_owned(),
+                        found: "contract".to_owned(),
+                    })
+                }
+            },
+            BuildApplication::Contract(contract) => match self.input {
+                InputBuild::Circuit { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "contract".to_owned(),
+                        found: "circuit".to_owned(),
+                    })
+                }
+                InputBuild::Contract {
+                    arguments,
+                    msg: transaction,
+                    storage,
+                } => {
+                    let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
+                    let method = contract.methods.get(method_name.as_str()).cloned().ok_or(
+                        Error::MethodNotFound {
+                            name: method_name.clone(),
+                        },
+                    )?;
+
+                    let method_arguments = arguments.get(method_name.as_str()).cloned().ok_or(
+                        Error::MethodArgumentsNotFound {
+                            name: method_name.clone(),
+                        },
+                    )?;
+                    let method_arguments =
+                        BuildValue::try_from_typed_json(method_arguments, method.input)?;
+
+                    let storage_values = match storage {
+                        JsonValue::Array(array) => {
+                            let mut storage_values = Vec::with_capacity(contract.storage.len());
+                            for (field, value) in contract.storage.clone().into_iter().zip(array) {
+                                storage_values.push(BuildContractFieldValue::new(
+                                    field.name,
+                                    BuildValue::try_from_typed_json(value, field.r#type)?,
+                                    field.is_public,
+                                    field.is_implicit,
+                                ));
+                            }
+                            storage_values
+                        }
+                        value => return Err(Error::InvalidContractStorageFormat { found: value }),
+                    };
+
+                    let input = ContractInput::new(
+                        method_arguments,
+                        BuildValue::Contract(storage_values),
+                        method_name,
+                        TransactionMsg::try_from(&transaction).map_err(|error| {
+                            Error::InvalidTransaction {
+                                inner: error,
+                                found: transaction,
+                            }
+                        })?,
+                    );
+
+                    ContractFacade::new(contract).debug::<Bn256, _>(input, debugger)?;
+                }
+            },
+        }
+
+        Ok(())
+    }
+}
+
+///
+/// Removes the `.` components, so the bytecode file paths can be matched against the
+/// absolute source paths of the editor.
+///
+pub fn normalize(file: &str) -> PathBuf {
+    Path::new(file)
+        .components()
+        .filter(|component| component != &Component::CurDir)
+        .collect()
+}
@@ -0,0 +1,154 @@
+//!
+//! The Debug Adapter Protocol transport.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod request;
+
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::error::Error;
+
+use self::request::Request;
+
+/// The message header which specifies the message body length.
+const CONTENT_LENGTH: &str = "Content-Length";
+
+///
+/// Reads a message, which consists of the headers, an empty line, and the JSON body.
+///
+/// Returns `None` if the input has been closed.
+///
+pub fn read<R: BufRead>(input: &mut R) -> Result<Option<JsonValue>, Error> {
+    let mut content_length = None;
+
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let line = line.trim_end_matches(&['\r', '\n'][..]);
+        if line.is_empty() {
+            if content_length.is_some() {
+                break;
+            }
+            continue;
+        }
+
+        let mut parts = line.splitn(2, ':');
+        let name = parts.next().unwrap_or_default().trim();
+        let value = parts.next().unwrap_or_default().trim();
+        if name.eq_ignore_ascii_case(CONTENT_LENGTH) {
+            let length = value.parse::<usize>().map_err(|_| {
+                Error::Protocol(format!("invalid `{}` value `{}`", CONTENT_LENGTH, value))
+            })?;
+            content_length = Some(length);
+        }
+    }
+
+    let mut body = vec![0u8; content_length.unwrap_or_default()];
+    input.read_exact(body.as_mut_slice())?;
+
+    Ok(Some(serde_json::from_slice(body.as_slice())?))
+}
+
+///
+/// Writes a message with the `Content-Length` header.
+///
+pub fn write<W: Write>(output: &mut W, message: &JsonValue) -> io::Result<()> {
+    let body = message.to_string();
+    write!(output, "{}: {}\r\n\r\n{}", CONTENT_LENGTH, body.len(), body)?;
+    output.flush()
+}
+
+///
+/// The outgoing side of the connection, which numbers the messages.
+///
+/// It is shared between the request handler and the virtual machine thread.
+///
+pub struct Connection<W> {
+    /// The messages output.
+    output: W,
+    /// The last sent message sequence number.
+    sequence: usize,
+}
+
+impl<W: Write> Connection<W> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(output: W) -> Self {
+        Self {
+            output,
+            sequence: 0,
+        }
+    }
+
+    ///
+    /// Sends a successful response to the `request`.
+    ///
+    pub fn respond(&mut self, request: &Request, body: JsonValue) -> io::Result<()> {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request.seq,
+            "command": request.command,
+            "success": true,
+            "body": body,
+        }))
+    }
+
+    ///
+    /// Sends a failure response to the `request`.
+    ///
+    pub fn fail(&mut self, request: &Request, message: String) -> io::Result<()> {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request.seq,
+            "command": request.command,
+            "success": false,
+            "message": message,
+        }))
+    }
+
+    ///
+    /// Sends an event.
+    ///
+    pub fn event(&mut self, event: &str, body: JsonValue) -> io::Result<()> {
+        self.send(json!({
+            "type": "event",
+            "event": event,
+            "body": body,
+        }))
+    }
+
+    ///
+    /// Sends text to the client debug console.
+    ///
+    pub fn output(&mut self, category: &str, text: String) -> io::Result<()> {
+        self.event(
+            "output",
+            json!({
+                "category": category,
+                "output": text,
+            }),
+        )
+    }
+
+    ///
+    /// Numbers and writes a message.
+    ///
+    fn send(&mut self, mut message: JsonValue) -> io::Result<()> {
+        self.sequence += 1;
+        message["seq"] = json!(self.sequence);
+
+        write(&mut self.output, &message)
+    }
+}
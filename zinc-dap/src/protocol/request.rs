@@ -0,0 +1,89 @@
+//!
+//! The Debug Adapter Protocol request.
+//!
+
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+
+///
+/// The client request.
+///
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    /// The request sequence number.
+    pub seq: usize,
+    /// The request command name.
+    pub command: String,
+    /// The command-specific arguments.
+    #[serde(default)]
+    pub arguments: JsonValue,
+}
+
+///
+/// The `launch` request arguments.
+///
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchArguments {
+    /// The path to the binary bytecode file.
+    pub binary: String,
+    /// The path to the input JSON file.
+    pub input: String,
+    /// The method name to call, if the application is a contract.
+    pub method: Option<String>,
+    /// The directory the source file paths in the bytecode are relative to.
+    pub cwd: Option<String>,
+    /// Whether to stop at the first source code line.
+    #[serde(default)]
+    pub stop_on_entry: bool,
+}
+
+///
+/// The source breakpoint in the `setBreakpoints` request.
+///
+#[derive(Debug, Deserialize)]
+pub struct SourceBreakpoint {
+    /// The source code line.
+    pub line: usize,
+}
+
+///
+/// The source in the `setBreakpoints` request.
+///
+#[derive(Debug, Deserialize)]
+pub struct Source {
+    /// The source file path.
+    pub path: Option<String>,
+}
+
+///
+/// The `setBreakpoints` request arguments.
+///
+#[derive(Debug, Deserialize)]
+pub struct SetBreakpointsArguments {
+    /// The source the breakpoints are set in.
+    pub source: Source,
+    /// The breakpoints, which replace the previous ones in the source.
+    #[serde(default)]
+    pub breakpoints: Vec<SourceBreakpoint>,
+}
+
+///
+/// The `scopes` request arguments.
+///
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScopesArguments {
+    /// The stack frame identifier.
+    pub frame_id: usize,
+}
+
+///
+/// The `variables` request arguments.
+///
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariablesArguments {
+    /// The variables reference returned by `scopes` or a previous `variables` request.
+    pub variables_reference: usize,
+}
@@ -0,0 +1,63 @@
+//!
+//! The Debug Adapter Protocol transport tests.
+//!
+
+use std::io::Cursor;
+
+use serde_json::json;
+
+use crate::protocol::request::Request;
+use crate::protocol::Connection;
+
+#[test]
+fn ok_read_written() {
+    let message = json!({"seq": 1, "type": "request", "command": "initialize"});
+
+    let mut buffer = Vec::new();
+    super::write(&mut buffer, &message).expect(zinc_const::panic::TEST_DATA_VALID);
+    buffer.extend_from_slice(b"content-length: 2\r\n\r\n{}");
+
+    let mut input = Cursor::new(buffer);
+    let first = super::read(&mut input).expect(zinc_const::panic::TEST_DATA_VALID);
+    let second = super::read(&mut input).expect(zinc_const::panic::TEST_DATA_VALID);
+    let third = super::read(&mut input).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(first, Some(message));
+    assert_eq!(second, Some(json!({})));
+    assert_eq!(third, None);
+}
+
+#[test]
+fn ok_connection_sequence() {
+    let request: Request = serde_json::from_value(json!({"seq": 7, "command": "threads"}))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let mut connection = Connection::new(Vec::new());
+    connection
+        .respond(&request, json!({}))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    connection
+        .event("initialized", json!({}))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let mut input = Cursor::new(connection.output);
+    let response = super::read(&mut input)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let event = super::read(&mut input)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(response["seq"], json!(1));
+    assert_eq!(response["request_seq"], json!(7));
+    assert_eq!(response["command"], json!("threads"));
+    assert_eq!(event["seq"], json!(2));
+    assert_eq!(event["event"], json!("initialized"));
+}
+
+#[test]
+fn error_content_length_invalid() {
+    let mut input = Cursor::new(b"Content-Length: many\r\n\r\n{}".to_vec());
+
+    assert!(super::read(&mut input).is_err());
+}
@@ -0,0 +1,20 @@
+//!
+//! The debugged program runtime command.
+//!
+
+///
+/// The client command, which resumes the stopped execution.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    /// Execute until a breakpoint or the first unsatisfied constraint.
+    Continue,
+    /// Execute until the next line in the same or an outer function.
+    Next,
+    /// Execute until the next line in any function.
+    StepIn,
+    /// Execute until the next line in an outer function.
+    StepOut,
+    /// Execute until the end without stopping.
+    Disconnect,
+}
@@ -0,0 +1,264 @@
+//!
+//! The debugged program runtime, which runs the virtual machine in a separate thread.
+//!
+
+pub mod command;
+pub mod mode;
+pub mod shared;
+
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::Sender;
+use std::thread;
+
+use serde_json::json;
+
+use zinc_build::Instruction;
+
+use zinc_vm::gadgets::contract::merkle_tree::IMerkleTree;
+use zinc_vm::Bn256;
+use zinc_vm::ExecutionState;
+use zinc_vm::IDebugger;
+use zinc_vm::Location;
+use zinc_vm::RuntimeError;
+use zinc_vm::Snapshot;
+use zinc_vm::Unsatisfied;
+
+use crate::error::Error;
+use crate::program::Program;
+
+use self::command::Command;
+use self::mode::Mode;
+use self::shared::Shared;
+
+/// The only thread identifier, since the virtual machine is single-threaded.
+pub const THREAD_ID: usize = 1;
+
+///
+/// The virtual machine debugger hook, which stops the execution and waits for the client commands.
+///
+pub struct Runtime<W> {
+    /// The state shared with the request handler.
+    shared: Shared<W>,
+    /// The client commands, which resume the stopped execution.
+    commands: Receiver<Command>,
+    /// The application bytecode instructions.
+    instructions: Vec<Instruction>,
+    /// The contract storage field names.
+    storage_fields: Vec<String>,
+    /// The current execution mode.
+    mode: Mode,
+    /// Whether the execution has not been stopped yet.
+    is_entry: bool,
+    /// The address of the instruction which is going to be executed next.
+    next_address: usize,
+    /// The number of executed instructions.
+    step: usize,
+    /// The previous instruction source code line, used to stop at a line only once.
+    previous_line: (Option<String>, Option<usize>),
+    /// Whether the first unsatisfied constraint has already been reported.
+    is_unsatisfied: bool,
+}
+
+impl<W> Runtime<W>
+where
+    W: Write + Send + 'static,
+{
+    ///
+    /// Starts the `program` in a separate thread.
+    ///
+    /// Returns the sender of the commands, which resume the stopped execution.
+    ///
+    pub fn spawn(program: Program, stop_on_entry: bool, shared: Shared<W>) -> Sender<Command> {
+        let (sender, receiver) = mpsc::channel();
+
+        let mut runtime = Self {
+            shared,
+            commands: receiver,
+            instructions: program.instructions().to_vec(),
+            storage_fields: program.storage_fields(),
+            mode: if stop_on_entry {
+                Mode::StepIn
+            } else {
+                Mode::Continue
+            },
+            is_entry: true,
+            next_address: program.entry(),
+            step: 0,
+            previous_line: (None, None),
+            is_unsatisfied: false,
+        };
+
+        thread::spawn(move || {
+            let result = program.run(&mut runtime);
+            runtime.terminate(result);
+        });
+
+        sender
+    }
+
+    ///
+    /// Checks if there is a breakpoint at the `location` line.
+    ///
+    fn is_breakpoint(&self, location: &Location) -> bool {
+        let (file, line) = match (location.file.as_ref(), location.line) {
+            (Some(file), Some(line)) => (crate::program::normalize(file), line),
+            _ => return false,
+        };
+
+        self.shared
+            .breakpoints
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .iter()
+            .any(|(path, lines)| lines.contains(&line) && Path::new(path).ends_with(&file))
+    }
+
+    ///
+    /// Stops the execution, notifies the client, and waits for a command to resume.
+    ///
+    fn stop<S>(
+        &mut self,
+        reason: &str,
+        text: Option<String>,
+        state: &ExecutionState<Bn256>,
+        location: &Location,
+        storage: Option<&S>,
+    ) where
+        S: IMerkleTree<Bn256>,
+    {
+        self.is_entry = false;
+
+        let storage_fields = self.storage_fields.as_slice();
+        let snapshot = Snapshot::new(
+            state,
+            location,
+            storage.map(|storage| (storage, storage_fields)),
+        );
+        *self
+            .shared
+            .snapshot
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION) = Some(snapshot);
+
+        let result = self
+            .shared
+            .connection
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .event(
+                "stopped",
+                json!({
+                    "reason": reason,
+                    "description": text.clone(),
+                    "text": text,
+                    "threadId": THREAD_ID,
+                    "allThreadsStopped": true,
+                }),
+            );
+        if let Err(error) = result {
+            log::error!("Output error: {}", error);
+            self.mode = Mode::Detached;
+            return;
+        }
+
+        let depth = state.frames_stack.len();
+        self.mode = match self.commands.recv() {
+            Ok(Command::Continue) => Mode::Continue,
+            Ok(Command::Next) => Mode::Next(depth),
+            Ok(Command::StepIn) => Mode::StepIn,
+            Ok(Command::StepOut) => Mode::StepOut(depth),
+            Ok(Command::Disconnect) | Err(_) => Mode::Detached,
+        };
+
+        self.shared
+            .snapshot
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .take();
+    }
+
+    ///
+    /// Reports the execution `result` and notifies the client that the program has terminated.
+    ///
+    fn terminate(self, result: Result<(), Error>) {
+        let (category, text, exit_code) = match result {
+            Ok(()) => (
+                "console",
+                "The execution has finished successfully".to_owned(),
+                zinc_const::exit_code::SUCCESS,
+            ),
+            Err(Error::Runtime(RuntimeError::UnsatisfiedConstraint)) if self.is_unsatisfied => (
+                "stderr",
+                "The execution has finished with an unsatisfied constraint".to_owned(),
+                zinc_const::exit_code::FAILURE,
+            ),
+            Err(error) => ("stderr", error.to_string(), zinc_const::exit_code::FAILURE),
+        };
+
+        let mut connection = self
+            .shared
+            .connection
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let result = connection
+            .output(category, format!("{}\n", text))
+            .and_then(|()| connection.event("exited", json!({ "exitCode": exit_code })))
+            .and_then(|()| connection.event("terminated", json!({})));
+        if let Err(error) = result {
+            log::error!("Output error: {}", error);
+        }
+    }
+}
+
+impl<W> IDebugger<Bn256> for Runtime<W>
+where
+    W: Write + Send + 'static,
+{
+    fn on_instruction<S: IMerkleTree<Bn256>>(
+        &mut self,
+        is_satisfied: bool,
+        state: &ExecutionState<Bn256>,
+        location: &Location,
+        storage: Option<&S>,
+    ) {
+        let address = self.next_address;
+        self.next_address = state.instruction_counter;
+        self.step += 1;
+
+        if let Mode::Detached = self.mode {
+            return;
+        }
+
+        let line = (location.file.clone(), location.line);
+        let is_new_line = location.line.is_some() && line != self.previous_line;
+        self.previous_line = line;
+
+        if !is_satisfied && !self.is_unsatisfied {
+            self.is_unsatisfied = true;
+            let unsatisfied = Unsatisfied {
+                step: self.step,
+                address,
+                instruction: self.instructions.get(address).cloned(),
+                location: location.to_owned(),
+            };
+            self.stop(
+                "exception",
+                Some(unsatisfied.to_string()),
+                state,
+                location,
+                storage,
+            );
+        } else if self.shared.pause.swap(false, Ordering::SeqCst) {
+            self.stop("pause", None, state, location, storage);
+        } else if is_new_line && self.is_breakpoint(location) {
+            self.stop("breakpoint", None, state, location, storage);
+        } else if is_new_line && self.mode.should_stop(state.frames_stack.len()) {
+            let reason = if self.is_entry { "entry" } else { "step" };
+            self.stop(reason, None, state, location, storage);
+        }
+    }
+}
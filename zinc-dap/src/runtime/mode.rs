@@ -0,0 +1,36 @@
+//!
+//! The debugged program runtime execution mode.
+//!
+
+///
+/// The execution mode, which defines where the execution stops.
+///
+/// The call stack depths are measured in the virtual machine frames.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    /// Stops at breakpoints and at the first unsatisfied constraint.
+    Continue,
+    /// Stops at the next line in any function.
+    StepIn,
+    /// Stops at the next line in a function with the call stack depth not greater than the specified.
+    Next(usize),
+    /// Stops at the next line in a function with the call stack depth less than the specified.
+    StepOut(usize),
+    /// Never stops again.
+    Detached,
+}
+
+impl Mode {
+    ///
+    /// Checks if the execution must stop at a new line with the call stack `depth`.
+    ///
+    pub fn should_stop(self, depth: usize) -> bool {
+        match self {
+            Self::StepIn => true,
+            Self::Next(start_depth) => depth <= start_depth,
+            Self::StepOut(start_depth) => depth < start_depth,
+            Self::Continue | Self::Detached => false,
+        }
+    }
+}
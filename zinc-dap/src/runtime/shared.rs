@@ -0,0 +1,52 @@
+//!
+//! The state shared between the request handler and the debugged program runtime.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use zinc_vm::Snapshot;
+
+use crate::protocol::Connection;
+
+///
+/// The state shared between the request handler and the debugged program runtime.
+///
+pub struct Shared<W> {
+    /// The outgoing side of the connection.
+    pub connection: Arc<Mutex<Connection<W>>>,
+    /// The state of the stopped execution.
+    pub snapshot: Arc<Mutex<Option<Snapshot>>>,
+    /// The breakpoint lines by the source file paths.
+    pub breakpoints: Arc<Mutex<HashMap<String, HashSet<usize>>>>,
+    /// Whether the client has requested to pause the execution.
+    pub pause: Arc<AtomicBool>,
+}
+
+impl<W> Shared<W> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(connection: Connection<W>) -> Self {
+        Self {
+            connection: Arc::new(Mutex::new(connection)),
+            snapshot: Arc::new(Mutex::new(None)),
+            breakpoints: Arc::new(Mutex::new(HashMap::new())),
+            pause: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+impl<W> Clone for Shared<W> {
+    fn clone(&self) -> Self {
+        Self {
+            connection: self.connection.clone(),
+            snapshot: self.snapshot.clone(),
+            breakpoints: self.breakpoints.clone(),
+            pause: self.pause.clone(),
+        }
+    }
+}
@@ -0,0 +1,401 @@
+//!
+//! The Debug Adapter Protocol server.
+//!
+
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc::Sender;
+use std::sync::MutexGuard;
+
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use zinc_vm::Variable;
+
+use crate::error::Error;
+use crate::program::Program;
+use crate::protocol;
+use crate::protocol::request::LaunchArguments;
+use crate::protocol::request::Request;
+use crate::protocol::request::ScopesArguments;
+use crate::protocol::request::SetBreakpointsArguments;
+use crate::protocol::request::VariablesArguments;
+use crate::protocol::Connection;
+use crate::runtime::command::Command;
+use crate::runtime::shared::Shared;
+use crate::runtime::Runtime;
+use crate::runtime::THREAD_ID;
+
+///
+/// The Debug Adapter Protocol server, which handles the client requests.
+///
+pub struct Server<R, W> {
+    /// The requests input.
+    input: R,
+    /// The state shared with the debugged program runtime.
+    shared: Shared<W>,
+    /// The program loaded by the `launch` request, which waits for the client configuration.
+    program: Option<(Program, bool)>,
+    /// Whether the client has sent all the initial configuration, e.g. breakpoints.
+    is_configured: bool,
+    /// The source code lines which have the line markers in the bytecode.
+    lines: Option<HashSet<(PathBuf, usize)>>,
+    /// The directory the source file paths in the bytecode are relative to.
+    cwd: Option<PathBuf>,
+    /// The sender of the commands, which resume the stopped execution.
+    commands: Option<Sender<Command>>,
+    /// The variable lists, which are referenced by their index plus one.
+    variables: Vec<Vec<Variable>>,
+}
+
+impl<R, W> Server<R, W>
+where
+    R: BufRead,
+    W: Write + Send + 'static,
+{
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(input: R, output: W) -> Self {
+        Self {
+            input,
+            shared: Shared::new(Connection::new(output)),
+            program: None,
+            is_configured: false,
+            lines: None,
+            cwd: None,
+            commands: None,
+            variables: Vec::new(),
+        }
+    }
+
+    ///
+    /// Handles the requests until the client disconnects or closes the input.
+    ///
+    pub fn run(mut self) -> Result<(), Error> {
+        while let Some(message) = protocol::read(&mut self.input)? {
+            if message["type"] != json!("request") {
+                continue;
+            }
+
+            let request: Request = serde_json::from_value(message)?;
+            log::debug!("Request `{}`: {}", request.command, request.arguments);
+
+            if let Err(error) = self.handle(&request) {
+                self.connection().fail(&request, error.to_string())?;
+            }
+
+            if request.command == "disconnect" {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Handles a request.
+    ///
+    fn handle(&mut self, request: &Request) -> Result<(), Error> {
+        match request.command.as_str() {
+            "initialize" => {
+                let mut connection = self.connection();
+                connection.respond(
+                    request,
+                    json!({
+                        "supportsConfigurationDoneRequest": true,
+                        "supportsTerminateRequest": true,
+                    }),
+                )?;
+                connection.event("initialized", json!({}))?;
+            }
+            "launch" => self.launch(request)?,
+            "setBreakpoints" => self.set_breakpoints(request)?,
+            "setExceptionBreakpoints" => self.connection().respond(request, json!({}))?,
+            "configurationDone" => {
+                self.is_configured = true;
+                self.connection().respond(request, json!({}))?;
+                self.start();
+            }
+            "threads" => self.connection().respond(
+                request,
+                json!({
+                    "threads": [{ "id": THREAD_ID, "name": "main" }],
+                }),
+            )?,
+            "stackTrace" => self.stack_trace(request)?,
+            "scopes" => self.scopes(request)?,
+            "variables" => self.variables(request)?,
+            "continue" => {
+                self.resume(Command::Continue);
+                self.connection()
+                    .respond(request, json!({ "allThreadsContinued": true }))?;
+            }
+            "next" => {
+                self.resume(Command::Next);
+                self.connection().respond(request, json!({}))?;
+            }
+            "stepIn" => {
+                self.resume(Command::StepIn);
+                self.connection().respond(request, json!({}))?;
+            }
+            "stepOut" => {
+                self.resume(Command::StepOut);
+                self.connection().respond(request, json!({}))?;
+            }
+            "pause" => {
+                self.shared.pause.store(true, Ordering::SeqCst);
+                self.connection().respond(request, json!({}))?;
+            }
+            "disconnect" | "terminate" => {
+                self.resume(Command::Disconnect);
+                self.connection().respond(request, json!({}))?;
+            }
+            command => {
+                return Err(Error::Protocol(format!(
+                    "unsupported command `{}`",
+                    command
+                )))
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Loads the program, which is started after the client configuration is done.
+    ///
+    fn launch(&mut self, request: &Request) -> Result<(), Error> {
+        let arguments: LaunchArguments = serde_json::from_value(request.arguments.to_owned())?;
+
+        let program = Program::new(&arguments)?;
+        self.lines = Some(program.lines());
+        self.cwd = arguments.cwd.map(PathBuf::from);
+        self.program = Some((program, arguments.stop_on_entry));
+
+        self.connection().respond(request, json!({}))?;
+        self.start();
+
+        Ok(())
+    }
+
+    ///
+    /// Starts the program if it has been launched and configured.
+    ///
+    fn start(&mut self) {
+        if !self.is_configured {
+            return;
+        }
+
+        if let Some((program, stop_on_entry)) = self.program.take() {
+            self.commands = Some(Runtime::spawn(program, stop_on_entry, self.shared.clone()));
+        }
+    }
+
+    ///
+    /// Replaces the breakpoints in a source file.
+    ///
+    /// The breakpoints are verified if there are line markers for them in the bytecode.
+    ///
+    fn set_breakpoints(&mut self, request: &Request) -> Result<(), Error> {
+        let arguments: SetBreakpointsArguments =
+            serde_json::from_value(request.arguments.to_owned())?;
+        let path = arguments
+            .source
+            .path
+            .ok_or_else(|| Error::Protocol("the breakpoint source path is missing".to_owned()))?;
+
+        let mut lines = HashSet::with_capacity(arguments.breakpoints.len());
+        let mut breakpoints = Vec::with_capacity(arguments.breakpoints.len());
+        for breakpoint in arguments.breakpoints.into_iter() {
+            let is_verified = match self.lines {
+                Some(ref markers) => markers.iter().any(|(file, line)| {
+                    *line == breakpoint.line && Path::new(path.as_str()).ends_with(file)
+                }),
+                None => true,
+            };
+
+            lines.insert(breakpoint.line);
+            breakpoints.push(json!({
+                "verified": is_verified,
+                "line": breakpoint.line,
+                "message": if is_verified { None } else { Some("There is no code at this line") },
+            }));
+        }
+
+        self.shared
+            .breakpoints
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .insert(path, lines);
+
+        self.connection()
+            .respond(request, json!({ "breakpoints": breakpoints }))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Sends the call stack of the stopped execution.
+    ///
+    fn stack_trace(&mut self, request: &Request) -> Result<(), Error> {
+        let frames: Vec<JsonValue> = self
+            .shared
+            .snapshot
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .as_ref()
+            .map(|snapshot| {
+                snapshot
+                    .frames
+                    .iter()
+                    .enumerate()
+                    .map(|(index, frame)| {
+                        let source = frame.location.file.as_ref().map(|file| {
+                            let path = crate::program::normalize(file.as_str());
+                            let path = match self.cwd {
+                                Some(ref cwd) => cwd.join(path),
+                                None => path,
+                            };
+
+                            json!({
+                                "name": path.file_name().map(|name| name.to_string_lossy()),
+                                "path": path.to_string_lossy(),
+                            })
+                        });
+
+                        json!({
+                            "id": index,
+                            "name": frame.location.function.as_deref().unwrap_or("<unknown>"),
+                            "source": source,
+                            "line": frame.location.line.unwrap_or_default(),
+                            "column": frame.location.column.unwrap_or(1),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.connection().respond(
+            request,
+            json!({
+                "totalFrames": frames.len(),
+                "stackFrames": frames,
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Sends the variable scopes of a stack frame.
+    ///
+    fn scopes(&mut self, request: &Request) -> Result<(), Error> {
+        let arguments: ScopesArguments = serde_json::from_value(request.arguments.to_owned())?;
+
+        let (locals, storage) = match *self
+            .shared
+            .snapshot
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+        {
+            Some(ref snapshot) => (
+                snapshot
+                    .frames
+                    .get(arguments.frame_id)
+                    .map(|frame| frame.locals.to_owned())
+                    .unwrap_or_default(),
+                snapshot.storage.to_owned(),
+            ),
+            None => (vec![], None),
+        };
+
+        let mut scopes = vec![json!({
+            "name": "Locals",
+            "presentationHint": "locals",
+            "variablesReference": self.reference(locals),
+            "expensive": false,
+        })];
+        if let Some(storage) = storage {
+            scopes.push(json!({
+                "name": "Storage",
+                "variablesReference": self.reference(storage),
+                "expensive": false,
+            }));
+        }
+
+        self.connection()
+            .respond(request, json!({ "scopes": scopes }))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Sends the variables by their reference.
+    ///
+    fn variables(&mut self, request: &Request) -> Result<(), Error> {
+        let arguments: VariablesArguments = serde_json::from_value(request.arguments.to_owned())?;
+
+        let variables = arguments
+            .variables_reference
+            .checked_sub(1)
+            .and_then(|index| self.variables.get(index))
+            .cloned()
+            .unwrap_or_default();
+
+        let mut result = Vec::with_capacity(variables.len());
+        for variable in variables.into_iter() {
+            let reference = if variable.children.is_empty() {
+                0
+            } else {
+                self.reference(variable.children)
+            };
+
+            result.push(json!({
+                "name": variable.name,
+                "value": variable.value,
+                "variablesReference": reference,
+            }));
+        }
+
+        self.connection()
+            .respond(request, json!({ "variables": result }))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Resumes the stopped execution, invalidating the variable references.
+    ///
+    fn resume(&mut self, command: Command) {
+        self.variables.clear();
+
+        if let Some(ref commands) = self.commands {
+            if commands.send(command).is_err() {
+                log::debug!("The program has already terminated");
+            }
+        }
+    }
+
+    ///
+    /// Stores the variable list and returns its reference.
+    ///
+    fn reference(&mut self, variables: Vec<Variable>) -> usize {
+        self.variables.push(variables);
+        self.variables.len()
+    }
+
+    ///
+    /// Locks the outgoing side of the connection.
+    ///
+    fn connection(&self) -> MutexGuard<'_, Connection<W>> {
+        self.shared
+            .connection
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+    }
+}
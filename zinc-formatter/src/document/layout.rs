@@ -0,0 +1,144 @@
+//!
+//! The formatter document layout.
+//!
+
+use crate::document::Document;
+
+/// The number of spaces in a single indentation level.
+const INDENT_SIZE: usize = 4;
+
+///
+/// The group layout mode.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    /// The group is printed on a single line.
+    Flat,
+    /// The group line breaks are printed as line breaks.
+    Broken,
+}
+
+///
+/// Lays the `document` out and returns the resulting text.
+///
+/// The groups are processed from the outermost ones, and each group is printed on a single line
+/// if the rest of the line up to the next line break fits the `width`.
+///
+pub fn render(document: &Document, width: usize) -> String {
+    let mut output = String::new();
+    let mut column = 0;
+    let mut stack = vec![(0, Mode::Broken, document)];
+
+    while let Some((indent, mode, document)) = stack.pop() {
+        match document {
+            Document::Text(text) => {
+                output.push_str(text);
+                column = match text.rfind('\n') {
+                    Some(position) => text[position + 1..].chars().count(),
+                    None => column + text.chars().count(),
+                };
+            }
+            Document::Line if mode == Mode::Flat => {
+                output.push(' ');
+                column += 1;
+            }
+            Document::SoftLine if mode == Mode::Flat => {}
+            Document::Line | Document::SoftLine | Document::HardLine => {
+                column = newline(&mut output, indent);
+            }
+            Document::IfBroken(text) => {
+                if mode == Mode::Broken {
+                    output.push_str(text);
+                    column += text.chars().count();
+                }
+            }
+            Document::Indent(inner) => stack.push((indent + INDENT_SIZE, mode, inner)),
+            Document::Group(inner) => {
+                let mode = if mode == Mode::Flat
+                    || (!inner.is_multiline() && fits(inner, &stack, width.saturating_sub(column)))
+                {
+                    Mode::Flat
+                } else {
+                    Mode::Broken
+                };
+                stack.push((indent, mode, inner));
+            }
+            Document::Concat(documents) => {
+                for document in documents.iter().rev() {
+                    stack.push((indent, mode, document));
+                }
+            }
+        }
+    }
+
+    let length = output.trim_end().len();
+    output.truncate(length);
+    output
+}
+
+///
+/// Checks whether the flat `document` followed by the `rest` up to the next line break
+/// fits the `width`.
+///
+fn fits(document: &Document, rest: &[(usize, Mode, &Document)], width: usize) -> bool {
+    let mut width = width as isize;
+    let mut rest = rest.iter().rev();
+    let mut queue = vec![(Mode::Flat, document)];
+
+    loop {
+        let (mode, document) = match queue.pop() {
+            Some(element) => element,
+            None => match rest.next() {
+                Some((_indent, mode, document)) => (*mode, *document),
+                None => return true,
+            },
+        };
+
+        match document {
+            Document::Text(text) => match text.find('\n') {
+                Some(position) => return width >= text[..position].chars().count() as isize,
+                None => width -= text.chars().count() as isize,
+            },
+            Document::Line if mode == Mode::Flat => width -= 1,
+            Document::SoftLine if mode == Mode::Flat => {}
+            Document::Line | Document::SoftLine | Document::HardLine => return true,
+            Document::IfBroken(text) => {
+                if mode == Mode::Broken {
+                    width -= text.chars().count() as isize;
+                }
+            }
+            Document::Indent(inner) => queue.push((mode, inner)),
+            Document::Group(inner) => {
+                let mode = if inner.is_multiline() {
+                    Mode::Broken
+                } else {
+                    mode
+                };
+                queue.push((mode, inner));
+            }
+            Document::Concat(documents) => {
+                for document in documents.iter().rev() {
+                    queue.push((mode, document));
+                }
+            }
+        }
+
+        if width < 0 {
+            return false;
+        }
+    }
+}
+
+///
+/// Writes a line break followed by the `indent`, removing the trailing whitespace of
+/// the previous line.
+///
+/// Returns the new column.
+///
+fn newline(output: &mut String, indent: usize) -> usize {
+    let length = output.trim_end_matches(' ').len();
+    output.truncate(length);
+    output.push('\n');
+    output.push_str(" ".repeat(indent).as_str());
+    indent
+}
@@ -0,0 +1,165 @@
+//!
+//! The formatter document.
+//!
+
+pub mod layout;
+
+///
+/// The formatter document, which describes the output text and the places where it may be broken
+/// into several lines.
+///
+/// The document is laid out with the `layout` module, which breaks the outermost groups first,
+/// until the lines fit the maximal width.
+///
+#[derive(Debug, Clone)]
+pub enum Document {
+    /// The text, which is printed as is.
+    Text(String),
+    /// The space if the enclosing group fits the line, and the line break otherwise.
+    Line,
+    /// Nothing if the enclosing group fits the line, and the line break otherwise.
+    SoftLine,
+    /// The line break, which also breaks all the enclosing groups.
+    HardLine,
+    /// The text, which is printed only if the enclosing group is broken, e.g. a trailing comma.
+    IfBroken(&'static str),
+    /// The inner document, whose lines are indented one level deeper.
+    Indent(Box<Self>),
+    /// The inner document, which is either printed on a single line or broken as a whole.
+    Group(Box<Self>),
+    /// The sequence of documents.
+    Concat(Vec<Self>),
+}
+
+impl Document {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn text<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::Text(text.into())
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn concat(documents: Vec<Self>) -> Self {
+        Self::Concat(documents)
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn indent(inner: Self) -> Self {
+        Self::Indent(Box::new(inner))
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn group(inner: Self) -> Self {
+        Self::Group(Box::new(inner))
+    }
+
+    ///
+    /// Joins the `documents` with the `separator`.
+    ///
+    pub fn join(documents: Vec<Self>, separator: Self) -> Self {
+        let mut result = Vec::with_capacity(documents.len() * 2);
+        for (index, document) in documents.into_iter().enumerate() {
+            if index > 0 {
+                result.push(separator.clone());
+            }
+            result.push(document);
+        }
+        Self::Concat(result)
+    }
+
+    ///
+    /// Creates a comma-separated list enclosed with `open` and `close`, which is broken into
+    /// one element per line with a trailing comma if it does not fit the line.
+    ///
+    /// '(a, b, c)'
+    /// '[a, b, c]'
+    ///
+    pub fn list(open: &'static str, elements: Vec<Self>, close: &'static str) -> Self {
+        if elements.is_empty() {
+            return Self::text(format!("{}{}", open, close));
+        }
+
+        Self::group(Self::concat(vec![
+            Self::text(open),
+            Self::indent(Self::concat(vec![
+                Self::SoftLine,
+                Self::join(elements, Self::concat(vec![Self::text(","), Self::Line])),
+                Self::IfBroken(","),
+            ])),
+            Self::SoftLine,
+            Self::text(close),
+        ]))
+    }
+
+    ///
+    /// Creates a comma-separated list enclosed with curly brackets, which are separated from
+    /// the elements with spaces.
+    ///
+    /// '{ a: 1, b: 2 }'
+    ///
+    pub fn braces(elements: Vec<Self>) -> Self {
+        if elements.is_empty() {
+            return Self::text("{}");
+        }
+
+        Self::group(Self::concat(vec![
+            Self::text("{"),
+            Self::indent(Self::concat(vec![
+                Self::Line,
+                Self::join(elements, Self::concat(vec![Self::text(","), Self::Line])),
+                Self::IfBroken(","),
+            ])),
+            Self::Line,
+            Self::text("}"),
+        ]))
+    }
+
+    ///
+    /// Creates a block enclosed with curly brackets, whose lines are always printed separately.
+    ///
+    pub fn block(lines: Vec<Self>) -> Self {
+        if lines.is_empty() {
+            return Self::text("{}");
+        }
+
+        Self::concat(vec![
+            Self::text("{"),
+            Self::indent(Self::concat(vec![
+                Self::HardLine,
+                Self::join(lines, Self::HardLine),
+            ])),
+            Self::HardLine,
+            Self::text("}"),
+        ])
+    }
+
+    ///
+    /// Checks whether the document contains a hard line break, so it can never fit a line.
+    ///
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Self::Text(text) => text.contains('\n'),
+            Self::HardLine => true,
+            Self::Indent(inner) | Self::Group(inner) => inner.is_multiline(),
+            Self::Concat(documents) => documents.iter().any(Self::is_multiline),
+            Self::Line | Self::SoftLine | Self::IfBroken(_) => false,
+        }
+    }
+
+    ///
+    /// Lays the document out and returns the text, whose lines fit the `width` where possible.
+    ///
+    pub fn render(&self, width: usize) -> String {
+        self::layout::render(self, width)
+    }
+}
@@ -0,0 +1,36 @@
+//!
+//! The formatter error.
+//!
+
+use failure::Fail;
+
+use zinc_compiler::Diagnostic;
+
+///
+/// The formatter error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The source code cannot be parsed, so it cannot be formatted.
+    #[fail(display = "{}", _0)]
+    Parsing(String),
+    /// The formatted code cannot be parsed, which is a formatter bug.
+    #[fail(display = "the formatted code is invalid:\n{}", _0)]
+    Invalid(String),
+}
+
+impl Error {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn parsing(diagnostic: Diagnostic) -> Self {
+        Self::Parsing(diagnostic.format())
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn invalid(diagnostic: Diagnostic) -> Self {
+        Self::Invalid(diagnostic.format())
+    }
+}
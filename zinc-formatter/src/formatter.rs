@@ -0,0 +1,67 @@
+//!
+//! The Zinc source code formatter.
+//!
+
+use std::path::PathBuf;
+
+use zinc_compiler::Error as CompilerError;
+use zinc_lexical::FILE_INDEX;
+use zinc_syntax::Parser;
+
+use crate::error::Error;
+use crate::printer::Printer;
+use crate::trivia::Trivia;
+
+///
+/// The Zinc source code formatter.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Formatter {
+    /// The maximal line width, which the formatter tries to fit the code into.
+    pub width: usize,
+}
+
+impl Default for Formatter {
+    fn default() -> Self {
+        Self::new(zinc_const::limit::FORMATTER_WIDTH)
+    }
+}
+
+impl Formatter {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(width: usize) -> Self {
+        Self { width }
+    }
+
+    ///
+    /// Formats the `code` of the file at `path`.
+    ///
+    /// The formatted code is parsed again to make sure the formatter has not broken it.
+    ///
+    pub fn format(&self, path: &PathBuf, code: &str) -> Result<String, Error> {
+        let file = FILE_INDEX.next(path, code.to_owned());
+        let module = Parser::default()
+            .parse(code, file)
+            .map_err(|error| Error::parsing(CompilerError::from(error).diagnostic()))?;
+        let trivia = Trivia::new(code, file)
+            .map_err(|error| Error::parsing(CompilerError::from(error).diagnostic()))?;
+
+        let mut output = Printer::new(trivia)
+            .module(module)
+            .render(self.width)
+            .replace("\r\n", "\n");
+        output.push('\n');
+        if code.contains("\r\n") {
+            output = output.replace('\n', "\r\n");
+        }
+
+        let file = FILE_INDEX.next(path, output.clone());
+        Parser::default()
+            .parse(output.as_str(), file)
+            .map_err(|error| Error::invalid(CompilerError::from(error).diagnostic()))?;
+
+        Ok(output)
+    }
+}
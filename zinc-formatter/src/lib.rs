@@ -0,0 +1,15 @@
+//!
+//! The Zinc source code formatter library.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) mod document;
+pub(crate) mod error;
+pub(crate) mod formatter;
+pub(crate) mod printer;
+pub(crate) mod trivia;
+
+pub use self::error::Error;
+pub use self::formatter::Formatter;
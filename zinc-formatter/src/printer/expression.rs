@@ -0,0 +1,794 @@
+//!
+//! The syntax tree printer expressions.
+//!
+
+use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+use zinc_lexical::Location;
+use zinc_syntax::ArrayExpressionVariant;
+use zinc_syntax::BlockExpression;
+use zinc_syntax::ConditionalExpression;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionOperator;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::FunctionLocalStatement;
+use zinc_syntax::IntegerLiteral;
+use zinc_syntax::MatchExpression;
+
+use crate::document::Document;
+use crate::printer::statement;
+use crate::printer::Printer;
+use crate::trivia::Trivia;
+
+/// The precedence of the assignment operators.
+const PRECEDENCE_ASSIGNMENT: usize = 1;
+/// The precedence of the range operators.
+const PRECEDENCE_RANGE: usize = 2;
+/// The precedence of the comparison operators.
+const PRECEDENCE_COMPARISON: usize = 6;
+/// The precedence of the casting operator.
+const PRECEDENCE_CASTING: usize = 13;
+/// The precedence of the unary operators.
+const PRECEDENCE_UNARY: usize = 14;
+/// The precedence of the access operators, e.g. the call or field access.
+const PRECEDENCE_ACCESS: usize = 15;
+/// The precedence of the path and structure operators.
+const PRECEDENCE_PATH: usize = 16;
+/// The precedence of the operands, which never require parentheses.
+const PRECEDENCE_OPERAND: usize = 17;
+
+impl<'a> Printer<'a> {
+    ///
+    /// Prints the expression, restoring the parentheses where the operator precedence requires.
+    ///
+    pub(crate) fn expression(&mut self, tree: ExpressionTree) -> Document {
+        match *tree.value {
+            ExpressionTreeNode::Operand(operand) => self.operand(operand),
+            ExpressionTreeNode::Operator(operator) => {
+                let left = tree.left.map(|left| *left);
+                let right = tree.right.map(|right| *right);
+
+                match operator {
+                    ExpressionOperator::Not
+                    | ExpressionOperator::BitwiseNot
+                    | ExpressionOperator::Negation => {
+                        let operand =
+                            left.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
+                        let is_double_negation = operator == ExpressionOperator::Negation
+                            && matches!(
+                                *operand.value,
+                                ExpressionTreeNode::Operator(ExpressionOperator::Negation)
+                            );
+                        let operand = if is_double_negation {
+                            self.parenthesized(operand)
+                        } else {
+                            self.operand_of(operand, PRECEDENCE_UNARY, false)
+                        };
+
+                        Document::concat(vec![Document::text(symbol(operator)), operand])
+                    }
+                    ExpressionOperator::Index => Document::concat(vec![
+                        self.operand_of(
+                            left.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                            PRECEDENCE_ACCESS,
+                            false,
+                        ),
+                        Document::text("["),
+                        self.expression(
+                            right.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                        ),
+                        Document::text("]"),
+                    ]),
+                    ExpressionOperator::Dot | ExpressionOperator::Path => Document::concat(vec![
+                        match left.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS) {
+                            left if is_tuple_index(&left)
+                                && right.as_ref().map_or(false, is_tuple_index_operand) =>
+                            {
+                                self.parenthesized(left)
+                            }
+                            left => self.operand_of(left, precedence(&operator), false),
+                        },
+                        Document::text(symbol(operator)),
+                        self.operand_of(
+                            right.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                            precedence(&operator),
+                            true,
+                        ),
+                    ]),
+                    ExpressionOperator::CallIntrinsic => Document::concat(vec![
+                        self.operand_of(
+                            left.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                            PRECEDENCE_ACCESS,
+                            false,
+                        ),
+                        Document::text("!"),
+                    ]),
+                    ExpressionOperator::Call => {
+                        let callee = self.operand_of(
+                            left.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                            PRECEDENCE_ACCESS,
+                            false,
+                        );
+                        let arguments = match right.map(|right| *right.value) {
+                            Some(ExpressionTreeNode::Operand(ExpressionOperand::List(list))) => {
+                                self.expression_list("(", list.elements, ")")
+                            }
+                            _ => Document::text("()"),
+                        };
+
+                        Document::concat(vec![callee, arguments])
+                    }
+                    ExpressionOperator::Structure => Document::concat(vec![
+                        self.operand_of(
+                            left.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                            PRECEDENCE_PATH,
+                            false,
+                        ),
+                        Document::text(" "),
+                        self.expression(
+                            right.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                        ),
+                    ]),
+                    ExpressionOperator::Casting => Document::concat(vec![
+                        self.operand_of(
+                            left.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                            PRECEDENCE_CASTING,
+                            false,
+                        ),
+                        Document::text(" as "),
+                        self.expression(
+                            right.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                        ),
+                    ]),
+                    ExpressionOperator::Range | ExpressionOperator::RangeInclusive => {
+                        Document::concat(vec![
+                            self.binary_operand(
+                                left.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                                PRECEDENCE_RANGE,
+                                true,
+                                false,
+                            ),
+                            Document::text(symbol(operator)),
+                            self.binary_operand(
+                                right.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                                PRECEDENCE_RANGE,
+                                true,
+                                true,
+                            ),
+                        ])
+                    }
+                    operator if precedence(&operator) == PRECEDENCE_ASSIGNMENT => {
+                        Document::concat(vec![
+                            self.operand_of(
+                                left.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                                PRECEDENCE_ASSIGNMENT,
+                                true,
+                            ),
+                            Document::text(format!(" {} ", symbol(operator))),
+                            self.operand_of(
+                                right.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                                PRECEDENCE_ASSIGNMENT,
+                                true,
+                            ),
+                        ])
+                    }
+                    operator => self.binary(
+                        operator,
+                        left.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                        right.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                    ),
+                }
+            }
+        }
+    }
+
+    ///
+    /// Prints the comma-separated expressions enclosed into the `open` and `close` brackets.
+    ///
+    pub(crate) fn expression_list(
+        &mut self,
+        open: &'static str,
+        elements: Vec<ExpressionTree>,
+        close: &'static str,
+    ) -> Document {
+        let elements = elements
+            .into_iter()
+            .map(|element| self.expression(element))
+            .collect();
+        Document::list(open, elements, close)
+    }
+
+    ///
+    /// Prints the block with a statement per line.
+    ///
+    pub(crate) fn block(&mut self, block: BlockExpression) -> Document {
+        Document::block(self.block_lines(block))
+    }
+
+    ///
+    /// Prints the integer literal in its original spelling.
+    ///
+    pub(crate) fn integer(&self, literal: IntegerLiteral) -> Document {
+        match self.trivia.literal(literal.location) {
+            Some(text) => Document::text(text),
+            None => Document::text(integer(literal.inner)),
+        }
+    }
+
+    ///
+    /// Prints the block statements and the final expression, one per line.
+    ///
+    fn block_lines(&mut self, block: BlockExpression) -> Vec<Document> {
+        let end = self.trivia.closing(block.location);
+
+        let statements: Vec<FunctionLocalStatement> = block
+            .statements
+            .into_iter()
+            .filter(|statement| !matches!(statement, FunctionLocalStatement::Empty(_)))
+            .collect();
+        let last_statement = statements.len().checked_sub(1);
+        let has_expression = block.expression.is_some();
+
+        let mut items: Vec<(FunctionLocalStatement, &'static str)> = statements
+            .into_iter()
+            .enumerate()
+            .map(|(index, statement)| {
+                let is_unterminated = match statement {
+                    FunctionLocalStatement::Expression(ref expression) => {
+                        expression.can_be_unterminated()
+                            && (has_expression || Some(index) != last_statement)
+                    }
+                    _ => false,
+                };
+                (statement, if is_unterminated { "" } else { ";" })
+            })
+            .collect();
+        if let Some(expression) = block.expression {
+            items.push((FunctionLocalStatement::Expression(*expression), ""));
+        }
+
+        self.lines(
+            items,
+            |(statement, _)| statement::start_function_local(statement),
+            end,
+            |printer, (statement, terminator)| match statement {
+                FunctionLocalStatement::Expression(expression) => {
+                    printer.statement_expression(expression, terminator)
+                }
+                statement => printer.function_local_statement(statement),
+            },
+        )
+    }
+
+    ///
+    /// Prints the expression, which is a function-level statement or the final block expression,
+    /// followed by the `terminator`.
+    ///
+    /// The expressions, which start with a block-like operand, are parenthesized, since otherwise
+    /// the operand would be parsed as a separate statement.
+    ///
+    pub(crate) fn statement_expression(
+        &mut self,
+        expression: ExpressionTree,
+        terminator: &'static str,
+    ) -> Document {
+        let document = if !expression.can_be_unterminated() && starts_with_block(&expression) {
+            self.parenthesized(expression)
+        } else {
+            self.expression(expression)
+        };
+
+        Document::concat(vec![document, Document::text(terminator)])
+    }
+
+    ///
+    /// Prints the operand of an operator with the `precedence`, parenthesizing it if its own
+    /// precedence is lower, or lower or equal if `is_strict` is set.
+    ///
+    fn operand_of(&mut self, tree: ExpressionTree, precedence: usize, is_strict: bool) -> Document {
+        let own = tree_precedence(&tree);
+        if own < precedence || (is_strict && own == precedence) {
+            self.parenthesized(tree)
+        } else {
+            self.expression(tree)
+        }
+    }
+
+    ///
+    /// Prints the operand of a binary operator like `operand_of`, but also parenthesizes
+    /// the casting, unless it is the `is_last` operand, e.g. `(a as u8) + b as u8`.
+    ///
+    fn binary_operand(
+        &mut self,
+        tree: ExpressionTree,
+        precedence: usize,
+        is_strict: bool,
+        is_last: bool,
+    ) -> Document {
+        let is_casting = matches!(
+            *tree.value,
+            ExpressionTreeNode::Operator(ExpressionOperator::Casting)
+        );
+
+        if is_casting && !is_last {
+            self.parenthesized(tree)
+        } else {
+            self.operand_of(tree, precedence, is_strict)
+        }
+    }
+
+    ///
+    /// Prints the expression enclosed into parentheses.
+    ///
+    fn parenthesized(&mut self, tree: ExpressionTree) -> Document {
+        Document::concat(vec![
+            Document::text("("),
+            self.expression(tree),
+            Document::text(")"),
+        ])
+    }
+
+    ///
+    /// Prints a chain of the left-associative binary operators of the same precedence, which
+    /// is broken before the operators if it does not fit the line.
+    ///
+    fn binary(
+        &mut self,
+        operator: ExpressionOperator,
+        left: ExpressionTree,
+        right: ExpressionTree,
+    ) -> Document {
+        let chain_precedence = precedence(&operator);
+        let is_associative = chain_precedence != PRECEDENCE_COMPARISON;
+
+        let mut chain = vec![(operator, right)];
+        let mut first = left;
+        while is_associative {
+            match *first.value {
+                ExpressionTreeNode::Operator(operator)
+                    if precedence(&operator) == chain_precedence =>
+                {
+                    chain.push((
+                        operator,
+                        *first
+                            .right
+                            .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                    ));
+                    first = *first
+                        .left
+                        .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
+                }
+                _ => break,
+            }
+        }
+
+        let first = self.binary_operand(first, chain_precedence, !is_associative, false);
+        let mut rest = Vec::with_capacity(chain.len() * 3);
+        let last = chain.len() - 1;
+        for (index, (operator, operand)) in chain.into_iter().rev().enumerate() {
+            rest.push(Document::Line);
+            rest.push(Document::text(format!("{} ", symbol(operator))));
+            rest.push(self.binary_operand(operand, chain_precedence, true, index == last));
+        }
+
+        Document::group(Document::concat(vec![
+            first,
+            Document::indent(Document::concat(rest)),
+        ]))
+    }
+
+    ///
+    /// Prints the expression operand.
+    ///
+    fn operand(&mut self, operand: ExpressionOperand) -> Document {
+        match operand {
+            ExpressionOperand::LiteralUnit(_location) => Document::text("()"),
+            ExpressionOperand::LiteralBoolean(literal) => Document::text(literal.inner.to_string()),
+            ExpressionOperand::LiteralInteger(literal) => self.integer(literal),
+            ExpressionOperand::LiteralString(literal) => {
+                match self.trivia.literal(literal.location) {
+                    Some(text) => Document::text(text),
+                    None => Document::text(format!(
+                        "\"{}\"",
+                        literal
+                            .inner
+                            .inner
+                            .replace('\\', "\\\\")
+                            .replace('"', "\\\"")
+                    )),
+                }
+            }
+            ExpressionOperand::TupleIndex(index) => self.integer(index.literal),
+            ExpressionOperand::Identifier(identifier) => Document::text(identifier.name),
+            ExpressionOperand::Type(r#type) => self.r#type(r#type),
+            ExpressionOperand::Array(array) => match array.variant {
+                ArrayExpressionVariant::List { elements } => {
+                    self.expression_list("[", elements, "]")
+                }
+                ArrayExpressionVariant::Repeated {
+                    expression,
+                    size_expression,
+                } => Document::concat(vec![
+                    Document::text("["),
+                    self.expression(expression),
+                    Document::text("; "),
+                    self.expression(size_expression),
+                    Document::text("]"),
+                ]),
+            },
+            ExpressionOperand::Tuple(tuple) if tuple.elements.len() == 1 => {
+                let mut elements = tuple.elements;
+                Document::concat(vec![
+                    Document::text("("),
+                    self.expression(elements.remove(0)),
+                    Document::text(",)"),
+                ])
+            }
+            ExpressionOperand::Tuple(tuple) => self.expression_list("(", tuple.elements, ")"),
+            ExpressionOperand::Structure(structure) => {
+                let fields = structure
+                    .fields
+                    .into_iter()
+                    .map(|(identifier, expression)| {
+                        Document::concat(vec![
+                            Document::text(format!("{}: ", identifier.name)),
+                            self.expression(expression),
+                        ])
+                    })
+                    .collect();
+                Document::braces(fields)
+            }
+            ExpressionOperand::List(list) => self.expression_list("(", list.elements, ")"),
+            ExpressionOperand::Block(block) => {
+                let is_expression_only = block.statements.is_empty() && block.expression.is_some();
+                let comments = self.trivia.pending();
+                let mut lines = self.block_lines(block);
+
+                if is_expression_only && lines.len() == 1 && self.trivia.pending() == comments {
+                    Document::group(Document::concat(vec![
+                        Document::text("{"),
+                        Document::indent(Document::concat(vec![Document::Line, lines.remove(0)])),
+                        Document::Line,
+                        Document::text("}"),
+                    ]))
+                } else {
+                    Document::block(lines)
+                }
+            }
+            ExpressionOperand::Conditional(conditional) => self.conditional(conditional),
+            ExpressionOperand::Match(r#match) => self.r#match(r#match),
+            ExpressionOperand::Closure(closure) => {
+                let arguments: Vec<Document> = closure
+                    .arguments
+                    .into_iter()
+                    .map(|binding| self.binding(binding))
+                    .collect();
+
+                Document::concat(vec![
+                    Document::text("|"),
+                    Document::join(arguments, Document::text(", ")),
+                    Document::text("| "),
+                    self.expression(*closure.body),
+                ])
+            }
+        }
+    }
+
+    ///
+    /// Prints the conditional expression, collapsing the nested conditionals in the `else`
+    /// blocks into the `else if` chains.
+    ///
+    fn conditional(&mut self, conditional: ConditionalExpression) -> Document {
+        if self.is_conditional_inline(&conditional) {
+            let branch = |expression: Option<Box<ExpressionTree>>, printer: &mut Self| {
+                let expression =
+                    expression.expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
+                Document::concat(vec![
+                    Document::indent(Document::concat(vec![
+                        Document::Line,
+                        printer.expression(*expression),
+                    ])),
+                    Document::Line,
+                ])
+            };
+
+            return Document::group(Document::concat(vec![
+                Document::text("if "),
+                self.expression(*conditional.condition),
+                Document::text(" {"),
+                branch(conditional.main_block.expression, self),
+                Document::text("} else {"),
+                branch(
+                    conditional.else_block.and_then(|block| block.expression),
+                    self,
+                ),
+                Document::text("}"),
+            ]));
+        }
+
+        let mut documents = vec![
+            Document::text("if "),
+            self.expression(*conditional.condition),
+            Document::text(" "),
+            self.block(conditional.main_block),
+        ];
+
+        if let Some(mut else_block) = conditional.else_block {
+            documents.push(Document::text(" else "));
+
+            let is_else_if = else_block.statements.is_empty()
+                && match else_block.expression.as_deref() {
+                    Some(ExpressionTree {
+                        value, location, ..
+                    }) => {
+                        matches!(
+                            **value,
+                            ExpressionTreeNode::Operand(ExpressionOperand::Conditional(_))
+                        ) && Trivia::position(*location) == Trivia::position(else_block.location)
+                    }
+                    None => false,
+                };
+
+            match else_block.expression.take() {
+                Some(expression) if is_else_if => documents.push(self.expression(*expression)),
+                expression => {
+                    else_block.expression = expression;
+                    documents.push(self.block(else_block));
+                }
+            }
+        }
+
+        Document::concat(documents)
+    }
+
+    ///
+    /// Checks whether the conditional is short like `if a > b { a } else { b }`, that is, both
+    /// blocks consist of a single expression without comments, and may be put on a single line.
+    ///
+    fn is_conditional_inline(&self, conditional: &ConditionalExpression) -> bool {
+        let is_expression_only = |block: &BlockExpression| match block.expression.as_deref() {
+            Some(expression) => block.statements.is_empty() && !expression.can_be_unterminated(),
+            None => false,
+        };
+
+        match conditional.else_block {
+            Some(ref else_block)
+                if is_expression_only(&conditional.main_block)
+                    && is_expression_only(else_block) =>
+            {
+                match self.trivia.closing(else_block.location) {
+                    Some(end) => !self
+                        .trivia
+                        .has_comments(conditional.main_block.location, end),
+                    None => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    ///
+    /// Prints the match expression with a branch per line.
+    ///
+    fn r#match(&mut self, r#match: MatchExpression) -> Document {
+        let scrutinee = self.expression(r#match.scrutinee);
+
+        let end = r#match
+            .branches
+            .first()
+            .and_then(|(pattern, _)| self.trivia.enclosing(pattern.location));
+        let branches = self.lines(
+            r#match.branches,
+            |(pattern, _)| pattern.location,
+            end,
+            |printer, (pattern, expression)| {
+                let is_block = matches!(
+                    *expression.value,
+                    ExpressionTreeNode::Operand(ExpressionOperand::Block(_))
+                );
+
+                Document::concat(vec![
+                    printer.match_pattern(pattern),
+                    Document::text(" => "),
+                    printer.expression(expression),
+                    Document::text(if is_block { "" } else { "," }),
+                ])
+            },
+        );
+
+        Document::concat(vec![
+            Document::text("match "),
+            scrutinee,
+            Document::text(" "),
+            Document::block(branches),
+        ])
+    }
+}
+
+///
+/// Returns the location of the leftmost expression token.
+///
+pub fn start(tree: &ExpressionTree) -> Location {
+    match tree.left.as_deref() {
+        Some(left) => {
+            let left = start(left);
+            if Trivia::position(left) < Trivia::position(tree.location) {
+                left
+            } else {
+                tree.location
+            }
+        }
+        None => tree.location,
+    }
+}
+
+///
+/// Checks whether the expression is a tuple field access like `tuple.0`.
+///
+/// The nested tuple field access like `(tuple.0).1` must keep the parentheses, since `0.1` is
+/// a decimal literal.
+///
+fn is_tuple_index(tree: &ExpressionTree) -> bool {
+    matches!(
+        *tree.value,
+        ExpressionTreeNode::Operator(ExpressionOperator::Dot)
+    ) && tree.right.as_deref().map_or(false, is_tuple_index_operand)
+}
+
+///
+/// Checks whether the expression is a tuple index operand.
+///
+fn is_tuple_index_operand(tree: &ExpressionTree) -> bool {
+    matches!(
+        *tree.value,
+        ExpressionTreeNode::Operand(ExpressionOperand::TupleIndex(_))
+    )
+}
+
+///
+/// Checks whether the leftmost expression operand is a block, conditional, or match.
+///
+fn starts_with_block(tree: &ExpressionTree) -> bool {
+    match *tree.value {
+        ExpressionTreeNode::Operand(ref operand) => operand.can_be_unterminated(),
+        ExpressionTreeNode::Operator(operator) if precedence(&operator) == PRECEDENCE_UNARY => {
+            false
+        }
+        ExpressionTreeNode::Operator(_) => tree.left.as_deref().map_or(false, starts_with_block),
+    }
+}
+
+///
+/// Returns the expression precedence, which is the precedence of its root operator.
+///
+fn tree_precedence(tree: &ExpressionTree) -> usize {
+    match *tree.value {
+        ExpressionTreeNode::Operand(ExpressionOperand::Closure(_)) => 0,
+        ExpressionTreeNode::Operand(_) => PRECEDENCE_OPERAND,
+        ExpressionTreeNode::Operator(ref operator) => precedence(operator),
+    }
+}
+
+///
+/// Returns the operator precedence in the order of the parser, from the lowest one.
+///
+fn precedence(operator: &ExpressionOperator) -> usize {
+    match operator {
+        ExpressionOperator::Assignment
+        | ExpressionOperator::AssignmentBitwiseOr
+        | ExpressionOperator::AssignmentBitwiseXor
+        | ExpressionOperator::AssignmentBitwiseAnd
+        | ExpressionOperator::AssignmentBitwiseShiftLeft
+        | ExpressionOperator::AssignmentBitwiseShiftRight
+        | ExpressionOperator::AssignmentAddition
+        | ExpressionOperator::AssignmentSubtraction
+        | ExpressionOperator::AssignmentMultiplication
+        | ExpressionOperator::AssignmentDivision
+        | ExpressionOperator::AssignmentRemainder => PRECEDENCE_ASSIGNMENT,
+        ExpressionOperator::Range | ExpressionOperator::RangeInclusive => PRECEDENCE_RANGE,
+        ExpressionOperator::Or => 3,
+        ExpressionOperator::Xor => 4,
+        ExpressionOperator::And => 5,
+        ExpressionOperator::Equals
+        | ExpressionOperator::NotEquals
+        | ExpressionOperator::GreaterEquals
+        | ExpressionOperator::LesserEquals
+        | ExpressionOperator::Greater
+        | ExpressionOperator::Lesser => PRECEDENCE_COMPARISON,
+        ExpressionOperator::BitwiseOr => 7,
+        ExpressionOperator::BitwiseXor => 8,
+        ExpressionOperator::BitwiseAnd => 9,
+        ExpressionOperator::BitwiseShiftLeft | ExpressionOperator::BitwiseShiftRight => 10,
+        ExpressionOperator::Addition | ExpressionOperator::Subtraction => 11,
+        ExpressionOperator::Multiplication
+        | ExpressionOperator::Division
+        | ExpressionOperator::Remainder => 12,
+        ExpressionOperator::Casting => PRECEDENCE_CASTING,
+        ExpressionOperator::Not | ExpressionOperator::BitwiseNot | ExpressionOperator::Negation => {
+            PRECEDENCE_UNARY
+        }
+        ExpressionOperator::Index
+        | ExpressionOperator::Dot
+        | ExpressionOperator::CallIntrinsic
+        | ExpressionOperator::Call => PRECEDENCE_ACCESS,
+        ExpressionOperator::Path | ExpressionOperator::Structure => PRECEDENCE_PATH,
+    }
+}
+
+///
+/// Returns the operator source code symbol.
+///
+fn symbol(operator: ExpressionOperator) -> &'static str {
+    match operator {
+        ExpressionOperator::Assignment => "=",
+        ExpressionOperator::AssignmentBitwiseOr => "|=",
+        ExpressionOperator::AssignmentBitwiseXor => "^=",
+        ExpressionOperator::AssignmentBitwiseAnd => "&=",
+        ExpressionOperator::AssignmentBitwiseShiftLeft => "<<=",
+        ExpressionOperator::AssignmentBitwiseShiftRight => ">>=",
+        ExpressionOperator::AssignmentAddition => "+=",
+        ExpressionOperator::AssignmentSubtraction => "-=",
+        ExpressionOperator::AssignmentMultiplication => "*=",
+        ExpressionOperator::AssignmentDivision => "/=",
+        ExpressionOperator::AssignmentRemainder => "%=",
+        ExpressionOperator::Range => "..",
+        ExpressionOperator::RangeInclusive => "..=",
+        ExpressionOperator::Or => "||",
+        ExpressionOperator::Xor => "^^",
+        ExpressionOperator::And => "&&",
+        ExpressionOperator::Equals => "==",
+        ExpressionOperator::NotEquals => "!=",
+        ExpressionOperator::GreaterEquals => ">=",
+        ExpressionOperator::LesserEquals => "<=",
+        ExpressionOperator::Greater => ">",
+        ExpressionOperator::Lesser => "<",
+        ExpressionOperator::BitwiseOr => "|",
+        ExpressionOperator::BitwiseXor => "^",
+        ExpressionOperator::BitwiseAnd => "&",
+        ExpressionOperator::BitwiseShiftLeft => "<<",
+        ExpressionOperator::BitwiseShiftRight => ">>",
+        ExpressionOperator::Addition => "+",
+        ExpressionOperator::Subtraction => "-",
+        ExpressionOperator::Multiplication => "*",
+        ExpressionOperator::Division => "/",
+        ExpressionOperator::Remainder => "%",
+        ExpressionOperator::Casting => "as",
+        ExpressionOperator::Not => "!",
+        ExpressionOperator::BitwiseNot => "~",
+        ExpressionOperator::Negation => "-",
+        ExpressionOperator::Index => "[]",
+        ExpressionOperator::Dot => ".",
+        ExpressionOperator::CallIntrinsic => "!",
+        ExpressionOperator::Call => "()",
+        ExpressionOperator::Path => "::",
+        ExpressionOperator::Structure => "{}",
+    }
+}
+
+///
+/// Prints the integer literal in the normalized form, which is used if the original spelling
+/// is not available.
+///
+fn integer(literal: LexicalIntegerLiteral) -> String {
+    match literal {
+        LexicalIntegerLiteral::Binary { inner } => format!("0b{}", inner),
+        LexicalIntegerLiteral::Octal { inner } => format!("0o{}", inner),
+        LexicalIntegerLiteral::Hexadecimal { inner } => format!("0x{}", inner),
+        LexicalIntegerLiteral::Decimal {
+            integer,
+            fractional,
+            exponent,
+        } => {
+            let mut output = integer;
+            if let Some(fractional) = fractional {
+                output.push('.');
+                output.push_str(fractional.as_str());
+            }
+            if let Some(exponent) = exponent {
+                output.push('E');
+                output.push_str(exponent.as_str());
+            }
+            output
+        }
+    }
+}
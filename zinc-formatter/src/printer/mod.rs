@@ -0,0 +1,193 @@
+//!
+//! The syntax tree printer.
+//!
+
+pub mod expression;
+pub mod pattern;
+pub mod statement;
+pub mod r#type;
+
+use zinc_lexical::Location;
+use zinc_syntax::Attribute;
+use zinc_syntax::GenericParameter;
+use zinc_syntax::Module;
+
+use crate::document::Document;
+use crate::trivia::Comment;
+use crate::trivia::Trivia;
+
+///
+/// The syntax tree printer, which converts the tree into the formatter document.
+///
+/// The comments are not a part of the syntax tree, so they are printed on the statement level,
+/// that is, between module items, block statements, structure fields, enumeration variants,
+/// and match branches. The comments located inside expressions are moved to the closest
+/// statement boundary.
+///
+pub struct Printer<'a> {
+    /// The source code trivia.
+    trivia: Trivia<'a>,
+}
+
+impl<'a> Printer<'a> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(trivia: Trivia<'a>) -> Self {
+        Self { trivia }
+    }
+
+    ///
+    /// Prints the module, that is, the whole source code file.
+    ///
+    pub fn module(mut self, module: Module) -> Document {
+        let statements = module
+            .statements
+            .into_iter()
+            .filter(|statement| !statement::is_empty_module_local(statement))
+            .collect();
+
+        let lines = self.lines(
+            statements,
+            statement::start_module_local,
+            None,
+            Self::module_local_statement,
+        );
+
+        Document::concat(vec![
+            Document::join(lines, Document::HardLine),
+            Document::HardLine,
+        ])
+    }
+
+    ///
+    /// Prints the `items` one per line with the comments, which are located before each item,
+    /// and before the `end` of the enclosing block.
+    ///
+    /// If the `end` is not specified, all the remaining comments are printed after the items.
+    ///
+    fn lines<T, S, P>(
+        &mut self,
+        items: Vec<T>,
+        start: S,
+        end: Option<Location>,
+        mut print: P,
+    ) -> Vec<Document>
+    where
+        S: Fn(&T) -> Location,
+        P: FnMut(&mut Self, T) -> Document,
+    {
+        let mut lines = Vec::with_capacity(items.len());
+        let mut has_trailing_comment = false;
+
+        for item in items.into_iter() {
+            let location = start(&item);
+
+            let comments = self.trivia.take_before(location);
+            Self::comments(&mut lines, comments, &mut has_trailing_comment);
+
+            if !lines.is_empty() && self.trivia.is_blank_before(location) {
+                lines.push(Document::text(""));
+            }
+            lines.push(print(self, item));
+            has_trailing_comment = false;
+        }
+
+        let comments = match end {
+            Some(end) => self.trivia.take_before(end),
+            None => self.trivia.take_all(),
+        };
+        Self::comments(&mut lines, comments, &mut has_trailing_comment);
+
+        lines
+    }
+
+    ///
+    /// Appends the `comments` to the `lines`.
+    ///
+    /// The trailing comments are appended to the last line, unless it already has one.
+    ///
+    fn comments(lines: &mut Vec<Document>, comments: Vec<Comment>, has_trailing: &mut bool) {
+        for comment in comments.into_iter() {
+            match lines.pop() {
+                Some(line) if comment.is_trailing && !*has_trailing => {
+                    lines.push(Document::concat(vec![
+                        line,
+                        Document::text(" "),
+                        Document::text(comment.text),
+                    ]));
+                    *has_trailing = true;
+                }
+                line => {
+                    if let Some(line) = line {
+                        lines.push(line);
+                        if comment.is_blank_before {
+                            lines.push(Document::text(""));
+                        }
+                    }
+                    lines.push(Document::text(comment.text));
+                    *has_trailing = true;
+                }
+            }
+        }
+    }
+
+    ///
+    /// Prints the outer attributes, each on a separate line.
+    ///
+    fn attributes(&mut self, attributes: Vec<Attribute>) -> Document {
+        let mut documents = Vec::with_capacity(attributes.len() * 2);
+        for attribute in attributes.into_iter() {
+            let arguments = if attribute.arguments.is_empty() {
+                Document::text("")
+            } else {
+                self.expression_list("(", attribute.arguments, ")")
+            };
+
+            documents.push(Document::concat(vec![
+                Document::text(if attribute.is_inner { "#![" } else { "#[" }),
+                Document::text(attribute.identifier.name),
+                arguments,
+                Document::text("]"),
+            ]));
+            documents.push(Document::HardLine);
+        }
+        Document::concat(documents)
+    }
+
+    ///
+    /// Prints the generic parameters.
+    ///
+    /// '<T: Trait + Other, const N: u8>'
+    ///
+    fn generics(&mut self, generics: Vec<GenericParameter>) -> Document {
+        if generics.is_empty() {
+            return Document::text("");
+        }
+
+        let parameters = generics
+            .into_iter()
+            .map(|parameter| match parameter.r#type {
+                Some(r#type) => Document::concat(vec![
+                    Document::text("const "),
+                    Document::text(parameter.identifier.name),
+                    Document::text(": "),
+                    self.r#type(r#type),
+                ]),
+                None if parameter.bounds.is_empty() => Document::text(parameter.identifier.name),
+                None => Document::text(format!(
+                    "{}: {}",
+                    parameter.identifier.name,
+                    parameter
+                        .bounds
+                        .into_iter()
+                        .map(|bound| bound.name)
+                        .collect::<Vec<String>>()
+                        .join(" + ")
+                )),
+            })
+            .collect();
+
+        Document::list("<", parameters, ">")
+    }
+}
@@ -0,0 +1,113 @@
+//!
+//! The syntax tree printer patterns.
+//!
+
+use zinc_syntax::Binding;
+use zinc_syntax::BindingPattern;
+use zinc_syntax::BindingPatternVariant;
+use zinc_syntax::MatchPattern;
+use zinc_syntax::MatchPatternVariant;
+
+use crate::document::Document;
+use crate::printer::Printer;
+
+impl<'a> Printer<'a> {
+    ///
+    /// Prints the binding with the optional type, e.g. a function argument.
+    ///
+    pub(crate) fn binding(&mut self, binding: Binding) -> Document {
+        match binding.r#type {
+            Some(r#type) => Document::concat(vec![
+                self.binding_pattern(binding.pattern),
+                Document::text(": "),
+                self.r#type(r#type),
+            ]),
+            None => self.binding_pattern(binding.pattern),
+        }
+    }
+
+    ///
+    /// Prints the binding pattern.
+    ///
+    pub(crate) fn binding_pattern(&mut self, pattern: BindingPattern) -> Document {
+        match pattern.variant {
+            BindingPatternVariant::Binding {
+                identifier,
+                is_mutable,
+            } => Document::text(if is_mutable {
+                format!("mut {}", identifier.name)
+            } else {
+                identifier.name
+            }),
+            BindingPatternVariant::BindingList { mut bindings } if bindings.len() == 1 => {
+                Document::concat(vec![
+                    Document::text("("),
+                    self.binding_pattern(bindings.remove(0)),
+                    Document::text(",)"),
+                ])
+            }
+            BindingPatternVariant::BindingList { bindings } => {
+                let bindings = bindings
+                    .into_iter()
+                    .map(|binding| self.binding_pattern(binding))
+                    .collect();
+                Document::list("(", bindings, ")")
+            }
+            BindingPatternVariant::Wildcard => Document::text("_"),
+        }
+    }
+
+    ///
+    /// Prints the match branch pattern.
+    ///
+    pub(crate) fn match_pattern(&mut self, pattern: MatchPattern) -> Document {
+        match pattern.variant {
+            MatchPatternVariant::BooleanLiteral(literal) => {
+                Document::text(literal.inner.to_string())
+            }
+            MatchPatternVariant::IntegerLiteral(literal) => self.integer(literal),
+            MatchPatternVariant::Binding(identifier) => Document::text(identifier.name),
+            MatchPatternVariant::Path(path) => self.expression(path),
+            MatchPatternVariant::TupleStruct { path, patterns } => {
+                let patterns = patterns
+                    .into_iter()
+                    .map(|pattern| self.match_pattern(pattern))
+                    .collect();
+                Document::concat(vec![
+                    self.expression(path),
+                    Document::list("(", patterns, ")"),
+                ])
+            }
+            MatchPatternVariant::Structure {
+                path,
+                fields,
+                has_rest,
+            } => {
+                let mut fields: Vec<Document> = fields
+                    .into_iter()
+                    .map(|(identifier, pattern)| match pattern.variant {
+                        MatchPatternVariant::Binding(ref binding)
+                            if binding.name == identifier.name =>
+                        {
+                            Document::text(identifier.name)
+                        }
+                        _ => Document::concat(vec![
+                            Document::text(format!("{}: ", identifier.name)),
+                            self.match_pattern(pattern),
+                        ]),
+                    })
+                    .collect();
+                if has_rest {
+                    fields.push(Document::text(".."));
+                }
+
+                Document::concat(vec![
+                    self.expression(path),
+                    Document::text(" "),
+                    Document::braces(fields),
+                ])
+            }
+            MatchPatternVariant::Wildcard => Document::text("_"),
+        }
+    }
+}
@@ -0,0 +1,447 @@
+//!
+//! The syntax tree printer statements.
+//!
+
+use zinc_lexical::Location;
+use zinc_syntax::Attribute;
+use zinc_syntax::ConstStatement;
+use zinc_syntax::ContractLocalStatement;
+use zinc_syntax::ContractStatement;
+use zinc_syntax::EnumStatement;
+use zinc_syntax::FieldStatement;
+use zinc_syntax::FnStatement;
+use zinc_syntax::FunctionLocalStatement;
+use zinc_syntax::ImplStatement;
+use zinc_syntax::ImplementationLocalStatement;
+use zinc_syntax::ModuleLocalStatement;
+use zinc_syntax::StructStatement;
+use zinc_syntax::TraitLocalStatement;
+use zinc_syntax::TraitStatement;
+use zinc_syntax::VariantData;
+
+use crate::document::Document;
+use crate::printer::Printer;
+use crate::trivia::Trivia;
+
+impl<'a> Printer<'a> {
+    ///
+    /// Prints a module-level statement.
+    ///
+    pub(crate) fn module_local_statement(&mut self, statement: ModuleLocalStatement) -> Document {
+        match statement {
+            ModuleLocalStatement::Const(inner) => self.const_statement(inner),
+            ModuleLocalStatement::Type(inner) => Document::concat(vec![
+                Document::text(format!("type {} = ", inner.identifier.name)),
+                self.r#type(inner.r#type),
+                Document::text(";"),
+            ]),
+            ModuleLocalStatement::Struct(inner) => self.struct_statement(inner),
+            ModuleLocalStatement::Enum(inner) => self.enum_statement(inner),
+            ModuleLocalStatement::Fn(inner) => self.fn_statement(inner),
+            ModuleLocalStatement::Mod(inner) => {
+                Document::text(format!("mod {};", inner.identifier.name))
+            }
+            ModuleLocalStatement::Use(inner) => Document::concat(vec![
+                Document::text("use "),
+                self.expression(inner.path),
+                match inner.alias_identifier {
+                    Some(alias) => Document::text(format!(" as {}", alias.name)),
+                    None => Document::text(""),
+                },
+                Document::text(";"),
+            ]),
+            ModuleLocalStatement::Impl(inner) => self.impl_statement(inner),
+            ModuleLocalStatement::Trait(inner) => self.trait_statement(inner),
+            ModuleLocalStatement::Contract(inner) => self.contract_statement(inner),
+            ModuleLocalStatement::Empty(_location) => Document::text(""),
+        }
+    }
+
+    ///
+    /// Prints a function-level statement.
+    ///
+    pub(crate) fn function_local_statement(
+        &mut self,
+        statement: FunctionLocalStatement,
+    ) -> Document {
+        match statement {
+            FunctionLocalStatement::Let(inner) => Document::concat(vec![
+                Document::text("let "),
+                self.binding(inner.binding),
+                Document::text(" = "),
+                self.expression(inner.expression),
+                Document::text(";"),
+            ]),
+            FunctionLocalStatement::Const(inner) => self.const_statement(inner),
+            FunctionLocalStatement::For(inner) => {
+                let mut documents = vec![
+                    Document::text(format!("for {} in ", inner.index_identifier.name)),
+                    self.expression(inner.bounds_expression),
+                ];
+                if let Some(condition) = inner.while_condition {
+                    documents.push(Document::text(" while "));
+                    documents.push(self.expression(condition));
+                }
+                documents.push(Document::text(" "));
+                documents.push(self.block(inner.block));
+                Document::concat(documents)
+            }
+            FunctionLocalStatement::Loop(inner) => {
+                let mut documents = vec![self.attributes(inner.attributes)];
+                match inner.condition {
+                    Some(condition) => {
+                        documents.push(Document::text("while "));
+                        documents.push(self.expression(condition));
+                        documents.push(Document::text(" "));
+                    }
+                    None => documents.push(Document::text("loop ")),
+                }
+                documents.push(self.block(inner.block));
+                Document::concat(documents)
+            }
+            FunctionLocalStatement::Break(_location) => Document::text("break;"),
+            FunctionLocalStatement::Continue(_location) => Document::text("continue;"),
+            FunctionLocalStatement::Return(inner) => match inner.expression {
+                Some(expression) => Document::concat(vec![
+                    Document::text("return "),
+                    self.expression(expression),
+                    Document::text(";"),
+                ]),
+                None => Document::text("return;"),
+            },
+            FunctionLocalStatement::Empty(_location) => Document::text(""),
+            FunctionLocalStatement::Expression(expression) => {
+                self.statement_expression(expression, ";")
+            }
+        }
+    }
+
+    ///
+    /// Prints a function, including the trait method signatures without the body.
+    ///
+    pub(crate) fn fn_statement(&mut self, statement: FnStatement) -> Document {
+        let mut documents = vec![self.attributes(statement.attributes)];
+        if statement.is_public {
+            documents.push(Document::text("pub "));
+        }
+        if statement.is_constant {
+            documents.push(Document::text("const "));
+        }
+        documents.push(Document::text(format!("fn {}", statement.identifier.name)));
+        documents.push(self.generics(statement.generics));
+
+        let arguments = statement
+            .argument_bindings
+            .into_iter()
+            .map(|binding| self.binding(binding))
+            .collect();
+        documents.push(Document::list("(", arguments, ")"));
+
+        if let Some(r#type) = statement.return_type {
+            documents.push(Document::text(" -> "));
+            documents.push(self.r#type(r#type));
+        }
+
+        documents.push(Document::text(" "));
+        documents.push(self.block(statement.body));
+        Document::concat(documents)
+    }
+
+    ///
+    /// Prints a constant declaration.
+    ///
+    fn const_statement(&mut self, statement: ConstStatement) -> Document {
+        Document::concat(vec![
+            Document::text(format!("const {}: ", statement.identifier.name)),
+            self.r#type(statement.r#type),
+            Document::text(" = "),
+            self.expression(statement.expression),
+            Document::text(";"),
+        ])
+    }
+
+    ///
+    /// Prints a structure declaration with a field per line.
+    ///
+    fn struct_statement(&mut self, statement: StructStatement) -> Document {
+        let end = self.trivia.closing(statement.location);
+        let fields = self.lines(
+            statement.fields,
+            |field| field.location,
+            end,
+            |printer, field| {
+                Document::concat(vec![
+                    Document::text(format!("{}: ", field.identifier.name)),
+                    printer.r#type(field.r#type),
+                    Document::text(","),
+                ])
+            },
+        );
+
+        Document::concat(vec![
+            Document::text(format!("struct {}", statement.identifier.name)),
+            self.generics(statement.generics),
+            Document::text(" "),
+            Document::block(fields),
+        ])
+    }
+
+    ///
+    /// Prints an enumeration declaration with a variant per line.
+    ///
+    fn enum_statement(&mut self, statement: EnumStatement) -> Document {
+        let end = self.trivia.closing(statement.location);
+        let variants = self.lines(
+            statement.variants,
+            |variant| variant.location,
+            end,
+            |printer, variant| {
+                let mut documents = vec![Document::text(variant.identifier.name)];
+                match variant.data {
+                    Some(VariantData::Tuple(types)) => {
+                        let types = types
+                            .into_iter()
+                            .map(|r#type| printer.r#type(r#type))
+                            .collect();
+                        documents.push(Document::list("(", types, ")"));
+                    }
+                    Some(VariantData::Structure(fields)) => {
+                        let fields = fields
+                            .into_iter()
+                            .map(|field| {
+                                Document::concat(vec![
+                                    Document::text(format!("{}: ", field.identifier.name)),
+                                    printer.r#type(field.r#type),
+                                ])
+                            })
+                            .collect();
+                        documents.push(Document::text(" "));
+                        documents.push(Document::braces(fields));
+                    }
+                    None => {}
+                }
+                if let Some(literal) = variant.literal {
+                    documents.push(Document::text(" = "));
+                    documents.push(printer.integer(literal));
+                }
+                documents.push(Document::text(","));
+                Document::concat(documents)
+            },
+        );
+
+        Document::concat(vec![
+            Document::text(format!("enum {} ", statement.identifier.name)),
+            Document::block(variants),
+        ])
+    }
+
+    ///
+    /// Prints a type implementation.
+    ///
+    fn impl_statement(&mut self, statement: ImplStatement) -> Document {
+        let header = match statement.trait_identifier {
+            Some(r#trait) => format!("impl {} for {} ", r#trait.name, statement.identifier.name),
+            None => format!("impl {} ", statement.identifier.name),
+        };
+
+        let end = self.trivia.closing(statement.location);
+        let statements = statement
+            .statements
+            .into_iter()
+            .filter(|statement| !matches!(statement, ImplementationLocalStatement::Empty(_)))
+            .collect();
+        let lines = self.lines(
+            statements,
+            |statement| match statement {
+                ImplementationLocalStatement::Const(inner) => inner.location,
+                ImplementationLocalStatement::Fn(inner) => start_fn(inner),
+                ImplementationLocalStatement::Empty(location) => *location,
+            },
+            end,
+            |printer, statement| match statement {
+                ImplementationLocalStatement::Const(inner) => printer.const_statement(inner),
+                ImplementationLocalStatement::Fn(inner) => printer.fn_statement(inner),
+                ImplementationLocalStatement::Empty(_location) => Document::text(""),
+            },
+        );
+
+        Document::concat(vec![Document::text(header), Document::block(lines)])
+    }
+
+    ///
+    /// Prints a trait declaration.
+    ///
+    fn trait_statement(&mut self, statement: TraitStatement) -> Document {
+        let end = self.trivia.closing(statement.location);
+        let statements = statement
+            .statements
+            .into_iter()
+            .filter(|statement| !matches!(statement, TraitLocalStatement::Empty(_)))
+            .collect();
+        let lines = self.lines(
+            statements,
+            |statement| match statement {
+                TraitLocalStatement::Signature(inner) | TraitLocalStatement::Fn(inner) => {
+                    start_fn(inner)
+                }
+                TraitLocalStatement::Empty(location) => *location,
+            },
+            end,
+            |printer, statement| match statement {
+                TraitLocalStatement::Signature(inner) => printer.fn_signature(inner),
+                TraitLocalStatement::Fn(inner) => printer.fn_statement(inner),
+                TraitLocalStatement::Empty(_location) => Document::text(""),
+            },
+        );
+
+        Document::concat(vec![
+            Document::text(format!("trait {} ", statement.identifier.name)),
+            Document::block(lines),
+        ])
+    }
+
+    ///
+    /// Prints a contract declaration.
+    ///
+    fn contract_statement(&mut self, statement: ContractStatement) -> Document {
+        let end = self.trivia.closing(statement.location);
+        let statements = statement
+            .statements
+            .into_iter()
+            .filter(|statement| !matches!(statement, ContractLocalStatement::Empty(_)))
+            .collect();
+        let lines = self.lines(
+            statements,
+            |statement| match statement {
+                ContractLocalStatement::Field(inner) => {
+                    start_attributed(inner.location, inner.attributes.as_slice())
+                }
+                ContractLocalStatement::Const(inner) => inner.location,
+                ContractLocalStatement::Fn(inner) => start_fn(inner),
+                ContractLocalStatement::Empty(location) => *location,
+            },
+            end,
+            |printer, statement| match statement {
+                ContractLocalStatement::Field(inner) => printer.field_statement(inner),
+                ContractLocalStatement::Const(inner) => printer.const_statement(inner),
+                ContractLocalStatement::Fn(inner) => printer.fn_statement(inner),
+                ContractLocalStatement::Empty(_location) => Document::text(""),
+            },
+        );
+
+        Document::concat(vec![
+            Document::text(format!("contract {} ", statement.identifier.name)),
+            Document::block(lines),
+        ])
+    }
+
+    ///
+    /// Prints a contract storage field.
+    ///
+    fn field_statement(&mut self, statement: FieldStatement) -> Document {
+        Document::concat(vec![
+            self.attributes(statement.attributes),
+            Document::text(if statement.is_public { "pub " } else { "" }),
+            Document::text(format!("{}: ", statement.identifier.name)),
+            self.r#type(statement.r#type),
+            Document::text(";"),
+        ])
+    }
+
+    ///
+    /// Prints a trait method signature, which is a function without the body.
+    ///
+    fn fn_signature(&mut self, statement: FnStatement) -> Document {
+        let mut documents = vec![self.attributes(statement.attributes)];
+        if statement.is_public {
+            documents.push(Document::text("pub "));
+        }
+        if statement.is_constant {
+            documents.push(Document::text("const "));
+        }
+        documents.push(Document::text(format!("fn {}", statement.identifier.name)));
+        documents.push(self.generics(statement.generics));
+
+        let arguments = statement
+            .argument_bindings
+            .into_iter()
+            .map(|binding| self.binding(binding))
+            .collect();
+        documents.push(Document::list("(", arguments, ")"));
+
+        if let Some(r#type) = statement.return_type {
+            documents.push(Document::text(" -> "));
+            documents.push(self.r#type(r#type));
+        }
+
+        documents.push(Document::text(";"));
+        Document::concat(documents)
+    }
+}
+
+///
+/// Checks whether the module-level statement is a redundant semicolon.
+///
+pub fn is_empty_module_local(statement: &ModuleLocalStatement) -> bool {
+    matches!(statement, ModuleLocalStatement::Empty(_))
+}
+
+///
+/// Returns the location of the first module-level statement token.
+///
+pub fn start_module_local(statement: &ModuleLocalStatement) -> Location {
+    match statement {
+        ModuleLocalStatement::Const(inner) => inner.location,
+        ModuleLocalStatement::Type(inner) => inner.location,
+        ModuleLocalStatement::Struct(inner) => inner.location,
+        ModuleLocalStatement::Enum(inner) => inner.location,
+        ModuleLocalStatement::Fn(inner) => start_fn(inner),
+        ModuleLocalStatement::Mod(inner) => inner.location,
+        ModuleLocalStatement::Use(inner) => inner.location,
+        ModuleLocalStatement::Impl(inner) => inner.location,
+        ModuleLocalStatement::Trait(inner) => inner.location,
+        ModuleLocalStatement::Contract(inner) => inner.location,
+        ModuleLocalStatement::Empty(location) => *location,
+    }
+}
+
+///
+/// Returns the location of the first function-level statement token.
+///
+pub fn start_function_local(statement: &FunctionLocalStatement) -> Location {
+    match statement {
+        FunctionLocalStatement::Let(inner) => inner.location,
+        FunctionLocalStatement::Const(inner) => inner.location,
+        FunctionLocalStatement::For(inner) => inner.location,
+        FunctionLocalStatement::Loop(inner) => inner
+            .attributes
+            .first()
+            .map(|attribute| attribute.location)
+            .unwrap_or(inner.location),
+        FunctionLocalStatement::Break(location) => *location,
+        FunctionLocalStatement::Continue(location) => *location,
+        FunctionLocalStatement::Return(inner) => inner.location,
+        FunctionLocalStatement::Empty(location) => *location,
+        FunctionLocalStatement::Expression(inner) => super::expression::start(inner),
+    }
+}
+
+///
+/// Returns the location of the first function token, which is either an attribute or a keyword.
+///
+fn start_fn(statement: &FnStatement) -> Location {
+    start_attributed(statement.location, statement.attributes.as_slice())
+}
+
+///
+/// Returns the location of the first token of a statement with outer `attributes`, which may
+/// precede the statement `location`.
+///
+fn start_attributed(location: Location, attributes: &[Attribute]) -> Location {
+    match attributes.first() {
+        Some(attribute) if Trivia::position(attribute.location) < Trivia::position(location) => {
+            attribute.location
+        }
+        _ => location,
+    }
+}
@@ -0,0 +1,66 @@
+//!
+//! The syntax tree printer types.
+//!
+
+use zinc_syntax::Type;
+use zinc_syntax::TypeVariant;
+
+use crate::document::Document;
+use crate::printer::Printer;
+
+impl<'a> Printer<'a> {
+    ///
+    /// Prints the type.
+    ///
+    pub(crate) fn r#type(&mut self, r#type: Type) -> Document {
+        match r#type.variant {
+            TypeVariant::Unit => Document::text("()"),
+            TypeVariant::Boolean => Document::text("bool"),
+            TypeVariant::IntegerUnsigned { bitlength } => Document::text(format!("u{}", bitlength)),
+            TypeVariant::IntegerSigned { bitlength } => Document::text(format!("i{}", bitlength)),
+            TypeVariant::Field => Document::text("field"),
+            TypeVariant::Array { inner, size } => Document::concat(vec![
+                Document::text("["),
+                self.r#type(*inner),
+                Document::text("; "),
+                self.expression(size),
+                Document::text("]"),
+            ]),
+            TypeVariant::Tuple { mut inners } if inners.len() == 1 => Document::concat(vec![
+                Document::text("("),
+                self.r#type(inners.remove(0)),
+                Document::text(",)"),
+            ]),
+            TypeVariant::Tuple { inners } => self.types("(", inners, ")"),
+            TypeVariant::Alias { path, generics } => {
+                let path = self.expression(path);
+                match generics {
+                    Some(generics) => Document::concat(vec![path, self.types("<", generics, ">")]),
+                    None => path,
+                }
+            }
+            TypeVariant::Function {
+                arguments,
+                return_type,
+            } => {
+                let mut documents = vec![Document::text("fn"), self.types("(", arguments, ")")];
+                if return_type.variant != TypeVariant::Unit {
+                    documents.push(Document::text(" -> "));
+                    documents.push(self.r#type(*return_type));
+                }
+                Document::concat(documents)
+            }
+        }
+    }
+
+    ///
+    /// Prints the comma-separated types enclosed into the `open` and `close` brackets.
+    ///
+    fn types(&mut self, open: &'static str, types: Vec<Type>, close: &'static str) -> Document {
+        let types = types
+            .into_iter()
+            .map(|r#type| self.r#type(r#type))
+            .collect();
+        Document::list(open, types, close)
+    }
+}
@@ -0,0 +1,350 @@
+//!
+//! The formatter tests.
+//!
+
+use std::path::PathBuf;
+
+use crate::error::Error;
+use crate::formatter::Formatter;
+
+fn format(code: &str, width: usize) -> Result<String, Error> {
+    let path = PathBuf::from("/zinc-formatter-test/main.zn");
+    Formatter::new(width).format(&path, code)
+}
+
+fn check(input: &str, expected: &str) {
+    let result = format(input, zinc_const::limit::FORMATTER_WIDTH)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(result, expected.trim_start());
+
+    let again = format(result.as_str(), zinc_const::limit::FORMATTER_WIDTH)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(again, result, "The formatting is not idempotent");
+}
+
+#[test]
+fn ok_whitespace() {
+    check(
+        r#"
+fn   main(a:u8,b :u8)->u8{let   mut c=a+b*2;c}
+"#,
+        r#"
+fn main(a: u8, b: u8) -> u8 {
+    let mut c = a + b * 2;
+    c
+}
+"#,
+    );
+}
+
+#[test]
+fn ok_comments() {
+    check(
+        r#"
+//! The module comment.
+
+/// The constant.
+const A: u8 = 42; // trailing
+
+
+/* The block
+   comment */
+fn main() {
+    // leading
+    let a = A;   // after the statement
+
+    // at the end
+}
+// at the end of file
+"#,
+        r#"
+//! The module comment.
+
+/// The constant.
+const A: u8 = 42; // trailing
+
+/* The block
+   comment */
+fn main() {
+    // leading
+    let a = A; // after the statement
+
+    // at the end
+}
+// at the end of file
+"#,
+    );
+}
+
+#[test]
+fn ok_comments_items() {
+    check(
+        r#"
+struct Data {
+    a: u8, // first
+    // second
+    b: bool,
+}
+
+enum Kind {
+    // first
+    A = 1,
+    B = 2, // second
+}
+
+fn main(kind: Kind) -> u8 {
+    match kind {
+        // first
+        Kind::A => 1,
+        // second
+        Kind::B => 2,
+    }
+}
+"#,
+        r#"
+struct Data {
+    a: u8, // first
+    // second
+    b: bool,
+}
+
+enum Kind {
+    // first
+    A = 1,
+    B = 2, // second
+}
+
+fn main(kind: Kind) -> u8 {
+    match kind {
+        // first
+        Kind::A => 1,
+        // second
+        Kind::B => 2,
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn ok_width() {
+    let input = r#"
+fn main() -> u8 {
+    compute(first_argument, second_argument, third_argument)
+}
+"#;
+
+    let result = format(input, 40).expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(
+        result,
+        r#"
+fn main() -> u8 {
+    compute(
+        first_argument,
+        second_argument,
+        third_argument,
+    )
+}
+"#
+        .trim_start()
+    );
+
+    let result = format(input, 80).expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(
+        result,
+        r#"
+fn main() -> u8 {
+    compute(first_argument, second_argument, third_argument)
+}
+"#
+        .trim_start()
+    );
+}
+
+#[test]
+fn ok_width_binary() {
+    let result = format(
+        r#"
+fn main() -> bool {
+    first_operand > 0 && second_operand > 0 && third_operand > 0
+}
+"#,
+        40,
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        result,
+        r#"
+fn main() -> bool {
+    first_operand > 0
+        && second_operand > 0
+        && third_operand > 0
+}
+"#
+        .trim_start()
+    );
+}
+
+#[test]
+fn ok_parentheses() {
+    check(
+        r#"
+fn main(a: u8, b: u8, c: u8) -> u8 {
+    let x = (a + b) * c;
+    let y = a - (b - c);
+    let z = (a + (b)) + c;
+    let w = -(a as i8);
+    let v = (((1, 2), 3).0).1;
+    let r = (0 as u8)..(a + 1);
+    x + y + z
+}
+"#,
+        r#"
+fn main(a: u8, b: u8, c: u8) -> u8 {
+    let x = (a + b) * c;
+    let y = a - (b - c);
+    let z = a + b + c;
+    let w = -(a as i8);
+    let v = (((1, 2), 3).0).1;
+    let r = (0 as u8)..a + 1;
+    x + y + z
+}
+"#,
+    );
+}
+
+#[test]
+fn ok_literals() {
+    check(
+        r#"
+fn main() {
+    let a = 1_000_000;
+    let b = 0xDEAD_BEEF;
+    let c = "quoted \"text\"";
+    let d = 1.5E3;
+}
+"#,
+        r#"
+fn main() {
+    let a = 1_000_000;
+    let b = 0xDEAD_BEEF;
+    let c = "quoted \"text\"";
+    let d = 1.5E3;
+}
+"#,
+    );
+}
+
+#[test]
+fn ok_conditional() {
+    check(
+        r#"
+fn main(a: u8) -> u8 {
+    if a == 1 { dbg!("one"); } else if a == 2 { dbg!("two"); } else { dbg!("many"); };
+
+    let b = if a > 10 { 10 } else { a };
+    b
+}
+"#,
+        r#"
+fn main(a: u8) -> u8 {
+    if a == 1 {
+        dbg!("one");
+    } else if a == 2 {
+        dbg!("two");
+    } else {
+        dbg!("many");
+    }
+
+    let b = if a > 10 { 10 } else { a };
+    b
+}
+"#,
+    );
+}
+
+#[test]
+fn ok_terminated_block_like() {
+    check(
+        r#"
+fn main(a: u8) {
+    if a == 1 { dbg!("one"); };
+}
+"#,
+        r#"
+fn main(a: u8) {
+    if a == 1 {
+        dbg!("one");
+    };
+}
+"#,
+    );
+}
+
+#[test]
+fn ok_items() {
+    check(
+        r#"
+use std::crypto::sha256 as hash;
+mod inner;
+type Pair = (u8, bool);
+struct Data<T> { a: [T; 4], b: (u8,) }
+enum Message { Quit, Move(u8, u8), Write { length: u8 } }
+trait Shape { fn area(self) -> u8; }
+impl Shape for Square { fn area(self) -> u8 { self.side * self.side } }
+contract Wallet {
+    pub balance: u248;
+    #[allow(unwritten_storage_fields)] owner: u160;
+
+    #[test]
+    pub fn deposit(mut self, amount: u248) { self.balance += amount; }
+}
+"#,
+        r#"
+use std::crypto::sha256 as hash;
+mod inner;
+type Pair = (u8, bool);
+struct Data<T> {
+    a: [T; 4],
+    b: (u8,),
+}
+enum Message {
+    Quit,
+    Move(u8, u8),
+    Write { length: u8 },
+}
+trait Shape {
+    fn area(self) -> u8;
+}
+impl Shape for Square {
+    fn area(self) -> u8 {
+        self.side * self.side
+    }
+}
+contract Wallet {
+    pub balance: u248;
+    #[allow(unwritten_storage_fields)]
+    owner: u160;
+
+    #[test]
+    pub fn deposit(mut self, amount: u248) {
+        self.balance += amount;
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn ok_crlf() {
+    let result = format("fn main() {\r\n    // comment\r\n}\r\n", 100)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(result, "fn main() {\r\n    // comment\r\n}\r\n");
+}
+
+#[test]
+fn error_parsing() {
+    let result = format("fn main( {", 100);
+    assert!(matches!(result, Err(Error::Parsing(_))));
+}
@@ -0,0 +1,264 @@
+//!
+//! The formatter source code trivia.
+//!
+
+use std::collections::VecDeque;
+
+use zinc_lexical::Comment as LexicalComment;
+use zinc_lexical::Error as LexicalError;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Location;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+///
+/// The source code comment, which is waiting to be printed.
+///
+#[derive(Debug, Clone)]
+pub struct Comment {
+    /// The comment text including the delimiters.
+    pub text: String,
+    /// Whether the comment follows some code on the same line.
+    pub is_trailing: bool,
+    /// Whether the comment is separated from the previous code with an empty line.
+    pub is_blank_before: bool,
+}
+
+///
+/// The source code information, which is not preserved in the syntax tree, that is, the comments,
+/// empty lines, closing brackets, and the original literal spelling.
+///
+pub struct Trivia<'a> {
+    /// The source code.
+    code: &'a str,
+    /// The byte offsets of the source code lines.
+    lines: Vec<usize>,
+    /// All the source code tokens including the comments and the end of file.
+    tokens: Vec<Token>,
+    /// The comments, which have not been printed yet, with their locations.
+    comments: VecDeque<(Location, Comment)>,
+}
+
+impl<'a> Trivia<'a> {
+    ///
+    /// Tokenizes the `code` keeping the comments.
+    ///
+    pub fn new(code: &'a str, file: usize) -> Result<Self, LexicalError> {
+        let mut stream = TokenStream::new(code, file).with_comments();
+        let mut tokens = Vec::new();
+        loop {
+            let token = stream.next()?;
+            let is_eof = token.lexeme == Lexeme::Eof;
+            tokens.push(token);
+            if is_eof {
+                break;
+            }
+        }
+
+        let mut lines = vec![0];
+        lines.extend(code.match_indices('\n').map(|(offset, _)| offset + 1));
+
+        let mut comments = VecDeque::new();
+        for (index, token) in tokens.iter().enumerate() {
+            if let Lexeme::Comment(ref comment) = token.lexeme {
+                let previous_line = match index {
+                    0 => None,
+                    index => Some(Self::end_line(&tokens[index - 1])),
+                };
+
+                comments.push_back((
+                    token.location,
+                    Comment {
+                        text: match comment {
+                            LexicalComment::Line { inner } => format!("//{}", inner.trim_end()),
+                            LexicalComment::Block { inner } => format!("/*{}*/", inner),
+                        },
+                        is_trailing: previous_line == Some(token.location.line),
+                        is_blank_before: previous_line
+                            .map(|line| token.location.line > line + 1)
+                            .unwrap_or_default(),
+                    },
+                ));
+            }
+        }
+
+        Ok(Self {
+            code,
+            lines,
+            tokens,
+            comments,
+        })
+    }
+
+    ///
+    /// Takes the unprinted comments located before `location`.
+    ///
+    pub fn take_before(&mut self, location: Location) -> Vec<Comment> {
+        let mut comments = Vec::new();
+        while let Some((comment_location, _)) = self.comments.front() {
+            if Self::position(*comment_location) >= Self::position(location) {
+                break;
+            }
+
+            if let Some((_, comment)) = self.comments.pop_front() {
+                comments.push(comment);
+            }
+        }
+        comments
+    }
+
+    ///
+    /// Takes all the unprinted comments.
+    ///
+    pub fn take_all(&mut self) -> Vec<Comment> {
+        self.comments
+            .drain(..)
+            .map(|(_, comment)| comment)
+            .collect()
+    }
+
+    ///
+    /// Returns the number of the unprinted comments.
+    ///
+    pub fn pending(&self) -> usize {
+        self.comments.len()
+    }
+
+    ///
+    /// Checks whether there are unprinted comments between `start` and `end`.
+    ///
+    pub fn has_comments(&self, start: Location, end: Location) -> bool {
+        let (start, end) = (Self::position(start), Self::position(end));
+        self.comments.iter().any(|(location, _)| {
+            let position = Self::position(*location);
+            position > start && position < end
+        })
+    }
+
+    ///
+    /// Checks whether the token at `location` is separated from the previous token with
+    /// an empty line.
+    ///
+    pub fn is_blank_before(&self, location: Location) -> bool {
+        match self.index(location) {
+            Some(index) if index > 0 => location.line > Self::end_line(&self.tokens[index - 1]) + 1,
+            _ => false,
+        }
+    }
+
+    ///
+    /// Finds the first opening curly bracket at or after `location` and returns the location of
+    /// the matching closing one.
+    ///
+    pub fn closing(&self, location: Location) -> Option<Location> {
+        let position = Self::position(location);
+        let opening = self.tokens.iter().position(|token| {
+            Self::position(token.location) >= position
+                && token.lexeme == Lexeme::Symbol(Symbol::BracketCurlyLeft)
+        })?;
+
+        self.unmatched(opening + 1)
+    }
+
+    ///
+    /// Returns the location of the first closing bracket after `location`, which does not have
+    /// a matching opening bracket after `location`, that is, the end of the enclosing block.
+    ///
+    pub fn enclosing(&self, location: Location) -> Option<Location> {
+        let position = Self::position(location);
+        let start = self
+            .tokens
+            .iter()
+            .position(|token| Self::position(token.location) >= position)?;
+
+        self.unmatched(start)
+    }
+
+    ///
+    /// Returns the original spelling of the literal at `location`, e.g. with the digit separators
+    /// or escape sequences, which are not preserved in the syntax tree.
+    ///
+    pub fn literal(&self, location: Location) -> Option<&'a str> {
+        let index = self.index(location)?;
+        if let Lexeme::Literal(_) = self.tokens[index].lexeme {
+            let start = self.offset(location)?;
+            let end = self.offset(self.tokens.get(index + 1)?.location)?;
+            self.code.get(start..end).map(str::trim_end)
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns the location of the first unmatched closing bracket, starting from the token
+    /// with the `start` index.
+    ///
+    fn unmatched(&self, start: usize) -> Option<Location> {
+        let mut depth = 0;
+        for token in self.tokens.iter().skip(start) {
+            match token.lexeme {
+                Lexeme::Symbol(Symbol::BracketCurlyLeft)
+                | Lexeme::Symbol(Symbol::BracketSquareLeft)
+                | Lexeme::Symbol(Symbol::ParenthesisLeft) => depth += 1,
+                Lexeme::Symbol(Symbol::BracketCurlyRight)
+                | Lexeme::Symbol(Symbol::BracketSquareRight)
+                | Lexeme::Symbol(Symbol::ParenthesisRight) => {
+                    if depth == 0 {
+                        return Some(token.location);
+                    }
+                    depth -= 1;
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    ///
+    /// Returns the index of the token at `location`.
+    ///
+    fn index(&self, location: Location) -> Option<usize> {
+        self.tokens
+            .binary_search_by_key(&Self::position(location), |token| {
+                Self::position(token.location)
+            })
+            .ok()
+    }
+
+    ///
+    /// Converts the `location` to the byte offset in the source code.
+    ///
+    fn offset(&self, location: Location) -> Option<usize> {
+        let line_start = *self.lines.get(location.line.checked_sub(1)?)?;
+        let line = &self.code[line_start..];
+
+        let column_offset = line
+            .char_indices()
+            .nth(location.column.checked_sub(1)?)
+            .map(|(offset, _)| offset)
+            .unwrap_or_else(|| line.len());
+
+        Some(line_start + column_offset)
+    }
+
+    ///
+    /// Returns the line where the `token` ends, which is different from the starting one for
+    /// the multi-line comments and string literals.
+    ///
+    fn end_line(token: &Token) -> usize {
+        let lines = match token.lexeme {
+            Lexeme::Comment(LexicalComment::Block { ref inner }) => inner.matches('\n').count(),
+            _ => 0,
+        };
+
+        token.location.line + lines
+    }
+
+    ///
+    /// Converts the `location` into a comparable position ignoring the file.
+    ///
+    pub fn position(location: Location) -> (usize, usize) {
+        (location.line, location.column)
+    }
+}
@@ -11,6 +11,7 @@ pub(crate) mod token;
 
 pub use self::error::Error;
 pub use self::stream::TokenStream;
+pub use self::token::lexeme::comment::Comment;
 pub use self::token::lexeme::identifier::Identifier;
 pub use self::token::lexeme::keyword::Keyword;
 pub use self::token::lexeme::literal::boolean::Boolean as BooleanLiteral;
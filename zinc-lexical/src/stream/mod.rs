@@ -39,6 +39,8 @@ pub struct TokenStream<'a> {
     /// The queue buffer where the characters acquired with the look-ahead method are stored.
     /// If the queue is not empty, the next character will be taken therefrom.
     look_ahead: VecDeque<Token>,
+    /// Whether the comments are returned as tokens instead of being skipped.
+    is_comment_kept: bool,
 }
 
 impl<'a> TokenStream<'a> {
@@ -55,9 +57,19 @@ impl<'a> TokenStream<'a> {
             offset: 0,
             location: Location::new(file),
             look_ahead: VecDeque::with_capacity(Self::LOOK_AHEAD_INITIAL_CAPACITY),
+            is_comment_kept: false,
         }
     }
 
+    ///
+    /// Makes the stream return the comments as tokens instead of skipping them.
+    /// Is used by tools which must preserve the comments, e.g. the source code formatter.
+    ///
+    pub fn with_comments(mut self) -> Self {
+        self.is_comment_kept = true;
+        self
+    }
+
     ///
     /// Wraps the stream into `Rc<RefCell<_>>` simplifying most of initializations.
     ///
@@ -109,13 +121,14 @@ impl<'a> TokenStream<'a> {
             offset: 0,
             location: Location::new(0),
             look_ahead: VecDeque::with_capacity(Self::LOOK_AHEAD_INITIAL_CAPACITY),
+            is_comment_kept: false,
         }
     }
 
     ///
     /// The function checks if a character:
     /// 1. Is a whitespace -> skip
-    /// 2. Starts a comment -> start the comment subparser
+    /// 2. Starts a comment -> start the comment subparser, skip or return the comment
     /// 3. Starts a string literal -> start the string subparser
     /// 4. Starts a number -> start the number subparser
     /// 5. Starts a word -> start the word subparser
@@ -140,12 +153,19 @@ impl<'a> TokenStream<'a> {
             if character == '/' {
                 match self::comment::parse(&self.input[self.offset..]) {
                     Ok(output) => {
+                        let location = self.location;
                         self.location.line += output.lines;
                         self.location.column = match output.comment {
                             Comment::Line { .. } => 1,
+                            Comment::Block { .. } if output.lines == 0 => {
+                                self.location.column + output.column - 1
+                            }
                             Comment::Block { .. } => output.column,
                         };
                         self.offset += output.size;
+                        if self.is_comment_kept {
+                            return Ok(Token::new(Lexeme::Comment(output.comment), location));
+                        }
                         continue;
                     }
                     Err(CommentParserError::NotAComment) => {}
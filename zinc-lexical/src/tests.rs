@@ -4,6 +4,7 @@
 
 use crate::error::Error;
 use crate::stream::TokenStream;
+use crate::token::lexeme::comment::Comment;
 use crate::token::lexeme::identifier::Identifier;
 use crate::token::lexeme::keyword::Keyword;
 use crate::token::lexeme::literal::integer::Integer;
@@ -184,3 +185,42 @@ fn error_unexpected_end() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_comments_kept() {
+    let input = r#"/* block */ let // line
+x"#;
+
+    let expected = vec![
+        Token {
+            lexeme: Lexeme::Comment(Comment::new_block(" block ".to_owned())),
+            location: Location::test(1, 1),
+        },
+        Token {
+            lexeme: Lexeme::Keyword(Keyword::Let),
+            location: Location::test(1, 13),
+        },
+        Token {
+            lexeme: Lexeme::Comment(Comment::new_line(" line".to_owned())),
+            location: Location::test(1, 17),
+        },
+        Token {
+            lexeme: Lexeme::Identifier(Identifier::new("x".to_owned())),
+            location: Location::test(2, 1),
+        },
+    ];
+
+    let mut result = Vec::with_capacity(expected.len());
+    let mut stream = TokenStream::test(input).with_comments();
+    loop {
+        match stream.next().expect(zinc_const::panic::TEST_DATA_VALID) {
+            Token {
+                lexeme: Lexeme::Eof,
+                ..
+            } => break,
+            token => result.push(token),
+        }
+    }
+
+    assert_eq!(result, expected);
+}
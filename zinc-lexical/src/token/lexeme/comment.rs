@@ -5,7 +5,8 @@
 use std::fmt;
 
 ///
-/// The source code comment, which is dropped during the lexical analysis.
+/// The source code comment, which is dropped during the lexical analysis unless the stream
+/// has been created with comments.
 ///
 #[derive(Debug, Clone, PartialEq)]
 pub enum Comment {
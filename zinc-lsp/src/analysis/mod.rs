@@ -0,0 +1,317 @@
+//!
+//! The source code analysis, which runs the compiler semantic analyzer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use lsp_types::Diagnostic as LspDiagnostic;
+use lsp_types::DiagnosticRelatedInformation;
+use lsp_types::DiagnosticSeverity;
+use lsp_types::Hover;
+use lsp_types::HoverContents;
+use lsp_types::Location as LspLocation;
+use lsp_types::MarkupContent;
+use lsp_types::MarkupKind;
+use lsp_types::NumberOrString;
+use lsp_types::Position;
+use lsp_types::Range;
+use lsp_types::Url;
+
+use zinc_compiler::Diagnostic;
+use zinc_compiler::EntryAnalyzer;
+use zinc_compiler::Error as CompilerError;
+use zinc_compiler::Reference;
+use zinc_compiler::Severity;
+use zinc_compiler::Source;
+use zinc_compiler::SourceError;
+use zinc_compiler::LINT_INDEX;
+use zinc_compiler::REFERENCE_INDEX;
+use zinc_lexical::Location;
+use zinc_lexical::FILE_INDEX;
+
+///
+/// The analysis results of a project or a standalone file.
+///
+pub struct Analysis {
+    /// The analyzed files identifiers in the file index by their paths.
+    files: HashMap<PathBuf, usize>,
+    /// The compiler diagnostics by file paths.
+    pub diagnostics: HashMap<PathBuf, Vec<LspDiagnostic>>,
+}
+
+impl Analysis {
+    /// The diagnostics source name shown by the client.
+    const DIAGNOSTIC_SOURCE: &'static str = "znc";
+
+    ///
+    /// Finds the analysis target of the document at `path`.
+    ///
+    /// It is the project source directory if there is a manifest in one of the parent directories,
+    /// and the file itself otherwise.
+    ///
+    pub fn target(path: &Path) -> PathBuf {
+        let manifest = format!(
+            "{}.{}",
+            zinc_const::file_name::MANIFEST,
+            zinc_const::extension::MANIFEST
+        );
+
+        path.ancestors()
+            .skip(1)
+            .find(|directory| directory.join(manifest.as_str()).is_file())
+            .map(|project| project.join(zinc_const::directory::SOURCE))
+            .unwrap_or_else(|| path.to_owned())
+    }
+
+    ///
+    /// Analyzes the `target` project source directory or standalone file.
+    ///
+    /// The opened `documents` contents take precedence over the files on disk.
+    ///
+    pub fn new(target: &Path, documents: &HashMap<PathBuf, String>) -> Self {
+        let mut analysis = Self {
+            files: HashMap::new(),
+            diagnostics: HashMap::new(),
+        };
+
+        let source = match documents.get(target) {
+            Some(code) => zinc_source::Source::File(zinc_source::File {
+                name: target
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                path: target.to_string_lossy().to_string(),
+                code: code.to_owned(),
+            }),
+            None => match zinc_source::Source::try_from_path(&target.to_path_buf(), true) {
+                Ok(mut source) => {
+                    Self::overlay(&mut source, documents);
+                    source
+                }
+                Err(error) => {
+                    log::warn!("Source code loading error: {}", error);
+                    return analysis;
+                }
+            },
+        };
+
+        let first_file = FILE_INDEX.peek();
+        let diagnostic = match Source::try_from_string(source, true) {
+            Ok(source) => EntryAnalyzer::define(source)
+                .err()
+                .map(|error| CompilerError::from(error).diagnostic()),
+            Err(SourceError::Compiling(diagnostic)) => Some(diagnostic),
+            Err(error) => Some(Diagnostic::new(error.to_string().as_str(), None)),
+        };
+        let last_file = FILE_INDEX.current();
+
+        for file in first_file..=last_file {
+            analysis.files.insert(FILE_INDEX.get_path(file), file);
+            analysis
+                .diagnostics
+                .insert(FILE_INDEX.get_path(file), Vec::new());
+
+            for warning in LINT_INDEX.get(file).into_iter() {
+                analysis.push(target, warning.diagnostic());
+            }
+        }
+
+        if let Some(diagnostic) = diagnostic {
+            analysis.push(target, diagnostic);
+        }
+
+        analysis
+    }
+
+    ///
+    /// Finds the identifier at `position` in the file at `path` and describes the item it refers to.
+    ///
+    pub fn hover(&self, path: &Path, position: Position) -> Option<Hover> {
+        let reference = self.reference(path, position)?;
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```zn\n{}\n```", reference.description),
+            }),
+            range: Some(Self::range(reference.location, reference.length)),
+        })
+    }
+
+    ///
+    /// Finds the identifier at `position` in the file at `path` and returns the location where
+    /// the item it refers to is declared.
+    ///
+    pub fn definition(&self, path: &Path, position: Position) -> Option<LspLocation> {
+        let declaration = self.reference(path, position)?.declaration?;
+
+        Self::location(declaration, 0)
+    }
+
+    ///
+    /// Checks whether the file at `path` has been analyzed.
+    ///
+    pub fn contains(&self, path: &Path) -> bool {
+        self.files.contains_key(path)
+    }
+
+    ///
+    /// Finds the reference at `position` in the file at `path`.
+    ///
+    fn reference(&self, path: &Path, position: Position) -> Option<Reference> {
+        let file = *self.files.get(path)?;
+
+        REFERENCE_INDEX.find(
+            file,
+            position.line as usize + 1,
+            position.character as usize + 1,
+        )
+    }
+
+    ///
+    /// Converts the compiler `diagnostic` and stores it by its file path.
+    ///
+    /// Locationless diagnostics are stored by the `target` path.
+    ///
+    fn push(&mut self, target: &Path, diagnostic: Diagnostic) {
+        let severity = match diagnostic.severity {
+            Severity::Error => DiagnosticSeverity::Error,
+            Severity::Warning => DiagnosticSeverity::Warning,
+        };
+
+        let code = diagnostic
+            .code
+            .map(|code| NumberOrString::String(code.to_owned()));
+
+        let mut message = diagnostic.message;
+        if let Some(help) = diagnostic.help {
+            message.push_str("\nhelp: ");
+            message.push_str(help.as_str());
+        }
+
+        let location = diagnostic
+            .location
+            .filter(|location| location.line > 0 && location.column > 0);
+        let (path, range) = match location {
+            Some(location) => {
+                let range = match diagnostic.end {
+                    Some(end) => Range::new(
+                        Self::position(location),
+                        Position::new(end.line as u32 - 1, end.column as u32),
+                    ),
+                    None => Self::range(location, Self::word_length(location)),
+                };
+
+                (FILE_INDEX.get_path(location.file), range)
+            }
+            None => (target.to_owned(), Range::default()),
+        };
+
+        let related_information = diagnostic
+            .reference
+            .and_then(|reference| Self::location(reference, Self::word_length(reference)))
+            .map(|location| {
+                vec![DiagnosticRelatedInformation {
+                    location,
+                    message: "the related item is here".to_owned(),
+                }]
+            });
+
+        self.diagnostics
+            .entry(path)
+            .or_default()
+            .push(LspDiagnostic::new(
+                range,
+                Some(severity),
+                code,
+                Some(Self::DIAGNOSTIC_SOURCE.to_owned()),
+                message,
+                related_information,
+                None,
+            ));
+    }
+
+    ///
+    /// Replaces the contents of the `source` files, which are opened in the editor.
+    ///
+    fn overlay(source: &mut zinc_source::Source, documents: &HashMap<PathBuf, String>) {
+        match source {
+            zinc_source::Source::File(file) => {
+                if let Some(code) = documents.get(Path::new(file.path.as_str())) {
+                    file.code = code.to_owned();
+                }
+            }
+            zinc_source::Source::Directory(directory) => {
+                for module in directory.modules.values_mut() {
+                    Self::overlay(module, documents);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Returns the length of the identifier or literal starting at `location`.
+    ///
+    /// The length is at least 1, so the client can always highlight the location.
+    ///
+    fn word_length(location: Location) -> usize {
+        let index = FILE_INDEX
+            .inner
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+
+        index
+            .get(&location.file)
+            .and_then(|data| data.code.lines().nth(location.line - 1))
+            .map(|line| {
+                line.chars()
+                    .skip(location.column - 1)
+                    .take_while(|character| character.is_alphanumeric() || *character == '_')
+                    .count()
+            })
+            .unwrap_or_default()
+            .max(1)
+    }
+
+    ///
+    /// Converts the compiler `location` into the protocol location.
+    ///
+    fn location(location: Location, length: usize) -> Option<LspLocation> {
+        let uri = Url::from_file_path(FILE_INDEX.get_path(location.file)).ok()?;
+
+        Some(LspLocation::new(uri, Self::range(location, length)))
+    }
+
+    ///
+    /// Converts the compiler `location` into the protocol range of `length` characters.
+    ///
+    fn range(location: Location, length: usize) -> Range {
+        let start = Self::position(location);
+
+        Range::new(
+            start,
+            Position::new(start.line, start.character + length as u32),
+        )
+    }
+
+    ///
+    /// Converts the one-based compiler `location` into the zero-based protocol position.
+    ///
+    fn position(location: Location) -> Position {
+        Position::new(location.line as u32 - 1, location.column as u32 - 1)
+    }
+}
+
+impl Drop for Analysis {
+    fn drop(&mut self) {
+        for file in self.files.values() {
+            REFERENCE_INDEX.remove(*file);
+            LINT_INDEX.remove(*file);
+        }
+    }
+}
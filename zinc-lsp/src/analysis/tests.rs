@@ -0,0 +1,120 @@
+//!
+//! The source code analysis tests.
+//!
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use lsp_types::DiagnosticSeverity;
+use lsp_types::HoverContents;
+use lsp_types::NumberOrString;
+use lsp_types::Position;
+use lsp_types::Range;
+
+use crate::analysis::Analysis;
+
+fn analyze(code: &str) -> (PathBuf, Analysis) {
+    let path = PathBuf::from("/zinc-lsp-test/main.zn");
+    let mut documents = HashMap::new();
+    documents.insert(path.clone(), code.to_owned());
+
+    let target = Analysis::target(&path);
+    assert_eq!(target, path);
+
+    let analysis = Analysis::new(&target, &documents);
+    (path, analysis)
+}
+
+#[test]
+fn ok_hover() {
+    let (path, analysis) = analyze(
+        r#"
+const LIMIT: u8 = 42;
+
+fn main() -> u8 {
+    let value = LIMIT;
+    value
+}
+"#,
+    );
+
+    assert!(analysis.diagnostics[&path].is_empty());
+
+    let hover = analysis
+        .hover(&path, Position::new(5, 6))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let description = match hover.contents {
+        HoverContents::Markup(content) => content.value,
+        _ => String::new(),
+    };
+    assert_eq!(description, "```zn\nlet value: u8\n```");
+    assert_eq!(
+        hover.range,
+        Some(Range::new(Position::new(5, 4), Position::new(5, 9)))
+    );
+
+    assert!(analysis.hover(&path, Position::new(5, 9)).is_none());
+}
+
+#[test]
+fn ok_definition() {
+    let (path, analysis) = analyze(
+        r#"
+const LIMIT: u8 = 42;
+
+fn main() -> u8 {
+    LIMIT
+}
+"#,
+    );
+
+    let definition = analysis
+        .definition(&path, Position::new(4, 4))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(definition.uri.path(), "/zinc-lsp-test/main.zn");
+    assert_eq!(definition.range.start, Position::new(1, 6));
+}
+
+#[test]
+fn error_diagnostic() {
+    let (path, analysis) = analyze(
+        r#"
+fn main() -> u8 {
+    unknown
+}
+"#,
+    );
+
+    let diagnostics = &analysis.diagnostics[&path];
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].range,
+        Range::new(Position::new(2, 4), Position::new(2, 11))
+    );
+    assert!(diagnostics[0].message.contains("unknown"));
+}
+
+#[test]
+fn warning_diagnostic() {
+    let (path, analysis) = analyze(
+        r#"
+fn main() -> u8 {
+    let mut value = 42;
+    value
+}
+"#,
+    );
+
+    let diagnostics = &analysis.diagnostics[&path];
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::Warning));
+    assert_eq!(
+        diagnostics[0].code,
+        Some(NumberOrString::String("unused_mut".to_owned()))
+    );
+    assert_eq!(
+        diagnostics[0].range,
+        Range::new(Position::new(2, 12), Position::new(2, 17))
+    );
+    assert!(diagnostics[0].message.contains("unused_mut"));
+}
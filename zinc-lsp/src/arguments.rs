@@ -0,0 +1,30 @@
+//!
+//! The Zinc Language Server Protocol server arguments.
+//!
+
+use structopt::StructOpt;
+
+///
+/// The Zinc Language Server Protocol server arguments.
+///
+/// The protocol messages are read from the standard input and written to the standard output.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = zinc_const::app_name::LANGUAGE_SERVER,
+    about = "The Zinc Language Server Protocol server"
+)]
+pub struct Arguments {
+    /// Prints more logs to the standard error, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+}
+
+impl Arguments {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self::from_args()
+    }
+}
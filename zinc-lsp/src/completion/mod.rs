@@ -0,0 +1,65 @@
+//!
+//! The intrinsic path completion.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use lsp_types::CompletionItem;
+use lsp_types::CompletionItemKind;
+
+use zinc_compiler::IntrinsicScope;
+
+/// The path elements separator.
+const PATH_SEPARATOR: &str = "::";
+
+///
+/// Completes the intrinsic path, e.g. `std::crypto::`, which ends the `line` prefix before the cursor.
+///
+/// The last path element is the unfinished identifier, which is filtered by the client.
+///
+pub fn complete(line: &str) -> Vec<CompletionItem> {
+    let path_start = line
+        .char_indices()
+        .rev()
+        .take_while(|(_index, character)| {
+            character.is_alphanumeric() || *character == '_' || *character == ':'
+        })
+        .last()
+        .map(|(index, _character)| index)
+        .unwrap_or_else(|| line.len());
+
+    let mut path: Vec<&str> = line[path_start..].split(PATH_SEPARATOR).collect();
+    path.pop();
+    if path.is_empty() || path.iter().any(|element| element.is_empty()) {
+        return vec![];
+    }
+
+    IntrinsicScope::complete(path.as_slice())
+        .into_iter()
+        .map(|(name, description)| CompletionItem {
+            kind: kind(description.as_str()),
+            ..CompletionItem::new_simple(name, description)
+        })
+        .collect()
+}
+
+///
+/// Derives the completion item kind from the scope item `description`.
+///
+fn kind(description: &str) -> Option<CompletionItemKind> {
+    let kinds = [
+        ("module", CompletionItemKind::Module),
+        ("type function", CompletionItemKind::Function),
+        ("type structure", CompletionItemKind::Struct),
+        ("type enumeration", CompletionItemKind::Enum),
+        ("type", CompletionItemKind::TypeParameter),
+        ("constant", CompletionItemKind::Constant),
+        ("variant", CompletionItemKind::EnumMember),
+    ];
+
+    kinds
+        .iter()
+        .find(|(prefix, _kind)| description.starts_with(prefix))
+        .map(|(_prefix, kind)| *kind)
+}
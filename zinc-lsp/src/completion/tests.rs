@@ -0,0 +1,41 @@
+//!
+//! The intrinsic path completion tests.
+//!
+
+use lsp_types::CompletionItemKind;
+
+#[test]
+fn ok_module() {
+    let items = super::complete("    let hash = std::crypto::sh");
+
+    let sha256 = items
+        .iter()
+        .find(|item| item.label == "sha256")
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(sha256.kind, Some(CompletionItemKind::Function));
+
+    let ecc = items
+        .iter()
+        .find(|item| item.label == "ecc")
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(ecc.kind, Some(CompletionItemKind::Module));
+}
+
+#[test]
+fn ok_root() {
+    let items = super::complete("use zksync::");
+
+    assert!(items.iter().any(|item| item.label == "transfer"));
+}
+
+#[test]
+fn ok_not_a_path() {
+    assert!(super::complete("let value = std").is_empty());
+    assert!(super::complete("let value = ::").is_empty());
+    assert!(super::complete("").is_empty());
+}
+
+#[test]
+fn ok_unknown_path() {
+    assert!(super::complete("unknown::").is_empty());
+}
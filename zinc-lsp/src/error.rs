@@ -0,0 +1,45 @@
+//!
+//! The Zinc Language Server Protocol server error.
+//!
+
+use std::io;
+
+use failure::Fail;
+
+///
+/// The Zinc Language Server Protocol server error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The standard input or output error.
+    #[fail(display = "input/output: {}", _0)]
+    Stdio(io::Error),
+
+    /// The protocol message is malformed.
+    #[fail(display = "invalid protocol message: {}", _0)]
+    Protocol(String),
+
+    /// The JSON decoding error.
+    #[fail(display = "failed to parse json: {}", _0)]
+    JsonDecoding(serde_json::Error),
+
+    /// The request method is not supported.
+    #[fail(display = "method `{}` not found", _0)]
+    MethodNotFound(String),
+
+    /// The document URI does not point to a local file.
+    #[fail(display = "document `{}` is not a local file", _0)]
+    DocumentNotLocal(String),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Stdio(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::JsonDecoding(error)
+    }
+}
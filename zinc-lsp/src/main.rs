@@ -0,0 +1,36 @@
+//!
+//! The Zinc Language Server Protocol server binary.
+//!
+
+pub(crate) mod analysis;
+pub(crate) mod arguments;
+pub(crate) mod completion;
+pub(crate) mod error;
+pub(crate) mod protocol;
+pub(crate) mod server;
+
+use std::io;
+use std::process;
+
+use self::arguments::Arguments;
+use self::server::Server;
+
+///
+/// The application entry point.
+///
+fn main() {
+    let args = Arguments::new();
+
+    zinc_logger::initialize(zinc_const::app_name::LANGUAGE_SERVER, args.verbosity);
+
+    let stdin = io::stdin();
+    let server = Server::new(stdin.lock(), io::stdout());
+
+    process::exit(match server.run() {
+        Ok(()) => zinc_const::exit_code::SUCCESS,
+        Err(error) => {
+            log::error!("{}", error);
+            zinc_const::exit_code::FAILURE
+        }
+    })
+}
@@ -0,0 +1,20 @@
+//!
+//! The Language Server Protocol message.
+//!
+
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+
+///
+/// The client message, which is a request if it has an `id`, and a notification otherwise.
+///
+#[derive(Debug, Deserialize)]
+pub struct Message {
+    /// The request identifier, which is absent in notifications.
+    pub id: Option<JsonValue>,
+    /// The request or notification method name.
+    pub method: String,
+    /// The method-specific parameters.
+    #[serde(default)]
+    pub params: JsonValue,
+}
@@ -0,0 +1,148 @@
+//!
+//! The Language Server Protocol transport.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod message;
+
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::error::Error;
+
+/// The message header which specifies the message body length.
+const CONTENT_LENGTH: &str = "Content-Length";
+
+/// The JSON-RPC protocol version.
+const JSONRPC_VERSION: &str = "2.0";
+
+/// The JSON-RPC error code sent if the request is not valid, e.g. received after `shutdown`.
+pub const ERROR_INVALID_REQUEST: i64 = -32600;
+
+/// The JSON-RPC error code sent if the request method is not supported.
+pub const ERROR_METHOD_NOT_FOUND: i64 = -32601;
+
+/// The JSON-RPC error code sent if the request parameters are invalid.
+pub const ERROR_INVALID_PARAMS: i64 = -32602;
+
+/// The JSON-RPC error code sent if the request has failed for any other reason.
+pub const ERROR_INTERNAL: i64 = -32603;
+
+/// The Language Server Protocol error code sent if a request is received before `initialize`.
+pub const ERROR_SERVER_NOT_INITIALIZED: i64 = -32002;
+
+///
+/// Reads a message, which consists of the headers, an empty line, and the JSON body.
+///
+/// Returns `None` if the input has been closed.
+///
+pub fn read<R: BufRead>(input: &mut R) -> Result<Option<JsonValue>, Error> {
+    let mut content_length = None;
+
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let line = line.trim_end_matches(&['\r', '\n'][..]);
+        if line.is_empty() {
+            if content_length.is_some() {
+                break;
+            }
+            continue;
+        }
+
+        let mut parts = line.splitn(2, ':');
+        let name = parts.next().unwrap_or_default().trim();
+        let value = parts.next().unwrap_or_default().trim();
+        if name.eq_ignore_ascii_case(CONTENT_LENGTH) {
+            let length = value.parse::<usize>().map_err(|_| {
+                Error::Protocol(format!("invalid `{}` value `{}`", CONTENT_LENGTH, value))
+            })?;
+            content_length = Some(length);
+        }
+    }
+
+    let mut body = vec![0u8; content_length.unwrap_or_default()];
+    input.read_exact(body.as_mut_slice())?;
+
+    Ok(Some(serde_json::from_slice(body.as_slice())?))
+}
+
+///
+/// Writes a message with the `Content-Length` header.
+///
+pub fn write<W: Write>(output: &mut W, message: &JsonValue) -> io::Result<()> {
+    let body = message.to_string();
+    write!(output, "{}: {}\r\n\r\n{}", CONTENT_LENGTH, body.len(), body)?;
+    output.flush()
+}
+
+///
+/// The outgoing side of the connection.
+///
+pub struct Connection<W> {
+    /// The messages output.
+    output: W,
+}
+
+impl<W: Write> Connection<W> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(output: W) -> Self {
+        Self { output }
+    }
+
+    ///
+    /// Sends a successful response to the request with `id`.
+    ///
+    pub fn respond(&mut self, id: JsonValue, result: JsonValue) -> io::Result<()> {
+        write(
+            &mut self.output,
+            &json!({
+                "jsonrpc": JSONRPC_VERSION,
+                "id": id,
+                "result": result,
+            }),
+        )
+    }
+
+    ///
+    /// Sends an error response to the request with `id`.
+    ///
+    pub fn fail(&mut self, id: JsonValue, code: i64, message: String) -> io::Result<()> {
+        write(
+            &mut self.output,
+            &json!({
+                "jsonrpc": JSONRPC_VERSION,
+                "id": id,
+                "error": {
+                    "code": code,
+                    "message": message,
+                },
+            }),
+        )
+    }
+
+    ///
+    /// Sends a notification.
+    ///
+    pub fn notify(&mut self, method: &str, params: JsonValue) -> io::Result<()> {
+        write(
+            &mut self.output,
+            &json!({
+                "jsonrpc": JSONRPC_VERSION,
+                "method": method,
+                "params": params,
+            }),
+        )
+    }
+}
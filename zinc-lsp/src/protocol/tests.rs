@@ -0,0 +1,52 @@
+//!
+//! The Language Server Protocol transport tests.
+//!
+
+use std::io::Cursor;
+
+use serde_json::json;
+
+use crate::protocol::message::Message;
+use crate::protocol::Connection;
+
+#[test]
+fn ok_response_read() {
+    let mut connection = Connection::new(Vec::new());
+    connection
+        .respond(json!(3), json!(null))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    connection
+        .fail(
+            json!(4),
+            super::ERROR_METHOD_NOT_FOUND,
+            "unknown".to_owned(),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let mut input = Cursor::new(connection.output);
+    let response = super::read(&mut input)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let error = super::read(&mut input)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(response, json!({"jsonrpc": "2.0", "id": 3, "result": null}));
+    assert_eq!(error["error"]["code"], json!(super::ERROR_METHOD_NOT_FOUND));
+    assert_eq!(
+        super::read(&mut input).expect(zinc_const::panic::TEST_DATA_VALID),
+        None
+    );
+}
+
+#[test]
+fn ok_notification_decoded() {
+    let message: Message = serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "method": "initialized",
+    }))
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(message.id.is_none());
+    assert_eq!(message.method, "initialized");
+}
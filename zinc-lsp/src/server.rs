@@ -0,0 +1,314 @@
+//!
+//! The Language Server Protocol server.
+//!
+
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::io::Write;
+use std::path::PathBuf;
+
+use lsp_types::CompletionParams;
+use lsp_types::DidChangeTextDocumentParams;
+use lsp_types::DidCloseTextDocumentParams;
+use lsp_types::DidOpenTextDocumentParams;
+use lsp_types::DidSaveTextDocumentParams;
+use lsp_types::GotoDefinitionParams;
+use lsp_types::GotoDefinitionResponse;
+use lsp_types::HoverParams;
+use lsp_types::PublishDiagnosticsParams;
+use lsp_types::Url;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::analysis::Analysis;
+use crate::error::Error;
+use crate::protocol;
+use crate::protocol::message::Message;
+use crate::protocol::Connection;
+
+///
+/// The Language Server Protocol server, which handles the client requests and notifications.
+///
+pub struct Server<R, W> {
+    /// The messages input.
+    input: R,
+    /// The messages output.
+    connection: Connection<W>,
+    /// The opened documents contents by their paths.
+    documents: HashMap<PathBuf, String>,
+    /// The latest analysis results by the analysis target paths.
+    analyses: HashMap<PathBuf, Analysis>,
+    /// Whether the `initialize` request has been received.
+    is_initialized: bool,
+    /// Whether the `shutdown` request has been received.
+    is_shutdown: bool,
+}
+
+impl<R, W> Server<R, W>
+where
+    R: BufRead,
+    W: Write,
+{
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(input: R, output: W) -> Self {
+        Self {
+            input,
+            connection: Connection::new(output),
+            documents: HashMap::new(),
+            analyses: HashMap::new(),
+            is_initialized: false,
+            is_shutdown: false,
+        }
+    }
+
+    ///
+    /// Handles the messages until the `exit` notification or the input is closed.
+    ///
+    pub fn run(mut self) -> Result<(), Error> {
+        while let Some(message) = protocol::read(&mut self.input)? {
+            let message: Message = match serde_json::from_value(message) {
+                Ok(message) => message,
+                Err(error) => {
+                    log::debug!("Skipping a message without a method: {}", error);
+                    continue;
+                }
+            };
+
+            match message.id {
+                Some(id) => self.request(id, message.method.as_str(), message.params)?,
+                None if message.method == "exit" => break,
+                None => {
+                    if let Err(error) = self.notification(message.method.as_str(), message.params) {
+                        log::error!("Notification `{}` error: {}", message.method, error);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Handles a request and sends the response.
+    ///
+    fn request(&mut self, id: JsonValue, method: &str, params: JsonValue) -> Result<(), Error> {
+        log::debug!("Request `{}`: {}", method, params);
+
+        if !self.is_initialized && method != "initialize" {
+            self.connection.fail(
+                id,
+                protocol::ERROR_SERVER_NOT_INITIALIZED,
+                "the server has not been initialized".to_owned(),
+            )?;
+            return Ok(());
+        }
+
+        if self.is_shutdown {
+            self.connection.fail(
+                id,
+                protocol::ERROR_INVALID_REQUEST,
+                "the server has been shut down".to_owned(),
+            )?;
+            return Ok(());
+        }
+
+        let result = match method {
+            "initialize" => {
+                self.is_initialized = true;
+                Ok(json!({
+                    "capabilities": {
+                        "textDocumentSync": 1,
+                        "hoverProvider": true,
+                        "definitionProvider": true,
+                        "completionProvider": {
+                            "triggerCharacters": [":"],
+                        },
+                    },
+                    "serverInfo": {
+                        "name": zinc_const::app_name::LANGUAGE_SERVER,
+                        "version": env!("CARGO_PKG_VERSION"),
+                    },
+                }))
+            }
+            "shutdown" => {
+                self.is_shutdown = true;
+                Ok(JsonValue::Null)
+            }
+            "textDocument/hover" => self.hover(params),
+            "textDocument/definition" => self.definition(params),
+            "textDocument/completion" => self.completion(params),
+            method => Err(Error::MethodNotFound(method.to_owned())),
+        };
+
+        match result {
+            Ok(result) => self.connection.respond(id, result)?,
+            Err(error) => {
+                let code = match error {
+                    Error::MethodNotFound(_) => protocol::ERROR_METHOD_NOT_FOUND,
+                    Error::JsonDecoding(_) => protocol::ERROR_INVALID_PARAMS,
+                    _ => protocol::ERROR_INTERNAL,
+                };
+                self.connection.fail(id, code, error.to_string())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Handles a notification, which does not require a response.
+    ///
+    fn notification(&mut self, method: &str, params: JsonValue) -> Result<(), Error> {
+        log::debug!("Notification `{}`", method);
+
+        match method {
+            "textDocument/didOpen" => {
+                let params: DidOpenTextDocumentParams = serde_json::from_value(params)?;
+                let path = Self::path(&params.text_document.uri)?;
+                self.documents
+                    .insert(path.clone(), params.text_document.text);
+                self.analyze(path)?;
+            }
+            "textDocument/didChange" => {
+                let params: DidChangeTextDocumentParams = serde_json::from_value(params)?;
+                let path = Self::path(&params.text_document.uri)?;
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    self.documents.insert(path.clone(), change.text);
+                }
+                self.analyze(path)?;
+            }
+            "textDocument/didSave" => {
+                let params: DidSaveTextDocumentParams = serde_json::from_value(params)?;
+                let path = Self::path(&params.text_document.uri)?;
+                self.analyze(path)?;
+            }
+            "textDocument/didClose" => {
+                let params: DidCloseTextDocumentParams = serde_json::from_value(params)?;
+                let path = Self::path(&params.text_document.uri)?;
+                self.documents.remove(&path);
+                if Analysis::target(&path) == path {
+                    self.publish(path.clone(), vec![])?;
+                    self.analyses.remove(&path);
+                } else {
+                    self.analyze(path)?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Analyzes the project or standalone file, which the document at `path` belongs to,
+    /// and publishes the diagnostics.
+    ///
+    fn analyze(&mut self, path: PathBuf) -> Result<(), Error> {
+        let target = Analysis::target(&path);
+        let analysis = Analysis::new(&target, &self.documents);
+
+        let mut diagnostics = analysis.diagnostics.clone();
+        if let Some(previous) = self.analyses.insert(target, analysis) {
+            for path in previous.diagnostics.keys() {
+                diagnostics.entry(path.to_owned()).or_default();
+            }
+        }
+
+        for (path, diagnostics) in diagnostics.into_iter() {
+            self.publish(path, diagnostics)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Sends the diagnostics of the file at `path` to the client.
+    ///
+    fn publish(
+        &mut self,
+        path: PathBuf,
+        diagnostics: Vec<lsp_types::Diagnostic>,
+    ) -> Result<(), Error> {
+        let uri = match Url::from_file_path(path.as_path()) {
+            Ok(uri) => uri,
+            Err(()) => return Ok(()),
+        };
+
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+        self.connection.notify(
+            "textDocument/publishDiagnostics",
+            serde_json::to_value(params)?,
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Describes the item, which the identifier under the cursor refers to.
+    ///
+    fn hover(&mut self, params: JsonValue) -> Result<JsonValue, Error> {
+        let params: HoverParams = serde_json::from_value(params)?;
+        let position = params.text_document_position_params;
+        let path = Self::path(&position.text_document.uri)?;
+
+        let hover = self
+            .analyses
+            .values()
+            .find(|analysis| analysis.contains(&path))
+            .and_then(|analysis| analysis.hover(&path, position.position));
+
+        Ok(serde_json::to_value(hover)?)
+    }
+
+    ///
+    /// Finds the declaration of the item, which the identifier under the cursor refers to.
+    ///
+    fn definition(&mut self, params: JsonValue) -> Result<JsonValue, Error> {
+        let params: GotoDefinitionParams = serde_json::from_value(params)?;
+        let position = params.text_document_position_params;
+        let path = Self::path(&position.text_document.uri)?;
+
+        let definition = self
+            .analyses
+            .values()
+            .find(|analysis| analysis.contains(&path))
+            .and_then(|analysis| analysis.definition(&path, position.position))
+            .map(GotoDefinitionResponse::Scalar);
+
+        Ok(serde_json::to_value(definition)?)
+    }
+
+    ///
+    /// Completes the intrinsic path before the cursor.
+    ///
+    fn completion(&mut self, params: JsonValue) -> Result<JsonValue, Error> {
+        let params: CompletionParams = serde_json::from_value(params)?;
+        let position = params.text_document_position;
+        let path = Self::path(&position.text_document.uri)?;
+
+        let line: String = self
+            .documents
+            .get(&path)
+            .and_then(|code| code.lines().nth(position.position.line as usize))
+            .map(|line| {
+                line.chars()
+                    .take(position.position.character as usize)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(serde_json::to_value(crate::completion::complete(
+            line.as_str(),
+        ))?)
+    }
+
+    ///
+    /// Converts the document `uri` into the local file path.
+    ///
+    fn path(uri: &Url) -> Result<PathBuf, Error> {
+        uri.to_file_path()
+            .map_err(|()| Error::DocumentNotLocal(uri.to_string()))
+    }
+}
@@ -14,6 +14,7 @@ pub use self::tree::binding::Binding;
 pub use self::tree::expression::array::variant::Variant as ArrayExpressionVariant;
 pub use self::tree::expression::array::Expression as ArrayExpression;
 pub use self::tree::expression::block::Expression as BlockExpression;
+pub use self::tree::expression::closure::Expression as ClosureExpression;
 pub use self::tree::expression::conditional::Expression as ConditionalExpression;
 pub use self::tree::expression::list::Expression as ListExpression;
 pub use self::tree::expression::r#match::Expression as MatchExpression;
@@ -23,6 +24,7 @@ pub use self::tree::expression::tree::node::operator::Operator as ExpressionOper
 pub use self::tree::expression::tree::node::Node as ExpressionTreeNode;
 pub use self::tree::expression::tree::Tree as ExpressionTree;
 pub use self::tree::expression::tuple::Expression as TupleExpression;
+pub use self::tree::generic_parameter::GenericParameter;
 pub use self::tree::identifier::Identifier;
 pub use self::tree::literal::boolean::Literal as BooleanLiteral;
 pub use self::tree::literal::integer::Literal as IntegerLiteral;
@@ -40,6 +42,7 @@ pub use self::tree::statement::local_contract::Statement as ContractLocalStateme
 pub use self::tree::statement::local_fn::Statement as FunctionLocalStatement;
 pub use self::tree::statement::local_impl::Statement as ImplementationLocalStatement;
 pub use self::tree::statement::local_mod::Statement as ModuleLocalStatement;
+pub use self::tree::statement::local_trait::Statement as TraitLocalStatement;
 pub use self::tree::statement::module::Statement as ModStatement;
 pub use self::tree::statement::r#const::Statement as ConstStatement;
 pub use self::tree::statement::r#enum::Statement as EnumStatement;
@@ -47,8 +50,12 @@ pub use self::tree::statement::r#fn::Statement as FnStatement;
 pub use self::tree::statement::r#for::Statement as ForStatement;
 pub use self::tree::statement::r#impl::Statement as ImplStatement;
 pub use self::tree::statement::r#let::Statement as LetStatement;
+pub use self::tree::statement::r#loop::Statement as LoopStatement;
+pub use self::tree::statement::r#return::Statement as ReturnStatement;
 pub use self::tree::statement::r#struct::Statement as StructStatement;
+pub use self::tree::statement::r#trait::Statement as TraitStatement;
 pub use self::tree::statement::r#type::Statement as TypeStatement;
 pub use self::tree::statement::r#use::Statement as UseStatement;
 pub use self::tree::tuple_index::TupleIndex;
+pub use self::tree::variant::data::Data as VariantData;
 pub use self::tree::variant::Variant;
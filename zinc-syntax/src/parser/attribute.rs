@@ -12,6 +12,7 @@ use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::expression::terminal::list::Parser as ExpressionListParser;
 use crate::tree::attribute::builder::Builder as AttributeBuilder;
 use crate::tree::attribute::Attribute;
 use crate::tree::identifier::Identifier;
@@ -30,6 +31,10 @@ pub enum State {
     /// The `#[` has been parsed so far.
     Identifier,
     /// The `#[ {identifier}` has been parsed so far.
+    ParenthesisLeftOrBracketSquareRight,
+    /// The `#[ {identifier} ( {arguments}` has been parsed so far.
+    ParenthesisRight,
+    /// The `#[ {identifier}` with optional arguments has been parsed so far.
     BrackerSquareRight,
 }
 
@@ -57,6 +62,7 @@ impl Parser {
     /// Parses an attribute.
     ///
     /// '#[test]'
+    /// '#[bound(256)]'
     ///
     pub fn parse(
         mut self,
@@ -125,7 +131,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::BrackerSquareRight;
+                            self.state = State::ParenthesisLeftOrBracketSquareRight;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
@@ -134,6 +140,45 @@ impl Parser {
                         }
                     }
                 }
+                State::ParenthesisLeftOrBracketSquareRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            location,
+                        } => {
+                            let (expression, next) = ExpressionListParser::default().parse(
+                                stream.clone(),
+                                None,
+                                location,
+                            )?;
+                            self.next = next;
+                            for argument in expression.elements.into_iter() {
+                                self.builder.push_argument(argument);
+                            }
+                            self.state = State::ParenthesisRight;
+                        }
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::BrackerSquareRight;
+                        }
+                    }
+                }
+                State::ParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => self.state = State::BrackerSquareRight,
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
                 State::BrackerSquareRight => {
                     return match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -152,6 +197,7 @@ impl Parser {
 
 #[cfg(test)]
 mod tests {
+    use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
     use zinc_lexical::Symbol;
@@ -161,7 +207,11 @@ mod tests {
     use crate::error::Error as SyntaxError;
     use crate::error::ParsingError;
     use crate::tree::attribute::Attribute;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::identifier::Identifier;
+    use crate::tree::literal::integer::Literal as IntegerLiteral;
 
     #[test]
     fn ok_outer() {
@@ -172,6 +222,7 @@ mod tests {
                 Location::test(1, 1),
                 false,
                 Identifier::new(Location::test(1, 3), "test".to_owned()),
+                vec![],
             ),
             None,
         ));
@@ -190,6 +241,34 @@ mod tests {
                 Location::test(1, 1),
                 true,
                 Identifier::new(Location::test(1, 4), "test".to_owned()),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_arguments() {
+        let input = r#"#[bound(256)]"#;
+
+        let expected = Ok((
+            Attribute::new(
+                Location::test(1, 1),
+                false,
+                Identifier::new(Location::test(1, 3), "bound".to_owned()),
+                vec![ExpressionTree::new(
+                    Location::test(1, 9),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::test(1, 9),
+                            LexicalIntegerLiteral::new_decimal("256".to_owned()),
+                        ),
+                    )),
+                )],
             ),
             None,
         ));
@@ -230,6 +309,22 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn error_expected_parenthesis_right() {
+        let input = r#"#[bound(256]"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 12),
+            vec![")"],
+            Lexeme::Symbol(Symbol::BracketSquareRight),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_bracket_square_right() {
         let input = r#"#[test)"#;
@@ -0,0 +1,273 @@
+//!
+//! The closure expression parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::binding::Parser as BindingParser;
+use crate::parser::expression::Parser as ExpressionParser;
+use crate::tree::expression::closure::builder::Builder as ClosureExpressionBuilder;
+use crate::tree::expression::closure::Expression as ClosureExpression;
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    VerticalBarOrDoubleVerticalBar,
+    /// The `|` has been parsed so far.
+    BindingOrVerticalBar,
+    /// The `| {binding}` has been parsed so far.
+    CommaOrVerticalBar,
+    /// The `| ... |` or `||` has been parsed so far.
+    Body,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::VerticalBarOrDoubleVerticalBar
+    }
+}
+
+///
+/// The closure expression parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The builder of the parsed value.
+    builder: ClosureExpressionBuilder,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a closure expression.
+    ///
+    /// '|a, b: u8| a + b'
+    /// '|| 42'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(ClosureExpression, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::VerticalBarOrDoubleVerticalBar => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::VerticalBar),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::BindingOrVerticalBar;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::DoubleVerticalBar),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::Body;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["|", "||"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::BindingOrVerticalBar => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::VerticalBar),
+                            ..
+                        } => {
+                            self.state = State::Body;
+                        }
+                        token => {
+                            let (binding, next) =
+                                BindingParser::default().parse(stream.clone(), Some(token))?;
+                            self.builder.push_argument(binding);
+                            self.next = next;
+                            self.state = State::CommaOrVerticalBar;
+                        }
+                    }
+                }
+                State::CommaOrVerticalBar => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            self.state = State::BindingOrVerticalBar;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::VerticalBar),
+                            ..
+                        } => {
+                            self.state = State::Body;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", "|"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Body => {
+                    let (expression, next) =
+                        ExpressionParser::default().parse(stream.clone(), self.next.take())?;
+                    self.builder.set_body(expression);
+                    return Ok((self.builder.finish(), next));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::Token;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::binding::Binding;
+    use crate::tree::expression::closure::Expression as ClosureExpression;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+    use crate::tree::pattern_binding::Pattern as BindingPattern;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+
+    #[test]
+    fn ok_empty() {
+        let input = r#"|| 42"#;
+
+        let expected = Ok((
+            ClosureExpression::new(
+                Location::test(1, 1),
+                vec![],
+                ExpressionTree::new(
+                    Location::test(1, 4),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::test(1, 4),
+                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                        ),
+                    )),
+                ),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 6))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_arguments() {
+        let input = r#"|a, b: u8| a + b"#;
+
+        let expected = Ok((
+            ClosureExpression::new(
+                Location::test(1, 1),
+                vec![
+                    Binding::new(
+                        Location::test(1, 2),
+                        BindingPattern::new(
+                            Location::test(1, 2),
+                            BindingPatternVariant::new_binding(
+                                Identifier::new(Location::test(1, 2), "a".to_owned()),
+                                false,
+                            ),
+                        ),
+                        None,
+                    ),
+                    Binding::new(
+                        Location::test(1, 5),
+                        BindingPattern::new(
+                            Location::test(1, 5),
+                            BindingPatternVariant::new_binding(
+                                Identifier::new(Location::test(1, 5), "b".to_owned()),
+                                false,
+                            ),
+                        ),
+                        Some(Type::new(
+                            Location::test(1, 8),
+                            TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                        )),
+                    ),
+                ],
+                ExpressionTree::new_with_leaves(
+                    Location::test(1, 14),
+                    ExpressionTreeNode::operator(ExpressionOperator::Addition),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 12),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 12), "a".to_owned()),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 16),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 16), "b".to_owned()),
+                        )),
+                    )),
+                ),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 17))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_vertical_bar() {
+        let input = r#"|a; b| a"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 3),
+            vec![",", "|"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
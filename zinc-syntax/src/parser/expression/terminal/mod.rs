@@ -4,6 +4,7 @@
 
 pub mod array;
 pub mod block;
+pub mod closure;
 pub mod conditional;
 pub mod list;
 pub mod r#match;
@@ -31,6 +32,7 @@ use crate::tree::literal::string::Literal as StringLiteral;
 
 use self::array::Parser as ArrayExpressionParser;
 use self::block::Parser as BlockExpressionParser;
+use self::closure::Parser as ClosureExpressionParser;
 use self::conditional::Parser as ConditionalExpressionParser;
 use self::r#match::Parser as MatchExpressionParser;
 use self::tuple::Parser as TupleExpressionParser;
@@ -52,6 +54,7 @@ impl Parser {
     /// - array
     /// - conditional
     /// - match
+    /// - closure
     /// - alias (`crate`, `super`, `Self`, `self`)
     /// - identifier
     /// - literal (boolean, integer, string)
@@ -127,6 +130,25 @@ impl Parser {
                             (ExpressionOperand::Match(operand), location, token)
                         })
                 }
+                token
+                @
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::VerticalBar),
+                    ..
+                }
+                | token
+                @
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::DoubleVerticalBar),
+                    ..
+                } => {
+                    let location = token.location;
+                    ClosureExpressionParser::default()
+                        .parse(stream, Some(token))
+                        .map(|(operand, token)| {
+                            (ExpressionOperand::Closure(operand), location, token)
+                        })
+                }
                 Token {
                     lexeme: Lexeme::Keyword(keyword @ Keyword::Crate),
                     location,
@@ -0,0 +1,410 @@
+//!
+//! The generic parameter list parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::r#type::Parser as TypeParser;
+use crate::tree::generic_parameter::builder::Builder as GenericParameterBuilder;
+use crate::tree::generic_parameter::GenericParameter;
+use crate::tree::identifier::Identifier;
+
+/// The missing identifier error hint.
+pub static HINT_EXPECTED_IDENTIFIER: &str =
+    "generic parameter must have an identifier, e.g. `<T>` or `<const N: usize>`";
+/// The missing trait bound error hint.
+pub static HINT_EXPECTED_BOUND: &str =
+    "generic parameter bound must be a trait identifier, e.g. `<T: Hashable + Serialize>`";
+/// The missing constant parameter type error hint.
+pub static HINT_EXPECTED_TYPE: &str =
+    "constant generic parameter must have a type, e.g. `<const N: usize>`";
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    Lesser,
+    /// The `<` or `<{parameter},` has been parsed so far.
+    KeywordConstOrIdentifierOrGreater,
+    /// The `< const` has been parsed so far.
+    Identifier,
+    /// The `< const {identifier}` has been parsed so far.
+    Colon,
+    /// The `< const {identifier}:` has been parsed so far.
+    Type,
+    /// The `< {identifier}` has been parsed so far.
+    ColonOrCommaOrGreater,
+    /// The `< {identifier}:` or `< {identifier}: {bound} +` has been parsed so far.
+    Bound,
+    /// The `< {identifier}: {bound}` has been parsed so far.
+    PlusOrCommaOrGreater,
+    /// The `< {parameter}` has been parsed so far.
+    CommaOrGreater,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::Lesser
+    }
+}
+
+///
+/// The generic parameter list parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The builder of the currently parsed parameter.
+    builder: GenericParameterBuilder,
+    /// The parsed parameters.
+    parameters: Vec<GenericParameter>,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a generic parameter list.
+    ///
+    /// '<T, U: Hashable + Serialize, const N: usize>'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(Vec<GenericParameter>, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::Lesser => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => {
+                            self.state = State::KeywordConstOrIdentifierOrGreater;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["<"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::KeywordConstOrIdentifierOrGreater => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Const),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::Identifier;
+                        }
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.builder
+                                .set_identifier(Identifier::new(location, identifier.inner));
+                            self.state = State::ColonOrCommaOrGreater;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => return Ok((self.parameters, None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::Identifier => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.builder
+                                .set_identifier(Identifier::new(location, identifier.inner));
+                            self.state = State::Colon;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::Colon => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Colon),
+                            ..
+                        } => {
+                            self.state = State::Type;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_type(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_TYPE),
+                            )));
+                        }
+                    }
+                }
+                State::Type => {
+                    let (r#type, next) =
+                        TypeParser::default().parse(stream.clone(), self.next.take())?;
+                    self.builder.set_type(r#type);
+                    self.parameters.push(self.builder.finish());
+                    self.next = next;
+                    self.state = State::CommaOrGreater;
+                }
+                State::ColonOrCommaOrGreater => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Colon),
+                            ..
+                        } => {
+                            self.state = State::Bound;
+                        }
+                        token => {
+                            self.parameters.push(self.builder.finish());
+                            self.next = Some(token);
+                            self.state = State::CommaOrGreater;
+                        }
+                    }
+                }
+                State::Bound => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.builder
+                                .push_bound(Identifier::new(location, identifier.inner));
+                            self.state = State::PlusOrCommaOrGreater;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_BOUND),
+                            )));
+                        }
+                    }
+                }
+                State::PlusOrCommaOrGreater => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Plus),
+                            ..
+                        } => {
+                            self.state = State::Bound;
+                        }
+                        token => {
+                            self.parameters.push(self.builder.finish());
+                            self.next = Some(token);
+                            self.state = State::CommaOrGreater;
+                        }
+                    }
+                }
+                State::CommaOrGreater => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            self.state = State::KeywordConstOrIdentifierOrGreater;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => return Ok((self.parameters, None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ">"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Literal as LexicalLiteral;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::generic_parameter::GenericParameter;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+
+    #[test]
+    fn ok_empty() {
+        let input = r#"<>"#;
+
+        let expected = Ok((vec![], None));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_single_type() {
+        let input = r#"<T>"#;
+
+        let expected = Ok((
+            vec![GenericParameter::new(
+                Location::test(1, 2),
+                Identifier::new(Location::test(1, 2), "T".to_owned()),
+                None,
+                vec![],
+            )],
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_multiple_with_constant() {
+        let input = r#"<T, const N: u64,>"#;
+
+        let expected = Ok((
+            vec![
+                GenericParameter::new(
+                    Location::test(1, 2),
+                    Identifier::new(Location::test(1, 2), "T".to_owned()),
+                    None,
+                    vec![],
+                ),
+                GenericParameter::new(
+                    Location::test(1, 5),
+                    Identifier::new(Location::test(1, 11), "N".to_owned()),
+                    Some(Type::new(
+                        Location::test(1, 14),
+                        TypeVariant::integer_unsigned(zinc_const::bitlength::INDEX),
+                    )),
+                    vec![],
+                ),
+            ],
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_bounds() {
+        let input = r#"<T: Hashable + Serialize>"#;
+
+        let expected = Ok((
+            vec![GenericParameter::new(
+                Location::test(1, 2),
+                Identifier::new(Location::test(1, 2), "T".to_owned()),
+                None,
+                vec![
+                    Identifier::new(Location::test(1, 5), "Hashable".to_owned()),
+                    Identifier::new(Location::test(1, 16), "Serialize".to_owned()),
+                ],
+            )],
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bound() {
+        let input = r#"<T: 42>"#;
+
+        let expected: Result<(Vec<GenericParameter>, Option<_>), ParsingError> =
+            Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                Location::test(1, 5),
+                Lexeme::Literal(LexicalLiteral::Integer(LexicalIntegerLiteral::new_decimal(
+                    "42".to_owned(),
+                ))),
+                Some(super::HINT_EXPECTED_BOUND),
+            )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_type() {
+        let input = r#"<const N>"#;
+
+        let expected: Result<(Vec<GenericParameter>, Option<_>), ParsingError> =
+            Err(ParsingError::Syntax(SyntaxError::expected_type(
+                Location::test(1, 9),
+                Lexeme::Symbol(Symbol::Greater),
+                Some(super::HINT_EXPECTED_TYPE),
+            )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_greater() {
+        let input = r#"<T U>"#;
+
+        let expected: Result<(Vec<GenericParameter>, Option<_>), ParsingError> =
+            Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                Location::test(1, 4),
+                vec![",", ">"],
+                Lexeme::Identifier(zinc_lexical::Identifier::new("U".to_owned())),
+                None,
+            )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
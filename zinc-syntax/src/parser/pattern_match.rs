@@ -20,6 +20,7 @@ use crate::tree::identifier::Identifier;
 use crate::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
 use crate::tree::pattern_match::builder::Builder as MatchPatternBuilder;
+use crate::tree::pattern_match::variant::Variant as MatchPatternVariant;
 use crate::tree::pattern_match::Pattern as MatchPattern;
 
 ///
@@ -33,6 +34,18 @@ pub enum State {
     PathOperatorOrEnd,
     /// The first path operand and a `::` path operator have been parsed so far.
     PathOperand,
+    /// The path and `(` have been parsed so far.
+    NestedPatternOrParenthesisRight,
+    /// The path, `(`, and a nested pattern have been parsed so far.
+    CommaOrParenthesisRight,
+    /// The path and `{` or a field pattern followed by `,` have been parsed so far.
+    FieldIdentifierOrRestOrBracketCurlyRight,
+    /// The path, `{`, and a field identifier have been parsed so far.
+    ColonOrCommaOrBracketCurlyRight,
+    /// The path, `{`, and a field pattern have been parsed so far.
+    CommaOrBracketCurlyRight,
+    /// The path, `{`, and the `..` rest pattern have been parsed so far.
+    BracketCurlyRight,
 }
 
 impl Default for State {
@@ -41,6 +54,10 @@ impl Default for State {
     }
 }
 
+/// The invalid structure-like pattern field error hint.
+pub static HINT_EXPECTED_FIELD_IDENTIFIER: &str =
+    "structure pattern fields must be identifiers, e.g. `Message::Set { key, value: _, .. }`";
+
 ///
 /// The match pattern parser.
 ///
@@ -50,6 +67,8 @@ pub struct Parser {
     state: State,
     /// The builder of the parsed value.
     builder: MatchPatternBuilder,
+    /// The field identifier of the structure-like pattern, whose nested pattern is being parsed.
+    field: Option<Identifier>,
     /// The token returned from a subparser.
     next: Option<Token>,
 }
@@ -62,6 +81,8 @@ impl Parser {
     /// '42'
     /// 'variable'
     /// 'Path::To::Item'
+    /// 'Some(value)'
+    /// 'Message::Set { key, value: _, .. }'
     /// '_'
     ///
     pub fn parse(
@@ -148,6 +169,20 @@ impl Parser {
                                 .push_path_operator(ExpressionOperator::Path, location);
                             self.state = State::PathOperand;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => {
+                            self.builder.set_tuple_struct();
+                            self.state = State::NestedPatternOrParenthesisRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => {
+                            self.builder.set_structure();
+                            self.state = State::FieldIdentifierOrRestOrBracketCurlyRight;
+                        }
                         token => return Ok((self.builder.finish(), Some(token))),
                     }
                 }
@@ -158,9 +193,155 @@ impl Parser {
                     self.builder.push_path_element(expression);
                     self.state = State::PathOperatorOrEnd;
                 }
+                State::NestedPatternOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (pattern, next) =
+                                Self::default().parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.push_nested_pattern(pattern);
+                            self.state = State::CommaOrParenthesisRight;
+                        }
+                    }
+                }
+                State::CommaOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::NestedPatternOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::FieldIdentifierOrRestOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.field = Some(Identifier::new(location, identifier.inner));
+                            self.state = State::ColonOrCommaOrBracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::DoubleDot),
+                            ..
+                        } => {
+                            self.builder.set_rest();
+                            self.state = State::BracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(
+                                SyntaxError::expected_field_identifier(
+                                    location,
+                                    lexeme,
+                                    Some(HINT_EXPECTED_FIELD_IDENTIFIER),
+                                ),
+                            ));
+                        }
+                    }
+                }
+                State::ColonOrCommaOrBracketCurlyRight => {
+                    let field = self
+                        .field
+                        .take()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Colon),
+                            ..
+                        } => {
+                            let (pattern, next) = Self::default().parse(stream.clone(), None)?;
+                            self.next = next;
+                            self.builder.push_nested_field(field, pattern);
+                            self.state = State::CommaOrBracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            self.builder
+                                .push_nested_field(field.clone(), Self::shorthand(field));
+                            self.state = State::FieldIdentifierOrRestOrBracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => {
+                            self.builder
+                                .push_nested_field(field.clone(), Self::shorthand(field));
+                            return Ok((self.builder.finish(), None));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![":", ",", "}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::CommaOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::FieldIdentifierOrRestOrBracketCurlyRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", "}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::BracketCurlyRight => {
+                    return match crate::parser::take_or_next(self.next.take(), stream)? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => Err(ParsingError::Syntax(
+                            SyntaxError::expected_one_of(location, vec!["}"], lexeme, None),
+                        )),
+                    };
+                }
             }
         }
     }
+
+    ///
+    /// Creates the binding pattern for the shorthand field pattern, e.g. `key` in `{ key }`.
+    ///
+    fn shorthand(field: Identifier) -> MatchPattern {
+        MatchPattern::new(field.location, MatchPatternVariant::Binding(field))
+    }
 }
 
 #[cfg(test)]
@@ -169,11 +350,15 @@ mod tests {
     use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
     use zinc_lexical::Keyword;
     use zinc_lexical::Lexeme;
+    use zinc_lexical::Literal as LexicalLiteral;
     use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
     use zinc_lexical::Token;
     use zinc_lexical::TokenStream;
 
     use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
     use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
     use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
@@ -321,6 +506,219 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_tuple_struct() {
+        let input = r#"Some(value)"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::TupleStruct {
+                    path: ExpressionTree::new(
+                        Location::test(1, 1),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 1), "Some".to_owned()),
+                        )),
+                    ),
+                    patterns: vec![MatchPattern::new(
+                        Location::test(1, 6),
+                        MatchPatternVariant::Binding(Identifier::new(
+                            Location::test(1, 6),
+                            "value".to_owned(),
+                        )),
+                    )],
+                },
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_tuple_struct_multiple() {
+        let input = r#"Shape::Rectangle(_, 42,)"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::TupleStruct {
+                    path: ExpressionTree::new_with_leaves(
+                        Location::test(1, 6),
+                        ExpressionTreeNode::operator(ExpressionOperator::Path),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 1),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 1), "Shape".to_owned()),
+                            )),
+                        )),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 8),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 8), "Rectangle".to_owned()),
+                            )),
+                        )),
+                    ),
+                    patterns: vec![
+                        MatchPattern::new(Location::test(1, 18), MatchPatternVariant::Wildcard),
+                        MatchPattern::new(
+                            Location::test(1, 21),
+                            MatchPatternVariant::IntegerLiteral(IntegerLiteral::new(
+                                Location::test(1, 21),
+                                LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                            )),
+                        ),
+                    ],
+                },
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_parenthesis_right() {
+        let input = r#"Some(value;"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 11),
+            vec![",", ")"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_structure() {
+        let input = r#"Message::Set { key, value: _, .. }"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Structure {
+                    path: ExpressionTree::new_with_leaves(
+                        Location::test(1, 8),
+                        ExpressionTreeNode::operator(ExpressionOperator::Path),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 1),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 1), "Message".to_owned()),
+                            )),
+                        )),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 10),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 10), "Set".to_owned()),
+                            )),
+                        )),
+                    ),
+                    fields: vec![
+                        (
+                            Identifier::new(Location::test(1, 16), "key".to_owned()),
+                            MatchPattern::new(
+                                Location::test(1, 16),
+                                MatchPatternVariant::Binding(Identifier::new(
+                                    Location::test(1, 16),
+                                    "key".to_owned(),
+                                )),
+                            ),
+                        ),
+                        (
+                            Identifier::new(Location::test(1, 21), "value".to_owned()),
+                            MatchPattern::new(Location::test(1, 28), MatchPatternVariant::Wildcard),
+                        ),
+                    ],
+                    has_rest: true,
+                },
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_structure_empty() {
+        let input = r#"Message::Empty {}"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Structure {
+                    path: ExpressionTree::new_with_leaves(
+                        Location::test(1, 8),
+                        ExpressionTreeNode::operator(ExpressionOperator::Path),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 1),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 1), "Message".to_owned()),
+                            )),
+                        )),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 10),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 10), "Empty".to_owned()),
+                            )),
+                        )),
+                    ),
+                    fields: vec![],
+                    has_rest: false,
+                },
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_field_identifier() {
+        let input = r#"Message::Set { 42 }"#;
+
+        let expected = Err(ParsingError::Syntax(
+            SyntaxError::expected_field_identifier(
+                Location::test(1, 16),
+                Lexeme::Literal(LexicalLiteral::Integer(LexicalIntegerLiteral::new_decimal(
+                    "42".to_owned(),
+                ))),
+                Some(super::HINT_EXPECTED_FIELD_IDENTIFIER),
+            ),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_curly_right() {
+        let input = r#"Message::Set { .., key }"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 18),
+            vec!["}"],
+            Lexeme::Symbol(Symbol::Comma),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_wildcard() {
         let input = r#"_"#;
@@ -232,6 +232,7 @@ mod tests {
                     false,
                     Identifier::new(Location::test(3, 9), "a".to_owned()),
                     Type::new(Location::test(3, 12), TypeVariant::integer_unsigned(232)),
+                    vec![],
                 ))],
             ),
             None,
@@ -262,18 +263,21 @@ mod tests {
                         false,
                         Identifier::new(Location::test(3, 9), "a".to_owned()),
                         Type::new(Location::test(3, 12), TypeVariant::integer_unsigned(232)),
+                        vec![],
                     )),
                     ContractLocalStatement::Field(FieldStatement::new(
                         Location::test(4, 9),
                         true,
                         Identifier::new(Location::test(4, 13), "b".to_owned()),
                         Type::new(Location::test(4, 16), TypeVariant::integer_unsigned(232)),
+                        vec![],
                     )),
                     ContractLocalStatement::Field(FieldStatement::new(
                         Location::test(5, 9),
                         true,
                         Identifier::new(Location::test(5, 13), "c".to_owned()),
                         Type::new(Location::test(5, 16), TypeVariant::integer_unsigned(232)),
+                        vec![],
                     )),
                 ],
             ),
@@ -404,6 +408,7 @@ mod tests {
                     false,
                     false,
                     Identifier::new(Location::test(3, 12), "f".to_owned()),
+                    vec![],
                     vec![Binding::new(
                         Location::test(3, 14),
                         BindingPattern::new(
@@ -450,6 +455,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(3, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(3, 15),
                             BindingPattern::new(
@@ -470,6 +476,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(5, 12), "f2".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(5, 15),
                             BindingPattern::new(
@@ -490,6 +497,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(7, 12), "f3".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(7, 15),
                             BindingPattern::new(
@@ -537,6 +545,7 @@ mod tests {
                         true,
                         Identifier::new(Location::test(3, 13), "a".to_owned()),
                         Type::new(Location::test(3, 16), TypeVariant::integer_unsigned(232)),
+                        vec![],
                     )),
                     ContractLocalStatement::Const(ConstStatement::new(
                         Location::test(5, 9),
@@ -557,6 +566,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(7, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(7, 15),
                             BindingPattern::new(
@@ -612,18 +622,21 @@ mod tests {
                         false,
                         Identifier::new(Location::test(3, 9), "a".to_owned()),
                         Type::new(Location::test(3, 12), TypeVariant::integer_unsigned(232)),
+                        vec![],
                     )),
                     ContractLocalStatement::Field(FieldStatement::new(
                         Location::test(4, 9),
                         true,
                         Identifier::new(Location::test(4, 13), "b".to_owned()),
                         Type::new(Location::test(4, 16), TypeVariant::integer_unsigned(232)),
+                        vec![],
                     )),
                     ContractLocalStatement::Field(FieldStatement::new(
                         Location::test(5, 9),
                         true,
                         Identifier::new(Location::test(5, 13), "c".to_owned()),
                         Type::new(Location::test(5, 16), TypeVariant::integer_unsigned(232)),
+                        vec![],
                     )),
                     ContractLocalStatement::Const(ConstStatement::new(
                         Location::test(7, 9),
@@ -672,6 +685,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(11, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(11, 15),
                             BindingPattern::new(
@@ -692,6 +706,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(13, 12), "f2".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(13, 15),
                             BindingPattern::new(
@@ -712,6 +727,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(15, 12), "f3".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(15, 15),
                             BindingPattern::new(
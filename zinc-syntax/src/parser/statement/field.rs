@@ -158,6 +158,7 @@ mod tests {
                 false,
                 Identifier::new(Location::test(1, 1), "data".to_owned()),
                 Type::new(Location::test(1, 7), TypeVariant::integer_unsigned(64)),
+                vec![],
             ),
             None,
         ));
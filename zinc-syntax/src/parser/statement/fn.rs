@@ -15,6 +15,7 @@ use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
 use crate::parser::binding_list::Parser as BindingListParser;
 use crate::parser::expression::terminal::block::Parser as BlockExpressionParser;
+use crate::parser::generic_parameter_list::Parser as GenericParameterListParser;
 use crate::parser::r#type::Parser as TypeParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::r#fn::builder::Builder as FnStatementBuilder;
@@ -36,6 +37,8 @@ pub enum State {
     /// The `fn` has been parsed so far.
     Identifier,
     /// The `fn {identifier}` has been parsed so far.
+    GenericsOrParenthesisLeft,
+    /// The `fn {identifier}` with optional `<{generics}>` has been parsed so far.
     ParenthesisLeft,
     /// The `fn {identifier} (` has been parsed so far.
     ArgumentBindingList,
@@ -64,11 +67,23 @@ pub struct Parser {
     state: State,
     /// The builder of the parsed value.
     builder: FnStatementBuilder,
+    /// If the body may be replaced with `;`, which is allowed for trait method signatures.
+    is_body_optional: bool,
     /// The token returned from a subparser.
     next: Option<Token>,
 }
 
 impl Parser {
+    ///
+    /// Creates a parser of trait methods, which may be declared without the body.
+    ///
+    pub fn new_trait_method() -> Self {
+        Self {
+            is_body_optional: true,
+            ..Self::default()
+        }
+    }
+
     ///
     /// Parses an 'fn' statement.
     ///
@@ -78,6 +93,12 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// fn max<T>(a: T, b: T) -> T {
+    ///     if a > b { a } else { b }
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -114,7 +135,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::ParenthesisLeft;
+                            self.state = State::GenericsOrParenthesisLeft;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
@@ -125,6 +146,23 @@ impl Parser {
                         }
                     }
                 }
+                State::GenericsOrParenthesisLeft => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => {
+                            let (generics, next) = GenericParameterListParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.builder.set_generics(generics);
+                            self.next = next;
+                        }
+                        token => self.next = Some(token),
+                    }
+                    self.state = State::ParenthesisLeft;
+                }
                 State::ParenthesisLeft => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -184,6 +222,14 @@ impl Parser {
                     self.state = State::Body;
                 }
                 State::Body => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } if self.is_body_optional => return Ok((self.builder, None)),
+                        token => self.next = Some(token),
+                    }
+
                     let (expression, next) =
                         BlockExpressionParser::default().parse(stream, self.next.take())?;
 
@@ -207,6 +253,10 @@ mod tests {
     use crate::error::ParsingError;
     use crate::tree::binding::Binding;
     use crate::tree::expression::block::Expression as BlockExpression;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
+    use crate::tree::generic_parameter::GenericParameter;
     use crate::tree::identifier::Identifier;
     use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::tree::pattern_binding::Pattern as BindingPattern;
@@ -224,6 +274,7 @@ mod tests {
                 false,
                 false,
                 Identifier::new(Location::test(1, 4), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 6),
                     BindingPattern::new(
@@ -259,6 +310,7 @@ mod tests {
                 false,
                 false,
                 Identifier::new(Location::test(1, 4), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 6),
                     BindingPattern::new(
@@ -284,6 +336,62 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_generic() {
+        let input = r#"fn f<T>(a: T) -> T {}"#;
+
+        let alias = |column| {
+            Type::new(
+                Location::test(1, column),
+                TypeVariant::alias(
+                    ExpressionTree::new(
+                        Location::test(1, column),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, column), "T".to_owned()),
+                        )),
+                    ),
+                    None,
+                ),
+            )
+        };
+
+        let expected = Ok((
+            FnStatement::new(
+                Location::test(1, 1),
+                false,
+                false,
+                Identifier::new(Location::test(1, 4), "f".to_owned()),
+                vec![GenericParameter::new(
+                    Location::test(1, 6),
+                    Identifier::new(Location::test(1, 6), "T".to_owned()),
+                    None,
+                    vec![],
+                )],
+                vec![Binding::new(
+                    Location::test(1, 9),
+                    BindingPattern::new(
+                        Location::test(1, 9),
+                        BindingPatternVariant::new_binding(
+                            Identifier::new(Location::test(1, 9), "a".to_owned()),
+                            false,
+                        ),
+                    ),
+                    Some(alias(12)),
+                )],
+                Some(alias(18)),
+                BlockExpression::new(Location::test(1, 20), vec![], None),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_identifier() {
         let input = r#"fn (a: u8) -> field {}"#;
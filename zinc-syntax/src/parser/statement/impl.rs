@@ -21,6 +21,9 @@ use crate::tree::statement::r#impl::Statement as ImplStatement;
 /// The missing identifier error hint.
 pub static HINT_EXPECTED_IDENTIFIER: &str =
     "type implementation must have an identifier, e.g. `impl Data { ... }`";
+/// The missing type identifier after `for` error hint.
+pub static HINT_EXPECTED_TYPE_IDENTIFIER: &str =
+    "trait implementation must have a type identifier, e.g. `impl Hashable for Data { ... }`";
 
 ///
 /// The parser state.
@@ -32,6 +35,10 @@ pub enum State {
     /// The `impl` has been parsed so far.
     Identifier,
     /// The `impl {identifier}` has been parsed so far.
+    KeywordForOrBracketCurlyLeft,
+    /// The `impl {trait} for` has been parsed so far.
+    TypeIdentifier,
+    /// The `impl {identifier}` or `impl {trait} for {identifier}` has been parsed so far.
     BracketCurlyLeft,
     /// The `impl {identifier} {` has been parsed so far.
     StatementOrBracketCurlyRight,
@@ -68,6 +75,14 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// impl Hashable for Data {
+    ///     fn hash(self) -> field {
+    ///         self.value
+    ///     }
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -104,7 +119,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::BracketCurlyLeft;
+                            self.state = State::KeywordForOrBracketCurlyLeft;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
@@ -115,6 +130,40 @@ impl Parser {
                         }
                     }
                 }
+                State::KeywordForOrBracketCurlyLeft => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::For),
+                            ..
+                        } => {
+                            self.builder.set_trait();
+                            self.state = State::TypeIdentifier;
+                        }
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::BracketCurlyLeft;
+                        }
+                    }
+                }
+                State::TypeIdentifier => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_identifier(identifier);
+                            self.state = State::BracketCurlyLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_TYPE_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
                 State::BracketCurlyLeft => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -182,6 +231,31 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_trait() {
+        let input = r#"
+    impl Hashable for Test {}
+"#;
+
+        let expected = Ok((
+            ImplStatement::new(
+                Location::test(2, 5),
+                Identifier::new(Location::test(2, 23), "Test".to_owned()),
+                Some(Identifier::new(
+                    Location::test(2, 10),
+                    "Hashable".to_owned(),
+                )),
                 vec![],
             ),
             None,
@@ -204,6 +278,7 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![ImplementationLocalStatement::Const(ConstStatement::new(
                     Location::test(3, 9),
                     Identifier::new(Location::test(3, 15), "VALUE".to_owned()),
@@ -243,6 +318,7 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
@@ -308,11 +384,13 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![ImplementationLocalStatement::Fn(FnStatement::new(
                     Location::test(3, 9),
                     false,
                     false,
                     Identifier::new(Location::test(3, 12), "f".to_owned()),
+                    vec![],
                     vec![Binding::new(
                         Location::test(3, 14),
                         BindingPattern::new(
@@ -353,12 +431,14 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![
                     ImplementationLocalStatement::Fn(FnStatement::new(
                         Location::test(3, 9),
                         false,
                         false,
                         Identifier::new(Location::test(3, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(3, 15),
                             BindingPattern::new(
@@ -379,6 +459,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(5, 12), "f2".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(5, 15),
                             BindingPattern::new(
@@ -399,6 +480,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(7, 12), "f3".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(7, 15),
                             BindingPattern::new(
@@ -438,6 +520,7 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
@@ -458,6 +541,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(5, 12), "f".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(5, 14),
                             BindingPattern::new(
@@ -505,6 +589,7 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
@@ -553,6 +638,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(9, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(9, 15),
                             BindingPattern::new(
@@ -573,6 +659,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(11, 12), "f2".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(11, 15),
                             BindingPattern::new(
@@ -593,6 +680,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(13, 12), "f3".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(13, 15),
                             BindingPattern::new(
@@ -175,6 +175,8 @@ impl Parser {
                                 builder.set_public();
                             }
 
+                            builder.set_attributes(self.attributes);
+
                             Ok((ContractLocalStatement::Field(builder.finish()), next))
                         }
                     }
@@ -193,11 +195,15 @@ mod tests {
     use crate::tree::attribute::Attribute;
     use crate::tree::binding::Binding;
     use crate::tree::expression::block::Expression as BlockExpression;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::identifier::Identifier;
     use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::tree::pattern_binding::Pattern as BindingPattern;
     use crate::tree::r#type::variant::Variant as TypeVariant;
     use crate::tree::r#type::Type;
+    use crate::tree::statement::field::Statement as FieldStatement;
     use crate::tree::statement::local_contract::Statement as ContractLocalStatement;
     use crate::tree::statement::r#fn::Statement as FnStatement;
 
@@ -211,6 +217,7 @@ mod tests {
                 true,
                 false,
                 Identifier::new(Location::test(1, 8), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 10),
                     BindingPattern::new(
@@ -244,6 +251,7 @@ mod tests {
                 false,
                 true,
                 Identifier::new(Location::test(1, 10), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 12),
                     BindingPattern::new(
@@ -277,6 +285,7 @@ mod tests {
                 true,
                 true,
                 Identifier::new(Location::test(1, 14), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 16),
                     BindingPattern::new(
@@ -314,12 +323,14 @@ fn test() {}
                 false,
                 Identifier::new(Location::test(3, 4), "test".to_owned()),
                 vec![],
+                vec![],
                 None,
                 BlockExpression::new(Location::test(3, 11), vec![], None),
                 vec![Attribute::new(
                     Location::test(2, 1),
                     false,
                     Identifier::new(Location::test(2, 3), "test".to_owned()),
+                    vec![],
                 )],
             )),
             None,
@@ -346,6 +357,7 @@ fn test() {}
                 false,
                 Identifier::new(Location::test(5, 4), "test".to_owned()),
                 vec![],
+                vec![],
                 None,
                 BlockExpression::new(Location::test(5, 11), vec![], None),
                 vec![
@@ -353,16 +365,19 @@ fn test() {}
                         Location::test(2, 1),
                         false,
                         Identifier::new(Location::test(2, 3), "test".to_owned()),
+                        vec![],
                     ),
                     Attribute::new(
                         Location::test(3, 1),
                         false,
                         Identifier::new(Location::test(3, 3), "should_panic".to_owned()),
+                        vec![],
                     ),
                     Attribute::new(
                         Location::test(4, 1),
                         false,
                         Identifier::new(Location::test(4, 3), "ignore".to_owned()),
+                        vec![],
                     ),
                 ],
             )),
@@ -373,4 +388,40 @@ fn test() {}
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_field_attributes() {
+        let input = r#"
+#[allow(unwritten_storage_fields)]
+pub data: u8;
+"#;
+
+        let expected = Ok((
+            ContractLocalStatement::Field(FieldStatement::new(
+                Location::test(3, 1),
+                true,
+                Identifier::new(Location::test(3, 5), "data".to_owned()),
+                Type::new(Location::test(3, 11), TypeVariant::integer_unsigned(8)),
+                vec![Attribute::new(
+                    Location::test(2, 1),
+                    false,
+                    Identifier::new(Location::test(2, 3), "allow".to_owned()),
+                    vec![ExpressionTree::new(
+                        Location::test(2, 9),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(
+                                Location::test(2, 9),
+                                "unwritten_storage_fields".to_owned(),
+                            ),
+                        )),
+                    )],
+                )],
+            )),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
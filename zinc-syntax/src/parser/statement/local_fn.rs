@@ -11,18 +11,29 @@ use zinc_lexical::Symbol;
 use zinc_lexical::Token;
 use zinc_lexical::TokenStream;
 
+use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::attribute::Parser as AttributeParser;
 use crate::parser::expression::Parser as ExpressionParser;
 use crate::parser::statement::r#const::Parser as ConstStatementParser;
 use crate::parser::statement::r#for::Parser as ForStatementParser;
 use crate::parser::statement::r#let::Parser as LetStatementParser;
+use crate::parser::statement::r#loop::Parser as LoopStatementParser;
+use crate::parser::statement::r#return::Parser as ReturnStatementParser;
+use crate::tree::attribute::Attribute;
 use crate::tree::statement::local_fn::Statement as FunctionLocalStatement;
 
+/// The misplaced attribute error hint.
+pub static HINT_ATTRIBUTES_ONLY_LOOPS: &str =
+    "only loops may have attributes inside functions, e.g. `#[bound(16)] while x < y { ... }`";
+
 ///
 /// The function-local statement parser.
 ///
 #[derive(Default)]
 pub struct Parser {
+    /// The loop outer attributes.
+    attributes: Vec<Attribute>,
     /// The token returned from a subparser.
     next: Option<Token>,
 }
@@ -38,6 +49,53 @@ impl Parser {
     ) -> Result<(FunctionLocalStatement, Option<Token>, bool), ParsingError> {
         self.next = initial;
 
+        loop {
+            match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                token
+                @
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Number),
+                    ..
+                } => {
+                    let (attribute, next) =
+                        AttributeParser::default().parse(stream.clone(), Some(token))?;
+                    self.attributes.push(attribute);
+                    self.next = next;
+                }
+                token
+                @
+                Token {
+                    lexeme: Lexeme::Keyword(Keyword::Loop),
+                    ..
+                }
+                | token
+                @
+                Token {
+                    lexeme: Lexeme::Keyword(Keyword::While),
+                    ..
+                } => {
+                    let (statement, next) = LoopStatementParser::default().parse(
+                        stream.clone(),
+                        Some(token),
+                        self.attributes,
+                    )?;
+                    return Ok((FunctionLocalStatement::Loop(statement), next, false));
+                }
+                Token { lexeme, location } if !self.attributes.is_empty() => {
+                    return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                        location,
+                        vec!["loop", "while"],
+                        lexeme,
+                        Some(HINT_ATTRIBUTES_ONLY_LOOPS),
+                    )));
+                }
+                token => {
+                    self.next = Some(token);
+                    break;
+                }
+            }
+        }
+
         let statement = match crate::parser::take_or_next(self.next.take(), stream.clone())? {
             token
             @
@@ -72,6 +130,24 @@ impl Parser {
                 self.next = next;
                 FunctionLocalStatement::For(statement)
             }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Return),
+                ..
+            } => {
+                let (statement, next) =
+                    ReturnStatementParser::default().parse(stream.clone(), Some(token))?;
+                return Ok((FunctionLocalStatement::Return(statement), next, false));
+            }
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Break),
+                location,
+            } => FunctionLocalStatement::Break(location),
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Continue),
+                location,
+            } => FunctionLocalStatement::Continue(location),
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Semicolon),
                 location,
@@ -93,6 +169,24 @@ impl Parser {
                     token => Ok((statement, Some(token), true)),
                 }
             }
+            statement @ FunctionLocalStatement::Break { .. }
+            | statement @ FunctionLocalStatement::Continue { .. } => {
+                match crate::parser::take_or_next(self.next.take(), stream)? {
+                    Token {
+                        lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                        ..
+                    } => Ok((statement, None, false)),
+                    token
+                    @
+                    Token {
+                        lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                        ..
+                    } => Ok((statement, Some(token), false)),
+                    Token { lexeme, location } => Err(ParsingError::Syntax(
+                        SyntaxError::expected_one_of(location, vec![";"], lexeme, None),
+                    )),
+                }
+            }
             statement => Ok((statement, None, false)),
         }
     }
@@ -101,12 +195,18 @@ impl Parser {
 #[cfg(test)]
 mod tests {
     use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+    use zinc_lexical::Keyword;
     use zinc_lexical::Lexeme;
+    use zinc_lexical::Literal as LexicalLiteral;
     use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
     use zinc_lexical::Token;
     use zinc_lexical::TokenStream;
 
     use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::attribute::Attribute;
     use crate::tree::binding::Binding;
     use crate::tree::expression::block::Expression as BlockExpression;
     use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
@@ -120,6 +220,8 @@ mod tests {
     use crate::tree::r#type::Type;
     use crate::tree::statement::local_fn::Statement as FunctionLocalStatement;
     use crate::tree::statement::r#let::Statement as LetStatement;
+    use crate::tree::statement::r#loop::Statement as LoopStatement;
+    use crate::tree::statement::r#return::Statement as ReturnStatement;
 
     #[test]
     fn ok_semicolon_terminated() {
@@ -190,4 +292,119 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_loop_bounded() {
+        let input = r#"#[bound(8)] loop { break; }"#;
+
+        let expected = Ok((
+            FunctionLocalStatement::Loop(LoopStatement::new(
+                Location::test(1, 13),
+                vec![Attribute::new(
+                    Location::test(1, 1),
+                    false,
+                    Identifier::new(Location::test(1, 3), "bound".to_owned()),
+                    vec![ExpressionTree::new(
+                        Location::test(1, 9),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::test(1, 9),
+                                LexicalIntegerLiteral::new_decimal("8".to_owned()),
+                            ),
+                        )),
+                    )],
+                )],
+                None,
+                BlockExpression::new(
+                    Location::test(1, 18),
+                    vec![FunctionLocalStatement::Break(Location::test(1, 20))],
+                    None,
+                ),
+            )),
+            None,
+            false,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_continue_unterminated() {
+        let input = r#"continue }"#;
+
+        let expected = Ok((
+            FunctionLocalStatement::Continue(Location::test(1, 1)),
+            Some(Token::new(
+                Lexeme::Symbol(Symbol::BracketCurlyRight),
+                Location::test(1, 10),
+            )),
+            false,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_return() {
+        let input = r#"return 42;"#;
+
+        let expected = Ok((
+            FunctionLocalStatement::Return(ReturnStatement::new(
+                Location::test(1, 1),
+                Some(ExpressionTree::new(
+                    Location::test(1, 8),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::test(1, 8),
+                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                        ),
+                    )),
+                )),
+            )),
+            None,
+            false,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_attribute_expected_loop() {
+        let input = r#"#[bound(8)] let a = 42;"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 13),
+            vec!["loop", "while"],
+            Lexeme::Keyword(Keyword::Let),
+            Some(super::HINT_ATTRIBUTES_ONLY_LOOPS),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_break_expected_semicolon() {
+        let input = r#"break 42;"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 7),
+            vec![";"],
+            Lexeme::Literal(LexicalLiteral::Integer(LexicalIntegerLiteral::new_decimal(
+                "42".to_owned(),
+            ))),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -207,6 +207,7 @@ mod tests {
                 true,
                 false,
                 Identifier::new(Location::test(1, 8), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 10),
                     BindingPattern::new(
@@ -240,6 +241,7 @@ mod tests {
                 false,
                 true,
                 Identifier::new(Location::test(1, 10), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 12),
                     BindingPattern::new(
@@ -273,6 +275,7 @@ mod tests {
                 true,
                 true,
                 Identifier::new(Location::test(1, 14), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 16),
                     BindingPattern::new(
@@ -310,12 +313,14 @@ fn test() {}
                 false,
                 Identifier::new(Location::test(3, 4), "test".to_owned()),
                 vec![],
+                vec![],
                 None,
                 BlockExpression::new(Location::test(3, 11), vec![], None),
                 vec![Attribute::new(
                     Location::test(2, 1),
                     false,
                     Identifier::new(Location::test(2, 3), "test".to_owned()),
+                    vec![],
                 )],
             )),
             None,
@@ -342,6 +347,7 @@ fn test() {}
                 false,
                 Identifier::new(Location::test(5, 4), "test".to_owned()),
                 vec![],
+                vec![],
                 None,
                 BlockExpression::new(Location::test(5, 11), vec![], None),
                 vec![
@@ -349,16 +355,19 @@ fn test() {}
                         Location::test(2, 1),
                         false,
                         Identifier::new(Location::test(2, 3), "test".to_owned()),
+                        vec![],
                     ),
                     Attribute::new(
                         Location::test(3, 1),
                         false,
                         Identifier::new(Location::test(3, 3), "should_panic".to_owned()),
+                        vec![],
                     ),
                     Attribute::new(
                         Location::test(4, 1),
                         false,
                         Identifier::new(Location::test(4, 3), "ignore".to_owned()),
+                        vec![],
                     ),
                 ],
             )),
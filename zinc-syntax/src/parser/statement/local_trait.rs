@@ -0,0 +1,159 @@
+//!
+//! The trait-local statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::statement::r#fn::Parser as FnStatementParser;
+use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::statement::local_trait::Statement as TraitLocalStatement;
+
+/// The invalid statement error hint.
+pub static HINT_ONLY_FUNCTIONS: &str =
+    "only method signatures and default methods may be declared within a trait";
+
+///
+/// The trait-local statement parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a statement allowed in traits.
+    ///
+    /// '
+    /// fn hash(self) -> field;
+    /// '
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(TraitLocalStatement, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Fn),
+                ..
+            } => {
+                let location = token.location;
+                let (mut builder, next) =
+                    FnStatementParser::new_trait_method().parse(stream, Some(token))?;
+
+                if builder.has_body() {
+                    Ok((TraitLocalStatement::Fn(builder.finish()), next))
+                } else {
+                    builder.set_body(BlockExpression::new(location, vec![], None));
+                    Ok((TraitLocalStatement::Signature(builder.finish()), next))
+                }
+            }
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                location,
+            } => Ok((TraitLocalStatement::Empty(location), None)),
+            Token { lexeme, location } => Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                location,
+                vec!["fn"],
+                lexeme,
+                Some(HINT_ONLY_FUNCTIONS),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Keyword;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::expression::block::Expression as BlockExpression;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::statement::local_trait::Statement as TraitLocalStatement;
+    use crate::tree::statement::r#fn::Statement as FnStatement;
+
+    #[test]
+    fn ok_signature() {
+        let input = r#"fn f();"#;
+
+        let expected = Ok((
+            TraitLocalStatement::Signature(FnStatement::new(
+                Location::test(1, 1),
+                false,
+                false,
+                Identifier::new(Location::test(1, 4), "f".to_owned()),
+                vec![],
+                vec![],
+                None,
+                BlockExpression::new(Location::test(1, 1), vec![], None),
+                vec![],
+            )),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_default() {
+        let input = r#"fn f() {}"#;
+
+        let expected = Ok((
+            TraitLocalStatement::Fn(FnStatement::new(
+                Location::test(1, 1),
+                false,
+                false,
+                Identifier::new(Location::test(1, 4), "f".to_owned()),
+                vec![],
+                vec![],
+                None,
+                BlockExpression::new(Location::test(1, 8), vec![], None),
+                vec![],
+            )),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_fn() {
+        let input = r#"const A: u8 = 42;"#;
+
+        let expected: Result<_, ParsingError> =
+            Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                Location::test(1, 1),
+                vec!["fn"],
+                Lexeme::Keyword(Keyword::Const),
+                Some(super::HINT_ONLY_FUNCTIONS),
+            )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,229 @@
+//!
+//! The `loop` and `while` statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::expression::terminal::block::Parser as BlockExpressionParser;
+use crate::parser::expression::Parser as ExpressionParser;
+use crate::tree::attribute::Attribute;
+use crate::tree::statement::r#loop::builder::Builder as LoopStatementBuilder;
+use crate::tree::statement::r#loop::Statement as LoopStatement;
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    KeywordLoopOrWhile,
+    /// The `while` has been parsed so far.
+    ConditionExpression,
+    /// The `loop` or `while {expression}` has been parsed so far.
+    BlockExpression,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::KeywordLoopOrWhile
+    }
+}
+
+///
+/// The `loop` and `while` statement parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The builder of the parsed value.
+    builder: LoopStatementBuilder,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a bounded `loop` or `while` statement.
+    ///
+    /// The `attributes` are parsed by the enclosing statement parser.
+    ///
+    /// '
+    /// #[bound(16)]
+    /// while x < 100 {
+    ///     x *= 2;
+    /// }
+    /// '
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+        attributes: Vec<Attribute>,
+    ) -> Result<(LoopStatement, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        self.builder.set_attributes(attributes);
+        loop {
+            match self.state {
+                State::KeywordLoopOrWhile => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Loop),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::BlockExpression;
+                        }
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::While),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::ConditionExpression;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["loop", "while"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::ConditionExpression => {
+                    let (expression, next) =
+                        ExpressionParser::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.builder.set_condition(expression);
+                    self.state = State::BlockExpression;
+                }
+                State::BlockExpression => {
+                    let (expression, next) =
+                        BlockExpressionParser::default().parse(stream, self.next.take())?;
+                    self.builder.set_block(expression);
+                    return Ok((self.builder.finish(), next));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+    use zinc_lexical::Keyword;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::expression::block::Expression as BlockExpression;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::statement::r#loop::Statement as LoopStatement;
+
+    #[test]
+    fn ok_loop() {
+        let input = r#"loop {}"#;
+
+        let expected = Ok((
+            LoopStatement::new(
+                Location::test(1, 1),
+                vec![],
+                None,
+                BlockExpression::new(Location::test(1, 6), vec![], None),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None, vec![]);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_while() {
+        let input = r#"while x < 10 {}"#;
+
+        let expected = Ok((
+            LoopStatement::new(
+                Location::test(1, 1),
+                vec![],
+                Some(ExpressionTree::new_with_leaves(
+                    Location::test(1, 9),
+                    ExpressionTreeNode::operator(ExpressionOperator::Lesser),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 7),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 7), "x".to_owned()),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 11),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::test(1, 11),
+                                LexicalIntegerLiteral::new_decimal("10".to_owned()),
+                            ),
+                        )),
+                    )),
+                )),
+                BlockExpression::new(Location::test(1, 14), vec![], None),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None, vec![]);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_keyword_loop_or_while() {
+        let input = r#"for {}"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 1),
+            vec!["loop", "while"],
+            Lexeme::Keyword(Keyword::For),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None, vec![]);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_curly_left() {
+        let input = r#"loop;"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 5),
+            vec!["{"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None, vec![]);
+
+        assert_eq!(result, expected);
+    }
+}
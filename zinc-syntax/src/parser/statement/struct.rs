@@ -14,6 +14,7 @@ use zinc_lexical::TokenStream;
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
 use crate::parser::field_list::Parser as FieldListParser;
+use crate::parser::generic_parameter_list::Parser as GenericParameterListParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::r#struct::builder::Builder as StructStatementBuilder;
 use crate::tree::statement::r#struct::Statement as StructStatement;
@@ -32,6 +33,8 @@ pub enum State {
     /// The `struct` has been parsed so far.
     Identifier,
     /// The `struct {identifier}` has been parsed so far.
+    GenericsOrBracketCurlyLeftOrEnd,
+    /// The `struct {identifier}` with optional `<{generics}>` has been parsed so far.
     BracketCurlyLeftOrEnd,
     /// The `struct {identifier} {` has been parsed so far.
     FieldList,
@@ -70,6 +73,13 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// struct Pair<T> {
+    ///     a: T,
+    ///     b: T,
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -106,7 +116,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::BracketCurlyLeftOrEnd;
+                            self.state = State::GenericsOrBracketCurlyLeftOrEnd;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
@@ -117,6 +127,23 @@ impl Parser {
                         }
                     }
                 }
+                State::GenericsOrBracketCurlyLeftOrEnd => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => {
+                            let (generics, next) = GenericParameterListParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.builder.set_generics(generics);
+                            self.next = next;
+                        }
+                        token => self.next = Some(token),
+                    }
+                    self.state = State::BracketCurlyLeftOrEnd;
+                }
                 State::BracketCurlyLeftOrEnd => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -162,7 +189,11 @@ mod tests {
     use super::Parser;
     use crate::error::Error as SyntaxError;
     use crate::error::ParsingError;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::field::Field;
+    use crate::tree::generic_parameter::GenericParameter;
     use crate::tree::identifier::Identifier;
     use crate::tree::r#type::variant::Variant as TypeVariant;
     use crate::tree::r#type::Type;
@@ -179,6 +210,46 @@ mod tests {
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 12), "Test".to_owned()),
                 vec![],
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_generic() {
+        let input = r#"struct Pair<T> { a: T }"#;
+
+        let expected = Ok((
+            StructStatement::new(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 8), "Pair".to_owned()),
+                vec![GenericParameter::new(
+                    Location::test(1, 13),
+                    Identifier::new(Location::test(1, 13), "T".to_owned()),
+                    None,
+                    vec![],
+                )],
+                vec![Field::new(
+                    Location::test(1, 18),
+                    Identifier::new(Location::test(1, 18), "a".to_owned()),
+                    Type::new(
+                        Location::test(1, 21),
+                        TypeVariant::alias(
+                            ExpressionTree::new(
+                                Location::test(1, 21),
+                                ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                    Identifier::new(Location::test(1, 21), "T".to_owned()),
+                                )),
+                            ),
+                            None,
+                        ),
+                    ),
+                )],
             ),
             None,
         ));
@@ -199,6 +270,7 @@ mod tests {
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 12), "Test".to_owned()),
                 vec![],
+                vec![],
             ),
             Some(Token::new(
                 Lexeme::Symbol(Symbol::Semicolon),
@@ -223,6 +295,7 @@ mod tests {
             StructStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 12), "Test".to_owned()),
+                vec![],
                 vec![Field::new(
                     Location::test(3, 9),
                     Identifier::new(Location::test(3, 9), "a".to_owned()),
@@ -251,6 +324,7 @@ mod tests {
             StructStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 12), "Test".to_owned()),
+                vec![],
                 vec![
                     Field::new(
                         Location::test(3, 9),
@@ -0,0 +1,278 @@
+//!
+//! The `trait` statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::statement::local_trait::Parser as TraitLocalStatementParser;
+use crate::tree::identifier::Identifier;
+use crate::tree::statement::r#trait::builder::Builder as TraitStatementBuilder;
+use crate::tree::statement::r#trait::Statement as TraitStatement;
+
+/// The missing identifier error hint.
+pub static HINT_EXPECTED_IDENTIFIER: &str =
+    "trait must have an identifier, e.g. `trait Hashable { ... }`";
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    KeywordTrait,
+    /// The `trait` has been parsed so far.
+    Identifier,
+    /// The `trait {identifier}` has been parsed so far.
+    BracketCurlyLeft,
+    /// The `trait {identifier} {` has been parsed so far.
+    StatementOrBracketCurlyRight,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::KeywordTrait
+    }
+}
+
+///
+/// The `trait` statement parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The builder of the parsed value.
+    builder: TraitStatementBuilder,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a 'trait' statement.
+    ///
+    /// '
+    /// trait Hashable {
+    ///     fn hash(self) -> field;
+    ///     fn is_zero(self) -> bool {
+    ///         self.hash() == 0
+    ///     }
+    /// }
+    /// '
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(TraitStatement, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::KeywordTrait => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Trait),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::Identifier;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["trait"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Identifier => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_identifier(identifier);
+                            self.state = State::BracketCurlyLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::BracketCurlyLeft => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => {
+                            self.state = State::StatementOrBracketCurlyRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["{"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::StatementOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (statement, next) = TraitLocalStatementParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.push_statement(statement);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Keyword;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::binding::Binding;
+    use crate::tree::expression::block::Expression as BlockExpression;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+    use crate::tree::pattern_binding::Pattern as BindingPattern;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+    use crate::tree::statement::local_trait::Statement as TraitLocalStatement;
+    use crate::tree::statement::r#fn::Statement as FnStatement;
+    use crate::tree::statement::r#trait::Statement as TraitStatement;
+
+    #[test]
+    fn ok_empty() {
+        let input = r#"
+    trait Test {}
+"#;
+
+        let expected = Ok((
+            TraitStatement::new(
+                Location::test(2, 5),
+                Identifier::new(Location::test(2, 11), "Test".to_owned()),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_signature_and_default() {
+        let input = r#"
+    trait Test {
+        fn f(self) -> u8;
+        fn g() {}
+    }
+"#;
+
+        let expected = Ok((
+            TraitStatement::new(
+                Location::test(2, 5),
+                Identifier::new(Location::test(2, 11), "Test".to_owned()),
+                vec![
+                    TraitLocalStatement::Signature(FnStatement::new(
+                        Location::test(3, 9),
+                        false,
+                        false,
+                        Identifier::new(Location::test(3, 12), "f".to_owned()),
+                        vec![],
+                        vec![Binding::new(
+                            Location::test(3, 14),
+                            BindingPattern::new(
+                                Location::test(3, 14),
+                                BindingPatternVariant::new_binding(
+                                    Identifier::new(
+                                        Location::test(3, 14),
+                                        Keyword::SelfLowercase.to_string(),
+                                    ),
+                                    false,
+                                ),
+                            ),
+                            None,
+                        )],
+                        Some(Type::new(
+                            Location::test(3, 23),
+                            TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                        )),
+                        BlockExpression::new(Location::test(3, 9), vec![], None),
+                        vec![],
+                    )),
+                    TraitLocalStatement::Fn(FnStatement::new(
+                        Location::test(4, 9),
+                        false,
+                        false,
+                        Identifier::new(Location::test(4, 12), "g".to_owned()),
+                        vec![],
+                        vec![],
+                        None,
+                        BlockExpression::new(Location::test(4, 16), vec![], None),
+                        vec![],
+                    )),
+                ],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_curly_left() {
+        let input = r#"
+    trait Test;
+"#;
+
+        let expected: Result<_, ParsingError> =
+            Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                Location::test(2, 15),
+                vec!["{"],
+                Lexeme::Symbol(Symbol::Semicolon),
+                None,
+            )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
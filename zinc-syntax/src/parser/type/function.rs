@@ -0,0 +1,263 @@
+//!
+//! The function type parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::r#type::Parser as TypeParser;
+use crate::tree::r#type::builder::Builder as TypeBuilder;
+use crate::tree::r#type::Type;
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    KeywordFn,
+    /// The `fn` has been parsed so far.
+    ParenthesisLeft,
+    /// The `fn (` has been parsed so far.
+    TypeOrParenthesisRight,
+    /// The `fn ( {type}` has been parsed so far.
+    CommaOrParenthesisRight,
+    /// The `fn ( ... )` has been parsed so far.
+    ArrowOrEnd,
+    /// The `fn ( ... ) ->` has been parsed so far.
+    ReturnType,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::KeywordFn
+    }
+}
+
+///
+/// The function type parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+    /// The function argument types.
+    arguments: Vec<Type>,
+    /// The builder of the parsed type.
+    builder: TypeBuilder,
+}
+
+impl Parser {
+    ///
+    /// Parses a function type literal.
+    ///
+    /// 'fn(u8, field) -> bool'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(Type, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::KeywordFn => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Fn),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::ParenthesisLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["fn"],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    }
+                }
+                State::ParenthesisLeft => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => {
+                            self.state = State::TypeOrParenthesisRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["("],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    }
+                }
+                State::TypeOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => {
+                            self.state = State::ArrowOrEnd;
+                        }
+                        token => {
+                            let (argument_type, next) =
+                                TypeParser::default().parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.arguments.push(argument_type);
+                            self.state = State::CommaOrParenthesisRight;
+                        }
+                    }
+                }
+                State::CommaOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::TypeOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => self.state = State::ArrowOrEnd,
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    }
+                }
+                State::ArrowOrEnd => {
+                    self.builder
+                        .set_function_argument_types(std::mem::take(&mut self.arguments));
+
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::MinusGreater),
+                            ..
+                        } => self.state = State::ReturnType,
+                        token => return Ok((self.builder.finish(), Some(token))),
+                    }
+                }
+                State::ReturnType => {
+                    let (return_type, next) =
+                        TypeParser::default().parse(stream.clone(), self.next.take())?;
+                    self.builder.set_function_return_type(return_type);
+
+                    return Ok((self.builder.finish(), next));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::BooleanLiteral as LexicalBooleanLiteral;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Literal as LexicalLiteral;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::Token;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+
+    #[test]
+    fn ok_empty() {
+        let input = r#"fn()"#;
+
+        let expected = Ok((
+            Type::new(
+                Location::test(1, 1),
+                TypeVariant::function(vec![], Type::new(Location::test(1, 1), TypeVariant::unit())),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 5))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_arguments_and_return_type() {
+        let input = r#"fn(u8, field) -> bool"#;
+
+        let expected = Ok((
+            Type::new(
+                Location::test(1, 1),
+                TypeVariant::function(
+                    vec![
+                        Type::new(
+                            Location::test(1, 4),
+                            TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                        ),
+                        Type::new(Location::test(1, 8), TypeVariant::field()),
+                    ],
+                    Type::new(Location::test(1, 18), TypeVariant::boolean()),
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_parenthesis_left() {
+        let input = r#"fn true"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 4),
+            vec!["("],
+            Lexeme::Literal(LexicalLiteral::Boolean(LexicalBooleanLiteral::True)),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_parenthesis_right() {
+        let input = r#"fn(u8;)"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 6),
+            vec![",", ")"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -3,6 +3,7 @@
 //!
 
 pub mod array;
+pub mod function;
 pub mod generics;
 pub mod path;
 pub mod tuple;
@@ -22,6 +23,7 @@ use crate::tree::r#type::builder::Builder as TypeBuilder;
 use crate::tree::r#type::Type;
 
 use self::array::Parser as ArrayParser;
+use self::function::Parser as FunctionParser;
 use self::generics::Parser as GenericsParser;
 use self::path::Parser as PathParser;
 use self::tuple::Parser as TupleParser;
@@ -44,6 +46,7 @@ impl Parser {
     /// 'bool'
     /// '[u8; 16]'
     /// '(u8, field, bool)'
+    /// 'fn(u8, field) -> bool'
     /// 'Path::To::Type`
     ///
     pub fn parse(
@@ -105,6 +108,12 @@ impl Parser {
                     token => Ok((self.builder.finish(), Some(token))),
                 }
             }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Fn),
+                ..
+            } => FunctionParser::default().parse(stream.clone(), Some(token)),
             Token {
                 lexeme: Lexeme::Keyword(keyword),
                 location,
@@ -13,24 +13,57 @@ use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::field_list::Parser as FieldListParser;
+use crate::parser::r#type::Parser as TypeParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
+use crate::tree::r#type::Type;
 use crate::tree::variant::builder::Builder as VariantBuilder;
+use crate::tree::variant::data::Data as VariantData;
 use crate::tree::variant::Variant;
 
 /// The missing identifier error hint.
 pub static HINT_EXPECTED_IDENTIFIER: &str =
     "enumeration variant must have an identifier, e.g. `Value = 42`";
-/// The missing value error hint.
-pub static HINT_EXPECTED_VALUE: &str = "enumeration variant must be initialized, e.g. `Value = 42`";
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    Identifier,
+    /// The identifier has been parsed so far.
+    EqualsOrDataOrEnd,
+    /// The identifier and `=` have been parsed so far.
+    IntegerLiteral,
+    /// The identifier and `(` have been parsed so far.
+    TupleTypeOrParenthesisRight,
+    /// The identifier, `(`, and a type have been parsed so far.
+    CommaOrParenthesisRight,
+    /// The identifier and `{` have been parsed so far.
+    FieldList,
+    /// The identifier, `{`, and the field list have been parsed so far.
+    BracketCurlyRight,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::Identifier
+    }
+}
 
 ///
 /// The variant parser.
 ///
 #[derive(Default)]
 pub struct Parser {
+    /// The parser state.
+    state: State,
     /// The builder of the parsed value.
     builder: VariantBuilder,
+    /// The tuple-like variant data types.
+    types: Vec<Type>,
     /// The token returned from a subparser.
     next: Option<Token>,
 }
@@ -40,6 +73,9 @@ impl Parser {
     /// Parses an enum variant.
     ///
     /// 'A = 1'
+    /// 'A'
+    /// 'A(u8, field)'
+    /// 'A { a: u8, b: field }'
     ///
     pub fn parse(
         mut self,
@@ -48,51 +84,121 @@ impl Parser {
     ) -> Result<(Variant, Option<Token>), ParsingError> {
         self.next = initial;
 
-        match crate::parser::take_or_next(self.next.take(), stream.clone())? {
-            Token {
-                lexeme: Lexeme::Identifier(identifier),
-                location,
-            } => {
-                let identifier = Identifier::new(location, identifier.inner);
-                self.builder.set_location(location);
-                self.builder.set_identifier(identifier);
-            }
-            Token { lexeme, location } => {
-                return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
-                    location,
-                    lexeme,
-                    Some(HINT_EXPECTED_IDENTIFIER),
-                )));
+        loop {
+            match self.state {
+                State::Identifier => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_location(location);
+                            self.builder.set_identifier(identifier);
+                            self.state = State::EqualsOrDataOrEnd;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::EqualsOrDataOrEnd => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Equals),
+                            ..
+                        } => self.state = State::IntegerLiteral,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => self.state = State::TupleTypeOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => self.state = State::FieldList,
+                        token => return Ok((self.builder.finish(), Some(token))),
+                    }
+                }
+                State::IntegerLiteral => {
+                    return match crate::parser::take_or_next(self.next.take(), stream)? {
+                        Token {
+                            lexeme: Lexeme::Literal(LexicalLiteral::Integer(literal)),
+                            location,
+                        } => {
+                            self.builder
+                                .set_literal(IntegerLiteral::new(location, literal));
+                            Ok((self.builder.finish(), None))
+                        }
+                        Token { lexeme, location } => Err(ParsingError::Syntax(
+                            SyntaxError::expected_integer_literal(location, lexeme),
+                        )),
+                    };
+                }
+                State::TupleTypeOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => {
+                            self.builder.set_data(VariantData::Tuple(self.types));
+                            return Ok((self.builder.finish(), None));
+                        }
+                        token => {
+                            let (r#type, next) =
+                                TypeParser::default().parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.types.push(r#type);
+                            self.state = State::CommaOrParenthesisRight;
+                        }
+                    }
+                }
+                State::CommaOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::TupleTypeOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => {
+                            self.builder.set_data(VariantData::Tuple(self.types));
+                            return Ok((self.builder.finish(), None));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::FieldList => {
+                    let (fields, next) =
+                        FieldListParser::default().parse(stream.clone(), self.next.take())?;
+                    self.builder.set_data(VariantData::Structure(fields));
+                    self.next = next;
+                    self.state = State::BracketCurlyRight;
+                }
+                State::BracketCurlyRight => {
+                    return match crate::parser::take_or_next(self.next.take(), stream)? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => Err(ParsingError::Syntax(
+                            SyntaxError::expected_one_of(location, vec!["}"], lexeme, None),
+                        )),
+                    };
+                }
             }
         }
-
-        match crate::parser::take_or_next(self.next.take(), stream.clone())? {
-            Token {
-                lexeme: Lexeme::Symbol(Symbol::Equals),
-                ..
-            } => {}
-            Token { lexeme, location } => {
-                return Err(ParsingError::Syntax(SyntaxError::expected_value(
-                    location,
-                    lexeme,
-                    Some(HINT_EXPECTED_VALUE),
-                )));
-            }
-        }
-
-        match crate::parser::take_or_next(self.next.take(), stream)? {
-            Token {
-                lexeme: Lexeme::Literal(LexicalLiteral::Integer(literal)),
-                location,
-            } => {
-                self.builder
-                    .set_literal(IntegerLiteral::new(location, literal));
-                Ok((self.builder.finish(), self.next.take()))
-            }
-            Token { lexeme, location } => Err(ParsingError::Syntax(
-                SyntaxError::expected_integer_literal(location, lexeme),
-            )),
-        }
     }
 }
 
@@ -102,13 +208,19 @@ mod tests {
     use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::Token;
     use zinc_lexical::TokenStream;
 
     use super::Parser;
     use crate::error::Error as SyntaxError;
     use crate::error::ParsingError;
+    use crate::tree::field::Field;
     use crate::tree::identifier::Identifier;
     use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+    use crate::tree::variant::data::Data as VariantData;
     use crate::tree::variant::Variant;
 
     #[test]
@@ -133,13 +245,97 @@ mod tests {
     }
 
     #[test]
-    fn error_expected_value() {
+    fn ok_unit() {
         let input = r#"A"#;
 
-        let expected = Err(ParsingError::Syntax(SyntaxError::expected_value(
-            Location::test(1, 2),
-            Lexeme::Eof,
-            Some(super::HINT_EXPECTED_VALUE),
+        let expected = Ok((
+            Variant::new_unit(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "A".to_owned()),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 2))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_tuple() {
+        let input = r#"A(u8, field)"#;
+
+        let expected = Ok((
+            Variant::new_with_data(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "A".to_owned()),
+                VariantData::Tuple(vec![
+                    Type::new(Location::test(1, 3), TypeVariant::integer_unsigned(8)),
+                    Type::new(Location::test(1, 7), TypeVariant::field()),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_structure() {
+        let input = r#"A { a: u8, b: field }"#;
+
+        let expected = Ok((
+            Variant::new_with_data(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "A".to_owned()),
+                VariantData::Structure(vec![
+                    Field::new(
+                        Location::test(1, 5),
+                        Identifier::new(Location::test(1, 5), "a".to_owned()),
+                        Type::new(Location::test(1, 8), TypeVariant::integer_unsigned(8)),
+                    ),
+                    Field::new(
+                        Location::test(1, 12),
+                        Identifier::new(Location::test(1, 12), "b".to_owned()),
+                        Type::new(Location::test(1, 15), TypeVariant::field()),
+                    ),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_parenthesis_right() {
+        let input = r#"A(u8; field)"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 5),
+            vec![",", ")"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_curly_right() {
+        let input = r#"A { a: u8;"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 10),
+            vec!["}"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
         )));
 
         let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
@@ -30,6 +30,7 @@ impl Parser {
     /// Parses an enum variant list.
     ///
     /// 'A = 1, B = 2, C = 3'
+    /// 'A, B(u8, field), C { a: u8 }'
     ///
     pub fn parse(
         mut self,
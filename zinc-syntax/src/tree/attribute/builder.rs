@@ -5,6 +5,7 @@
 use zinc_lexical::Location;
 
 use crate::tree::attribute::Attribute;
+use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
 
 ///
@@ -18,6 +19,8 @@ pub struct Builder {
     is_inner: bool,
     /// The attribute identifier.
     identifier: Option<Identifier>,
+    /// The optional attribute arguments, e.g. `N` in `#[bound(N)]`.
+    arguments: Vec<ExpressionTree>,
 }
 
 impl Builder {
@@ -42,6 +45,13 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    pub fn push_argument(&mut self, value: ExpressionTree) {
+        self.arguments.push(value);
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -65,6 +75,6 @@ impl Builder {
             )
         });
 
-        Attribute::new(location, self.is_inner, identifier)
+        Attribute::new(location, self.is_inner, identifier, self.arguments)
     }
 }
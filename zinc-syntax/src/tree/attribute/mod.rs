@@ -6,6 +6,7 @@ pub mod builder;
 
 use zinc_lexical::Location;
 
+use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
 
 ///
@@ -19,17 +20,25 @@ pub struct Attribute {
     pub is_inner: bool,
     /// The attribute identifier.
     pub identifier: Identifier,
+    /// The optional attribute arguments, e.g. `N` in `#[bound(N)]`.
+    pub arguments: Vec<ExpressionTree>,
 }
 
 impl Attribute {
     ///
     /// Creates the attribute value.
     ///
-    pub fn new(location: Location, is_inner: bool, identifier: Identifier) -> Self {
+    pub fn new(
+        location: Location,
+        is_inner: bool,
+        identifier: Identifier,
+        arguments: Vec<ExpressionTree>,
+    ) -> Self {
         Self {
             location,
             is_inner,
             identifier,
+            arguments,
         }
     }
 }
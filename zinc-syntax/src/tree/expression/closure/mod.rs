@@ -0,0 +1,36 @@
+//!
+//! The closure expression.
+//!
+
+pub mod builder;
+
+use zinc_lexical::Location;
+
+use crate::tree::binding::Binding;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+
+///
+/// The closure expression.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The closure argument bindings, whose types may be omitted.
+    pub arguments: Vec<Binding>,
+    /// The closure body expression.
+    pub body: Box<ExpressionTree>,
+}
+
+impl Expression {
+    ///
+    /// Creates a closure expression.
+    ///
+    pub fn new(location: Location, arguments: Vec<Binding>, body: ExpressionTree) -> Self {
+        Self {
+            location,
+            arguments,
+            body: Box::new(body),
+        }
+    }
+}
@@ -4,6 +4,7 @@
 
 pub mod array;
 pub mod block;
+pub mod closure;
 pub mod conditional;
 pub mod list;
 pub mod r#match;
@@ -6,6 +6,7 @@ use zinc_lexical::Location;
 
 use crate::tree::expression::array::Expression as ArrayExpression;
 use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::expression::closure::Expression as ClosureExpression;
 use crate::tree::expression::conditional::Expression as ConditionalExpression;
 use crate::tree::expression::list::Expression as ListExpression;
 use crate::tree::expression::r#match::Expression as MatchExpression;
@@ -51,6 +52,8 @@ pub enum Operand {
     Conditional(ConditionalExpression),
     /// A match expression `match value { 1 => 10, _ => 42 }`.
     Match(MatchExpression),
+    /// A closure expression `|x| x + 1`.
+    Closure(ClosureExpression),
 }
 
 impl Operand {
@@ -0,0 +1,81 @@
+//!
+//! The generic parameter builder.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::generic_parameter::GenericParameter;
+use crate::tree::identifier::Identifier;
+use crate::tree::r#type::Type;
+
+///
+/// The generic parameter builder.
+///
+#[derive(Default)]
+pub struct Builder {
+    /// The location of the syntax construction.
+    location: Option<Location>,
+    /// The generic parameter identifier.
+    identifier: Option<Identifier>,
+    /// The constant parameter type.
+    r#type: Option<Type>,
+    /// The type parameter trait bounds.
+    bounds: Vec<Identifier>,
+}
+
+impl Builder {
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_identifier(&mut self, value: Identifier) {
+        self.identifier = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_type(&mut self, value: Type) {
+        self.r#type = Some(value);
+    }
+
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    pub fn push_bound(&mut self, value: Identifier) {
+        self.bounds.push(value);
+    }
+
+    ///
+    /// Finalizes the builder and returns the built value.
+    ///
+    /// # Panics
+    /// If some of the required items has not been set.
+    ///
+    pub fn finish(&mut self) -> GenericParameter {
+        GenericParameter::new(
+            self.location.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "location"
+                )
+            }),
+            self.identifier.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "identifier"
+                )
+            }),
+            self.r#type.take(),
+            std::mem::take(&mut self.bounds),
+        )
+    }
+}
@@ -0,0 +1,51 @@
+//!
+//! The generic parameter.
+//!
+
+pub mod builder;
+
+use zinc_lexical::Location;
+
+use crate::tree::identifier::Identifier;
+use crate::tree::r#type::Type;
+
+///
+/// The generic parameter, e.g. `T: Ord` or `const N: usize` in `fn sort<T: Ord, const N: usize>(...)`.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenericParameter {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The generic parameter identifier.
+    pub identifier: Identifier,
+    /// The constant parameter type. `None` if the parameter is a type one.
+    pub r#type: Option<Type>,
+    /// The trait bounds of the type parameter, e.g. `Hashable` in `T: Hashable`.
+    pub bounds: Vec<Identifier>,
+}
+
+impl GenericParameter {
+    ///
+    /// Creates a generic parameter.
+    ///
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        r#type: Option<Type>,
+        bounds: Vec<Identifier>,
+    ) -> Self {
+        Self {
+            location,
+            identifier,
+            r#type,
+            bounds,
+        }
+    }
+
+    ///
+    /// Checks whether the parameter is a constant one, that is, declared with `const`.
+    ///
+    pub fn is_constant(&self) -> bool {
+        self.r#type.is_some()
+    }
+}
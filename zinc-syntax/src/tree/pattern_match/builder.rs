@@ -29,6 +29,12 @@ pub struct Builder {
     binding: Option<Identifier>,
     /// The path builder variant, which means that the pattern is a path expression.
     path_builder: ExpressionTreeBuilder,
+    /// The nested patterns, which are set if the path is followed by parentheses.
+    nested_patterns: Option<Vec<MatchPattern>>,
+    /// The nested field patterns, which are set if the path is followed by curly brackets.
+    nested_fields: Option<Vec<(Identifier, MatchPattern)>>,
+    /// If the structure-like pattern contains the `..` rest pattern.
+    has_rest: bool,
     /// If the pattern variant is a wildcard.
     is_wildcard: bool,
 }
@@ -78,6 +84,47 @@ impl Builder {
         self.path_builder.eat(tree);
     }
 
+    ///
+    /// Sets the tuple-like pattern flag, which means that the path is followed by parentheses.
+    ///
+    pub fn set_tuple_struct(&mut self) {
+        self.move_binding_to_path();
+        self.nested_patterns = Some(Vec::new());
+    }
+
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    pub fn push_nested_pattern(&mut self, pattern: MatchPattern) {
+        self.nested_patterns
+            .get_or_insert_with(Vec::new)
+            .push(pattern);
+    }
+
+    ///
+    /// Sets the structure-like pattern flag, which means that the path is followed by curly brackets.
+    ///
+    pub fn set_structure(&mut self) {
+        self.move_binding_to_path();
+        self.nested_fields = Some(Vec::new());
+    }
+
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    pub fn push_nested_field(&mut self, identifier: Identifier, pattern: MatchPattern) {
+        self.nested_fields
+            .get_or_insert_with(Vec::new)
+            .push((identifier, pattern));
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_rest(&mut self) {
+        self.has_rest = true;
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -108,13 +155,24 @@ impl Builder {
             MatchPatternVariant::IntegerLiteral(integer_literal)
         } else if let Some(identifier) = self.binding.take() {
             MatchPatternVariant::Binding(identifier)
+        } else if let Some(patterns) = self.nested_patterns.take() {
+            MatchPatternVariant::TupleStruct {
+                path: self.path_builder.finish(),
+                patterns,
+            }
+        } else if let Some(fields) = self.nested_fields.take() {
+            MatchPatternVariant::Structure {
+                path: self.path_builder.finish(),
+                fields,
+                has_rest: self.has_rest,
+            }
         } else if !self.path_builder.is_empty() {
             MatchPatternVariant::Path(self.path_builder.finish())
         } else {
             panic!(
                 "{}{}",
                 zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                "boolean | integer | binding | path | wildcard"
+                "boolean | integer | binding | path | tuple struct | structure | wildcard"
             );
         };
 
@@ -6,6 +6,7 @@ use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
+use crate::tree::pattern_match::Pattern;
 
 ///
 /// The match pattern variant.
@@ -20,6 +21,22 @@ pub enum Variant {
     Binding(Identifier),
     /// An expression path refutable pattern, usually points to a constant or enumeration variant.
     Path(ExpressionTree),
+    /// A tuple-like refutable pattern with nested patterns, e.g. `Some(value)`.
+    TupleStruct {
+        /// The path to the tuple-like item, e.g. `Some`.
+        path: ExpressionTree,
+        /// The nested patterns enclosed in parentheses.
+        patterns: Vec<Pattern>,
+    },
+    /// A structure-like refutable pattern with nested field patterns, e.g. `Message::Set { key, .. }`.
+    Structure {
+        /// The path to the structure-like item, e.g. `Message::Set`.
+        path: ExpressionTree,
+        /// The field identifiers with their nested patterns enclosed in curly brackets.
+        fields: Vec<(Identifier, Pattern)>,
+        /// Whether the `..` rest pattern is specified, which allows omitting some fields.
+        has_rest: bool,
+    },
     /// A wildcard irrefutable pattern.
     Wildcard,
 }
@@ -53,6 +70,28 @@ impl Variant {
         Self::Path(expression)
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_tuple_struct(path: ExpressionTree, patterns: Vec<Pattern>) -> Self {
+        Self::TupleStruct { path, patterns }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_structure(
+        path: ExpressionTree,
+        fields: Vec<(Identifier, Pattern)>,
+        has_rest: bool,
+    ) -> Self {
+        Self::Structure {
+            path,
+            fields,
+            has_rest,
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
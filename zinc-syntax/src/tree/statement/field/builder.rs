@@ -4,6 +4,7 @@
 
 use zinc_lexical::Location;
 
+use crate::tree::attribute::Attribute;
 use crate::tree::identifier::Identifier;
 use crate::tree::r#type::Type;
 use crate::tree::statement::field::Statement as FieldStatement;
@@ -21,6 +22,8 @@ pub struct Builder {
     identifier: Option<Identifier>,
     /// The contract storage field type.
     r#type: Option<Type>,
+    /// The contract storage field outer attributes.
+    attributes: Vec<Attribute>,
 }
 
 impl Builder {
@@ -52,6 +55,13 @@ impl Builder {
         self.r#type = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_attributes(&mut self, value: Vec<Attribute>) {
+        self.attributes = value;
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -78,6 +88,7 @@ impl Builder {
             self.r#type.take().unwrap_or_else(|| {
                 panic!("{}{}", zinc_const::panic::BUILDER_REQUIRES_VALUE, "type")
             }),
+            self.attributes,
         )
     }
 }
@@ -6,6 +6,7 @@ pub mod builder;
 
 use zinc_lexical::Location;
 
+use crate::tree::attribute::Attribute;
 use crate::tree::identifier::Identifier;
 use crate::tree::r#type::Type;
 
@@ -22,18 +23,27 @@ pub struct Statement {
     pub identifier: Identifier,
     /// The contract storage field type.
     pub r#type: Type,
+    /// The contract storage field outer attributes.
+    pub attributes: Vec<Attribute>,
 }
 
 impl Statement {
     ///
     /// Creates a contract storage `field` statement.
     ///
-    pub fn new(location: Location, is_public: bool, identifier: Identifier, r#type: Type) -> Self {
+    pub fn new(
+        location: Location,
+        is_public: bool,
+        identifier: Identifier,
+        r#type: Type,
+        attributes: Vec<Attribute>,
+    ) -> Self {
         Self {
             location,
             is_public,
             identifier,
             r#type,
+            attributes,
         }
     }
 }
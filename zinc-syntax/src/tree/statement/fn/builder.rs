@@ -7,6 +7,7 @@ use zinc_lexical::Location;
 use crate::tree::attribute::Attribute;
 use crate::tree::binding::Binding;
 use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::generic_parameter::GenericParameter;
 use crate::tree::identifier::Identifier;
 use crate::tree::r#type::Type;
 use crate::tree::statement::r#fn::Statement as FnStatement;
@@ -24,6 +25,8 @@ pub struct Builder {
     is_constant: bool,
     /// The function identifier.
     identifier: Option<Identifier>,
+    /// The function generic parameters.
+    generics: Vec<GenericParameter>,
     /// The function argument bindings.
     argument_bindings: Vec<Binding>,
     /// The optional function return type, which is `()` if not specified.
@@ -63,6 +66,13 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_generics(&mut self, value: Vec<GenericParameter>) {
+        self.generics = value;
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -91,6 +101,15 @@ impl Builder {
         self.attributes = value;
     }
 
+    ///
+    /// Checks if the function body has been parsed.
+    ///
+    /// The body may be missing only in trait method signatures.
+    ///
+    pub fn has_body(&self) -> bool {
+        self.body.is_some()
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -115,6 +134,7 @@ impl Builder {
                     "identifier"
                 )
             }),
+            self.generics,
             self.argument_bindings,
             self.return_type.take(),
             self.body.take().unwrap_or_else(|| {
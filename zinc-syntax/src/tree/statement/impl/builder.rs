@@ -17,6 +17,8 @@ pub struct Builder {
     location: Option<Location>,
     /// The identifier of the implemented type.
     identifier: Option<Identifier>,
+    /// The identifier of the implemented trait.
+    trait_identifier: Option<Identifier>,
     /// The implementation statements.
     statements: Vec<ImplementationLocalStatement>,
 }
@@ -36,6 +38,16 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    /// The identifier parsed before `for` is moved to the trait identifier, since it was not
+    /// known to be a trait until then.
+    ///
+    pub fn set_trait(&mut self) {
+        self.trait_identifier = self.identifier.take();
+    }
+
     ///
     /// Pushes the corresponding builder value.
     ///
@@ -65,6 +77,7 @@ impl Builder {
                     "identifier"
                 )
             }),
+            self.trait_identifier.take(),
             self.statements,
         )
     }
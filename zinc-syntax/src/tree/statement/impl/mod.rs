@@ -18,6 +18,8 @@ pub struct Statement {
     pub location: Location,
     /// The identifier of the implemented type.
     pub identifier: Identifier,
+    /// The identifier of the implemented trait, e.g. `Hashable` in `impl Hashable for Data`.
+    pub trait_identifier: Option<Identifier>,
     /// The implementation statements.
     pub statements: Vec<ImplementationLocalStatement>,
 }
@@ -29,11 +31,13 @@ impl Statement {
     pub fn new(
         location: Location,
         identifier: Identifier,
+        trait_identifier: Option<Identifier>,
         statements: Vec<ImplementationLocalStatement>,
     ) -> Self {
         Self {
             location,
             identifier,
+            trait_identifier,
             statements,
         }
     }
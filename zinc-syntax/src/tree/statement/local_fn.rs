@@ -8,6 +8,8 @@ use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::statement::r#const::Statement as ConstStatement;
 use crate::tree::statement::r#for::Statement as ForStatement;
 use crate::tree::statement::r#let::Statement as LetStatement;
+use crate::tree::statement::r#loop::Statement as LoopStatement;
+use crate::tree::statement::r#return::Statement as ReturnStatement;
 
 ///
 /// The function-or-block-level statement.
@@ -20,6 +22,14 @@ pub enum Statement {
     Const(ConstStatement),
     /// The `for` statement.
     For(ForStatement),
+    /// The bounded `loop` or `while` statement.
+    Loop(LoopStatement),
+    /// The `break` statement.
+    Break(Location),
+    /// The `continue` statement.
+    Continue(Location),
+    /// The `return` statement.
+    Return(ReturnStatement),
     /// The empty `;` statement.
     Empty(Location),
     /// The expression statement.
@@ -35,6 +45,10 @@ impl Statement {
             Self::Let(inner) => inner.location,
             Self::Const(inner) => inner.location,
             Self::For(inner) => inner.location,
+            Self::Loop(inner) => inner.location,
+            Self::Break(location) => *location,
+            Self::Continue(location) => *location,
+            Self::Return(inner) => inner.location,
             Self::Empty(location) => *location,
             Self::Expression(inner) => inner.location,
         }
@@ -11,6 +11,7 @@ use crate::tree::statement::r#enum::Statement as EnumStatement;
 use crate::tree::statement::r#fn::Statement as FnStatement;
 use crate::tree::statement::r#impl::Statement as ImplStatement;
 use crate::tree::statement::r#struct::Statement as StructStatement;
+use crate::tree::statement::r#trait::Statement as TraitStatement;
 use crate::tree::statement::r#type::Statement as TypeStatement;
 use crate::tree::statement::r#use::Statement as UseStatement;
 
@@ -35,6 +36,8 @@ pub enum Statement {
     Use(UseStatement),
     /// The `impl` statement.
     Impl(ImplStatement),
+    /// The `trait` statement.
+    Trait(TraitStatement),
     /// The `contract` statement.
     Contract(ContractStatement),
     /// The empty `;` statement.
@@ -55,6 +58,7 @@ impl Statement {
             Self::Mod(inner) => inner.location,
             Self::Use(inner) => inner.location,
             Self::Impl(inner) => inner.location,
+            Self::Trait(inner) => inner.location,
             Self::Contract(inner) => inner.location,
             Self::Empty(location) => *location,
         }
@@ -0,0 +1,33 @@
+//!
+//! The trait-local statement.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::statement::r#fn::Statement as FnStatement;
+
+///
+/// The trait-level statement.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    /// The required method signature, which has an empty body, e.g. `fn hash(self) -> field;`.
+    Signature(FnStatement),
+    /// The default method, which is used if the implementation does not override it.
+    Fn(FnStatement),
+    /// The empty `;` statement.
+    Empty(Location),
+}
+
+impl Statement {
+    ///
+    /// The statement location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::Signature(inner) => inner.location,
+            Self::Fn(inner) => inner.location,
+            Self::Empty(location) => *location,
+        }
+    }
+}
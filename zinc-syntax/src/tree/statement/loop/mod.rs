@@ -0,0 +1,48 @@
+//!
+//! The `loop` and `while` statement.
+//!
+
+pub mod builder;
+
+use zinc_lexical::Location;
+
+use crate::tree::attribute::Attribute;
+use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+
+///
+/// The `loop` and `while` statement.
+///
+/// Both forms must be bounded with the `#[bound(N)]` attribute, since the number of iterations
+/// must be known at compile time.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The loop outer attributes, e.g. `#[bound(16)]`.
+    pub attributes: Vec<Attribute>,
+    /// The `while` condition expression, which is `None` for the `loop` form.
+    pub condition: Option<ExpressionTree>,
+    /// The loop block.
+    pub block: BlockExpression,
+}
+
+impl Statement {
+    ///
+    /// Creates a `loop` or `while` statement.
+    ///
+    pub fn new(
+        location: Location,
+        attributes: Vec<Attribute>,
+        condition: Option<ExpressionTree>,
+        block: BlockExpression,
+    ) -> Self {
+        Self {
+            location,
+            attributes,
+            condition,
+            block,
+        }
+    }
+}
@@ -10,11 +10,15 @@ pub mod r#fn;
 pub mod r#for;
 pub mod r#impl;
 pub mod r#let;
+pub mod r#loop;
 pub mod local_contract;
 pub mod local_fn;
 pub mod local_impl;
 pub mod local_mod;
+pub mod local_trait;
 pub mod module;
+pub mod r#return;
 pub mod r#struct;
+pub mod r#trait;
 pub mod r#type;
 pub mod r#use;
@@ -7,6 +7,7 @@ pub mod builder;
 use zinc_lexical::Location;
 
 use crate::tree::field::Field;
+use crate::tree::generic_parameter::GenericParameter;
 use crate::tree::identifier::Identifier;
 
 ///
@@ -18,6 +19,8 @@ pub struct Statement {
     pub location: Location,
     /// The structure type identifier.
     pub identifier: Identifier,
+    /// The structure type generic parameters.
+    pub generics: Vec<GenericParameter>,
     /// The structure type fields.
     pub fields: Vec<Field>,
 }
@@ -26,11 +29,24 @@ impl Statement {
     ///
     /// Creates a `struct` statement.
     ///
-    pub fn new(location: Location, identifier: Identifier, fields: Vec<Field>) -> Self {
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        generics: Vec<GenericParameter>,
+        fields: Vec<Field>,
+    ) -> Self {
         Self {
             location,
             identifier,
+            generics,
             fields,
         }
     }
+
+    ///
+    /// Checks whether the structure has generic parameters and must be monomorphized.
+    ///
+    pub fn is_generic(&self) -> bool {
+        !self.generics.is_empty()
+    }
 }
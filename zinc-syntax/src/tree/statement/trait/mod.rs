@@ -0,0 +1,40 @@
+//!
+//! The `trait` statement.
+//!
+
+pub mod builder;
+
+use zinc_lexical::Location;
+
+use crate::tree::identifier::Identifier;
+use crate::tree::statement::local_trait::Statement as TraitLocalStatement;
+
+///
+/// The `trait` statement.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The trait identifier.
+    pub identifier: Identifier,
+    /// The trait method signatures and default methods.
+    pub statements: Vec<TraitLocalStatement>,
+}
+
+impl Statement {
+    ///
+    /// Creates a `trait` statement.
+    ///
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        statements: Vec<TraitLocalStatement>,
+    ) -> Self {
+        Self {
+            location,
+            identifier,
+            statements,
+        }
+    }
+}
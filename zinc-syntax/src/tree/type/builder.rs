@@ -30,6 +30,10 @@ pub struct Builder {
     path_expression: Option<ExpressionTree>,
     /// The optional generic type arguments.
     generics: Option<Vec<Type>>,
+    /// The function argument types, which means that the type is a function.
+    function_argument_types: Option<Vec<Type>>,
+    /// The function result type.
+    function_return_type: Option<Type>,
 }
 
 /// The invalid type keyword panic, which is prevented by the type parser.
@@ -98,6 +102,20 @@ impl Builder {
         self.generics = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_function_argument_types(&mut self, value: Vec<Type>) {
+        self.function_argument_types = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_function_return_type(&mut self, value: Type) {
+        self.function_return_type = Some(value);
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -113,7 +131,14 @@ impl Builder {
             )
         });
 
-        let variant = if let Some(path) = self.path_expression.take() {
+        let variant = if let Some(arguments) = self.function_argument_types.take() {
+            TypeVariant::function(
+                arguments,
+                self.function_return_type
+                    .take()
+                    .unwrap_or_else(|| Type::new(location, TypeVariant::unit())),
+            )
+        } else if let Some(path) = self.path_expression.take() {
             TypeVariant::alias(path, self.generics.take())
         } else if let Some(keyword) = self.keyword.take() {
             match keyword {
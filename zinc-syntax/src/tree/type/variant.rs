@@ -49,6 +49,13 @@ pub enum Variant {
         /// The optional generic type arguments.
         generics: Option<Vec<Type>>,
     },
+    /// `fn({type1}, {type2}, ...) -> {type}` in the source code.
+    Function {
+        /// The function argument types.
+        arguments: Vec<Type>,
+        /// The function result type, which is `()` if omitted.
+        return_type: Box<Type>,
+    },
 }
 
 impl Variant {
@@ -121,4 +128,14 @@ impl Variant {
     pub fn alias(path: ExpressionTree, generics: Option<Vec<Type>>) -> Self {
         Self::Alias { path, generics }
     }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn function(arguments: Vec<Type>, return_type: Type) -> Self {
+        Self::Function {
+            arguments,
+            return_type: Box::new(return_type),
+        }
+    }
 }
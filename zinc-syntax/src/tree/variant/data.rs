@@ -0,0 +1,17 @@
+//!
+//! The enumeration variant data.
+//!
+
+use crate::tree::field::Field;
+use crate::tree::r#type::Type;
+
+///
+/// The enumeration variant data, which makes the enumeration data-carrying.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Data {
+    /// The tuple-like data, e.g. `Transfer(u160, u248)`.
+    Tuple(Vec<Type>),
+    /// The structure-like data, e.g. `Set { key: u8, value: field }`.
+    Structure(Vec<Field>),
+}
@@ -3,12 +3,15 @@
 //!
 
 pub mod builder;
+pub mod data;
 
 use zinc_lexical::Location;
 
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
 
+use self::data::Data;
+
 ///
 /// The enumeration variant.
 ///
@@ -18,19 +21,46 @@ pub struct Variant {
     pub location: Location,
     /// The enumeration variant identifier.
     pub identifier: Identifier,
-    /// The enumeration variant integer value.
-    pub literal: IntegerLiteral,
+    /// The enumeration variant integer value, which is set for C-like variants.
+    pub literal: Option<IntegerLiteral>,
+    /// The enumeration variant data, which is set for data-carrying variants.
+    pub data: Option<Data>,
 }
 
 impl Variant {
     ///
-    /// Creates an enumeration variant.
+    /// Creates a C-like enumeration variant with an integer value.
     ///
     pub fn new(location: Location, identifier: Identifier, literal: IntegerLiteral) -> Self {
         Self {
             location,
             identifier,
-            literal,
+            literal: Some(literal),
+            data: None,
+        }
+    }
+
+    ///
+    /// Creates an enumeration variant without a value and data.
+    ///
+    pub fn new_unit(location: Location, identifier: Identifier) -> Self {
+        Self {
+            location,
+            identifier,
+            literal: None,
+            data: None,
+        }
+    }
+
+    ///
+    /// Creates a data-carrying enumeration variant.
+    ///
+    pub fn new_with_data(location: Location, identifier: Identifier, data: Data) -> Self {
+        Self {
+            location,
+            identifier,
+            literal: None,
+            data: Some(data),
         }
     }
 }
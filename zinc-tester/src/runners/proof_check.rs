@@ -16,6 +16,7 @@ use zinc_vm::CircuitFacade;
 use zinc_vm::ContractFacade;
 use zinc_vm::ContractInput;
 use zinc_vm::Facade;
+use zinc_vm::Groth16;
 use zinc_zksync::TransactionMsg;
 
 use crate::file::File;
@@ -93,14 +94,19 @@ impl IRunnable for Runner {
                 }
             };
 
-            let params = match match instance.application.clone() {
-                BuildApplication::Circuit(circuit) => CircuitFacade::new(circuit).setup::<Bn256>(),
+            let (proving_key, verifying_key) = match match instance.application.clone() {
+                BuildApplication::Circuit(circuit) => {
+                    CircuitFacade::new(circuit).setup::<Bn256, Groth16>(())
+                }
                 BuildApplication::Contract(contract) => ContractFacade::new(contract)
-                    .setup::<Bn256>(case.method.clone().unwrap_or_else(|| {
-                        zinc_const::source::FUNCTION_MAIN_IDENTIFIER.to_owned()
-                    })),
+                    .setup::<Bn256, Groth16>(
+                        case.method.clone().unwrap_or_else(|| {
+                            zinc_const::source::FUNCTION_MAIN_IDENTIFIER.to_owned()
+                        }),
+                        (),
+                    ),
             } {
-                Ok(params) => params,
+                Ok(keys) => keys,
                 Err(error) => {
                     summary
                         .lock()
@@ -118,8 +124,8 @@ impl IRunnable for Runner {
 
             let (output, proof) = match instance.application.clone() {
                 BuildApplication::Circuit(circuit) => {
-                    let result =
-                        CircuitFacade::new(circuit).prove::<Bn256>(params.clone(), instance.input);
+                    let result = CircuitFacade::new(circuit)
+                        .prove::<Bn256, Groth16>(proving_key, instance.input);
 
                     match result {
                         Ok((result, proof)) => {
@@ -177,8 +183,8 @@ impl IRunnable for Runner {
                         .map(BuildContractFieldValue::new_from_type)
                         .collect();
 
-                    let result = ContractFacade::new(contract).prove::<Bn256>(
-                        params.clone(),
+                    let result = ContractFacade::new(contract).prove::<Bn256, Groth16>(
+                        proving_key,
                         ContractInput::new(
                             instance.input,
                             BuildValue::Contract(storage),
@@ -239,7 +245,7 @@ impl IRunnable for Runner {
                 }
             };
 
-            match Facade::verify(params.vk, proof, output) {
+            match Facade::verify::<Bn256, Groth16>(verifying_key, proof, output) {
                 Ok(success) => {
                     if success {
                         summary
@@ -57,6 +57,10 @@ impl<E: IEngine> Main<E> {
     pub fn num_constraints(&self) -> usize {
         self.constraints_num
     }
+
+    pub fn num_witnesses(&self) -> usize {
+        self.witness.len()
+    }
 }
 
 impl<E: IEngine> ConstraintSystem<E> for Main<E> {
@@ -0,0 +1,96 @@
+//!
+//! The Groth16 proving backend.
+//!
+
+use std::io;
+
+use franklin_crypto::bellman::groth16;
+use franklin_crypto::bellman::groth16::Parameters;
+use franklin_crypto::bellman::groth16::Proof;
+use franklin_crypto::bellman::groth16::VerifyingKey;
+use franklin_crypto::bellman::Circuit;
+
+use crate::core::backend::IBackend;
+use crate::error::RuntimeError;
+use crate::error::VerificationError;
+use crate::IEngine;
+
+///
+/// The Groth16 proving backend.
+///
+/// Requires a trusted setup for every circuit, but produces the smallest proofs.
+///
+pub struct Groth16;
+
+impl<E: IEngine> IBackend<E> for Groth16 {
+    type ProvingKey = Parameters<E>;
+    type VerifyingKey = VerifyingKey<E>;
+    type Proof = Proof<E>;
+    type SetupParameters = ();
+
+    fn setup<C>(
+        circuit: C,
+        _parameters: Self::SetupParameters,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), RuntimeError>
+    where
+        C: Circuit<E> + Clone,
+    {
+        let rng = &mut rand::thread_rng();
+
+        let params = groth16::generate_random_parameters::<E, _, _>(circuit, rng)?;
+        let verifying_key = params.vk.clone();
+
+        Ok((params, verifying_key))
+    }
+
+    fn prove<C>(circuit: C, proving_key: &Self::ProvingKey) -> Result<Self::Proof, RuntimeError>
+    where
+        C: Circuit<E> + Clone,
+    {
+        let rng = &mut rand::thread_rng();
+
+        groth16::create_random_proof(circuit, proving_key, rng)
+            .map_err(RuntimeError::SynthesisError)
+    }
+
+    fn verify(
+        verifying_key: &Self::VerifyingKey,
+        proof: &Self::Proof,
+        public_input: &[E::Fr],
+    ) -> Result<bool, VerificationError> {
+        let prepared_verifying_key = groth16::prepare_verifying_key(verifying_key);
+
+        groth16::verify_proof(&prepared_verifying_key, proof, public_input)
+            .map_err(VerificationError::SynthesisError)
+    }
+
+    fn read_proving_key<R: io::Read>(reader: R) -> io::Result<Self::ProvingKey> {
+        Parameters::<E>::read(reader, true)
+    }
+
+    fn write_proving_key<W: io::Write>(
+        proving_key: &Self::ProvingKey,
+        writer: W,
+    ) -> io::Result<()> {
+        proving_key.write(writer)
+    }
+
+    fn read_verifying_key<R: io::Read>(reader: R) -> io::Result<Self::VerifyingKey> {
+        VerifyingKey::<E>::read(reader)
+    }
+
+    fn write_verifying_key<W: io::Write>(
+        verifying_key: &Self::VerifyingKey,
+        writer: W,
+    ) -> io::Result<()> {
+        verifying_key.write(writer)
+    }
+
+    fn read_proof<R: io::Read>(reader: R) -> io::Result<Self::Proof> {
+        Proof::<E>::read(reader)
+    }
+
+    fn write_proof<W: io::Write>(proof: &Self::Proof, writer: W) -> io::Result<()> {
+        proof.write(writer)
+    }
+}
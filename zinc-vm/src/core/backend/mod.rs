@@ -0,0 +1,173 @@
+//!
+//! The virtual machine proving backend.
+//!
+
+pub mod groth16;
+pub mod plonk;
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use franklin_crypto::bellman::Circuit;
+
+use crate::error::RuntimeError;
+use crate::error::VerificationError;
+use crate::IEngine;
+
+///
+/// The proving scheme abstraction, which generates the keys, proves, and verifies circuits.
+///
+/// The circuit is cloned by the schemes, which must synthesize it more than once.
+///
+pub trait IBackend<E: IEngine> {
+    /// The proving key, which is used by the prover.
+    type ProvingKey;
+    /// The verifying key, which is used by the verifier.
+    type VerifyingKey;
+    /// The proof.
+    type Proof;
+    /// The setup parameters, which are not derived from the circuit.
+    type SetupParameters;
+
+    ///
+    /// Generates a pair of proving and verifying keys for the `circuit`.
+    ///
+    fn setup<C>(
+        circuit: C,
+        parameters: Self::SetupParameters,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), RuntimeError>
+    where
+        C: Circuit<E> + Clone;
+
+    ///
+    /// Generates a proof for the `circuit` with witness values.
+    ///
+    fn prove<C>(circuit: C, proving_key: &Self::ProvingKey) -> Result<Self::Proof, RuntimeError>
+    where
+        C: Circuit<E> + Clone;
+
+    ///
+    /// Verifies the `proof` against the `public_input`.
+    ///
+    fn verify(
+        verifying_key: &Self::VerifyingKey,
+        proof: &Self::Proof,
+        public_input: &[E::Fr],
+    ) -> Result<bool, VerificationError>;
+
+    ///
+    /// Reads the proving key from the `reader`.
+    ///
+    fn read_proving_key<R: io::Read>(reader: R) -> io::Result<Self::ProvingKey>;
+
+    ///
+    /// Writes the proving key to the `writer`.
+    ///
+    fn write_proving_key<W: io::Write>(proving_key: &Self::ProvingKey, writer: W)
+        -> io::Result<()>;
+
+    ///
+    /// Reads the verifying key from the `reader`.
+    ///
+    fn read_verifying_key<R: io::Read>(reader: R) -> io::Result<Self::VerifyingKey>;
+
+    ///
+    /// Writes the verifying key to the `writer`.
+    ///
+    fn write_verifying_key<W: io::Write>(
+        verifying_key: &Self::VerifyingKey,
+        writer: W,
+    ) -> io::Result<()>;
+
+    ///
+    /// Reads the proof from the `reader`.
+    ///
+    fn read_proof<R: io::Read>(reader: R) -> io::Result<Self::Proof>;
+
+    ///
+    /// Writes the proof to the `writer`.
+    ///
+    fn write_proof<W: io::Write>(proof: &Self::Proof, writer: W) -> io::Result<()>;
+}
+
+///
+/// The proving backend identifier, which is selected by the user and recorded next to the
+/// verifying key.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Backend {
+    /// The Groth16 scheme with a per-circuit trusted setup.
+    Groth16,
+    /// The PLONK scheme with a universal structured reference string.
+    Plonk,
+}
+
+impl Backend {
+    ///
+    /// Returns the path to the backend record written next to the verifying key.
+    ///
+    pub fn record_path(verifying_key_path: &Path) -> PathBuf {
+        verifying_key_path.with_extension(zinc_const::extension::BACKEND)
+    }
+
+    ///
+    /// Writes the backend record next to the verifying key at `verifying_key_path`.
+    ///
+    pub fn write_record(self, verifying_key_path: &Path) -> io::Result<()> {
+        fs::write(Self::record_path(verifying_key_path), self.to_string())
+    }
+
+    ///
+    /// Reads the backend record written next to the verifying key at `verifying_key_path`.
+    ///
+    /// Returns the default backend if there is no record, since the keys generated before the
+    /// record was introduced are Groth16 ones.
+    ///
+    pub fn read_record(verifying_key_path: &Path) -> io::Result<Self> {
+        let record_path = Self::record_path(verifying_key_path);
+        if !record_path.exists() {
+            return Ok(Self::default());
+        }
+
+        fs::read_to_string(record_path)?
+            .trim()
+            .parse()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Self::Groth16
+    }
+}
+
+impl FromStr for Backend {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "groth16" => Ok(Self::Groth16),
+            "plonk" => Ok(Self::Plonk),
+            another => Err(format!(
+                "unknown backend `{}`, expected `groth16` or `plonk`",
+                another
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Groth16 => write!(f, "groth16"),
+            Self::Plonk => write!(f, "plonk"),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//!
+//! The PLONK proving backend.
+//!
+
+use std::io;
+
+use franklin_crypto::bellman::kate_commitment::Crs;
+use franklin_crypto::bellman::kate_commitment::CrsForMonomialForm;
+use franklin_crypto::bellman::plonk;
+use franklin_crypto::bellman::plonk::better_cs::cs::PlonkCsWidth4WithNextStepParams;
+use franklin_crypto::bellman::plonk::commitments::transcript::keccak_transcript::RollingKeccakTranscript;
+use franklin_crypto::bellman::plonk::Proof;
+use franklin_crypto::bellman::plonk::SetupPolynomials;
+use franklin_crypto::bellman::plonk::VerificationKey;
+#[cfg(test)]
+use franklin_crypto::bellman::worker::Worker;
+use franklin_crypto::bellman::Circuit;
+
+use crate::core::backend::IBackend;
+use crate::error::RuntimeError;
+use crate::error::VerificationError;
+use crate::IEngine;
+
+///
+/// The PLONK proving backend.
+///
+/// The R1CS circuit is transpiled into the width-4 PLONK constraint system, and committed with
+/// a universal structured reference string, so changing a circuit does not require a new
+/// trusted setup ceremony. The reference string is passed to the setup, since it cannot be
+/// generated locally without knowing its secret.
+///
+pub struct Plonk;
+
+///
+/// The PLONK proving key, which consists of the circuit setup polynomials and the reference
+/// string in the monomial form.
+///
+pub struct ProvingKey<E: IEngine> {
+    /// The circuit setup polynomials.
+    pub setup: SetupPolynomials<E, PlonkCsWidth4WithNextStepParams>,
+    /// The universal structured reference string.
+    pub reference_string: Crs<E, CrsForMonomialForm>,
+}
+
+impl Plonk {
+    ///
+    /// Reads the universal structured reference string in the monomial form from the `reader`.
+    ///
+    /// The string must be produced by a multi-party ceremony, e.g. the one used by zkSync, since
+    /// anyone knowing its secret can forge proofs.
+    ///
+    pub fn read_reference_string<E: IEngine, R: io::Read>(
+        reader: R,
+    ) -> io::Result<Crs<E, CrsForMonomialForm>> {
+        Crs::<E, CrsForMonomialForm>::read(reader)
+    }
+
+    ///
+    /// Generates the universal structured reference string of `size` locally.
+    ///
+    /// The string is built from a publicly known secret, so it is only available for testing.
+    ///
+    #[cfg(test)]
+    pub fn reference_string<E: IEngine>(size: usize) -> Crs<E, CrsForMonomialForm> {
+        Crs::<E, CrsForMonomialForm>::crs_42(size, &Worker::new())
+    }
+}
+
+impl<E: IEngine> IBackend<E> for Plonk {
+    type ProvingKey = ProvingKey<E>;
+    type VerifyingKey = VerificationKey<E, PlonkCsWidth4WithNextStepParams>;
+    type Proof = Proof<E, PlonkCsWidth4WithNextStepParams>;
+    type SetupParameters = Crs<E, CrsForMonomialForm>;
+
+    fn setup<C>(
+        circuit: C,
+        reference_string: Self::SetupParameters,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), RuntimeError>
+    where
+        C: Circuit<E> + Clone,
+    {
+        let hints = plonk::transpile::<E, _>(circuit.clone())?;
+        let setup = plonk::setup(circuit, &hints)?;
+
+        let size = setup.n.next_power_of_two();
+        if reference_string.g1_bases.len() < size {
+            return Err(RuntimeError::ReferenceStringTooSmall {
+                expected: size,
+                found: reference_string.g1_bases.len(),
+            });
+        }
+        let verifying_key = plonk::make_verification_key(&setup, &reference_string)?;
+
+        Ok((
+            ProvingKey {
+                setup,
+                reference_string,
+            },
+            verifying_key,
+        ))
+    }
+
+    fn prove<C>(circuit: C, proving_key: &Self::ProvingKey) -> Result<Self::Proof, RuntimeError>
+    where
+        C: Circuit<E> + Clone,
+    {
+        let hints = plonk::transpile::<E, _>(circuit.clone())?;
+
+        plonk::prove_by_steps::<E, _, RollingKeccakTranscript<E::Fr>>(
+            circuit,
+            &hints,
+            &proving_key.setup,
+            None,
+            &proving_key.reference_string,
+            None,
+        )
+        .map_err(RuntimeError::SynthesisError)
+    }
+
+    fn verify(
+        verifying_key: &Self::VerifyingKey,
+        proof: &Self::Proof,
+        public_input: &[E::Fr],
+    ) -> Result<bool, VerificationError> {
+        if proof.input_values.as_slice() != public_input {
+            return Ok(false);
+        }
+
+        plonk::verify::<E, _, RollingKeccakTranscript<E::Fr>>(proof, verifying_key, None)
+            .map_err(VerificationError::SynthesisError)
+    }
+
+    fn read_proving_key<R: io::Read>(mut reader: R) -> io::Result<Self::ProvingKey> {
+        let setup = SetupPolynomials::read(&mut reader)?;
+        let reference_string = Crs::<E, CrsForMonomialForm>::read(&mut reader)?;
+
+        Ok(ProvingKey {
+            setup,
+            reference_string,
+        })
+    }
+
+    fn write_proving_key<W: io::Write>(
+        proving_key: &Self::ProvingKey,
+        mut writer: W,
+    ) -> io::Result<()> {
+        proving_key.setup.write(&mut writer)?;
+        proving_key.reference_string.write(&mut writer)
+    }
+
+    fn read_verifying_key<R: io::Read>(reader: R) -> io::Result<Self::VerifyingKey> {
+        VerificationKey::read(reader)
+    }
+
+    fn write_verifying_key<W: io::Write>(
+        verifying_key: &Self::VerifyingKey,
+        writer: W,
+    ) -> io::Result<()> {
+        verifying_key.write(writer)
+    }
+
+    fn read_proof<R: io::Read>(reader: R) -> io::Result<Self::Proof> {
+        Proof::read(reader)
+    }
+
+    fn write_proof<W: io::Write>(proof: &Self::Proof, writer: W) -> io::Result<()> {
+        proof.write(writer)
+    }
+}
@@ -0,0 +1,144 @@
+//!
+//! The proving backend tests.
+//!
+
+use std::collections::HashMap;
+use std::fs;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_build::Circuit as BuildCircuit;
+use zinc_build::Exit;
+use zinc_build::Load;
+use zinc_build::Mul;
+use zinc_build::ScalarType;
+use zinc_build::ScalarValue;
+use zinc_build::Type as BuildType;
+use zinc_build::Value as BuildValue;
+
+use crate::core::backend::groth16::Groth16;
+use crate::core::backend::plonk::Plonk;
+use crate::core::backend::Backend;
+use crate::core::backend::IBackend;
+use crate::core::circuit::facade::Facade as CircuitFacade;
+use crate::core::facade::Facade;
+
+/// The reference string size, which is enough for the tested circuit.
+const REFERENCE_STRING_SIZE: usize = 1 << 10;
+
+///
+/// Builds the `fn main(x: field) -> field { x * x }` circuit.
+///
+fn square() -> BuildCircuit {
+    BuildCircuit::new(
+        "square".to_owned(),
+        0,
+        BuildType::Scalar(ScalarType::Field),
+        BuildType::Scalar(ScalarType::Field),
+        HashMap::new(),
+        vec![
+            Load::new(0, 1).into(),
+            Load::new(0, 1).into(),
+            Mul.into(),
+            Exit::new(1).into(),
+        ],
+    )
+}
+
+///
+/// Creates a `field` value.
+///
+fn field(value: usize) -> BuildValue {
+    BuildValue::Scalar(ScalarValue::Field(BigInt::from(value)))
+}
+
+///
+/// Runs the setup, proves the square of `3`, and verifies the proof against the correct and a
+/// wrong public input, passing the keys and proof through their binary representation.
+///
+fn roundtrip<B: IBackend<Bn256>>(parameters: B::SetupParameters) {
+    let (proving_key, verifying_key) = CircuitFacade::new(square())
+        .setup::<Bn256, B>(parameters)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let mut proving_key_bytes = Vec::new();
+    B::write_proving_key(&proving_key, &mut proving_key_bytes)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let mut verifying_key_bytes = Vec::new();
+    B::write_verifying_key(&verifying_key, &mut verifying_key_bytes)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let proving_key = B::read_proving_key(proving_key_bytes.as_slice())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let (output, proof) = CircuitFacade::new(square())
+        .prove::<Bn256, B>(proving_key, field(3))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(output.clone().into_flat_values(), vec![BigInt::from(9)]);
+
+    let mut proof_bytes = Vec::new();
+    B::write_proof(&proof, &mut proof_bytes).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    for (public_input, expected) in vec![(output, true), (field(10), false)].into_iter() {
+        let verifying_key = B::read_verifying_key(verifying_key_bytes.as_slice())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let proof =
+            B::read_proof(proof_bytes.as_slice()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let verified = Facade::verify::<Bn256, B>(verifying_key, proof, public_input)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(verified, expected);
+    }
+}
+
+#[test]
+fn ok_groth16_roundtrip() {
+    roundtrip::<Groth16>(());
+}
+
+#[test]
+fn ok_plonk_roundtrip() {
+    roundtrip::<Plonk>(Plonk::reference_string::<Bn256>(REFERENCE_STRING_SIZE));
+}
+
+#[test]
+fn error_plonk_reference_string_too_small() {
+    let result =
+        CircuitFacade::new(square()).setup::<Bn256, Plonk>(Plonk::reference_string::<Bn256>(1));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn error_backend_record_mismatch() {
+    let directory =
+        std::env::temp_dir().join(format!("zinc-vm-backend-record-{}", std::process::id()));
+    fs::create_dir_all(&directory).expect(zinc_const::panic::TEST_DATA_VALID);
+    let verifying_key_path = directory.join(zinc_const::file_name::VERIFYING_KEY);
+
+    let (_proving_key, verifying_key) = CircuitFacade::new(square())
+        .setup::<Bn256, Groth16>(())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    <Groth16 as IBackend<Bn256>>::write_verifying_key(
+        &verifying_key,
+        fs::File::create(&verifying_key_path).expect(zinc_const::panic::TEST_DATA_VALID),
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+    Backend::Plonk
+        .write_record(&verifying_key_path)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let backend =
+        Backend::read_record(&verifying_key_path).expect(zinc_const::panic::TEST_DATA_VALID);
+    let result = <Plonk as IBackend<Bn256>>::read_verifying_key(
+        fs::File::open(&verifying_key_path).expect(zinc_const::panic::TEST_DATA_VALID),
+    );
+    fs::remove_dir_all(&directory).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(backend, Backend::Plonk);
+    assert!(
+        result.is_err(),
+        "The Groth16 verifying key must not be read as a PLONK one"
+    );
+}
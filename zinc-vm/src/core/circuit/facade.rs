@@ -2,14 +2,13 @@
 //! The virtual machine circuit facade.
 //!
 
+use std::cell::RefCell;
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 use colored::Colorize;
 use num::BigInt;
 
-use franklin_crypto::bellman::groth16;
-use franklin_crypto::bellman::groth16::Parameters;
-use franklin_crypto::bellman::groth16::Proof;
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 use franklin_crypto::circuit::test::TestConstraintSystem;
 
@@ -18,9 +17,13 @@ use zinc_build::Value as BuildValue;
 use zinc_const::UnitTestExitCode;
 
 use crate::constraint_systems::main::Main as MainCS;
+use crate::core::backend::IBackend;
 use crate::core::circuit::output::Output as CircuitOutput;
 use crate::core::circuit::synthesizer::Synthesizer as CircuitSynthesizer;
 use crate::core::circuit::State as CircuitState;
+use crate::core::contract::storage::setup::Storage as SetupStorage;
+use crate::core::debugger::IDebugger;
+use crate::core::profiler::Profiler;
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::RuntimeError;
 use crate::IEngine;
@@ -46,7 +49,7 @@ impl Facade {
         let result = state.run(
             self.inner,
             Some(&inputs_flat),
-            |cs| {
+            |cs, _, _| {
                 let num = cs.num_constraints() - num_constraints;
                 num_constraints += num;
                 log::trace!("Constraints: {}", num);
@@ -71,6 +74,64 @@ impl Facade {
         Ok(CircuitOutput::new(output_value))
     }
 
+    pub fn profile<E: IEngine>(self, input: BuildValue) -> Result<Profiler, RuntimeError> {
+        let cs = MainCS::<Bn256>::new();
+
+        let inputs_flat = input.into_flat_values();
+
+        let mut state = CircuitState::new(cs);
+
+        let mut profiler = Profiler::new();
+        state.run(
+            self.inner,
+            Some(&inputs_flat),
+            |cs, state, location| {
+                profiler.record(
+                    cs.num_constraints(),
+                    cs.num_witnesses(),
+                    location,
+                    state.frames_stack.as_slice(),
+                )
+            },
+            |_| Ok(()),
+        )?;
+
+        Ok(profiler)
+    }
+
+    pub fn debug<E, D>(self, input: BuildValue, debugger: &mut D) -> Result<(), RuntimeError>
+    where
+        E: IEngine,
+        D: IDebugger<Bn256>,
+    {
+        let cs = MainCS::<Bn256>::new();
+
+        let inputs_flat = input.into_flat_values();
+
+        let mut state = CircuitState::new(cs);
+
+        state.run(
+            self.inner,
+            Some(&inputs_flat),
+            |cs, execution_state, location| {
+                debugger.on_instruction::<SetupStorage<Bn256>>(
+                    cs.is_satisfied(),
+                    execution_state,
+                    location,
+                    None,
+                )
+            },
+            |_| Ok(()),
+        )?;
+
+        let cs = state.constraint_system();
+        if !cs.is_satisfied() {
+            return Err(RuntimeError::UnsatisfiedConstraint);
+        }
+
+        Ok(())
+    }
+
     pub fn test<E: IEngine>(self) -> Result<UnitTestExitCode, RuntimeError> {
         let mut exit_code = UnitTestExitCode::Passed;
 
@@ -84,7 +145,7 @@ impl Facade {
 
             let mut state = CircuitState::new(cs);
 
-            let result = state.run(self.inner.clone(), Some(&[]), |_| {}, |_| Ok(()));
+            let result = state.run(self.inner.clone(), Some(&[]), |_, _, _| {}, |_| Ok(()));
             match result {
                 Err(_) if unit_test.should_panic => {
                     println!("test {} ... {} (failed)", name, "ok".green());
@@ -111,49 +172,60 @@ impl Facade {
         Ok(exit_code)
     }
 
-    pub fn setup<E: IEngine>(self) -> Result<Parameters<E>, RuntimeError> {
-        let rng = &mut rand::thread_rng();
-        let mut result = None;
+    pub fn setup<E, B>(
+        self,
+        parameters: B::SetupParameters,
+    ) -> Result<(B::ProvingKey, B::VerifyingKey), RuntimeError>
+    where
+        E: IEngine,
+        B: IBackend<E>,
+    {
+        let result = Rc::new(RefCell::new(None));
 
         let synthesizable = CircuitSynthesizer {
             inputs: None,
-            output: &mut result,
+            output: result.clone(),
             bytecode: self.inner,
 
             _pd: PhantomData,
         };
 
-        let params = groth16::generate_random_parameters::<E, _, _>(synthesizable, rng)?;
+        let keys = B::setup(synthesizable, parameters)?;
 
-        match result.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS) {
-            Ok(_) => Ok(params),
+        match result
+            .replace(None)
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+        {
+            Ok(_) => Ok(keys),
             Err(error) => Err(error),
         }
     }
 
-    pub fn prove<E: IEngine>(
+    pub fn prove<E, B>(
         self,
-        params: Parameters<E>,
+        proving_key: B::ProvingKey,
         input: BuildValue,
-    ) -> Result<(BuildValue, Proof<E>), RuntimeError> {
-        let mut result = None;
-        let rng = &mut rand::thread_rng();
+    ) -> Result<(BuildValue, B::Proof), RuntimeError>
+    where
+        E: IEngine,
+        B: IBackend<E>,
+    {
+        let result = Rc::new(RefCell::new(None));
 
         let inputs_flat = input.into_flat_values();
         let output_type = self.inner.output.clone();
 
         let synthesizable = CircuitSynthesizer {
             inputs: Some(inputs_flat),
-            output: &mut result,
+            output: result.clone(),
             bytecode: self.inner,
 
             _pd: PhantomData,
         };
 
-        let proof = groth16::create_random_proof(synthesizable, &params, rng)
-            .map_err(RuntimeError::SynthesisError)?;
+        let proof = B::prove(synthesizable, &proving_key)?;
 
-        match result {
+        match result.replace(None) {
             None => Err(RuntimeError::InternalError(
                 "circuit hasn't generate outputs".into(),
             )),
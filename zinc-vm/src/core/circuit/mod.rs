@@ -70,7 +70,7 @@ where
         mut check_cs: F,
     ) -> Result<Vec<Option<BigInt>>, RuntimeError>
     where
-        CB: FnMut(&CS),
+        CB: FnMut(&CS, &ExecutionState<E>, &Location),
         F: FnMut(&CS) -> Result<(), RuntimeError>,
     {
         self.counter.cs.enforce(
@@ -85,7 +85,7 @@ where
         let input_size = circuit.input.size();
         self.execution_state
             .frames_stack
-            .push(Frame::new(0, std::usize::MAX));
+            .push(Frame::new(0, std::usize::MAX, Location::new()));
         self.init_root_frame(circuit.input, input_values)?;
         if let Err(error) = zinc_build::Call::new(circuit.address, input_size)
             .execute(self)
@@ -119,7 +119,7 @@ where
             }
 
             log::trace!("{}", self.execution_state);
-            instruction_callback(&self.counter.cs);
+            instruction_callback(&self.counter.cs, &self.execution_state, &self.location);
             self.counter.cs.pop_namespace();
             step += 1;
         }
@@ -267,9 +267,11 @@ where
 
     fn call(&mut self, address: usize, inputs_count: usize) -> Result<(), RuntimeError> {
         let offset = self.top_frame()?.stack_frame_end;
-        self.execution_state
-            .frames_stack
-            .push(Frame::new(offset, self.execution_state.instruction_counter));
+        self.execution_state.frames_stack.push(Frame::new(
+            offset,
+            self.execution_state.instruction_counter,
+            self.location.clone(),
+        ));
 
         for i in 0..inputs_count {
             let arg = self.pop()?;
@@ -294,6 +296,7 @@ where
             .ok_or(MalformedBytecode::StackUnderflow)?;
 
         self.execution_state.instruction_counter = frame.return_address;
+        self.location = frame.caller_location;
 
         for p in outputs.into_iter().rev() {
             self.push(p)?;
@@ -2,7 +2,9 @@
 //! The virtual machine circuit synthesizer.
 //!
 
+use std::cell::RefCell;
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 use num::BigInt;
 
@@ -18,21 +20,28 @@ use crate::core::circuit::State;
 use crate::error::RuntimeError;
 use crate::IEngine;
 
-pub struct Synthesizer<'a, E: IEngine> {
+#[derive(Clone)]
+pub struct Synthesizer<E: IEngine> {
     pub inputs: Option<Vec<BigInt>>,
-    pub output: &'a mut Option<Result<Vec<Option<BigInt>>, RuntimeError>>,
+    pub output: Rc<RefCell<Option<Result<Vec<Option<BigInt>>, RuntimeError>>>>,
     pub bytecode: BuildCircuit,
 
     pub _pd: PhantomData<E>,
 }
 
-impl<E> bellman::Circuit<E> for Synthesizer<'_, E>
+impl<E> bellman::Circuit<E> for Synthesizer<E>
 where
     E: IEngine,
 {
     fn synthesize<CS: ConstraintSystem<E>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
         let mut circuit = State::new(DedupCS::new(LoggingCS::new(cs)));
-        *self.output = Some(circuit.run(self.bytecode, self.inputs.as_deref(), |_| {}, |_| Ok(())));
+        let result = circuit.run(
+            self.bytecode,
+            self.inputs.as_deref(),
+            |_, _, _| {},
+            |_| Ok(()),
+        );
+        self.output.replace(Some(result));
 
         Ok(())
     }
@@ -2,14 +2,13 @@
 //! The virtual machine contract facade.
 //!
 
+use std::cell::RefCell;
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 use colored::Colorize;
 use num::BigInt;
 
-use franklin_crypto::bellman::groth16;
-use franklin_crypto::bellman::groth16::Parameters;
-use franklin_crypto::bellman::groth16::Proof;
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::test::TestConstraintSystem;
@@ -22,7 +21,10 @@ use zinc_const::UnitTestExitCode;
 use zinc_zksync::TransactionMsg;
 
 use crate::constraint_systems::constant::Constant as ConstantCS;
+use crate::constraint_systems::main::Main as MainCS;
+use crate::core::backend::IBackend;
 use crate::core::contract::input::Input as ContractInput;
+use crate::core::contract::output::event::Event as ContractEvent;
 use crate::core::contract::output::Output as ContractOutput;
 use crate::core::contract::storage::database::Storage as DatabaseStorage;
 use crate::core::contract::storage::leaf::LeafInput;
@@ -30,7 +32,10 @@ use crate::core::contract::storage::leaf::LeafOutput;
 use crate::core::contract::storage::setup::Storage as SetupStorage;
 use crate::core::contract::synthesizer::Synthesizer as ContractSynthesizer;
 use crate::core::contract::State as ContractState;
+use crate::core::debugger::IDebugger;
+use crate::core::profiler::Profiler;
 use crate::core::virtual_machine::IVirtualMachine;
+use crate::error::MalformedBytecode;
 use crate::error::RuntimeError;
 use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
@@ -69,49 +74,12 @@ impl Facade {
         };
 
         let storage_fields = self.inner.storage.clone();
+        let event_types = self.inner.events.clone();
         let mut storage_types = Vec::with_capacity(self.inner.storage.len());
         for field in self.inner.storage.iter() {
             storage_types.push(field.r#type.to_owned());
         }
-        let storage_leaves = match input.storage {
-            BuildValue::Contract(fields) => fields
-                .into_iter()
-                .enumerate()
-                .map(|(index, field)| {
-                    let r#type = storage_types[index].to_owned();
-
-                    match field.value {
-                        BuildValue::Map(map) => {
-                            let (key_type, value_type) = match r#type {
-                                BuildType::Map {
-                                    key_type,
-                                    value_type,
-                                } => (*key_type, *value_type),
-                                _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
-                            };
-
-                            let entries = map
-                                .into_iter()
-                                .map(|(key, value)| {
-                                    (key.into_flat_values(), value.into_flat_values())
-                                })
-                                .collect();
-                            LeafInput::Map {
-                                key_type,
-                                value_type,
-                                entries,
-                            }
-                        }
-                        value => {
-                            let mut values = value.into_flat_values();
-                            values.reverse();
-                            LeafInput::Array { r#type, values }
-                        }
-                    }
-                })
-                .collect::<Vec<LeafInput>>(),
-            _ => return Err(RuntimeError::InvalidStorageValue),
-        };
+        let storage_leaves = Self::storage_leaves(storage_types.as_slice(), input.storage)?;
         let storage = DatabaseStorage::<Bn256>::new(storage_leaves);
         let storage_gadget =
             StorageGadget::<_, _, Sha256Hasher>::new(cs.namespace(|| "storage"), storage)?;
@@ -124,7 +92,7 @@ impl Facade {
             self.inner,
             method.input,
             Some(&arguments_flat),
-            |cs| {
+            |cs, _, _, _| {
                 let num = cs.num_constraints() - num_constraints;
                 num_constraints += num;
                 log::trace!("Constraints: {}", num);
@@ -195,7 +163,123 @@ impl Facade {
 
         let transfers = state.execution_state.transfers;
 
-        Ok(ContractOutput::new(output_value, storage_value, transfers))
+        let mut events = Vec::with_capacity(state.execution_state.events.len());
+        for (index, values) in state.execution_state.events.into_iter() {
+            let event = event_types.get(index).cloned().ok_or_else(|| {
+                MalformedBytecode::InvalidArguments(format!("event #{} is not declared", index))
+            })?;
+
+            events.push(ContractEvent::new(
+                event.name,
+                BuildValue::from_flat_values(event.r#type, values.as_slice()),
+            ));
+        }
+
+        Ok(ContractOutput::new(
+            output_value,
+            storage_value,
+            transfers,
+            events,
+        ))
+    }
+
+    pub fn profile<E: IEngine>(self, input: ContractInput) -> Result<Profiler, RuntimeError> {
+        let mut cs = MainCS::<Bn256>::new();
+
+        let method = self
+            .inner
+            .methods
+            .get(input.method_name.as_str())
+            .cloned()
+            .ok_or(RuntimeError::MethodNotFound {
+                found: input.method_name.clone(),
+            })?;
+
+        let arguments_flat = input.arguments.into_flat_values();
+
+        let storage_types: Vec<BuildType> = self
+            .inner
+            .storage
+            .iter()
+            .map(|field| field.r#type.to_owned())
+            .collect();
+        let storage_leaves = Self::storage_leaves(storage_types.as_slice(), input.storage)?;
+        let storage = DatabaseStorage::<Bn256>::new(storage_leaves);
+        let storage_gadget =
+            StorageGadget::<_, _, Sha256Hasher>::new(cs.namespace(|| "storage"), storage)?;
+
+        let mut state =
+            ContractState::new(cs, storage_gadget, input.method_name, input.transaction);
+
+        let mut profiler = Profiler::new();
+        state.run(
+            self.inner,
+            method.input,
+            Some(&arguments_flat),
+            |cs, state, location, _| {
+                profiler.record(
+                    cs.num_constraints(),
+                    cs.num_witnesses(),
+                    location,
+                    state.frames_stack.as_slice(),
+                )
+            },
+            |_| Ok(()),
+            method.address,
+        )?;
+
+        Ok(profiler)
+    }
+
+    pub fn debug<E, D>(self, input: ContractInput, debugger: &mut D) -> Result<(), RuntimeError>
+    where
+        E: IEngine,
+        D: IDebugger<Bn256>,
+    {
+        let mut cs = MainCS::<Bn256>::new();
+
+        let method = self
+            .inner
+            .methods
+            .get(input.method_name.as_str())
+            .cloned()
+            .ok_or(RuntimeError::MethodNotFound {
+                found: input.method_name.clone(),
+            })?;
+
+        let arguments_flat = input.arguments.into_flat_values();
+
+        let storage_types: Vec<BuildType> = self
+            .inner
+            .storage
+            .iter()
+            .map(|field| field.r#type.to_owned())
+            .collect();
+        let storage_leaves = Self::storage_leaves(storage_types.as_slice(), input.storage)?;
+        let storage = DatabaseStorage::<Bn256>::new(storage_leaves);
+        let storage_gadget =
+            StorageGadget::<_, _, Sha256Hasher>::new(cs.namespace(|| "storage"), storage)?;
+
+        let mut state =
+            ContractState::new(cs, storage_gadget, input.method_name, input.transaction);
+
+        state.run(
+            self.inner,
+            method.input,
+            Some(&arguments_flat),
+            |cs, execution_state, location, storage| {
+                debugger.on_instruction(cs.is_satisfied(), execution_state, location, Some(storage))
+            },
+            |_| Ok(()),
+            method.address,
+        )?;
+
+        let cs = state.constraint_system();
+        if !cs.is_satisfied() {
+            return Err(RuntimeError::UnsatisfiedConstraint);
+        }
+
+        Ok(())
     }
 
     pub fn test<E: IEngine>(self) -> Result<UnitTestExitCode, RuntimeError> {
@@ -227,7 +311,7 @@ impl Facade {
                 self.inner.clone(),
                 BuildType::new_empty_structure(),
                 Some(&[]),
-                |_| {},
+                |_, _, _, _| {},
                 |_| Ok(()),
                 unit_test.address,
             );
@@ -258,9 +342,64 @@ impl Facade {
         Ok(exit_code)
     }
 
-    pub fn setup<E: IEngine>(self, method_name: String) -> Result<Parameters<E>, RuntimeError> {
-        let rng = &mut rand::thread_rng();
-        let mut result = None;
+    ///
+    /// Converts the storage `value` into the leaf inputs of the storage Merkle tree.
+    ///
+    fn storage_leaves(
+        storage_types: &[BuildType],
+        storage: BuildValue,
+    ) -> Result<Vec<LeafInput>, RuntimeError> {
+        match storage {
+            BuildValue::Contract(fields) => Ok(fields
+                .into_iter()
+                .enumerate()
+                .map(|(index, field)| {
+                    let r#type = storage_types[index].to_owned();
+
+                    match field.value {
+                        BuildValue::Map(map) => {
+                            let (key_type, value_type) = match r#type {
+                                BuildType::Map {
+                                    key_type,
+                                    value_type,
+                                } => (*key_type, *value_type),
+                                _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                            };
+
+                            let entries = map
+                                .into_iter()
+                                .map(|(key, value)| {
+                                    (key.into_flat_values(), value.into_flat_values())
+                                })
+                                .collect();
+                            LeafInput::Map {
+                                key_type,
+                                value_type,
+                                entries,
+                            }
+                        }
+                        value => {
+                            let mut values = value.into_flat_values();
+                            values.reverse();
+                            LeafInput::Array { r#type, values }
+                        }
+                    }
+                })
+                .collect::<Vec<LeafInput>>()),
+            _ => Err(RuntimeError::InvalidStorageValue),
+        }
+    }
+
+    pub fn setup<E, B>(
+        self,
+        method_name: String,
+        parameters: B::SetupParameters,
+    ) -> Result<(B::ProvingKey, B::VerifyingKey), RuntimeError>
+    where
+        E: IEngine,
+        B: IBackend<E>,
+    {
+        let result = Rc::new(RefCell::new(None));
 
         let method = self
             .inner
@@ -281,7 +420,7 @@ impl Facade {
 
         let synthesizable = ContractSynthesizer {
             inputs: None,
-            output: &mut result,
+            output: result.clone(),
             bytecode: self.inner,
             method,
             storage,
@@ -290,19 +429,26 @@ impl Facade {
             _pd: PhantomData,
         };
 
-        let params = groth16::generate_random_parameters::<E, _, _>(synthesizable, rng)?;
+        let keys = B::setup(synthesizable, parameters)?;
 
-        match result.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS) {
-            Ok(_) => Ok(params),
+        match result
+            .replace(None)
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+        {
+            Ok(_) => Ok(keys),
             Err(error) => Err(error),
         }
     }
 
-    pub fn prove<E: IEngine>(
+    pub fn prove<E, B>(
         self,
-        params: Parameters<E>,
+        proving_key: B::ProvingKey,
         input: ContractInput,
-    ) -> Result<(BuildValue, Proof<E>), RuntimeError> {
+    ) -> Result<(BuildValue, B::Proof), RuntimeError>
+    where
+        E: IEngine,
+        B: IBackend<E>,
+    {
         let method = self
             .inner
             .methods
@@ -312,8 +458,7 @@ impl Facade {
                 found: input.method_name.clone(),
             })?;
 
-        let mut result = None;
-        let rng = &mut rand::thread_rng();
+        let result = Rc::new(RefCell::new(None));
 
         let arguments_flat = input.arguments.into_flat_values();
         let output_type = if method.is_mutable {
@@ -369,7 +514,7 @@ impl Facade {
 
         let synthesizable = ContractSynthesizer {
             inputs: Some(arguments_flat),
-            output: &mut result,
+            output: result.clone(),
             bytecode: self.inner,
             method,
             storage,
@@ -378,10 +523,9 @@ impl Facade {
             _pd: PhantomData,
         };
 
-        let proof = groth16::create_random_proof(synthesizable, &params, rng)
-            .map_err(RuntimeError::SynthesisError)?;
+        let proof = B::prove(synthesizable, &proving_key)?;
 
-        match result {
+        match result.replace(None) {
             None => Err(RuntimeError::InternalError(
                 "contract hasn't generate outputs".into(),
             )),
@@ -97,7 +97,7 @@ where
         address: usize,
     ) -> Result<Vec<Option<BigInt>>, RuntimeError>
     where
-        CB: FnMut(&CS),
+        CB: FnMut(&CS, &ExecutionState<E>, &Location, &S),
         F: FnMut(&CS) -> Result<(), RuntimeError>,
     {
         self.counter.cs.enforce(
@@ -112,7 +112,7 @@ where
         let input_size = input_type.size();
         self.execution_state
             .frames_stack
-            .push(Frame::new(0, std::usize::MAX));
+            .push(Frame::new(0, std::usize::MAX, Location::new()));
         self.init_root_frame(input_type, input_values)?;
 
         if let Err(error) = zinc_build::Call::new(address, input_size)
@@ -150,7 +150,12 @@ where
             }
 
             log::trace!("{}", self.execution_state);
-            instruction_callback(&self.counter.cs);
+            instruction_callback(
+                &self.counter.cs,
+                &self.execution_state,
+                &self.location,
+                self.storage.inner(),
+            );
             self.counter.cs.pop_namespace();
             step += 1;
         }
@@ -184,7 +189,7 @@ where
     ) -> Result<(), RuntimeError> {
         self.execution_state
             .frames_stack
-            .push(Frame::new(0, std::usize::MAX));
+            .push(Frame::new(0, std::usize::MAX, Location::new()));
 
         let types = input_type.into_flat_scalar_types();
 
@@ -326,9 +331,11 @@ where
 
     fn call(&mut self, address: usize, inputs_count: usize) -> Result<(), RuntimeError> {
         let offset = self.top_frame()?.stack_frame_end;
-        self.execution_state
-            .frames_stack
-            .push(Frame::new(offset, self.execution_state.instruction_counter));
+        self.execution_state.frames_stack.push(Frame::new(
+            offset,
+            self.execution_state.instruction_counter,
+            self.location.clone(),
+        ));
 
         let mut transaction_field_iter = 0..4;
 
@@ -423,6 +430,7 @@ where
             .ok_or(MalformedBytecode::StackUnderflow)?;
 
         self.execution_state.instruction_counter = frame.return_address;
+        self.location = frame.caller_location;
 
         self.execution_state
             .data_stack
@@ -0,0 +1,25 @@
+//!
+//! The virtual machine contract output event.
+//!
+
+use zinc_build::Value as BuildValue;
+
+///
+/// The virtual machine contract output event.
+///
+#[derive(Debug)]
+pub struct Event {
+    /// The event name, which is the emitted structure identifier.
+    pub name: String,
+    /// The event data.
+    pub value: BuildValue,
+}
+
+impl Event {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, value: BuildValue) -> Self {
+        Self { name, value }
+    }
+}
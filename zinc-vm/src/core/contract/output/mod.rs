@@ -2,10 +2,12 @@
 //! The virtual machine contract output.
 //!
 
+pub mod event;
 pub mod transfer;
 
 use zinc_build::Value as BuildValue;
 
+use self::event::Event;
 use self::transfer::Transfer;
 
 ///
@@ -19,17 +21,25 @@ pub struct Output {
     pub storage: BuildValue,
     /// The transfers executed in the contract method.
     pub transfers: Vec<Transfer>,
+    /// The events emitted in the contract method.
+    pub events: Vec<Event>,
 }
 
 impl Output {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(result: BuildValue, storage: BuildValue, transfers: Vec<Transfer>) -> Self {
+    pub fn new(
+        result: BuildValue,
+        storage: BuildValue,
+        transfers: Vec<Transfer>,
+        events: Vec<Event>,
+    ) -> Self {
         Self {
             result,
             storage,
             transfers,
+            events,
         }
     }
 }
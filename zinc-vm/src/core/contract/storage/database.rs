@@ -14,6 +14,7 @@ use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
+#[derive(Clone)]
 pub struct Storage<E: IEngine> {
     hash_tree: Vec<Vec<u8>>,
     leaf_values: Vec<LeafVariant<E>>,
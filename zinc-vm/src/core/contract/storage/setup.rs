@@ -14,6 +14,7 @@ use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
+#[derive(Clone)]
 pub struct Storage<E: IEngine> {
     leaf_values: Vec<Vec<Scalar<E>>>,
     depth: usize,
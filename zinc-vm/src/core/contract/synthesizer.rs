@@ -2,7 +2,9 @@
 //! The virtual machine contract synthesizer.
 //!
 
+use std::cell::RefCell;
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 use num::BigInt;
 
@@ -23,9 +25,10 @@ use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::contract::storage::StorageGadget;
 use crate::IEngine;
 
-pub struct Synthesizer<'a, E: IEngine, S: IMerkleTree<E>> {
+#[derive(Clone)]
+pub struct Synthesizer<E: IEngine, S: IMerkleTree<E>> {
     pub inputs: Option<Vec<BigInt>>,
-    pub output: &'a mut Option<Result<Vec<Option<BigInt>>, RuntimeError>>,
+    pub output: Rc<RefCell<Option<Result<Vec<Option<BigInt>>, RuntimeError>>>>,
     pub bytecode: BytecodeContract,
     pub method: ContractMethod,
     pub storage: S,
@@ -34,7 +37,7 @@ pub struct Synthesizer<'a, E: IEngine, S: IMerkleTree<E>> {
     pub _pd: PhantomData<E>,
 }
 
-impl<E, S> bellman::Circuit<E> for Synthesizer<'_, E, S>
+impl<E, S> bellman::Circuit<E> for Synthesizer<E, S>
 where
     E: IEngine,
     S: IMerkleTree<E>,
@@ -52,14 +55,15 @@ where
             self.transaction,
         );
 
-        *self.output = Some(contract.run(
+        let result = contract.run(
             self.bytecode,
             self.method.input,
             self.inputs.as_deref(),
-            |_| {},
+            |_, _, _, _| {},
             |_| Ok(()),
             self.method.address,
-        ));
+        );
+        self.output.replace(Some(result));
 
         Ok(())
     }
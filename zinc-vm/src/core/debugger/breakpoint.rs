@@ -0,0 +1,64 @@
+//!
+//! The virtual machine debugger breakpoint.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::core::location::Location;
+
+///
+/// The source code breakpoint.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Breakpoint {
+    /// The file path or its suffix.
+    pub file: String,
+    /// The line number.
+    pub line: usize,
+}
+
+impl Breakpoint {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(file: String, line: usize) -> Self {
+        Self { file, line }
+    }
+
+    ///
+    /// Checks if the breakpoint points to the `location` line.
+    ///
+    pub fn matches(&self, location: &Location) -> bool {
+        location.line == Some(self.line)
+            && location
+                .file
+                .as_ref()
+                .map(|file| file.ends_with(self.file.as_str()))
+                .unwrap_or_default()
+    }
+}
+
+impl FromStr for Breakpoint {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.rsplitn(2, ':');
+        let line = parts
+            .next()
+            .and_then(|line| line.parse::<usize>().ok())
+            .ok_or_else(|| format!("expected `<file>:<line>`, found `{}`", value))?;
+        let file = parts
+            .next()
+            .filter(|file| !file.is_empty())
+            .ok_or_else(|| format!("expected `<file>:<line>`, found `{}`", value))?;
+
+        Ok(Self::new(file.to_owned(), line))
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
@@ -0,0 +1,482 @@
+//!
+//! The virtual machine interactive debugger.
+//!
+
+pub mod breakpoint;
+pub mod snapshot;
+pub mod unsatisfied;
+
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+use std::str::FromStr;
+
+use zinc_build::Instruction;
+
+use crate::core::execution_state::ExecutionState;
+use crate::core::location::Location;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::IEngine;
+
+use self::breakpoint::Breakpoint;
+use self::snapshot::Snapshot;
+use self::snapshot::Variable;
+use self::unsatisfied::Unsatisfied;
+
+///
+/// The virtual machine debugger hook, which is called after each executed instruction.
+///
+pub trait IDebugger<E: IEngine> {
+    ///
+    /// Inspects the state after an instruction, which has left the constraint system
+    /// `is_satisfied`, and left the execution at `location`.
+    ///
+    /// The `storage` is only passed for contracts.
+    ///
+    fn on_instruction<S: IMerkleTree<E>>(
+        &mut self,
+        is_satisfied: bool,
+        state: &ExecutionState<E>,
+        location: &Location,
+        storage: Option<&S>,
+    );
+}
+
+///
+/// The debugger execution mode.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    /// Stops after the specified number of instructions.
+    Step(usize),
+    /// Stops at breakpoints and at the first unsatisfied constraint.
+    Continue,
+    /// Never stops again.
+    Detached,
+}
+
+///
+/// The interactive debugger, which is driven by the virtual machine instruction callback.
+///
+/// The commands are read from `input` and the responses are written to `output`.
+///
+pub struct Debugger<R, W> {
+    /// The application bytecode instructions.
+    instructions: Vec<Instruction>,
+    /// The contract storage field names.
+    storage_fields: Vec<String>,
+    /// The breakpoints set by the user.
+    breakpoints: Vec<Breakpoint>,
+    /// The current execution mode.
+    mode: Mode,
+    /// The number of executed instructions.
+    step: usize,
+    /// The address of the instruction which is going to be executed next.
+    next_address: usize,
+    /// The previous instruction source code line, used to stop at a breakpoint line only once.
+    previous_line: (Option<String>, Option<usize>),
+    /// The first unsatisfied constraint report.
+    unsatisfied: Option<Unsatisfied>,
+    /// The commands input.
+    input: R,
+    /// The responses output.
+    output: W,
+}
+
+impl<R, W> Debugger<R, W>
+where
+    R: BufRead,
+    W: Write,
+{
+    /// The command prompt.
+    const PROMPT: &'static str = "(zdb) ";
+
+    /// The commands help.
+    const HELP: &'static str = r#"Commands:
+    s, step [N]           execute the next N instructions (1 by default, also an empty line)
+    c, continue           execute until a breakpoint or the first unsatisfied constraint
+    b, break <file:line>  set a breakpoint
+    d, delete <file:line> remove a breakpoint
+    breakpoints           list the breakpoints
+    w, where              print the current location and the next instruction
+    bt, backtrace         print the call stack
+    stack                 print the evaluation stack
+    data                  print the data stack
+    storage               print the contract storage
+    q, quit               detach the debugger and execute until the end
+    h, help               print this help"#;
+
+    ///
+    /// Creates a debugger, which is going to start at the `entry` instruction address.
+    ///
+    pub fn new(
+        instructions: Vec<Instruction>,
+        entry: usize,
+        storage_fields: Vec<String>,
+        input: R,
+        output: W,
+    ) -> Self {
+        Self {
+            instructions,
+            storage_fields,
+            breakpoints: Vec::new(),
+            mode: Mode::Step(1),
+            step: 0,
+            next_address: entry,
+            previous_line: (None, None),
+            unsatisfied: None,
+            input,
+            output,
+        }
+    }
+
+    ///
+    /// Adds a breakpoint before the execution starts.
+    ///
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        if !self.breakpoints.contains(&breakpoint) {
+            self.breakpoints.push(breakpoint);
+        }
+    }
+
+    ///
+    /// Starts the execution without stopping at the first instruction.
+    ///
+    pub fn start_continued(&mut self) {
+        self.mode = Mode::Continue;
+    }
+
+    ///
+    /// Returns the first unsatisfied constraint report, if the constraint system has become
+    /// unsatisfied during the execution.
+    ///
+    pub fn unsatisfied(&self) -> Option<&Unsatisfied> {
+        self.unsatisfied.as_ref()
+    }
+
+    ///
+    /// Handles an executed instruction, stopping and reading the user commands if necessary.
+    ///
+    /// If the input or output fails, the debugger is detached.
+    ///
+    fn handle<E, S>(
+        &mut self,
+        is_satisfied: bool,
+        state: &ExecutionState<E>,
+        location: &Location,
+        storage: Option<&S>,
+    ) where
+        E: IEngine,
+        S: IMerkleTree<E>,
+    {
+        let address = self.next_address;
+        self.next_address = state.instruction_counter;
+        self.step += 1;
+
+        if let Err(error) = self.check(is_satisfied, address, location) {
+            log::error!("Debugger output error: {}", error);
+            self.mode = Mode::Detached;
+            return;
+        }
+
+        if let Err(error) = self.prompt(state, location, storage) {
+            log::error!("Debugger input error: {}", error);
+            self.mode = Mode::Detached;
+        }
+    }
+
+    ///
+    /// Updates the execution mode and reports the reason to stop, if there is any.
+    ///
+    fn check(&mut self, is_satisfied: bool, address: usize, location: &Location) -> io::Result<()> {
+        let mut should_stop = false;
+
+        if !is_satisfied && self.unsatisfied.is_none() {
+            let unsatisfied = Unsatisfied {
+                step: self.step,
+                address,
+                instruction: self.instructions.get(address).cloned(),
+                location: location.to_owned(),
+            };
+            writeln!(self.output, "{}", unsatisfied)?;
+            self.unsatisfied = Some(unsatisfied);
+            should_stop = true;
+        }
+
+        let line = (location.file.clone(), location.line);
+        if line != self.previous_line {
+            if let Some(breakpoint) = self
+                .breakpoints
+                .iter()
+                .find(|breakpoint| breakpoint.matches(location))
+            {
+                writeln!(self.output, "Breakpoint {} hit", breakpoint)?;
+                should_stop = true;
+            }
+            self.previous_line = line;
+        }
+
+        self.mode = match self.mode {
+            Mode::Detached => Mode::Detached,
+            _ if should_stop => Mode::Step(0),
+            Mode::Step(count) if count > 1 => Mode::Step(count - 1),
+            Mode::Step(_) => Mode::Step(0),
+            Mode::Continue => Mode::Continue,
+        };
+
+        Ok(())
+    }
+
+    ///
+    /// Reads and executes the user commands until the execution is resumed.
+    ///
+    fn prompt<E, S>(
+        &mut self,
+        state: &ExecutionState<E>,
+        location: &Location,
+        storage: Option<&S>,
+    ) -> io::Result<()>
+    where
+        E: IEngine,
+        S: IMerkleTree<E>,
+    {
+        if self.mode != Mode::Step(0) {
+            return Ok(());
+        }
+
+        self.print_where(location)?;
+
+        loop {
+            write!(self.output, "{}", Self::PROMPT)?;
+            self.output.flush()?;
+
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                writeln!(self.output)?;
+                self.mode = Mode::Detached;
+                return Ok(());
+            }
+
+            let mut words = line.split_whitespace();
+            let command = words.next().unwrap_or("step");
+            let argument = words.next();
+
+            match command {
+                "s" | "step" => match argument.map(str::parse::<usize>) {
+                    None => {
+                        self.mode = Mode::Step(1);
+                        return Ok(());
+                    }
+                    Some(Ok(count)) if count > 0 => {
+                        self.mode = Mode::Step(count);
+                        return Ok(());
+                    }
+                    Some(_) => writeln!(self.output, "Expected a positive number of steps")?,
+                },
+                "c" | "continue" => {
+                    self.mode = Mode::Continue;
+                    return Ok(());
+                }
+                "q" | "quit" => {
+                    self.mode = Mode::Detached;
+                    return Ok(());
+                }
+                "b" | "break" => match argument.map(Breakpoint::from_str) {
+                    Some(Ok(breakpoint)) => {
+                        writeln!(self.output, "Breakpoint {} set", breakpoint)?;
+                        self.add_breakpoint(breakpoint);
+                    }
+                    Some(Err(error)) => writeln!(self.output, "{}", error)?,
+                    None => writeln!(self.output, "Expected `<file>:<line>`")?,
+                },
+                "d" | "delete" => match argument.map(Breakpoint::from_str) {
+                    Some(Ok(breakpoint)) => {
+                        let count = self.breakpoints.len();
+                        self.breakpoints.retain(|existing| existing != &breakpoint);
+                        if self.breakpoints.len() < count {
+                            writeln!(self.output, "Breakpoint {} removed", breakpoint)?;
+                        } else {
+                            writeln!(self.output, "Breakpoint {} not found", breakpoint)?;
+                        }
+                    }
+                    Some(Err(error)) => writeln!(self.output, "{}", error)?,
+                    None => writeln!(self.output, "Expected `<file>:<line>`")?,
+                },
+                "breakpoints" => {
+                    if self.breakpoints.is_empty() {
+                        writeln!(self.output, "No breakpoints")?;
+                    }
+                    for breakpoint in self.breakpoints.iter() {
+                        writeln!(self.output, "{}", breakpoint)?;
+                    }
+                }
+                "w" | "where" => self.print_where(location)?,
+                "bt" | "backtrace" => {
+                    writeln!(self.output, "#0 {}", location)?;
+                    for (index, frame) in state.frames_stack.iter().skip(1).rev().enumerate() {
+                        writeln!(self.output, "#{} {}", index + 1, frame.caller_location)?;
+                    }
+                }
+                "stack" => write!(self.output, "{}", state.evaluation_stack)?,
+                "data" => write!(self.output, "{}", state.data_stack)?,
+                "storage" => match storage {
+                    Some(storage) => self.print_storage(state, location, storage)?,
+                    None => writeln!(self.output, "The storage is only available for contracts")?,
+                },
+                "h" | "help" => writeln!(self.output, "{}", Self::HELP)?,
+                command => writeln!(
+                    self.output,
+                    "Unknown command `{}`, type `help` to list the commands",
+                    command
+                )?,
+            }
+        }
+    }
+
+    ///
+    /// Prints the current location and the next instruction.
+    ///
+    fn print_where(&mut self, location: &Location) -> io::Result<()> {
+        writeln!(self.output, "[step {}] at {}", self.step, location)?;
+        match self.instructions.get(self.next_address) {
+            Some(instruction) => {
+                writeln!(self.output, " -> {:04}: {}", self.next_address, instruction)
+            }
+            None => writeln!(self.output, " -> the end of execution"),
+        }
+    }
+
+    ///
+    /// Prints the contract storage fields.
+    ///
+    fn print_storage<E, S>(
+        &mut self,
+        state: &ExecutionState<E>,
+        location: &Location,
+        storage: &S,
+    ) -> io::Result<()>
+    where
+        E: IEngine,
+        S: IMerkleTree<E>,
+    {
+        let snapshot = Snapshot::new(
+            state,
+            location,
+            Some((storage, self.storage_fields.as_slice())),
+        );
+
+        for field in snapshot.storage.unwrap_or_default().into_iter() {
+            Self::print_variable(&mut self.output, &field, 0)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Prints a variable with its nested variables.
+    ///
+    fn print_variable(output: &mut W, variable: &Variable, depth: usize) -> io::Result<()> {
+        writeln!(
+            output,
+            "{}{}: {}",
+            "    ".repeat(depth),
+            variable.name,
+            variable.value
+        )?;
+        for child in variable.children.iter() {
+            Self::print_variable(output, child, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<E, R, W> IDebugger<E> for Debugger<R, W>
+where
+    E: IEngine,
+    R: BufRead,
+    W: Write,
+{
+    fn on_instruction<S: IMerkleTree<E>>(
+        &mut self,
+        is_satisfied: bool,
+        state: &ExecutionState<E>,
+        location: &Location,
+        storage: Option<&S>,
+    ) {
+        self.handle(is_satisfied, state, location, storage);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+    use zinc_build::Instruction;
+    use zinc_build::NoOperation;
+
+    use crate::core::contract::storage::setup::Storage as SetupStorage;
+    use crate::core::execution_state::ExecutionState;
+    use crate::core::location::Location;
+
+    use super::Breakpoint;
+    use super::Debugger;
+    use super::IDebugger;
+
+    fn location(line: usize) -> Location {
+        Location {
+            file: Some("src/main.zn".to_owned()),
+            function: Some("main".to_owned()),
+            line: Some(line),
+            column: Some(5),
+        }
+    }
+
+    #[test]
+    fn breakpoint_parse() {
+        assert_eq!(
+            "src/main.zn:42".parse::<Breakpoint>(),
+            Ok(Breakpoint::new("src/main.zn".to_owned(), 42))
+        );
+        assert!("src/main.zn".parse::<Breakpoint>().is_err());
+        assert!(":42".parse::<Breakpoint>().is_err());
+    }
+
+    #[test]
+    fn stops_at_breakpoint_and_unsatisfied() {
+        let instructions = vec![Instruction::NoOperation(NoOperation); 4];
+        let mut output = Vec::new();
+        let mut debugger = Debugger::new(
+            instructions,
+            0,
+            vec![],
+            Cursor::new("break main.zn:3\ncontinue\ncontinue\n"),
+            &mut output,
+        );
+
+        let mut state = ExecutionState::<Bn256>::new();
+        for (line, is_satisfied) in [(1, true), (3, true), (3, true), (4, false)].iter() {
+            state.instruction_counter += 1;
+            debugger.on_instruction::<SetupStorage<Bn256>>(
+                *is_satisfied,
+                &state,
+                &location(*line),
+                None,
+            );
+        }
+
+        let unsatisfied = debugger
+            .unsatisfied()
+            .cloned()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        assert_eq!(unsatisfied.step, 4);
+        assert_eq!(unsatisfied.address, 3);
+        assert_eq!(unsatisfied.location.line, Some(4));
+
+        let output = String::from_utf8(output).expect(zinc_const::panic::DATA_CONVERSION);
+        assert_eq!(output.matches("Breakpoint main.zn:3 hit").count(), 1);
+        assert_eq!(output.matches("(zdb) ").count(), 4);
+    }
+}
@@ -0,0 +1,150 @@
+//!
+//! The virtual machine debugger state snapshot.
+//!
+
+use num::BigInt;
+
+use crate::core::contract::storage::leaf::LeafVariant;
+use crate::core::execution_state::cell::Cell;
+use crate::core::execution_state::ExecutionState;
+use crate::core::location::Location;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+///
+/// The named value shown to the user.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variable {
+    /// The variable name.
+    pub name: String,
+    /// The variable value representation.
+    pub value: String,
+    /// The nested variables, e.g. the contract storage map entries.
+    pub children: Vec<Variable>,
+}
+
+impl Variable {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, value: String, children: Vec<Variable>) -> Self {
+        Self {
+            name,
+            value,
+            children,
+        }
+    }
+}
+
+///
+/// The function call stack frame.
+///
+#[derive(Debug, Clone)]
+pub struct StackFrame {
+    /// The location the frame is currently at.
+    pub location: Location,
+    /// The data stack cells of the frame, named by their addresses.
+    pub locals: Vec<Variable>,
+}
+
+///
+/// The owned copy of the virtual machine state, which can be sent to another thread.
+///
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    /// The call stack frames, starting from the innermost one.
+    pub frames: Vec<StackFrame>,
+    /// The contract storage fields, if the application is a contract.
+    pub storage: Option<Vec<Variable>>,
+}
+
+impl Snapshot {
+    ///
+    /// Copies the execution state stopped at `location`.
+    ///
+    /// The `storage` is the contract storage with its field names.
+    ///
+    pub fn new<E, S>(
+        state: &ExecutionState<E>,
+        location: &Location,
+        storage: Option<(&S, &[String])>,
+    ) -> Self
+    where
+        E: IEngine,
+        S: IMerkleTree<E>,
+    {
+        let frames_stack = state.frames_stack.as_slice();
+
+        let mut frames = Vec::with_capacity(frames_stack.len());
+        for index in (1..frames_stack.len()).rev() {
+            let frame = &frames_stack[index];
+
+            let location = match frames_stack.get(index + 1) {
+                Some(callee) => callee.caller_location.to_owned(),
+                None => location.to_owned(),
+            };
+
+            let locals = state
+                .data_stack
+                .range(frame.stack_frame_start, frame.stack_frame_end)
+                .iter()
+                .enumerate()
+                .map(|(address, cell)| {
+                    let value = match cell {
+                        Some(Cell::Value(value)) => value.to_string(),
+                        None => "<empty>".to_owned(),
+                    };
+                    Variable::new(format!("[{}]", address), value, vec![])
+                })
+                .collect();
+
+            frames.push(StackFrame { location, locals });
+        }
+        if frames.is_empty() {
+            frames.push(StackFrame {
+                location: location.to_owned(),
+                locals: vec![],
+            });
+        }
+
+        let storage = storage.map(|(storage, names)| {
+            names
+                .iter()
+                .enumerate()
+                .map(|(index, name)| match storage.load(BigInt::from(index)) {
+                    Ok(leaf) => match leaf.leaf_values {
+                        LeafVariant::Array(values) => {
+                            Variable::new(name.to_owned(), Self::scalars(values.as_slice()), vec![])
+                        }
+                        LeafVariant::Map { data, .. } => Variable::new(
+                            name.to_owned(),
+                            format!("{} entries", data.len()),
+                            data.iter()
+                                .map(|(key, value)| {
+                                    Variable::new(
+                                        Self::scalars(key.as_slice()),
+                                        Self::scalars(value.as_slice()),
+                                        vec![],
+                                    )
+                                })
+                                .collect(),
+                        ),
+                    },
+                    Err(error) => Variable::new(name.to_owned(), error.to_string(), vec![]),
+                })
+                .collect()
+        });
+
+        Self { frames, storage }
+    }
+
+    ///
+    /// Formats a flat list of scalars.
+    ///
+    fn scalars<E: IEngine>(values: &[Scalar<E>]) -> String {
+        let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+        format!("[{}]", values.join(", "))
+    }
+}
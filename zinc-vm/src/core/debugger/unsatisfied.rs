@@ -0,0 +1,38 @@
+//!
+//! The virtual machine debugger unsatisfied constraint report.
+//!
+
+use std::fmt;
+
+use zinc_build::Instruction;
+
+use crate::core::location::Location;
+
+///
+/// The first instruction after which the constraint system became unsatisfied.
+///
+#[derive(Debug, Clone)]
+pub struct Unsatisfied {
+    /// The execution step number, starting from 1.
+    pub step: usize,
+    /// The instruction address.
+    pub address: usize,
+    /// The instruction, if the address is valid.
+    pub instruction: Option<Instruction>,
+    /// The source code location after the instruction.
+    pub location: Location,
+}
+
+impl fmt::Display for Unsatisfied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The constraint system became unsatisfied after step {}, instruction {:04}",
+            self.step, self.address
+        )?;
+        if let Some(ref instruction) = self.instruction {
+            write!(f, " `{}`", instruction)?;
+        }
+        write!(f, "\nat {}", self.location)
+    }
+}
@@ -173,6 +173,15 @@ impl<E: IEngine> DataStack<E> {
         Ok(())
     }
 
+    ///
+    /// Returns the memory cells in the `start..end` address range, clamped to the used memory.
+    ///
+    pub fn range(&self, start: usize, end: usize) -> &[Option<Cell<E>>] {
+        let end = std::cmp::min(end, self.memory.len());
+        let start = std::cmp::min(start, end);
+        &self.memory[start..end]
+    }
+
     ///
     /// Erase the memory starting from `start_address`.
     ///
@@ -3,6 +3,7 @@
 //!
 
 use crate::core::execution_state::block::Block;
+use crate::core::location::Location;
 use crate::IEngine;
 
 #[derive(Debug)]
@@ -11,17 +12,23 @@ pub struct Frame<E: IEngine> {
     pub return_address: usize,
     pub stack_frame_start: usize,
     pub stack_frame_end: usize,
+    pub caller_location: Location,
 }
 
 impl<E: IEngine> Frame<E> {
     const BLOCKS_INITIAL_CAPACITY: usize = 16;
 
-    pub fn new(data_stack_address: usize, return_address: usize) -> Self {
+    pub fn new(
+        data_stack_address: usize,
+        return_address: usize,
+        caller_location: Location,
+    ) -> Self {
         Self {
             blocks: Vec::with_capacity(Self::BLOCKS_INITIAL_CAPACITY),
             return_address,
             stack_frame_start: data_stack_address,
             stack_frame_end: data_stack_address,
+            caller_location,
         }
     }
 }
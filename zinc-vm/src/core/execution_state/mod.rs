@@ -10,6 +10,8 @@ pub mod function_frame;
 
 use std::fmt;
 
+use num::BigInt;
+
 use crate::core::contract::output::transfer::Transfer;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
@@ -26,12 +28,15 @@ pub struct ExecutionState<E: IEngine> {
     pub conditions_stack: Vec<Scalar<E>>,
     pub frames_stack: Vec<Frame<E>>,
     pub transfers: Vec<Transfer>,
+    /// The emitted events as their metadata indexes and flat values.
+    pub events: Vec<(usize, Vec<BigInt>)>,
 }
 
 impl<E: IEngine> ExecutionState<E> {
     const CONDITIONS_INITIAL_CAPACITY: usize = 16;
     const FRAMES_INITIAL_CAPACITY: usize = 16;
     const TRANSFERS_INITIAL_CAPACITY: usize = 4;
+    const EVENTS_INITIAL_CAPACITY: usize = 4;
 
     pub fn new() -> Self {
         Self {
@@ -41,6 +46,7 @@ impl<E: IEngine> ExecutionState<E> {
             conditions_stack: Vec::with_capacity(Self::CONDITIONS_INITIAL_CAPACITY),
             frames_stack: Vec::with_capacity(Self::FRAMES_INITIAL_CAPACITY),
             transfers: Vec::with_capacity(Self::TRANSFERS_INITIAL_CAPACITY),
+            events: Vec::with_capacity(Self::EVENTS_INITIAL_CAPACITY),
         }
     }
 }
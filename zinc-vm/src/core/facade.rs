@@ -2,12 +2,9 @@
 //! The virtual machine core facade.
 //!
 
-use franklin_crypto::bellman::groth16;
-use franklin_crypto::bellman::groth16::Proof;
-use franklin_crypto::bellman::groth16::VerifyingKey;
-
 use zinc_build::Value as BuildValue;
 
+use crate::core::backend::IBackend;
 use crate::error::VerificationError;
 use crate::gadgets;
 use crate::IEngine;
@@ -15,11 +12,15 @@ use crate::IEngine;
 pub struct Facade;
 
 impl Facade {
-    pub fn verify<E: IEngine>(
-        verifying_key: VerifyingKey<E>,
-        proof: Proof<E>,
+    pub fn verify<E, B>(
+        verifying_key: B::VerifyingKey,
+        proof: B::Proof,
         public_input: BuildValue,
-    ) -> Result<bool, VerificationError> {
+    ) -> Result<bool, VerificationError>
+    where
+        E: IEngine,
+        B: IBackend<E>,
+    {
         let public_input_flat = public_input
             .into_flat_values()
             .into_iter()
@@ -29,14 +30,6 @@ impl Facade {
             })
             .collect::<Result<Vec<E::Fr>, VerificationError>>()?;
 
-        let prepared_verifying_key = groth16::prepare_verifying_key(&verifying_key);
-        let success = groth16::verify_proof(
-            &prepared_verifying_key,
-            &proof,
-            public_input_flat.as_slice(),
-        )
-        .map_err(VerificationError::SynthesisError)?;
-
-        Ok(success)
+        B::verify(&verifying_key, &proof, public_input_flat.as_slice())
     }
 }
@@ -2,10 +2,13 @@
 //! The virtual machine core.
 //!
 
+pub mod backend;
 pub mod circuit;
 pub mod contract;
 pub mod counter;
+pub mod debugger;
 pub mod execution_state;
 pub mod facade;
 pub mod location;
+pub mod profiler;
 pub mod virtual_machine;
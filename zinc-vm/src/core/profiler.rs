@@ -0,0 +1,243 @@
+//!
+//! The virtual machine constraint profiler.
+//!
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::AddAssign;
+
+use crate::core::execution_state::function_frame::Frame;
+use crate::core::location::Location;
+use crate::IEngine;
+
+///
+/// The resources spent at a source code location.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Cost {
+    /// The number of constraints.
+    pub constraints: usize,
+    /// The number of witness variables.
+    pub witnesses: usize,
+    /// The number of executed instructions.
+    pub instructions: usize,
+}
+
+impl AddAssign for Cost {
+    fn add_assign(&mut self, other: Self) {
+        self.constraints += other.constraints;
+        self.witnesses += other.witnesses;
+        self.instructions += other.instructions;
+    }
+}
+
+///
+/// The profiler, which aggregates the cost of executed instructions by file, line, and function.
+///
+#[derive(Debug, Default)]
+pub struct Profiler {
+    /// The total cost.
+    total: Cost,
+    /// The cost by file.
+    files: HashMap<String, Cost>,
+    /// The cost by file and line.
+    lines: HashMap<(String, usize), Cost>,
+    /// The cost by function.
+    functions: HashMap<String, Cost>,
+    /// The constraints by call stack, where the function names are separated with `;`.
+    stacks: HashMap<String, usize>,
+}
+
+impl Profiler {
+    /// The placeholder for the unknown file or function.
+    const UNKNOWN: &'static str = "<unknown>";
+
+    /// The number of table rows printed for each section.
+    const TABLE_ROWS: usize = 20;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Records an instruction executed at `location` inside the `frames` call stack.
+    ///
+    /// The `constraints` and `witnesses` are the constraint system totals after the instruction,
+    /// so the instruction cost is the difference with the previous record.
+    ///
+    pub fn record<E: IEngine>(
+        &mut self,
+        constraints: usize,
+        witnesses: usize,
+        location: &Location,
+        frames: &[Frame<E>],
+    ) {
+        let cost = Cost {
+            constraints: constraints - self.total.constraints,
+            witnesses: witnesses - self.total.witnesses,
+            instructions: 1,
+        };
+        self.total += cost;
+
+        let file = location
+            .file
+            .clone()
+            .unwrap_or_else(|| Self::UNKNOWN.to_owned());
+        let function = location
+            .function
+            .clone()
+            .unwrap_or_else(|| Self::UNKNOWN.to_owned());
+
+        *self.files.entry(file.clone()).or_default() += cost;
+        if let Some(line) = location.line {
+            *self.lines.entry((file, line)).or_default() += cost;
+        }
+        *self.functions.entry(function.clone()).or_default() += cost;
+
+        if cost.constraints > 0 {
+            let mut stack: Vec<&str> = frames
+                .iter()
+                .filter_map(|frame| frame.caller_location.function.as_deref())
+                .collect();
+            stack.push(function.as_str());
+            *self.stacks.entry(stack.join(";")).or_default() += cost.constraints;
+        }
+    }
+
+    ///
+    /// The total cost of the execution.
+    ///
+    pub fn total(&self) -> Cost {
+        self.total
+    }
+
+    ///
+    /// Renders the call stacks in the folded format, which is accepted by the flamegraph tools.
+    ///
+    pub fn folded(&self) -> String {
+        let mut stacks: Vec<(&String, &usize)> = self.stacks.iter().collect();
+        stacks.sort();
+
+        stacks
+            .into_iter()
+            .map(|(stack, constraints)| format!("{} {}\n", stack, constraints))
+            .collect()
+    }
+
+    ///
+    /// Writes the table section sorted by the number of constraints in descending order.
+    ///
+    fn write_section(
+        f: &mut fmt::Formatter<'_>,
+        title: &str,
+        mut entries: Vec<(String, Cost)>,
+    ) -> fmt::Result {
+        entries.sort_by(|(name_1, cost_1), (name_2, cost_2)| {
+            cost_2
+                .constraints
+                .cmp(&cost_1.constraints)
+                .then(cost_2.witnesses.cmp(&cost_1.witnesses))
+                .then(name_1.cmp(name_2))
+        });
+
+        writeln!(
+            f,
+            "{:>12} {:>12} {:>12}  {}",
+            "constraints", "witnesses", "instructions", title
+        )?;
+        for (name, cost) in entries.into_iter().take(Self::TABLE_ROWS) {
+            writeln!(
+                f,
+                "{:>12} {:>12} {:>12}  {}",
+                cost.constraints, cost.witnesses, cost.instructions, name
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Profiler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>12} {:>12} {:>12}  total",
+            self.total.constraints, self.total.witnesses, self.total.instructions
+        )?;
+        writeln!(f)?;
+
+        Self::write_section(
+            f,
+            "function",
+            self.functions
+                .iter()
+                .map(|(name, cost)| (name.to_owned(), *cost))
+                .collect(),
+        )?;
+        writeln!(f)?;
+
+        Self::write_section(
+            f,
+            "file",
+            self.files
+                .iter()
+                .map(|(name, cost)| (name.to_owned(), *cost))
+                .collect(),
+        )?;
+        writeln!(f)?;
+
+        Self::write_section(
+            f,
+            "line",
+            self.lines
+                .iter()
+                .map(|((file, line), cost)| (format!("{}:{}", file, line), *cost))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+    use crate::core::execution_state::function_frame::Frame;
+    use crate::core::location::Location;
+    use crate::core::profiler::Cost;
+    use crate::core::profiler::Profiler;
+
+    fn location(function: &str, line: usize) -> Location {
+        Location {
+            file: Some("main.zn".to_owned()),
+            function: Some(function.to_owned()),
+            line: Some(line),
+            column: None,
+        }
+    }
+
+    #[test]
+    fn test_aggregation() {
+        let frames = vec![
+            Frame::<Bn256>::new(0, std::usize::MAX, Location::new()),
+            Frame::<Bn256>::new(0, 0, Location::new()),
+            Frame::<Bn256>::new(0, 0, location("main", 2)),
+        ];
+
+        let mut profiler = Profiler::new();
+        profiler.record(2, 1, &location("main", 2), &frames[..2]);
+        profiler.record(5, 4, &location("square", 7), &frames[..]);
+
+        assert_eq!(
+            profiler.total(),
+            Cost {
+                constraints: 5,
+                witnesses: 4,
+                instructions: 2,
+            }
+        );
+        assert_eq!(profiler.folded(), "main 2\nmain;square 3\n");
+    }
+}
@@ -119,6 +119,12 @@ pub enum RuntimeError {
 
     #[fail(display = "contract method `{}` does not exist", _0)]
     MethodNotFound { found: String },
+
+    #[fail(
+        display = "the reference string is too small: expected at least {} points, found {}",
+        expected, found
+    )]
+    ReferenceStringTooSmall { expected: usize, found: usize },
 }
 
 impl From<SynthesisError> for RuntimeError {
@@ -1,3 +1,5 @@
+pub mod pedersen;
+pub mod poseidon;
 pub mod sha256;
 
 use franklin_crypto::bellman::ConstraintSystem;
@@ -27,3 +29,64 @@ pub trait IHasher<E: IEngine>: Default {
     where
         CS: ConstraintSystem<E>;
 }
+
+#[cfg(test)]
+mod tests {
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::num::AllocatedNum;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use rand::Rng;
+    use rand::SeedableRng;
+    use rand::XorShiftRng;
+
+    use crate::gadgets::contract::merkle_tree::hasher::pedersen::Hasher as PedersenHasher;
+    use crate::gadgets::contract::merkle_tree::hasher::poseidon::Hasher as PoseidonHasher;
+    use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
+    use crate::gadgets::contract::merkle_tree::hasher::IHasher;
+    use crate::gadgets::scalar::Scalar;
+
+    fn hash_leaf_and_node<H: IHasher<Bn256>>(hasher: H) {
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let mut leaf_value = Vec::with_capacity(3);
+        for index in 0..3 {
+            leaf_value.push(Scalar::<Bn256>::from(
+                AllocatedNum::alloc(
+                    cs.namespace(|| format!("field {}", index)),
+                    || Ok(rng.gen()),
+                )
+                .expect(zinc_const::panic::TEST_DATA_VALID),
+            ));
+        }
+
+        let leaf_hash = hasher
+            .leaf_value_hash(cs.namespace(|| "leaf"), leaf_value.as_slice())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(leaf_hash.len(), hasher.hash_width());
+
+        let node_hash = hasher
+            .node_hash(cs.namespace(|| "node"), &leaf_hash, &leaf_hash)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(node_hash.len(), hasher.hash_width());
+
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_sha256() {
+        hash_leaf_and_node(Sha256Hasher::default());
+    }
+
+    #[test]
+    fn test_pedersen() {
+        hash_leaf_and_node(PedersenHasher::default());
+    }
+
+    #[test]
+    fn test_poseidon() {
+        hash_leaf_and_node(PoseidonHasher::default());
+    }
+}
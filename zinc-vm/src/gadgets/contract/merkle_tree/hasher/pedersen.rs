@@ -0,0 +1,87 @@
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::pedersen_hash;
+use franklin_crypto::circuit::pedersen_hash::Personalization;
+
+use crate::error::RuntimeError;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+#[derive(Default)]
+pub struct Hasher {}
+
+impl<E: IEngine> IMerkleTreeHasher<E> for Hasher {
+    fn hash_width(&self) -> usize {
+        zinc_const::bitlength::FIELD
+    }
+
+    fn leaf_value_hash<CS>(
+        &self,
+        mut cs: CS,
+        leaf_value: &[Scalar<E>],
+    ) -> Result<Vec<Boolean>, RuntimeError>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let mut digest_bits: Option<Vec<Boolean>> = None;
+
+        // the fields are chained one by one to stay within the Pedersen hash input limit
+        for (index, field) in leaf_value.iter().enumerate() {
+            let field_bits = field.to_expression::<CS>().into_bits_le_strict(
+                cs.namespace(|| format!("{} field of leaf value to bits", index)),
+            )?;
+
+            let preimage = match digest_bits.take() {
+                Some(mut preimage) => {
+                    preimage.extend(field_bits);
+                    preimage
+                }
+                None => field_bits,
+            };
+
+            let digest = pedersen_hash::pedersen_hash(
+                cs.namespace(|| format!("{} field of leaf value pedersen", index)),
+                Personalization::NoteCommitment,
+                preimage.as_slice(),
+                E::jubjub_params(),
+            )?;
+
+            digest_bits = Some(digest.get_x().into_bits_le_strict(
+                cs.namespace(|| format!("{} field of leaf value digest to bits", index)),
+            )?);
+        }
+
+        Ok(digest_bits
+            .unwrap_or_else(|| vec![Boolean::Constant(false); zinc_const::bitlength::FIELD]))
+    }
+
+    fn node_hash<CS>(
+        &self,
+        mut cs: CS,
+        left_node: &[Boolean],
+        right_node: &[Boolean],
+    ) -> Result<Vec<Boolean>, RuntimeError>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        if left_node.len() != zinc_const::bitlength::FIELD
+            || right_node.len() != zinc_const::bitlength::FIELD
+        {
+            return Err(RuntimeError::RequireError(
+                "Incorrect node hash width".into(),
+            ));
+        }
+
+        let digest = pedersen_hash::pedersen_hash(
+            cs.namespace(|| "node_pedersen"),
+            Personalization::MerkleTree(0),
+            &[left_node, right_node].concat(),
+            E::jubjub_params(),
+        )?;
+
+        Ok(digest
+            .get_x()
+            .into_bits_le_strict(cs.namespace(|| "node digest to bits"))?)
+    }
+}
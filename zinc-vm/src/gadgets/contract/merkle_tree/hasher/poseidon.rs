@@ -0,0 +1,85 @@
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::num::AllocatedNum;
+use franklin_crypto::circuit::poseidon_hash;
+
+use crate::error::RuntimeError;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+#[derive(Default)]
+pub struct Hasher {}
+
+impl<E: IEngine> IMerkleTreeHasher<E> for Hasher {
+    fn hash_width(&self) -> usize {
+        zinc_const::bitlength::FIELD
+    }
+
+    fn leaf_value_hash<CS>(
+        &self,
+        mut cs: CS,
+        leaf_value: &[Scalar<E>],
+    ) -> Result<Vec<Boolean>, RuntimeError>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        if leaf_value.is_empty() {
+            return Ok(vec![Boolean::Constant(false); zinc_const::bitlength::FIELD]);
+        }
+
+        let mut preimage = Vec::with_capacity(leaf_value.len());
+        for (index, field) in leaf_value.iter().enumerate() {
+            preimage.push(field.to_allocated_num(
+                cs.namespace(|| format!("{} field of leaf value to num", index)),
+            )?);
+        }
+
+        Self::hash(cs, preimage.as_slice())
+    }
+
+    fn node_hash<CS>(
+        &self,
+        mut cs: CS,
+        left_node: &[Boolean],
+        right_node: &[Boolean],
+    ) -> Result<Vec<Boolean>, RuntimeError>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        if left_node.len() != zinc_const::bitlength::FIELD
+            || right_node.len() != zinc_const::bitlength::FIELD
+        {
+            return Err(RuntimeError::RequireError(
+                "Incorrect node hash width".into(),
+            ));
+        }
+
+        let left_node =
+            AllocatedNum::pack_bits_to_element(cs.namespace(|| "left node to num"), left_node)?;
+        let right_node =
+            AllocatedNum::pack_bits_to_element(cs.namespace(|| "right node to num"), right_node)?;
+
+        Self::hash(cs, &[left_node, right_node])
+    }
+}
+
+impl Hasher {
+    fn hash<E, CS>(mut cs: CS, preimage: &[AllocatedNum<E>]) -> Result<Vec<Boolean>, RuntimeError>
+    where
+        E: IEngine,
+        CS: ConstraintSystem<E>,
+    {
+        let digest = poseidon_hash::poseidon_hash(
+            cs.namespace(|| "poseidon"),
+            preimage,
+            E::poseidon_params(),
+        )?;
+        let digest = digest
+            .into_iter()
+            .next()
+            .ok_or_else(|| RuntimeError::InternalError("Poseidon hash output is empty".into()))?;
+
+        Ok(digest.into_bits_le_strict(cs.namespace(|| "digest to bits"))?)
+    }
+}
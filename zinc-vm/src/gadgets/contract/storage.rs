@@ -179,6 +179,10 @@ where
         Ok(self.root_hash.clone())
     }
 
+    pub fn inner(&self) -> &S {
+        &self.storage
+    }
+
     #[allow(clippy::should_implement_trait)]
     pub fn as_mut(&mut self) -> &mut S {
         self.storage.borrow_mut()
@@ -10,6 +10,7 @@ pub mod comparison;
 pub mod contract;
 pub mod logical;
 pub mod output;
+pub mod permutation;
 pub mod require;
 pub mod scalar;
 pub mod select;
@@ -0,0 +1,395 @@
+//!
+//! The permutation network gadgets.
+//!
+//! The Waksman network of size `n` permutes its inputs in any order using about
+//! `n * log2(n) - n` switches, each costing a single allocated bit and a selection.
+//!
+
+use num::bigint::ToBigInt;
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::AllocatedBit;
+use franklin_crypto::circuit::boolean::Boolean;
+
+use crate::error::RuntimeError;
+use crate::gadgets;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+///
+/// Sorts the `values` in ascending order.
+///
+/// The sorted order is computed out of circuit and routed through a permutation network,
+/// whereupon the outputs are constrained to be ordered.
+///
+pub fn sort<E, CS>(mut cs: CS, values: &[Scalar<E>]) -> Result<Vec<Scalar<E>>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    if values.len() < 2 {
+        return Ok(values.to_vec());
+    }
+
+    if values.iter().all(Scalar::is_constant) {
+        let mut sorted = values.to_vec();
+        sorted.sort_by_key(ToBigInt::to_bigint);
+        return Ok(sorted);
+    }
+
+    let keys: Option<Vec<BigInt>> = values.iter().map(ToBigInt::to_bigint).collect();
+
+    let sorted = match keys {
+        Some(keys) => {
+            let mut permutation: Vec<usize> = (0..values.len()).collect();
+            permutation.sort_by(|a, b| keys[*a].cmp(&keys[*b]));
+            let mut bits = route(permutation.as_slice()).into_iter().map(Some);
+            apply(cs.namespace(|| "network"), values.to_vec(), &mut bits)?
+        }
+        None => {
+            let mut bits = std::iter::repeat(None);
+            apply(cs.namespace(|| "network"), values.to_vec(), &mut bits)?
+        }
+    };
+
+    for index in 0..sorted.len() - 1 {
+        let is_ordered = gadgets::comparison::lesser_or_equals(
+            cs.namespace(|| format!("ordered {}", index)),
+            &sorted[index],
+            &sorted[index + 1],
+        )?;
+        gadgets::require::require(
+            cs.namespace(|| format!("require ordered {}", index)),
+            is_ordered,
+            Some("array::sort: the permutation witness is not ordered"),
+        )?;
+    }
+
+    Ok(sorted)
+}
+
+///
+/// Computes the switch settings, which route the network inputs so that
+/// `outputs[j] = inputs[permutation[j]]`.
+///
+/// The settings are ordered the same way they are consumed by `apply`: the input switches,
+/// the top subnetwork, the bottom subnetwork, and the output switches. The `true` setting
+/// means that the switch crosses its inputs.
+///
+fn route(permutation: &[usize]) -> Vec<bool> {
+    let size = permutation.len();
+    match size {
+        0 | 1 => return vec![],
+        2 => return vec![permutation[0] == 1],
+        _ => {}
+    }
+
+    let top_size = size / 2;
+    let mut inverse = vec![0; size];
+    for (output, input) in permutation.iter().enumerate() {
+        inverse[*input] = output;
+    }
+
+    // `false` stands for the top subnetwork, `true` stands for the bottom one
+    let mut input_colors: Vec<Option<bool>> = vec![None; size];
+    let mut output_colors: Vec<Option<bool>> = vec![None; size];
+
+    // the last input and output of an odd network, and the last output pair of an even network
+    // are not switched, so their subnetworks are fixed
+    let mut stack: Vec<(bool, usize)> = Vec::with_capacity(size * 2);
+    if size % 2 == 1 {
+        input_colors[size - 1] = Some(true);
+        output_colors[size - 1] = Some(true);
+        stack.push((false, size - 1));
+        stack.push((true, size - 1));
+    } else {
+        output_colors[size - 2] = Some(false);
+        output_colors[size - 1] = Some(true);
+        stack.push((true, size - 2));
+        stack.push((true, size - 1));
+    }
+
+    let mut next = 0;
+    loop {
+        while let Some((is_output, index)) = stack.pop() {
+            let partner = if index / 2 < top_size {
+                Some(index ^ 1)
+            } else {
+                None
+            };
+            let (color, linked) = if is_output {
+                (
+                    output_colors[index].expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    permutation[index],
+                )
+            } else {
+                (
+                    input_colors[index].expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    inverse[index],
+                )
+            };
+
+            let (linked_colors, partner_colors) = if is_output {
+                (&mut input_colors, &mut output_colors)
+            } else {
+                (&mut output_colors, &mut input_colors)
+            };
+            if linked_colors[linked].is_none() {
+                linked_colors[linked] = Some(color);
+                stack.push((!is_output, linked));
+            }
+            if let Some(partner) = partner {
+                if partner_colors[partner].is_none() {
+                    partner_colors[partner] = Some(!color);
+                    stack.push((is_output, partner));
+                }
+            }
+        }
+
+        while next < size && input_colors[next].is_some() {
+            next += 1;
+        }
+        if next == size {
+            break;
+        }
+        input_colors[next] = Some(false);
+        stack.push((false, next));
+    }
+
+    let input_colors: Vec<bool> = input_colors
+        .into_iter()
+        .map(|color| color.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS))
+        .collect();
+    let output_colors: Vec<bool> = output_colors
+        .into_iter()
+        .map(|color| color.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS))
+        .collect();
+
+    let mut top = Vec::with_capacity(top_size);
+    let mut bottom = Vec::with_capacity(size - top_size);
+    for index in 0..top_size {
+        let (top_output, bottom_output) = if output_colors[2 * index] {
+            (2 * index + 1, 2 * index)
+        } else {
+            (2 * index, 2 * index + 1)
+        };
+        top.push(permutation[top_output] / 2);
+        bottom.push(permutation[bottom_output] / 2);
+    }
+    if size % 2 == 1 {
+        bottom.push(permutation[size - 1] / 2);
+    }
+
+    let mut bits = Vec::with_capacity(size * 2);
+    for index in 0..top_size {
+        bits.push(input_colors[2 * index]);
+    }
+    bits.extend(route(top.as_slice()));
+    bits.extend(route(bottom.as_slice()));
+    for index in 0..output_switches(size) {
+        bits.push(output_colors[2 * index]);
+    }
+    bits
+}
+
+///
+/// Routes the `inputs` through the network, allocating a switch for each of the `bits`.
+///
+/// The bits are `None` when the witness is unknown, e.g. during the trusted setup.
+///
+fn apply<E, CS, I>(
+    mut cs: CS,
+    inputs: Vec<Scalar<E>>,
+    bits: &mut I,
+) -> Result<Vec<Scalar<E>>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+    I: Iterator<Item = Option<bool>>,
+{
+    let size = inputs.len();
+    match size {
+        0 | 1 => return Ok(inputs),
+        2 => {
+            let (upper, lower) = switch(
+                cs.namespace(|| "switch"),
+                &inputs[0],
+                &inputs[1],
+                bits.next().flatten(),
+            )?;
+            return Ok(vec![upper, lower]);
+        }
+        _ => {}
+    }
+
+    let top_size = size / 2;
+    let mut top = Vec::with_capacity(top_size);
+    let mut bottom = Vec::with_capacity(size - top_size);
+    for index in 0..top_size {
+        let (upper, lower) = switch(
+            cs.namespace(|| format!("input switch {}", index)),
+            &inputs[2 * index],
+            &inputs[2 * index + 1],
+            bits.next().flatten(),
+        )?;
+        top.push(upper);
+        bottom.push(lower);
+    }
+    if size % 2 == 1 {
+        bottom.push(inputs[size - 1].clone());
+    }
+
+    let top = apply(cs.namespace(|| "top"), top, bits)?;
+    let bottom = apply(cs.namespace(|| "bottom"), bottom, bits)?;
+
+    let mut outputs = Vec::with_capacity(size);
+    for index in 0..output_switches(size) {
+        let (upper, lower) = switch(
+            cs.namespace(|| format!("output switch {}", index)),
+            &top[index],
+            &bottom[index],
+            bits.next().flatten(),
+        )?;
+        outputs.push(upper);
+        outputs.push(lower);
+    }
+    if size % 2 == 0 {
+        outputs.push(top[top_size - 1].clone());
+        outputs.push(bottom[top_size - 1].clone());
+    } else {
+        outputs.push(bottom[top_size].clone());
+    }
+
+    Ok(outputs)
+}
+
+///
+/// The number of output switches in a network of `size`.
+///
+fn output_switches(size: usize) -> usize {
+    if size % 2 == 1 {
+        size / 2
+    } else {
+        size / 2 - 1
+    }
+}
+
+///
+/// Allocates a switch, which crosses the `first` and `second` inputs if the `bit` is set.
+///
+fn switch<E, CS>(
+    mut cs: CS,
+    first: &Scalar<E>,
+    second: &Scalar<E>,
+    bit: Option<bool>,
+) -> Result<(Scalar<E>, Scalar<E>), RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let scalar_type = first.get_type();
+
+    let bit = AllocatedBit::alloc(cs.namespace(|| "bit"), bit)?;
+    let bit = Scalar::from_boolean(cs.namespace(|| "from boolean"), Boolean::from(bit))?;
+
+    let upper = gadgets::select::conditional(cs.namespace(|| "upper"), &bit, second, first)?;
+    let sum = gadgets::arithmetic::add::add(cs.namespace(|| "sum"), first, second)?;
+    let lower = gadgets::arithmetic::sub::sub(cs.namespace(|| "lower"), &sum, &upper)?;
+
+    Ok((upper, lower.to_type_unchecked(scalar_type)))
+}
+
+#[cfg(test)]
+mod tests {
+    use num::bigint::ToBigInt;
+    use num::BigInt;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    use zinc_build::IntegerType;
+    use zinc_build::ScalarType;
+
+    use crate::gadgets;
+
+    #[test]
+    fn test_route() {
+        fn permutations(size: usize) -> Vec<Vec<usize>> {
+            if size == 0 {
+                return vec![vec![]];
+            }
+
+            let mut result = Vec::new();
+            for permutation in permutations(size - 1) {
+                for position in 0..size {
+                    let mut permutation = permutation.clone();
+                    permutation.insert(position, size - 1);
+                    result.push(permutation);
+                }
+            }
+            result
+        }
+
+        for size in 0..=6 {
+            for permutation in permutations(size) {
+                let mut cs = TestConstraintSystem::<Bn256>::new();
+
+                let inputs = (0..size)
+                    .map(|index| {
+                        gadgets::witness::allocate(
+                            cs.namespace(|| format!("input {}", index)),
+                            Some(&BigInt::from(index)),
+                            ScalarType::Integer(IntegerType::U8),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .expect(zinc_const::panic::TEST_DATA_VALID);
+
+                let mut bits = super::route(permutation.as_slice()).into_iter().map(Some);
+                let outputs = super::apply(cs.namespace(|| "network"), inputs, &mut bits)
+                    .expect(zinc_const::panic::TEST_DATA_VALID);
+                assert!(bits.next().is_none(), "{:?}", permutation);
+                assert!(cs.is_satisfied(), "{:?}", permutation);
+
+                let outputs: Vec<Option<BigInt>> =
+                    outputs.iter().map(ToBigInt::to_bigint).collect();
+                let expected: Vec<Option<BigInt>> = permutation
+                    .into_iter()
+                    .map(|index| Some(BigInt::from(index)))
+                    .collect();
+                assert_eq!(outputs, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let inputs = [5, -3, 7, 0, -3, 2, 1]
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                gadgets::witness::allocate(
+                    cs.namespace(|| format!("input {}", index)),
+                    Some(&BigInt::from(*value)),
+                    ScalarType::Integer(IntegerType::I8),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let outputs = super::sort(cs.namespace(|| "sort"), inputs.as_slice())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert!(cs.is_satisfied());
+
+        let outputs: Vec<Option<BigInt>> = outputs.iter().map(ToBigInt::to_bigint).collect();
+        let expected: Vec<Option<BigInt>> = [-3, -3, 0, 1, 2, 5, 7]
+            .iter()
+            .map(|value| Some(BigInt::from(*value)))
+            .collect();
+        assert_eq!(outputs, expected);
+    }
+}
@@ -133,6 +133,22 @@ impl<E: IEngine> Scalar<E> {
         }
     }
 
+    pub fn to_allocated_num<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<AllocatedNum<E>, RuntimeError> {
+        let num = AllocatedNum::alloc(cs.namespace(|| "allocated num"), || self.grab_value())?;
+
+        cs.enforce(
+            || "allocated num equality",
+            |lc| lc + &self.to_linear_combination::<CS>(),
+            |lc| lc + CS::one(),
+            |lc| lc + num.get_variable(),
+        );
+
+        Ok(num)
+    }
+
     pub fn get_type(&self) -> ScalarType {
         self.scalar_type.to_owned()
     }
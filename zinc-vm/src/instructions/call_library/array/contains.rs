@@ -0,0 +1,64 @@
+//!
+//! The `std::array::contains` function call.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::cell::Cell;
+use crate::core::execution_state::ExecutionState;
+use crate::error::MalformedBytecode;
+use crate::error::RuntimeError;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Contains {
+    array_length: usize,
+}
+
+impl Contains {
+    pub fn new(inputs_count: usize) -> Result<Self, RuntimeError> {
+        inputs_count
+            .checked_sub(1)
+            .map(|array_length| Self { array_length })
+            .ok_or_else(|| {
+                MalformedBytecode::InvalidArguments(
+                    "array::contains expects at least 2 arguments".into(),
+                )
+                .into()
+            })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Contains {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storage: Option<&mut S>,
+    ) -> Result<(), RuntimeError> {
+        let value = state.evaluation_stack.pop()?.try_into_value()?;
+
+        let mut is_found = Scalar::new_constant_bool(false);
+        for index in 0..self.array_length {
+            let element = state.evaluation_stack.pop()?.try_into_value()?;
+
+            let is_equal = gadgets::comparison::equals(
+                cs.namespace(|| format!("equals {}", index)),
+                &element,
+                &value,
+            )?;
+            is_found = gadgets::logical::or::or(
+                cs.namespace(|| format!("found {}", index)),
+                &is_found,
+                &is_equal,
+            )?;
+        }
+
+        state.evaluation_stack.push(Cell::Value(is_found))?;
+
+        Ok(())
+    }
+}
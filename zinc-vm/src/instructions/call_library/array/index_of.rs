@@ -0,0 +1,91 @@
+//!
+//! The `std::array::index_of` function call.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use zinc_build::IntegerType;
+use zinc_build::ScalarType;
+
+use crate::core::execution_state::cell::Cell;
+use crate::core::execution_state::ExecutionState;
+use crate::error::MalformedBytecode;
+use crate::error::RuntimeError;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct IndexOf {
+    array_length: usize,
+}
+
+impl IndexOf {
+    pub fn new(inputs_count: usize) -> Result<Self, RuntimeError> {
+        inputs_count
+            .checked_sub(1)
+            .map(|array_length| Self { array_length })
+            .ok_or_else(|| {
+                MalformedBytecode::InvalidArguments(
+                    "array::index_of expects at least 2 arguments".into(),
+                )
+                .into()
+            })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for IndexOf {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storage: Option<&mut S>,
+    ) -> Result<(), RuntimeError> {
+        let value = state.evaluation_stack.pop()?.try_into_value()?;
+
+        let mut array = Vec::with_capacity(self.array_length);
+        for _ in 0..self.array_length {
+            array.push(state.evaluation_stack.pop()?.try_into_value()?);
+        }
+        array.reverse();
+
+        let index_type = ScalarType::Integer(IntegerType::new(false, zinc_const::bitlength::INDEX));
+
+        let mut result = Scalar::new_constant_usize(0, index_type.clone());
+        let mut is_found = Scalar::new_constant_bool(false);
+        for (index, element) in array.into_iter().enumerate() {
+            let is_equal = gadgets::comparison::equals(
+                cs.namespace(|| format!("equals {}", index)),
+                &element,
+                &value,
+            )?;
+            let is_not_found = gadgets::logical::not::not(
+                cs.namespace(|| format!("not found {}", index)),
+                &is_found,
+            )?;
+            let is_first = gadgets::logical::and::and(
+                cs.namespace(|| format!("first {}", index)),
+                &is_equal,
+                &is_not_found,
+            )?;
+
+            result = gadgets::select::conditional(
+                cs.namespace(|| format!("index {}", index)),
+                &is_first,
+                &Scalar::new_constant_usize(index, index_type.clone()),
+                &result,
+            )?;
+            is_found = gadgets::logical::or::or(
+                cs.namespace(|| format!("found {}", index)),
+                &is_found,
+                &is_equal,
+            )?;
+        }
+
+        state.evaluation_stack.push(Cell::Value(result))?;
+        state.evaluation_stack.push(Cell::Value(is_found))?;
+
+        Ok(())
+    }
+}
@@ -2,6 +2,11 @@
 //! The `std::array` module calls.
 //!
 
+pub mod contains;
+pub mod index_of;
 pub mod pad;
 pub mod reverse;
+pub mod sort;
+pub mod sum;
 pub mod truncate;
+pub mod zip;
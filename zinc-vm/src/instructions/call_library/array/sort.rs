@@ -0,0 +1,48 @@
+//!
+//! The `std::array::sort` function call.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::cell::Cell;
+use crate::core::execution_state::ExecutionState;
+use crate::error::RuntimeError;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Sort {
+    array_length: usize,
+}
+
+impl Sort {
+    pub fn new(inputs_count: usize) -> Result<Self, RuntimeError> {
+        Ok(Self {
+            array_length: inputs_count,
+        })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Sort {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        cs: CS,
+        state: &mut ExecutionState<E>,
+        _storage: Option<&mut S>,
+    ) -> Result<(), RuntimeError> {
+        let mut array = Vec::with_capacity(self.array_length);
+        for _ in 0..self.array_length {
+            array.push(state.evaluation_stack.pop()?.try_into_value()?);
+        }
+        array.reverse();
+
+        let sorted = gadgets::permutation::sort(cs, array.as_slice())?;
+
+        for value in sorted {
+            state.evaluation_stack.push(Cell::Value(value))?;
+        }
+
+        Ok(())
+    }
+}
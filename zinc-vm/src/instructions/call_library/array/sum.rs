@@ -0,0 +1,74 @@
+//!
+//! The `std::array::sum` function call.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::cell::Cell;
+use crate::core::execution_state::ExecutionState;
+use crate::error::MalformedBytecode;
+use crate::error::RuntimeError;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Sum {
+    array_length: usize,
+}
+
+impl Sum {
+    pub fn new(inputs_count: usize) -> Result<Self, RuntimeError> {
+        if inputs_count == 0 {
+            return Err(MalformedBytecode::InvalidArguments(
+                "array::sum expects a non-empty array".into(),
+            )
+            .into());
+        }
+
+        Ok(Self {
+            array_length: inputs_count,
+        })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Sum {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storage: Option<&mut S>,
+    ) -> Result<(), RuntimeError> {
+        let first = state.evaluation_stack.pop()?.try_into_value()?;
+        let sum_type = first.get_type();
+
+        // the intermediate sums are not type-checked, since only the result may overflow
+        let mut unchecked_sum = first;
+        for index in 1..self.array_length {
+            let element = state.evaluation_stack.pop()?.try_into_value()?;
+            unchecked_sum = gadgets::arithmetic::add::add(
+                cs.namespace(|| format!("sum {}", index)),
+                &unchecked_sum,
+                &element,
+            )?;
+        }
+
+        let condition = state
+            .conditions_stack
+            .last()
+            .cloned()
+            .ok_or(MalformedBytecode::StackUnderflow)?;
+
+        let sum = Scalar::conditional_type_check(
+            cs.namespace(|| "type check"),
+            &condition,
+            &unchecked_sum,
+            sum_type,
+        )?;
+
+        state.evaluation_stack.push(Cell::Value(sum))?;
+
+        Ok(())
+    }
+}
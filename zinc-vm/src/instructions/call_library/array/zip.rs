@@ -0,0 +1,57 @@
+//!
+//! The `std::array::zip` function call.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::MalformedBytecode;
+use crate::error::RuntimeError;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Zip {
+    array_length: usize,
+}
+
+impl Zip {
+    pub fn new(inputs_count: usize) -> Result<Self, RuntimeError> {
+        if inputs_count % 2 != 0 {
+            return Err(MalformedBytecode::InvalidArguments(
+                "array::zip expects two arrays of the same length".into(),
+            )
+            .into());
+        }
+
+        Ok(Self {
+            array_length: inputs_count / 2,
+        })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Zip {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        _cs: CS,
+        state: &mut ExecutionState<E>,
+        _storage: Option<&mut S>,
+    ) -> Result<(), RuntimeError> {
+        let mut right = Vec::with_capacity(self.array_length);
+        for _ in 0..self.array_length {
+            right.push(state.evaluation_stack.pop()?);
+        }
+
+        let mut left = Vec::with_capacity(self.array_length);
+        for _ in 0..self.array_length {
+            left.push(state.evaluation_stack.pop()?);
+        }
+
+        for (left, right) in left.into_iter().rev().zip(right.into_iter().rev()) {
+            state.evaluation_stack.push(left)?;
+            state.evaluation_stack.push(right)?;
+        }
+
+        Ok(())
+    }
+}
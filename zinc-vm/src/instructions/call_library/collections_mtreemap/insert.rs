@@ -71,7 +71,7 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Insert {
             .position(|(map_key, _value)| map_key.as_slice() == key.as_slice());
         let output = match position {
             Some(position) => data[position].1.to_owned(),
-            None => vec![Scalar::new_constant_bool(false); self.output_size],
+            None => vec![Scalar::new_constant_bool(false); self.output_size - 1],
         };
 
         for value in output.into_iter() {
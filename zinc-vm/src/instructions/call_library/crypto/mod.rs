@@ -3,5 +3,7 @@
 //!
 
 pub mod pedersen;
+pub mod poseidon;
+pub mod rescue;
 pub mod schnorr_verify;
 pub mod sha256;
@@ -0,0 +1,65 @@
+//!
+//! The `std::crypto::poseidon` function call.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::poseidon_hash;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::MalformedBytecode;
+use crate::error::RuntimeError;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Poseidon {
+    message_length: usize,
+}
+
+impl Poseidon {
+    pub fn new(message_length: usize) -> Result<Self, RuntimeError> {
+        if message_length == 0 {
+            return Err(MalformedBytecode::InvalidArguments(
+                "poseidon hash preimage must not be empty".into(),
+            )
+            .into());
+        }
+
+        Ok(Self { message_length })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Poseidon {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storage: Option<&mut S>,
+    ) -> Result<(), RuntimeError> {
+        let mut preimage = Vec::with_capacity(self.message_length);
+        for index in 0..self.message_length {
+            let num = state
+                .evaluation_stack
+                .pop()?
+                .try_into_value()?
+                .to_allocated_num(cs.namespace(|| format!("element {}", index)))?;
+
+            preimage.push(num);
+        }
+        preimage.reverse();
+
+        let digest = poseidon_hash::poseidon_hash(
+            cs.namespace(|| "poseidon"),
+            preimage.as_slice(),
+            E::poseidon_params(),
+        )?;
+        let digest = digest.into_iter().next().ok_or_else(|| {
+            MalformedBytecode::InvalidArguments("poseidon hash output is empty".into())
+        })?;
+
+        state.evaluation_stack.push(Scalar::from(digest).into())?;
+
+        Ok(())
+    }
+}
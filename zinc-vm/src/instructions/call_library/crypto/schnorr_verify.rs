@@ -2,6 +2,7 @@
 //! The `std::crypto::schnorr::Signature::verify` function call.
 //!
 
+use franklin_crypto::alt_babyjubjub::JubjubEngine;
 use franklin_crypto::bellman::pairing::ff::PrimeField;
 use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::baby_eddsa::EddsaSignature;
@@ -111,7 +112,7 @@ pub fn verify_signature<E, CS>(
     mut cs: CS,
     message: &[Scalar<E>],
     signature: &EddsaSignature<E>,
-    params: &E::Params,
+    params: &<E as JubjubEngine>::Params,
 ) -> Result<Scalar<E>, RuntimeError>
 where
     E: IEngine,
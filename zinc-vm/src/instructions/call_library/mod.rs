@@ -16,14 +16,20 @@ use zinc_build::LibraryFunctionIdentifier;
 
 use crate::core::execution_state::ExecutionState;
 use crate::core::virtual_machine::IVirtualMachine;
+use crate::error::MalformedBytecode;
 use crate::error::RuntimeError;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::instructions::IExecutable;
 use crate::IEngine;
 
+use self::array::contains::Contains as ArrayContains;
+use self::array::index_of::IndexOf as ArrayIndexOf;
 use self::array::pad::Pad as ArrayPad;
 use self::array::reverse::Reverse as ArrayReverse;
+use self::array::sort::Sort as ArraySort;
+use self::array::sum::Sum as ArraySum;
 use self::array::truncate::Truncate as ArrayTruncate;
+use self::array::zip::Zip as ArrayZip;
 use self::collections_mtreemap::contains::Contains as CollectionsMTreeMapContains;
 use self::collections_mtreemap::get::Get as CollectionsMTreeMapGet;
 use self::collections_mtreemap::insert::Insert as CollectionsMTreeMapInsert;
@@ -33,9 +39,12 @@ use self::convert::from_bits_signed::FromBitsSigned as ConvertFromBitsSigned;
 use self::convert::from_bits_unsigned::FromBitsUnsigned as ConvertFromBitsUnsigned;
 use self::convert::to_bits::ToBits as ConvertToBits;
 use self::crypto::pedersen::Pedersen as CryptoPedersen;
+use self::crypto::poseidon::Poseidon as CryptoPoseidon;
+use self::crypto::rescue::Rescue as CryptoRescue;
 use self::crypto::schnorr_verify::SchnorrSignatureVerify as CryptoSchnorrSignatureVerify;
 use self::crypto::sha256::Sha256 as CryptoSha256;
 use self::ff::invert::Inverse as FfInverse;
+use self::zksync::emit::Emit as ZksyncEmit;
 use self::zksync::transfer::Transfer as ZksyncTransfer;
 
 pub trait INativeCallable<E: IEngine, S: IMerkleTree<E>> {
@@ -56,6 +65,12 @@ impl<VM: IVirtualMachine> IExecutable<VM> for CallLibrary {
             LibraryFunctionIdentifier::CryptoPedersen => {
                 vm.call_native(CryptoPedersen::new(self.input_size)?)
             }
+            LibraryFunctionIdentifier::CryptoPoseidon => {
+                vm.call_native(CryptoPoseidon::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::CryptoRescue => {
+                vm.call_native(CryptoRescue::new(self.input_size)?)
+            }
             LibraryFunctionIdentifier::CryptoSchnorrSignatureVerify => {
                 vm.call_native(CryptoSchnorrSignatureVerify::new(self.input_size)?)
             }
@@ -76,10 +91,30 @@ impl<VM: IVirtualMachine> IExecutable<VM> for CallLibrary {
                 vm.call_native(ArrayTruncate::new(self.input_size)?)
             }
             LibraryFunctionIdentifier::ArrayPad => vm.call_native(ArrayPad::new(self.input_size)?),
+            LibraryFunctionIdentifier::ArrayMap | LibraryFunctionIdentifier::ArrayFold => {
+                Err(MalformedBytecode::InvalidArguments(
+                    "array::map and array::fold are inlined by the compiler".into(),
+                )
+                .into())
+            }
+            LibraryFunctionIdentifier::ArrayZip => vm.call_native(ArrayZip::new(self.input_size)?),
+            LibraryFunctionIdentifier::ArrayContains => {
+                vm.call_native(ArrayContains::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::ArrayIndexOf => {
+                vm.call_native(ArrayIndexOf::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::ArraySum => vm.call_native(ArraySum::new(self.input_size)?),
+            LibraryFunctionIdentifier::ArraySort => {
+                vm.call_native(ArraySort::new(self.input_size)?)
+            }
 
             LibraryFunctionIdentifier::FfInvert => vm.call_native(FfInverse),
 
             LibraryFunctionIdentifier::ZksyncTransfer => vm.call_native(ZksyncTransfer),
+            LibraryFunctionIdentifier::ZksyncEmit => {
+                vm.call_native(ZksyncEmit::new(self.input_size)?)
+            }
 
             LibraryFunctionIdentifier::CollectionsMTreeMapGet => vm.call_native(
                 CollectionsMTreeMapGet::new(self.input_size, self.output_size),
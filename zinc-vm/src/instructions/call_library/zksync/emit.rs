@@ -0,0 +1,70 @@
+//!
+//! The `zksync::emit` function call.
+//!
+
+use num::bigint::ToBigInt;
+use num::Zero;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::MalformedBytecode;
+use crate::error::RuntimeError;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Emit {
+    event_size: usize,
+}
+
+impl Emit {
+    pub fn new(inputs_count: usize) -> Result<Self, RuntimeError> {
+        if inputs_count == 0 {
+            return Err(MalformedBytecode::InvalidArguments(
+                "zksync::emit expects the event index".into(),
+            )
+            .into());
+        }
+
+        Ok(Self {
+            event_size: inputs_count - 1,
+        })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Emit {
+    fn call<CS>(
+        &self,
+        _cs: CS,
+        state: &mut ExecutionState<E>,
+        _storage: Option<&mut S>,
+    ) -> Result<(), RuntimeError>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let index = state
+            .evaluation_stack
+            .pop()?
+            .try_into_value()?
+            .get_constant_usize()?;
+
+        let mut values = Vec::with_capacity(self.event_size);
+        for _ in 0..self.event_size {
+            let value = state.evaluation_stack.pop()?.try_into_value()?;
+            values.push(value.to_bigint().unwrap_or_default());
+        }
+        values.reverse();
+
+        // the events emitted in the branches which are not taken are discarded
+        let is_emitted = match state.conditions_stack.last() {
+            Some(condition) => !condition.to_bigint().unwrap_or_default().is_zero(),
+            None => true,
+        };
+        if is_emitted {
+            state.events.push((index, values));
+        }
+
+        Ok(())
+    }
+}
@@ -16,12 +16,26 @@ pub(crate) mod instructions;
 
 pub use franklin_crypto::bellman::pairing::bn256::Bn256;
 
+pub use self::core::backend::groth16::Groth16;
+pub use self::core::backend::plonk::Plonk;
+pub use self::core::backend::Backend;
+pub use self::core::backend::IBackend;
 pub use self::core::circuit::facade::Facade as CircuitFacade;
 pub use self::core::circuit::output::Output as CircuitOutput;
 pub use self::core::contract::facade::Facade as ContractFacade;
 pub use self::core::contract::input::Input as ContractInput;
 pub use self::core::contract::output::Output as ContractOutput;
+pub use self::core::debugger::breakpoint::Breakpoint;
+pub use self::core::debugger::snapshot::Snapshot;
+pub use self::core::debugger::snapshot::StackFrame;
+pub use self::core::debugger::snapshot::Variable;
+pub use self::core::debugger::unsatisfied::Unsatisfied;
+pub use self::core::debugger::Debugger;
+pub use self::core::debugger::IDebugger;
+pub use self::core::execution_state::ExecutionState;
 pub use self::core::facade::Facade;
+pub use self::core::location::Location;
+pub use self::core::profiler::Profiler;
 pub use self::error::RuntimeError;
 pub use self::error::VerificationError;
 
@@ -31,17 +45,36 @@ use lazy_static::lazy_static;
 
 use franklin_crypto::alt_babyjubjub::AltJubjubBn256;
 use franklin_crypto::alt_babyjubjub::JubjubEngine;
+use franklin_crypto::poseidon::bn256::Bn256PoseidonParams;
+use franklin_crypto::poseidon::PoseidonEngine;
+use franklin_crypto::rescue::bn256::Bn256RescueParams;
+use franklin_crypto::rescue::RescueEngine;
 
-pub trait IEngine: fmt::Debug + JubjubEngine {
-    fn jubjub_params<'a>() -> &'a Self::Params;
+pub trait IEngine: fmt::Debug + JubjubEngine + RescueEngine + PoseidonEngine {
+    fn jubjub_params<'a>() -> &'a <Self as JubjubEngine>::Params;
+
+    fn rescue_params<'a>() -> &'a <Self as RescueEngine>::Params;
+
+    fn poseidon_params<'a>() -> &'a <Self as PoseidonEngine>::Params;
 }
 
 lazy_static! {
     static ref JUBJUB_BN256_PARAMS: AltJubjubBn256 = AltJubjubBn256::new();
+    static ref RESCUE_BN256_PARAMS: Bn256RescueParams = Bn256RescueParams::new_checked_2_into_1();
+    static ref POSEIDON_BN256_PARAMS: Bn256PoseidonParams =
+        Bn256PoseidonParams::new_checked_2_into_1();
 }
 
 impl IEngine for Bn256 {
-    fn jubjub_params<'a>() -> &'a Self::Params {
+    fn jubjub_params<'a>() -> &'a <Self as JubjubEngine>::Params {
         &JUBJUB_BN256_PARAMS
     }
+
+    fn rescue_params<'a>() -> &'a <Self as RescueEngine>::Params {
+        &RESCUE_BN256_PARAMS
+    }
+
+    fn poseidon_params<'a>() -> &'a <Self as PoseidonEngine>::Params {
+        &POSEIDON_BN256_PARAMS
+    }
 }
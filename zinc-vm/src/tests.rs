@@ -99,7 +99,7 @@ impl TestRunner {
             self.instructions,
         );
 
-        vm.run(circuit, Some(&[]), |_| {}, |_| Ok(()))
+        vm.run(circuit, Some(&[]), |_, _, _| {}, |_| Ok(()))
             .map_err(TestingError::RuntimeError)?;
 
         let cs = vm.constraint_system();
@@ -0,0 +1,214 @@
+//!
+//! The Zinc virtual machine `debug` subcommand.
+//!
+
+use std::convert::TryFrom;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde_json::Value as JsonValue;
+use structopt::StructOpt;
+
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_build::Application as BuildApplication;
+use zinc_build::ContractFieldValue as BuildContractFieldValue;
+use zinc_build::InputBuild;
+use zinc_build::Value as BuildValue;
+use zinc_zksync::TransactionMsg;
+
+use zinc_vm::Breakpoint;
+use zinc_vm::CircuitFacade;
+use zinc_vm::ContractFacade;
+use zinc_vm::ContractInput;
+use zinc_vm::Debugger;
+use zinc_vm::RuntimeError;
+
+use crate::arguments::command::IExecutable;
+use crate::error::Error;
+use crate::error::IErrorPath;
+
+///
+/// The Zinc virtual machine `debug` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "debug",
+    about = "Executes the bytecode step by step in the interactive debugger"
+)]
+pub struct Command {
+    /// The path to the binary bytecode file.
+    #[structopt(long = "binary")]
+    pub binary_path: PathBuf,
+
+    /// The path to the input JSON file.
+    #[structopt(long = "input")]
+    pub input_path: PathBuf,
+
+    /// The method name to call, if the application is a contract.
+    #[structopt(long = "method")]
+    pub method: Option<String>,
+
+    /// The breakpoints in the `<file>:<line>` format.
+    #[structopt(short = "b", long = "break")]
+    pub breakpoints: Vec<Breakpoint>,
+
+    /// Do not stop at the first instruction.
+    #[structopt(short = "c", long = "continue")]
+    pub is_continued: bool,
+}
+
+impl IExecutable for Command {
+    type Error = Error;
+
+    fn execute(self) -> Result<i32, Self::Error> {
+        // Read the bytecode
+        let bytecode =
+            fs::read(&self.binary_path).error_with_path(|| self.binary_path.to_string_lossy())?;
+        let application = BuildApplication::try_from_slice(bytecode.as_slice())
+            .map_err(Error::ApplicationDecoding)?;
+
+        // Read the input file
+        let input_template = fs::read_to_string(&self.input_path)
+            .error_with_path(|| self.input_path.to_string_lossy())?;
+        let input: InputBuild = serde_json::from_str(input_template.as_str())?;
+
+        let stdin = io::stdin();
+        let stdout = io::stdout();
+
+        let (result, unsatisfied) = match application {
+            BuildApplication::Circuit(circuit) => match input {
+                InputBuild::Circuit { arguments } => {
+                    let input_type = circuit.input.clone();
+                    let arguments = BuildValue::try_from_typed_json(arguments, input_type)?;
+
+                    let mut debugger = Debugger::new(
+                        circuit.instructions.clone(),
+                        circuit.address,
+                        vec![],
+                        stdin.lock(),
+                        stdout.lock(),
+                    );
+                    self.configure(&mut debugger);
+
+                    let result =
+                        CircuitFacade::new(circuit).debug::<Bn256, _>(arguments, &mut debugger);
+                    (result, debugger.unsatisfied().cloned())
+                }
+                InputBuild::Contract { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "circuit".to_owned(),
+                        found: "contract".to_owned(),
+                    })
+                }
+            },
+            BuildApplication::Contract(contract) => match input {
+                InputBuild::Circuit { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "contract".to_owned(),
+                        found: "circuit".to_owned(),
+                    })
+                }
+                InputBuild::Contract {
+                    arguments,
+                    msg: transaction,
+                    storage,
+                } => {
+                    let method_name = self.method.clone().ok_or(Error::MethodNameNotFound)?;
+                    let method = contract.methods.get(method_name.as_str()).cloned().ok_or(
+                        Error::MethodNotFound {
+                            name: method_name.clone(),
+                        },
+                    )?;
+
+                    let method_arguments = arguments.get(method_name.as_str()).cloned().ok_or(
+                        Error::MethodArgumentsNotFound {
+                            name: method_name.clone(),
+                        },
+                    )?;
+                    let method_arguments =
+                        BuildValue::try_from_typed_json(method_arguments, method.input)?;
+
+                    let storage_values = match storage {
+                        JsonValue::Array(array) => {
+                            let mut storage_values = Vec::with_capacity(contract.storage.len());
+                            for (field, value) in contract.storage.clone().into_iter().zip(array) {
+                                storage_values.push(BuildContractFieldValue::new(
+                                    field.name,
+                                    BuildValue::try_from_typed_json(value, field.r#type)?,
+                                    field.is_public,
+                                    field.is_implicit,
+                                ));
+                            }
+                            storage_values
+                        }
+                        value => return Err(Error::InvalidContractStorageFormat { found: value }),
+                    };
+
+                    let input = ContractInput::new(
+                        method_arguments,
+                        BuildValue::Contract(storage_values),
+                        method_name,
+                        TransactionMsg::try_from(&transaction).map_err(|error| {
+                            Error::InvalidTransaction {
+                                inner: error,
+                                found: transaction,
+                            }
+                        })?,
+                    );
+
+                    let mut debugger = Debugger::new(
+                        contract.instructions.clone(),
+                        method.address,
+                        contract
+                            .storage
+                            .iter()
+                            .map(|field| field.name.to_owned())
+                            .collect(),
+                        stdin.lock(),
+                        stdout.lock(),
+                    );
+                    self.configure(&mut debugger);
+
+                    let result =
+                        ContractFacade::new(contract).debug::<Bn256, _>(input, &mut debugger);
+                    (result, debugger.unsatisfied().cloned())
+                }
+            },
+        };
+
+        match result {
+            Ok(()) => {
+                println!("The execution has finished successfully");
+                Ok(zinc_const::exit_code::SUCCESS as i32)
+            }
+            Err(RuntimeError::UnsatisfiedConstraint) => {
+                if let Some(unsatisfied) = unsatisfied {
+                    println!("{}", unsatisfied);
+                }
+                Err(RuntimeError::UnsatisfiedConstraint.into())
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+impl Command {
+    ///
+    /// Applies the command line options to the debugger.
+    ///
+    fn configure<R, W>(&self, debugger: &mut Debugger<R, W>)
+    where
+        R: io::BufRead,
+        W: io::Write,
+    {
+        for breakpoint in self.breakpoints.iter() {
+            debugger.add_breakpoint(breakpoint.to_owned());
+        }
+
+        if self.is_continued {
+            debugger.start_continued();
+        }
+    }
+}
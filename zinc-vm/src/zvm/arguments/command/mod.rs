@@ -2,6 +2,8 @@
 //! The Zinc virtual machine subcommand.
 //!
 
+pub mod debug;
+pub mod profile;
 pub mod prove;
 pub mod run;
 pub mod setup;
@@ -12,6 +14,8 @@ use structopt::StructOpt;
 
 use crate::error::Error;
 
+use self::debug::Command as DebugCommand;
+use self::profile::Command as ProfileCommand;
 use self::prove::Command as ProveCommand;
 use self::run::Command as RunCommand;
 use self::setup::Command as SetupCommand;
@@ -41,6 +45,10 @@ pub enum Command {
     Run(RunCommand),
     /// Executes a unit test.
     Test(TestCommand),
+    /// Executes the bytecode and prints its constraint profile.
+    Profile(ProfileCommand),
+    /// Executes the bytecode step by step in the interactive debugger.
+    Debug(DebugCommand),
     /// Generates a pair of proving and verifying keys.
     Setup(SetupCommand),
     /// Generates a proof using the proving key.
@@ -56,6 +64,8 @@ impl IExecutable for Command {
         match self {
             Command::Run(inner) => inner.execute(),
             Command::Test(inner) => inner.execute(),
+            Command::Profile(inner) => inner.execute(),
+            Command::Debug(inner) => inner.execute(),
             Command::Setup(inner) => inner.execute(),
             Command::Prove(inner) => inner.execute(),
             Command::Verify(inner) => inner.execute(),
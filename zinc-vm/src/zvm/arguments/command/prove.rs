@@ -9,7 +9,6 @@ use std::path::PathBuf;
 use serde_json::Value as JsonValue;
 use structopt::StructOpt;
 
-use franklin_crypto::bellman::groth16::Parameters;
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 
 use zinc_build::Application as BuildApplication;
@@ -18,9 +17,13 @@ use zinc_build::InputBuild;
 use zinc_build::Value as BuildValue;
 use zinc_zksync::TransactionMsg;
 
+use zinc_vm::Backend;
 use zinc_vm::CircuitFacade;
 use zinc_vm::ContractFacade;
 use zinc_vm::ContractInput;
+use zinc_vm::Groth16;
+use zinc_vm::IBackend;
+use zinc_vm::Plonk;
 
 use crate::arguments::command::IExecutable;
 use crate::error::Error;
@@ -51,6 +54,10 @@ pub struct Command {
     /// The method name to call, if the application is a contract.
     #[structopt(long = "method")]
     pub method: Option<String>,
+
+    /// The proving backend, either `groth16` or `plonk`.
+    #[structopt(long = "backend", default_value = "groth16")]
+    pub backend: Backend,
 }
 
 impl IExecutable for Command {
@@ -68,12 +75,30 @@ impl IExecutable for Command {
             .error_with_path(|| self.input_path.to_string_lossy())?;
         let input: InputBuild = serde_json::from_str(input_template.as_str())?;
 
+        match self.backend {
+            Backend::Groth16 => self.prove::<Groth16>(application, input)?,
+            Backend::Plonk => self.prove::<Plonk>(application, input)?,
+        }
+
+        Ok(zinc_const::exit_code::SUCCESS as i32)
+    }
+}
+
+impl Command {
+    ///
+    /// Generates the proof with the backend `B` and writes it to the standard output.
+    ///
+    fn prove<B: IBackend<Bn256>>(
+        self,
+        application: BuildApplication,
+        input: InputBuild,
+    ) -> Result<(), Error> {
         // Read the proving key
         let proving_key_path = self.proving_key_path;
         let file = fs::File::open(&proving_key_path)
             .error_with_path(|| proving_key_path.to_string_lossy())?;
-        let params = Parameters::<Bn256>::read(file, true)
-            .error_with_path(|| proving_key_path.to_string_lossy())?;
+        let proving_key =
+            B::read_proving_key(file).error_with_path(|| proving_key_path.to_string_lossy())?;
 
         let proof = match application {
             BuildApplication::Circuit(circuit) => match input {
@@ -82,7 +107,7 @@ impl IExecutable for Command {
                     let arguments = BuildValue::try_from_typed_json(arguments, input_type)?;
 
                     let (_output, proof) =
-                        CircuitFacade::new(circuit).prove::<Bn256>(params, arguments)?;
+                        CircuitFacade::new(circuit).prove::<Bn256, B>(proving_key, arguments)?;
 
                     proof
                 }
@@ -136,8 +161,8 @@ impl IExecutable for Command {
                         value => return Err(Error::InvalidContractStorageFormat { found: value }),
                     };
 
-                    let (_output, proof) = ContractFacade::new(contract).prove::<Bn256>(
-                        params,
+                    let (_output, proof) = ContractFacade::new(contract).prove::<Bn256, B>(
+                        proving_key,
                         ContractInput::new(
                             method_arguments,
                             BuildValue::Contract(storage_values),
@@ -158,10 +183,10 @@ impl IExecutable for Command {
 
         // Write the proof to stdout
         let mut proof_bytes = Vec::new();
-        proof.write(&mut proof_bytes).expect("writing to vec");
+        B::write_proof(&proof, &mut proof_bytes).expect(zinc_const::panic::DATA_CONVERSION);
         let proof_hex = hex::encode(proof_bytes);
         println!("{}", proof_hex);
 
-        Ok(zinc_const::exit_code::SUCCESS as i32)
+        Ok(())
     }
 }
@@ -3,6 +3,7 @@
 //!
 
 use std::fs;
+use std::io;
 use std::path::PathBuf;
 
 use structopt::StructOpt;
@@ -11,8 +12,12 @@ use franklin_crypto::bellman::pairing::bn256::Bn256;
 
 use zinc_build::Application as BuildApplication;
 
+use zinc_vm::Backend;
 use zinc_vm::CircuitFacade;
 use zinc_vm::ContractFacade;
+use zinc_vm::Groth16;
+use zinc_vm::IBackend;
+use zinc_vm::Plonk;
 
 use crate::arguments::command::IExecutable;
 use crate::error::Error;
@@ -42,6 +47,15 @@ pub struct Command {
     /// The method name to call, if the application is a contract.
     #[structopt(long = "method")]
     pub method: Option<String>,
+
+    /// The proving backend, either `groth16` or `plonk`.
+    #[structopt(long = "backend", default_value = "groth16")]
+    pub backend: Backend,
+
+    /// The path to the universal reference string file in the monomial form.
+    /// Required by the `plonk` backend.
+    #[structopt(long = "srs")]
+    pub srs_path: Option<PathBuf>,
 }
 
 impl IExecutable for Command {
@@ -53,27 +67,70 @@ impl IExecutable for Command {
         let application = BuildApplication::try_from_slice(bytes.as_slice())
             .map_err(Error::ApplicationDecoding)?;
 
-        let params = match application {
-            BuildApplication::Circuit(circuit) => CircuitFacade::new(circuit).setup::<Bn256>()?,
+        let backend = self.backend;
+        let verifying_key_path = self.verifying_key_path.clone();
+
+        match backend {
+            Backend::Groth16 => self.setup::<Groth16>(application, ())?,
+            Backend::Plonk => {
+                let srs_path = self
+                    .srs_path
+                    .clone()
+                    .ok_or(Error::ReferenceStringNotFound)?;
+                let srs_file =
+                    fs::File::open(&srs_path).error_with_path(|| srs_path.to_string_lossy())?;
+                let reference_string =
+                    Plonk::read_reference_string::<Bn256, _>(io::BufReader::new(srs_file))
+                        .error_with_path(|| srs_path.to_string_lossy())?;
+
+                self.setup::<Plonk>(application, reference_string)?
+            }
+        }
+
+        backend
+            .write_record(&verifying_key_path)
+            .error_with_path(|| {
+                Backend::record_path(&verifying_key_path)
+                    .to_string_lossy()
+                    .to_string()
+            })?;
+
+        Ok(zinc_const::exit_code::SUCCESS as i32)
+    }
+}
+
+impl Command {
+    ///
+    /// Generates the keys with the backend `B` and the setup `parameters`, and writes them to
+    /// the files.
+    ///
+    fn setup<B: IBackend<Bn256>>(
+        self,
+        application: BuildApplication,
+        parameters: B::SetupParameters,
+    ) -> Result<(), Error> {
+        let (proving_key, verifying_key) = match application {
+            BuildApplication::Circuit(circuit) => {
+                CircuitFacade::new(circuit).setup::<Bn256, B>(parameters)?
+            }
             BuildApplication::Contract(contract) => {
                 let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
-                ContractFacade::new(contract).setup::<Bn256>(method_name)?
+                ContractFacade::new(contract).setup::<Bn256, B>(method_name, parameters)?
             }
         };
 
         let proving_key_path = self.proving_key_path;
         let pkey_file = fs::File::create(&proving_key_path)
             .error_with_path(|| proving_key_path.to_string_lossy())?;
-        params
-            .write(pkey_file)
+        B::write_proving_key(&proving_key, pkey_file)
             .error_with_path(|| proving_key_path.to_string_lossy())?;
 
-        let mut verifying_key = Vec::new();
-        params.vk.write(&mut verifying_key).expect("writing to vec");
         let verifying_key_path = self.verifying_key_path;
-        fs::write(&verifying_key_path, verifying_key)
+        let vkey_file = fs::File::create(&verifying_key_path)
+            .error_with_path(|| verifying_key_path.to_string_lossy())?;
+        B::write_verifying_key(&verifying_key, vkey_file)
             .error_with_path(|| verifying_key_path.to_string_lossy())?;
 
-        Ok(zinc_const::exit_code::SUCCESS as i32)
+        Ok(())
     }
 }
@@ -9,14 +9,16 @@ use std::path::PathBuf;
 use colored::Colorize;
 use structopt::StructOpt;
 
-use franklin_crypto::bellman::groth16::Proof;
-use franklin_crypto::bellman::groth16::VerifyingKey;
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 
 use zinc_build::Application as BuildApplication;
 use zinc_build::Value as BuildValue;
 
+use zinc_vm::Backend;
 use zinc_vm::Facade;
+use zinc_vm::Groth16;
+use zinc_vm::IBackend;
+use zinc_vm::Plonk;
 
 use crate::arguments::command::IExecutable;
 use crate::error::Error;
@@ -43,6 +45,10 @@ pub struct Command {
     /// The method name to call, if the application is a contract.
     #[structopt(long = "method")]
     pub method: Option<String>,
+
+    /// The proving backend. If not specified, it is read from the record written by `setup`.
+    #[structopt(long = "backend")]
+    pub backend: Option<Backend>,
 }
 
 impl IExecutable for Command {
@@ -58,7 +64,6 @@ impl IExecutable for Command {
             context: "proof".to_owned(),
             error,
         })?;
-        let proof = Proof::<Bn256>::read(proof.as_slice()).error_with_path(|| "<proof data>")?;
 
         // Read the application
         let bytes =
@@ -73,8 +78,6 @@ impl IExecutable for Command {
         verifying_key_file
             .read_to_end(&mut verifying_key)
             .error_with_path(|| self.verifying_key_path.to_string_lossy())?;
-        let verifying_key = VerifyingKey::<Bn256>::read(verifying_key.as_slice())
-            .error_with_path(|| self.verifying_key_path.to_string_lossy())?;
 
         // Read the public input
         let output_text = fs::read_to_string(&self.output_path)
@@ -98,8 +101,25 @@ impl IExecutable for Command {
         };
         let output_value = BuildValue::try_from_typed_json(output_json, output_type)?;
 
+        // Read the backend
+        let backend = match self.backend {
+            Some(backend) => backend,
+            None => Backend::read_record(&self.verifying_key_path).error_with_path(|| {
+                Backend::record_path(&self.verifying_key_path)
+                    .to_string_lossy()
+                    .to_string()
+            })?,
+        };
+
         // Verify the proof
-        let verified = Facade::verify::<Bn256>(verifying_key, proof, output_value)?;
+        let verified = match backend {
+            Backend::Groth16 => {
+                Self::verify::<Groth16>(verifying_key.as_slice(), proof.as_slice(), output_value)?
+            }
+            Backend::Plonk => {
+                Self::verify::<Plonk>(verifying_key.as_slice(), proof.as_slice(), output_value)?
+            }
+        };
 
         Ok(if verified {
             println!("{}", " ✔ Verified".bold().green());
@@ -110,3 +130,24 @@ impl IExecutable for Command {
         })
     }
 }
+
+impl Command {
+    ///
+    /// Decodes the verifying key and proof, and verifies the latter with the backend `B`.
+    ///
+    fn verify<B: IBackend<Bn256>>(
+        verifying_key: &[u8],
+        proof: &[u8],
+        public_input: BuildValue,
+    ) -> Result<bool, Error> {
+        let verifying_key =
+            B::read_verifying_key(verifying_key).error_with_path(|| "<verifying key data>")?;
+        let proof = B::read_proof(proof).error_with_path(|| "<proof data>")?;
+
+        Ok(Facade::verify::<Bn256, B>(
+            verifying_key,
+            proof,
+            public_input,
+        )?)
+    }
+}
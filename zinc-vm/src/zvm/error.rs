@@ -51,6 +51,12 @@ pub enum Error {
     #[fail(display = "failed to decode an application: {}", _0)]
     ApplicationDecoding(String),
 
+    /// The universal reference string is required by the backend, but not specified.
+    #[fail(
+        display = "the `plonk` backend requires a universal reference string passed with `--srs`"
+    )]
+    ReferenceStringNotFound,
+
     /// The hexadecimal data decoding error. Is caused by invalid proofs and keys.
     #[fail(display = "failed to decode {} hex-code: {}", context, error)]
     HexDecoding {
@@ -2,12 +2,15 @@
 //! The Zinc source code JSON representation.
 //!
 
+pub(crate) mod mock;
 pub(crate) mod request;
 pub(crate) mod response;
 pub(crate) mod source;
 pub(crate) mod transaction;
 pub(crate) mod utils;
 
+pub use self::mock::error::Error as MockError;
+pub use self::mock::State as MockState;
 pub use self::request::call::Body as CallRequestBody;
 pub use self::request::call::Query as CallRequestQuery;
 pub use self::request::fee::Body as FeeRequestBody;
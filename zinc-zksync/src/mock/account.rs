@@ -0,0 +1,102 @@
+//!
+//! The mock zkSync server account.
+//!
+
+use std::collections::BTreeMap;
+
+use num::BigUint;
+use num::Zero;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use super::error::Error;
+use super::token::Token;
+
+///
+/// The mock zkSync server account.
+///
+#[derive(Debug, Clone)]
+pub struct Account {
+    /// The account ID assigned on the first incoming transfer or deposit.
+    pub id: u32,
+    /// The token balances by the token IDs.
+    pub balances: BTreeMap<u16, BigUint>,
+    /// The account nonce, which is incremented by every outgoing transaction.
+    pub nonce: u32,
+    /// The signing key hash set by the change-pubkey transaction.
+    pub pub_key_hash: String,
+}
+
+impl Account {
+    /// The signing key hash of the accounts, which have not set their signing key yet.
+    pub const PUB_KEY_HASH_EMPTY: &'static str = "sync:0000000000000000000000000000000000000000";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            balances: BTreeMap::new(),
+            nonce: 0,
+            pub_key_hash: Self::PUB_KEY_HASH_EMPTY.to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the `token_id` balance.
+    ///
+    pub fn balance(&self, token_id: u16) -> BigUint {
+        self.balances.get(&token_id).cloned().unwrap_or_default()
+    }
+
+    ///
+    /// Adds `amount` to the `token_id` balance.
+    ///
+    pub fn credit(&mut self, token_id: u16, amount: &BigUint) {
+        *self.balances.entry(token_id).or_insert_with(BigUint::zero) += amount;
+    }
+
+    ///
+    /// Subtracts `amount` from the `token_id` balance.
+    ///
+    pub fn debit(&mut self, token_id: u16, amount: &BigUint) -> Result<(), Error> {
+        let balance = self.balances.entry(token_id).or_insert_with(BigUint::zero);
+        if *balance < *amount {
+            return Err(Error::NotEnoughBalance);
+        }
+        *balance -= amount;
+
+        Ok(())
+    }
+
+    ///
+    /// Converts the account into the zkSync JSON-RPC account state representation.
+    ///
+    pub fn to_state_json(&self) -> JsonValue {
+        let mut balances = serde_json::Map::with_capacity(self.balances.len());
+        for (token_id, balance) in self.balances.iter() {
+            let symbol = Token::by_id(*token_id)
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+                .symbol;
+            balances.insert(symbol.to_owned(), JsonValue::String(balance.to_string()));
+        }
+
+        json!({
+            "balances": balances,
+            "nonce": self.nonce,
+            "pubKeyHash": self.pub_key_hash,
+        })
+    }
+
+    ///
+    /// The zkSync JSON-RPC account state representation of a non-existent account.
+    ///
+    pub fn empty_state_json() -> JsonValue {
+        json!({
+            "balances": {},
+            "nonce": 0,
+            "pubKeyHash": Self::PUB_KEY_HASH_EMPTY,
+        })
+    }
+}
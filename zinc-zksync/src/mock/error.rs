@@ -0,0 +1,66 @@
+//!
+//! The mock zkSync server error.
+//!
+
+use failure::Fail;
+
+///
+/// The mock zkSync server error.
+///
+/// The JSON-RPC errors reject a request, whereas the transaction execution errors are
+/// reported as the transaction failure reasons.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The JSON-RPC method is not implemented.
+    #[fail(display = "method `{}` not found", _0)]
+    MethodNotFound(String),
+    /// The JSON-RPC method parameters are invalid.
+    #[fail(display = "invalid parameters: {}", _0)]
+    InvalidParameters(String),
+    /// The token is not registered.
+    #[fail(display = "token `{}` not found", _0)]
+    TokenNotFound(String),
+    /// The transaction type cannot be executed without Ethereum.
+    #[fail(display = "transaction type `{}` is not supported", _0)]
+    TransactionUnsupported(String),
+
+    /// The transaction sender account does not exist.
+    #[fail(display = "account {} does not exist", _0)]
+    AccountNotFound(String),
+    /// The transaction account ID does not match the sender account.
+    #[fail(
+        display = "account ID mismatch: expected {}, found {}",
+        expected, found
+    )]
+    AccountIdMismatch {
+        /// The sender account ID.
+        expected: u32,
+        /// The transaction account ID.
+        found: u32,
+    },
+    /// The transaction nonce does not match the sender account.
+    #[fail(display = "nonce mismatch: expected {}, found {}", expected, found)]
+    NonceMismatch {
+        /// The sender account nonce.
+        expected: u32,
+        /// The transaction nonce.
+        found: u32,
+    },
+    /// The sender account balance is less than the transaction amount and fee.
+    #[fail(display = "not enough balance")]
+    NotEnoughBalance,
+}
+
+impl Error {
+    ///
+    /// The JSON-RPC error code.
+    ///
+    pub fn code(&self) -> i64 {
+        match self {
+            Self::MethodNotFound(_) => -32601,
+            Self::InvalidParameters(_) => -32602,
+            _ => -32000,
+        }
+    }
+}
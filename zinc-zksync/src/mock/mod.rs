@@ -0,0 +1,480 @@
+//!
+//! The mock zkSync server.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod account;
+pub mod error;
+pub mod token;
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use num::BigUint;
+use num::Zero;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use self::account::Account;
+use self::error::Error;
+use self::token::Token;
+use self::token::TOKENS;
+
+///
+/// The mock zkSync server state.
+///
+/// Implements the subset of the zkSync JSON-RPC interface used by the zkSync SDK provider and
+/// wallet, so Zandbox and Zargo can work without a real zkSync network. The transactions are
+/// executed and committed immediately on submission, and each submission forms a new block.
+///
+/// Since there is no Ethereum network behind the mock, the accounts are funded with the
+/// non-standard `mock_deposit` method, and the signatures are not verified.
+///
+#[derive(Debug, Default)]
+pub struct State {
+    /// The accounts by their lowercase hexadecimal addresses.
+    accounts: BTreeMap<String, Account>,
+    /// The executed transaction receipts by the transaction hashes.
+    receipts: HashMap<String, Receipt>,
+    /// The last committed block number.
+    block_number: u32,
+}
+
+///
+/// The executed transaction receipt.
+///
+#[derive(Debug)]
+struct Receipt {
+    /// The block the transaction was included into.
+    block_number: u32,
+    /// The failure reason, if the transaction has failed.
+    fail_reason: Option<String>,
+}
+
+///
+/// The parsed transaction.
+///
+#[derive(Debug)]
+enum Transaction {
+    /// The transfer between accounts.
+    Transfer {
+        /// The sender account ID.
+        account_id: u32,
+        /// The sender address.
+        from: String,
+        /// The recipient address.
+        to: String,
+        /// The transferred token ID.
+        token_id: u16,
+        /// The transferred amount.
+        amount: BigUint,
+        /// The fee paid in the transferred token.
+        fee: BigUint,
+        /// The sender nonce.
+        nonce: u32,
+    },
+    /// The account signing key change.
+    ChangePubKey {
+        /// The account ID.
+        account_id: u32,
+        /// The account address.
+        account: String,
+        /// The new signing key hash.
+        new_pk_hash: String,
+        /// The fee token ID.
+        fee_token_id: u16,
+        /// The fee amount.
+        fee: BigUint,
+        /// The account nonce.
+        nonce: u32,
+    },
+}
+
+impl State {
+    /// The `Transfer` transaction fee quote in the token minimal units.
+    pub const FEE_TRANSFER: u64 = 10_000_000_000_000;
+
+    /// The `ChangePubKey` transaction fee quote in the token minimal units.
+    pub const FEE_CHANGE_PUBKEY: u64 = 50_000_000_000_000;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Handles a JSON-RPC 2.0 request and returns the response.
+    ///
+    pub fn handle(&mut self, request: JsonValue) -> JsonValue {
+        let id = request.get("id").cloned().unwrap_or(JsonValue::Null);
+        let method = request
+            .get("method")
+            .and_then(JsonValue::as_str)
+            .unwrap_or_default()
+            .to_owned();
+        let parameters = request
+            .get("params")
+            .cloned()
+            .unwrap_or_else(|| JsonValue::Array(vec![]));
+
+        match self.dispatch(method.as_str(), &parameters) {
+            Ok(result) => json!({
+                "jsonrpc": "2.0",
+                "result": result,
+                "id": id,
+            }),
+            Err(error) => json!({
+                "jsonrpc": "2.0",
+                "error": {
+                    "code": error.code(),
+                    "message": error.to_string(),
+                },
+                "id": id,
+            }),
+        }
+    }
+
+    ///
+    /// Credits the `amount` of `token` to the `address` account, creating it if necessary.
+    ///
+    pub fn deposit(
+        &mut self,
+        address: &str,
+        token: &JsonValue,
+        amount: BigUint,
+    ) -> Result<(), Error> {
+        let address = Self::address(&JsonValue::String(address.to_owned()))?;
+        let token = Token::resolve(token)?;
+
+        Self::account_or_create(&mut self.accounts, address).credit(token.id, &amount);
+
+        Ok(())
+    }
+
+    ///
+    /// Dispatches the JSON-RPC method call.
+    ///
+    fn dispatch(&mut self, method: &str, parameters: &JsonValue) -> Result<JsonValue, Error> {
+        match method {
+            "tokens" => {
+                let mut tokens = serde_json::Map::with_capacity(TOKENS.len());
+                for token in TOKENS.iter() {
+                    tokens.insert(token.symbol.to_owned(), token.to_json());
+                }
+                Ok(JsonValue::Object(tokens))
+            }
+            "contract_address" => Ok(json!({
+                "mainContract": TOKENS[0].address,
+                "govContract": TOKENS[0].address,
+            })),
+            "account_info" => {
+                let address = Self::address(Self::parameter(parameters, 0)?)?;
+                Ok(self.account_info(address.as_str()))
+            }
+            "get_tx_fee" => {
+                let fee = match Self::parameter(parameters, 0)? {
+                    JsonValue::String(r#type)
+                        if r#type == "Transfer" || r#type == "TransferToNew" =>
+                    {
+                        Self::FEE_TRANSFER
+                    }
+                    JsonValue::Object(r#type) if r#type.contains_key("ChangePubKey") => {
+                        Self::FEE_CHANGE_PUBKEY
+                    }
+                    r#type => return Err(Error::TransactionUnsupported(r#type.to_string())),
+                };
+                Self::address(Self::parameter(parameters, 1)?)?;
+                Token::resolve(Self::parameter(parameters, 2)?)?;
+
+                Ok(json!({
+                    "feeType": parameters[0],
+                    "gasTxAmount": "0",
+                    "gasPriceWei": "0",
+                    "gasFee": fee.to_string(),
+                    "zkpFee": "0",
+                    "totalFee": fee.to_string(),
+                }))
+            }
+            "tx_submit" => {
+                let transaction = Self::transaction(Self::parameter(parameters, 0)?)?;
+                let hashes = self.execute(vec![transaction]);
+                Ok(JsonValue::String(
+                    hashes.into_iter().next().unwrap_or_default(),
+                ))
+            }
+            "submit_txs_batch" => {
+                let transactions = Self::parameter(parameters, 0)?
+                    .as_array()
+                    .ok_or_else(|| {
+                        Error::InvalidParameters("expected an array of transactions".to_owned())
+                    })?
+                    .iter()
+                    .map(|transaction| {
+                        Self::transaction(transaction.get("tx").unwrap_or(&JsonValue::Null))
+                    })
+                    .collect::<Result<Vec<Transaction>, Error>>()?;
+                Ok(json!(self.execute(transactions)))
+            }
+            "tx_info" => {
+                let hash = Self::parameter(parameters, 0)?
+                    .as_str()
+                    .ok_or_else(|| Error::InvalidParameters("expected a hash".to_owned()))?;
+                Ok(match self.receipts.get(hash) {
+                    Some(receipt) => json!({
+                        "executed": true,
+                        "success": receipt.fail_reason.is_none(),
+                        "failReason": receipt.fail_reason,
+                        "block": {
+                            "blockNumber": receipt.block_number,
+                            "committed": true,
+                            "verified": true,
+                        },
+                    }),
+                    None => json!({
+                        "executed": false,
+                        "success": null,
+                        "failReason": null,
+                        "block": null,
+                    }),
+                })
+            }
+            "mock_deposit" => {
+                let address = Self::address(Self::parameter(parameters, 0)?)?;
+                let amount = Self::amount(Self::parameter(parameters, 2)?)?;
+                self.deposit(address.as_str(), Self::parameter(parameters, 1)?, amount)?;
+                Ok(self.account_info(address.as_str()))
+            }
+            method => Err(Error::MethodNotFound(method.to_owned())),
+        }
+    }
+
+    ///
+    /// Returns the zkSync JSON-RPC account info representation.
+    ///
+    fn account_info(&self, address: &str) -> JsonValue {
+        let (id, state) = match self.accounts.get(address) {
+            Some(account) => (json!(account.id), account.to_state_json()),
+            None => (JsonValue::Null, Account::empty_state_json()),
+        };
+
+        json!({
+            "address": address,
+            "id": id,
+            "depositing": {
+                "balances": {},
+            },
+            "committed": state,
+            "verified": state,
+        })
+    }
+
+    ///
+    /// Executes the transactions atomically in a new block and returns their hashes.
+    ///
+    /// If any transaction fails, the state is not changed, and all the transactions are
+    /// reported as failed with the reason of the first failure.
+    ///
+    fn execute(&mut self, transactions: Vec<Transaction>) -> Vec<String> {
+        self.block_number += 1;
+
+        let mut accounts = self.accounts.clone();
+        let fail_reason = transactions
+            .iter()
+            .try_for_each(|transaction| Self::apply(&mut accounts, transaction))
+            .err()
+            .map(|error| error.to_string());
+        if fail_reason.is_none() {
+            self.accounts = accounts;
+        }
+
+        transactions
+            .into_iter()
+            .map(|_transaction| {
+                let hash = format!("sync-tx:{:064x}", self.receipts.len() + 1);
+                self.receipts.insert(
+                    hash.clone(),
+                    Receipt {
+                        block_number: self.block_number,
+                        fail_reason: fail_reason.clone(),
+                    },
+                );
+                hash
+            })
+            .collect()
+    }
+
+    ///
+    /// Applies a transaction to the accounts.
+    ///
+    fn apply(
+        accounts: &mut BTreeMap<String, Account>,
+        transaction: &Transaction,
+    ) -> Result<(), Error> {
+        match transaction {
+            Transaction::Transfer {
+                account_id,
+                from,
+                to,
+                token_id,
+                amount,
+                fee,
+                nonce,
+            } => {
+                let sender = Self::sender(accounts, from, *account_id, *nonce)?;
+                sender.debit(*token_id, &(amount + fee))?;
+                sender.nonce += 1;
+
+                Self::account_or_create(accounts, to.to_owned()).credit(*token_id, amount);
+            }
+            Transaction::ChangePubKey {
+                account_id,
+                account,
+                new_pk_hash,
+                fee_token_id,
+                fee,
+                nonce,
+            } => {
+                let sender = Self::sender(accounts, account, *account_id, *nonce)?;
+                sender.debit(*fee_token_id, fee)?;
+                sender.nonce += 1;
+                sender.pub_key_hash = new_pk_hash.to_owned();
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the transaction sender account, checking its ID and nonce.
+    ///
+    fn sender<'a>(
+        accounts: &'a mut BTreeMap<String, Account>,
+        address: &str,
+        account_id: u32,
+        nonce: u32,
+    ) -> Result<&'a mut Account, Error> {
+        let account = accounts
+            .get_mut(address)
+            .ok_or_else(|| Error::AccountNotFound(address.to_owned()))?;
+        if account.id != account_id {
+            return Err(Error::AccountIdMismatch {
+                expected: account.id,
+                found: account_id,
+            });
+        }
+        if account.nonce != nonce {
+            return Err(Error::NonceMismatch {
+                expected: account.nonce,
+                found: nonce,
+            });
+        }
+
+        Ok(account)
+    }
+
+    ///
+    /// Returns the account at `address`, creating it with the next free ID if necessary.
+    ///
+    fn account_or_create(
+        accounts: &mut BTreeMap<String, Account>,
+        address: String,
+    ) -> &mut Account {
+        let next_id = accounts.len() as u32 + 1;
+        accounts
+            .entry(address)
+            .or_insert_with(|| Account::new(next_id))
+    }
+
+    ///
+    /// Parses a zkSync transaction from its JSON representation.
+    ///
+    fn transaction(value: &JsonValue) -> Result<Transaction, Error> {
+        let field = |name: &str| {
+            value.get(name).ok_or_else(|| {
+                Error::InvalidParameters(format!("the transaction field `{}` is missing", name))
+            })
+        };
+
+        match value.get("type").and_then(JsonValue::as_str) {
+            Some("Transfer") => Ok(Transaction::Transfer {
+                account_id: Self::integer(field("accountId")?)? as u32,
+                from: Self::address(field("from")?)?,
+                to: Self::address(field("to")?)?,
+                token_id: Token::resolve(field("token")?)?.id,
+                amount: Self::amount(field("amount")?)?,
+                fee: Self::amount(field("fee")?)?,
+                nonce: Self::integer(field("nonce")?)? as u32,
+            }),
+            Some("ChangePubKey") => Ok(Transaction::ChangePubKey {
+                account_id: Self::integer(field("accountId")?)? as u32,
+                account: Self::address(field("account")?)?,
+                new_pk_hash: field("newPkHash")?
+                    .as_str()
+                    .ok_or_else(|| Error::InvalidParameters("expected a key hash".to_owned()))?
+                    .to_owned(),
+                fee_token_id: match value.get("feeToken") {
+                    Some(token) => Token::resolve(token)?.id,
+                    None => TOKENS[0].id,
+                },
+                fee: match value.get("fee") {
+                    Some(fee) => Self::amount(fee)?,
+                    None => BigUint::zero(),
+                },
+                nonce: Self::integer(field("nonce")?)? as u32,
+            }),
+            Some(r#type) => Err(Error::TransactionUnsupported(r#type.to_owned())),
+            None => Err(Error::InvalidParameters(
+                "the transaction type is missing".to_owned(),
+            )),
+        }
+    }
+
+    ///
+    /// Returns the positional JSON-RPC parameter.
+    ///
+    fn parameter(parameters: &JsonValue, index: usize) -> Result<&JsonValue, Error> {
+        parameters
+            .get(index)
+            .ok_or_else(|| Error::InvalidParameters(format!("parameter {} is missing", index)))
+    }
+
+    ///
+    /// Parses and normalizes an ETH address.
+    ///
+    fn address(value: &JsonValue) -> Result<String, Error> {
+        value
+            .as_str()
+            .filter(|address| {
+                address.len() == 2 + 2 * zinc_const::size::ETH_ADDRESS
+                    && address.starts_with("0x")
+                    && address[2..].chars().all(|c| c.is_ascii_hexdigit())
+            })
+            .map(str::to_ascii_lowercase)
+            .ok_or_else(|| Error::InvalidParameters(format!("invalid address {}", value)))
+    }
+
+    ///
+    /// Parses a token amount, which is serialized as a decimal string.
+    ///
+    fn amount(value: &JsonValue) -> Result<BigUint, Error> {
+        match value {
+            JsonValue::String(amount) => amount.parse().ok(),
+            JsonValue::Number(amount) => amount.as_u64().map(BigUint::from),
+            _ => None,
+        }
+        .ok_or_else(|| Error::InvalidParameters(format!("invalid amount {}", value)))
+    }
+
+    ///
+    /// Parses an unsigned integer like an account ID or nonce.
+    ///
+    fn integer(value: &JsonValue) -> Result<u64, Error> {
+        value
+            .as_u64()
+            .ok_or_else(|| Error::InvalidParameters(format!("invalid integer {}", value)))
+    }
+}
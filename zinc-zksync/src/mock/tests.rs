@@ -0,0 +1,221 @@
+//!
+//! The mock zkSync server tests.
+//!
+
+use num::BigUint;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::mock::State;
+
+const ALICE: &str = "0x1111111111111111111111111111111111111111";
+
+const BOB: &str = "0x2222222222222222222222222222222222222222";
+
+const CAROL: &str = "0x3333333333333333333333333333333333333333";
+
+fn call(state: &mut State, method: &str, parameters: JsonValue) -> JsonValue {
+    let response = state.handle(json!({
+        "jsonrpc": "2.0",
+        "method": method,
+        "params": parameters,
+        "id": 42,
+    }));
+    assert_eq!(response["id"], json!(42));
+    response
+}
+
+fn result(state: &mut State, method: &str, parameters: JsonValue) -> JsonValue {
+    let response = call(state, method, parameters);
+    assert!(
+        response.get("error").is_none(),
+        "Unexpected error: {}",
+        response
+    );
+    response["result"].to_owned()
+}
+
+fn transfer(from: &str, account_id: u32, to: &str, amount: &str, nonce: u32) -> JsonValue {
+    json!({
+        "type": "Transfer",
+        "accountId": account_id,
+        "from": from,
+        "to": to,
+        "token": 0,
+        "amount": amount,
+        "fee": "10",
+        "nonce": nonce,
+        "signature": null,
+    })
+}
+
+fn funded() -> State {
+    let mut state = State::new();
+    state
+        .deposit(ALICE, &json!("ETH"), BigUint::from(1_000u32))
+        .expect("Depositing");
+    state
+}
+
+#[test]
+fn ok_deposit() {
+    let mut state = State::new();
+
+    let info = result(&mut state, "account_info", json!([ALICE]));
+    assert_eq!(info["id"], JsonValue::Null);
+
+    result(&mut state, "mock_deposit", json!([ALICE, "ETH", "1000"]));
+    result(&mut state, "mock_deposit", json!([ALICE, 1, 25]));
+
+    let info = result(&mut state, "account_info", json!([ALICE]));
+    assert_eq!(info["id"], json!(1));
+    assert_eq!(
+        info["committed"]["balances"],
+        json!({ "ETH": "1000", "DAI": "25" })
+    );
+}
+
+#[test]
+fn ok_transfer_to_new() {
+    let mut state = funded();
+
+    let hash = result(
+        &mut state,
+        "tx_submit",
+        json!([transfer(ALICE, 1, BOB, "100", 0), null, false]),
+    );
+    let receipt = result(&mut state, "tx_info", json!([hash]));
+    assert_eq!(receipt["executed"], json!(true));
+    assert_eq!(receipt["success"], json!(true));
+    assert_eq!(receipt["block"]["blockNumber"], json!(1));
+
+    let alice = result(&mut state, "account_info", json!([ALICE]));
+    assert_eq!(alice["committed"]["balances"]["ETH"], json!("890"));
+    assert_eq!(alice["committed"]["nonce"], json!(1));
+
+    let bob = result(&mut state, "account_info", json!([BOB]));
+    assert_eq!(bob["id"], json!(2));
+    assert_eq!(bob["committed"]["balances"]["ETH"], json!("100"));
+}
+
+#[test]
+fn ok_change_pubkey() {
+    let mut state = funded();
+
+    let hash = result(
+        &mut state,
+        "tx_submit",
+        json!([{
+            "type": "ChangePubKey",
+            "accountId": 1,
+            "account": ALICE,
+            "newPkHash": "sync:1111111111111111111111111111111111111111",
+            "feeToken": 0,
+            "fee": "50",
+            "nonce": 0,
+        }, null, false]),
+    );
+    assert_eq!(
+        result(&mut state, "tx_info", json!([hash]))["success"],
+        json!(true)
+    );
+
+    let alice = result(&mut state, "account_info", json!([ALICE]));
+    assert_eq!(
+        alice["committed"]["pubKeyHash"],
+        json!("sync:1111111111111111111111111111111111111111")
+    );
+    assert_eq!(alice["committed"]["balances"]["ETH"], json!("950"));
+}
+
+#[test]
+fn ok_fee() {
+    let mut state = State::new();
+
+    let fee = result(&mut state, "get_tx_fee", json!(["Transfer", ALICE, "ETH"]));
+    assert_eq!(fee["totalFee"], json!(State::FEE_TRANSFER.to_string()));
+}
+
+#[test]
+fn ok_tx_info_unknown() {
+    let mut state = State::new();
+
+    let receipt = result(&mut state, "tx_info", json!(["sync-tx:00"]));
+    assert_eq!(receipt["executed"], json!(false));
+}
+
+#[test]
+fn error_nonce_mismatch() {
+    let mut state = funded();
+
+    let hash = result(
+        &mut state,
+        "tx_submit",
+        json!([transfer(ALICE, 1, BOB, "100", 5), null, false]),
+    );
+    let receipt = result(&mut state, "tx_info", json!([hash]));
+    assert_eq!(receipt["success"], json!(false));
+    assert_eq!(
+        receipt["failReason"],
+        json!("nonce mismatch: expected 0, found 5")
+    );
+}
+
+#[test]
+fn error_not_enough_balance() {
+    let mut state = funded();
+
+    let hash = result(
+        &mut state,
+        "tx_submit",
+        json!([transfer(ALICE, 1, BOB, "1000", 0), null, false]),
+    );
+    let receipt = result(&mut state, "tx_info", json!([hash]));
+    assert_eq!(receipt["failReason"], json!("not enough balance"));
+}
+
+#[test]
+fn error_batch_atomic() {
+    let mut state = funded();
+
+    let hashes = result(
+        &mut state,
+        "submit_txs_batch",
+        json!([[
+            { "tx": transfer(ALICE, 1, BOB, "100", 0), "signature": null },
+            { "tx": transfer(ALICE, 1, CAROL, "1000", 1), "signature": null },
+        ], null]),
+    );
+    let hashes = hashes.as_array().expect("Hashes array");
+    assert_eq!(hashes.len(), 2);
+    for hash in hashes.iter() {
+        let receipt = result(&mut state, "tx_info", json!([hash]));
+        assert_eq!(receipt["success"], json!(false));
+    }
+
+    let alice = result(&mut state, "account_info", json!([ALICE]));
+    assert_eq!(alice["committed"]["balances"]["ETH"], json!("1000"));
+    assert_eq!(alice["committed"]["nonce"], json!(0));
+    let bob = result(&mut state, "account_info", json!([BOB]));
+    assert_eq!(bob["id"], JsonValue::Null);
+}
+
+#[test]
+fn error_method_not_found() {
+    let mut state = State::new();
+
+    let response = call(&mut state, "ethop_info", json!([1]));
+    assert_eq!(response["error"]["code"], json!(-32601));
+}
+
+#[test]
+fn error_transaction_unsupported() {
+    let mut state = funded();
+
+    let response = call(
+        &mut state,
+        "tx_submit",
+        json!([{ "type": "Withdraw", "accountId": 1 }, null, false]),
+    );
+    assert_eq!(response["error"]["code"], json!(-32000));
+}
@@ -0,0 +1,85 @@
+//!
+//! The mock zkSync server token.
+//!
+
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use super::error::Error;
+
+///
+/// The mock zkSync server token.
+///
+#[derive(Debug)]
+pub struct Token {
+    /// The zkSync token ID.
+    pub id: u16,
+    /// The token ERC20 contract address.
+    pub address: &'static str,
+    /// The token symbol.
+    pub symbol: &'static str,
+    /// The number of the token decimal places.
+    pub decimals: u8,
+}
+
+///
+/// The tokens of the local zkSync development network.
+///
+pub static TOKENS: &[Token] = &[
+    Token {
+        id: 0,
+        address: "0x0000000000000000000000000000000000000000",
+        symbol: "ETH",
+        decimals: 18,
+    },
+    Token {
+        id: 1,
+        address: "0x3bdfbbfdcf051c6ec5a741cc0fde89e30ff2f824",
+        symbol: "DAI",
+        decimals: 18,
+    },
+];
+
+impl Token {
+    ///
+    /// Resolves the token by its ID, address, or symbol, like the zkSync `TokenLike` type.
+    ///
+    pub fn resolve(token_like: &JsonValue) -> Result<&'static Self, Error> {
+        let token = match token_like {
+            JsonValue::Number(id) => TOKENS
+                .iter()
+                .find(|token| id.as_u64() == Some(token.id as u64)),
+            JsonValue::String(address) if address.starts_with("0x") => TOKENS
+                .iter()
+                .find(|token| token.address.eq_ignore_ascii_case(address.as_str())),
+            JsonValue::String(symbol) => TOKENS
+                .iter()
+                .find(|token| token.symbol.eq_ignore_ascii_case(symbol.as_str())),
+            _ => None,
+        };
+
+        token.ok_or_else(|| Error::TokenNotFound(token_like.to_string()))
+    }
+
+    ///
+    /// Resolves the token by its ID.
+    ///
+    pub fn by_id(id: u16) -> Result<&'static Self, Error> {
+        TOKENS
+            .iter()
+            .find(|token| token.id == id)
+            .ok_or_else(|| Error::TokenNotFound(id.to_string()))
+    }
+
+    ///
+    /// Converts the token into the zkSync JSON-RPC representation.
+    ///
+    pub fn to_json(&self) -> JsonValue {
+        json!({
+            "id": self.id,
+            "address": self.address,
+            "symbol": self.symbol,
+            "decimals": self.decimals,
+        })
+    }
+}
@@ -22,17 +22,25 @@ pub struct Query {
     pub method: Option<String>,
     /// The network where the contract resides.
     pub network: Network,
+    /// The storage snapshot version to query. If not specified, the latest storage is used.
+    pub version: Option<u32>,
 }
 
 impl Query {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(address: Address, method: Option<String>, network: Network) -> Self {
+    pub fn new(
+        address: Address,
+        method: Option<String>,
+        network: Network,
+        version: Option<u32>,
+    ) -> Self {
         Self {
             address,
             method,
             network,
+            version,
         }
     }
 }
@@ -43,7 +51,7 @@ impl IntoIterator for Query {
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let mut result = Vec::with_capacity(3);
+        let mut result = Vec::with_capacity(4);
         result.push((
             "address",
             serde_json::to_string(&self.address)
@@ -54,6 +62,9 @@ impl IntoIterator for Query {
             result.push(("method", method));
         }
         result.push(("network", self.network.to_string()));
+        if let Some(version) = self.version {
+            result.push(("version", version.to_string()));
+        }
         result.into_iter()
     }
 }